@@ -6,7 +6,7 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `audio_input_format_by_mime_type`, `chat_message_content_to_text`, `decode_local_whisper_wav_bytes`, `extract_chat_stream_delta_text`, `extract_text_from_json_value`, `extract_transcript_text`, `file_ext_for_mime_type`, `is_auto_transcribe_lang`, `key_from_bytes`, `local_whisper_model_filename`, `multimodal_transcribe_prompt`, `normalize_local_whisper_lang`, `normalize_local_whisper_model_name`, `normalize_transcript_text`, `openai_audio_transcriptions_url`, `openai_chat_completions_url`, `parse_chat_transcribe_sse_payload`, `parse_sse_data_events`, `parse_usage_value`, `parse_whisper_sse_payload`, `resolve_local_whisper_model_path`
+// These functions are ignored because they are not marked as `pub`: `audio_input_format_by_mime_type`, `byok_transcribe_credentials`, `byok_transcribe_profiles`, `chat_message_content_to_text`, `decode_local_whisper_wav_bytes`, `extract_chat_stream_delta_text`, `extract_text_from_json_value`, `extract_transcript_text`, `file_ext_for_mime_type`, `is_auto_transcribe_lang`, `key_from_bytes`, `local_whisper_model_filename`, `multimodal_transcribe_prompt`, `normalize_local_whisper_lang`, `normalize_local_whisper_model_name`, `normalize_transcript_text`, `openai_audio_transcriptions_url`, `openai_chat_completions_url`, `parse_chat_transcribe_sse_payload`, `parse_sse_data_events`, `parse_usage_value`, `parse_whisper_sse_payload`, `record_audio_transcribe_usage`, `resolve_local_whisper_model_path`, `transcribe_byok_multimodal_once`, `transcribe_byok_whisper_once`
 // These types are ignored because they are not used by any `pub` functions: `OpenAiAudioTranscribeResponse`, `OpenAiUsage`
 
 Future<String> audioTranscribeLocalWhisper(
@@ -6,9 +6,10 @@
 import '../db.dart';
 import '../frb_generated.dart';
 import '../semantic_parse.dart';
+import '../sync/caldav.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `default_embedding_model_name_for_platform`, `emit_ask_ai_meta_if_any`, `finish_ask_ai_stream`, `key_from_bytes`, `map_attachment_download_error`, `normalize_embedding_model_name`, `sync_key_from_bytes`
+// These functions are ignored because they are not marked as `pub`: `byok_ask_ai_search_queries`, `cloud_gateway_ask_ai_search_queries`, `default_embedding_model_name_for_platform`, `emit_ask_ai_meta_if_any`, `finish_ask_ai_stream`, `key_from_bytes`, `map_attachment_download_error`, `normalize_embedding_model_name`, `sync_key_from_bytes`
 
 Future<bool> authIsInitialized({required String appDir}) =>
     RustLib.instance.api.crateApiCoreAuthIsInitialized(appDir: appDir);
@@ -203,6 +204,148 @@ Future<String?> dbGetTodoRecurrenceRuleJson(
     RustLib.instance.api.crateApiCoreDbGetTodoRecurrenceRuleJson(
         appDir: appDir, todoId: todoId);
 
+Future<Todo> dbSetTodoTimezone(
+        {required String appDir,
+        required List<int> key,
+        required String todoId,
+        String? tz}) =>
+    RustLib.instance.api.crateApiCoreDbSetTodoTimezone(
+        appDir: appDir, key: key, todoId: todoId, tz: tz);
+
+Future<String?> dbGetTodoTimezone(
+        {required String appDir, required String todoId}) =>
+    RustLib.instance.api
+        .crateApiCoreDbGetTodoTimezone(appDir: appDir, todoId: todoId);
+
+Future<Todo> dbSetTodoParent(
+        {required String appDir,
+        required List<int> key,
+        required String todoId,
+        String? parentId}) =>
+    RustLib.instance.api.crateApiCoreDbSetTodoParent(
+        appDir: appDir, key: key, todoId: todoId, parentId: parentId);
+
+Future<Todo> dbSetTodoCompleteWithSubtasks(
+        {required String appDir,
+        required List<int> key,
+        required String todoId,
+        required bool enabled}) =>
+    RustLib.instance.api.crateApiCoreDbSetTodoCompleteWithSubtasks(
+        appDir: appDir, key: key, todoId: todoId, enabled: enabled);
+
+Future<List<Todo>> dbListTodoSubtasks(
+        {required String appDir,
+        required List<int> key,
+        required String parentId}) =>
+    RustLib.instance.api.crateApiCoreDbListTodoSubtasks(
+        appDir: appDir, key: key, parentId: parentId);
+
+Future<void> dbAddTodoDependency(
+        {required String appDir,
+        required List<int> key,
+        required String todoId,
+        required String blockedByTodoId}) =>
+    RustLib.instance.api.crateApiCoreDbAddTodoDependency(
+        appDir: appDir,
+        key: key,
+        todoId: todoId,
+        blockedByTodoId: blockedByTodoId);
+
+Future<void> dbRemoveTodoDependency(
+        {required String appDir,
+        required List<int> key,
+        required String todoId,
+        required String blockedByTodoId}) =>
+    RustLib.instance.api.crateApiCoreDbRemoveTodoDependency(
+        appDir: appDir,
+        key: key,
+        todoId: todoId,
+        blockedByTodoId: blockedByTodoId);
+
+Future<List<Todo>> dbListTodoBlockers(
+        {required String appDir,
+        required List<int> key,
+        required String todoId}) =>
+    RustLib.instance.api.crateApiCoreDbListTodoBlockers(
+        appDir: appDir, key: key, todoId: todoId);
+
+Future<List<Todo>> dbListTodosBlockedBy(
+        {required String appDir,
+        required List<int> key,
+        required String todoId}) =>
+    RustLib.instance.api.crateApiCoreDbListTodosBlockedBy(
+        appDir: appDir, key: key, todoId: todoId);
+
+Future<TodoRollup> dbGetTodoRollup(
+        {required String appDir,
+        required List<int> key,
+        required String todoId}) =>
+    RustLib.instance.api
+        .crateApiCoreDbGetTodoRollup(appDir: appDir, key: key, todoId: todoId);
+
+Future<ReviewItem> dbMarkForReview(
+        {required String appDir,
+        required List<int> key,
+        required String itemKind,
+        required String itemId,
+        PlatformInt64? firstReviewAtMs}) =>
+    RustLib.instance.api.crateApiCoreDbMarkForReview(
+        appDir: appDir,
+        key: key,
+        itemKind: itemKind,
+        itemId: itemId,
+        firstReviewAtMs: firstReviewAtMs);
+
+Future<void> dbUnmarkForReview(
+        {required String appDir,
+        required List<int> key,
+        required String itemKind,
+        required String itemId}) =>
+    RustLib.instance.api.crateApiCoreDbUnmarkForReview(
+        appDir: appDir, key: key, itemKind: itemKind, itemId: itemId);
+
+Future<List<ReviewItem>> dbListDueReviews(
+        {required String appDir,
+        required List<int> key,
+        required PlatformInt64 dueBeforeMs,
+        required int limit}) =>
+    RustLib.instance.api.crateApiCoreDbListDueReviews(
+        appDir: appDir, key: key, dueBeforeMs: dueBeforeMs, limit: limit);
+
+Future<ReviewItem> dbRecordReview(
+        {required String appDir,
+        required List<int> key,
+        required String itemKind,
+        required String itemId,
+        required PlatformInt64 grade}) =>
+    RustLib.instance.api.crateApiCoreDbRecordReview(
+        appDir: appDir,
+        key: key,
+        itemKind: itemKind,
+        itemId: itemId,
+        grade: grade);
+
+Future<Int64List> dbPreviewReviewIntervals(
+        {required String appDir,
+        required String itemKind,
+        required String itemId}) =>
+    RustLib.instance.api.crateApiCoreDbPreviewReviewIntervals(
+        appDir: appDir, itemKind: itemKind, itemId: itemId);
+
+Future<List<ReviewActivity>> dbListReviewActivities(
+        {required String appDir,
+        required String itemKind,
+        required String itemId}) =>
+    RustLib.instance.api.crateApiCoreDbListReviewActivities(
+        appDir: appDir, itemKind: itemKind, itemId: itemId);
+
+Future<Int64List> dbPreviewTodoRecurrence(
+        {required String ruleJson,
+        required PlatformInt64 startAtMs,
+        required int limit}) =>
+    RustLib.instance.api.crateApiCoreDbPreviewTodoRecurrence(
+        ruleJson: ruleJson, startAtMs: startAtMs, limit: limit);
+
 Future<BigInt> dbDeleteTodoAndAssociatedMessages(
         {required String appDir,
         required List<int> key,
@@ -290,6 +433,100 @@ Future<List<Event>> dbListEvents(
         {required String appDir, required List<int> key}) =>
     RustLib.instance.api.crateApiCoreDbListEvents(appDir: appDir, key: key);
 
+Future<void> dbSetEventRecurrenceRule(
+        {required String appDir,
+        required List<int> key,
+        required String eventId,
+        String? ruleJson}) =>
+    RustLib.instance.api.crateApiCoreDbSetEventRecurrenceRule(
+        appDir: appDir, key: key, eventId: eventId, ruleJson: ruleJson);
+
+Future<String?> dbGetEventRecurrenceRuleJson(
+        {required String appDir, required String eventId}) =>
+    RustLib.instance.api.crateApiCoreDbGetEventRecurrenceRuleJson(
+        appDir: appDir, eventId: eventId);
+
+Future<void> dbCancelEventOccurrence(
+        {required String appDir,
+        required List<int> key,
+        required String eventId,
+        required PlatformInt64 occurrenceStartMs}) =>
+    RustLib.instance.api.crateApiCoreDbCancelEventOccurrence(
+        appDir: appDir,
+        key: key,
+        eventId: eventId,
+        occurrenceStartMs: occurrenceStartMs);
+
+Future<void> dbMoveEventOccurrence(
+        {required String appDir,
+        required List<int> key,
+        required String eventId,
+        required PlatformInt64 occurrenceStartMs,
+        required PlatformInt64 startAtMs,
+        required PlatformInt64 endAtMs,
+        String? title}) =>
+    RustLib.instance.api.crateApiCoreDbMoveEventOccurrence(
+        appDir: appDir,
+        key: key,
+        eventId: eventId,
+        occurrenceStartMs: occurrenceStartMs,
+        startAtMs: startAtMs,
+        endAtMs: endAtMs,
+        title: title);
+
+Future<void> dbRestoreEventOccurrence(
+        {required String appDir,
+        required List<int> key,
+        required String eventId,
+        required PlatformInt64 occurrenceStartMs}) =>
+    RustLib.instance.api.crateApiCoreDbRestoreEventOccurrence(
+        appDir: appDir,
+        key: key,
+        eventId: eventId,
+        occurrenceStartMs: occurrenceStartMs);
+
+Future<List<EventOccurrence>> dbListEventOccurrencesInRange(
+        {required String appDir,
+        required List<int> key,
+        required PlatformInt64 startAtMsInclusive,
+        required PlatformInt64 endAtMsExclusive}) =>
+    RustLib.instance.api.crateApiCoreDbListEventOccurrencesInRange(
+        appDir: appDir,
+        key: key,
+        startAtMsInclusive: startAtMsInclusive,
+        endAtMsExclusive: endAtMsExclusive);
+
+Future<AgendaPage> dbAgendaRange(
+        {required String appDir,
+        required List<int> key,
+        required PlatformInt64 startAtMsInclusive,
+        required PlatformInt64 endAtMsExclusive,
+        required String tz,
+        String? cursor,
+        required int limit}) =>
+    RustLib.instance.api.crateApiCoreDbAgendaRange(
+        appDir: appDir,
+        key: key,
+        startAtMsInclusive: startAtMsInclusive,
+        endAtMsExclusive: endAtMsExclusive,
+        tz: tz,
+        cursor: cursor,
+        limit: limit);
+
+Future<bool> dbGetAskAiQueryRewriteEnabled({required String appDir}) =>
+    RustLib.instance.api
+        .crateApiCoreDbGetAskAiQueryRewriteEnabled(appDir: appDir);
+
+Future<void> dbSetAskAiQueryRewriteEnabled(
+        {required String appDir, required bool enabled}) =>
+    RustLib.instance.api.crateApiCoreDbSetAskAiQueryRewriteEnabled(
+        appDir: appDir, enabled: enabled);
+
+Future<List<MessageCitation>> dbListMessageCitations(
+        {required String appDir, required String messageId}) =>
+    RustLib.instance.api.crateApiCoreDbListMessageCitations(
+        appDir: appDir, messageId: messageId);
+
 Future<void> dbEditMessage(
         {required String appDir,
         required List<int> key,
@@ -548,6 +785,19 @@ Future<void> dbMarkSemanticParseJobFailed(
         lastError: lastError,
         nowMs: nowMs);
 
+/// Why the job last failed (`semantic_parse::failure_reason`), if it has.
+Future<String?> dbGetSemanticParseJobFailureReason(
+        {required String appDir,
+        required List<int> key,
+        required String messageId}) =>
+    RustLib.instance.api.crateApiCoreDbGetSemanticParseJobFailureReason(
+        appDir: appDir, key: key, messageId: messageId);
+
+Future<List<SemanticParseFailureReasonCount>> dbCountSemanticParseFailureReasons(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api.crateApiCoreDbCountSemanticParseFailureReasons(
+        appDir: appDir, key: key);
+
 Future<void> dbMarkSemanticParseJobRetry(
         {required String appDir,
         required List<int> key,
@@ -753,6 +1003,18 @@ Future<void> dbDeleteLlmProfile(
     RustLib.instance.api.crateApiCoreDbDeleteLlmProfile(
         appDir: appDir, key: key, profileId: profileId);
 
+Future<List<String>> dbListLlmFallbackChain(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api
+        .crateApiCoreDbListLlmFallbackChain(appDir: appDir, key: key);
+
+Future<void> dbSetLlmFallbackChain(
+        {required String appDir,
+        required List<int> key,
+        required List<String> profileIds}) =>
+    RustLib.instance.api.crateApiCoreDbSetLlmFallbackChain(
+        appDir: appDir, key: key, profileIds: profileIds);
+
 Future<EmbeddingProfile> dbCreateEmbeddingProfile(
         {required String appDir,
         required List<int> key,
@@ -926,6 +1188,17 @@ Future<bool> dbSetActiveEmbeddingModelName(
     RustLib.instance.api.crateApiCoreDbSetActiveEmbeddingModelName(
         appDir: appDir, key: key, modelName: modelName);
 
+/// Whether the optional local reranker model is downloaded. Ask AI uses it
+/// automatically once installed.
+Future<bool> embeddingIsRerankerInstalled({required String appDir}) =>
+    RustLib.instance.api
+        .crateApiCoreEmbeddingIsRerankerInstalled(appDir: appDir);
+
+/// Downloads the local reranker model. Errors on platforms without local
+/// ONNX support.
+Future<void> embeddingInstallReranker({required String appDir}) =>
+    RustLib.instance.api.crateApiCoreEmbeddingInstallReranker(appDir: appDir);
+
 Future<void> dbRecordLlmUsageDaily(
         {required String appDir,
         required List<int> key,
@@ -958,6 +1231,63 @@ Future<List<LlmUsageAggregate>> dbSumLlmUsageDailyByPurpose(
         startDay: startDay,
         endDay: endDay);
 
+Future<List<LlmModelPrice>> dbListLlmModelPrices(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api
+        .crateApiCoreDbListLlmModelPrices(appDir: appDir, key: key);
+
+Future<LlmModelPrice> dbUpsertLlmModelPrice(
+        {required String appDir,
+        required List<int> key,
+        required String providerType,
+        required String modelName,
+        required double inputUsdPerMillionTokens,
+        required double outputUsdPerMillionTokens}) =>
+    RustLib.instance.api.crateApiCoreDbUpsertLlmModelPrice(
+        appDir: appDir,
+        key: key,
+        providerType: providerType,
+        modelName: modelName,
+        inputUsdPerMillionTokens: inputUsdPerMillionTokens,
+        outputUsdPerMillionTokens: outputUsdPerMillionTokens);
+
+Future<void> dbDeleteLlmModelPrice(
+        {required String appDir,
+        required List<int> key,
+        required String providerType,
+        required String modelName}) =>
+    RustLib.instance.api.crateApiCoreDbDeleteLlmModelPrice(
+        appDir: appDir,
+        key: key,
+        providerType: providerType,
+        modelName: modelName);
+
+/// Usage and cost per profile and purpose for `month` (`YYYY-MM`).
+Future<List<LlmMonthlyCost>> dbSumLlmCostMonthly(
+        {required String appDir,
+        required List<int> key,
+        required String month}) =>
+    RustLib.instance.api.crateApiCoreDbSumLlmCostMonthly(
+        appDir: appDir, key: key, month: month);
+
+Future<List<LlmSpendingLimit>> dbListLlmSpendingLimits(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api
+        .crateApiCoreDbListLlmSpendingLimits(appDir: appDir, key: key);
+
+/// Sets the monthly limit for `profile_id` (empty for all profiles together);
+/// `None` removes it.
+Future<void> dbSetLlmSpendingLimit(
+        {required String appDir,
+        required List<int> key,
+        required String profileId,
+        PlatformInt64? monthlyLimitUsdMicros}) =>
+    RustLib.instance.api.crateApiCoreDbSetLlmSpendingLimit(
+        appDir: appDir,
+        key: key,
+        profileId: profileId,
+        monthlyLimitUsdMicros: monthlyLimitUsdMicros);
+
 Future<String> aiSemanticParseMessageAction(
         {required String appDir,
         required List<int> key,
@@ -1045,7 +1375,8 @@ Stream<String> ragAskAiStream(
         required String question,
         required int topK,
         required bool thisThreadOnly,
-        required String localDay}) =>
+        required String localDay,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStream(
         appDir: appDir,
         key: key,
@@ -1053,7 +1384,8 @@ Stream<String> ragAskAiStream(
         question: question,
         topK: topK,
         thisThreadOnly: thisThreadOnly,
-        localDay: localDay);
+        localDay: localDay,
+        tz: tz);
 
 Stream<String> ragAskAiStreamTimeWindow(
         {required String appDir,
@@ -1064,7 +1396,8 @@ Stream<String> ragAskAiStreamTimeWindow(
         required bool thisThreadOnly,
         required PlatformInt64 timeStartMs,
         required PlatformInt64 timeEndMs,
-        required String localDay}) =>
+        required String localDay,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamTimeWindow(
         appDir: appDir,
         key: key,
@@ -1074,7 +1407,8 @@ Stream<String> ragAskAiStreamTimeWindow(
         thisThreadOnly: thisThreadOnly,
         timeStartMs: timeStartMs,
         timeEndMs: timeEndMs,
-        localDay: localDay);
+        localDay: localDay,
+        tz: tz);
 
 Stream<String> ragAskAiStreamWithBrokEmbeddings(
         {required String appDir,
@@ -1083,7 +1417,8 @@ Stream<String> ragAskAiStreamWithBrokEmbeddings(
         required String question,
         required int topK,
         required bool thisThreadOnly,
-        required String localDay}) =>
+        required String localDay,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamWithBrokEmbeddings(
         appDir: appDir,
         key: key,
@@ -1091,7 +1426,8 @@ Stream<String> ragAskAiStreamWithBrokEmbeddings(
         question: question,
         topK: topK,
         thisThreadOnly: thisThreadOnly,
-        localDay: localDay);
+        localDay: localDay,
+        tz: tz);
 
 Stream<String> ragAskAiStreamWithBrokEmbeddingsTimeWindow(
         {required String appDir,
@@ -1102,7 +1438,8 @@ Stream<String> ragAskAiStreamWithBrokEmbeddingsTimeWindow(
         required bool thisThreadOnly,
         required PlatformInt64 timeStartMs,
         required PlatformInt64 timeEndMs,
-        required String localDay}) =>
+        required String localDay,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindow(
         appDir: appDir,
         key: key,
@@ -1112,7 +1449,8 @@ Stream<String> ragAskAiStreamWithBrokEmbeddingsTimeWindow(
         thisThreadOnly: thisThreadOnly,
         timeStartMs: timeStartMs,
         timeEndMs: timeEndMs,
-        localDay: localDay);
+        localDay: localDay,
+        tz: tz);
 
 Stream<String> ragAskAiStreamCloudGateway(
         {required String appDir,
@@ -1123,7 +1461,8 @@ Stream<String> ragAskAiStreamCloudGateway(
         required bool thisThreadOnly,
         required String gatewayBaseUrl,
         required String firebaseIdToken,
-        required String modelName}) =>
+        required String modelName,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamCloudGateway(
         appDir: appDir,
         key: key,
@@ -1133,6 +1472,39 @@ Stream<String> ragAskAiStreamCloudGateway(
         thisThreadOnly: thisThreadOnly,
         gatewayBaseUrl: gatewayBaseUrl,
         firebaseIdToken: firebaseIdToken,
+        modelName: modelName,
+        tz: tz);
+
+/// Ask AI in agent mode: the model may search memories, list todos and
+/// events, create or update todos and read attachments before answering.
+Stream<String> ragAskAiAgentStream(
+        {required String appDir,
+        required List<int> key,
+        required String conversationId,
+        required String question,
+        required String localDay}) =>
+    RustLib.instance.api.crateApiCoreRagAskAiAgentStream(
+        appDir: appDir,
+        key: key,
+        conversationId: conversationId,
+        question: question,
+        localDay: localDay);
+
+Stream<String> ragAskAiAgentStreamCloudGateway(
+        {required String appDir,
+        required List<int> key,
+        required String conversationId,
+        required String question,
+        required String gatewayBaseUrl,
+        required String firebaseIdToken,
+        required String modelName}) =>
+    RustLib.instance.api.crateApiCoreRagAskAiAgentStreamCloudGateway(
+        appDir: appDir,
+        key: key,
+        conversationId: conversationId,
+        question: question,
+        gatewayBaseUrl: gatewayBaseUrl,
+        firebaseIdToken: firebaseIdToken,
         modelName: modelName);
 
 Stream<String> ragAskAiStreamCloudGatewayTimeWindow(
@@ -1146,7 +1518,8 @@ Stream<String> ragAskAiStreamCloudGatewayTimeWindow(
         required PlatformInt64 timeEndMs,
         required String gatewayBaseUrl,
         required String firebaseIdToken,
-        required String modelName}) =>
+        required String modelName,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamCloudGatewayTimeWindow(
         appDir: appDir,
         key: key,
@@ -1158,7 +1531,8 @@ Stream<String> ragAskAiStreamCloudGatewayTimeWindow(
         timeEndMs: timeEndMs,
         gatewayBaseUrl: gatewayBaseUrl,
         firebaseIdToken: firebaseIdToken,
-        modelName: modelName);
+        modelName: modelName,
+        tz: tz);
 
 Stream<String> ragAskAiStreamCloudGatewayWithEmbeddings(
         {required String appDir,
@@ -1170,7 +1544,8 @@ Stream<String> ragAskAiStreamCloudGatewayWithEmbeddings(
         required String gatewayBaseUrl,
         required String firebaseIdToken,
         required String modelName,
-        required String embeddingsModelName}) =>
+        required String embeddingsModelName,
+        String? tz}) =>
     RustLib.instance.api.crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddings(
         appDir: appDir,
         key: key,
@@ -1181,7 +1556,8 @@ Stream<String> ragAskAiStreamCloudGatewayWithEmbeddings(
         gatewayBaseUrl: gatewayBaseUrl,
         firebaseIdToken: firebaseIdToken,
         modelName: modelName,
-        embeddingsModelName: embeddingsModelName);
+        embeddingsModelName: embeddingsModelName,
+        tz: tz);
 
 Stream<String> ragAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
         {required String appDir,
@@ -1195,7 +1571,8 @@ Stream<String> ragAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
         required String gatewayBaseUrl,
         required String firebaseIdToken,
         required String modelName,
-        required String embeddingsModelName}) =>
+        required String embeddingsModelName,
+        String? tz}) =>
     RustLib.instance.api
         .crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
             appDir: appDir,
@@ -1209,7 +1586,8 @@ Stream<String> ragAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
             gatewayBaseUrl: gatewayBaseUrl,
             firebaseIdToken: firebaseIdToken,
             modelName: modelName,
-            embeddingsModelName: embeddingsModelName);
+            embeddingsModelName: embeddingsModelName,
+            tz: tz);
 
 Future<Uint8List> syncDeriveKey({required String passphrase}) =>
     RustLib.instance.api.crateApiCoreSyncDeriveKey(passphrase: passphrase);
@@ -1325,6 +1703,23 @@ Future<void> syncWebdavClearRemoteRoot(
         password: password,
         remoteRoot: remoteRoot);
 
+Future<CalDavSyncReport> syncCaldav(
+        {required String appDir,
+        required List<int> key,
+        required String collectionUrl,
+        String? username,
+        String? password,
+        String? defaultTz,
+        required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiCoreSyncCaldav(
+        appDir: appDir,
+        key: key,
+        collectionUrl: collectionUrl,
+        username: username,
+        password: password,
+        defaultTz: defaultTz,
+        nowMs: nowMs);
+
 Future<void> syncLocaldirTestConnection(
         {required String localDir, required String remoteRoot}) =>
     RustLib.instance.api.crateApiCoreSyncLocaldirTestConnection(
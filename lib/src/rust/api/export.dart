@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../export/ics.dart';
+import '../export/jsonl.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `key_from_bytes`
+
+Future<JsonlExportSummary> dbExportVaultJsonl(
+        {required String appDir,
+        required List<int> key,
+        required String outDir,
+        required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiExportDbExportVaultJsonl(
+        appDir: appDir, key: key, outDir: outDir, nowMs: nowMs);
+
+Future<JsonlExportVerification> verifyVaultJsonlExport(
+        {required String exportDir}) =>
+    RustLib.instance.api
+        .crateApiExportVerifyVaultJsonlExport(exportDir: exportDir);
+
+Future<IcsExportSummary> dbExportIcs(
+        {required String appDir,
+        required List<int> key,
+        required String outPath,
+        required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiExportDbExportIcs(
+        appDir: appDir, key: key, outPath: outPath, nowMs: nowMs);
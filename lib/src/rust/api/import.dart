@@ -0,0 +1,66 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../import.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `key_from_bytes`
+
+Future<ImportReport> dbImportMarkdownFolder(
+        {required String appDir,
+        required List<int> key,
+        required String rootDir,
+        required String lang,
+        String? defaultTz}) =>
+    RustLib.instance.api.crateApiImportDbImportMarkdownFolder(
+        appDir: appDir,
+        key: key,
+        rootDir: rootDir,
+        lang: lang,
+        defaultTz: defaultTz);
+
+Future<ImportReport> dbImportTelegramExport(
+        {required String appDir,
+        required List<int> key,
+        required String jsonPath,
+        required String lang,
+        String? defaultTz,
+        required bool preview}) =>
+    RustLib.instance.api.crateApiImportDbImportTelegramExport(
+        appDir: appDir,
+        key: key,
+        jsonPath: jsonPath,
+        lang: lang,
+        defaultTz: defaultTz,
+        preview: preview);
+
+Future<ImportReport> dbImportWhatsappExport(
+        {required String appDir,
+        required List<int> key,
+        required String txtPath,
+        required String lang,
+        String? defaultTz,
+        required bool preview}) =>
+    RustLib.instance.api.crateApiImportDbImportWhatsappExport(
+        appDir: appDir,
+        key: key,
+        txtPath: txtPath,
+        lang: lang,
+        defaultTz: defaultTz,
+        preview: preview);
+
+Future<ImportReport> dbImportIcs(
+        {required String appDir,
+        required List<int> key,
+        required String icsPath,
+        String? defaultTz,
+        required bool preview}) =>
+    RustLib.instance.api.crateApiImportDbImportIcs(
+        appDir: appDir,
+        key: key,
+        icsPath: icsPath,
+        defaultTz: defaultTz,
+        preview: preview);
@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../llm/local_gguf.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<List<LocalGgufModelInfo>> localLlmListModels({required String appDir}) =>
+    RustLib.instance.api.crateApiLocalLlmLocalLlmListModels(appDir: appDir);
+
+Future<LocalGgufModelInfo> localLlmInstallModel(
+        {required String appDir,
+        required String sourcePath,
+        String? expectedSha256}) =>
+    RustLib.instance.api.crateApiLocalLlmLocalLlmInstallModel(
+        appDir: appDir, sourcePath: sourcePath, expectedSha256: expectedSha256);
+
+Future<bool> localLlmDeleteModel(
+        {required String appDir, required String fileName}) =>
+    RustLib.instance.api.crateApiLocalLlmLocalLlmDeleteModel(
+        appDir: appDir, fileName: fileName);
+
+Future<String> localLlmModelSha256(
+        {required String appDir, required String fileName}) =>
+    RustLib.instance.api.crateApiLocalLlmLocalLlmModelSha256(
+        appDir: appDir, fileName: fileName);
+
+/// Loads the llama.cpp runtime installed under `app_dir` when none is
+/// loaded yet.
+Future<bool> localLlmRuntimeAvailable({required String appDir}) =>
+    RustLib.instance.api
+        .crateApiLocalLlmLocalLlmRuntimeAvailable(appDir: appDir);
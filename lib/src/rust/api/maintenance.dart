@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../db.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `key_from_bytes`
+
+Future<IntegrityReport> dbCheckIntegrity(
+        {required String appDir,
+        required List<int> key,
+        required bool fix,
+        required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiMaintenanceDbCheckIntegrity(
+        appDir: appDir, key: key, fix: fix, nowMs: nowMs);
+
+Future<StorageUsageReport> dbStorageUsage(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api
+        .crateApiMaintenanceDbStorageUsage(appDir: appDir, key: key);
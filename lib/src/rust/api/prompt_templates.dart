@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../db.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `key_from_bytes`
+
+Future<String> promptTemplateDefault({required String purpose}) =>
+    RustLib.instance.api
+        .crateApiPromptTemplatesPromptTemplateDefault(purpose: purpose);
+
+Future<void> promptTemplateValidate(
+        {required String purpose, required String body}) =>
+    RustLib.instance.api.crateApiPromptTemplatesPromptTemplateValidate(
+        purpose: purpose, body: body);
+
+Future<List<PromptTemplate>> dbListPromptTemplates(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api
+        .crateApiPromptTemplatesDbListPromptTemplates(appDir: appDir, key: key);
+
+Future<PromptTemplate> dbUpsertPromptTemplate(
+        {required String appDir,
+        required List<int> key,
+        String? templateId,
+        required String name,
+        required String purpose,
+        required String body}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbUpsertPromptTemplate(
+        appDir: appDir,
+        key: key,
+        templateId: templateId,
+        name: name,
+        purpose: purpose,
+        body: body);
+
+Future<PromptTemplate> dbResetPromptTemplateToDefault(
+        {required String appDir,
+        required List<int> key,
+        required String templateId}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbResetPromptTemplateToDefault(
+        appDir: appDir, key: key, templateId: templateId);
+
+Future<void> dbDeletePromptTemplate(
+        {required String appDir,
+        required List<int> key,
+        required String templateId}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbDeletePromptTemplate(
+        appDir: appDir, key: key, templateId: templateId);
+
+Future<List<PromptTemplateSelection>> dbListPromptTemplateSelections(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbListPromptTemplateSelections(
+        appDir: appDir, key: key);
+
+Future<void> dbSetPromptTemplateSelection(
+        {required String appDir,
+        required List<int> key,
+        required String purpose,
+        required String profileId,
+        String? templateId}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbSetPromptTemplateSelection(
+        appDir: appDir,
+        key: key,
+        purpose: purpose,
+        profileId: profileId,
+        templateId: templateId);
+
+Future<void> dbResetPromptTemplateSelections(
+        {required String appDir,
+        required List<int> key,
+        required String purpose}) =>
+    RustLib.instance.api.crateApiPromptTemplatesDbResetPromptTemplateSelections(
+        appDir: appDir, key: key, purpose: purpose);
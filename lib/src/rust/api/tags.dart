@@ -20,6 +20,25 @@ Future<Tag> dbUpsertTag(
     RustLib.instance.api
         .crateApiTagsDbUpsertTag(appDir: appDir, key: key, name: name);
 
+Future<Tag> dbUpsertTagPath(
+        {required String appDir,
+        required List<int> key,
+        required String path}) =>
+    RustLib.instance.api
+        .crateApiTagsDbUpsertTagPath(appDir: appDir, key: key, path: path);
+
+Future<Tag> dbSetTagParent(
+        {required String appDir,
+        required List<int> key,
+        required String tagId,
+        String? parentId}) =>
+    RustLib.instance.api.crateApiTagsDbSetTagParent(
+        appDir: appDir, key: key, tagId: tagId, parentId: parentId);
+
+Future<List<TagNode>> dbListTagTree(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api.crateApiTagsDbListTagTree(appDir: appDir, key: key);
+
 Future<List<Tag>> dbListMessageTags(
         {required String appDir,
         required List<int> key,
@@ -83,3 +102,41 @@ Future<List<String>> dbListMessageIdsByTagIds(
         key: key,
         conversationId: conversationId,
         tagIds: tagIds);
+
+Future<List<TagRule>> dbListTagRules(
+        {required String appDir, required List<int> key}) =>
+    RustLib.instance.api.crateApiTagsDbListTagRules(appDir: appDir, key: key);
+
+Future<TagRule> dbUpsertTagRule(
+        {required String appDir,
+        required List<int> key,
+        String? ruleId,
+        required String name,
+        required String kind,
+        required String pattern,
+        required String tagId,
+        required bool enabled}) =>
+    RustLib.instance.api.crateApiTagsDbUpsertTagRule(
+        appDir: appDir,
+        key: key,
+        ruleId: ruleId,
+        name: name,
+        kind: kind,
+        pattern: pattern,
+        tagId: tagId,
+        enabled: enabled);
+
+Future<void> dbDeleteTagRule(
+        {required String appDir,
+        required List<int> key,
+        required String ruleId}) =>
+    RustLib.instance.api
+        .crateApiTagsDbDeleteTagRule(appDir: appDir, key: key, ruleId: ruleId);
+
+Future<int> dbBackfillTagRules(
+        {required String appDir,
+        required List<int> key,
+        String? ruleId,
+        required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiTagsDbBackfillTagRules(
+        appDir: appDir, key: key, ruleId: ruleId, nowMs: nowMs);
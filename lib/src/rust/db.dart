@@ -6,6 +6,114 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+/// Items whose `at_ms` falls on one local calendar day.
+class AgendaDay {
+  /// Local date, `YYYY-MM-DD`.
+  final String date;
+  final PlatformInt64 dayStartMs;
+  final List<AgendaItem> items;
+
+  const AgendaDay({
+    required this.date,
+    required this.dayStartMs,
+    required this.items,
+  });
+
+  @override
+  int get hashCode => date.hashCode ^ dayStartMs.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgendaDay &&
+          runtimeType == other.runtimeType &&
+          date == other.date &&
+          dayStartMs == other.dayStartMs &&
+          items == other.items;
+}
+
+/// One row of the agenda. `at_ms` is the event start, due time, review time
+/// or completion time, depending on `kind`.
+class AgendaItem {
+  final String kind;
+  /// Unique within `kind`; event occurrences use `<event_id>@<occurrence_start_ms>`.
+  final String itemId;
+  /// Todo, event or review target the item belongs to.
+  final String sourceId;
+  final String title;
+  final PlatformInt64 atMs;
+  final PlatformInt64? endAtMs;
+  /// Todo status for todo and completed items.
+  final String? status;
+  final String? tz;
+  final bool isRecurring;
+
+  const AgendaItem({
+    required this.kind,
+    required this.itemId,
+    required this.sourceId,
+    required this.title,
+    required this.atMs,
+    this.endAtMs,
+    this.status,
+    this.tz,
+    required this.isRecurring,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      itemId.hashCode ^
+      sourceId.hashCode ^
+      title.hashCode ^
+      atMs.hashCode ^
+      endAtMs.hashCode ^
+      status.hashCode ^
+      tz.hashCode ^
+      isRecurring.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgendaItem &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          itemId == other.itemId &&
+          sourceId == other.sourceId &&
+          title == other.title &&
+          atMs == other.atMs &&
+          endAtMs == other.endAtMs &&
+          status == other.status &&
+          tz == other.tz &&
+          isRecurring == other.isRecurring;
+}
+
+class AgendaPage {
+  /// Open todos and reviews due before the range; only on the first page.
+  final List<AgendaItem> overdue;
+  final List<AgendaDay> days;
+  /// Pass back as `cursor` to continue after the last returned item.
+  final String? nextCursor;
+
+  const AgendaPage({
+    required this.overdue,
+    required this.days,
+    this.nextCursor,
+  });
+
+  @override
+  int get hashCode => overdue.hashCode ^ days.hashCode ^ nextCursor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgendaPage &&
+          runtimeType == other.runtimeType &&
+          overdue == other.overdue &&
+          days == other.days &&
+          nextCursor == other.nextCursor;
+}
+
 class Attachment {
   final String sha256;
   final String mimeType;
@@ -541,6 +649,183 @@ class Event {
           updatedAtMs == other.updatedAtMs;
 }
 
+/// One event instance in a calendar range: a plain event, or one occurrence
+/// of a recurring event with its exception applied.
+class EventOccurrence {
+  final String eventId;
+  /// Start the rule gives this occurrence, which identifies it even after it
+  /// was moved. Equals `start_at_ms` for plain events.
+  final PlatformInt64 occurrenceStartMs;
+  final String title;
+  final PlatformInt64 startAtMs;
+  final PlatformInt64 endAtMs;
+  final String tz;
+  final bool isRecurring;
+  final bool isMoved;
+
+  const EventOccurrence({
+    required this.eventId,
+    required this.occurrenceStartMs,
+    required this.title,
+    required this.startAtMs,
+    required this.endAtMs,
+    required this.tz,
+    required this.isRecurring,
+    required this.isMoved,
+  });
+
+  @override
+  int get hashCode =>
+      eventId.hashCode ^
+      occurrenceStartMs.hashCode ^
+      title.hashCode ^
+      startAtMs.hashCode ^
+      endAtMs.hashCode ^
+      tz.hashCode ^
+      isRecurring.hashCode ^
+      isMoved.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventOccurrence &&
+          runtimeType == other.runtimeType &&
+          eventId == other.eventId &&
+          occurrenceStartMs == other.occurrenceStartMs &&
+          title == other.title &&
+          startAtMs == other.startAtMs &&
+          endAtMs == other.endAtMs &&
+          tz == other.tz &&
+          isRecurring == other.isRecurring &&
+          isMoved == other.isMoved;
+}
+
+class IntegrityIssue {
+  final String kind;
+  final String subject;
+  final bool fixed;
+
+  const IntegrityIssue({
+    required this.kind,
+    required this.subject,
+    required this.fixed,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ subject.hashCode ^ fixed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityIssue &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          subject == other.subject &&
+          fixed == other.fixed;
+}
+
+class IntegrityReport {
+  final List<IntegrityIssue> issues;
+  final int fixedCount;
+
+  const IntegrityReport({
+    required this.issues,
+    required this.fixedCount,
+  });
+
+  @override
+  int get hashCode => issues.hashCode ^ fixedCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityReport &&
+          runtimeType == other.runtimeType &&
+          issues == other.issues &&
+          fixedCount == other.fixedCount;
+}
+
+/// Price of one model, in USD per million tokens. Synced, keyed by provider
+/// type and model name so every device prices the same model the same way.
+class LlmModelPrice {
+  final String providerType;
+  final String modelName;
+  final double inputUsdPerMillionTokens;
+  final double outputUsdPerMillionTokens;
+  final PlatformInt64 updatedAtMs;
+
+  const LlmModelPrice({
+    required this.providerType,
+    required this.modelName,
+    required this.inputUsdPerMillionTokens,
+    required this.outputUsdPerMillionTokens,
+    required this.updatedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      providerType.hashCode ^
+      modelName.hashCode ^
+      inputUsdPerMillionTokens.hashCode ^
+      outputUsdPerMillionTokens.hashCode ^
+      updatedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LlmModelPrice &&
+          runtimeType == other.runtimeType &&
+          providerType == other.providerType &&
+          modelName == other.modelName &&
+          inputUsdPerMillionTokens == other.inputUsdPerMillionTokens &&
+          outputUsdPerMillionTokens == other.outputUsdPerMillionTokens &&
+          updatedAtMs == other.updatedAtMs;
+}
+
+/// Usage and cost of one profile for one purpose in one month.
+class LlmMonthlyCost {
+  final String profileId;
+  final String purpose;
+  final PlatformInt64 requests;
+  final PlatformInt64 requestsWithUsage;
+  final PlatformInt64 inputTokens;
+  final PlatformInt64 outputTokens;
+  final PlatformInt64 costUsdMicros;
+
+  const LlmMonthlyCost({
+    required this.profileId,
+    required this.purpose,
+    required this.requests,
+    required this.requestsWithUsage,
+    required this.inputTokens,
+    required this.outputTokens,
+    required this.costUsdMicros,
+  });
+
+  @override
+  int get hashCode =>
+      profileId.hashCode ^
+      purpose.hashCode ^
+      requests.hashCode ^
+      requestsWithUsage.hashCode ^
+      inputTokens.hashCode ^
+      outputTokens.hashCode ^
+      costUsdMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LlmMonthlyCost &&
+          runtimeType == other.runtimeType &&
+          profileId == other.profileId &&
+          purpose == other.purpose &&
+          requests == other.requests &&
+          requestsWithUsage == other.requestsWithUsage &&
+          inputTokens == other.inputTokens &&
+          outputTokens == other.outputTokens &&
+          costUsdMicros == other.costUsdMicros;
+}
+
 class LlmProfile {
   final String id;
   final String name;
@@ -588,6 +873,29 @@ class LlmProfile {
           updatedAtMs == other.updatedAtMs;
 }
 
+/// A monthly spending limit. `profile_id` is empty for the limit on all
+/// profiles together.
+class LlmSpendingLimit {
+  final String profileId;
+  final PlatformInt64 monthlyLimitUsdMicros;
+
+  const LlmSpendingLimit({
+    required this.profileId,
+    required this.monthlyLimitUsdMicros,
+  });
+
+  @override
+  int get hashCode => profileId.hashCode ^ monthlyLimitUsdMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LlmSpendingLimit &&
+          runtimeType == other.runtimeType &&
+          profileId == other.profileId &&
+          monthlyLimitUsdMicros == other.monthlyLimitUsdMicros;
+}
+
 class LlmUsageAggregate {
   final String purpose;
   final PlatformInt64 requests;
@@ -705,6 +1013,226 @@ class Message {
           isMemory == other.isMemory;
 }
 
+/// Maps an inline `[marker]` in an assistant answer to the record it quotes.
+class MessageCitation {
+  final int marker;
+  /// One of the `CITATION_SOURCE_*` kinds.
+  final String sourceKind;
+  final String sourceId;
+
+  const MessageCitation({
+    required this.marker,
+    required this.sourceKind,
+    required this.sourceId,
+  });
+
+  @override
+  int get hashCode => marker.hashCode ^ sourceKind.hashCode ^ sourceId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageCitation &&
+          runtimeType == other.runtimeType &&
+          marker == other.marker &&
+          sourceKind == other.sourceKind &&
+          sourceId == other.sourceId;
+}
+
+/// A user-edited prompt for one purpose (`ask_ai`, `semantic_parse`,
+/// `media_annotation`). See `crate::prompt_templates` for its variables.
+class PromptTemplate {
+  final String id;
+  final String name;
+  final String purpose;
+  final String body;
+  final PlatformInt64 createdAtMs;
+  final PlatformInt64 updatedAtMs;
+
+  const PromptTemplate({
+    required this.id,
+    required this.name,
+    required this.purpose,
+    required this.body,
+    required this.createdAtMs,
+    required this.updatedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      purpose.hashCode ^
+      body.hashCode ^
+      createdAtMs.hashCode ^
+      updatedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PromptTemplate &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          purpose == other.purpose &&
+          body == other.body &&
+          createdAtMs == other.createdAtMs &&
+          updatedAtMs == other.updatedAtMs;
+}
+
+/// Which template a purpose uses. `profile_id` is empty for the selection
+/// that applies to every LLM profile without its own.
+class PromptTemplateSelection {
+  final String purpose;
+  final String profileId;
+  final String templateId;
+
+  const PromptTemplateSelection({
+    required this.purpose,
+    required this.profileId,
+    required this.templateId,
+  });
+
+  @override
+  int get hashCode =>
+      purpose.hashCode ^ profileId.hashCode ^ templateId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PromptTemplateSelection &&
+          runtimeType == other.runtimeType &&
+          purpose == other.purpose &&
+          profileId == other.profileId &&
+          templateId == other.templateId;
+}
+
+/// One graded review, with the schedule it produced.
+class ReviewActivity {
+  final String id;
+  final String itemKind;
+  final String itemId;
+  final PlatformInt64 grade;
+  final double easeFactor;
+  final PlatformInt64 intervalDays;
+  final PlatformInt64 dueAtMs;
+  final PlatformInt64 reviewedAtMs;
+
+  const ReviewActivity({
+    required this.id,
+    required this.itemKind,
+    required this.itemId,
+    required this.grade,
+    required this.easeFactor,
+    required this.intervalDays,
+    required this.dueAtMs,
+    required this.reviewedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      itemKind.hashCode ^
+      itemId.hashCode ^
+      grade.hashCode ^
+      easeFactor.hashCode ^
+      intervalDays.hashCode ^
+      dueAtMs.hashCode ^
+      reviewedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewActivity &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          itemKind == other.itemKind &&
+          itemId == other.itemId &&
+          grade == other.grade &&
+          easeFactor == other.easeFactor &&
+          intervalDays == other.intervalDays &&
+          dueAtMs == other.dueAtMs &&
+          reviewedAtMs == other.reviewedAtMs;
+}
+
+/// A todo or message in the review rotation.
+class ReviewItem {
+  final String itemKind;
+  final String itemId;
+  /// Todo title, or the start of the message.
+  final String title;
+  final double easeFactor;
+  final PlatformInt64 intervalDays;
+  final PlatformInt64 repetitions;
+  final PlatformInt64 lapses;
+  final PlatformInt64 dueAtMs;
+  final PlatformInt64? lastReviewedAtMs;
+  final bool isActive;
+
+  const ReviewItem({
+    required this.itemKind,
+    required this.itemId,
+    required this.title,
+    required this.easeFactor,
+    required this.intervalDays,
+    required this.repetitions,
+    required this.lapses,
+    required this.dueAtMs,
+    this.lastReviewedAtMs,
+    required this.isActive,
+  });
+
+  @override
+  int get hashCode =>
+      itemKind.hashCode ^
+      itemId.hashCode ^
+      title.hashCode ^
+      easeFactor.hashCode ^
+      intervalDays.hashCode ^
+      repetitions.hashCode ^
+      lapses.hashCode ^
+      dueAtMs.hashCode ^
+      lastReviewedAtMs.hashCode ^
+      isActive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewItem &&
+          runtimeType == other.runtimeType &&
+          itemKind == other.itemKind &&
+          itemId == other.itemId &&
+          title == other.title &&
+          easeFactor == other.easeFactor &&
+          intervalDays == other.intervalDays &&
+          repetitions == other.repetitions &&
+          lapses == other.lapses &&
+          dueAtMs == other.dueAtMs &&
+          lastReviewedAtMs == other.lastReviewedAtMs &&
+          isActive == other.isActive;
+}
+
+class SemanticParseFailureReasonCount {
+  final String reason;
+  final PlatformInt64 jobs;
+
+  const SemanticParseFailureReasonCount({
+    required this.reason,
+    required this.jobs,
+  });
+
+  @override
+  int get hashCode => reason.hashCode ^ jobs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SemanticParseFailureReasonCount &&
+          runtimeType == other.runtimeType &&
+          reason == other.reason &&
+          jobs == other.jobs;
+}
+
 class SemanticParseJob {
   final String messageId;
   final String status;
@@ -845,6 +1373,69 @@ class StoragePolicyConfig {
           autoPurgeIncludeImages == other.autoPurgeIncludeImages;
 }
 
+class StorageUsageEntry {
+  final String category;
+  final String label;
+  final PlatformInt64 bytes;
+  final PlatformInt64 count;
+
+  const StorageUsageEntry({
+    required this.category,
+    required this.label,
+    required this.bytes,
+    required this.count,
+  });
+
+  @override
+  int get hashCode =>
+      category.hashCode ^ label.hashCode ^ bytes.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StorageUsageEntry &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          label == other.label &&
+          bytes == other.bytes &&
+          count == other.count;
+}
+
+/// On-disk footprint of an app dir. `categories` add up to `total_bytes`
+/// (vector tables and the oplog are carved out of the database pages);
+/// `by_conversation` and `by_mime_type` are alternative views of attachment
+/// bytes and may overlap each other.
+class StorageUsageReport {
+  final PlatformInt64 totalBytes;
+  final List<StorageUsageEntry> categories;
+  final List<StorageUsageEntry> byConversation;
+  final List<StorageUsageEntry> byMimeType;
+
+  const StorageUsageReport({
+    required this.totalBytes,
+    required this.categories,
+    required this.byConversation,
+    required this.byMimeType,
+  });
+
+  @override
+  int get hashCode =>
+      totalBytes.hashCode ^
+      categories.hashCode ^
+      byConversation.hashCode ^
+      byMimeType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StorageUsageReport &&
+          runtimeType == other.runtimeType &&
+          totalBytes == other.totalBytes &&
+          categories == other.categories &&
+          byConversation == other.byConversation &&
+          byMimeType == other.byMimeType;
+}
+
 class Tag {
   final String id;
   final String name;
@@ -927,6 +1518,85 @@ class TagMergeSuggestion {
           targetUsageCount == other.targetUsageCount;
 }
 
+/// A tag placed in the tag hierarchy; `path` joins ancestor names with `/`.
+class TagNode {
+  final Tag tag;
+  final String? parentId;
+  final String path;
+  final int depth;
+
+  const TagNode({
+    required this.tag,
+    this.parentId,
+    required this.path,
+    required this.depth,
+  });
+
+  @override
+  int get hashCode =>
+      tag.hashCode ^ parentId.hashCode ^ path.hashCode ^ depth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagNode &&
+          runtimeType == other.runtimeType &&
+          tag == other.tag &&
+          parentId == other.parentId &&
+          path == other.path &&
+          depth == other.depth;
+}
+
+/// A user-defined, deterministic tagging rule. `pattern` is interpreted per
+/// `kind`: a case-insensitive phrase, a regex, a mime type (`image/*` allowed),
+/// a conversation id, or a case-insensitive place name fragment.
+class TagRule {
+  final String id;
+  final String name;
+  final String kind;
+  final String pattern;
+  final String tagId;
+  final bool enabled;
+  final PlatformInt64 createdAtMs;
+  final PlatformInt64 updatedAtMs;
+
+  const TagRule({
+    required this.id,
+    required this.name,
+    required this.kind,
+    required this.pattern,
+    required this.tagId,
+    required this.enabled,
+    required this.createdAtMs,
+    required this.updatedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      kind.hashCode ^
+      pattern.hashCode ^
+      tagId.hashCode ^
+      enabled.hashCode ^
+      createdAtMs.hashCode ^
+      updatedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagRule &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          kind == other.kind &&
+          pattern == other.pattern &&
+          tagId == other.tagId &&
+          enabled == other.enabled &&
+          createdAtMs == other.createdAtMs &&
+          updatedAtMs == other.updatedAtMs;
+}
+
 class Todo {
   final String id;
   final String title;
@@ -1028,3 +1698,45 @@ class TodoActivity {
           sourceMessageId == other.sourceMessageId &&
           createdAtMs == other.createdAtMs;
 }
+
+/// Subtask and "blocked by" summary for one todo.
+class TodoRollup {
+  final String todoId;
+  final String? parentId;
+  /// Direct subtasks, not counting dismissed ones.
+  final PlatformInt64 subtasksTotal;
+  final PlatformInt64 subtasksDone;
+  /// Blockers that are neither done nor dismissed.
+  final List<String> openBlockerIds;
+  final bool completeWithSubtasks;
+
+  const TodoRollup({
+    required this.todoId,
+    this.parentId,
+    required this.subtasksTotal,
+    required this.subtasksDone,
+    required this.openBlockerIds,
+    required this.completeWithSubtasks,
+  });
+
+  @override
+  int get hashCode =>
+      todoId.hashCode ^
+      parentId.hashCode ^
+      subtasksTotal.hashCode ^
+      subtasksDone.hashCode ^
+      openBlockerIds.hashCode ^
+      completeWithSubtasks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TodoRollup &&
+          runtimeType == other.runtimeType &&
+          todoId == other.todoId &&
+          parentId == other.parentId &&
+          subtasksTotal == other.subtasksTotal &&
+          subtasksDone == other.subtasksDone &&
+          openBlockerIds == other.openBlockerIds &&
+          completeWithSubtasks == other.completeWithSubtasks;
+}
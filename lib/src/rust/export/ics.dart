@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class IcsExportSummary {
+  final BigInt events;
+  final BigInt todos;
+
+  const IcsExportSummary({
+    required this.events,
+    required this.todos,
+  });
+
+  @override
+  int get hashCode => events.hashCode ^ todos.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IcsExportSummary &&
+          runtimeType == other.runtimeType &&
+          events == other.events &&
+          todos == other.todos;
+}
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class JsonlExportSummary {
+  final PlatformInt64 schemaVersion;
+  final PlatformInt64 exportedAtMs;
+  final List<JsonlStreamCount> streams;
+
+  const JsonlExportSummary({
+    required this.schemaVersion,
+    required this.exportedAtMs,
+    required this.streams,
+  });
+
+  @override
+  int get hashCode =>
+      schemaVersion.hashCode ^ exportedAtMs.hashCode ^ streams.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonlExportSummary &&
+          runtimeType == other.runtimeType &&
+          schemaVersion == other.schemaVersion &&
+          exportedAtMs == other.exportedAtMs &&
+          streams == other.streams;
+}
+
+class JsonlExportVerification {
+  final PlatformInt64 schemaVersion;
+  final List<JsonlStreamCount> streams;
+  final List<String> problems;
+
+  const JsonlExportVerification({
+    required this.schemaVersion,
+    required this.streams,
+    required this.problems,
+  });
+
+  @override
+  int get hashCode =>
+      schemaVersion.hashCode ^ streams.hashCode ^ problems.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonlExportVerification &&
+          runtimeType == other.runtimeType &&
+          schemaVersion == other.schemaVersion &&
+          streams == other.streams &&
+          problems == other.problems;
+}
+
+class JsonlStreamCount {
+  final String stream;
+  final BigInt count;
+
+  const JsonlStreamCount({
+    required this.stream,
+    required this.count,
+  });
+
+  @override
+  int get hashCode => stream.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonlStreamCount &&
+          runtimeType == other.runtimeType &&
+          stream == other.stream &&
+          count == other.count;
+}
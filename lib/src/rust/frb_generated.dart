@@ -10,7 +10,12 @@ import 'api/content_enrichment.dart';
 import 'api/content_extract.dart';
 import 'api/core.dart';
 import 'api/desktop_media.dart';
+import 'api/export.dart';
+import 'api/import.dart';
+import 'api/local_llm.dart';
+import 'api/maintenance.dart';
 import 'api/media_annotation.dart';
+import 'api/prompt_templates.dart';
 import 'api/simple.dart';
 import 'api/sync_progress.dart';
 import 'api/tags.dart';
@@ -18,10 +23,15 @@ import 'dart:async';
 import 'dart:convert';
 import 'db.dart';
 import 'desktop_media/ocr.dart';
+import 'export/ics.dart';
+import 'export/jsonl.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
+import 'import.dart';
+import 'llm/local_gguf.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'semantic_parse.dart';
+import 'sync/caldav.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -70,7 +80,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.0.0-dev.38';
 
   @override
-  int get rustContentHash => -849116207;
+  int get rustContentHash => 1827002556;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -247,6 +257,21 @@ abstract class RustLibApi extends BaseApi {
   Future<void> crateApiCoreAuthValidateKey(
       {required String appDir, required List<int> key});
 
+  Future<void> crateApiCoreDbAddTodoDependency(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required String blockedByTodoId});
+
+  Future<AgendaPage> crateApiCoreDbAgendaRange(
+      {required String appDir,
+      required List<int> key,
+      required PlatformInt64 startAtMsInclusive,
+      required PlatformInt64 endAtMsExclusive,
+      required String tz,
+      String? cursor,
+      required int limit});
+
   Future<TodoActivity> crateApiCoreDbAppendTodoNote(
       {required String appDir,
       required List<int> key,
@@ -260,12 +285,22 @@ abstract class RustLibApi extends BaseApi {
       required String desiredVariant,
       required PlatformInt64 nowMs});
 
+  Future<void> crateApiCoreDbCancelEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs});
+
   Future<void> crateApiCoreDbClearLocalAttachmentCache(
       {required String appDir, required List<int> key});
 
   Future<CloudMediaBackupSummary> crateApiCoreDbCloudMediaBackupSummary(
       {required String appDir, required List<int> key});
 
+  Future<List<SemanticParseFailureReasonCount>>
+      crateApiCoreDbCountSemanticParseFailureReasons(
+          {required String appDir, required List<int> key});
+
   Future<Conversation> crateApiCoreDbCreateConversation(
       {required String appDir, required List<int> key, required String title});
 
@@ -294,6 +329,12 @@ abstract class RustLibApi extends BaseApi {
       required List<int> key,
       required String profileId});
 
+  Future<void> crateApiCoreDbDeleteLlmModelPrice(
+      {required String appDir,
+      required List<int> key,
+      required String providerType,
+      required String modelName});
+
   Future<void> crateApiCoreDbDeleteLlmProfile(
       {required String appDir,
       required List<int> key,
@@ -338,6 +379,12 @@ abstract class RustLibApi extends BaseApi {
   Future<String> crateApiCoreDbGetActiveEmbeddingModelName(
       {required String appDir, required List<int> key});
 
+  Future<bool> crateApiCoreDbGetAskAiQueryRewriteEnabled(
+      {required String appDir});
+
+  Future<String?> crateApiCoreDbGetEventRecurrenceRuleJson(
+      {required String appDir, required String eventId});
+
   Future<Message?> crateApiCoreDbGetMessageById(
       {required String appDir,
       required List<int> key,
@@ -348,9 +395,20 @@ abstract class RustLibApi extends BaseApi {
   Future<Conversation> crateApiCoreDbGetOrCreateLoopHomeConversation(
       {required String appDir, required List<int> key});
 
+  Future<String?> crateApiCoreDbGetSemanticParseJobFailureReason(
+      {required String appDir,
+      required List<int> key,
+      required String messageId});
+
   Future<String?> crateApiCoreDbGetTodoRecurrenceRuleJson(
       {required String appDir, required String todoId});
 
+  Future<TodoRollup> crateApiCoreDbGetTodoRollup(
+      {required String appDir, required List<int> key, required String todoId});
+
+  Future<String?> crateApiCoreDbGetTodoTimezone(
+      {required String appDir, required String todoId});
+
   Future<Attachment> crateApiCoreDbInsertAttachment(
       {required String appDir,
       required List<int> key,
@@ -398,6 +456,12 @@ abstract class RustLibApi extends BaseApi {
       required PlatformInt64 nowMs,
       required int limit});
 
+  Future<List<ReviewItem>> crateApiCoreDbListDueReviews(
+      {required String appDir,
+      required List<int> key,
+      required PlatformInt64 dueBeforeMs,
+      required int limit});
+
   Future<List<SemanticParseJob>> crateApiCoreDbListDueSemanticParseJobs(
       {required String appDir,
       required List<int> key,
@@ -410,17 +474,35 @@ abstract class RustLibApi extends BaseApi {
   Future<List<EmbeddingProfile>> crateApiCoreDbListEmbeddingProfiles(
       {required String appDir, required List<int> key});
 
+  Future<List<EventOccurrence>> crateApiCoreDbListEventOccurrencesInRange(
+      {required String appDir,
+      required List<int> key,
+      required PlatformInt64 startAtMsInclusive,
+      required PlatformInt64 endAtMsExclusive});
+
   Future<List<Event>> crateApiCoreDbListEvents(
       {required String appDir, required List<int> key});
 
+  Future<List<String>> crateApiCoreDbListLlmFallbackChain(
+      {required String appDir, required List<int> key});
+
+  Future<List<LlmModelPrice>> crateApiCoreDbListLlmModelPrices(
+      {required String appDir, required List<int> key});
+
   Future<List<LlmProfile>> crateApiCoreDbListLlmProfiles(
       {required String appDir, required List<int> key});
 
+  Future<List<LlmSpendingLimit>> crateApiCoreDbListLlmSpendingLimits(
+      {required String appDir, required List<int> key});
+
   Future<List<Attachment>> crateApiCoreDbListMessageAttachments(
       {required String appDir,
       required List<int> key,
       required String messageId});
 
+  Future<List<MessageCitation>> crateApiCoreDbListMessageCitations(
+      {required String appDir, required String messageId});
+
   Future<List<Message>> crateApiCoreDbListMessages(
       {required String appDir,
       required List<int> key,
@@ -437,6 +519,11 @@ abstract class RustLibApi extends BaseApi {
   Future<List<Attachment>> crateApiCoreDbListRecentAttachments(
       {required String appDir, required List<int> key, required int limit});
 
+  Future<List<ReviewActivity>> crateApiCoreDbListReviewActivities(
+      {required String appDir,
+      required String itemKind,
+      required String itemId});
+
   Future<List<SemanticParseJob>>
       crateApiCoreDbListSemanticParseJobsByMessageIds(
           {required String appDir,
@@ -457,9 +544,20 @@ abstract class RustLibApi extends BaseApi {
       required List<int> key,
       required String activityId});
 
+  Future<List<Todo>> crateApiCoreDbListTodoBlockers(
+      {required String appDir, required List<int> key, required String todoId});
+
+  Future<List<Todo>> crateApiCoreDbListTodoSubtasks(
+      {required String appDir,
+      required List<int> key,
+      required String parentId});
+
   Future<List<Todo>> crateApiCoreDbListTodos(
       {required String appDir, required List<int> key});
 
+  Future<List<Todo>> crateApiCoreDbListTodosBlockedBy(
+      {required String appDir, required List<int> key, required String todoId});
+
   Future<List<Todo>> crateApiCoreDbListTodosCreatedInRange(
       {required String appDir,
       required List<int> key,
@@ -516,6 +614,13 @@ abstract class RustLibApi extends BaseApi {
       required String attachmentSha256,
       required PlatformInt64 nowMs});
 
+  Future<ReviewItem> crateApiCoreDbMarkForReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId,
+      PlatformInt64? firstReviewAtMs});
+
   Future<void> crateApiCoreDbMarkSemanticParseJobCanceled(
       {required String appDir,
       required List<int> key,
@@ -559,12 +664,31 @@ abstract class RustLibApi extends BaseApi {
       required String messageId,
       required PlatformInt64 nowMs});
 
+  Future<void> crateApiCoreDbMoveEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs,
+      required PlatformInt64 startAtMs,
+      required PlatformInt64 endAtMs,
+      String? title});
+
   Future<TodoActivity> crateApiCoreDbMoveTodoActivity(
       {required String appDir,
       required List<int> key,
       required String activityId,
       required String toTodoId});
 
+  Future<Int64List> crateApiCoreDbPreviewReviewIntervals(
+      {required String appDir,
+      required String itemKind,
+      required String itemId});
+
+  Future<Int64List> crateApiCoreDbPreviewTodoRecurrence(
+      {required String ruleJson,
+      required PlatformInt64 startAtMs,
+      required int limit});
+
   Future<int> crateApiCoreDbProcessPendingMessageEmbeddings(
       {required String appDir, required List<int> key, required int limit});
 
@@ -633,9 +757,28 @@ abstract class RustLibApi extends BaseApi {
       PlatformInt64? outputTokens,
       PlatformInt64? totalTokens});
 
+  Future<ReviewItem> crateApiCoreDbRecordReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId,
+      required PlatformInt64 grade});
+
+  Future<void> crateApiCoreDbRemoveTodoDependency(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required String blockedByTodoId});
+
   Future<void> crateApiCoreDbResetVaultDataPreservingLlmProfiles(
       {required String appDir, required List<int> key});
 
+  Future<void> crateApiCoreDbRestoreEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs});
+
   Future<List<SimilarMessage>> crateApiCoreDbSearchSimilarMessages(
       {required String appDir,
       required List<int> key,
@@ -694,12 +837,44 @@ abstract class RustLibApi extends BaseApi {
       required List<int> key,
       required String profileId});
 
+  Future<void> crateApiCoreDbSetAskAiQueryRewriteEnabled(
+      {required String appDir, required bool enabled});
+
+  Future<void> crateApiCoreDbSetEventRecurrenceRule(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      String? ruleJson});
+
+  Future<void> crateApiCoreDbSetLlmFallbackChain(
+      {required String appDir,
+      required List<int> key,
+      required List<String> profileIds});
+
+  Future<void> crateApiCoreDbSetLlmSpendingLimit(
+      {required String appDir,
+      required List<int> key,
+      required String profileId,
+      PlatformInt64? monthlyLimitUsdMicros});
+
   Future<void> crateApiCoreDbSetMessageDeleted(
       {required String appDir,
       required List<int> key,
       required String messageId,
       required bool isDeleted});
 
+  Future<Todo> crateApiCoreDbSetTodoCompleteWithSubtasks(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required bool enabled});
+
+  Future<Todo> crateApiCoreDbSetTodoParent(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      String? parentId});
+
   Future<Todo> crateApiCoreDbSetTodoStatus(
       {required String appDir,
       required List<int> key,
@@ -707,6 +882,15 @@ abstract class RustLibApi extends BaseApi {
       required String newStatus,
       String? sourceMessageId});
 
+  Future<Todo> crateApiCoreDbSetTodoTimezone(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      String? tz});
+
+  Future<List<LlmMonthlyCost>> crateApiCoreDbSumLlmCostMonthly(
+      {required String appDir, required List<int> key, required String month});
+
   Future<List<LlmUsageAggregate>> crateApiCoreDbSumLlmUsageDailyByPurpose(
       {required String appDir,
       required List<int> key,
@@ -714,6 +898,12 @@ abstract class RustLibApi extends BaseApi {
       required String startDay,
       required String endDay});
 
+  Future<void> crateApiCoreDbUnmarkForReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId});
+
   Future<Todo> crateApiCoreDbUpdateTodoDueWithScope(
       {required String appDir,
       required List<int> key,
@@ -762,6 +952,14 @@ abstract class RustLibApi extends BaseApi {
       required String tz,
       String? sourceEntryId});
 
+  Future<LlmModelPrice> crateApiCoreDbUpsertLlmModelPrice(
+      {required String appDir,
+      required List<int> key,
+      required String providerType,
+      required String modelName,
+      required double inputUsdPerMillionTokens,
+      required double outputUsdPerMillionTokens});
+
   Future<Todo> crateApiCoreDbUpsertTodo(
       {required String appDir,
       required List<int> key,
@@ -781,6 +979,11 @@ abstract class RustLibApi extends BaseApi {
       required String seriesId,
       required String ruleJson});
 
+  Future<void> crateApiCoreEmbeddingInstallReranker({required String appDir});
+
+  Future<bool> crateApiCoreEmbeddingIsRerankerInstalled(
+      {required String appDir});
+
   Future<String> crateApiCoreGeoReverseCloudGateway(
       {required String gatewayBaseUrl,
       required String firebaseIdToken,
@@ -796,6 +999,22 @@ abstract class RustLibApi extends BaseApi {
       required String mimeType,
       required List<int> imageBytes});
 
+  Stream<String> crateApiCoreRagAskAiAgentStream(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required String localDay});
+
+  Stream<String> crateApiCoreRagAskAiAgentStreamCloudGateway(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName});
+
   Stream<String> crateApiCoreRagAskAiStream(
       {required String appDir,
       required List<int> key,
@@ -803,7 +1022,8 @@ abstract class RustLibApi extends BaseApi {
       required String question,
       required int topK,
       required bool thisThreadOnly,
-      required String localDay});
+      required String localDay,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamCloudGateway(
       {required String appDir,
@@ -814,7 +1034,8 @@ abstract class RustLibApi extends BaseApi {
       required bool thisThreadOnly,
       required String gatewayBaseUrl,
       required String firebaseIdToken,
-      required String modelName});
+      required String modelName,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamCloudGatewayTimeWindow(
       {required String appDir,
@@ -827,7 +1048,8 @@ abstract class RustLibApi extends BaseApi {
       required PlatformInt64 timeEndMs,
       required String gatewayBaseUrl,
       required String firebaseIdToken,
-      required String modelName});
+      required String modelName,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddings(
       {required String appDir,
@@ -839,7 +1061,8 @@ abstract class RustLibApi extends BaseApi {
       required String gatewayBaseUrl,
       required String firebaseIdToken,
       required String modelName,
-      required String embeddingsModelName});
+      required String embeddingsModelName,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
       {required String appDir,
@@ -853,7 +1076,8 @@ abstract class RustLibApi extends BaseApi {
       required String gatewayBaseUrl,
       required String firebaseIdToken,
       required String modelName,
-      required String embeddingsModelName});
+      required String embeddingsModelName,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamTimeWindow(
       {required String appDir,
@@ -864,7 +1088,8 @@ abstract class RustLibApi extends BaseApi {
       required bool thisThreadOnly,
       required PlatformInt64 timeStartMs,
       required PlatformInt64 timeEndMs,
-      required String localDay});
+      required String localDay,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddings(
       {required String appDir,
@@ -873,7 +1098,8 @@ abstract class RustLibApi extends BaseApi {
       required String question,
       required int topK,
       required bool thisThreadOnly,
-      required String localDay});
+      required String localDay,
+      String? tz});
 
   Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindow(
       {required String appDir,
@@ -884,7 +1110,17 @@ abstract class RustLibApi extends BaseApi {
       required bool thisThreadOnly,
       required PlatformInt64 timeStartMs,
       required PlatformInt64 timeEndMs,
-      required String localDay});
+      required String localDay,
+      String? tz});
+
+  Future<CalDavSyncReport> crateApiCoreSyncCaldav(
+      {required String appDir,
+      required List<int> key,
+      required String collectionUrl,
+      String? username,
+      String? password,
+      String? defaultTz,
+      required PlatformInt64 nowMs});
 
   Future<Uint8List> crateApiCoreSyncDeriveKey({required String passphrase});
 
@@ -1052,6 +1288,77 @@ abstract class RustLibApi extends BaseApi {
       required int dpi,
       required String languageHints});
 
+  Future<IcsExportSummary> crateApiExportDbExportIcs(
+      {required String appDir,
+      required List<int> key,
+      required String outPath,
+      required PlatformInt64 nowMs});
+
+  Future<JsonlExportSummary> crateApiExportDbExportVaultJsonl(
+      {required String appDir,
+      required List<int> key,
+      required String outDir,
+      required PlatformInt64 nowMs});
+
+  Future<JsonlExportVerification> crateApiExportVerifyVaultJsonlExport(
+      {required String exportDir});
+
+  Future<ImportReport> crateApiImportDbImportIcs(
+      {required String appDir,
+      required List<int> key,
+      required String icsPath,
+      String? defaultTz,
+      required bool preview});
+
+  Future<ImportReport> crateApiImportDbImportMarkdownFolder(
+      {required String appDir,
+      required List<int> key,
+      required String rootDir,
+      required String lang,
+      String? defaultTz});
+
+  Future<ImportReport> crateApiImportDbImportTelegramExport(
+      {required String appDir,
+      required List<int> key,
+      required String jsonPath,
+      required String lang,
+      String? defaultTz,
+      required bool preview});
+
+  Future<ImportReport> crateApiImportDbImportWhatsappExport(
+      {required String appDir,
+      required List<int> key,
+      required String txtPath,
+      required String lang,
+      String? defaultTz,
+      required bool preview});
+
+  Future<bool> crateApiLocalLlmLocalLlmDeleteModel(
+      {required String appDir, required String fileName});
+
+  Future<LocalGgufModelInfo> crateApiLocalLlmLocalLlmInstallModel(
+      {required String appDir,
+      required String sourcePath,
+      String? expectedSha256});
+
+  Future<List<LocalGgufModelInfo>> crateApiLocalLlmLocalLlmListModels(
+      {required String appDir});
+
+  Future<String> crateApiLocalLlmLocalLlmModelSha256(
+      {required String appDir, required String fileName});
+
+  Future<bool> crateApiLocalLlmLocalLlmRuntimeAvailable(
+      {required String appDir});
+
+  Future<IntegrityReport> crateApiMaintenanceDbCheckIntegrity(
+      {required String appDir,
+      required List<int> key,
+      required bool fix,
+      required PlatformInt64 nowMs});
+
+  Future<StorageUsageReport> crateApiMaintenanceDbStorageUsage(
+      {required String appDir, required List<int> key});
+
   Future<MediaAnnotationConfig>
       crateApiMediaAnnotationDbGetMediaAnnotationConfig(
           {required String appDir, required List<int> key});
@@ -1070,6 +1377,49 @@ abstract class RustLibApi extends BaseApi {
       required String mimeType,
       required List<int> imageBytes});
 
+  Future<void> crateApiPromptTemplatesDbDeletePromptTemplate(
+      {required String appDir,
+      required List<int> key,
+      required String templateId});
+
+  Future<List<PromptTemplateSelection>>
+      crateApiPromptTemplatesDbListPromptTemplateSelections(
+          {required String appDir, required List<int> key});
+
+  Future<List<PromptTemplate>> crateApiPromptTemplatesDbListPromptTemplates(
+      {required String appDir, required List<int> key});
+
+  Future<void> crateApiPromptTemplatesDbResetPromptTemplateSelections(
+      {required String appDir,
+      required List<int> key,
+      required String purpose});
+
+  Future<PromptTemplate> crateApiPromptTemplatesDbResetPromptTemplateToDefault(
+      {required String appDir,
+      required List<int> key,
+      required String templateId});
+
+  Future<void> crateApiPromptTemplatesDbSetPromptTemplateSelection(
+      {required String appDir,
+      required List<int> key,
+      required String purpose,
+      required String profileId,
+      String? templateId});
+
+  Future<PromptTemplate> crateApiPromptTemplatesDbUpsertPromptTemplate(
+      {required String appDir,
+      required List<int> key,
+      String? templateId,
+      required String name,
+      required String purpose,
+      required String body});
+
+  Future<String> crateApiPromptTemplatesPromptTemplateDefault(
+      {required String purpose});
+
+  Future<void> crateApiPromptTemplatesPromptTemplateValidate(
+      {required String purpose, required String body});
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
@@ -1122,6 +1472,15 @@ abstract class RustLibApi extends BaseApi {
       String? password,
       required String remoteRoot});
 
+  Future<int> crateApiTagsDbBackfillTagRules(
+      {required String appDir,
+      required List<int> key,
+      String? ruleId,
+      required PlatformInt64 nowMs});
+
+  Future<void> crateApiTagsDbDeleteTagRule(
+      {required String appDir, required List<int> key, required String ruleId});
+
   Future<List<String>> crateApiTagsDbListMessageIdsByTagIds(
       {required String appDir,
       required List<int> key,
@@ -1141,6 +1500,12 @@ abstract class RustLibApi extends BaseApi {
   Future<List<TagMergeSuggestion>> crateApiTagsDbListTagMergeSuggestions(
       {required String appDir, required List<int> key, required int limit});
 
+  Future<List<TagRule>> crateApiTagsDbListTagRules(
+      {required String appDir, required List<int> key});
+
+  Future<List<TagNode>> crateApiTagsDbListTagTree(
+      {required String appDir, required List<int> key});
+
   Future<List<Tag>> crateApiTagsDbListTags(
       {required String appDir, required List<int> key});
 
@@ -1164,8 +1529,27 @@ abstract class RustLibApi extends BaseApi {
       required String messageId,
       required List<String> tagIds});
 
+  Future<Tag> crateApiTagsDbSetTagParent(
+      {required String appDir,
+      required List<int> key,
+      required String tagId,
+      String? parentId});
+
   Future<Tag> crateApiTagsDbUpsertTag(
       {required String appDir, required List<int> key, required String name});
+
+  Future<Tag> crateApiTagsDbUpsertTagPath(
+      {required String appDir, required List<int> key, required String path});
+
+  Future<TagRule> crateApiTagsDbUpsertTagRule(
+      {required String appDir,
+      required List<int> key,
+      String? ruleId,
+      required String name,
+      required String kind,
+      required String pattern,
+      required String tagId,
+      required bool enabled});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -2186,71 +2570,188 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<TodoActivity> crateApiCoreDbAppendTodoNote(
+  Future<void> crateApiCoreDbAddTodoDependency(
       {required String appDir,
       required List<int> key,
       required String todoId,
-      required String content,
-      String? sourceMessageId}) {
+      required String blockedByTodoId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(todoId, serializer);
-        sse_encode_String(content, serializer);
-        sse_encode_opt_String(sourceMessageId, serializer);
+        sse_encode_String(blockedByTodoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 25, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_todo_activity,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbAppendTodoNoteConstMeta,
-      argValues: [appDir, key, todoId, content, sourceMessageId],
+      constMeta: kCrateApiCoreDbAddTodoDependencyConstMeta,
+      argValues: [appDir, key, todoId, blockedByTodoId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbAppendTodoNoteConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbAddTodoDependencyConstMeta =>
       const TaskConstMeta(
-        debugName: "db_append_todo_note",
-        argNames: ["appDir", "key", "todoId", "content", "sourceMessageId"],
+        debugName: "db_add_todo_dependency",
+        argNames: ["appDir", "key", "todoId", "blockedByTodoId"],
       );
 
   @override
-  Future<BigInt> crateApiCoreDbBackfillCloudMediaBackupImages(
+  Future<AgendaPage> crateApiCoreDbAgendaRange(
       {required String appDir,
       required List<int> key,
-      required String desiredVariant,
-      required PlatformInt64 nowMs}) {
+      required PlatformInt64 startAtMsInclusive,
+      required PlatformInt64 endAtMsExclusive,
+      required String tz,
+      String? cursor,
+      required int limit}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(desiredVariant, serializer);
-        sse_encode_i_64(nowMs, serializer);
+        sse_encode_i_64(startAtMsInclusive, serializer);
+        sse_encode_i_64(endAtMsExclusive, serializer);
+        sse_encode_String(tz, serializer);
+        sse_encode_opt_String(cursor, serializer);
+        sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 26, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_agenda_page,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbBackfillCloudMediaBackupImagesConstMeta,
-      argValues: [appDir, key, desiredVariant, nowMs],
+      constMeta: kCrateApiCoreDbAgendaRangeConstMeta,
+      argValues: [
+        appDir,
+        key,
+        startAtMsInclusive,
+        endAtMsExclusive,
+        tz,
+        cursor,
+        limit
+      ],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbBackfillCloudMediaBackupImagesConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbAgendaRangeConstMeta => const TaskConstMeta(
+        debugName: "db_agenda_range",
+        argNames: [
+          "appDir",
+          "key",
+          "startAtMsInclusive",
+          "endAtMsExclusive",
+          "tz",
+          "cursor",
+          "limit"
+        ],
+      );
+
+  @override
+  Future<TodoActivity> crateApiCoreDbAppendTodoNote(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required String content,
+      String? sourceMessageId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(content, serializer);
+        sse_encode_opt_String(sourceMessageId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_todo_activity,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbAppendTodoNoteConstMeta,
+      argValues: [appDir, key, todoId, content, sourceMessageId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbAppendTodoNoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_append_todo_note",
+        argNames: ["appDir", "key", "todoId", "content", "sourceMessageId"],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreDbBackfillCloudMediaBackupImages(
+      {required String appDir,
+      required List<int> key,
+      required String desiredVariant,
+      required PlatformInt64 nowMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(desiredVariant, serializer);
+        sse_encode_i_64(nowMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbBackfillCloudMediaBackupImagesConstMeta,
+      argValues: [appDir, key, desiredVariant, nowMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbBackfillCloudMediaBackupImagesConstMeta =>
       const TaskConstMeta(
         debugName: "db_backfill_cloud_media_backup_images",
         argNames: ["appDir", "key", "desiredVariant", "nowMs"],
       );
 
+  @override
+  Future<void> crateApiCoreDbCancelEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_i_64(occurrenceStartMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbCancelEventOccurrenceConstMeta,
+      argValues: [appDir, key, eventId, occurrenceStartMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbCancelEventOccurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_cancel_event_occurrence",
+        argNames: ["appDir", "key", "eventId", "occurrenceStartMs"],
+      );
+
   @override
   Future<void> crateApiCoreDbClearLocalAttachmentCache(
       {required String appDir, required List<int> key}) {
@@ -2260,7 +2761,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 27, port: port_);
+            funcId: 30, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2287,7 +2788,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 28, port: port_);
+            funcId: 31, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_cloud_media_backup_summary,
@@ -2305,6 +2806,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<List<SemanticParseFailureReasonCount>>
+      crateApiCoreDbCountSemanticParseFailureReasons(
+          {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_semantic_parse_failure_reason_count,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbCountSemanticParseFailureReasonsConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbCountSemanticParseFailureReasonsConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_count_semantic_parse_failure_reasons",
+        argNames: ["appDir", "key"],
+      );
+
   @override
   Future<Conversation> crateApiCoreDbCreateConversation(
       {required String appDir, required List<int> key, required String title}) {
@@ -2315,7 +2844,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(title, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 29, port: port_);
+            funcId: 33, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_conversation,
@@ -2355,7 +2884,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(modelName, serializer);
         sse_encode_bool(setActive, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 30, port: port_);
+            funcId: 34, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_embedding_profile,
@@ -2413,7 +2942,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(modelName, serializer);
         sse_encode_bool(setActive, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
+            funcId: 35, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_llm_profile,
@@ -2461,7 +2990,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(profileId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 32, port: port_);
+            funcId: 36, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2479,6 +3008,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "profileId"],
       );
 
+  @override
+  Future<void> crateApiCoreDbDeleteLlmModelPrice(
+      {required String appDir,
+      required List<int> key,
+      required String providerType,
+      required String modelName}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(providerType, serializer);
+        sse_encode_String(modelName, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbDeleteLlmModelPriceConstMeta,
+      argValues: [appDir, key, providerType, modelName],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbDeleteLlmModelPriceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_delete_llm_model_price",
+        argNames: ["appDir", "key", "providerType", "modelName"],
+      );
+
   @override
   Future<void> crateApiCoreDbDeleteLlmProfile(
       {required String appDir,
@@ -2491,7 +3052,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(profileId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
+            funcId: 38, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2521,7 +3082,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(todoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 34, port: port_);
+            funcId: 39, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_64,
@@ -2553,7 +3114,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_String(content, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 35, port: port_);
+            funcId: 40, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2586,7 +3147,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lang, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 36, port: port_);
+            funcId: 41, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2620,7 +3181,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lang, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 37, port: port_);
+            funcId: 42, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2654,7 +3215,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(desiredVariant, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 38, port: port_);
+            funcId: 43, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2692,7 +3253,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 39, port: port_);
+            funcId: 44, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2719,7 +3280,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 40, port: port_);
+            funcId: 45, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -2737,6 +3298,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<bool> crateApiCoreDbGetAskAiQueryRewriteEnabled(
+      {required String appDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbGetAskAiQueryRewriteEnabledConstMeta,
+      argValues: [appDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbGetAskAiQueryRewriteEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_get_ask_ai_query_rewrite_enabled",
+        argNames: ["appDir"],
+      );
+
+  @override
+  Future<String?> crateApiCoreDbGetEventRecurrenceRuleJson(
+      {required String appDir, required String eventId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(eventId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbGetEventRecurrenceRuleJsonConstMeta,
+      argValues: [appDir, eventId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbGetEventRecurrenceRuleJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_get_event_recurrence_rule_json",
+        argNames: ["appDir", "eventId"],
+      );
+
   @override
   Future<Message?> crateApiCoreDbGetMessageById(
       {required String appDir,
@@ -2749,7 +3363,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(messageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 41, port: port_);
+            funcId: 48, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_box_autoadd_message,
@@ -2774,7 +3388,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 42, port: port_);
+            funcId: 49, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -2801,7 +3415,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 43, port: port_);
+            funcId: 50, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_conversation,
@@ -2819,6 +3433,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<String?> crateApiCoreDbGetSemanticParseJobFailureReason(
+      {required String appDir,
+      required List<int> key,
+      required String messageId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(messageId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbGetSemanticParseJobFailureReasonConstMeta,
+      argValues: [appDir, key, messageId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbGetSemanticParseJobFailureReasonConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_get_semantic_parse_job_failure_reason",
+        argNames: ["appDir", "key", "messageId"],
+      );
+
   @override
   Future<String?> crateApiCoreDbGetTodoRecurrenceRuleJson(
       {required String appDir, required String todoId}) {
@@ -2828,7 +3472,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_String(todoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 44, port: port_);
+            funcId: 52, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -2847,34 +3491,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<Attachment> crateApiCoreDbInsertAttachment(
+  Future<TodoRollup> crateApiCoreDbGetTodoRollup(
       {required String appDir,
       required List<int> key,
-      required List<int> bytes,
-      required String mimeType}) {
+      required String todoId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(bytes, serializer);
-        sse_encode_String(mimeType, serializer);
+        sse_encode_String(todoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 45, port: port_);
+            funcId: 53, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_attachment,
+        decodeSuccessData: sse_decode_todo_rollup,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbInsertAttachmentConstMeta,
-      argValues: [appDir, key, bytes, mimeType],
+      constMeta: kCrateApiCoreDbGetTodoRollupConstMeta,
+      argValues: [appDir, key, todoId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbInsertAttachmentConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbGetTodoRollupConstMeta =>
       const TaskConstMeta(
-        debugName: "db_insert_attachment",
+        debugName: "db_get_todo_rollup",
+        argNames: ["appDir", "key", "todoId"],
+      );
+
+  @override
+  Future<String?> crateApiCoreDbGetTodoTimezone(
+      {required String appDir, required String todoId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(todoId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbGetTodoTimezoneConstMeta,
+      argValues: [appDir, todoId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbGetTodoTimezoneConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_get_todo_timezone",
+        argNames: ["appDir", "todoId"],
+      );
+
+  @override
+  Future<Attachment> crateApiCoreDbInsertAttachment(
+      {required String appDir,
+      required List<int> key,
+      required List<int> bytes,
+      required String mimeType}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(bytes, serializer);
+        sse_encode_String(mimeType, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_attachment,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbInsertAttachmentConstMeta,
+      argValues: [appDir, key, bytes, mimeType],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbInsertAttachmentConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_insert_attachment",
         argNames: ["appDir", "key", "bytes", "mimeType"],
       );
 
@@ -2894,7 +3595,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(role, serializer);
         sse_encode_String(content, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 46, port: port_);
+            funcId: 56, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_message,
@@ -2926,7 +3627,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_String(attachmentSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 47, port: port_);
+            funcId: 57, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2958,7 +3659,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(activityId, serializer);
         sse_encode_String(attachmentSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 48, port: port_);
+            funcId: 58, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2985,7 +3686,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 49, port: port_);
+            funcId: 59, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_conversation,
@@ -3018,7 +3719,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(nowMs, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 50, port: port_);
+            funcId: 60, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_attachment_annotation_job,
@@ -3050,7 +3751,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(nowMs, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 51, port: port_);
+            funcId: 61, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_attachment_place_job,
@@ -3082,7 +3783,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(nowMs, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 52, port: port_);
+            funcId: 62, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_cloud_media_backup,
@@ -3100,6 +3801,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "nowMs", "limit"],
       );
 
+  @override
+  Future<List<ReviewItem>> crateApiCoreDbListDueReviews(
+      {required String appDir,
+      required List<int> key,
+      required PlatformInt64 dueBeforeMs,
+      required int limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_i_64(dueBeforeMs, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_review_item,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListDueReviewsConstMeta,
+      argValues: [appDir, key, dueBeforeMs, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListDueReviewsConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_due_reviews",
+        argNames: ["appDir", "key", "dueBeforeMs", "limit"],
+      );
+
   @override
   Future<List<SemanticParseJob>> crateApiCoreDbListDueSemanticParseJobs(
       {required String appDir,
@@ -3114,7 +3847,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(nowMs, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 53, port: port_);
+            funcId: 64, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_semantic_parse_job,
@@ -3141,7 +3874,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 54, port: port_);
+            funcId: 65, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_String,
@@ -3168,7 +3901,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 55, port: port_);
+            funcId: 66, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_embedding_profile,
@@ -3186,6 +3919,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<List<EventOccurrence>> crateApiCoreDbListEventOccurrencesInRange(
+      {required String appDir,
+      required List<int> key,
+      required PlatformInt64 startAtMsInclusive,
+      required PlatformInt64 endAtMsExclusive}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_i_64(startAtMsInclusive, serializer);
+        sse_encode_i_64(endAtMsExclusive, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_event_occurrence,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListEventOccurrencesInRangeConstMeta,
+      argValues: [appDir, key, startAtMsInclusive, endAtMsExclusive],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListEventOccurrencesInRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_event_occurrences_in_range",
+        argNames: ["appDir", "key", "startAtMsInclusive", "endAtMsExclusive"],
+      );
+
   @override
   Future<List<Event>> crateApiCoreDbListEvents(
       {required String appDir, required List<int> key}) {
@@ -3195,7 +3960,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 56, port: port_);
+            funcId: 68, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_event,
@@ -3212,6 +3977,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<List<String>> crateApiCoreDbListLlmFallbackChain(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 69, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListLlmFallbackChainConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListLlmFallbackChainConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_llm_fallback_chain",
+        argNames: ["appDir", "key"],
+      );
+
+  @override
+  Future<List<LlmModelPrice>> crateApiCoreDbListLlmModelPrices(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_llm_model_price,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListLlmModelPricesConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListLlmModelPricesConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_llm_model_prices",
+        argNames: ["appDir", "key"],
+      );
+
   @override
   Future<List<LlmProfile>> crateApiCoreDbListLlmProfiles(
       {required String appDir, required List<int> key}) {
@@ -3221,7 +4040,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 57, port: port_);
+            funcId: 71, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_llm_profile,
@@ -3239,6 +4058,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<List<LlmSpendingLimit>> crateApiCoreDbListLlmSpendingLimits(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_llm_spending_limit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListLlmSpendingLimitsConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListLlmSpendingLimitsConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_llm_spending_limits",
+        argNames: ["appDir", "key"],
+      );
+
   @override
   Future<List<Attachment>> crateApiCoreDbListMessageAttachments(
       {required String appDir,
@@ -3251,7 +4097,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(messageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 58, port: port_);
+            funcId: 73, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_attachment,
@@ -3269,6 +4115,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "messageId"],
       );
 
+  @override
+  Future<List<MessageCitation>> crateApiCoreDbListMessageCitations(
+      {required String appDir, required String messageId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(messageId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_message_citation,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListMessageCitationsConstMeta,
+      argValues: [appDir, messageId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListMessageCitationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_message_citations",
+        argNames: ["appDir", "messageId"],
+      );
+
   @override
   Future<List<Message>> crateApiCoreDbListMessages(
       {required String appDir,
@@ -3281,7 +4154,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(conversationId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 59, port: port_);
+            funcId: 75, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_message,
@@ -3316,7 +4189,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(beforeId, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 60, port: port_);
+            funcId: 76, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_message,
@@ -3358,7 +4231,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 61, port: port_);
+            funcId: 77, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_attachment,
@@ -3376,6 +4249,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "limit"],
       );
 
+  @override
+  Future<List<ReviewActivity>> crateApiCoreDbListReviewActivities(
+      {required String appDir,
+      required String itemKind,
+      required String itemId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(itemKind, serializer);
+        sse_encode_String(itemId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_review_activity,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListReviewActivitiesConstMeta,
+      argValues: [appDir, itemKind, itemId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListReviewActivitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_review_activities",
+        argNames: ["appDir", "itemKind", "itemId"],
+      );
+
   @override
   Future<List<SemanticParseJob>>
       crateApiCoreDbListSemanticParseJobsByMessageIds(
@@ -3389,7 +4292,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_list_String(messageIds, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 62, port: port_);
+            funcId: 79, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_semantic_parse_job,
@@ -3419,7 +4322,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(todoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 63, port: port_);
+            funcId: 80, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_todo_activity,
@@ -3451,7 +4354,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(startAtMsInclusive, serializer);
         sse_encode_i_64(endAtMsExclusive, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 64, port: port_);
+            funcId: 81, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_todo_activity,
@@ -3481,7 +4384,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(activityId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 65, port: port_);
+            funcId: 82, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_attachment,
@@ -3500,15 +4403,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<List<Todo>> crateApiCoreDbListTodos(
-      {required String appDir, required List<int> key}) {
+  Future<List<Todo>> crateApiCoreDbListTodoBlockers(
+      {required String appDir,
+      required List<int> key,
+      required String todoId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 66, port: port_);
+            funcId: 83, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_todo,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListTodoBlockersConstMeta,
+      argValues: [appDir, key, todoId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListTodoBlockersConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_todo_blockers",
+        argNames: ["appDir", "key", "todoId"],
+      );
+
+  @override
+  Future<List<Todo>> crateApiCoreDbListTodoSubtasks(
+      {required String appDir,
+      required List<int> key,
+      required String parentId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(parentId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 84, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_todo,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListTodoSubtasksConstMeta,
+      argValues: [appDir, key, parentId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListTodoSubtasksConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_todo_subtasks",
+        argNames: ["appDir", "key", "parentId"],
+      );
+
+  @override
+  Future<List<Todo>> crateApiCoreDbListTodos(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 85, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_todo,
@@ -3525,6 +4488,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key"],
       );
 
+  @override
+  Future<List<Todo>> crateApiCoreDbListTodosBlockedBy(
+      {required String appDir,
+      required List<int> key,
+      required String todoId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 86, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_todo,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbListTodosBlockedByConstMeta,
+      argValues: [appDir, key, todoId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbListTodosBlockedByConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_list_todos_blocked_by",
+        argNames: ["appDir", "key", "todoId"],
+      );
+
   @override
   Future<List<Todo>> crateApiCoreDbListTodosCreatedInRange(
       {required String appDir,
@@ -3539,7 +4532,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_64(startAtMsInclusive, serializer);
         sse_encode_i_64(endAtMsExclusive, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 67, port: port_);
+            funcId: 87, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_todo,
@@ -3577,7 +4570,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lastError, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 68, port: port_);
+            funcId: 88, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3631,7 +4624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(payloadJson, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 69, port: port_);
+            funcId: 89, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3685,7 +4678,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lastError, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 70, port: port_);
+            funcId: 90, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3737,7 +4730,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(payloadJson, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 71, port: port_);
+            funcId: 91, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3782,7 +4775,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lastError, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 72, port: port_);
+            funcId: 92, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3830,7 +4823,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(attachmentSha256, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 73, port: port_);
+            funcId: 93, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3848,6 +4841,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "attachmentSha256", "nowMs"],
       );
 
+  @override
+  Future<ReviewItem> crateApiCoreDbMarkForReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId,
+      PlatformInt64? firstReviewAtMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(itemKind, serializer);
+        sse_encode_String(itemId, serializer);
+        sse_encode_opt_box_autoadd_i_64(firstReviewAtMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 94, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_review_item,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbMarkForReviewConstMeta,
+      argValues: [appDir, key, itemKind, itemId, firstReviewAtMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbMarkForReviewConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_mark_for_review",
+        argNames: ["appDir", "key", "itemKind", "itemId", "firstReviewAtMs"],
+      );
+
   @override
   Future<void> crateApiCoreDbMarkSemanticParseJobCanceled(
       {required String appDir,
@@ -3862,7 +4889,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 74, port: port_);
+            funcId: 95, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3900,7 +4927,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(lastError, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 75, port: port_);
+            funcId: 96, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3948,7 +4975,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 76, port: port_);
+            funcId: 97, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -3980,7 +5007,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 77, port: port_);
+            funcId: 98, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4020,7 +5047,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(appliedPrevTodoStatus, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 78, port: port_);
+            funcId: 99, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4070,7 +5097,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 79, port: port_);
+            funcId: 100, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4088,6 +5115,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "messageId", "nowMs"],
       );
 
+  @override
+  Future<void> crateApiCoreDbMoveEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs,
+      required PlatformInt64 startAtMs,
+      required PlatformInt64 endAtMs,
+      String? title}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_i_64(occurrenceStartMs, serializer);
+        sse_encode_i_64(startAtMs, serializer);
+        sse_encode_i_64(endAtMs, serializer);
+        sse_encode_opt_String(title, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 101, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbMoveEventOccurrenceConstMeta,
+      argValues: [
+        appDir,
+        key,
+        eventId,
+        occurrenceStartMs,
+        startAtMs,
+        endAtMs,
+        title
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbMoveEventOccurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_move_event_occurrence",
+        argNames: [
+          "appDir",
+          "key",
+          "eventId",
+          "occurrenceStartMs",
+          "startAtMs",
+          "endAtMs",
+          "title"
+        ],
+      );
+
   @override
   Future<TodoActivity> crateApiCoreDbMoveTodoActivity(
       {required String appDir,
@@ -4102,7 +5183,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(activityId, serializer);
         sse_encode_String(toTodoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 80, port: port_);
+            funcId: 102, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_todo_activity,
@@ -4120,6 +5201,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "activityId", "toTodoId"],
       );
 
+  @override
+  Future<Int64List> crateApiCoreDbPreviewReviewIntervals(
+      {required String appDir,
+      required String itemKind,
+      required String itemId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(itemKind, serializer);
+        sse_encode_String(itemId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 103, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_64_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbPreviewReviewIntervalsConstMeta,
+      argValues: [appDir, itemKind, itemId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbPreviewReviewIntervalsConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_preview_review_intervals",
+        argNames: ["appDir", "itemKind", "itemId"],
+      );
+
+  @override
+  Future<Int64List> crateApiCoreDbPreviewTodoRecurrence(
+      {required String ruleJson,
+      required PlatformInt64 startAtMs,
+      required int limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(ruleJson, serializer);
+        sse_encode_i_64(startAtMs, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 104, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_i_64_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbPreviewTodoRecurrenceConstMeta,
+      argValues: [ruleJson, startAtMs, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbPreviewTodoRecurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_preview_todo_recurrence",
+        argNames: ["ruleJson", "startAtMs", "limit"],
+      );
+
   @override
   Future<int> crateApiCoreDbProcessPendingMessageEmbeddings(
       {required String appDir, required List<int> key, required int limit}) {
@@ -4130,7 +5271,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 81, port: port_);
+            funcId: 105, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -4162,7 +5303,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(todoLimit, serializer);
         sse_encode_u_32(activityLimit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 82, port: port_);
+            funcId: 106, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -4195,7 +5336,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(todoLimit, serializer);
         sse_encode_u_32(activityLimit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 83, port: port_);
+            funcId: 107, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -4234,7 +5375,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(firebaseIdToken, serializer);
         sse_encode_String(modelName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 84, port: port_);
+            funcId: 108, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -4283,7 +5424,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(messageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 85, port: port_);
+            funcId: 109, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_64,
@@ -4313,7 +5454,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(attachmentSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 86, port: port_);
+            funcId: 110, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -4344,7 +5485,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(sha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 87, port: port_);
+            funcId: 111, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -4374,7 +5515,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(attachmentSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 88, port: port_);
+            funcId: 112, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_box_autoadd_attachment_exif_metadata,
@@ -4404,7 +5545,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(attachmentSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 89, port: port_);
+            funcId: 113, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_String,
@@ -4436,7 +5577,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(attachmentSha256, serializer);
         sse_encode_String(variant, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 90, port: port_);
+            funcId: 114, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -4466,7 +5607,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_u_32(batchLimit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 91, port: port_);
+            funcId: 115, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -4506,7 +5647,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_box_autoadd_i_64(outputTokens, serializer);
         sse_encode_opt_box_autoadd_i_64(totalTokens, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 92, port: port_);
+            funcId: 116, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4543,55 +5684,152 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiCoreDbResetVaultDataPreservingLlmProfiles(
-      {required String appDir, required List<int> key}) {
+  Future<ReviewItem> crateApiCoreDbRecordReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId,
+      required PlatformInt64 grade}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(itemKind, serializer);
+        sse_encode_String(itemId, serializer);
+        sse_encode_i_64(grade, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 93, port: port_);
+            funcId: 117, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_review_item,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbResetVaultDataPreservingLlmProfilesConstMeta,
-      argValues: [appDir, key],
+      constMeta: kCrateApiCoreDbRecordReviewConstMeta,
+      argValues: [appDir, key, itemKind, itemId, grade],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta
-      get kCrateApiCoreDbResetVaultDataPreservingLlmProfilesConstMeta =>
-          const TaskConstMeta(
-            debugName: "db_reset_vault_data_preserving_llm_profiles",
-            argNames: ["appDir", "key"],
-          );
+  TaskConstMeta get kCrateApiCoreDbRecordReviewConstMeta => const TaskConstMeta(
+        debugName: "db_record_review",
+        argNames: ["appDir", "key", "itemKind", "itemId", "grade"],
+      );
 
   @override
-  Future<List<SimilarMessage>> crateApiCoreDbSearchSimilarMessages(
+  Future<void> crateApiCoreDbRemoveTodoDependency(
       {required String appDir,
       required List<int> key,
-      required String query,
-      required int topK}) {
+      required String todoId,
+      required String blockedByTodoId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(query, serializer);
-        sse_encode_u_32(topK, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(blockedByTodoId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 94, port: port_);
+            funcId: 118, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_similar_message,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbSearchSimilarMessagesConstMeta,
-      argValues: [appDir, key, query, topK],
+      constMeta: kCrateApiCoreDbRemoveTodoDependencyConstMeta,
+      argValues: [appDir, key, todoId, blockedByTodoId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbRemoveTodoDependencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_remove_todo_dependency",
+        argNames: ["appDir", "key", "todoId", "blockedByTodoId"],
+      );
+
+  @override
+  Future<void> crateApiCoreDbResetVaultDataPreservingLlmProfiles(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 119, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbResetVaultDataPreservingLlmProfilesConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiCoreDbResetVaultDataPreservingLlmProfilesConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_reset_vault_data_preserving_llm_profiles",
+            argNames: ["appDir", "key"],
+          );
+
+  @override
+  Future<void> crateApiCoreDbRestoreEventOccurrence(
+      {required String appDir,
+      required List<int> key,
+      required String eventId,
+      required PlatformInt64 occurrenceStartMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_i_64(occurrenceStartMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 120, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbRestoreEventOccurrenceConstMeta,
+      argValues: [appDir, key, eventId, occurrenceStartMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbRestoreEventOccurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_restore_event_occurrence",
+        argNames: ["appDir", "key", "eventId", "occurrenceStartMs"],
+      );
+
+  @override
+  Future<List<SimilarMessage>> crateApiCoreDbSearchSimilarMessages(
+      {required String appDir,
+      required List<int> key,
+      required String query,
+      required int topK}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(topK, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 121, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_similar_message,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbSearchSimilarMessagesConstMeta,
+      argValues: [appDir, key, query, topK],
       apiImpl: this,
     ));
   }
@@ -4616,7 +5854,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(query, serializer);
         sse_encode_u_32(topK, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 95, port: port_);
+            funcId: 122, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_similar_message,
@@ -4654,7 +5892,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(firebaseIdToken, serializer);
         sse_encode_String(modelName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 96, port: port_);
+            funcId: 123, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_similar_message,
@@ -4702,7 +5940,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(query, serializer);
         sse_encode_u_32(topK, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 97, port: port_);
+            funcId: 124, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_similar_todo_thread,
@@ -4734,7 +5972,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(query, serializer);
         sse_encode_u_32(topK, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 98, port: port_);
+            funcId: 125, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_similar_todo_thread,
@@ -4773,7 +6011,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(firebaseIdToken, serializer);
         sse_encode_String(modelName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 99, port: port_);
+            funcId: 126, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_similar_todo_thread,
@@ -4820,7 +6058,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(modelName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 100, port: port_);
+            funcId: 127, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -4850,7 +6088,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(profileId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 101, port: port_);
+            funcId: 128, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4880,7 +6118,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(profileId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 102, port: port_);
+            funcId: 129, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -4899,2171 +6137,3435 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiCoreDbSetMessageDeleted(
-      {required String appDir,
-      required List<int> key,
-      required String messageId,
-      required bool isDeleted}) {
+  Future<void> crateApiCoreDbSetAskAiQueryRewriteEnabled(
+      {required String appDir, required bool enabled}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(messageId, serializer);
-        sse_encode_bool(isDeleted, serializer);
+        sse_encode_bool(enabled, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 103, port: port_);
+            funcId: 130, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbSetMessageDeletedConstMeta,
-      argValues: [appDir, key, messageId, isDeleted],
+      constMeta: kCrateApiCoreDbSetAskAiQueryRewriteEnabledConstMeta,
+      argValues: [appDir, enabled],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbSetMessageDeletedConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetAskAiQueryRewriteEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "db_set_message_deleted",
-        argNames: ["appDir", "key", "messageId", "isDeleted"],
+        debugName: "db_set_ask_ai_query_rewrite_enabled",
+        argNames: ["appDir", "enabled"],
       );
 
   @override
-  Future<Todo> crateApiCoreDbSetTodoStatus(
+  Future<void> crateApiCoreDbSetEventRecurrenceRule(
       {required String appDir,
       required List<int> key,
-      required String todoId,
-      required String newStatus,
-      String? sourceMessageId}) {
+      required String eventId,
+      String? ruleJson}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(todoId, serializer);
-        sse_encode_String(newStatus, serializer);
-        sse_encode_opt_String(sourceMessageId, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_opt_String(ruleJson, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 104, port: port_);
+            funcId: 131, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_todo,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbSetTodoStatusConstMeta,
-      argValues: [appDir, key, todoId, newStatus, sourceMessageId],
+      constMeta: kCrateApiCoreDbSetEventRecurrenceRuleConstMeta,
+      argValues: [appDir, key, eventId, ruleJson],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbSetTodoStatusConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetEventRecurrenceRuleConstMeta =>
       const TaskConstMeta(
-        debugName: "db_set_todo_status",
-        argNames: ["appDir", "key", "todoId", "newStatus", "sourceMessageId"],
+        debugName: "db_set_event_recurrence_rule",
+        argNames: ["appDir", "key", "eventId", "ruleJson"],
       );
 
   @override
-  Future<List<LlmUsageAggregate>> crateApiCoreDbSumLlmUsageDailyByPurpose(
+  Future<void> crateApiCoreDbSetLlmFallbackChain(
       {required String appDir,
       required List<int> key,
-      required String profileId,
-      required String startDay,
-      required String endDay}) {
+      required List<String> profileIds}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(profileId, serializer);
-        sse_encode_String(startDay, serializer);
-        sse_encode_String(endDay, serializer);
+        sse_encode_list_String(profileIds, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 105, port: port_);
+            funcId: 132, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_llm_usage_aggregate,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbSumLlmUsageDailyByPurposeConstMeta,
-      argValues: [appDir, key, profileId, startDay, endDay],
+      constMeta: kCrateApiCoreDbSetLlmFallbackChainConstMeta,
+      argValues: [appDir, key, profileIds],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbSumLlmUsageDailyByPurposeConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetLlmFallbackChainConstMeta =>
       const TaskConstMeta(
-        debugName: "db_sum_llm_usage_daily_by_purpose",
-        argNames: ["appDir", "key", "profileId", "startDay", "endDay"],
+        debugName: "db_set_llm_fallback_chain",
+        argNames: ["appDir", "key", "profileIds"],
       );
 
   @override
-  Future<Todo> crateApiCoreDbUpdateTodoDueWithScope(
+  Future<void> crateApiCoreDbSetLlmSpendingLimit(
       {required String appDir,
       required List<int> key,
-      required String todoId,
-      required PlatformInt64 dueAtMs,
-      required String scope}) {
+      required String profileId,
+      PlatformInt64? monthlyLimitUsdMicros}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(todoId, serializer);
-        sse_encode_i_64(dueAtMs, serializer);
-        sse_encode_String(scope, serializer);
+        sse_encode_String(profileId, serializer);
+        sse_encode_opt_box_autoadd_i_64(monthlyLimitUsdMicros, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 106, port: port_);
+            funcId: 133, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_todo,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpdateTodoDueWithScopeConstMeta,
-      argValues: [appDir, key, todoId, dueAtMs, scope],
+      constMeta: kCrateApiCoreDbSetLlmSpendingLimitConstMeta,
+      argValues: [appDir, key, profileId, monthlyLimitUsdMicros],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpdateTodoDueWithScopeConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetLlmSpendingLimitConstMeta =>
       const TaskConstMeta(
-        debugName: "db_update_todo_due_with_scope",
-        argNames: ["appDir", "key", "todoId", "dueAtMs", "scope"],
+        debugName: "db_set_llm_spending_limit",
+        argNames: ["appDir", "key", "profileId", "monthlyLimitUsdMicros"],
       );
 
   @override
-  Future<void> crateApiCoreDbUpdateTodoRecurrenceRuleWithScope(
+  Future<void> crateApiCoreDbSetMessageDeleted(
       {required String appDir,
       required List<int> key,
-      required String todoId,
-      required String ruleJson,
-      required String scope}) {
+      required String messageId,
+      required bool isDeleted}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(todoId, serializer);
-        sse_encode_String(ruleJson, serializer);
-        sse_encode_String(scope, serializer);
+        sse_encode_String(messageId, serializer);
+        sse_encode_bool(isDeleted, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 107, port: port_);
+            funcId: 134, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpdateTodoRecurrenceRuleWithScopeConstMeta,
-      argValues: [appDir, key, todoId, ruleJson, scope],
+      constMeta: kCrateApiCoreDbSetMessageDeletedConstMeta,
+      argValues: [appDir, key, messageId, isDeleted],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpdateTodoRecurrenceRuleWithScopeConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetMessageDeletedConstMeta =>
       const TaskConstMeta(
-        debugName: "db_update_todo_recurrence_rule_with_scope",
-        argNames: ["appDir", "key", "todoId", "ruleJson", "scope"],
+        debugName: "db_set_message_deleted",
+        argNames: ["appDir", "key", "messageId", "isDeleted"],
       );
 
   @override
-  Future<Todo> crateApiCoreDbUpdateTodoStatusWithScope(
+  Future<Todo> crateApiCoreDbSetTodoCompleteWithSubtasks(
       {required String appDir,
       required List<int> key,
       required String todoId,
-      required String newStatus,
-      String? sourceMessageId,
-      required String scope}) {
+      required bool enabled}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(todoId, serializer);
-        sse_encode_String(newStatus, serializer);
-        sse_encode_opt_String(sourceMessageId, serializer);
-        sse_encode_String(scope, serializer);
+        sse_encode_bool(enabled, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 108, port: port_);
+            funcId: 135, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_todo,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpdateTodoStatusWithScopeConstMeta,
-      argValues: [appDir, key, todoId, newStatus, sourceMessageId, scope],
+      constMeta: kCrateApiCoreDbSetTodoCompleteWithSubtasksConstMeta,
+      argValues: [appDir, key, todoId, enabled],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpdateTodoStatusWithScopeConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetTodoCompleteWithSubtasksConstMeta =>
       const TaskConstMeta(
-        debugName: "db_update_todo_status_with_scope",
-        argNames: [
-          "appDir",
-          "key",
-          "todoId",
-          "newStatus",
-          "sourceMessageId",
-          "scope"
-        ],
+        debugName: "db_set_todo_complete_with_subtasks",
+        argNames: ["appDir", "key", "todoId", "enabled"],
       );
 
   @override
-  Future<void> crateApiCoreDbUpsertAttachmentExifMetadata(
+  Future<Todo> crateApiCoreDbSetTodoParent(
       {required String appDir,
       required List<int> key,
-      required String attachmentSha256,
-      PlatformInt64? capturedAtMs,
-      double? latitude,
-      double? longitude}) {
+      required String todoId,
+      String? parentId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(attachmentSha256, serializer);
-        sse_encode_opt_box_autoadd_i_64(capturedAtMs, serializer);
-        sse_encode_opt_box_autoadd_f_64(latitude, serializer);
-        sse_encode_opt_box_autoadd_f_64(longitude, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_opt_String(parentId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 109, port: port_);
+            funcId: 136, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_todo,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpsertAttachmentExifMetadataConstMeta,
-      argValues: [
-        appDir,
-        key,
-        attachmentSha256,
-        capturedAtMs,
-        latitude,
-        longitude
-      ],
+      constMeta: kCrateApiCoreDbSetTodoParentConstMeta,
+      argValues: [appDir, key, todoId, parentId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpsertAttachmentExifMetadataConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetTodoParentConstMeta =>
       const TaskConstMeta(
-        debugName: "db_upsert_attachment_exif_metadata",
-        argNames: [
-          "appDir",
-          "key",
-          "attachmentSha256",
-          "capturedAtMs",
-          "latitude",
-          "longitude"
-        ],
+        debugName: "db_set_todo_parent",
+        argNames: ["appDir", "key", "todoId", "parentId"],
       );
 
   @override
-  Future<AttachmentVariant> crateApiCoreDbUpsertAttachmentVariant(
+  Future<Todo> crateApiCoreDbSetTodoStatus(
       {required String appDir,
       required List<int> key,
-      required String attachmentSha256,
-      required String variant,
-      required List<int> bytes,
-      required String mimeType}) {
+      required String todoId,
+      required String newStatus,
+      String? sourceMessageId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(attachmentSha256, serializer);
-        sse_encode_String(variant, serializer);
-        sse_encode_list_prim_u_8_loose(bytes, serializer);
-        sse_encode_String(mimeType, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(newStatus, serializer);
+        sse_encode_opt_String(sourceMessageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 110, port: port_);
+            funcId: 137, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_attachment_variant,
+        decodeSuccessData: sse_decode_todo,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpsertAttachmentVariantConstMeta,
-      argValues: [appDir, key, attachmentSha256, variant, bytes, mimeType],
+      constMeta: kCrateApiCoreDbSetTodoStatusConstMeta,
+      argValues: [appDir, key, todoId, newStatus, sourceMessageId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpsertAttachmentVariantConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSetTodoStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "db_upsert_attachment_variant",
-        argNames: [
-          "appDir",
-          "key",
-          "attachmentSha256",
-          "variant",
-          "bytes",
-          "mimeType"
-        ],
+        debugName: "db_set_todo_status",
+        argNames: ["appDir", "key", "todoId", "newStatus", "sourceMessageId"],
       );
 
   @override
-  Future<Event> crateApiCoreDbUpsertEvent(
+  Future<Todo> crateApiCoreDbSetTodoTimezone(
       {required String appDir,
       required List<int> key,
-      required String id,
-      required String title,
-      required PlatformInt64 startAtMs,
-      required PlatformInt64 endAtMs,
-      required String tz,
-      String? sourceEntryId}) {
+      required String todoId,
+      String? tz}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(id, serializer);
-        sse_encode_String(title, serializer);
-        sse_encode_i_64(startAtMs, serializer);
-        sse_encode_i_64(endAtMs, serializer);
-        sse_encode_String(tz, serializer);
-        sse_encode_opt_String(sourceEntryId, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_opt_String(tz, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 111, port: port_);
+            funcId: 138, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_event,
+        decodeSuccessData: sse_decode_todo,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpsertEventConstMeta,
-      argValues: [
-        appDir,
-        key,
-        id,
-        title,
-        startAtMs,
-        endAtMs,
-        tz,
-        sourceEntryId
-      ],
+      constMeta: kCrateApiCoreDbSetTodoTimezoneConstMeta,
+      argValues: [appDir, key, todoId, tz],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpsertEventConstMeta => const TaskConstMeta(
-        debugName: "db_upsert_event",
-        argNames: [
-          "appDir",
-          "key",
-          "id",
-          "title",
-          "startAtMs",
-          "endAtMs",
-          "tz",
-          "sourceEntryId"
-        ],
+  TaskConstMeta get kCrateApiCoreDbSetTodoTimezoneConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_set_todo_timezone",
+        argNames: ["appDir", "key", "todoId", "tz"],
       );
 
   @override
-  Future<Todo> crateApiCoreDbUpsertTodo(
-      {required String appDir,
-      required List<int> key,
-      required String id,
-      required String title,
-      PlatformInt64? dueAtMs,
-      required String status,
-      String? sourceEntryId,
-      PlatformInt64? reviewStage,
-      PlatformInt64? nextReviewAtMs,
-      PlatformInt64? lastReviewAtMs}) {
+  Future<List<LlmMonthlyCost>> crateApiCoreDbSumLlmCostMonthly(
+      {required String appDir, required List<int> key, required String month}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(id, serializer);
-        sse_encode_String(title, serializer);
-        sse_encode_opt_box_autoadd_i_64(dueAtMs, serializer);
-        sse_encode_String(status, serializer);
-        sse_encode_opt_String(sourceEntryId, serializer);
-        sse_encode_opt_box_autoadd_i_64(reviewStage, serializer);
-        sse_encode_opt_box_autoadd_i_64(nextReviewAtMs, serializer);
-        sse_encode_opt_box_autoadd_i_64(lastReviewAtMs, serializer);
+        sse_encode_String(month, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 112, port: port_);
+            funcId: 139, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_todo,
+        decodeSuccessData: sse_decode_list_llm_monthly_cost,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpsertTodoConstMeta,
-      argValues: [
-        appDir,
-        key,
-        id,
-        title,
-        dueAtMs,
-        status,
-        sourceEntryId,
-        reviewStage,
-        nextReviewAtMs,
-        lastReviewAtMs
-      ],
+      constMeta: kCrateApiCoreDbSumLlmCostMonthlyConstMeta,
+      argValues: [appDir, key, month],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpsertTodoConstMeta => const TaskConstMeta(
-        debugName: "db_upsert_todo",
-        argNames: [
-          "appDir",
-          "key",
-          "id",
-          "title",
-          "dueAtMs",
-          "status",
-          "sourceEntryId",
-          "reviewStage",
-          "nextReviewAtMs",
-          "lastReviewAtMs"
-        ],
+  TaskConstMeta get kCrateApiCoreDbSumLlmCostMonthlyConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_sum_llm_cost_monthly",
+        argNames: ["appDir", "key", "month"],
       );
 
   @override
-  Future<void> crateApiCoreDbUpsertTodoRecurrence(
+  Future<List<LlmUsageAggregate>> crateApiCoreDbSumLlmUsageDailyByPurpose(
       {required String appDir,
       required List<int> key,
-      required String todoId,
-      required String seriesId,
-      required String ruleJson}) {
+      required String profileId,
+      required String startDay,
+      required String endDay}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(todoId, serializer);
-        sse_encode_String(seriesId, serializer);
-        sse_encode_String(ruleJson, serializer);
+        sse_encode_String(profileId, serializer);
+        sse_encode_String(startDay, serializer);
+        sse_encode_String(endDay, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 113, port: port_);
+            funcId: 140, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_llm_usage_aggregate,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreDbUpsertTodoRecurrenceConstMeta,
-      argValues: [appDir, key, todoId, seriesId, ruleJson],
+      constMeta: kCrateApiCoreDbSumLlmUsageDailyByPurposeConstMeta,
+      argValues: [appDir, key, profileId, startDay, endDay],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreDbUpsertTodoRecurrenceConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbSumLlmUsageDailyByPurposeConstMeta =>
       const TaskConstMeta(
-        debugName: "db_upsert_todo_recurrence",
-        argNames: ["appDir", "key", "todoId", "seriesId", "ruleJson"],
+        debugName: "db_sum_llm_usage_daily_by_purpose",
+        argNames: ["appDir", "key", "profileId", "startDay", "endDay"],
       );
 
   @override
-  Future<String> crateApiCoreGeoReverseCloudGateway(
-      {required String gatewayBaseUrl,
-      required String firebaseIdToken,
-      required double lat,
-      required double lon,
-      required String lang}) {
+  Future<void> crateApiCoreDbUnmarkForReview(
+      {required String appDir,
+      required List<int> key,
+      required String itemKind,
+      required String itemId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(gatewayBaseUrl, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_f_64(lat, serializer);
-        sse_encode_f_64(lon, serializer);
-        sse_encode_String(lang, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(itemKind, serializer);
+        sse_encode_String(itemId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 114, port: port_);
+            funcId: 141, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreGeoReverseCloudGatewayConstMeta,
-      argValues: [gatewayBaseUrl, firebaseIdToken, lat, lon, lang],
+      constMeta: kCrateApiCoreDbUnmarkForReviewConstMeta,
+      argValues: [appDir, key, itemKind, itemId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreGeoReverseCloudGatewayConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbUnmarkForReviewConstMeta =>
       const TaskConstMeta(
-        debugName: "geo_reverse_cloud_gateway",
-        argNames: ["gatewayBaseUrl", "firebaseIdToken", "lat", "lon", "lang"],
+        debugName: "db_unmark_for_review",
+        argNames: ["appDir", "key", "itemKind", "itemId"],
       );
 
   @override
-  Future<String> crateApiCoreMediaAnnotationCloudGateway(
-      {required String gatewayBaseUrl,
-      required String firebaseIdToken,
-      required String modelName,
-      required String lang,
-      required String mimeType,
-      required List<int> imageBytes}) {
+  Future<Todo> crateApiCoreDbUpdateTodoDueWithScope(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required PlatformInt64 dueAtMs,
+      required String scope}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(gatewayBaseUrl, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(modelName, serializer);
-        sse_encode_String(lang, serializer);
-        sse_encode_String(mimeType, serializer);
-        sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_i_64(dueAtMs, serializer);
+        sse_encode_String(scope, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 115, port: port_);
+            funcId: 142, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_todo,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreMediaAnnotationCloudGatewayConstMeta,
-      argValues: [
-        gatewayBaseUrl,
-        firebaseIdToken,
-        modelName,
-        lang,
-        mimeType,
-        imageBytes
-      ],
+      constMeta: kCrateApiCoreDbUpdateTodoDueWithScopeConstMeta,
+      argValues: [appDir, key, todoId, dueAtMs, scope],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreMediaAnnotationCloudGatewayConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbUpdateTodoDueWithScopeConstMeta =>
       const TaskConstMeta(
-        debugName: "media_annotation_cloud_gateway",
-        argNames: [
-          "gatewayBaseUrl",
-          "firebaseIdToken",
-          "modelName",
-          "lang",
-          "mimeType",
-          "imageBytes"
-        ],
+        debugName: "db_update_todo_due_with_scope",
+        argNames: ["appDir", "key", "todoId", "dueAtMs", "scope"],
       );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStream(
+  Future<void> crateApiCoreDbUpdateTodoRecurrenceRuleWithScope(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required String localDay}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required String todoId,
+      required String ruleJson,
+      required String scope}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_String(localDay, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(ruleJson, serializer);
+        sse_encode_String(scope, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 116, port: port_);
+            funcId: 143, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamConstMeta,
-      argValues: [
-        appDir,
-        key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        localDay,
-        sink
-      ],
+      constMeta: kCrateApiCoreDbUpdateTodoRecurrenceRuleWithScopeConstMeta,
+      argValues: [appDir, key, todoId, ruleJson, scope],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateApiCoreRagAskAiStreamConstMeta => const TaskConstMeta(
-        debugName: "rag_ask_ai_stream",
+  TaskConstMeta get kCrateApiCoreDbUpdateTodoRecurrenceRuleWithScopeConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_update_todo_recurrence_rule_with_scope",
+        argNames: ["appDir", "key", "todoId", "ruleJson", "scope"],
+      );
+
+  @override
+  Future<Todo> crateApiCoreDbUpdateTodoStatusWithScope(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required String newStatus,
+      String? sourceMessageId,
+      required String scope}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(newStatus, serializer);
+        sse_encode_opt_String(sourceMessageId, serializer);
+        sse_encode_String(scope, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 144, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_todo,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbUpdateTodoStatusWithScopeConstMeta,
+      argValues: [appDir, key, todoId, newStatus, sourceMessageId, scope],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbUpdateTodoStatusWithScopeConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_update_todo_status_with_scope",
         argNames: [
           "appDir",
           "key",
-          "conversationId",
-          "question",
-          "topK",
-          "thisThreadOnly",
-          "localDay",
-          "sink"
+          "todoId",
+          "newStatus",
+          "sourceMessageId",
+          "scope"
         ],
       );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamCloudGateway(
+  Future<void> crateApiCoreDbUpsertAttachmentExifMetadata(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required String gatewayBaseUrl,
-      required String firebaseIdToken,
-      required String modelName}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required String attachmentSha256,
+      PlatformInt64? capturedAtMs,
+      double? latitude,
+      double? longitude}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_String(gatewayBaseUrl, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(modelName, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_String(attachmentSha256, serializer);
+        sse_encode_opt_box_autoadd_i_64(capturedAtMs, serializer);
+        sse_encode_opt_box_autoadd_f_64(latitude, serializer);
+        sse_encode_opt_box_autoadd_f_64(longitude, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 117, port: port_);
+            funcId: 145, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayConstMeta,
+      constMeta: kCrateApiCoreDbUpsertAttachmentExifMetadataConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        gatewayBaseUrl,
-        firebaseIdToken,
-        modelName,
-        sink
+        attachmentSha256,
+        capturedAtMs,
+        latitude,
+        longitude
       ],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateApiCoreRagAskAiStreamCloudGatewayConstMeta =>
+  TaskConstMeta get kCrateApiCoreDbUpsertAttachmentExifMetadataConstMeta =>
       const TaskConstMeta(
-        debugName: "rag_ask_ai_stream_cloud_gateway",
+        debugName: "db_upsert_attachment_exif_metadata",
         argNames: [
           "appDir",
           "key",
-          "conversationId",
-          "question",
-          "topK",
-          "thisThreadOnly",
-          "gatewayBaseUrl",
-          "firebaseIdToken",
-          "modelName",
-          "sink"
+          "attachmentSha256",
+          "capturedAtMs",
+          "latitude",
+          "longitude"
         ],
       );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayTimeWindow(
+  Future<AttachmentVariant> crateApiCoreDbUpsertAttachmentVariant(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required PlatformInt64 timeStartMs,
-      required PlatformInt64 timeEndMs,
-      required String gatewayBaseUrl,
-      required String firebaseIdToken,
-      required String modelName}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required String attachmentSha256,
+      required String variant,
+      required List<int> bytes,
+      required String mimeType}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_i_64(timeStartMs, serializer);
-        sse_encode_i_64(timeEndMs, serializer);
-        sse_encode_String(gatewayBaseUrl, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(modelName, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_String(attachmentSha256, serializer);
+        sse_encode_String(variant, serializer);
+        sse_encode_list_prim_u_8_loose(bytes, serializer);
+        sse_encode_String(mimeType, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 118, port: port_);
+            funcId: 146, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_attachment_variant,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayTimeWindowConstMeta,
-      argValues: [
-        appDir,
-        key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        timeStartMs,
-        timeEndMs,
-        gatewayBaseUrl,
-        firebaseIdToken,
-        modelName,
-        sink
-      ],
+      constMeta: kCrateApiCoreDbUpsertAttachmentVariantConstMeta,
+      argValues: [appDir, key, attachmentSha256, variant, bytes, mimeType],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta
-      get kCrateApiCoreRagAskAiStreamCloudGatewayTimeWindowConstMeta =>
-          const TaskConstMeta(
-            debugName: "rag_ask_ai_stream_cloud_gateway_time_window",
-            argNames: [
-              "appDir",
-              "key",
-              "conversationId",
-              "question",
-              "topK",
-              "thisThreadOnly",
-              "timeStartMs",
-              "timeEndMs",
-              "gatewayBaseUrl",
-              "firebaseIdToken",
-              "modelName",
-              "sink"
-            ],
-          );
+  TaskConstMeta get kCrateApiCoreDbUpsertAttachmentVariantConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_attachment_variant",
+        argNames: [
+          "appDir",
+          "key",
+          "attachmentSha256",
+          "variant",
+          "bytes",
+          "mimeType"
+        ],
+      );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddings(
+  Future<Event> crateApiCoreDbUpsertEvent(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required String gatewayBaseUrl,
-      required String firebaseIdToken,
-      required String modelName,
-      required String embeddingsModelName}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required String id,
+      required String title,
+      required PlatformInt64 startAtMs,
+      required PlatformInt64 endAtMs,
+      required String tz,
+      String? sourceEntryId}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_String(gatewayBaseUrl, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(modelName, serializer);
-        sse_encode_String(embeddingsModelName, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(title, serializer);
+        sse_encode_i_64(startAtMs, serializer);
+        sse_encode_i_64(endAtMs, serializer);
+        sse_encode_String(tz, serializer);
+        sse_encode_opt_String(sourceEntryId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 119, port: port_);
+            funcId: 147, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_event,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsConstMeta,
+      constMeta: kCrateApiCoreDbUpsertEventConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        gatewayBaseUrl,
-        firebaseIdToken,
-        modelName,
-        embeddingsModelName,
-        sink
+        id,
+        title,
+        startAtMs,
+        endAtMs,
+        tz,
+        sourceEntryId
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbUpsertEventConstMeta => const TaskConstMeta(
+        debugName: "db_upsert_event",
+        argNames: [
+          "appDir",
+          "key",
+          "id",
+          "title",
+          "startAtMs",
+          "endAtMs",
+          "tz",
+          "sourceEntryId"
+        ],
+      );
+
+  @override
+  Future<LlmModelPrice> crateApiCoreDbUpsertLlmModelPrice(
+      {required String appDir,
+      required List<int> key,
+      required String providerType,
+      required String modelName,
+      required double inputUsdPerMillionTokens,
+      required double outputUsdPerMillionTokens}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(providerType, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_f_64(inputUsdPerMillionTokens, serializer);
+        sse_encode_f_64(outputUsdPerMillionTokens, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 148, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_llm_model_price,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbUpsertLlmModelPriceConstMeta,
+      argValues: [
+        appDir,
+        key,
+        providerType,
+        modelName,
+        inputUsdPerMillionTokens,
+        outputUsdPerMillionTokens
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbUpsertLlmModelPriceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_llm_model_price",
+        argNames: [
+          "appDir",
+          "key",
+          "providerType",
+          "modelName",
+          "inputUsdPerMillionTokens",
+          "outputUsdPerMillionTokens"
+        ],
+      );
+
+  @override
+  Future<Todo> crateApiCoreDbUpsertTodo(
+      {required String appDir,
+      required List<int> key,
+      required String id,
+      required String title,
+      PlatformInt64? dueAtMs,
+      required String status,
+      String? sourceEntryId,
+      PlatformInt64? reviewStage,
+      PlatformInt64? nextReviewAtMs,
+      PlatformInt64? lastReviewAtMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(title, serializer);
+        sse_encode_opt_box_autoadd_i_64(dueAtMs, serializer);
+        sse_encode_String(status, serializer);
+        sse_encode_opt_String(sourceEntryId, serializer);
+        sse_encode_opt_box_autoadd_i_64(reviewStage, serializer);
+        sse_encode_opt_box_autoadd_i_64(nextReviewAtMs, serializer);
+        sse_encode_opt_box_autoadd_i_64(lastReviewAtMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 149, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_todo,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbUpsertTodoConstMeta,
+      argValues: [
+        appDir,
+        key,
+        id,
+        title,
+        dueAtMs,
+        status,
+        sourceEntryId,
+        reviewStage,
+        nextReviewAtMs,
+        lastReviewAtMs
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbUpsertTodoConstMeta => const TaskConstMeta(
+        debugName: "db_upsert_todo",
+        argNames: [
+          "appDir",
+          "key",
+          "id",
+          "title",
+          "dueAtMs",
+          "status",
+          "sourceEntryId",
+          "reviewStage",
+          "nextReviewAtMs",
+          "lastReviewAtMs"
+        ],
+      );
+
+  @override
+  Future<void> crateApiCoreDbUpsertTodoRecurrence(
+      {required String appDir,
+      required List<int> key,
+      required String todoId,
+      required String seriesId,
+      required String ruleJson}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(todoId, serializer);
+        sse_encode_String(seriesId, serializer);
+        sse_encode_String(ruleJson, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 150, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreDbUpsertTodoRecurrenceConstMeta,
+      argValues: [appDir, key, todoId, seriesId, ruleJson],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreDbUpsertTodoRecurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_todo_recurrence",
+        argNames: ["appDir", "key", "todoId", "seriesId", "ruleJson"],
+      );
+
+  @override
+  Future<void> crateApiCoreEmbeddingInstallReranker({required String appDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 151, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreEmbeddingInstallRerankerConstMeta,
+      argValues: [appDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreEmbeddingInstallRerankerConstMeta =>
+      const TaskConstMeta(
+        debugName: "embedding_install_reranker",
+        argNames: ["appDir"],
+      );
+
+  @override
+  Future<bool> crateApiCoreEmbeddingIsRerankerInstalled(
+      {required String appDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 152, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCoreEmbeddingIsRerankerInstalledConstMeta,
+      argValues: [appDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreEmbeddingIsRerankerInstalledConstMeta =>
+      const TaskConstMeta(
+        debugName: "embedding_is_reranker_installed",
+        argNames: ["appDir"],
+      );
+
+  @override
+  Future<String> crateApiCoreGeoReverseCloudGateway(
+      {required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required double lat,
+      required double lon,
+      required String lang}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_f_64(lat, serializer);
+        sse_encode_f_64(lon, serializer);
+        sse_encode_String(lang, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 153, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreGeoReverseCloudGatewayConstMeta,
+      argValues: [gatewayBaseUrl, firebaseIdToken, lat, lon, lang],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreGeoReverseCloudGatewayConstMeta =>
+      const TaskConstMeta(
+        debugName: "geo_reverse_cloud_gateway",
+        argNames: ["gatewayBaseUrl", "firebaseIdToken", "lat", "lon", "lang"],
+      );
+
+  @override
+  Future<String> crateApiCoreMediaAnnotationCloudGateway(
+      {required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName,
+      required String lang,
+      required String mimeType,
+      required List<int> imageBytes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_String(lang, serializer);
+        sse_encode_String(mimeType, serializer);
+        sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 154, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreMediaAnnotationCloudGatewayConstMeta,
+      argValues: [
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        lang,
+        mimeType,
+        imageBytes
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreMediaAnnotationCloudGatewayConstMeta =>
+      const TaskConstMeta(
+        debugName: "media_annotation_cloud_gateway",
+        argNames: [
+          "gatewayBaseUrl",
+          "firebaseIdToken",
+          "modelName",
+          "lang",
+          "mimeType",
+          "imageBytes"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiAgentStream(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required String localDay}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 155, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiAgentStreamConstMeta,
+      argValues: [appDir, key, conversationId, question, localDay, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiAgentStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "rag_ask_ai_agent_stream",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "localDay",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiAgentStreamCloudGateway(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 156, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiAgentStreamCloudGatewayConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiAgentStreamCloudGatewayConstMeta =>
+      const TaskConstMeta(
+        debugName: "rag_ask_ai_agent_stream_cloud_gateway",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "gatewayBaseUrl",
+          "firebaseIdToken",
+          "modelName",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStream(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required String localDay,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 157, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        localDay,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiStreamConstMeta => const TaskConstMeta(
+        debugName: "rag_ask_ai_stream",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "topK",
+          "thisThreadOnly",
+          "localDay",
+          "tz",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamCloudGateway(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 158, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiStreamCloudGatewayConstMeta =>
+      const TaskConstMeta(
+        debugName: "rag_ask_ai_stream_cloud_gateway",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "topK",
+          "thisThreadOnly",
+          "gatewayBaseUrl",
+          "firebaseIdToken",
+          "modelName",
+          "tz",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayTimeWindow(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required PlatformInt64 timeStartMs,
+      required PlatformInt64 timeEndMs,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_i_64(timeStartMs, serializer);
+        sse_encode_i_64(timeEndMs, serializer);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 159, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayTimeWindowConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        timeStartMs,
+        timeEndMs,
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiCoreRagAskAiStreamCloudGatewayTimeWindowConstMeta =>
+          const TaskConstMeta(
+            debugName: "rag_ask_ai_stream_cloud_gateway_time_window",
+            argNames: [
+              "appDir",
+              "key",
+              "conversationId",
+              "question",
+              "topK",
+              "thisThreadOnly",
+              "timeStartMs",
+              "timeEndMs",
+              "gatewayBaseUrl",
+              "firebaseIdToken",
+              "modelName",
+              "tz",
+              "sink"
+            ],
+          );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddings(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName,
+      required String embeddingsModelName,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_String(embeddingsModelName, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 160, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        embeddingsModelName,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsConstMeta =>
+          const TaskConstMeta(
+            debugName: "rag_ask_ai_stream_cloud_gateway_with_embeddings",
+            argNames: [
+              "appDir",
+              "key",
+              "conversationId",
+              "question",
+              "topK",
+              "thisThreadOnly",
+              "gatewayBaseUrl",
+              "firebaseIdToken",
+              "modelName",
+              "embeddingsModelName",
+              "tz",
+              "sink"
+            ],
+          );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required PlatformInt64 timeStartMs,
+      required PlatformInt64 timeEndMs,
+      required String gatewayBaseUrl,
+      required String firebaseIdToken,
+      required String modelName,
+      required String embeddingsModelName,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_i_64(timeStartMs, serializer);
+        sse_encode_i_64(timeEndMs, serializer);
+        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(modelName, serializer);
+        sse_encode_String(embeddingsModelName, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 161, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindowConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        timeStartMs,
+        timeEndMs,
+        gatewayBaseUrl,
+        firebaseIdToken,
+        modelName,
+        embeddingsModelName,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindowConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window",
+            argNames: [
+              "appDir",
+              "key",
+              "conversationId",
+              "question",
+              "topK",
+              "thisThreadOnly",
+              "timeStartMs",
+              "timeEndMs",
+              "gatewayBaseUrl",
+              "firebaseIdToken",
+              "modelName",
+              "embeddingsModelName",
+              "tz",
+              "sink"
+            ],
+          );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamTimeWindow(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required PlatformInt64 timeStartMs,
+      required PlatformInt64 timeEndMs,
+      required String localDay,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_i_64(timeStartMs, serializer);
+        sse_encode_i_64(timeEndMs, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 162, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamTimeWindowConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        timeStartMs,
+        timeEndMs,
+        localDay,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiStreamTimeWindowConstMeta =>
+      const TaskConstMeta(
+        debugName: "rag_ask_ai_stream_time_window",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "topK",
+          "thisThreadOnly",
+          "timeStartMs",
+          "timeEndMs",
+          "localDay",
+          "tz",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddings(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required String localDay,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 163, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        localDay,
+        tz,
+        sink
+      ],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "rag_ask_ai_stream_with_brok_embeddings",
+        argNames: [
+          "appDir",
+          "key",
+          "conversationId",
+          "question",
+          "topK",
+          "thisThreadOnly",
+          "localDay",
+          "tz",
+          "sink"
+        ],
+      );
+
+  @override
+  Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindow(
+      {required String appDir,
+      required List<int> key,
+      required String conversationId,
+      required String question,
+      required int topK,
+      required bool thisThreadOnly,
+      required PlatformInt64 timeStartMs,
+      required PlatformInt64 timeEndMs,
+      required String localDay,
+      String? tz}) {
+    final sink = RustStreamSink<String>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(conversationId, serializer);
+        sse_encode_String(question, serializer);
+        sse_encode_u_32(topK, serializer);
+        sse_encode_bool(thisThreadOnly, serializer);
+        sse_encode_i_64(timeStartMs, serializer);
+        sse_encode_i_64(timeEndMs, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_opt_String(tz, serializer);
+        sse_encode_StreamSink_String_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 164, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindowConstMeta,
+      argValues: [
+        appDir,
+        key,
+        conversationId,
+        question,
+        topK,
+        thisThreadOnly,
+        timeStartMs,
+        timeEndMs,
+        localDay,
+        tz,
+        sink
       ],
       apiImpl: this,
     )));
     return sink.stream;
   }
 
-  TaskConstMeta
-      get kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsConstMeta =>
-          const TaskConstMeta(
-            debugName: "rag_ask_ai_stream_cloud_gateway_with_embeddings",
-            argNames: [
-              "appDir",
-              "key",
-              "conversationId",
-              "question",
-              "topK",
-              "thisThreadOnly",
-              "gatewayBaseUrl",
-              "firebaseIdToken",
-              "modelName",
-              "embeddingsModelName",
-              "sink"
-            ],
-          );
+  TaskConstMeta
+      get kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindowConstMeta =>
+          const TaskConstMeta(
+            debugName: "rag_ask_ai_stream_with_brok_embeddings_time_window",
+            argNames: [
+              "appDir",
+              "key",
+              "conversationId",
+              "question",
+              "topK",
+              "thisThreadOnly",
+              "timeStartMs",
+              "timeEndMs",
+              "localDay",
+              "tz",
+              "sink"
+            ],
+          );
+
+  @override
+  Future<CalDavSyncReport> crateApiCoreSyncCaldav(
+      {required String appDir,
+      required List<int> key,
+      required String collectionUrl,
+      String? username,
+      String? password,
+      String? defaultTz,
+      required PlatformInt64 nowMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(collectionUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_opt_String(defaultTz, serializer);
+        sse_encode_i_64(nowMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 165, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cal_dav_sync_report,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncCaldavConstMeta,
+      argValues: [
+        appDir,
+        key,
+        collectionUrl,
+        username,
+        password,
+        defaultTz,
+        nowMs
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncCaldavConstMeta => const TaskConstMeta(
+        debugName: "sync_caldav",
+        argNames: [
+          "appDir",
+          "key",
+          "collectionUrl",
+          "username",
+          "password",
+          "defaultTz",
+          "nowMs"
+        ],
+      );
+
+  @override
+  Future<Uint8List> crateApiCoreSyncDeriveKey({required String passphrase}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(passphrase, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 166, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncDeriveKeyConstMeta,
+      argValues: [passphrase],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncDeriveKeyConstMeta => const TaskConstMeta(
+        debugName: "sync_derive_key",
+        argNames: ["passphrase"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncLocaldirClearRemoteRoot(
+      {required String localDir, required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 167, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirClearRemoteRootConstMeta,
+      argValues: [localDir, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirClearRemoteRootConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_clear_remote_root",
+        argNames: ["localDir", "remoteRoot"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncLocaldirDownloadAttachmentBytes(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String localDir,
+      required String remoteRoot,
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(sha256, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 168, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirDownloadAttachmentBytesConstMeta,
+      argValues: [appDir, key, syncKey, localDir, remoteRoot, sha256],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirDownloadAttachmentBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_download_attachment_bytes",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "localDir",
+          "remoteRoot",
+          "sha256"
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncLocaldirPull(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String localDir,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 169, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirPullConstMeta,
+      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirPullConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_pull",
+        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncLocaldirPush(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String localDir,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 170, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirPushConstMeta,
+      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirPushConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_push",
+        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncLocaldirPushOpsOnly(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String localDir,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 171, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirPushOpsOnlyConstMeta,
+      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirPushOpsOnlyConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_push_ops_only",
+        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncLocaldirTestConnection(
+      {required String localDir, required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 172, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirTestConnectionConstMeta,
+      argValues: [localDir, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirTestConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_test_connection",
+        argNames: ["localDir", "remoteRoot"],
+      );
+
+  @override
+  Future<bool> crateApiCoreSyncLocaldirUploadAttachmentBytes(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String localDir,
+      required String remoteRoot,
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(localDir, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(sha256, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 173, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncLocaldirUploadAttachmentBytesConstMeta,
+      argValues: [appDir, key, syncKey, localDir, remoteRoot, sha256],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncLocaldirUploadAttachmentBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_localdir_upload_attachment_bytes",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "localDir",
+          "remoteRoot",
+          "sha256"
+        ],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncManagedVaultClearDevice(
+      {required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken,
+      required String deviceId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(deviceId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 174, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultClearDeviceConstMeta,
+      argValues: [baseUrl, vaultId, firebaseIdToken, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncManagedVaultClearDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_managed_vault_clear_device",
+        argNames: ["baseUrl", "vaultId", "firebaseIdToken", "deviceId"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncManagedVaultClearVault(
+      {required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 175, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultClearVaultConstMeta,
+      argValues: [baseUrl, vaultId, firebaseIdToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncManagedVaultClearVaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_managed_vault_clear_vault",
+        argNames: ["baseUrl", "vaultId", "firebaseIdToken"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncManagedVaultDownloadAttachmentBytes(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken,
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(sha256, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 176, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultDownloadAttachmentBytesConstMeta,
+      argValues: [
+        appDir,
+        key,
+        syncKey,
+        baseUrl,
+        vaultId,
+        firebaseIdToken,
+        sha256
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiCoreSyncManagedVaultDownloadAttachmentBytesConstMeta =>
+          const TaskConstMeta(
+            debugName: "sync_managed_vault_download_attachment_bytes",
+            argNames: [
+              "appDir",
+              "key",
+              "syncKey",
+              "baseUrl",
+              "vaultId",
+              "firebaseIdToken",
+              "sha256"
+            ],
+          );
+
+  @override
+  Future<BigInt> crateApiCoreSyncManagedVaultPull(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 177, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultPullConstMeta,
+      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncManagedVaultPullConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_managed_vault_pull",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "vaultId",
+          "firebaseIdToken"
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncManagedVaultPush(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 178, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultPushConstMeta,
+      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncManagedVaultPushConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_managed_vault_push",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "vaultId",
+          "firebaseIdToken"
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncManagedVaultPushOpsOnly(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      required String vaultId,
+      required String firebaseIdToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
+        sse_encode_String(firebaseIdToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 179, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncManagedVaultPushOpsOnlyConstMeta,
+      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncManagedVaultPushOpsOnlyConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_managed_vault_push_ops_only",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "vaultId",
+          "firebaseIdToken"
+        ],
+      );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindow(
+  Future<bool> crateApiCoreSyncManagedVaultUploadAttachmentBytes(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required PlatformInt64 timeStartMs,
-      required PlatformInt64 timeEndMs,
-      required String gatewayBaseUrl,
+      required List<int> syncKey,
+      required String baseUrl,
+      required String vaultId,
       required String firebaseIdToken,
-      required String modelName,
-      required String embeddingsModelName}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_i_64(timeStartMs, serializer);
-        sse_encode_i_64(timeEndMs, serializer);
-        sse_encode_String(gatewayBaseUrl, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_String(vaultId, serializer);
         sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(modelName, serializer);
-        sse_encode_String(embeddingsModelName, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_String(sha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 120, port: port_);
+            funcId: 180, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta:
-          kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindowConstMeta,
+      constMeta: kCrateApiCoreSyncManagedVaultUploadAttachmentBytesConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        timeStartMs,
-        timeEndMs,
-        gatewayBaseUrl,
+        syncKey,
+        baseUrl,
+        vaultId,
         firebaseIdToken,
-        modelName,
-        embeddingsModelName,
-        sink
+        sha256
       ],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
   TaskConstMeta
-      get kCrateApiCoreRagAskAiStreamCloudGatewayWithEmbeddingsTimeWindowConstMeta =>
+      get kCrateApiCoreSyncManagedVaultUploadAttachmentBytesConstMeta =>
           const TaskConstMeta(
-            debugName:
-                "rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window",
+            debugName: "sync_managed_vault_upload_attachment_bytes",
             argNames: [
               "appDir",
               "key",
-              "conversationId",
-              "question",
-              "topK",
-              "thisThreadOnly",
-              "timeStartMs",
-              "timeEndMs",
-              "gatewayBaseUrl",
+              "syncKey",
+              "baseUrl",
+              "vaultId",
               "firebaseIdToken",
-              "modelName",
-              "embeddingsModelName",
-              "sink"
+              "sha256"
             ],
           );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamTimeWindow(
+  Future<void> crateApiCoreSyncWebdavClearRemoteRoot(
+      {required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 181, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncWebdavClearRemoteRootConstMeta,
+      argValues: [baseUrl, username, password, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncWebdavClearRemoteRootConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_webdav_clear_remote_root",
+        argNames: ["baseUrl", "username", "password", "remoteRoot"],
+      );
+
+  @override
+  Future<void> crateApiCoreSyncWebdavDownloadAttachmentBytes(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot,
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(sha256, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 182, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncWebdavDownloadAttachmentBytesConstMeta,
+      argValues: [
+        appDir,
+        key,
+        syncKey,
+        baseUrl,
+        username,
+        password,
+        remoteRoot,
+        sha256
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncWebdavDownloadAttachmentBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_webdav_download_attachment_bytes",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "username",
+          "password",
+          "remoteRoot",
+          "sha256"
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncWebdavPull(
+      {required String appDir,
+      required List<int> key,
+      required List<int> syncKey,
+      required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 183, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncWebdavPullConstMeta,
+      argValues: [
+        appDir,
+        key,
+        syncKey,
+        baseUrl,
+        username,
+        password,
+        remoteRoot
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncWebdavPullConstMeta => const TaskConstMeta(
+        debugName: "sync_webdav_pull",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "username",
+          "password",
+          "remoteRoot"
+        ],
+      );
+
+  @override
+  Future<BigInt> crateApiCoreSyncWebdavPush(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required PlatformInt64 timeStartMs,
-      required PlatformInt64 timeEndMs,
-      required String localDay}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required List<int> syncKey,
+      required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_i_64(timeStartMs, serializer);
-        sse_encode_i_64(timeEndMs, serializer);
-        sse_encode_String(localDay, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 121, port: port_);
+            funcId: 184, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_u_64,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamTimeWindowConstMeta,
+      constMeta: kCrateApiCoreSyncWebdavPushConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        timeStartMs,
-        timeEndMs,
-        localDay,
-        sink
+        syncKey,
+        baseUrl,
+        username,
+        password,
+        remoteRoot
       ],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateApiCoreRagAskAiStreamTimeWindowConstMeta =>
-      const TaskConstMeta(
-        debugName: "rag_ask_ai_stream_time_window",
+  TaskConstMeta get kCrateApiCoreSyncWebdavPushConstMeta => const TaskConstMeta(
+        debugName: "sync_webdav_push",
         argNames: [
           "appDir",
           "key",
-          "conversationId",
-          "question",
-          "topK",
-          "thisThreadOnly",
-          "timeStartMs",
-          "timeEndMs",
-          "localDay",
-          "sink"
+          "syncKey",
+          "baseUrl",
+          "username",
+          "password",
+          "remoteRoot"
         ],
       );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddings(
+  Future<BigInt> crateApiCoreSyncWebdavPushOpsOnly(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required String localDay}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required List<int> syncKey,
+      required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_String(localDay, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 122, port: port_);
+            funcId: 185, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_u_64,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsConstMeta,
+      constMeta: kCrateApiCoreSyncWebdavPushOpsOnlyConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        localDay,
-        sink
+        syncKey,
+        baseUrl,
+        username,
+        password,
+        remoteRoot
       ],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsConstMeta =>
+  TaskConstMeta get kCrateApiCoreSyncWebdavPushOpsOnlyConstMeta =>
       const TaskConstMeta(
-        debugName: "rag_ask_ai_stream_with_brok_embeddings",
+        debugName: "sync_webdav_push_ops_only",
         argNames: [
           "appDir",
           "key",
-          "conversationId",
-          "question",
-          "topK",
-          "thisThreadOnly",
-          "localDay",
-          "sink"
+          "syncKey",
+          "baseUrl",
+          "username",
+          "password",
+          "remoteRoot"
         ],
       );
 
   @override
-  Stream<String> crateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindow(
+  Future<void> crateApiCoreSyncWebdavTestConnection(
+      {required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 186, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCoreSyncWebdavTestConnectionConstMeta,
+      argValues: [baseUrl, username, password, remoteRoot],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCoreSyncWebdavTestConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_webdav_test_connection",
+        argNames: ["baseUrl", "username", "password", "remoteRoot"],
+      );
+
+  @override
+  Future<bool> crateApiCoreSyncWebdavUploadAttachmentBytes(
       {required String appDir,
       required List<int> key,
-      required String conversationId,
-      required String question,
-      required int topK,
-      required bool thisThreadOnly,
-      required PlatformInt64 timeStartMs,
-      required PlatformInt64 timeEndMs,
-      required String localDay}) {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
+      required List<int> syncKey,
+      required String baseUrl,
+      String? username,
+      String? password,
+      required String remoteRoot,
+      required String sha256}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(conversationId, serializer);
-        sse_encode_String(question, serializer);
-        sse_encode_u_32(topK, serializer);
-        sse_encode_bool(thisThreadOnly, serializer);
-        sse_encode_i_64(timeStartMs, serializer);
-        sse_encode_i_64(timeEndMs, serializer);
-        sse_encode_String(localDay, serializer);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
+        sse_encode_list_prim_u_8_loose(syncKey, serializer);
+        sse_encode_String(baseUrl, serializer);
+        sse_encode_opt_String(username, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(sha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 123, port: port_);
+            funcId: 187, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta:
-          kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindowConstMeta,
+      constMeta: kCrateApiCoreSyncWebdavUploadAttachmentBytesConstMeta,
       argValues: [
         appDir,
         key,
-        conversationId,
-        question,
-        topK,
-        thisThreadOnly,
-        timeStartMs,
-        timeEndMs,
-        localDay,
-        sink
+        syncKey,
+        baseUrl,
+        username,
+        password,
+        remoteRoot,
+        sha256
       ],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta
-      get kCrateApiCoreRagAskAiStreamWithBrokEmbeddingsTimeWindowConstMeta =>
-          const TaskConstMeta(
-            debugName: "rag_ask_ai_stream_with_brok_embeddings_time_window",
-            argNames: [
-              "appDir",
-              "key",
-              "conversationId",
-              "question",
-              "topK",
-              "thisThreadOnly",
-              "timeStartMs",
-              "timeEndMs",
-              "localDay",
-              "sink"
-            ],
-          );
+  TaskConstMeta get kCrateApiCoreSyncWebdavUploadAttachmentBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_webdav_upload_attachment_bytes",
+        argNames: [
+          "appDir",
+          "key",
+          "syncKey",
+          "baseUrl",
+          "username",
+          "password",
+          "remoteRoot",
+          "sha256"
+        ],
+      );
 
   @override
-  Future<Uint8List> crateApiCoreSyncDeriveKey({required String passphrase}) {
+  Future<OcrPayload> crateApiDesktopMediaDesktopOcrImage(
+      {required List<int> bytes, required String languageHints}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(passphrase, serializer);
+        sse_encode_list_prim_u_8_loose(bytes, serializer);
+        sse_encode_String(languageHints, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 124, port: port_);
+            funcId: 188, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeSuccessData: sse_decode_ocr_payload,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncDeriveKeyConstMeta,
-      argValues: [passphrase],
+      constMeta: kCrateApiDesktopMediaDesktopOcrImageConstMeta,
+      argValues: [bytes, languageHints],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncDeriveKeyConstMeta => const TaskConstMeta(
-        debugName: "sync_derive_key",
-        argNames: ["passphrase"],
+  TaskConstMeta get kCrateApiDesktopMediaDesktopOcrImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "desktop_ocr_image",
+        argNames: ["bytes", "languageHints"],
       );
 
   @override
-  Future<void> crateApiCoreSyncLocaldirClearRemoteRoot(
-      {required String localDir, required String remoteRoot}) {
+  Future<OcrPayload> crateApiDesktopMediaDesktopOcrPdf(
+      {required List<int> bytes,
+      required int maxPages,
+      required int dpi,
+      required String languageHints}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_list_prim_u_8_loose(bytes, serializer);
+        sse_encode_u_32(maxPages, serializer);
+        sse_encode_u_32(dpi, serializer);
+        sse_encode_String(languageHints, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 125, port: port_);
+            funcId: 189, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_ocr_payload,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirClearRemoteRootConstMeta,
-      argValues: [localDir, remoteRoot],
+      constMeta: kCrateApiDesktopMediaDesktopOcrPdfConstMeta,
+      argValues: [bytes, maxPages, dpi, languageHints],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirClearRemoteRootConstMeta =>
+  TaskConstMeta get kCrateApiDesktopMediaDesktopOcrPdfConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_localdir_clear_remote_root",
-        argNames: ["localDir", "remoteRoot"],
+        debugName: "desktop_ocr_pdf",
+        argNames: ["bytes", "maxPages", "dpi", "languageHints"],
       );
 
   @override
-  Future<void> crateApiCoreSyncLocaldirDownloadAttachmentBytes(
+  Future<IcsExportSummary> crateApiExportDbExportIcs(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String localDir,
-      required String remoteRoot,
-      required String sha256}) {
+      required String outPath,
+      required PlatformInt64 nowMs}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
-        sse_encode_String(sha256, serializer);
+        sse_encode_String(outPath, serializer);
+        sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 126, port: port_);
+            funcId: 190, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_ics_export_summary,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirDownloadAttachmentBytesConstMeta,
-      argValues: [appDir, key, syncKey, localDir, remoteRoot, sha256],
+      constMeta: kCrateApiExportDbExportIcsConstMeta,
+      argValues: [appDir, key, outPath, nowMs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirDownloadAttachmentBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_localdir_download_attachment_bytes",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "localDir",
-          "remoteRoot",
-          "sha256"
-        ],
+  TaskConstMeta get kCrateApiExportDbExportIcsConstMeta => const TaskConstMeta(
+        debugName: "db_export_ics",
+        argNames: ["appDir", "key", "outPath", "nowMs"],
       );
 
   @override
-  Future<BigInt> crateApiCoreSyncLocaldirPull(
+  Future<JsonlExportSummary> crateApiExportDbExportVaultJsonl(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String localDir,
-      required String remoteRoot}) {
+      required String outDir,
+      required PlatformInt64 nowMs}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(outDir, serializer);
+        sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 127, port: port_);
+            funcId: 191, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_jsonl_export_summary,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirPullConstMeta,
-      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      constMeta: kCrateApiExportDbExportVaultJsonlConstMeta,
+      argValues: [appDir, key, outDir, nowMs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirPullConstMeta =>
+  TaskConstMeta get kCrateApiExportDbExportVaultJsonlConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_localdir_pull",
-        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+        debugName: "db_export_vault_jsonl",
+        argNames: ["appDir", "key", "outDir", "nowMs"],
       );
 
   @override
-  Future<BigInt> crateApiCoreSyncLocaldirPush(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String localDir,
-      required String remoteRoot}) {
+  Future<JsonlExportVerification> crateApiExportVerifyVaultJsonlExport(
+      {required String exportDir}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(exportDir, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 128, port: port_);
+            funcId: 192, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_jsonl_export_verification,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirPushConstMeta,
-      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      constMeta: kCrateApiExportVerifyVaultJsonlExportConstMeta,
+      argValues: [exportDir],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirPushConstMeta =>
+  TaskConstMeta get kCrateApiExportVerifyVaultJsonlExportConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_localdir_push",
-        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+        debugName: "verify_vault_jsonl_export",
+        argNames: ["exportDir"],
       );
 
   @override
-  Future<BigInt> crateApiCoreSyncLocaldirPushOpsOnly(
+  Future<ImportReport> crateApiImportDbImportIcs(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String localDir,
-      required String remoteRoot}) {
+      required String icsPath,
+      String? defaultTz,
+      required bool preview}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(icsPath, serializer);
+        sse_encode_opt_String(defaultTz, serializer);
+        sse_encode_bool(preview, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 129, port: port_);
+            funcId: 193, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_import_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirPushOpsOnlyConstMeta,
-      argValues: [appDir, key, syncKey, localDir, remoteRoot],
+      constMeta: kCrateApiImportDbImportIcsConstMeta,
+      argValues: [appDir, key, icsPath, defaultTz, preview],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirPushOpsOnlyConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_localdir_push_ops_only",
-        argNames: ["appDir", "key", "syncKey", "localDir", "remoteRoot"],
+  TaskConstMeta get kCrateApiImportDbImportIcsConstMeta => const TaskConstMeta(
+        debugName: "db_import_ics",
+        argNames: ["appDir", "key", "icsPath", "defaultTz", "preview"],
       );
 
   @override
-  Future<void> crateApiCoreSyncLocaldirTestConnection(
-      {required String localDir, required String remoteRoot}) {
+  Future<ImportReport> crateApiImportDbImportMarkdownFolder(
+      {required String appDir,
+      required List<int> key,
+      required String rootDir,
+      required String lang,
+      String? defaultTz}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(lang, serializer);
+        sse_encode_opt_String(defaultTz, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 130, port: port_);
+            funcId: 194, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_import_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirTestConnectionConstMeta,
-      argValues: [localDir, remoteRoot],
+      constMeta: kCrateApiImportDbImportMarkdownFolderConstMeta,
+      argValues: [appDir, key, rootDir, lang, defaultTz],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirTestConnectionConstMeta =>
+  TaskConstMeta get kCrateApiImportDbImportMarkdownFolderConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_localdir_test_connection",
-        argNames: ["localDir", "remoteRoot"],
+        debugName: "db_import_markdown_folder",
+        argNames: ["appDir", "key", "rootDir", "lang", "defaultTz"],
       );
 
   @override
-  Future<bool> crateApiCoreSyncLocaldirUploadAttachmentBytes(
+  Future<ImportReport> crateApiImportDbImportTelegramExport(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String localDir,
-      required String remoteRoot,
-      required String sha256}) {
+      required String jsonPath,
+      required String lang,
+      String? defaultTz,
+      required bool preview}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(localDir, serializer);
-        sse_encode_String(remoteRoot, serializer);
-        sse_encode_String(sha256, serializer);
+        sse_encode_String(jsonPath, serializer);
+        sse_encode_String(lang, serializer);
+        sse_encode_opt_String(defaultTz, serializer);
+        sse_encode_bool(preview, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 131, port: port_);
+            funcId: 195, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_import_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncLocaldirUploadAttachmentBytesConstMeta,
-      argValues: [appDir, key, syncKey, localDir, remoteRoot, sha256],
+      constMeta: kCrateApiImportDbImportTelegramExportConstMeta,
+      argValues: [appDir, key, jsonPath, lang, defaultTz, preview],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncLocaldirUploadAttachmentBytesConstMeta =>
+  TaskConstMeta get kCrateApiImportDbImportTelegramExportConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_localdir_upload_attachment_bytes",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "localDir",
-          "remoteRoot",
-          "sha256"
-        ],
+        debugName: "db_import_telegram_export",
+        argNames: ["appDir", "key", "jsonPath", "lang", "defaultTz", "preview"],
       );
 
   @override
-  Future<void> crateApiCoreSyncManagedVaultClearDevice(
-      {required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken,
-      required String deviceId}) {
+  Future<ImportReport> crateApiImportDbImportWhatsappExport(
+      {required String appDir,
+      required List<int> key,
+      required String txtPath,
+      required String lang,
+      String? defaultTz,
+      required bool preview}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(deviceId, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(txtPath, serializer);
+        sse_encode_String(lang, serializer);
+        sse_encode_opt_String(defaultTz, serializer);
+        sse_encode_bool(preview, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 132, port: port_);
+            funcId: 196, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_import_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultClearDeviceConstMeta,
-      argValues: [baseUrl, vaultId, firebaseIdToken, deviceId],
+      constMeta: kCrateApiImportDbImportWhatsappExportConstMeta,
+      argValues: [appDir, key, txtPath, lang, defaultTz, preview],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncManagedVaultClearDeviceConstMeta =>
+  TaskConstMeta get kCrateApiImportDbImportWhatsappExportConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_managed_vault_clear_device",
-        argNames: ["baseUrl", "vaultId", "firebaseIdToken", "deviceId"],
+        debugName: "db_import_whatsapp_export",
+        argNames: ["appDir", "key", "txtPath", "lang", "defaultTz", "preview"],
       );
 
   @override
-  Future<void> crateApiCoreSyncManagedVaultClearVault(
-      {required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken}) {
+  Future<bool> crateApiLocalLlmLocalLlmDeleteModel(
+      {required String appDir, required String fileName}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_String(fileName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 133, port: port_);
+            funcId: 197, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultClearVaultConstMeta,
-      argValues: [baseUrl, vaultId, firebaseIdToken],
+      constMeta: kCrateApiLocalLlmLocalLlmDeleteModelConstMeta,
+      argValues: [appDir, fileName],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncManagedVaultClearVaultConstMeta =>
+  TaskConstMeta get kCrateApiLocalLlmLocalLlmDeleteModelConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_managed_vault_clear_vault",
-        argNames: ["baseUrl", "vaultId", "firebaseIdToken"],
+        debugName: "local_llm_delete_model",
+        argNames: ["appDir", "fileName"],
       );
 
   @override
-  Future<void> crateApiCoreSyncManagedVaultDownloadAttachmentBytes(
+  Future<LocalGgufModelInfo> crateApiLocalLlmLocalLlmInstallModel(
       {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken,
-      required String sha256}) {
+      required String sourcePath,
+      String? expectedSha256}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(sha256, serializer);
+        sse_encode_String(sourcePath, serializer);
+        sse_encode_opt_String(expectedSha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 134, port: port_);
+            funcId: 198, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_local_gguf_model_info,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultDownloadAttachmentBytesConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        vaultId,
-        firebaseIdToken,
-        sha256
-      ],
+      constMeta: kCrateApiLocalLlmLocalLlmInstallModelConstMeta,
+      argValues: [appDir, sourcePath, expectedSha256],
       apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiCoreSyncManagedVaultDownloadAttachmentBytesConstMeta =>
-          const TaskConstMeta(
-            debugName: "sync_managed_vault_download_attachment_bytes",
-            argNames: [
-              "appDir",
-              "key",
-              "syncKey",
-              "baseUrl",
-              "vaultId",
-              "firebaseIdToken",
-              "sha256"
-            ],
-          );
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLocalLlmLocalLlmInstallModelConstMeta =>
+      const TaskConstMeta(
+        debugName: "local_llm_install_model",
+        argNames: ["appDir", "sourcePath", "expectedSha256"],
+      );
 
   @override
-  Future<BigInt> crateApiCoreSyncManagedVaultPull(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken}) {
+  Future<List<LocalGgufModelInfo>> crateApiLocalLlmLocalLlmListModels(
+      {required String appDir}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 135, port: port_);
+            funcId: 199, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_list_local_gguf_model_info,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultPullConstMeta,
-      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      constMeta: kCrateApiLocalLlmLocalLlmListModelsConstMeta,
+      argValues: [appDir],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncManagedVaultPullConstMeta =>
+  TaskConstMeta get kCrateApiLocalLlmLocalLlmListModelsConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_managed_vault_pull",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "vaultId",
-          "firebaseIdToken"
-        ],
+        debugName: "local_llm_list_models",
+        argNames: ["appDir"],
       );
 
   @override
-  Future<BigInt> crateApiCoreSyncManagedVaultPush(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken}) {
+  Future<String> crateApiLocalLlmLocalLlmModelSha256(
+      {required String appDir, required String fileName}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
+        sse_encode_String(fileName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 136, port: port_);
+            funcId: 200, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultPushConstMeta,
-      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      constMeta: kCrateApiLocalLlmLocalLlmModelSha256ConstMeta,
+      argValues: [appDir, fileName],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncManagedVaultPushConstMeta =>
+  TaskConstMeta get kCrateApiLocalLlmLocalLlmModelSha256ConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_managed_vault_push",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "vaultId",
-          "firebaseIdToken"
-        ],
+        debugName: "local_llm_model_sha256",
+        argNames: ["appDir", "fileName"],
       );
 
   @override
-  Future<BigInt> crateApiCoreSyncManagedVaultPushOpsOnly(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken}) {
+  Future<bool> crateApiLocalLlmLocalLlmRuntimeAvailable(
+      {required String appDir}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 137, port: port_);
+            funcId: 201, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultPushOpsOnlyConstMeta,
-      argValues: [appDir, key, syncKey, baseUrl, vaultId, firebaseIdToken],
+      constMeta: kCrateApiLocalLlmLocalLlmRuntimeAvailableConstMeta,
+      argValues: [appDir],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncManagedVaultPushOpsOnlyConstMeta =>
+  TaskConstMeta get kCrateApiLocalLlmLocalLlmRuntimeAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_managed_vault_push_ops_only",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "vaultId",
-          "firebaseIdToken"
-        ],
+        debugName: "local_llm_runtime_available",
+        argNames: ["appDir"],
       );
 
   @override
-  Future<bool> crateApiCoreSyncManagedVaultUploadAttachmentBytes(
+  Future<IntegrityReport> crateApiMaintenanceDbCheckIntegrity(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      required String vaultId,
-      required String firebaseIdToken,
-      required String sha256}) {
+      required bool fix,
+      required PlatformInt64 nowMs}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_String(vaultId, serializer);
-        sse_encode_String(firebaseIdToken, serializer);
-        sse_encode_String(sha256, serializer);
+        sse_encode_bool(fix, serializer);
+        sse_encode_i_64(nowMs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 138, port: port_);
+            funcId: 202, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_integrity_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncManagedVaultUploadAttachmentBytesConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        vaultId,
-        firebaseIdToken,
-        sha256
-      ],
+      constMeta: kCrateApiMaintenanceDbCheckIntegrityConstMeta,
+      argValues: [appDir, key, fix, nowMs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta
-      get kCrateApiCoreSyncManagedVaultUploadAttachmentBytesConstMeta =>
-          const TaskConstMeta(
-            debugName: "sync_managed_vault_upload_attachment_bytes",
-            argNames: [
-              "appDir",
-              "key",
-              "syncKey",
-              "baseUrl",
-              "vaultId",
-              "firebaseIdToken",
-              "sha256"
-            ],
-          );
+  TaskConstMeta get kCrateApiMaintenanceDbCheckIntegrityConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_check_integrity",
+        argNames: ["appDir", "key", "fix", "nowMs"],
+      );
 
   @override
-  Future<void> crateApiCoreSyncWebdavClearRemoteRoot(
-      {required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot}) {
+  Future<StorageUsageReport> crateApiMaintenanceDbStorageUsage(
+      {required String appDir, required List<int> key}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 139, port: port_);
+            funcId: 203, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_storage_usage_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavClearRemoteRootConstMeta,
-      argValues: [baseUrl, username, password, remoteRoot],
+      constMeta: kCrateApiMaintenanceDbStorageUsageConstMeta,
+      argValues: [appDir, key],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavClearRemoteRootConstMeta =>
+  TaskConstMeta get kCrateApiMaintenanceDbStorageUsageConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_webdav_clear_remote_root",
-        argNames: ["baseUrl", "username", "password", "remoteRoot"],
+        debugName: "db_storage_usage",
+        argNames: ["appDir", "key"],
       );
 
   @override
-  Future<void> crateApiCoreSyncWebdavDownloadAttachmentBytes(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot,
-      required String sha256}) {
+  Future<MediaAnnotationConfig>
+      crateApiMediaAnnotationDbGetMediaAnnotationConfig(
+          {required String appDir, required List<int> key}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
-        sse_encode_String(sha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 140, port: port_);
+            funcId: 204, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_media_annotation_config,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavDownloadAttachmentBytesConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        username,
-        password,
-        remoteRoot,
-        sha256
-      ],
+      constMeta: kCrateApiMediaAnnotationDbGetMediaAnnotationConfigConstMeta,
+      argValues: [appDir, key],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavDownloadAttachmentBytesConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_webdav_download_attachment_bytes",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "username",
-          "password",
-          "remoteRoot",
-          "sha256"
-        ],
-      );
+  TaskConstMeta
+      get kCrateApiMediaAnnotationDbGetMediaAnnotationConfigConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_get_media_annotation_config",
+            argNames: ["appDir", "key"],
+          );
 
   @override
-  Future<BigInt> crateApiCoreSyncWebdavPull(
+  Future<void> crateApiMediaAnnotationDbSetMediaAnnotationConfig(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot}) {
+      required MediaAnnotationConfig config}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_box_autoadd_media_annotation_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 141, port: port_);
+            funcId: 205, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavPullConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        username,
-        password,
-        remoteRoot
-      ],
+      constMeta: kCrateApiMediaAnnotationDbSetMediaAnnotationConfigConstMeta,
+      argValues: [appDir, key, config],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavPullConstMeta => const TaskConstMeta(
-        debugName: "sync_webdav_pull",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "username",
-          "password",
-          "remoteRoot"
-        ],
-      );
+  TaskConstMeta
+      get kCrateApiMediaAnnotationDbSetMediaAnnotationConfigConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_set_media_annotation_config",
+            argNames: ["appDir", "key", "config"],
+          );
 
   @override
-  Future<BigInt> crateApiCoreSyncWebdavPush(
+  Future<String> crateApiMediaAnnotationMediaAnnotationByokProfile(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot}) {
+      required String profileId,
+      required String localDay,
+      required String lang,
+      required String mimeType,
+      required List<int> imageBytes}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(profileId, serializer);
+        sse_encode_String(localDay, serializer);
+        sse_encode_String(lang, serializer);
+        sse_encode_String(mimeType, serializer);
+        sse_encode_list_prim_u_8_loose(imageBytes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 142, port: port_);
+            funcId: 206, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavPushConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        username,
-        password,
-        remoteRoot
-      ],
+      constMeta: kCrateApiMediaAnnotationMediaAnnotationByokProfileConstMeta,
+      argValues: [appDir, key, profileId, localDay, lang, mimeType, imageBytes],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavPushConstMeta => const TaskConstMeta(
-        debugName: "sync_webdav_push",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "username",
-          "password",
-          "remoteRoot"
-        ],
-      );
+  TaskConstMeta
+      get kCrateApiMediaAnnotationMediaAnnotationByokProfileConstMeta =>
+          const TaskConstMeta(
+            debugName: "media_annotation_byok_profile",
+            argNames: [
+              "appDir",
+              "key",
+              "profileId",
+              "localDay",
+              "lang",
+              "mimeType",
+              "imageBytes"
+            ],
+          );
 
   @override
-  Future<BigInt> crateApiCoreSyncWebdavPushOpsOnly(
+  Future<void> crateApiPromptTemplatesDbDeletePromptTemplate(
       {required String appDir,
       required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot}) {
+      required String templateId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(templateId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 143, port: port_);
+            funcId: 207, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavPushOpsOnlyConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        username,
-        password,
-        remoteRoot
-      ],
+      constMeta: kCrateApiPromptTemplatesDbDeletePromptTemplateConstMeta,
+      argValues: [appDir, key, templateId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavPushOpsOnlyConstMeta =>
+  TaskConstMeta get kCrateApiPromptTemplatesDbDeletePromptTemplateConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_webdav_push_ops_only",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "username",
-          "password",
-          "remoteRoot"
-        ],
+        debugName: "db_delete_prompt_template",
+        argNames: ["appDir", "key", "templateId"],
       );
 
   @override
-  Future<void> crateApiCoreSyncWebdavTestConnection(
-      {required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot}) {
+  Future<List<PromptTemplateSelection>>
+      crateApiPromptTemplatesDbListPromptTemplateSelections(
+          {required String appDir, required List<int> key}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 144, port: port_);
+            funcId: 208, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_prompt_template_selection,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavTestConnectionConstMeta,
-      argValues: [baseUrl, username, password, remoteRoot],
+      constMeta:
+          kCrateApiPromptTemplatesDbListPromptTemplateSelectionsConstMeta,
+      argValues: [appDir, key],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavTestConnectionConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_webdav_test_connection",
-        argNames: ["baseUrl", "username", "password", "remoteRoot"],
-      );
+  TaskConstMeta
+      get kCrateApiPromptTemplatesDbListPromptTemplateSelectionsConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_list_prompt_template_selections",
+            argNames: ["appDir", "key"],
+          );
 
   @override
-  Future<bool> crateApiCoreSyncWebdavUploadAttachmentBytes(
-      {required String appDir,
-      required List<int> key,
-      required List<int> syncKey,
-      required String baseUrl,
-      String? username,
-      String? password,
-      required String remoteRoot,
-      required String sha256}) {
+  Future<List<PromptTemplate>> crateApiPromptTemplatesDbListPromptTemplates(
+      {required String appDir, required List<int> key}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_list_prim_u_8_loose(syncKey, serializer);
-        sse_encode_String(baseUrl, serializer);
-        sse_encode_opt_String(username, serializer);
-        sse_encode_opt_String(password, serializer);
-        sse_encode_String(remoteRoot, serializer);
-        sse_encode_String(sha256, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 145, port: port_);
+            funcId: 209, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_list_prompt_template,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCoreSyncWebdavUploadAttachmentBytesConstMeta,
-      argValues: [
-        appDir,
-        key,
-        syncKey,
-        baseUrl,
-        username,
-        password,
-        remoteRoot,
-        sha256
-      ],
+      constMeta: kCrateApiPromptTemplatesDbListPromptTemplatesConstMeta,
+      argValues: [appDir, key],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCoreSyncWebdavUploadAttachmentBytesConstMeta =>
+  TaskConstMeta get kCrateApiPromptTemplatesDbListPromptTemplatesConstMeta =>
       const TaskConstMeta(
-        debugName: "sync_webdav_upload_attachment_bytes",
-        argNames: [
-          "appDir",
-          "key",
-          "syncKey",
-          "baseUrl",
-          "username",
-          "password",
-          "remoteRoot",
-          "sha256"
-        ],
+        debugName: "db_list_prompt_templates",
+        argNames: ["appDir", "key"],
       );
 
   @override
-  Future<OcrPayload> crateApiDesktopMediaDesktopOcrImage(
-      {required List<int> bytes, required String languageHints}) {
+  Future<void> crateApiPromptTemplatesDbResetPromptTemplateSelections(
+      {required String appDir,
+      required List<int> key,
+      required String purpose}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(bytes, serializer);
-        sse_encode_String(languageHints, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(purpose, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 146, port: port_);
+            funcId: 210, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_ocr_payload,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiDesktopMediaDesktopOcrImageConstMeta,
-      argValues: [bytes, languageHints],
+      constMeta:
+          kCrateApiPromptTemplatesDbResetPromptTemplateSelectionsConstMeta,
+      argValues: [appDir, key, purpose],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiDesktopMediaDesktopOcrImageConstMeta =>
-      const TaskConstMeta(
-        debugName: "desktop_ocr_image",
-        argNames: ["bytes", "languageHints"],
-      );
+  TaskConstMeta
+      get kCrateApiPromptTemplatesDbResetPromptTemplateSelectionsConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_reset_prompt_template_selections",
+            argNames: ["appDir", "key", "purpose"],
+          );
 
   @override
-  Future<OcrPayload> crateApiDesktopMediaDesktopOcrPdf(
-      {required List<int> bytes,
-      required int maxPages,
-      required int dpi,
-      required String languageHints}) {
+  Future<PromptTemplate> crateApiPromptTemplatesDbResetPromptTemplateToDefault(
+      {required String appDir,
+      required List<int> key,
+      required String templateId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(bytes, serializer);
-        sse_encode_u_32(maxPages, serializer);
-        sse_encode_u_32(dpi, serializer);
-        sse_encode_String(languageHints, serializer);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(templateId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 147, port: port_);
+            funcId: 211, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_ocr_payload,
+        decodeSuccessData: sse_decode_prompt_template,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiDesktopMediaDesktopOcrPdfConstMeta,
-      argValues: [bytes, maxPages, dpi, languageHints],
+      constMeta:
+          kCrateApiPromptTemplatesDbResetPromptTemplateToDefaultConstMeta,
+      argValues: [appDir, key, templateId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiDesktopMediaDesktopOcrPdfConstMeta =>
-      const TaskConstMeta(
-        debugName: "desktop_ocr_pdf",
-        argNames: ["bytes", "maxPages", "dpi", "languageHints"],
-      );
+  TaskConstMeta
+      get kCrateApiPromptTemplatesDbResetPromptTemplateToDefaultConstMeta =>
+          const TaskConstMeta(
+            debugName: "db_reset_prompt_template_to_default",
+            argNames: ["appDir", "key", "templateId"],
+          );
 
   @override
-  Future<MediaAnnotationConfig>
-      crateApiMediaAnnotationDbGetMediaAnnotationConfig(
-          {required String appDir, required List<int> key}) {
+  Future<void> crateApiPromptTemplatesDbSetPromptTemplateSelection(
+      {required String appDir,
+      required List<int> key,
+      required String purpose,
+      required String profileId,
+      String? templateId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(purpose, serializer);
+        sse_encode_String(profileId, serializer);
+        sse_encode_opt_String(templateId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 148, port: port_);
+            funcId: 212, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_media_annotation_config,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiMediaAnnotationDbGetMediaAnnotationConfigConstMeta,
-      argValues: [appDir, key],
+      constMeta: kCrateApiPromptTemplatesDbSetPromptTemplateSelectionConstMeta,
+      argValues: [appDir, key, purpose, profileId, templateId],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiMediaAnnotationDbGetMediaAnnotationConfigConstMeta =>
+      get kCrateApiPromptTemplatesDbSetPromptTemplateSelectionConstMeta =>
           const TaskConstMeta(
-            debugName: "db_get_media_annotation_config",
-            argNames: ["appDir", "key"],
+            debugName: "db_set_prompt_template_selection",
+            argNames: ["appDir", "key", "purpose", "profileId", "templateId"],
           );
 
   @override
-  Future<void> crateApiMediaAnnotationDbSetMediaAnnotationConfig(
+  Future<PromptTemplate> crateApiPromptTemplatesDbUpsertPromptTemplate(
       {required String appDir,
       required List<int> key,
-      required MediaAnnotationConfig config}) {
+      String? templateId,
+      required String name,
+      required String purpose,
+      required String body}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_opt_String(templateId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(purpose, serializer);
+        sse_encode_String(body, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 213, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_prompt_template,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiPromptTemplatesDbUpsertPromptTemplateConstMeta,
+      argValues: [appDir, key, templateId, name, purpose, body],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPromptTemplatesDbUpsertPromptTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_prompt_template",
+        argNames: ["appDir", "key", "templateId", "name", "purpose", "body"],
+      );
+
+  @override
+  Future<String> crateApiPromptTemplatesPromptTemplateDefault(
+      {required String purpose}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_box_autoadd_media_annotation_config(config, serializer);
+        sse_encode_String(purpose, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 149, port: port_);
+            funcId: 214, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiMediaAnnotationDbSetMediaAnnotationConfigConstMeta,
-      argValues: [appDir, key, config],
+      constMeta: kCrateApiPromptTemplatesPromptTemplateDefaultConstMeta,
+      argValues: [purpose],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta
-      get kCrateApiMediaAnnotationDbSetMediaAnnotationConfigConstMeta =>
-          const TaskConstMeta(
-            debugName: "db_set_media_annotation_config",
-            argNames: ["appDir", "key", "config"],
-          );
+  TaskConstMeta get kCrateApiPromptTemplatesPromptTemplateDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "prompt_template_default",
+        argNames: ["purpose"],
+      );
 
   @override
-  Future<String> crateApiMediaAnnotationMediaAnnotationByokProfile(
-      {required String appDir,
-      required List<int> key,
-      required String profileId,
-      required String localDay,
-      required String lang,
-      required String mimeType,
-      required List<int> imageBytes}) {
+  Future<void> crateApiPromptTemplatesPromptTemplateValidate(
+      {required String purpose, required String body}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(appDir, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        sse_encode_String(profileId, serializer);
-        sse_encode_String(localDay, serializer);
-        sse_encode_String(lang, serializer);
-        sse_encode_String(mimeType, serializer);
-        sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+        sse_encode_String(purpose, serializer);
+        sse_encode_String(body, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 150, port: port_);
+            funcId: 215, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiMediaAnnotationMediaAnnotationByokProfileConstMeta,
-      argValues: [appDir, key, profileId, localDay, lang, mimeType, imageBytes],
+      constMeta: kCrateApiPromptTemplatesPromptTemplateValidateConstMeta,
+      argValues: [purpose, body],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta
-      get kCrateApiMediaAnnotationMediaAnnotationByokProfileConstMeta =>
-          const TaskConstMeta(
-            debugName: "media_annotation_byok_profile",
-            argNames: [
-              "appDir",
-              "key",
-              "profileId",
-              "localDay",
-              "lang",
-              "mimeType",
-              "imageBytes"
-            ],
-          );
+  TaskConstMeta get kCrateApiPromptTemplatesPromptTemplateValidateConstMeta =>
+      const TaskConstMeta(
+        debugName: "prompt_template_validate",
+        argNames: ["purpose", "body"],
+      );
 
   @override
   String crateApiSimpleGreet({required String name}) {
@@ -7071,7 +9573,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -7094,7 +9596,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 152, port: port_);
+            funcId: 217, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7129,7 +9631,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(remoteRoot, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 153, port: port_);
+            funcId: 218, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7173,7 +9675,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(remoteRoot, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 154, port: port_);
+            funcId: 219, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7219,7 +9721,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(idToken, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 155, port: port_);
+            funcId: 220, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7267,7 +9769,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(idToken, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 156, port: port_);
+            funcId: 221, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7318,7 +9820,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(remoteRoot, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 157, port: port_);
+            funcId: 222, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7377,7 +9879,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(remoteRoot, serializer);
         sse_encode_StreamSink_String_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 158, port: port_);
+            funcId: 223, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7415,6 +9917,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             ],
           );
 
+  @override
+  Future<int> crateApiTagsDbBackfillTagRules(
+      {required String appDir,
+      required List<int> key,
+      String? ruleId,
+      required PlatformInt64 nowMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_opt_String(ruleId, serializer);
+        sse_encode_i_64(nowMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 224, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbBackfillTagRulesConstMeta,
+      argValues: [appDir, key, ruleId, nowMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbBackfillTagRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_backfill_tag_rules",
+        argNames: ["appDir", "key", "ruleId", "nowMs"],
+      );
+
+  @override
+  Future<void> crateApiTagsDbDeleteTagRule(
+      {required String appDir,
+      required List<int> key,
+      required String ruleId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(ruleId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 225, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbDeleteTagRuleConstMeta,
+      argValues: [appDir, key, ruleId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbDeleteTagRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_delete_tag_rule",
+        argNames: ["appDir", "key", "ruleId"],
+      );
+
   @override
   Future<List<String>> crateApiTagsDbListMessageIdsByTagIds(
       {required String appDir,
@@ -7429,7 +9993,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(conversationId, serializer);
         sse_encode_list_String(tagIds, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 159, port: port_);
+            funcId: 226, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_String,
@@ -7459,7 +10023,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(messageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 160, port: port_);
+            funcId: 227, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_String,
@@ -7489,7 +10053,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(messageId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 161, port: port_);
+            funcId: 228, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_tag,
@@ -7517,7 +10081,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_u_32(limit, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 162, port: port_);
+            funcId: 229, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_tag_merge_suggestion,
@@ -7535,6 +10099,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "limit"],
       );
 
+  @override
+  Future<List<TagRule>> crateApiTagsDbListTagRules(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 230, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_tag_rule,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbListTagRulesConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbListTagRulesConstMeta => const TaskConstMeta(
+        debugName: "db_list_tag_rules",
+        argNames: ["appDir", "key"],
+      );
+
+  @override
+  Future<List<TagNode>> crateApiTagsDbListTagTree(
+      {required String appDir, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 231, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_tag_node,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbListTagTreeConstMeta,
+      argValues: [appDir, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbListTagTreeConstMeta => const TaskConstMeta(
+        debugName: "db_list_tag_tree",
+        argNames: ["appDir", "key"],
+      );
+
   @override
   Future<List<Tag>> crateApiTagsDbListTags(
       {required String appDir, required List<int> key}) {
@@ -7544,7 +10160,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(appDir, serializer);
         sse_encode_list_prim_u_8_loose(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 163, port: port_);
+            funcId: 232, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_tag,
@@ -7575,7 +10191,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(sourceTagId, serializer);
         sse_encode_String(targetTagId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 164, port: port_);
+            funcId: 233, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -7610,7 +10226,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(reason, serializer);
         sse_encode_String(action, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 165, port: port_);
+            funcId: 234, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -7649,7 +10265,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(messageId, serializer);
         sse_encode_list_String(tagIds, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 166, port: port_);
+            funcId: 235, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_tag,
@@ -7667,6 +10283,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "messageId", "tagIds"],
       );
 
+  @override
+  Future<Tag> crateApiTagsDbSetTagParent(
+      {required String appDir,
+      required List<int> key,
+      required String tagId,
+      String? parentId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(tagId, serializer);
+        sse_encode_opt_String(parentId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 236, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tag,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbSetTagParentConstMeta,
+      argValues: [appDir, key, tagId, parentId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbSetTagParentConstMeta => const TaskConstMeta(
+        debugName: "db_set_tag_parent",
+        argNames: ["appDir", "key", "tagId", "parentId"],
+      );
+
   @override
   Future<Tag> crateApiTagsDbUpsertTag(
       {required String appDir, required List<int> key, required String name}) {
@@ -7677,7 +10324,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(key, serializer);
         sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 167, port: port_);
+            funcId: 237, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_tag,
@@ -7694,6 +10341,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["appDir", "key", "name"],
       );
 
+  @override
+  Future<Tag> crateApiTagsDbUpsertTagPath(
+      {required String appDir, required List<int> key, required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 238, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tag,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbUpsertTagPathConstMeta,
+      argValues: [appDir, key, path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbUpsertTagPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_tag_path",
+        argNames: ["appDir", "key", "path"],
+      );
+
+  @override
+  Future<TagRule> crateApiTagsDbUpsertTagRule(
+      {required String appDir,
+      required List<int> key,
+      String? ruleId,
+      required String name,
+      required String kind,
+      required String pattern,
+      required String tagId,
+      required bool enabled}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(appDir, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_opt_String(ruleId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(kind, serializer);
+        sse_encode_String(pattern, serializer);
+        sse_encode_String(tagId, serializer);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 239, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tag_rule,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiTagsDbUpsertTagRuleConstMeta,
+      argValues: [appDir, key, ruleId, name, kind, pattern, tagId, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagsDbUpsertTagRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "db_upsert_tag_rule",
+        argNames: [
+          "appDir",
+          "key",
+          "ruleId",
+          "name",
+          "kind",
+          "pattern",
+          "tagId",
+          "enabled"
+        ],
+      );
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7712,6 +10436,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  AgendaDay dco_decode_agenda_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AgendaDay(
+      date: dco_decode_String(arr[0]),
+      dayStartMs: dco_decode_i_64(arr[1]),
+      items: dco_decode_list_agenda_item(arr[2]),
+    );
+  }
+
+  @protected
+  AgendaItem dco_decode_agenda_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return AgendaItem(
+      kind: dco_decode_String(arr[0]),
+      itemId: dco_decode_String(arr[1]),
+      sourceId: dco_decode_String(arr[2]),
+      title: dco_decode_String(arr[3]),
+      atMs: dco_decode_i_64(arr[4]),
+      endAtMs: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      status: dco_decode_opt_String(arr[6]),
+      tz: dco_decode_opt_String(arr[7]),
+      isRecurring: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  AgendaPage dco_decode_agenda_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AgendaPage(
+      overdue: dco_decode_list_agenda_item(arr[0]),
+      days: dco_decode_list_agenda_day(arr[1]),
+      nextCursor: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   Attachment dco_decode_attachment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7867,6 +10636,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_storage_policy_config(raw);
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CalDavSyncReport dco_decode_cal_dav_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CalDavSyncReport(
+      pulledCreated: dco_decode_u_32(arr[0]),
+      pulledUpdated: dco_decode_u_32(arr[1]),
+      pulledDeleted: dco_decode_u_32(arr[2]),
+      pushedCreated: dco_decode_u_32(arr[3]),
+      pushedUpdated: dco_decode_u_32(arr[4]),
+      pushedDeleted: dco_decode_u_32(arr[5]),
+      conflicts: dco_decode_u_32(arr[6]),
+      errors: dco_decode_list_String(arr[7]),
+    );
+  }
+
   @protected
   CloudMediaBackup dco_decode_cloud_media_backup(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7979,6 +10772,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  EventOccurrence dco_decode_event_occurrence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return EventOccurrence(
+      eventId: dco_decode_String(arr[0]),
+      occurrenceStartMs: dco_decode_i_64(arr[1]),
+      title: dco_decode_String(arr[2]),
+      startAtMs: dco_decode_i_64(arr[3]),
+      endAtMs: dco_decode_i_64(arr[4]),
+      tz: dco_decode_String(arr[5]),
+      isRecurring: dco_decode_bool(arr[6]),
+      isMoved: dco_decode_bool(arr[7]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -7997,12 +10808,119 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  IcsExportSummary dco_decode_ics_export_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return IcsExportSummary(
+      events: dco_decode_u_64(arr[0]),
+      todos: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ImportReport(
+      itemsScanned: dco_decode_u_32(arr[0]),
+      itemsImported: dco_decode_u_32(arr[1]),
+      itemsUpdated: dco_decode_u_32(arr[2]),
+      itemsSkippedUnchanged: dco_decode_u_32(arr[3]),
+      itemsSkippedDuplicate: dco_decode_u_32(arr[4]),
+      messagesCreated: dco_decode_u_32(arr[5]),
+      todosCreated: dco_decode_u_32(arr[6]),
+      attachmentsLinked: dco_decode_u_32(arr[7]),
+      tagsApplied: dco_decode_u_32(arr[8]),
+      errors: dco_decode_list_String(arr[9]),
+    );
+  }
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IntegrityIssue(
+      kind: dco_decode_String(arr[0]),
+      subject: dco_decode_String(arr[1]),
+      fixed: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return IntegrityReport(
+      issues: dco_decode_list_integrity_issue(arr[0]),
+      fixedCount: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  JsonlExportSummary dco_decode_jsonl_export_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonlExportSummary(
+      schemaVersion: dco_decode_i_64(arr[0]),
+      exportedAtMs: dco_decode_i_64(arr[1]),
+      streams: dco_decode_list_jsonl_stream_count(arr[2]),
+    );
+  }
+
+  @protected
+  JsonlExportVerification dco_decode_jsonl_export_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonlExportVerification(
+      schemaVersion: dco_decode_i_64(arr[0]),
+      streams: dco_decode_list_jsonl_stream_count(arr[1]),
+      problems: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  JsonlStreamCount dco_decode_jsonl_stream_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return JsonlStreamCount(
+      stream: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<AgendaDay> dco_decode_list_agenda_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_agenda_day).toList();
+  }
+
+  @protected
+  List<AgendaItem> dco_decode_list_agenda_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_agenda_item).toList();
+  }
+
   @protected
   List<Attachment> dco_decode_list_attachment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8048,24 +10966,80 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_event).toList();
   }
 
+  @protected
+  List<EventOccurrence> dco_decode_list_event_occurrence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_event_occurrence).toList();
+  }
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_integrity_issue).toList();
+  }
+
+  @protected
+  List<JsonlStreamCount> dco_decode_list_jsonl_stream_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_jsonl_stream_count).toList();
+  }
+
+  @protected
+  List<LlmModelPrice> dco_decode_list_llm_model_price(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_llm_model_price).toList();
+  }
+
+  @protected
+  List<LlmMonthlyCost> dco_decode_list_llm_monthly_cost(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_llm_monthly_cost).toList();
+  }
+
   @protected
   List<LlmProfile> dco_decode_list_llm_profile(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_llm_profile).toList();
   }
 
+  @protected
+  List<LlmSpendingLimit> dco_decode_list_llm_spending_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_llm_spending_limit).toList();
+  }
+
   @protected
   List<LlmUsageAggregate> dco_decode_list_llm_usage_aggregate(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_llm_usage_aggregate).toList();
   }
 
+  @protected
+  List<LocalGgufModelInfo> dco_decode_list_local_gguf_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_local_gguf_model_info)
+        .toList();
+  }
+
   @protected
   List<Message> dco_decode_list_message(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_message).toList();
   }
 
+  @protected
+  List<MessageCitation> dco_decode_list_message_citation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_citation).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8078,6 +11052,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as Uint8List;
   }
 
+  @protected
+  List<PromptTemplate> dco_decode_list_prompt_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prompt_template).toList();
+  }
+
+  @protected
+  List<PromptTemplateSelection> dco_decode_list_prompt_template_selection(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_prompt_template_selection)
+        .toList();
+  }
+
+  @protected
+  List<ReviewActivity> dco_decode_list_review_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_review_activity).toList();
+  }
+
+  @protected
+  List<ReviewItem> dco_decode_list_review_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_review_item).toList();
+  }
+
+  @protected
+  List<SemanticParseFailureReasonCount> dco_decode_list_semantic_parse_failure_reason_count(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_semantic_parse_failure_reason_count)
+        .toList();
+  }
+
   @protected
   List<SemanticParseJob> dco_decode_list_semantic_parse_job(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8096,6 +11106,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_similar_todo_thread).toList();
   }
 
+  @protected
+  List<StorageUsageEntry> dco_decode_list_storage_usage_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_storage_usage_entry).toList();
+  }
+
   @protected
   List<Tag> dco_decode_list_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8108,6 +11124,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_tag_merge_suggestion).toList();
   }
 
+  @protected
+  List<TagNode> dco_decode_list_tag_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_node).toList();
+  }
+
+  @protected
+  List<TagRule> dco_decode_list_tag_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_rule).toList();
+  }
+
   @protected
   List<Todo> dco_decode_list_todo(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8126,6 +11154,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_todo_candidate).toList();
   }
 
+  @protected
+  LlmModelPrice dco_decode_llm_model_price(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LlmModelPrice(
+      providerType: dco_decode_String(arr[0]),
+      modelName: dco_decode_String(arr[1]),
+      inputUsdPerMillionTokens: dco_decode_f_64(arr[2]),
+      outputUsdPerMillionTokens: dco_decode_f_64(arr[3]),
+      updatedAtMs: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  LlmMonthlyCost dco_decode_llm_monthly_cost(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LlmMonthlyCost(
+      profileId: dco_decode_String(arr[0]),
+      purpose: dco_decode_String(arr[1]),
+      requests: dco_decode_i_64(arr[2]),
+      requestsWithUsage: dco_decode_i_64(arr[3]),
+      inputTokens: dco_decode_i_64(arr[4]),
+      outputTokens: dco_decode_i_64(arr[5]),
+      costUsdMicros: dco_decode_i_64(arr[6]),
+    );
+  }
+
   @protected
   LlmProfile dco_decode_llm_profile(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8144,6 +11204,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LlmSpendingLimit dco_decode_llm_spending_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LlmSpendingLimit(
+      profileId: dco_decode_String(arr[0]),
+      monthlyLimitUsdMicros: dco_decode_i_64(arr[1]),
+    );
+  }
+
   @protected
   LlmUsageAggregate dco_decode_llm_usage_aggregate(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8160,6 +11232,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LocalGgufModelInfo dco_decode_local_gguf_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LocalGgufModelInfo(
+      fileName: dco_decode_String(arr[0]),
+      sizeBytes: dco_decode_u_64(arr[1]),
+      architecture: dco_decode_opt_String(arr[2]),
+      name: dco_decode_opt_String(arr[3]),
+      contextLength: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
   @protected
   MediaAnnotationConfig dco_decode_media_annotation_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8192,6 +11279,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageCitation dco_decode_message_citation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MessageCitation(
+      marker: dco_decode_u_32(arr[0]),
+      sourceKind: dco_decode_String(arr[1]),
+      sourceId: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   OcrPayload dco_decode_ocr_payload(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8248,6 +11348,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_message(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  PromptTemplate dco_decode_prompt_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PromptTemplate(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      purpose: dco_decode_String(arr[2]),
+      body: dco_decode_String(arr[3]),
+      createdAtMs: dco_decode_i_64(arr[4]),
+      updatedAtMs: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  PromptTemplateSelection dco_decode_prompt_template_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PromptTemplateSelection(
+      purpose: dco_decode_String(arr[0]),
+      profileId: dco_decode_String(arr[1]),
+      templateId: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ReviewActivity dco_decode_review_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ReviewActivity(
+      id: dco_decode_String(arr[0]),
+      itemKind: dco_decode_String(arr[1]),
+      itemId: dco_decode_String(arr[2]),
+      grade: dco_decode_i_64(arr[3]),
+      easeFactor: dco_decode_f_64(arr[4]),
+      intervalDays: dco_decode_i_64(arr[5]),
+      dueAtMs: dco_decode_i_64(arr[6]),
+      reviewedAtMs: dco_decode_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  ReviewItem dco_decode_review_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ReviewItem(
+      itemKind: dco_decode_String(arr[0]),
+      itemId: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      easeFactor: dco_decode_f_64(arr[3]),
+      intervalDays: dco_decode_i_64(arr[4]),
+      repetitions: dco_decode_i_64(arr[5]),
+      lapses: dco_decode_i_64(arr[6]),
+      dueAtMs: dco_decode_i_64(arr[7]),
+      lastReviewedAtMs: dco_decode_opt_box_autoadd_i_64(arr[8]),
+      isActive: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  SemanticParseFailureReasonCount dco_decode_semantic_parse_failure_reason_count(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SemanticParseFailureReasonCount(
+      reason: dco_decode_String(arr[0]),
+      jobs: dco_decode_i_64(arr[1]),
+    );
+  }
+
   @protected
   SemanticParseJob dco_decode_semantic_parse_job(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8309,6 +11495,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  StorageUsageEntry dco_decode_storage_usage_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return StorageUsageEntry(
+      category: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      bytes: dco_decode_i_64(arr[2]),
+      count: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  StorageUsageReport dco_decode_storage_usage_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return StorageUsageReport(
+      totalBytes: dco_decode_i_64(arr[0]),
+      categories: dco_decode_list_storage_usage_entry(arr[1]),
+      byConversation: dco_decode_list_storage_usage_entry(arr[2]),
+      byMimeType: dco_decode_list_storage_usage_entry(arr[3]),
+    );
+  }
+
   @protected
   Tag dco_decode_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8342,6 +11556,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TagNode dco_decode_tag_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TagNode(
+      tag: dco_decode_tag(arr[0]),
+      parentId: dco_decode_opt_String(arr[1]),
+      path: dco_decode_String(arr[2]),
+      depth: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  TagRule dco_decode_tag_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return TagRule(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      kind: dco_decode_String(arr[2]),
+      pattern: dco_decode_String(arr[3]),
+      tagId: dco_decode_String(arr[4]),
+      enabled: dco_decode_bool(arr[5]),
+      createdAtMs: dco_decode_i_64(arr[6]),
+      updatedAtMs: dco_decode_i_64(arr[7]),
+    );
+  }
+
   @protected
   Todo dco_decode_todo(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8394,6 +11640,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TodoRollup dco_decode_todo_rollup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TodoRollup(
+      todoId: dco_decode_String(arr[0]),
+      parentId: dco_decode_opt_String(arr[1]),
+      subtasksTotal: dco_decode_i_64(arr[2]),
+      subtasksDone: dco_decode_i_64(arr[3]),
+      openBlockerIds: dco_decode_list_String(arr[4]),
+      completeWithSubtasks: dco_decode_bool(arr[5]),
+    );
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8439,6 +11701,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AgendaDay sse_decode_agenda_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_date = sse_decode_String(deserializer);
+    var var_dayStartMs = sse_decode_i_64(deserializer);
+    var var_items = sse_decode_list_agenda_item(deserializer);
+    return AgendaDay(
+        date: var_date, dayStartMs: var_dayStartMs, items: var_items);
+  }
+
+  @protected
+  AgendaItem sse_decode_agenda_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_itemId = sse_decode_String(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_atMs = sse_decode_i_64(deserializer);
+    var var_endAtMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_status = sse_decode_opt_String(deserializer);
+    var var_tz = sse_decode_opt_String(deserializer);
+    var var_isRecurring = sse_decode_bool(deserializer);
+    return AgendaItem(
+        kind: var_kind,
+        itemId: var_itemId,
+        sourceId: var_sourceId,
+        title: var_title,
+        atMs: var_atMs,
+        endAtMs: var_endAtMs,
+        status: var_status,
+        tz: var_tz,
+        isRecurring: var_isRecurring);
+  }
+
+  @protected
+  AgendaPage sse_decode_agenda_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_overdue = sse_decode_list_agenda_item(deserializer);
+    var var_days = sse_decode_list_agenda_day(deserializer);
+    var var_nextCursor = sse_decode_opt_String(deserializer);
+    return AgendaPage(
+        overdue: var_overdue, days: var_days, nextCursor: var_nextCursor);
+  }
+
   @protected
   Attachment sse_decode_attachment(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8613,6 +11919,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_storage_policy_config(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  CalDavSyncReport sse_decode_cal_dav_sync_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pulledCreated = sse_decode_u_32(deserializer);
+    var var_pulledUpdated = sse_decode_u_32(deserializer);
+    var var_pulledDeleted = sse_decode_u_32(deserializer);
+    var var_pushedCreated = sse_decode_u_32(deserializer);
+    var var_pushedUpdated = sse_decode_u_32(deserializer);
+    var var_pushedDeleted = sse_decode_u_32(deserializer);
+    var var_conflicts = sse_decode_u_32(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return CalDavSyncReport(
+        pulledCreated: var_pulledCreated,
+        pulledUpdated: var_pulledUpdated,
+        pulledDeleted: var_pulledDeleted,
+        pushedCreated: var_pushedCreated,
+        pushedUpdated: var_pushedUpdated,
+        pushedDeleted: var_pushedDeleted,
+        conflicts: var_conflicts,
+        errors: var_errors);
+  }
+
   @protected
   CloudMediaBackup sse_decode_cloud_media_backup(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8755,6 +12090,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         updatedAtMs: var_updatedAtMs);
   }
 
+  @protected
+  EventOccurrence sse_decode_event_occurrence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventId = sse_decode_String(deserializer);
+    var var_occurrenceStartMs = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_startAtMs = sse_decode_i_64(deserializer);
+    var var_endAtMs = sse_decode_i_64(deserializer);
+    var var_tz = sse_decode_String(deserializer);
+    var var_isRecurring = sse_decode_bool(deserializer);
+    var var_isMoved = sse_decode_bool(deserializer);
+    return EventOccurrence(
+        eventId: var_eventId,
+        occurrenceStartMs: var_occurrenceStartMs,
+        title: var_title,
+        startAtMs: var_startAtMs,
+        endAtMs: var_endAtMs,
+        tz: var_tz,
+        isRecurring: var_isRecurring,
+        isMoved: var_isMoved);
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8773,6 +12130,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  IcsExportSummary sse_decode_ics_export_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_events = sse_decode_u_64(deserializer);
+    var var_todos = sse_decode_u_64(deserializer);
+    return IcsExportSummary(events: var_events, todos: var_todos);
+  }
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_itemsScanned = sse_decode_u_32(deserializer);
+    var var_itemsImported = sse_decode_u_32(deserializer);
+    var var_itemsUpdated = sse_decode_u_32(deserializer);
+    var var_itemsSkippedUnchanged = sse_decode_u_32(deserializer);
+    var var_itemsSkippedDuplicate = sse_decode_u_32(deserializer);
+    var var_messagesCreated = sse_decode_u_32(deserializer);
+    var var_todosCreated = sse_decode_u_32(deserializer);
+    var var_attachmentsLinked = sse_decode_u_32(deserializer);
+    var var_tagsApplied = sse_decode_u_32(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return ImportReport(
+        itemsScanned: var_itemsScanned,
+        itemsImported: var_itemsImported,
+        itemsUpdated: var_itemsUpdated,
+        itemsSkippedUnchanged: var_itemsSkippedUnchanged,
+        itemsSkippedDuplicate: var_itemsSkippedDuplicate,
+        messagesCreated: var_messagesCreated,
+        todosCreated: var_todosCreated,
+        attachmentsLinked: var_attachmentsLinked,
+        tagsApplied: var_tagsApplied,
+        errors: var_errors);
+  }
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_subject = sse_decode_String(deserializer);
+    var var_fixed = sse_decode_bool(deserializer);
+    return IntegrityIssue(
+        kind: var_kind, subject: var_subject, fixed: var_fixed);
+  }
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_issues = sse_decode_list_integrity_issue(deserializer);
+    var var_fixedCount = sse_decode_u_32(deserializer);
+    return IntegrityReport(issues: var_issues, fixedCount: var_fixedCount);
+  }
+
+  @protected
+  JsonlExportSummary sse_decode_jsonl_export_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_schemaVersion = sse_decode_i_64(deserializer);
+    var var_exportedAtMs = sse_decode_i_64(deserializer);
+    var var_streams = sse_decode_list_jsonl_stream_count(deserializer);
+    return JsonlExportSummary(
+        schemaVersion: var_schemaVersion,
+        exportedAtMs: var_exportedAtMs,
+        streams: var_streams);
+  }
+
+  @protected
+  JsonlExportVerification sse_decode_jsonl_export_verification(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_schemaVersion = sse_decode_i_64(deserializer);
+    var var_streams = sse_decode_list_jsonl_stream_count(deserializer);
+    var var_problems = sse_decode_list_String(deserializer);
+    return JsonlExportVerification(
+        schemaVersion: var_schemaVersion,
+        streams: var_streams,
+        problems: var_problems);
+  }
+
+  @protected
+  JsonlStreamCount sse_decode_jsonl_stream_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stream = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return JsonlStreamCount(stream: var_stream, count: var_count);
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8780,149 +12223,348 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var len_ = sse_decode_i_32(deserializer);
     var ans_ = <String>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AgendaDay> sse_decode_list_agenda_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AgendaDay>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_agenda_day(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AgendaItem> sse_decode_list_agenda_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AgendaItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_agenda_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Attachment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_attachment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AttachmentAnnotationJob> sse_decode_list_attachment_annotation_job(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AttachmentAnnotationJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_attachment_annotation_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AttachmentPlaceJob> sse_decode_list_attachment_place_job(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AttachmentPlaceJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_attachment_place_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CloudMediaBackup> sse_decode_list_cloud_media_backup(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CloudMediaBackup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cloud_media_backup(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Conversation> sse_decode_list_conversation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Conversation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_conversation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmbeddingProfile> sse_decode_list_embedding_profile(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmbeddingProfile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_embedding_profile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Event> sse_decode_list_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Event>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EventOccurrence> sse_decode_list_event_occurrence(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EventOccurrence>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_event_occurrence(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IntegrityIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_integrity_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<JsonlStreamCount> sse_decode_list_jsonl_stream_count(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <JsonlStreamCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_jsonl_stream_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LlmModelPrice> sse_decode_list_llm_model_price(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LlmModelPrice>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_llm_model_price(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LlmMonthlyCost> sse_decode_list_llm_monthly_cost(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LlmMonthlyCost>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_llm_monthly_cost(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer) {
+  List<LlmProfile> sse_decode_list_llm_profile(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Attachment>[];
+    var ans_ = <LlmProfile>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attachment(deserializer));
+      ans_.add(sse_decode_llm_profile(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<AttachmentAnnotationJob> sse_decode_list_attachment_annotation_job(
+  List<LlmSpendingLimit> sse_decode_list_llm_spending_limit(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttachmentAnnotationJob>[];
+    var ans_ = <LlmSpendingLimit>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attachment_annotation_job(deserializer));
+      ans_.add(sse_decode_llm_spending_limit(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<AttachmentPlaceJob> sse_decode_list_attachment_place_job(
+  List<LlmUsageAggregate> sse_decode_list_llm_usage_aggregate(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttachmentPlaceJob>[];
+    var ans_ = <LlmUsageAggregate>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attachment_place_job(deserializer));
+      ans_.add(sse_decode_llm_usage_aggregate(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<CloudMediaBackup> sse_decode_list_cloud_media_backup(
+  List<LocalGgufModelInfo> sse_decode_list_local_gguf_model_info(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CloudMediaBackup>[];
+    var ans_ = <LocalGgufModelInfo>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_cloud_media_backup(deserializer));
+      ans_.add(sse_decode_local_gguf_model_info(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<Conversation> sse_decode_list_conversation(
-      SseDeserializer deserializer) {
+  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Conversation>[];
+    var ans_ = <Message>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_conversation(deserializer));
+      ans_.add(sse_decode_message(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<EmbeddingProfile> sse_decode_list_embedding_profile(
+  List<MessageCitation> sse_decode_list_message_citation(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EmbeddingProfile>[];
+    var ans_ = <MessageCitation>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_embedding_profile(deserializer));
+      ans_.add(sse_decode_message_citation(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<Event> sse_decode_list_event(SseDeserializer deserializer) {
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
 
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Event>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_event(deserializer));
-    }
-    return ans_;
+    return deserializer.buffer.getUint8List(len_);
   }
 
   @protected
-  List<LlmProfile> sse_decode_list_llm_profile(SseDeserializer deserializer) {
+  List<PromptTemplate> sse_decode_list_prompt_template(
+      SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LlmProfile>[];
+    var ans_ = <PromptTemplate>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_llm_profile(deserializer));
+      ans_.add(sse_decode_prompt_template(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<LlmUsageAggregate> sse_decode_list_llm_usage_aggregate(
+  List<PromptTemplateSelection> sse_decode_list_prompt_template_selection(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LlmUsageAggregate>[];
+    var ans_ = <PromptTemplateSelection>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_llm_usage_aggregate(deserializer));
+      ans_.add(sse_decode_prompt_template_selection(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+  List<ReviewActivity> sse_decode_list_review_activity(
+      SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Message>[];
+    var ans_ = <ReviewActivity>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_message(deserializer));
+      ans_.add(sse_decode_review_activity(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  List<ReviewItem> sse_decode_list_review_item(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <ReviewItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_review_item(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  List<SemanticParseFailureReasonCount> sse_decode_list_semantic_parse_failure_reason_count(
+      SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <SemanticParseFailureReasonCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_semantic_parse_failure_reason_count(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -8964,6 +12606,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<StorageUsageEntry> sse_decode_list_storage_usage_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StorageUsageEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_storage_usage_entry(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Tag> sse_decode_list_tag(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8989,6 +12644,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<TagNode> sse_decode_list_tag_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagNode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_node(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagRule> sse_decode_list_tag_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_rule(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Todo> sse_decode_list_todo(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9027,6 +12706,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  LlmModelPrice sse_decode_llm_model_price(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_providerType = sse_decode_String(deserializer);
+    var var_modelName = sse_decode_String(deserializer);
+    var var_inputUsdPerMillionTokens = sse_decode_f_64(deserializer);
+    var var_outputUsdPerMillionTokens = sse_decode_f_64(deserializer);
+    var var_updatedAtMs = sse_decode_i_64(deserializer);
+    return LlmModelPrice(
+        providerType: var_providerType,
+        modelName: var_modelName,
+        inputUsdPerMillionTokens: var_inputUsdPerMillionTokens,
+        outputUsdPerMillionTokens: var_outputUsdPerMillionTokens,
+        updatedAtMs: var_updatedAtMs);
+  }
+
+  @protected
+  LlmMonthlyCost sse_decode_llm_monthly_cost(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_profileId = sse_decode_String(deserializer);
+    var var_purpose = sse_decode_String(deserializer);
+    var var_requests = sse_decode_i_64(deserializer);
+    var var_requestsWithUsage = sse_decode_i_64(deserializer);
+    var var_inputTokens = sse_decode_i_64(deserializer);
+    var var_outputTokens = sse_decode_i_64(deserializer);
+    var var_costUsdMicros = sse_decode_i_64(deserializer);
+    return LlmMonthlyCost(
+        profileId: var_profileId,
+        purpose: var_purpose,
+        requests: var_requests,
+        requestsWithUsage: var_requestsWithUsage,
+        inputTokens: var_inputTokens,
+        outputTokens: var_outputTokens,
+        costUsdMicros: var_costUsdMicros);
+  }
+
   @protected
   LlmProfile sse_decode_llm_profile(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9049,6 +12764,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         updatedAtMs: var_updatedAtMs);
   }
 
+  @protected
+  LlmSpendingLimit sse_decode_llm_spending_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_profileId = sse_decode_String(deserializer);
+    var var_monthlyLimitUsdMicros = sse_decode_i_64(deserializer);
+    return LlmSpendingLimit(
+        profileId: var_profileId,
+        monthlyLimitUsdMicros: var_monthlyLimitUsdMicros);
+  }
+
   @protected
   LlmUsageAggregate sse_decode_llm_usage_aggregate(
       SseDeserializer deserializer) {
@@ -9068,6 +12793,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         totalTokens: var_totalTokens);
   }
 
+  @protected
+  LocalGgufModelInfo sse_decode_local_gguf_model_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileName = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    var var_architecture = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_contextLength = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return LocalGgufModelInfo(
+        fileName: var_fileName,
+        sizeBytes: var_sizeBytes,
+        architecture: var_architecture,
+        name: var_name,
+        contextLength: var_contextLength);
+  }
+
   @protected
   MediaAnnotationConfig sse_decode_media_annotation_config(
       SseDeserializer deserializer) {
@@ -9105,6 +12847,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         isMemory: var_isMemory);
   }
 
+  @protected
+  MessageCitation sse_decode_message_citation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_marker = sse_decode_u_32(deserializer);
+    var var_sourceKind = sse_decode_String(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
+    return MessageCitation(
+        marker: var_marker, sourceKind: var_sourceKind, sourceId: var_sourceId);
+  }
+
   @protected
   OcrPayload sse_decode_ocr_payload(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9191,6 +12943,105 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PromptTemplate sse_decode_prompt_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_purpose = sse_decode_String(deserializer);
+    var var_body = sse_decode_String(deserializer);
+    var var_createdAtMs = sse_decode_i_64(deserializer);
+    var var_updatedAtMs = sse_decode_i_64(deserializer);
+    return PromptTemplate(
+        id: var_id,
+        name: var_name,
+        purpose: var_purpose,
+        body: var_body,
+        createdAtMs: var_createdAtMs,
+        updatedAtMs: var_updatedAtMs);
+  }
+
+  @protected
+  PromptTemplateSelection sse_decode_prompt_template_selection(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_purpose = sse_decode_String(deserializer);
+    var var_profileId = sse_decode_String(deserializer);
+    var var_templateId = sse_decode_String(deserializer);
+    return PromptTemplateSelection(
+        purpose: var_purpose,
+        profileId: var_profileId,
+        templateId: var_templateId);
+  }
+
+  @protected
+  ReviewActivity sse_decode_review_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_itemKind = sse_decode_String(deserializer);
+    var var_itemId = sse_decode_String(deserializer);
+    var var_grade = sse_decode_i_64(deserializer);
+    var var_easeFactor = sse_decode_f_64(deserializer);
+    var var_intervalDays = sse_decode_i_64(deserializer);
+    var var_dueAtMs = sse_decode_i_64(deserializer);
+    var var_reviewedAtMs = sse_decode_i_64(deserializer);
+    return ReviewActivity(
+        id: var_id,
+        itemKind: var_itemKind,
+        itemId: var_itemId,
+        grade: var_grade,
+        easeFactor: var_easeFactor,
+        intervalDays: var_intervalDays,
+        dueAtMs: var_dueAtMs,
+        reviewedAtMs: var_reviewedAtMs);
+  }
+
+  @protected
+  ReviewItem sse_decode_review_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_itemKind = sse_decode_String(deserializer);
+    var var_itemId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_easeFactor = sse_decode_f_64(deserializer);
+    var var_intervalDays = sse_decode_i_64(deserializer);
+    var var_repetitions = sse_decode_i_64(deserializer);
+    var var_lapses = sse_decode_i_64(deserializer);
+    var var_dueAtMs = sse_decode_i_64(deserializer);
+    var var_lastReviewedAtMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_isActive = sse_decode_bool(deserializer);
+    return ReviewItem(
+        itemKind: var_itemKind,
+        itemId: var_itemId,
+        title: var_title,
+        easeFactor: var_easeFactor,
+        intervalDays: var_intervalDays,
+        repetitions: var_repetitions,
+        lapses: var_lapses,
+        dueAtMs: var_dueAtMs,
+        lastReviewedAtMs: var_lastReviewedAtMs,
+        isActive: var_isActive);
+  }
+
+  @protected
+  SemanticParseFailureReasonCount sse_decode_semantic_parse_failure_reason_count(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_reason = sse_decode_String(deserializer);
+    var var_jobs = sse_decode_i_64(deserializer);
+    return SemanticParseFailureReasonCount(reason: var_reason, jobs: var_jobs);
+  }
+
   @protected
   SemanticParseJob sse_decode_semantic_parse_job(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9255,6 +13106,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         autoPurgeIncludeImages: var_autoPurgeIncludeImages);
   }
 
+  @protected
+  StorageUsageEntry sse_decode_storage_usage_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_bytes = sse_decode_i_64(deserializer);
+    var var_count = sse_decode_i_64(deserializer);
+    return StorageUsageEntry(
+        category: var_category,
+        label: var_label,
+        bytes: var_bytes,
+        count: var_count);
+  }
+
+  @protected
+  StorageUsageReport sse_decode_storage_usage_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalBytes = sse_decode_i_64(deserializer);
+    var var_categories = sse_decode_list_storage_usage_entry(deserializer);
+    var var_byConversation = sse_decode_list_storage_usage_entry(deserializer);
+    var var_byMimeType = sse_decode_list_storage_usage_entry(deserializer);
+    return StorageUsageReport(
+        totalBytes: var_totalBytes,
+        categories: var_categories,
+        byConversation: var_byConversation,
+        byMimeType: var_byMimeType);
+  }
+
   @protected
   Tag sse_decode_tag(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9294,6 +13175,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         targetUsageCount: var_targetUsageCount);
   }
 
+  @protected
+  TagNode sse_decode_tag_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tag = sse_decode_tag(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    return TagNode(
+        tag: var_tag, parentId: var_parentId, path: var_path, depth: var_depth);
+  }
+
+  @protected
+  TagRule sse_decode_tag_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_pattern = sse_decode_String(deserializer);
+    var var_tagId = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_createdAtMs = sse_decode_i_64(deserializer);
+    var var_updatedAtMs = sse_decode_i_64(deserializer);
+    return TagRule(
+        id: var_id,
+        name: var_name,
+        kind: var_kind,
+        pattern: var_pattern,
+        tagId: var_tagId,
+        enabled: var_enabled,
+        createdAtMs: var_createdAtMs,
+        updatedAtMs: var_updatedAtMs);
+  }
+
   @protected
   Todo sse_decode_todo(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9356,6 +13270,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         dueLocalIso: var_dueLocalIso);
   }
 
+  @protected
+  TodoRollup sse_decode_todo_rollup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_todoId = sse_decode_String(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_subtasksTotal = sse_decode_i_64(deserializer);
+    var var_subtasksDone = sse_decode_i_64(deserializer);
+    var var_openBlockerIds = sse_decode_list_String(deserializer);
+    var var_completeWithSubtasks = sse_decode_bool(deserializer);
+    return TodoRollup(
+        todoId: var_todoId,
+        parentId: var_parentId,
+        subtasksTotal: var_subtasksTotal,
+        subtasksDone: var_subtasksDone,
+        openBlockerIds: var_openBlockerIds,
+        completeWithSubtasks: var_completeWithSubtasks);
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9403,6 +13335,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_agenda_day(AgendaDay self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.date, serializer);
+    sse_encode_i_64(self.dayStartMs, serializer);
+    sse_encode_list_agenda_item(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_agenda_item(AgendaItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.itemId, serializer);
+    sse_encode_String(self.sourceId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_i_64(self.atMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endAtMs, serializer);
+    sse_encode_opt_String(self.status, serializer);
+    sse_encode_opt_String(self.tz, serializer);
+    sse_encode_bool(self.isRecurring, serializer);
+  }
+
+  @protected
+  void sse_encode_agenda_page(AgendaPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_agenda_item(self.overdue, serializer);
+    sse_encode_list_agenda_day(self.days, serializer);
+    sse_encode_opt_String(self.nextCursor, serializer);
+  }
+
   @protected
   void sse_encode_attachment(Attachment self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9535,6 +13497,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_storage_policy_config(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_cal_dav_sync_report(
+      CalDavSyncReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pulledCreated, serializer);
+    sse_encode_u_32(self.pulledUpdated, serializer);
+    sse_encode_u_32(self.pulledDeleted, serializer);
+    sse_encode_u_32(self.pushedCreated, serializer);
+    sse_encode_u_32(self.pushedUpdated, serializer);
+    sse_encode_u_32(self.pushedDeleted, serializer);
+    sse_encode_u_32(self.conflicts, serializer);
+    sse_encode_list_String(self.errors, serializer);
+  }
+
   @protected
   void sse_encode_cloud_media_backup(
       CloudMediaBackup self, SseSerializer serializer) {
@@ -9609,16 +13591,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_event(Event self, SseSerializer serializer) {
+  void sse_encode_event(Event self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_i_64(self.startAtMs, serializer);
+    sse_encode_i_64(self.endAtMs, serializer);
+    sse_encode_String(self.tz, serializer);
+    sse_encode_opt_String(self.sourceEntryId, serializer);
+    sse_encode_i_64(self.createdAtMs, serializer);
+    sse_encode_i_64(self.updatedAtMs, serializer);
+  }
+
+  @protected
+  void sse_encode_event_occurrence(
+      EventOccurrence self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.eventId, serializer);
+    sse_encode_i_64(self.occurrenceStartMs, serializer);
     sse_encode_String(self.title, serializer);
     sse_encode_i_64(self.startAtMs, serializer);
     sse_encode_i_64(self.endAtMs, serializer);
     sse_encode_String(self.tz, serializer);
-    sse_encode_opt_String(self.sourceEntryId, serializer);
-    sse_encode_i_64(self.createdAtMs, serializer);
-    sse_encode_i_64(self.updatedAtMs, serializer);
+    sse_encode_bool(self.isRecurring, serializer);
+    sse_encode_bool(self.isMoved, serializer);
   }
 
   @protected
@@ -9639,6 +13635,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_ics_export_summary(
+      IcsExportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.events, serializer);
+    sse_encode_u_64(self.todos, serializer);
+  }
+
+  @protected
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.itemsScanned, serializer);
+    sse_encode_u_32(self.itemsImported, serializer);
+    sse_encode_u_32(self.itemsUpdated, serializer);
+    sse_encode_u_32(self.itemsSkippedUnchanged, serializer);
+    sse_encode_u_32(self.itemsSkippedDuplicate, serializer);
+    sse_encode_u_32(self.messagesCreated, serializer);
+    sse_encode_u_32(self.todosCreated, serializer);
+    sse_encode_u_32(self.attachmentsLinked, serializer);
+    sse_encode_u_32(self.tagsApplied, serializer);
+    sse_encode_list_String(self.errors, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_issue(
+      IntegrityIssue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.subject, serializer);
+    sse_encode_bool(self.fixed, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_report(
+      IntegrityReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_integrity_issue(self.issues, serializer);
+    sse_encode_u_32(self.fixedCount, serializer);
+  }
+
+  @protected
+  void sse_encode_jsonl_export_summary(
+      JsonlExportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.schemaVersion, serializer);
+    sse_encode_i_64(self.exportedAtMs, serializer);
+    sse_encode_list_jsonl_stream_count(self.streams, serializer);
+  }
+
+  @protected
+  void sse_encode_jsonl_export_verification(
+      JsonlExportVerification self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.schemaVersion, serializer);
+    sse_encode_list_jsonl_stream_count(self.streams, serializer);
+    sse_encode_list_String(self.problems, serializer);
+  }
+
+  @protected
+  void sse_encode_jsonl_stream_count(
+      JsonlStreamCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.stream, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9648,6 +13710,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_agenda_day(
+      List<AgendaDay> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_agenda_day(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_agenda_item(
+      List<AgendaItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_agenda_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_attachment(
       List<Attachment> self, SseSerializer serializer) {
@@ -9717,6 +13799,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_event_occurrence(
+      List<EventOccurrence> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_event_occurrence(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_integrity_issue(
+      List<IntegrityIssue> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_integrity_issue(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_jsonl_stream_count(
+      List<JsonlStreamCount> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_jsonl_stream_count(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_llm_model_price(
+      List<LlmModelPrice> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_llm_model_price(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_llm_monthly_cost(
+      List<LlmMonthlyCost> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_llm_monthly_cost(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_llm_profile(
       List<LlmProfile> self, SseSerializer serializer) {
@@ -9727,6 +13859,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_llm_spending_limit(
+      List<LlmSpendingLimit> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_llm_spending_limit(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_llm_usage_aggregate(
       List<LlmUsageAggregate> self, SseSerializer serializer) {
@@ -9737,6 +13879,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_local_gguf_model_info(
+      List<LocalGgufModelInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_local_gguf_model_info(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9746,6 +13898,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_message_citation(
+      List<MessageCitation> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_citation(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+      Int64List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
       List<int> self, SseSerializer serializer) {
@@ -9763,6 +13933,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_prompt_template(
+      List<PromptTemplate> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prompt_template(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prompt_template_selection(
+      List<PromptTemplateSelection> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prompt_template_selection(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_review_activity(
+      List<ReviewActivity> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_review_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_review_item(
+      List<ReviewItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_review_item(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_semantic_parse_failure_reason_count(
+      List<SemanticParseFailureReasonCount> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_semantic_parse_failure_reason_count(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_semantic_parse_job(
       List<SemanticParseJob> self, SseSerializer serializer) {
@@ -9793,6 +14013,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_storage_usage_entry(
+      List<StorageUsageEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_storage_usage_entry(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_tag(List<Tag> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9812,6 +14042,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_tag_node(List<TagNode> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_node(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_tag_rule(List<TagRule> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_rule(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_todo(List<Todo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9841,6 +14089,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_llm_model_price(
+      LlmModelPrice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.providerType, serializer);
+    sse_encode_String(self.modelName, serializer);
+    sse_encode_f_64(self.inputUsdPerMillionTokens, serializer);
+    sse_encode_f_64(self.outputUsdPerMillionTokens, serializer);
+    sse_encode_i_64(self.updatedAtMs, serializer);
+  }
+
+  @protected
+  void sse_encode_llm_monthly_cost(
+      LlmMonthlyCost self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.profileId, serializer);
+    sse_encode_String(self.purpose, serializer);
+    sse_encode_i_64(self.requests, serializer);
+    sse_encode_i_64(self.requestsWithUsage, serializer);
+    sse_encode_i_64(self.inputTokens, serializer);
+    sse_encode_i_64(self.outputTokens, serializer);
+    sse_encode_i_64(self.costUsdMicros, serializer);
+  }
+
   @protected
   void sse_encode_llm_profile(LlmProfile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9854,6 +14126,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.updatedAtMs, serializer);
   }
 
+  @protected
+  void sse_encode_llm_spending_limit(
+      LlmSpendingLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.profileId, serializer);
+    sse_encode_i_64(self.monthlyLimitUsdMicros, serializer);
+  }
+
   @protected
   void sse_encode_llm_usage_aggregate(
       LlmUsageAggregate self, SseSerializer serializer) {
@@ -9866,6 +14146,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.totalTokens, serializer);
   }
 
+  @protected
+  void sse_encode_local_gguf_model_info(
+      LocalGgufModelInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.architecture, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.contextLength, serializer);
+  }
+
   @protected
   void sse_encode_media_annotation_config(
       MediaAnnotationConfig self, SseSerializer serializer) {
@@ -9889,6 +14180,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isMemory, serializer);
   }
 
+  @protected
+  void sse_encode_message_citation(
+      MessageCitation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.marker, serializer);
+    sse_encode_String(self.sourceKind, serializer);
+    sse_encode_String(self.sourceId, serializer);
+  }
+
   @protected
   void sse_encode_ocr_payload(OcrPayload self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9964,6 +14264,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_prompt_template(
+      PromptTemplate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.purpose, serializer);
+    sse_encode_String(self.body, serializer);
+    sse_encode_i_64(self.createdAtMs, serializer);
+    sse_encode_i_64(self.updatedAtMs, serializer);
+  }
+
+  @protected
+  void sse_encode_prompt_template_selection(
+      PromptTemplateSelection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.purpose, serializer);
+    sse_encode_String(self.profileId, serializer);
+    sse_encode_String(self.templateId, serializer);
+  }
+
+  @protected
+  void sse_encode_review_activity(
+      ReviewActivity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.itemKind, serializer);
+    sse_encode_String(self.itemId, serializer);
+    sse_encode_i_64(self.grade, serializer);
+    sse_encode_f_64(self.easeFactor, serializer);
+    sse_encode_i_64(self.intervalDays, serializer);
+    sse_encode_i_64(self.dueAtMs, serializer);
+    sse_encode_i_64(self.reviewedAtMs, serializer);
+  }
+
+  @protected
+  void sse_encode_review_item(ReviewItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.itemKind, serializer);
+    sse_encode_String(self.itemId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_f_64(self.easeFactor, serializer);
+    sse_encode_i_64(self.intervalDays, serializer);
+    sse_encode_i_64(self.repetitions, serializer);
+    sse_encode_i_64(self.lapses, serializer);
+    sse_encode_i_64(self.dueAtMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastReviewedAtMs, serializer);
+    sse_encode_bool(self.isActive, serializer);
+  }
+
+  @protected
+  void sse_encode_semantic_parse_failure_reason_count(
+      SemanticParseFailureReasonCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.reason, serializer);
+    sse_encode_i_64(self.jobs, serializer);
+  }
+
   @protected
   void sse_encode_semantic_parse_job(
       SemanticParseJob self, SseSerializer serializer) {
@@ -10009,6 +14377,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.autoPurgeIncludeImages, serializer);
   }
 
+  @protected
+  void sse_encode_storage_usage_entry(
+      StorageUsageEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.category, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_i_64(self.bytes, serializer);
+    sse_encode_i_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_storage_usage_report(
+      StorageUsageReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.totalBytes, serializer);
+    sse_encode_list_storage_usage_entry(self.categories, serializer);
+    sse_encode_list_storage_usage_entry(self.byConversation, serializer);
+    sse_encode_list_storage_usage_entry(self.byMimeType, serializer);
+  }
+
   @protected
   void sse_encode_tag(Tag self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10033,6 +14421,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.targetUsageCount, serializer);
   }
 
+  @protected
+  void sse_encode_tag_node(TagNode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tag(self.tag, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.depth, serializer);
+  }
+
+  @protected
+  void sse_encode_tag_rule(TagRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_String(self.tagId, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_i_64(self.createdAtMs, serializer);
+    sse_encode_i_64(self.updatedAtMs, serializer);
+  }
+
   @protected
   void sse_encode_todo(Todo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10070,6 +14480,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.dueLocalIso, serializer);
   }
 
+  @protected
+  void sse_encode_todo_rollup(TodoRollup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.todoId, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_i_64(self.subtasksTotal, serializer);
+    sse_encode_i_64(self.subtasksDone, serializer);
+    sse_encode_list_String(self.openBlockerIds, serializer);
+    sse_encode_bool(self.completeWithSubtasks, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
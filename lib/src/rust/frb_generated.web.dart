@@ -13,7 +13,12 @@ import 'api/content_enrichment.dart';
 import 'api/content_extract.dart';
 import 'api/core.dart';
 import 'api/desktop_media.dart';
+import 'api/export.dart';
+import 'api/import.dart';
+import 'api/local_llm.dart';
+import 'api/maintenance.dart';
 import 'api/media_annotation.dart';
+import 'api/prompt_templates.dart';
 import 'api/simple.dart';
 import 'api/sync_progress.dart';
 import 'api/tags.dart';
@@ -21,9 +26,14 @@ import 'dart:async';
 import 'dart:convert';
 import 'db.dart';
 import 'desktop_media/ocr.dart';
+import 'export/ics.dart';
+import 'export/jsonl.dart';
 import 'frb_generated.dart';
+import 'import.dart';
+import 'llm/local_gguf.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'semantic_parse.dart';
+import 'sync/caldav.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -42,6 +52,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AgendaDay dco_decode_agenda_day(dynamic raw);
+
+  @protected
+  AgendaItem dco_decode_agenda_item(dynamic raw);
+
+  @protected
+  AgendaPage dco_decode_agenda_page(dynamic raw);
+
   @protected
   Attachment dco_decode_attachment(dynamic raw);
 
@@ -90,6 +109,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StoragePolicyConfig dco_decode_box_autoadd_storage_policy_config(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  CalDavSyncReport dco_decode_cal_dav_sync_report(dynamic raw);
+
   @protected
   CloudMediaBackup dco_decode_cloud_media_backup(dynamic raw);
 
@@ -108,6 +133,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Event dco_decode_event(dynamic raw);
 
+  @protected
+  EventOccurrence dco_decode_event_occurrence(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
@@ -117,9 +145,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  IcsExportSummary dco_decode_ics_export_summary(dynamic raw);
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw);
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw);
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw);
+
+  @protected
+  JsonlExportSummary dco_decode_jsonl_export_summary(dynamic raw);
+
+  @protected
+  JsonlExportVerification dco_decode_jsonl_export_verification(dynamic raw);
+
+  @protected
+  JsonlStreamCount dco_decode_jsonl_stream_count(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AgendaDay> dco_decode_list_agenda_day(dynamic raw);
+
+  @protected
+  List<AgendaItem> dco_decode_list_agenda_item(dynamic raw);
+
   @protected
   List<Attachment> dco_decode_list_attachment(dynamic raw);
 
@@ -142,21 +197,65 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Event> dco_decode_list_event(dynamic raw);
 
+  @protected
+  List<EventOccurrence> dco_decode_list_event_occurrence(dynamic raw);
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw);
+
+  @protected
+  List<JsonlStreamCount> dco_decode_list_jsonl_stream_count(dynamic raw);
+
+  @protected
+  List<LlmModelPrice> dco_decode_list_llm_model_price(dynamic raw);
+
+  @protected
+  List<LlmMonthlyCost> dco_decode_list_llm_monthly_cost(dynamic raw);
+
   @protected
   List<LlmProfile> dco_decode_list_llm_profile(dynamic raw);
 
+  @protected
+  List<LlmSpendingLimit> dco_decode_list_llm_spending_limit(dynamic raw);
+
   @protected
   List<LlmUsageAggregate> dco_decode_list_llm_usage_aggregate(dynamic raw);
 
+  @protected
+  List<LocalGgufModelInfo> dco_decode_list_local_gguf_model_info(dynamic raw);
+
   @protected
   List<Message> dco_decode_list_message(dynamic raw);
 
+  @protected
+  List<MessageCitation> dco_decode_list_message_citation(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<PromptTemplate> dco_decode_list_prompt_template(dynamic raw);
+
+  @protected
+  List<PromptTemplateSelection> dco_decode_list_prompt_template_selection(
+      dynamic raw);
+
+  @protected
+  List<ReviewActivity> dco_decode_list_review_activity(dynamic raw);
+
+  @protected
+  List<ReviewItem> dco_decode_list_review_item(dynamic raw);
+
+  @protected
+  List<SemanticParseFailureReasonCount> dco_decode_list_semantic_parse_failure_reason_count(
+      dynamic raw);
+
   @protected
   List<SemanticParseJob> dco_decode_list_semantic_parse_job(dynamic raw);
 
@@ -166,12 +265,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<SimilarTodoThread> dco_decode_list_similar_todo_thread(dynamic raw);
 
+  @protected
+  List<StorageUsageEntry> dco_decode_list_storage_usage_entry(dynamic raw);
+
   @protected
   List<Tag> dco_decode_list_tag(dynamic raw);
 
   @protected
   List<TagMergeSuggestion> dco_decode_list_tag_merge_suggestion(dynamic raw);
 
+  @protected
+  List<TagNode> dco_decode_list_tag_node(dynamic raw);
+
+  @protected
+  List<TagRule> dco_decode_list_tag_rule(dynamic raw);
+
   @protected
   List<Todo> dco_decode_list_todo(dynamic raw);
 
@@ -181,18 +289,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<TodoCandidate> dco_decode_list_todo_candidate(dynamic raw);
 
+  @protected
+  LlmModelPrice dco_decode_llm_model_price(dynamic raw);
+
+  @protected
+  LlmMonthlyCost dco_decode_llm_monthly_cost(dynamic raw);
+
   @protected
   LlmProfile dco_decode_llm_profile(dynamic raw);
 
+  @protected
+  LlmSpendingLimit dco_decode_llm_spending_limit(dynamic raw);
+
   @protected
   LlmUsageAggregate dco_decode_llm_usage_aggregate(dynamic raw);
 
+  @protected
+  LocalGgufModelInfo dco_decode_local_gguf_model_info(dynamic raw);
+
   @protected
   MediaAnnotationConfig dco_decode_media_annotation_config(dynamic raw);
 
   @protected
   Message dco_decode_message(dynamic raw);
 
+  @protected
+  MessageCitation dco_decode_message_citation(dynamic raw);
+
   @protected
   OcrPayload dco_decode_ocr_payload(dynamic raw);
 
@@ -216,6 +339,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Message? dco_decode_opt_box_autoadd_message(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  PromptTemplate dco_decode_prompt_template(dynamic raw);
+
+  @protected
+  PromptTemplateSelection dco_decode_prompt_template_selection(dynamic raw);
+
+  @protected
+  ReviewActivity dco_decode_review_activity(dynamic raw);
+
+  @protected
+  ReviewItem dco_decode_review_item(dynamic raw);
+
+  @protected
+  SemanticParseFailureReasonCount dco_decode_semantic_parse_failure_reason_count(
+      dynamic raw);
+
   @protected
   SemanticParseJob dco_decode_semantic_parse_job(dynamic raw);
 
@@ -228,12 +370,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StoragePolicyConfig dco_decode_storage_policy_config(dynamic raw);
 
+  @protected
+  StorageUsageEntry dco_decode_storage_usage_entry(dynamic raw);
+
+  @protected
+  StorageUsageReport dco_decode_storage_usage_report(dynamic raw);
+
   @protected
   Tag dco_decode_tag(dynamic raw);
 
   @protected
   TagMergeSuggestion dco_decode_tag_merge_suggestion(dynamic raw);
 
+  @protected
+  TagNode dco_decode_tag_node(dynamic raw);
+
+  @protected
+  TagRule dco_decode_tag_rule(dynamic raw);
+
   @protected
   Todo dco_decode_todo(dynamic raw);
 
@@ -243,6 +397,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TodoCandidate dco_decode_todo_candidate(dynamic raw);
 
+  @protected
+  TodoRollup dco_decode_todo_rollup(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -265,6 +422,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AgendaDay sse_decode_agenda_day(SseDeserializer deserializer);
+
+  @protected
+  AgendaItem sse_decode_agenda_item(SseDeserializer deserializer);
+
+  @protected
+  AgendaPage sse_decode_agenda_page(SseDeserializer deserializer);
+
   @protected
   Attachment sse_decode_attachment(SseDeserializer deserializer);
 
@@ -319,6 +485,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   StoragePolicyConfig sse_decode_box_autoadd_storage_policy_config(
       SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  CalDavSyncReport sse_decode_cal_dav_sync_report(SseDeserializer deserializer);
+
   @protected
   CloudMediaBackup sse_decode_cloud_media_backup(SseDeserializer deserializer);
 
@@ -339,6 +511,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Event sse_decode_event(SseDeserializer deserializer);
 
+  @protected
+  EventOccurrence sse_decode_event_occurrence(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
@@ -348,9 +523,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  IcsExportSummary sse_decode_ics_export_summary(SseDeserializer deserializer);
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer);
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
+  @protected
+  JsonlExportSummary sse_decode_jsonl_export_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonlExportVerification sse_decode_jsonl_export_verification(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonlStreamCount sse_decode_jsonl_stream_count(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AgendaDay> sse_decode_list_agenda_day(SseDeserializer deserializer);
+
+  @protected
+  List<AgendaItem> sse_decode_list_agenda_item(SseDeserializer deserializer);
+
   @protected
   List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer);
 
@@ -376,22 +580,76 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Event> sse_decode_list_event(SseDeserializer deserializer);
 
+  @protected
+  List<EventOccurrence> sse_decode_list_event_occurrence(
+      SseDeserializer deserializer);
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+      SseDeserializer deserializer);
+
+  @protected
+  List<JsonlStreamCount> sse_decode_list_jsonl_stream_count(
+      SseDeserializer deserializer);
+
+  @protected
+  List<LlmModelPrice> sse_decode_list_llm_model_price(
+      SseDeserializer deserializer);
+
+  @protected
+  List<LlmMonthlyCost> sse_decode_list_llm_monthly_cost(
+      SseDeserializer deserializer);
+
   @protected
   List<LlmProfile> sse_decode_list_llm_profile(SseDeserializer deserializer);
 
+  @protected
+  List<LlmSpendingLimit> sse_decode_list_llm_spending_limit(
+      SseDeserializer deserializer);
+
   @protected
   List<LlmUsageAggregate> sse_decode_list_llm_usage_aggregate(
       SseDeserializer deserializer);
 
+  @protected
+  List<LocalGgufModelInfo> sse_decode_list_local_gguf_model_info(
+      SseDeserializer deserializer);
+
   @protected
   List<Message> sse_decode_list_message(SseDeserializer deserializer);
 
+  @protected
+  List<MessageCitation> sse_decode_list_message_citation(
+      SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<PromptTemplate> sse_decode_list_prompt_template(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PromptTemplateSelection> sse_decode_list_prompt_template_selection(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ReviewActivity> sse_decode_list_review_activity(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ReviewItem> sse_decode_list_review_item(SseDeserializer deserializer);
+
+  @protected
+  List<SemanticParseFailureReasonCount> sse_decode_list_semantic_parse_failure_reason_count(
+      SseDeserializer deserializer);
+
   @protected
   List<SemanticParseJob> sse_decode_list_semantic_parse_job(
       SseDeserializer deserializer);
@@ -404,6 +662,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<SimilarTodoThread> sse_decode_list_similar_todo_thread(
       SseDeserializer deserializer);
 
+  @protected
+  List<StorageUsageEntry> sse_decode_list_storage_usage_entry(
+      SseDeserializer deserializer);
+
   @protected
   List<Tag> sse_decode_list_tag(SseDeserializer deserializer);
 
@@ -411,6 +673,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<TagMergeSuggestion> sse_decode_list_tag_merge_suggestion(
       SseDeserializer deserializer);
 
+  @protected
+  List<TagNode> sse_decode_list_tag_node(SseDeserializer deserializer);
+
+  @protected
+  List<TagRule> sse_decode_list_tag_rule(SseDeserializer deserializer);
+
   @protected
   List<Todo> sse_decode_list_todo(SseDeserializer deserializer);
 
@@ -422,13 +690,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<TodoCandidate> sse_decode_list_todo_candidate(
       SseDeserializer deserializer);
 
+  @protected
+  LlmModelPrice sse_decode_llm_model_price(SseDeserializer deserializer);
+
+  @protected
+  LlmMonthlyCost sse_decode_llm_monthly_cost(SseDeserializer deserializer);
+
   @protected
   LlmProfile sse_decode_llm_profile(SseDeserializer deserializer);
 
+  @protected
+  LlmSpendingLimit sse_decode_llm_spending_limit(SseDeserializer deserializer);
+
   @protected
   LlmUsageAggregate sse_decode_llm_usage_aggregate(
       SseDeserializer deserializer);
 
+  @protected
+  LocalGgufModelInfo sse_decode_local_gguf_model_info(
+      SseDeserializer deserializer);
+
   @protected
   MediaAnnotationConfig sse_decode_media_annotation_config(
       SseDeserializer deserializer);
@@ -436,6 +717,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Message sse_decode_message(SseDeserializer deserializer);
 
+  @protected
+  MessageCitation sse_decode_message_citation(SseDeserializer deserializer);
+
   @protected
   OcrPayload sse_decode_ocr_payload(SseDeserializer deserializer);
 
@@ -459,6 +743,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  PromptTemplate sse_decode_prompt_template(SseDeserializer deserializer);
+
+  @protected
+  PromptTemplateSelection sse_decode_prompt_template_selection(
+      SseDeserializer deserializer);
+
+  @protected
+  ReviewActivity sse_decode_review_activity(SseDeserializer deserializer);
+
+  @protected
+  ReviewItem sse_decode_review_item(SseDeserializer deserializer);
+
+  @protected
+  SemanticParseFailureReasonCount sse_decode_semantic_parse_failure_reason_count(
+      SseDeserializer deserializer);
+
   @protected
   SemanticParseJob sse_decode_semantic_parse_job(SseDeserializer deserializer);
 
@@ -473,6 +777,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   StoragePolicyConfig sse_decode_storage_policy_config(
       SseDeserializer deserializer);
 
+  @protected
+  StorageUsageEntry sse_decode_storage_usage_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  StorageUsageReport sse_decode_storage_usage_report(
+      SseDeserializer deserializer);
+
   @protected
   Tag sse_decode_tag(SseDeserializer deserializer);
 
@@ -480,6 +792,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TagMergeSuggestion sse_decode_tag_merge_suggestion(
       SseDeserializer deserializer);
 
+  @protected
+  TagNode sse_decode_tag_node(SseDeserializer deserializer);
+
+  @protected
+  TagRule sse_decode_tag_rule(SseDeserializer deserializer);
+
   @protected
   Todo sse_decode_todo(SseDeserializer deserializer);
 
@@ -489,6 +807,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TodoCandidate sse_decode_todo_candidate(SseDeserializer deserializer);
 
+  @protected
+  TodoRollup sse_decode_todo_rollup(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -512,6 +833,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_agenda_day(AgendaDay self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agenda_item(AgendaItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agenda_page(AgendaPage self, SseSerializer serializer);
+
   @protected
   void sse_encode_attachment(Attachment self, SseSerializer serializer);
 
@@ -568,6 +898,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_storage_policy_config(
       StoragePolicyConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cal_dav_sync_report(
+      CalDavSyncReport self, SseSerializer serializer);
+
   @protected
   void sse_encode_cloud_media_backup(
       CloudMediaBackup self, SseSerializer serializer);
@@ -590,6 +927,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_event(Event self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_event_occurrence(
+      EventOccurrence self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
@@ -599,9 +940,44 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ics_export_summary(
+      IcsExportSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_integrity_issue(
+      IntegrityIssue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_integrity_report(
+      IntegrityReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_jsonl_export_summary(
+      JsonlExportSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_jsonl_export_verification(
+      JsonlExportVerification self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_jsonl_stream_count(
+      JsonlStreamCount self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_agenda_day(
+      List<AgendaDay> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_agenda_item(
+      List<AgendaItem> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_attachment(
       List<Attachment> self, SseSerializer serializer);
@@ -629,17 +1005,53 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_event(List<Event> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_event_occurrence(
+      List<EventOccurrence> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_integrity_issue(
+      List<IntegrityIssue> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_jsonl_stream_count(
+      List<JsonlStreamCount> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_llm_model_price(
+      List<LlmModelPrice> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_llm_monthly_cost(
+      List<LlmMonthlyCost> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_llm_profile(
       List<LlmProfile> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_llm_spending_limit(
+      List<LlmSpendingLimit> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_llm_usage_aggregate(
       List<LlmUsageAggregate> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_local_gguf_model_info(
+      List<LocalGgufModelInfo> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_message(List<Message> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_message_citation(
+      List<MessageCitation> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+      Int64List self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -647,6 +1059,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prompt_template(
+      List<PromptTemplate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prompt_template_selection(
+      List<PromptTemplateSelection> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_review_activity(
+      List<ReviewActivity> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_review_item(
+      List<ReviewItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_semantic_parse_failure_reason_count(
+      List<SemanticParseFailureReasonCount> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_semantic_parse_job(
       List<SemanticParseJob> self, SseSerializer serializer);
@@ -659,6 +1091,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_similar_todo_thread(
       List<SimilarTodoThread> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_storage_usage_entry(
+      List<StorageUsageEntry> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_tag(List<Tag> self, SseSerializer serializer);
 
@@ -666,6 +1102,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_tag_merge_suggestion(
       List<TagMergeSuggestion> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_tag_node(List<TagNode> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_tag_rule(List<TagRule> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_todo(List<Todo> self, SseSerializer serializer);
 
@@ -677,13 +1119,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_todo_candidate(
       List<TodoCandidate> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_llm_model_price(LlmModelPrice self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_llm_monthly_cost(
+      LlmMonthlyCost self, SseSerializer serializer);
+
   @protected
   void sse_encode_llm_profile(LlmProfile self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_llm_spending_limit(
+      LlmSpendingLimit self, SseSerializer serializer);
+
   @protected
   void sse_encode_llm_usage_aggregate(
       LlmUsageAggregate self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_local_gguf_model_info(
+      LocalGgufModelInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_media_annotation_config(
       MediaAnnotationConfig self, SseSerializer serializer);
@@ -691,6 +1148,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_message(Message self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_message_citation(
+      MessageCitation self, SseSerializer serializer);
+
   @protected
   void sse_encode_ocr_payload(OcrPayload self, SseSerializer serializer);
 
@@ -716,6 +1177,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_message(
       Message? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prompt_template(
+      PromptTemplate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prompt_template_selection(
+      PromptTemplateSelection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_activity(
+      ReviewActivity self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_item(ReviewItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_semantic_parse_failure_reason_count(
+      SemanticParseFailureReasonCount self, SseSerializer serializer);
+
   @protected
   void sse_encode_semantic_parse_job(
       SemanticParseJob self, SseSerializer serializer);
@@ -732,6 +1215,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_storage_policy_config(
       StoragePolicyConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_storage_usage_entry(
+      StorageUsageEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_storage_usage_report(
+      StorageUsageReport self, SseSerializer serializer);
+
   @protected
   void sse_encode_tag(Tag self, SseSerializer serializer);
 
@@ -739,6 +1230,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_tag_merge_suggestion(
       TagMergeSuggestion self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_tag_node(TagNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_rule(TagRule self, SseSerializer serializer);
+
   @protected
   void sse_encode_todo(Todo self, SseSerializer serializer);
 
@@ -748,6 +1245,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_todo_candidate(TodoCandidate self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_todo_rollup(TodoRollup self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class ImportReport {
+  final int itemsScanned;
+  final int itemsImported;
+  final int itemsUpdated;
+  final int itemsSkippedUnchanged;
+  final int itemsSkippedDuplicate;
+  final int messagesCreated;
+  final int todosCreated;
+  final int attachmentsLinked;
+  final int tagsApplied;
+  final List<String> errors;
+
+  const ImportReport({
+    required this.itemsScanned,
+    required this.itemsImported,
+    required this.itemsUpdated,
+    required this.itemsSkippedUnchanged,
+    required this.itemsSkippedDuplicate,
+    required this.messagesCreated,
+    required this.todosCreated,
+    required this.attachmentsLinked,
+    required this.tagsApplied,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode =>
+      itemsScanned.hashCode ^
+      itemsImported.hashCode ^
+      itemsUpdated.hashCode ^
+      itemsSkippedUnchanged.hashCode ^
+      itemsSkippedDuplicate.hashCode ^
+      messagesCreated.hashCode ^
+      todosCreated.hashCode ^
+      attachmentsLinked.hashCode ^
+      tagsApplied.hashCode ^
+      errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportReport &&
+          runtimeType == other.runtimeType &&
+          itemsScanned == other.itemsScanned &&
+          itemsImported == other.itemsImported &&
+          itemsUpdated == other.itemsUpdated &&
+          itemsSkippedUnchanged == other.itemsSkippedUnchanged &&
+          itemsSkippedDuplicate == other.itemsSkippedDuplicate &&
+          messagesCreated == other.messagesCreated &&
+          todosCreated == other.todosCreated &&
+          attachmentsLinked == other.attachmentsLinked &&
+          tagsApplied == other.tagsApplied &&
+          errors == other.errors;
+}
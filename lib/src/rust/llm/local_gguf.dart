@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class LocalGgufModelInfo {
+  final String fileName;
+  final BigInt sizeBytes;
+  final String? architecture;
+  final String? name;
+  final int? contextLength;
+
+  const LocalGgufModelInfo({
+    required this.fileName,
+    required this.sizeBytes,
+    this.architecture,
+    this.name,
+    this.contextLength,
+  });
+
+  @override
+  int get hashCode =>
+      fileName.hashCode ^
+      sizeBytes.hashCode ^
+      architecture.hashCode ^
+      name.hashCode ^
+      contextLength.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalGgufModelInfo &&
+          runtimeType == other.runtimeType &&
+          fileName == other.fileName &&
+          sizeBytes == other.sizeBytes &&
+          architecture == other.architecture &&
+          name == other.name &&
+          contextLength == other.contextLength;
+}
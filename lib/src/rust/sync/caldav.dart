@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.38.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class CalDavSyncReport {
+  final int pulledCreated;
+  final int pulledUpdated;
+  final int pulledDeleted;
+  final int pushedCreated;
+  final int pushedUpdated;
+  final int pushedDeleted;
+  final int conflicts;
+  final List<String> errors;
+
+  const CalDavSyncReport({
+    required this.pulledCreated,
+    required this.pulledUpdated,
+    required this.pulledDeleted,
+    required this.pushedCreated,
+    required this.pushedUpdated,
+    required this.pushedDeleted,
+    required this.conflicts,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode =>
+      pulledCreated.hashCode ^
+      pulledUpdated.hashCode ^
+      pulledDeleted.hashCode ^
+      pushedCreated.hashCode ^
+      pushedUpdated.hashCode ^
+      pushedDeleted.hashCode ^
+      conflicts.hashCode ^
+      errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalDavSyncReport &&
+          runtimeType == other.runtimeType &&
+          pulledCreated == other.pulledCreated &&
+          pulledUpdated == other.pulledUpdated &&
+          pulledDeleted == other.pulledDeleted &&
+          pushedCreated == other.pushedCreated &&
+          pushedUpdated == other.pushedUpdated &&
+          pushedDeleted == other.pushedDeleted &&
+          conflicts == other.conflicts &&
+          errors == other.errors;
+}
@@ -35,6 +35,14 @@ fn emit_ask_ai_meta_if_any(sink: &StreamSink<String>, role: Option<&str>) -> Res
     let Some(role) = role else {
         return Ok(());
     };
+    if let Some(citations) = role.strip_prefix(rag::CITATIONS_ROLE_PREFIX) {
+        let payload =
+            format!("{ASK_AI_META_PREFIX}{{\"type\":\"citations\",\"citations\":{citations}}}");
+        if sink.add(payload).is_err() {
+            return Err(rag::StreamCancelled.into());
+        }
+        return Ok(());
+    }
     let Some(request_id) = role.strip_prefix(ASK_AI_META_REQUEST_ID_ROLE_PREFIX) else {
         return Ok(());
     };
@@ -136,7 +144,7 @@ fn collect_scoped_contexts(
     top_k: usize,
     time_scope: Option<TimeScope>,
     focus: ScopedFocus,
-) -> Result<Vec<String>> {
+) -> Result<Vec<rag::SourcedContext>> {
     let include_tag_ids = normalize_tag_ids(include_tag_ids);
     let exclude_tag_ids = normalize_tag_ids(exclude_tag_ids);
 
@@ -180,7 +188,7 @@ fn collect_scoped_contexts(
         return Ok(Vec::new());
     }
 
-    let mut contexts = Vec::<String>::new();
+    let mut contexts = Vec::<rag::SourcedContext>::new();
 
     let limit = top_k.max(1);
     for message_id in message_ids {
@@ -213,14 +221,18 @@ fn collect_scoped_contexts(
             continue;
         }
 
-        contexts.push(trimmed.to_string());
+        contexts.push(rag::SourcedContext {
+            source_kind: db::CITATION_SOURCE_MESSAGE.to_string(),
+            source_id: message.id.clone(),
+            text: trimmed.to_string(),
+        });
     }
 
     contexts.reverse();
     Ok(contexts)
 }
 
-fn build_scoped_prompt(question: &str, contexts: &[String]) -> String {
+fn build_scoped_prompt(question: &str, contexts: &[rag::SourcedContext]) -> String {
     let mut out = String::new();
     out.push_str("You are SecondLoop, a helpful personal assistant.\n");
     out.push_str("IMPORTANT: Reply in the same language as the user's question.\n");
@@ -234,8 +246,9 @@ fn build_scoped_prompt(question: &str, contexts: &[String]) -> String {
     } else {
         out.push_str("Scoped memories (quoted):\n");
         for (index, context) in contexts.iter().enumerate() {
-            out.push_str(&format!("{}. \"{}\"\n", index + 1, context));
+            out.push_str(&format!("[{}] \"{}\"\n", index + 1, context.text));
         }
+        out.push_str("Cite the memories you rely on inline with their markers, e.g. [1].\n");
     }
 
     out.push_str("\nQuestion: ");
@@ -295,58 +308,49 @@ fn stream_scoped_ask_with_provider(
     key: &[u8; 32],
     conversation_id: &str,
     question: &str,
-    contexts: &[String],
+    contexts: &[rag::SourcedContext],
     provider: &(impl rag::AnswerProvider + ?Sized),
     sink: &StreamSink<String>,
     emit_meta: bool,
 ) -> Result<()> {
     let prompt = build_scoped_prompt(question, contexts);
 
-    let mut has_text = false;
-    let mut assistant_text = String::new();
-    let result = provider.stream_answer(&prompt, &mut |ev| {
-        if emit_meta {
-            emit_ask_ai_meta_if_any(sink, ev.role.as_deref())?;
-        }
-
-        if ev.done {
-            if sink.add(String::new()).is_err() {
-                return Err(rag::StreamCancelled.into());
+    rag::stream_answer_with_citations(
+        conn,
+        key,
+        conversation_id,
+        question,
+        &prompt,
+        &rag::citations_for_contexts(contexts),
+        provider,
+        &mut |ev| {
+            let is_citations = ev
+                .role
+                .as_deref()
+                .is_some_and(|role| role.starts_with(rag::CITATIONS_ROLE_PREFIX));
+            if emit_meta || is_citations {
+                emit_ask_ai_meta_if_any(sink, ev.role.as_deref())?;
             }
-            return Ok(());
-        }
-
-        if ev.text_delta.is_empty() {
-            return Ok(());
-        }
 
-        has_text = true;
-        assistant_text.push_str(&ev.text_delta);
-        if sink.add(ev.text_delta).is_err() {
-            return Err(rag::StreamCancelled.into());
-        }
+            if ev.done {
+                if sink.add(String::new()).is_err() {
+                    return Err(rag::StreamCancelled.into());
+                }
+                return Ok(());
+            }
 
-        Ok(())
-    });
+            if ev.text_delta.is_empty() {
+                return Ok(());
+            }
 
-    match result {
-        Ok(()) => {
-            if !has_text {
-                return Err(anyhow!("empty response from LLM"));
+            if sink.add(ev.text_delta).is_err() {
+                return Err(rag::StreamCancelled.into());
             }
 
-            let _ = db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
-            let _ = db::insert_message_non_memory(
-                conn,
-                key,
-                conversation_id,
-                "assistant",
-                &assistant_text,
-            )?;
             Ok(())
-        }
-        Err(e) => Err(e),
-    }
+        },
+    )?;
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -371,8 +375,12 @@ pub fn rag_ask_ai_stream_scoped(
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
 
         let time_scope = match (time_start_ms, time_end_ms) {
             (Some(start), Some(end)) if start < end => Some(TimeScope {
@@ -409,23 +417,19 @@ pub fn rag_ask_ai_stream_scoped(
                 &sink,
             )
         } else {
-            let provider = llm::answer_provider_from_profile(&profile)?;
             stream_scoped_ask_with_provider(
                 &conn,
                 &key,
                 &conversation_id,
                 &question,
                 &contexts,
-                provider.as_ref(),
+                &provider,
                 &sink,
                 false,
             )
         };
 
-        let day = local_day.trim();
-        if !day.is_empty() {
-            let _ = db::record_llm_usage_daily(&conn, day, &profile_id, "ask_ai", None, None, None);
-        }
+        let _ = provider.record_usage(&conn, &local_day, "ask_ai");
 
         stream_result
     })();
@@ -596,7 +600,7 @@ mod tests {
         .expect("collect contexts");
 
         assert_eq!(contexts.len(), 1);
-        assert!(contexts[0].contains("work friday"));
+        assert!(contexts[0].text.contains("work friday"));
     }
 
     #[test]
@@ -645,7 +649,7 @@ mod tests {
         .expect("collect contexts");
 
         assert_eq!(contexts.len(), 1);
-        assert!(contexts[0].contains("in window note"));
+        assert!(contexts[0].text.contains("in window note"));
     }
 
     #[test]
@@ -689,10 +693,10 @@ mod tests {
         assert_eq!(contexts.len(), 2);
         assert!(contexts
             .iter()
-            .any(|value| value.contains("main work note")));
+            .any(|value| value.text.contains("main work note")));
         assert!(contexts
             .iter()
-            .any(|value| value.contains("side work note")));
+            .any(|value| value.text.contains("side work note")));
     }
 
     #[test]
@@ -729,8 +733,8 @@ mod tests {
         .expect("collect contexts");
 
         assert_eq!(contexts.len(), 1);
-        assert!(contexts[0].contains("personal note"));
-        assert!(contexts.iter().all(|v| !v.contains("work note")));
+        assert!(contexts[0].text.contains("personal note"));
+        assert!(contexts.iter().all(|v| !v.text.contains("work note")));
     }
 
     #[test]
@@ -776,6 +780,6 @@ mod tests {
         .expect("collect contexts");
 
         assert_eq!(contexts.len(), 1);
-        assert!(contexts[0].contains("work only"));
+        assert!(contexts[0].text.contains("work only"));
     }
 }
@@ -7,7 +7,7 @@ use reqwest::header;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::db;
+use crate::{db, llm};
 
 mod local_whisper_runtime;
 use local_whisper_runtime::create_local_whisper_context;
@@ -627,55 +627,67 @@ struct OpenAiUsage {
     total_tokens: Option<i64>,
 }
 
-#[flutter_rust_bridge::frb]
-pub fn audio_transcribe_byok_profile(
-    app_dir: String,
-    key: Vec<u8>,
-    profile_id: String,
-    local_day: String,
-    lang: String,
-    mime_type: String,
-    audio_bytes: Vec<u8>,
-) -> Result<String> {
-    let key = key_from_bytes(key)?;
-    if audio_bytes.is_empty() {
-        return Err(anyhow!("audio_bytes is empty"));
-    }
-    let audio_bytes_len = audio_bytes.len();
-    let mime_type = mime_type.trim();
-    if mime_type.is_empty() {
-        return Err(anyhow!("mime_type is required"));
-    }
-    let profile_id = profile_id.trim();
-    if profile_id.is_empty() {
-        return Err(anyhow!("profile_id is required"));
-    }
-
-    let conn = db::open(Path::new(&app_dir))?;
-    let profile = db::load_llm_profile_config_by_id(&conn, &key, profile_id)?
+/// The requested profile, on its own: the fallback chain holds chat
+/// profiles whose models can't serve transcription requests, so failures are
+/// only retried on this profile. Errors with `llm_spending_limit_exceeded`
+/// when it (or all profiles) reached the spending limit for the month of
+/// `local_day`.
+fn byok_transcribe_profiles(
+    conn: &rusqlite::Connection,
+    key: &[u8; 32],
+    profile_id: &str,
+    local_day: &str,
+    variant: &str,
+) -> Result<Vec<(String, db::LlmProfileConfig)>> {
+    let profile = db::load_llm_profile_config_by_id(conn, key, profile_id)?
         .ok_or_else(|| anyhow!("llm profile not found: {profile_id}"))?;
 
     if profile.provider_type != "openai-compatible" {
         return Err(anyhow!(
-            "audio transcribe byok v1 only supports provider_type=openai-compatible (got {})",
+            "audio transcribe byok {variant} only supports provider_type=openai-compatible (got {})",
             profile.provider_type
         ));
     }
 
+    let month = db::llm_usage_month(local_day);
+    db::check_llm_spending_limit(conn, "", &month)?;
+    db::check_llm_spending_limit(conn, profile_id, &month)?;
+
+    Ok(vec![(profile_id.to_string(), profile)])
+}
+
+/// Base URL, API key and model name of a BYOK transcription profile.
+fn byok_transcribe_credentials(
+    profile_id: &str,
+    profile: &db::LlmProfileConfig,
+) -> Result<(String, String, String)> {
     let base_url = profile
         .base_url
+        .clone()
         .ok_or_else(|| anyhow!("missing base_url for llm profile: {profile_id}"))?;
     let api_key = profile
         .api_key
+        .clone()
         .ok_or_else(|| anyhow!("missing api_key for llm profile: {profile_id}"))?;
     let model_name = profile.model_name.trim().to_string();
     if model_name.is_empty() {
         return Err(anyhow!("missing model_name for llm profile: {profile_id}"));
     }
+    Ok((base_url, api_key, model_name))
+}
+
+fn transcribe_byok_whisper_once(
+    profile_id: &str,
+    profile: &db::LlmProfileConfig,
+    lang: &str,
+    mime_type: &str,
+    audio_bytes: &[u8],
+) -> Result<(Value, Option<OpenAiUsage>)> {
+    let (base_url, api_key, model_name) = byok_transcribe_credentials(profile_id, profile)?;
 
     let url = openai_audio_transcriptions_url(&base_url);
     let ext = file_ext_for_mime_type(mime_type);
-    let file_part = multipart::Part::bytes(audio_bytes)
+    let file_part = multipart::Part::bytes(audio_bytes.to_vec())
         .file_name(format!("audio.{ext}"))
         .mime_str(mime_type)?;
     let mut form = multipart::Form::new()
@@ -684,12 +696,12 @@ pub fn audio_transcribe_byok_profile(
         .text("timestamp_granularities[]", "segment")
         .text("stream", "true")
         .part("file", file_part);
-    if !is_auto_transcribe_lang(&lang) {
+    if !is_auto_transcribe_lang(lang) {
         form = form.text("language", lang.trim().to_string());
     }
 
     let request_timeout =
-        crate::llm::timeouts::audio_transcribe_timeout_for_audio_bytes(audio_bytes_len, false);
+        crate::llm::timeouts::audio_transcribe_timeout_for_audio_bytes(audio_bytes.len(), false);
 
     let client = Client::new();
     let response = client
@@ -738,72 +750,17 @@ pub fn audio_transcribe_byok_profile(
         json["text"] = Value::String(text);
     }
 
-    let trimmed_day = local_day.trim();
-    if !trimmed_day.is_empty() {
-        let usage = parsed.usage.unwrap_or(OpenAiUsage {
-            prompt_tokens: None,
-            completion_tokens: None,
-            total_tokens: None,
-        });
-        let _ = db::record_llm_usage_daily(
-            &conn,
-            trimmed_day,
-            profile_id,
-            "audio_transcribe",
-            usage.prompt_tokens,
-            usage.completion_tokens,
-            usage.total_tokens,
-        );
-    }
-
-    Ok(json.to_string())
+    Ok((json, parsed.usage))
 }
 
-#[flutter_rust_bridge::frb]
-pub fn audio_transcribe_byok_profile_multimodal(
-    app_dir: String,
-    key: Vec<u8>,
-    profile_id: String,
-    local_day: String,
-    lang: String,
-    mime_type: String,
-    audio_bytes: Vec<u8>,
-) -> Result<String> {
-    let key = key_from_bytes(key)?;
-    if audio_bytes.is_empty() {
-        return Err(anyhow!("audio_bytes is empty"));
-    }
-    let audio_bytes_len = audio_bytes.len();
-    let mime_type = mime_type.trim();
-    if mime_type.is_empty() {
-        return Err(anyhow!("mime_type is required"));
-    }
-    let profile_id = profile_id.trim();
-    if profile_id.is_empty() {
-        return Err(anyhow!("profile_id is required"));
-    }
-
-    let conn = db::open(Path::new(&app_dir))?;
-    let profile = db::load_llm_profile_config_by_id(&conn, &key, profile_id)?
-        .ok_or_else(|| anyhow!("llm profile not found: {profile_id}"))?;
-
-    if profile.provider_type != "openai-compatible" {
-        return Err(anyhow!(
-            "audio transcribe byok multimodal only supports provider_type=openai-compatible (got {})",
-            profile.provider_type
-        ));
-    }
-
-    let base_url = profile
-        .base_url
-        .ok_or_else(|| anyhow!("missing base_url for llm profile: {profile_id}"))?;
-    let api_key = profile
-        .api_key
-        .ok_or_else(|| anyhow!("missing api_key for llm profile: {profile_id}"))?;
-    let model_name = profile.model_name.trim().to_string();
-    if model_name.is_empty() {
-        return Err(anyhow!("missing model_name for llm profile: {profile_id}"));
-    }
+fn transcribe_byok_multimodal_once(
+    profile_id: &str,
+    profile: &db::LlmProfileConfig,
+    lang: &str,
+    mime_type: &str,
+    audio_bytes: &[u8],
+) -> Result<(Value, Option<OpenAiUsage>)> {
+    let (base_url, api_key, model_name) = byok_transcribe_credentials(profile_id, profile)?;
 
     let payload = serde_json::json!({
         "model": model_name,
@@ -813,12 +770,12 @@ pub fn audio_transcribe_byok_profile_multimodal(
                 "content": [
                     {
                         "type": "text",
-                        "text": multimodal_transcribe_prompt(&lang),
+                        "text": multimodal_transcribe_prompt(lang),
                     },
                     {
                         "type": "input_audio",
                         "input_audio": {
-                            "data": STANDARD.encode(&audio_bytes),
+                            "data": STANDARD.encode(audio_bytes),
                             "format": audio_input_format_by_mime_type(mime_type),
                         }
                     }
@@ -832,7 +789,7 @@ pub fn audio_transcribe_byok_profile_multimodal(
     });
 
     let request_timeout =
-        crate::llm::timeouts::audio_transcribe_timeout_for_audio_bytes(audio_bytes_len, true);
+        crate::llm::timeouts::audio_transcribe_timeout_for_audio_bytes(audio_bytes.len(), true);
 
     let client = Client::new();
     let response = client
@@ -880,28 +837,112 @@ pub fn audio_transcribe_byok_profile_multimodal(
         json["text"] = Value::String(text);
     }
 
+    let usage = json
+        .get("usage")
+        .cloned()
+        .and_then(|raw| serde_json::from_value::<OpenAiUsage>(raw).ok());
+    Ok((json, usage))
+}
+
+fn record_audio_transcribe_usage(
+    conn: &rusqlite::Connection,
+    local_day: &str,
+    profile_id: &str,
+    usage: Option<OpenAiUsage>,
+) {
     let trimmed_day = local_day.trim();
-    if !trimmed_day.is_empty() {
-        let usage = json
-            .get("usage")
-            .cloned()
-            .and_then(|raw| serde_json::from_value::<OpenAiUsage>(raw).ok())
-            .unwrap_or(OpenAiUsage {
-                prompt_tokens: None,
-                completion_tokens: None,
-                total_tokens: None,
-            });
-        let _ = db::record_llm_usage_daily(
-            &conn,
-            trimmed_day,
-            profile_id,
-            "audio_transcribe",
-            usage.prompt_tokens,
-            usage.completion_tokens,
-            usage.total_tokens,
-        );
+    if trimmed_day.is_empty() {
+        return;
+    }
+    let usage = usage.unwrap_or(OpenAiUsage {
+        prompt_tokens: None,
+        completion_tokens: None,
+        total_tokens: None,
+    });
+    let _ = db::record_llm_usage_daily(
+        conn,
+        trimmed_day,
+        profile_id,
+        "audio_transcribe",
+        usage.prompt_tokens,
+        usage.completion_tokens,
+        usage.total_tokens,
+    );
+}
+
+#[flutter_rust_bridge::frb]
+pub fn audio_transcribe_byok_profile(
+    app_dir: String,
+    key: Vec<u8>,
+    profile_id: String,
+    local_day: String,
+    lang: String,
+    mime_type: String,
+    audio_bytes: Vec<u8>,
+) -> Result<String> {
+    let key = key_from_bytes(key)?;
+    if audio_bytes.is_empty() {
+        return Err(anyhow!("audio_bytes is empty"));
+    }
+    let mime_type = mime_type.trim();
+    if mime_type.is_empty() {
+        return Err(anyhow!("mime_type is required"));
+    }
+    let profile_id = profile_id.trim();
+    if profile_id.is_empty() {
+        return Err(anyhow!("profile_id is required"));
     }
 
+    let conn = db::open(Path::new(&app_dir))?;
+    let profiles = byok_transcribe_profiles(&conn, &key, profile_id, &local_day, "v1")?;
+    let (answered_profile_id, result) = llm::fallback::run_with_fallback(
+        &profiles,
+        &llm::fallback::RetryPolicy::default(),
+        |id, profile| transcribe_byok_whisper_once(id, profile, &lang, mime_type, &audio_bytes),
+    );
+    let (json, usage) = result?;
+
+    let usage_profile_id = answered_profile_id.unwrap_or_else(|| profile_id.to_string());
+    record_audio_transcribe_usage(&conn, &local_day, &usage_profile_id, usage);
+
+    Ok(json.to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn audio_transcribe_byok_profile_multimodal(
+    app_dir: String,
+    key: Vec<u8>,
+    profile_id: String,
+    local_day: String,
+    lang: String,
+    mime_type: String,
+    audio_bytes: Vec<u8>,
+) -> Result<String> {
+    let key = key_from_bytes(key)?;
+    if audio_bytes.is_empty() {
+        return Err(anyhow!("audio_bytes is empty"));
+    }
+    let mime_type = mime_type.trim();
+    if mime_type.is_empty() {
+        return Err(anyhow!("mime_type is required"));
+    }
+    let profile_id = profile_id.trim();
+    if profile_id.is_empty() {
+        return Err(anyhow!("profile_id is required"));
+    }
+
+    let conn = db::open(Path::new(&app_dir))?;
+    let profiles = byok_transcribe_profiles(&conn, &key, profile_id, &local_day, "multimodal")?;
+    let (answered_profile_id, result) = llm::fallback::run_with_fallback(
+        &profiles,
+        &llm::fallback::RetryPolicy::default(),
+        |id, profile| transcribe_byok_multimodal_once(id, profile, &lang, mime_type, &audio_bytes),
+    );
+    let (json, usage) = result?;
+
+    let usage_profile_id = answered_profile_id.unwrap_or_else(|| profile_id.to_string());
+    record_audio_transcribe_usage(&conn, &local_day, &usage_profile_id, usage);
+
     Ok(json.to_string())
 }
 
@@ -4,12 +4,14 @@ use crate::crypto::{derive_root_key, KdfParams};
 use crate::embedding;
 use crate::embedding::Embedder;
 use crate::frb_generated::StreamSink;
+use crate::prompt_templates;
 use crate::sync;
 use crate::sync::RemoteStore;
 use crate::{auth, db};
 use crate::{geo, media_annotation};
 use crate::{llm, rag, semantic_parse};
 use anyhow::{anyhow, Result};
+use rusqlite::Connection;
 
 const ASK_AI_ERROR_PREFIX: &str = "\u{001e}SL_ERROR\u{001e}";
 const ASK_AI_META_PREFIX: &str = "\u{001e}SL_META\u{001e}";
@@ -27,6 +29,21 @@ fn emit_ask_ai_meta_if_any(sink: &StreamSink<String>, role: Option<&str>) -> Res
     let Some(role) = role else {
         return Ok(());
     };
+    if let Some(citations) = role.strip_prefix(rag::CITATIONS_ROLE_PREFIX) {
+        let payload =
+            format!("{ASK_AI_META_PREFIX}{{\"type\":\"citations\",\"citations\":{citations}}}");
+        if sink.add(payload).is_err() {
+            return Err(rag::StreamCancelled.into());
+        }
+        return Ok(());
+    }
+    if let Some(call) = role.strip_prefix(rag::agent::TOOL_CALL_ROLE_PREFIX) {
+        let payload = format!("{ASK_AI_META_PREFIX}{{\"type\":\"tool_call\",\"call\":{call}}}");
+        if sink.add(payload).is_err() {
+            return Err(rag::StreamCancelled.into());
+        }
+        return Ok(());
+    }
     let Some(request_id) = role.strip_prefix(ASK_AI_META_REQUEST_ID_ROLE_PREFIX) else {
         return Ok(());
     };
@@ -54,6 +71,61 @@ fn finish_ask_ai_stream(sink: &StreamSink<String>, result: Result<()>) -> Result
     }
 }
 
+/// Search queries for a BYOK Ask AI turn; a rewrite call is recorded as
+/// `query_rewrite` usage of the answering profile.
+fn byok_ask_ai_search_queries(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    question: &str,
+    top_k: u32,
+    provider: &llm::fallback::FallbackAnswerProvider,
+    local_day: &str,
+) -> Result<Vec<String>> {
+    let rewrite = rag::search_queries_for_question(
+        conn,
+        key,
+        conversation_id,
+        question,
+        top_k as usize,
+        provider,
+    )?;
+    if rewrite.llm_called {
+        let _ = provider.record_usage(conn, local_day, "query_rewrite");
+    }
+    Ok(rewrite.queries)
+}
+
+/// Search queries for a cloud gateway Ask AI turn; the gateway meters the
+/// rewrite call under the `query_rewrite` purpose.
+fn cloud_gateway_ask_ai_search_queries(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    question: &str,
+    top_k: u32,
+    gateway_base_url: &str,
+    firebase_id_token: &str,
+    model_name: &str,
+) -> Result<Vec<String>> {
+    let rewriter = llm::gateway::CloudGatewayProvider::new_with_purpose(
+        gateway_base_url.to_string(),
+        firebase_id_token.to_string(),
+        model_name.to_string(),
+        None,
+        "query_rewrite".to_string(),
+    );
+    Ok(rag::search_queries_for_question(
+        conn,
+        key,
+        conversation_id,
+        question,
+        top_k as usize,
+        &rewriter,
+    )?
+    .queries)
+}
+
 fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
     if bytes.len() != 32 {
         return Err(anyhow!("invalid key length"));
@@ -360,6 +432,195 @@ pub fn db_get_todo_recurrence_rule_json(
     db::get_todo_recurrence_rule_json(&conn, &todo_id)
 }
 
+#[flutter_rust_bridge::frb]
+pub fn db_set_todo_timezone(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+    tz: Option<String>,
+) -> Result<db::Todo> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_todo_timezone(&conn, &key, &todo_id, tz.as_deref())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_get_todo_timezone(app_dir: String, todo_id: String) -> Result<Option<String>> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::get_todo_timezone(&conn, &todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_todo_parent(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+    parent_id: Option<String>,
+) -> Result<db::Todo> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_todo_parent(&conn, &key, &todo_id, parent_id.as_deref())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_todo_complete_with_subtasks(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+    enabled: bool,
+) -> Result<db::Todo> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_todo_complete_with_subtasks(&conn, &key, &todo_id, enabled)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_todo_subtasks(
+    app_dir: String,
+    key: Vec<u8>,
+    parent_id: String,
+) -> Result<Vec<db::Todo>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_todo_subtasks(&conn, &key, &parent_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_add_todo_dependency(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+    blocked_by_todo_id: String,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::add_todo_dependency(&conn, &key, &todo_id, &blocked_by_todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_remove_todo_dependency(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+    blocked_by_todo_id: String,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::remove_todo_dependency(&conn, &key, &todo_id, &blocked_by_todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_todo_blockers(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+) -> Result<Vec<db::Todo>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_todo_blockers(&conn, &key, &todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_todos_blocked_by(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+) -> Result<Vec<db::Todo>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_todos_blocked_by(&conn, &key, &todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_get_todo_rollup(
+    app_dir: String,
+    key: Vec<u8>,
+    todo_id: String,
+) -> Result<db::TodoRollup> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::get_todo_rollup(&conn, &key, &todo_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_mark_for_review(
+    app_dir: String,
+    key: Vec<u8>,
+    item_kind: String,
+    item_id: String,
+    first_review_at_ms: Option<i64>,
+) -> Result<db::ReviewItem> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::mark_for_review(&conn, &key, &item_kind, &item_id, first_review_at_ms)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_unmark_for_review(
+    app_dir: String,
+    key: Vec<u8>,
+    item_kind: String,
+    item_id: String,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::unmark_for_review(&conn, &key, &item_kind, &item_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_due_reviews(
+    app_dir: String,
+    key: Vec<u8>,
+    due_before_ms: i64,
+    limit: u32,
+) -> Result<Vec<db::ReviewItem>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_due_reviews(&conn, &key, due_before_ms, limit)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_record_review(
+    app_dir: String,
+    key: Vec<u8>,
+    item_kind: String,
+    item_id: String,
+    grade: i64,
+) -> Result<db::ReviewItem> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::record_review(&conn, &key, &item_kind, &item_id, grade)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_preview_review_intervals(
+    app_dir: String,
+    item_kind: String,
+    item_id: String,
+) -> Result<Vec<i64>> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::preview_review_intervals(&conn, &item_kind, &item_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_review_activities(
+    app_dir: String,
+    item_kind: String,
+    item_id: String,
+) -> Result<Vec<db::ReviewActivity>> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_review_activities(&conn, &item_kind, &item_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_preview_todo_recurrence(
+    rule_json: String,
+    start_at_ms: i64,
+    limit: u32,
+) -> Result<Vec<i64>> {
+    db::preview_recurrence_occurrences(&rule_json, start_at_ms, limit)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn db_delete_todo_and_associated_messages(
     app_dir: String,
@@ -481,6 +742,130 @@ pub fn db_list_events(app_dir: String, key: Vec<u8>) -> Result<Vec<db::Event>> {
     db::list_events(&conn, &key)
 }
 
+#[flutter_rust_bridge::frb]
+pub fn db_set_event_recurrence_rule(
+    app_dir: String,
+    key: Vec<u8>,
+    event_id: String,
+    rule_json: Option<String>,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_event_recurrence_rule(&conn, &key, &event_id, rule_json.as_deref())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_get_event_recurrence_rule_json(
+    app_dir: String,
+    event_id: String,
+) -> Result<Option<String>> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::get_event_recurrence_rule_json(&conn, &event_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_cancel_event_occurrence(
+    app_dir: String,
+    key: Vec<u8>,
+    event_id: String,
+    occurrence_start_ms: i64,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::cancel_event_occurrence(&conn, &key, &event_id, occurrence_start_ms)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_move_event_occurrence(
+    app_dir: String,
+    key: Vec<u8>,
+    event_id: String,
+    occurrence_start_ms: i64,
+    start_at_ms: i64,
+    end_at_ms: i64,
+    title: Option<String>,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::move_event_occurrence(
+        &conn,
+        &key,
+        &event_id,
+        occurrence_start_ms,
+        start_at_ms,
+        end_at_ms,
+        title.as_deref(),
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_restore_event_occurrence(
+    app_dir: String,
+    key: Vec<u8>,
+    event_id: String,
+    occurrence_start_ms: i64,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::restore_event_occurrence(&conn, &key, &event_id, occurrence_start_ms)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_event_occurrences_in_range(
+    app_dir: String,
+    key: Vec<u8>,
+    start_at_ms_inclusive: i64,
+    end_at_ms_exclusive: i64,
+) -> Result<Vec<db::EventOccurrence>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_event_occurrences_in_range(&conn, &key, start_at_ms_inclusive, end_at_ms_exclusive)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_agenda_range(
+    app_dir: String,
+    key: Vec<u8>,
+    start_at_ms_inclusive: i64,
+    end_at_ms_exclusive: i64,
+    tz: String,
+    cursor: Option<String>,
+    limit: u32,
+) -> Result<db::AgendaPage> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::agenda_range(
+        &conn,
+        &key,
+        start_at_ms_inclusive,
+        end_at_ms_exclusive,
+        &tz,
+        cursor.as_deref(),
+        limit,
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_get_ask_ai_query_rewrite_enabled(app_dir: String) -> Result<bool> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::get_ask_ai_query_rewrite_enabled(&conn)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_ask_ai_query_rewrite_enabled(app_dir: String, enabled: bool) -> Result<()> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_ask_ai_query_rewrite_enabled(&conn, enabled)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_message_citations(
+    app_dir: String,
+    message_id: String,
+) -> Result<Vec<db::MessageCitation>> {
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_message_citations(&conn, &message_id)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn db_edit_message(
     app_dir: String,
@@ -834,6 +1219,28 @@ pub fn db_mark_semantic_parse_job_failed(
     )
 }
 
+/// Why the job last failed (`semantic_parse::failure_reason`), if it has.
+#[flutter_rust_bridge::frb]
+pub fn db_get_semantic_parse_job_failure_reason(
+    app_dir: String,
+    key: Vec<u8>,
+    message_id: String,
+) -> Result<Option<String>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::get_semantic_parse_job_failure_reason(&conn, &message_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_count_semantic_parse_failure_reasons(
+    app_dir: String,
+    key: Vec<u8>,
+) -> Result<Vec<db::SemanticParseFailureReasonCount>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::count_semantic_parse_failure_reasons(&conn)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn db_mark_semantic_parse_job_retry(
     app_dir: String,
@@ -1138,13 +1545,31 @@ pub fn db_delete_llm_profile(app_dir: String, key: Vec<u8>, profile_id: String)
 }
 
 #[flutter_rust_bridge::frb]
-pub fn db_create_embedding_profile(
+pub fn db_list_llm_fallback_chain(app_dir: String, key: Vec<u8>) -> Result<Vec<String>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_llm_fallback_chain(&conn)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_llm_fallback_chain(
     app_dir: String,
     key: Vec<u8>,
-    name: String,
-    provider_type: String,
-    base_url: Option<String>,
-    api_key: Option<String>,
+    profile_ids: Vec<String>,
+) -> Result<()> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_llm_fallback_chain(&conn, &profile_ids)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_create_embedding_profile(
+    app_dir: String,
+    key: Vec<u8>,
+    name: String,
+    provider_type: String,
+    base_url: Option<String>,
+    api_key: Option<String>,
     model_name: String,
     set_active: bool,
 ) -> Result<db::EmbeddingProfile> {
@@ -1742,6 +2167,20 @@ pub fn db_set_active_embedding_model_name(
     db::set_active_embedding_model_name(&conn, desired)
 }
 
+/// Whether the optional local reranker model is downloaded. Ask AI uses it
+/// automatically once installed.
+#[flutter_rust_bridge::frb]
+pub fn embedding_is_reranker_installed(app_dir: String) -> bool {
+    embedding::is_reranker_installed(Path::new(&app_dir))
+}
+
+/// Downloads the local reranker model. Errors on platforms without local
+/// ONNX support.
+#[flutter_rust_bridge::frb]
+pub fn embedding_install_reranker(app_dir: String) -> Result<()> {
+    embedding::install_reranker(Path::new(&app_dir))
+}
+
 #[flutter_rust_bridge::frb]
 #[allow(clippy::too_many_arguments)]
 pub fn db_record_llm_usage_daily(
@@ -1780,6 +2219,82 @@ pub fn db_sum_llm_usage_daily_by_purpose(
     db::sum_llm_usage_daily_by_purpose(&conn, &profile_id, start_day.trim(), end_day.trim())
 }
 
+#[flutter_rust_bridge::frb]
+pub fn db_list_llm_model_prices(app_dir: String, key: Vec<u8>) -> Result<Vec<db::LlmModelPrice>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_llm_model_prices(&conn)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_upsert_llm_model_price(
+    app_dir: String,
+    key: Vec<u8>,
+    provider_type: String,
+    model_name: String,
+    input_usd_per_million_tokens: f64,
+    output_usd_per_million_tokens: f64,
+) -> Result<db::LlmModelPrice> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::upsert_llm_model_price(
+        &conn,
+        &key,
+        &provider_type,
+        &model_name,
+        input_usd_per_million_tokens,
+        output_usd_per_million_tokens,
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_delete_llm_model_price(
+    app_dir: String,
+    key: Vec<u8>,
+    provider_type: String,
+    model_name: String,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::delete_llm_model_price(&conn, &key, &provider_type, &model_name)
+}
+
+/// Usage and cost per profile and purpose for `month` (`YYYY-MM`).
+#[flutter_rust_bridge::frb]
+pub fn db_sum_llm_cost_monthly(
+    app_dir: String,
+    key: Vec<u8>,
+    month: String,
+) -> Result<Vec<db::LlmMonthlyCost>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::sum_llm_cost_monthly(&conn, &month)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_llm_spending_limits(
+    app_dir: String,
+    key: Vec<u8>,
+) -> Result<Vec<db::LlmSpendingLimit>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_llm_spending_limits(&conn)
+}
+
+/// Sets the monthly limit for `profile_id` (empty for all profiles together);
+/// `None` removes it.
+#[flutter_rust_bridge::frb]
+pub fn db_set_llm_spending_limit(
+    app_dir: String,
+    key: Vec<u8>,
+    profile_id: String,
+    monthly_limit_usd_micros: Option<i64>,
+) -> Result<()> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_llm_spending_limit(&conn, &profile_id, monthly_limit_usd_micros)
+}
+
 #[flutter_rust_bridge::frb]
 #[allow(clippy::too_many_arguments)]
 pub fn ai_semantic_parse_message_action(
@@ -1796,12 +2311,22 @@ pub fn ai_semantic_parse_message_action(
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
-        let result = semantic_parse::semantic_parse_message_action_json(
-            provider.as_ref(),
+        let prompt_template = db::resolve_prompt_template(
+            &conn,
+            &key,
+            prompt_templates::PURPOSE_SEMANTIC_PARSE,
+            None,
+        )?;
+        let result = semantic_parse::semantic_parse_message_action_json_with_template(
+            &provider,
+            prompt_template.as_deref(),
             &text,
             now_local_iso.trim(),
             locale.trim(),
@@ -1809,38 +2334,8 @@ pub fn ai_semantic_parse_message_action(
             &candidates,
         );
 
-        match result {
-            Ok(json) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "semantic_parse",
-                        None,
-                        None,
-                        None,
-                    );
-                }
-                Ok(json)
-            }
-            Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "semantic_parse",
-                        None,
-                        None,
-                        None,
-                    );
-                }
-                Err(e)
-            }
-        }
+        let _ = provider.record_usage(&conn, &local_day, "semantic_parse");
+        result
     })();
 
     result
@@ -1867,8 +2362,8 @@ pub fn ai_semantic_parse_message_action_cloud_gateway(
         return Err(anyhow!("missing firebase_id_token"));
     }
 
-    let _key = key_from_bytes(key)?;
-    let _conn = db::open(Path::new(&app_dir))?;
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
 
     let provider = llm::gateway::CloudGatewayProvider::new_with_purpose(
         gateway_base_url,
@@ -1877,9 +2372,17 @@ pub fn ai_semantic_parse_message_action_cloud_gateway(
         None,
         "semantic_parse".to_string(),
     );
+    // The gateway is not an LLM profile, so only the all-profiles selection applies.
+    let prompt_template = db::resolve_prompt_template(
+        &conn,
+        &key,
+        prompt_templates::PURPOSE_SEMANTIC_PARSE,
+        Some(""),
+    )?;
 
-    semantic_parse::semantic_parse_message_action_json(
+    semantic_parse::semantic_parse_message_action_json_with_template(
         &provider,
+        prompt_template.as_deref(),
         &text,
         now_local_iso.trim(),
         locale.trim(),
@@ -1902,50 +2405,23 @@ pub fn ai_semantic_parse_ask_ai_time_window(
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
         let result = semantic_parse::semantic_parse_ask_ai_time_window_json(
-            provider.as_ref(),
+            &provider,
             &question,
             now_local_iso.trim(),
             locale.trim(),
             first_day_of_week_index,
         );
 
-        match result {
-            Ok(json) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "semantic_parse",
-                        None,
-                        None,
-                        None,
-                    );
-                }
-                Ok(json)
-            }
-            Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "semantic_parse",
-                        None,
-                        None,
-                        None,
-                    );
-                }
-                Err(e)
-            }
-        }
+        let _ = provider.record_usage(&conn, &local_day, "semantic_parse");
+        result
     })();
 
     result
@@ -1992,6 +2468,7 @@ pub fn ai_semantic_parse_ask_ai_time_window_cloud_gateway(
 }
 
 #[flutter_rust_bridge::frb]
+#[allow(clippy::too_many_arguments)]
 pub fn rag_ask_ai_stream(
     app_dir: String,
     key: Vec<u8>,
@@ -2000,14 +2477,19 @@ pub fn rag_ask_ai_stream(
     top_k: u32,
     this_thread_only: bool,
     local_day: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
 
         let focus = if this_thread_only {
             rag::Focus::ThisThread
@@ -2015,16 +2497,29 @@ pub fn rag_ask_ai_stream(
             rag::Focus::AllMemories
         };
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
-        let result = rag::ask_ai_with_provider_using_active_embeddings(
+        let search_queries = byok_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &provider,
+            &local_day,
+        )?;
+
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
-            provider.as_ref(),
+            reranker.as_deref(),
+            tz.as_deref(),
+            &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
                 if ev.done {
@@ -2046,34 +2541,12 @@ pub fn rag_ask_ai_stream(
 
         match result {
             Ok(()) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Ok(())
             }
             Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
             Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Err(e)
             }
         }
@@ -2094,14 +2567,29 @@ pub fn rag_ask_ai_stream_time_window(
     time_start_ms: i64,
     time_end_ms: i64,
     local_day: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
+
+        let search_queries = byok_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &provider,
+            &local_day,
+        )?;
 
         let focus = if this_thread_only {
             rag::Focus::ThisThread
@@ -2109,18 +2597,21 @@ pub fn rag_ask_ai_stream_time_window(
             rag::Focus::AllMemories
         };
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
-        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window(
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
             time_start_ms,
             time_end_ms,
-            provider.as_ref(),
+            reranker.as_deref(),
+            tz.as_deref(),
+            &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
                 if ev.done {
@@ -2142,34 +2633,12 @@ pub fn rag_ask_ai_stream_time_window(
 
         match result {
             Ok(()) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Ok(())
             }
             Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
             Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Err(e)
             }
         }
@@ -2179,6 +2648,7 @@ pub fn rag_ask_ai_stream_time_window(
 }
 
 #[flutter_rust_bridge::frb]
+#[allow(clippy::too_many_arguments)]
 pub fn rag_ask_ai_stream_with_brok_embeddings(
     app_dir: String,
     key: Vec<u8>,
@@ -2187,14 +2657,19 @@ pub fn rag_ask_ai_stream_with_brok_embeddings(
     top_k: u32,
     this_thread_only: bool,
     local_day: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
 
         let (_emb_profile_id, emb_profile) = db::load_active_embedding_profile_config(&conn, &key)?
             .ok_or_else(|| anyhow!("no active embedding profile configured"))?;
@@ -2222,23 +2697,33 @@ pub fn rag_ask_ai_stream_with_brok_embeddings(
             embeddings_model_name,
         );
 
+        let search_queries = byok_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &provider,
+            &local_day,
+        )?;
+
         let focus = if this_thread_only {
             rag::Focus::ThisThread
         } else {
             rag::Focus::AllMemories
         };
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
-
-        let result = rag::ask_ai_with_provider_using_embedder(
+        let result = rag::ask_ai_with_provider_using_embedder_and_queries(
             &conn,
             &key,
             &embedder,
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
-            provider.as_ref(),
+            tz.as_deref(),
+            &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
                 if ev.done {
@@ -2260,34 +2745,12 @@ pub fn rag_ask_ai_stream_with_brok_embeddings(
 
         match result {
             Ok(()) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Ok(())
             }
             Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
             Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Err(e)
             }
         }
@@ -2308,14 +2771,29 @@ pub fn rag_ask_ai_stream_with_brok_embeddings_time_window(
     time_start_ms: i64,
     time_end_ms: i64,
     local_day: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
         let key = key_from_bytes(key)?;
         let conn = db::open(Path::new(&app_dir))?;
 
-        let (profile_id, profile) = db::load_active_llm_profile_config(&conn, &key)?
-            .ok_or_else(|| anyhow!("no active LLM profile configured"))?;
+        let provider = llm::fallback::answer_provider_for_active_profile(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &local_day,
+        )?;
+
+        let search_queries = byok_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &provider,
+            &local_day,
+        )?;
 
         let focus = if this_thread_only {
             rag::Focus::ThisThread
@@ -2323,19 +2801,21 @@ pub fn rag_ask_ai_stream_with_brok_embeddings_time_window(
             rag::Focus::AllMemories
         };
 
-        let provider = llm::answer_provider_from_profile(&profile)?;
-
-        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window(
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
             time_start_ms,
             time_end_ms,
-            provider.as_ref(),
+            reranker.as_deref(),
+            tz.as_deref(),
+            &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
                 if ev.done {
@@ -2357,34 +2837,12 @@ pub fn rag_ask_ai_stream_with_brok_embeddings_time_window(
 
         match result {
             Ok(()) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Ok(())
             }
             Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
             Err(e) => {
-                let day = local_day.trim();
-                if !day.is_empty() {
-                    let _ = db::record_llm_usage_daily(
-                        &conn,
-                        day,
-                        &profile_id,
-                        "ask_ai",
-                        None,
-                        None,
-                        None,
-                    );
-                }
+                let _ = provider.record_usage(&conn, &local_day, "ask_ai");
                 Err(e)
             }
         }
@@ -2405,6 +2863,7 @@ pub fn rag_ask_ai_stream_cloud_gateway(
     gateway_base_url: String,
     firebase_id_token: String,
     model_name: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
@@ -2424,6 +2883,17 @@ pub fn rag_ask_ai_stream_cloud_gateway(
             rag::Focus::AllMemories
         };
 
+        let search_queries = cloud_gateway_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &gateway_base_url,
+            &firebase_id_token,
+            &model_name,
+        )?;
+
         let provider = llm::gateway::CloudGatewayProvider::new(
             gateway_base_url,
             firebase_id_token,
@@ -2431,14 +2901,142 @@ pub fn rag_ask_ai_stream_cloud_gateway(
             None,
         );
 
-        let result = rag::ask_ai_with_provider_using_active_embeddings(
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
+            reranker.as_deref(),
+            tz.as_deref(),
+            &provider,
+            &mut |ev| {
+                emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
+                if ev.done {
+                    if sink.add(String::new()).is_err() {
+                        return Err(rag::StreamCancelled.into());
+                    }
+                    return Ok(());
+                }
+                if ev.text_delta.is_empty() {
+                    return Ok(());
+                }
+                if sink.add(ev.text_delta).is_err() {
+                    return Err(rag::StreamCancelled.into());
+                }
+                Ok(())
+            },
+        )
+        .map(|_| ());
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
+            Err(e) => Err(e),
+        }
+    })();
+
+    finish_ask_ai_stream(&sink, result)
+}
+
+/// Ask AI in agent mode: the model may search memories, list todos and
+/// events, create or update todos and read attachments before answering.
+#[flutter_rust_bridge::frb]
+pub fn rag_ask_ai_agent_stream(
+    app_dir: String,
+    key: Vec<u8>,
+    conversation_id: String,
+    question: String,
+    local_day: String,
+    sink: StreamSink<String>,
+) -> Result<()> {
+    let result = (|| -> Result<()> {
+        let key = key_from_bytes(key)?;
+        let conn = db::open(Path::new(&app_dir))?;
+
+        let provider = llm::fallback::tool_provider_for_active_profile(&conn, &key, &local_day)?;
+
+        let result = rag::agent::ask_ai_agent(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &conversation_id,
+            &question,
+            rag::agent::DEFAULT_MAX_AGENT_STEPS,
+            &provider,
+            &mut |ev| {
+                emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
+                if ev.done {
+                    if sink.add(String::new()).is_err() {
+                        return Err(rag::StreamCancelled.into());
+                    }
+                    return Ok(());
+                }
+                if ev.text_delta.is_empty() {
+                    return Ok(());
+                }
+                if sink.add(ev.text_delta).is_err() {
+                    return Err(rag::StreamCancelled.into());
+                }
+                Ok(())
+            },
+        )
+        .map(|_| ());
+
+        let _ = provider.record_usage(&conn, &local_day, "ask_ai_agent");
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) if e.is::<rag::StreamCancelled>() => Ok(()),
+            Err(e) => Err(e),
+        }
+    })();
+
+    finish_ask_ai_stream(&sink, result)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[flutter_rust_bridge::frb]
+pub fn rag_ask_ai_agent_stream_cloud_gateway(
+    app_dir: String,
+    key: Vec<u8>,
+    conversation_id: String,
+    question: String,
+    gateway_base_url: String,
+    firebase_id_token: String,
+    model_name: String,
+    sink: StreamSink<String>,
+) -> Result<()> {
+    let result = (|| -> Result<()> {
+        if gateway_base_url.trim().is_empty() {
+            return Err(anyhow!("missing gateway_base_url"));
+        }
+        if firebase_id_token.trim().is_empty() {
+            return Err(anyhow!("missing firebase_id_token"));
+        }
+
+        let key = key_from_bytes(key)?;
+        let conn = db::open(Path::new(&app_dir))?;
+
+        let provider = llm::gateway::CloudGatewayProvider::new_with_purpose(
+            gateway_base_url,
+            firebase_id_token,
+            model_name,
+            None,
+            "ask_ai_agent".to_string(),
+        );
+
+        let result = rag::agent::ask_ai_agent(
+            &conn,
+            &key,
+            Path::new(&app_dir),
+            &conversation_id,
+            &question,
+            rag::agent::DEFAULT_MAX_AGENT_STEPS,
             &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
@@ -2483,6 +3081,7 @@ pub fn rag_ask_ai_stream_cloud_gateway_time_window(
     gateway_base_url: String,
     firebase_id_token: String,
     model_name: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
@@ -2502,6 +3101,17 @@ pub fn rag_ask_ai_stream_cloud_gateway_time_window(
             rag::Focus::AllMemories
         };
 
+        let search_queries = cloud_gateway_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &gateway_base_url,
+            &firebase_id_token,
+            &model_name,
+        )?;
+
         let provider = llm::gateway::CloudGatewayProvider::new(
             gateway_base_url,
             firebase_id_token,
@@ -2509,16 +3119,20 @@ pub fn rag_ask_ai_stream_cloud_gateway_time_window(
             None,
         );
 
-        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window(
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
             time_start_ms,
             time_end_ms,
+            reranker.as_deref(),
+            tz.as_deref(),
             &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
@@ -2562,6 +3176,7 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings(
     firebase_id_token: String,
     model_name: String,
     embeddings_model_name: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
@@ -2589,6 +3204,17 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings(
             firebase_id_token.clone(),
             embeddings_model_name,
         );
+        let search_queries = cloud_gateway_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &gateway_base_url,
+            &firebase_id_token,
+            &model_name,
+        )?;
+
         let provider = llm::gateway::CloudGatewayProvider::new(
             gateway_base_url,
             firebase_id_token,
@@ -2596,14 +3222,16 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings(
             None,
         );
 
-        let result = rag::ask_ai_with_provider_using_embedder(
+        let result = rag::ask_ai_with_provider_using_embedder_and_queries(
             &conn,
             &key,
             &embedder,
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
+            tz.as_deref(),
             &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
@@ -2649,6 +3277,7 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window(
     firebase_id_token: String,
     model_name: String,
     embeddings_model_name: String,
+    tz: Option<String>,
     sink: StreamSink<String>,
 ) -> Result<()> {
     let result = (|| -> Result<()> {
@@ -2672,6 +3301,17 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window(
         };
 
         // Time-window RAG doesn't need remote embeddings; keep the signature for Flutter routing parity.
+        let search_queries = cloud_gateway_ask_ai_search_queries(
+            &conn,
+            &key,
+            &conversation_id,
+            &question,
+            top_k,
+            &gateway_base_url,
+            &firebase_id_token,
+            &model_name,
+        )?;
+
         let provider = llm::gateway::CloudGatewayProvider::new(
             gateway_base_url,
             firebase_id_token,
@@ -2679,16 +3319,20 @@ pub fn rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window(
             None,
         );
 
-        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window(
+        let reranker = embedding::installed_reranker(Path::new(&app_dir));
+        let result = rag::ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
             &conn,
             &key,
             Path::new(&app_dir),
             &conversation_id,
             &question,
+            &search_queries,
             top_k as usize,
             focus,
             time_start_ms,
             time_end_ms,
+            reranker.as_deref(),
+            tz.as_deref(),
             &provider,
             &mut |ev| {
                 emit_ask_ai_meta_if_any(&sink, ev.role.as_deref())?;
@@ -2843,6 +3487,22 @@ pub fn sync_webdav_clear_remote_root(
     sync::clear_remote_root(&remote, &remote_root)
 }
 
+#[flutter_rust_bridge::frb]
+pub fn sync_caldav(
+    app_dir: String,
+    key: Vec<u8>,
+    collection_url: String,
+    username: Option<String>,
+    password: Option<String>,
+    default_tz: Option<String>,
+    now_ms: i64,
+) -> Result<sync::caldav::CalDavSyncReport> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    let collection = sync::caldav::CalDavHttpCollection::new(collection_url, username, password)?;
+    sync::caldav::sync_caldav(&conn, &key, &collection, default_tz.as_deref(), now_ms)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn sync_localdir_test_connection(local_dir: String, remote_root: String) -> Result<()> {
     let remote = sync::localdir::LocalDirRemoteStore::new(PathBuf::from(local_dir))?;
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::db;
+use crate::export::{ics, jsonl};
+
+fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
+    if bytes.len() != 32 {
+        return Err(anyhow!("invalid key length"));
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_export_vault_jsonl(
+    app_dir: String,
+    key: Vec<u8>,
+    out_dir: String,
+    now_ms: i64,
+) -> Result<jsonl::JsonlExportSummary> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    jsonl::export_vault_jsonl(&conn, &key, Path::new(&out_dir), now_ms)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn verify_vault_jsonl_export(export_dir: String) -> Result<jsonl::JsonlExportVerification> {
+    jsonl::verify_vault_jsonl_export(Path::new(&export_dir))
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_export_ics(
+    app_dir: String,
+    key: Vec<u8>,
+    out_path: String,
+    now_ms: i64,
+) -> Result<ics::IcsExportSummary> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    ics::export_ics_file(&conn, &key, Path::new(&out_path), now_ms)
+}
@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::db;
+use crate::import;
+
+fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
+    if bytes.len() != 32 {
+        return Err(anyhow!("invalid key length"));
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_import_markdown_folder(
+    app_dir: String,
+    key: Vec<u8>,
+    root_dir: String,
+    lang: String,
+    default_tz: Option<String>,
+) -> Result<import::ImportReport> {
+    let key = key_from_bytes(key)?;
+    let app_dir = Path::new(&app_dir);
+    let conn = db::open(app_dir)?;
+    import::markdown::import_markdown_dir(
+        &conn,
+        &key,
+        app_dir,
+        Path::new(&root_dir),
+        &lang,
+        default_tz.as_deref(),
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_import_telegram_export(
+    app_dir: String,
+    key: Vec<u8>,
+    json_path: String,
+    lang: String,
+    default_tz: Option<String>,
+    preview: bool,
+) -> Result<import::ImportReport> {
+    let key = key_from_bytes(key)?;
+    let app_dir = Path::new(&app_dir);
+    let conn = db::open(app_dir)?;
+    import::chat::import_telegram_export(
+        &conn,
+        &key,
+        app_dir,
+        Path::new(&json_path),
+        &lang,
+        default_tz.as_deref(),
+        preview,
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_import_whatsapp_export(
+    app_dir: String,
+    key: Vec<u8>,
+    txt_path: String,
+    lang: String,
+    default_tz: Option<String>,
+    preview: bool,
+) -> Result<import::ImportReport> {
+    let key = key_from_bytes(key)?;
+    let app_dir = Path::new(&app_dir);
+    let conn = db::open(app_dir)?;
+    import::chat::import_whatsapp_export(
+        &conn,
+        &key,
+        app_dir,
+        Path::new(&txt_path),
+        &lang,
+        default_tz.as_deref(),
+        preview,
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_import_ics(
+    app_dir: String,
+    key: Vec<u8>,
+    ics_path: String,
+    default_tz: Option<String>,
+    preview: bool,
+) -> Result<import::ImportReport> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    import::ics::import_ics_file(
+        &conn,
+        &key,
+        Path::new(&ics_path),
+        default_tz.as_deref(),
+        preview,
+    )
+}
@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::llm::local_gguf;
+
+#[flutter_rust_bridge::frb]
+pub fn local_llm_list_models(app_dir: String) -> Result<Vec<local_gguf::LocalGgufModelInfo>> {
+    local_gguf::list_local_gguf_models(Path::new(&app_dir))
+}
+
+#[flutter_rust_bridge::frb]
+pub fn local_llm_install_model(
+    app_dir: String,
+    source_path: String,
+    expected_sha256: Option<String>,
+) -> Result<local_gguf::LocalGgufModelInfo> {
+    local_gguf::install_local_gguf_model(
+        Path::new(&app_dir),
+        Path::new(&source_path),
+        expected_sha256.as_deref(),
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn local_llm_delete_model(app_dir: String, file_name: String) -> Result<bool> {
+    local_gguf::delete_local_gguf_model(Path::new(&app_dir), &file_name)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn local_llm_model_sha256(app_dir: String, file_name: String) -> Result<String> {
+    local_gguf::local_gguf_model_sha256(Path::new(&app_dir), &file_name)
+}
+
+/// Loads the llama.cpp runtime installed under `app_dir` when none is
+/// loaded yet.
+#[flutter_rust_bridge::frb]
+pub fn local_llm_runtime_available(app_dir: String) -> bool {
+    local_gguf::ensure_local_llm_runtime(Path::new(&app_dir)).is_ok()
+}
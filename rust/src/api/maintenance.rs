@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::db;
+
+fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
+    if bytes.len() != 32 {
+        return Err(anyhow!("invalid key length"));
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_check_integrity(
+    app_dir: String,
+    key: Vec<u8>,
+    fix: bool,
+    now_ms: i64,
+) -> Result<db::IntegrityReport> {
+    let key = key_from_bytes(key)?;
+    let app_dir = Path::new(&app_dir);
+    let conn = db::open(app_dir)?;
+    db::check_integrity(&conn, &key, app_dir, fix, now_ms)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_storage_usage(app_dir: String, key: Vec<u8>) -> Result<db::StorageUsageReport> {
+    let key = key_from_bytes(key)?;
+    let app_dir = Path::new(&app_dir);
+    let conn = db::open(app_dir)?;
+    db::storage_usage(&conn, &key, app_dir)
+}
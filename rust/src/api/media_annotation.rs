@@ -2,7 +2,7 @@ use std::path::Path;
 
 use anyhow::{anyhow, Result};
 
-use crate::db;
+use crate::{db, llm, prompt_templates};
 
 fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
     if bytes.len() != 32 {
@@ -47,8 +47,11 @@ pub fn media_annotation_byok_profile(
     let key = key_from_bytes(key)?;
     let conn = db::open(Path::new(&app_dir))?;
 
-    let profile = db::load_llm_profile_config_by_id(&conn, &key, profile_id.trim())?
-        .ok_or_else(|| anyhow!("llm profile not found: {profile_id}"))?;
+    let profile_id = profile_id.trim();
+    let profiles = db::load_llm_profile_chain_configs(&conn, &key, Some(profile_id), &local_day)?;
+    let Some((_, profile)) = profiles.first() else {
+        return Err(anyhow!("llm profile not found: {profile_id}"));
+    };
 
     if profile.provider_type != "openai-compatible" {
         return Err(anyhow!(
@@ -56,21 +59,40 @@ pub fn media_annotation_byok_profile(
             profile.provider_type
         ));
     }
+    let profiles = profiles
+        .into_iter()
+        .filter(|(_, p)| p.provider_type == "openai-compatible")
+        .collect::<Vec<_>>();
 
-    let base_url = profile
-        .base_url
-        .ok_or_else(|| anyhow!("missing base_url for llm profile: {profile_id}"))?;
-    let api_key = profile
-        .api_key
-        .ok_or_else(|| anyhow!("missing api_key for llm profile: {profile_id}"))?;
+    let (answered_profile_id, result) = llm::fallback::run_with_fallback(
+        &profiles,
+        &llm::fallback::RetryPolicy::default(),
+        |id, profile| {
+            let base_url = profile
+                .base_url
+                .clone()
+                .ok_or_else(|| anyhow!("missing base_url for llm profile: {id}"))?;
+            let api_key = profile
+                .api_key
+                .clone()
+                .ok_or_else(|| anyhow!("missing api_key for llm profile: {id}"))?;
 
-    let client = crate::media_annotation::OpenAiCompatibleMediaAnnotationClient::new(
-        base_url,
-        api_key,
-        profile.model_name,
+            let prompt_template = db::resolve_prompt_template(
+                &conn,
+                &key,
+                prompt_templates::PURPOSE_MEDIA_ANNOTATION,
+                Some(id),
+            )?;
+            let client = crate::media_annotation::OpenAiCompatibleMediaAnnotationClient::new(
+                base_url,
+                api_key,
+                profile.model_name.clone(),
+            )
+            .with_prompt_template(prompt_template);
+            client.annotate_image_with_usage(&lang, &mime_type, &image_bytes)
+        },
     );
-
-    let result = client.annotate_image_with_usage(&lang, &mime_type, &image_bytes);
+    let usage_profile_id = answered_profile_id.unwrap_or_else(|| profile_id.to_string());
 
     let trimmed_day = local_day.trim();
     if !trimmed_day.is_empty() {
@@ -79,7 +101,7 @@ pub fn media_annotation_byok_profile(
                 let _ = db::record_llm_usage_daily(
                     &conn,
                     trimmed_day,
-                    profile_id.trim(),
+                    &usage_profile_id,
                     "media_annotation",
                     usage.input_tokens,
                     usage.output_tokens,
@@ -90,7 +112,7 @@ pub fn media_annotation_byok_profile(
                 let _ = db::record_llm_usage_daily(
                     &conn,
                     trimmed_day,
-                    profile_id.trim(),
+                    &usage_profile_id,
                     "media_annotation",
                     None,
                     None,
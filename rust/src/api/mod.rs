@@ -9,7 +9,12 @@ pub mod content_enrichment;
 pub mod content_extract;
 pub mod core;
 pub mod desktop_media;
+pub mod export;
+pub mod import;
+pub mod local_llm;
+pub mod maintenance;
 pub mod media_annotation;
+pub mod prompt_templates;
 pub mod simple;
 pub mod sync_progress;
 pub mod tags;
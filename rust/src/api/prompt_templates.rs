@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::{db, prompt_templates};
+
+fn key_from_bytes(bytes: Vec<u8>) -> Result<[u8; 32]> {
+    if bytes.len() != 32 {
+        return Err(anyhow!("invalid key length"));
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn prompt_template_default(purpose: String) -> Result<String> {
+    prompt_templates::default_prompt_template(purpose.trim()).map(str::to_string)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn prompt_template_validate(purpose: String, body: String) -> Result<()> {
+    prompt_templates::validate_prompt_template(purpose.trim(), &body)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_prompt_templates(app_dir: String, key: Vec<u8>) -> Result<Vec<db::PromptTemplate>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_prompt_templates(&conn, &key)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_upsert_prompt_template(
+    app_dir: String,
+    key: Vec<u8>,
+    template_id: Option<String>,
+    name: String,
+    purpose: String,
+    body: String,
+) -> Result<db::PromptTemplate> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::upsert_prompt_template(&conn, &key, template_id.as_deref(), &name, &purpose, &body)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_reset_prompt_template_to_default(
+    app_dir: String,
+    key: Vec<u8>,
+    template_id: String,
+) -> Result<db::PromptTemplate> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::reset_prompt_template_to_default(&conn, &key, &template_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_delete_prompt_template(app_dir: String, key: Vec<u8>, template_id: String) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::delete_prompt_template(&conn, &key, &template_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_prompt_template_selections(
+    app_dir: String,
+    key: Vec<u8>,
+) -> Result<Vec<db::PromptTemplateSelection>> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_prompt_template_selections(&conn)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_prompt_template_selection(
+    app_dir: String,
+    key: Vec<u8>,
+    purpose: String,
+    profile_id: String,
+    template_id: Option<String>,
+) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_prompt_template_selection(&conn, &key, &purpose, &profile_id, template_id.as_deref())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_reset_prompt_template_selections(
+    app_dir: String,
+    key: Vec<u8>,
+    purpose: String,
+) -> Result<()> {
+    let _key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::reset_prompt_template_selections(&conn, &purpose)
+}
@@ -27,6 +27,32 @@ pub fn db_upsert_tag(app_dir: String, key: Vec<u8>, name: String) -> Result<db::
     db::upsert_tag(&conn, &key, &name)
 }
 
+#[flutter_rust_bridge::frb]
+pub fn db_upsert_tag_path(app_dir: String, key: Vec<u8>, path: String) -> Result<db::Tag> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::upsert_tag_path(&conn, &key, &path)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_set_tag_parent(
+    app_dir: String,
+    key: Vec<u8>,
+    tag_id: String,
+    parent_id: Option<String>,
+) -> Result<db::Tag> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::set_tag_parent(&conn, &key, &tag_id, parent_id.as_deref())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_tag_tree(app_dir: String, key: Vec<u8>) -> Result<Vec<db::TagNode>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_tag_tree(&conn, &key)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn db_list_message_tags(
     app_dir: String,
@@ -109,3 +135,55 @@ pub fn db_list_message_ids_by_tag_ids(
     let conn = db::open(Path::new(&app_dir))?;
     db::list_message_ids_by_tag_ids(&conn, &conversation_id, &tag_ids)
 }
+
+#[flutter_rust_bridge::frb]
+pub fn db_list_tag_rules(app_dir: String, key: Vec<u8>) -> Result<Vec<db::TagRule>> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::list_tag_rules(&conn, &key)
+}
+
+#[flutter_rust_bridge::frb]
+#[allow(clippy::too_many_arguments)]
+pub fn db_upsert_tag_rule(
+    app_dir: String,
+    key: Vec<u8>,
+    rule_id: Option<String>,
+    name: String,
+    kind: String,
+    pattern: String,
+    tag_id: String,
+    enabled: bool,
+) -> Result<db::TagRule> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::upsert_tag_rule(
+        &conn,
+        &key,
+        rule_id.as_deref(),
+        &name,
+        &kind,
+        &pattern,
+        &tag_id,
+        enabled,
+    )
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_delete_tag_rule(app_dir: String, key: Vec<u8>, rule_id: String) -> Result<()> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::delete_tag_rule(&conn, &key, &rule_id)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn db_backfill_tag_rules(
+    app_dir: String,
+    key: Vec<u8>,
+    rule_id: Option<String>,
+    now_ms: i64,
+) -> Result<u32> {
+    let key = key_from_bytes(key)?;
+    let conn = db::open(Path::new(&app_dir))?;
+    db::backfill_tag_rules(&conn, &key, rule_id.as_deref(), now_ms)
+}
@@ -0,0 +1,204 @@
+/// Target size of one chunk, in bytes of UTF-8 text.
+pub const CHUNK_TARGET_BYTES: usize = 1200;
+/// Trailing text of a chunk that is repeated at the start of the next one.
+pub const CHUNK_OVERLAP_BYTES: usize = 200;
+
+/// A searchable piece of a long document or transcript.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextChunk {
+    pub text: String,
+    /// 1-based page the chunk starts on, for paged documents.
+    pub page: Option<u32>,
+    /// Time span covered, for transcripts.
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+}
+
+/// A transcript segment with its timing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimedSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+/// A piece of the source that is never split across chunks, as a byte range.
+#[derive(Clone, Copy, Debug)]
+struct Unit {
+    start: usize,
+    end: usize,
+}
+
+fn floor_char_boundary(s: &str, mut idx: usize) -> usize {
+    idx = idx.min(s.len());
+    while idx > 0 && !s.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+/// Splits `text[start..end]` into sentences, then into hard windows when a
+/// sentence alone is too long.
+fn split_long_unit(text: &str, start: usize, end: usize, out: &mut Vec<Unit>) {
+    let mut sentence_start = start;
+    let mut iter = text[start..end].char_indices().peekable();
+    while let Some((offset, ch)) = iter.next() {
+        let is_break = match ch {
+            '\n' | '。' | '！' | '？' => true,
+            '.' | '!' | '?' => iter.peek().is_none_or(|(_, next)| next.is_whitespace()),
+            _ => false,
+        };
+        if is_break {
+            let sentence_end = start + offset + ch.len_utf8();
+            push_windowed(text, sentence_start, sentence_end, out);
+            sentence_start = sentence_end;
+        }
+    }
+    push_windowed(text, sentence_start, end, out);
+}
+
+fn push_windowed(text: &str, mut start: usize, end: usize, out: &mut Vec<Unit>) {
+    while start < end {
+        let mut window_end = end;
+        if end - start > CHUNK_TARGET_BYTES {
+            window_end = floor_char_boundary(text, start + CHUNK_TARGET_BYTES);
+        }
+        if window_end <= start {
+            window_end = start + text[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        if !text[start..window_end].trim().is_empty() {
+            out.push(Unit {
+                start,
+                end: window_end,
+            });
+        }
+        start = window_end;
+    }
+}
+
+/// Paragraphs of `text[start..end]`, with overlong ones split into sentences
+/// or windows.
+fn paragraph_units(text: &str, start: usize, end: usize) -> Vec<Unit> {
+    let mut units = Vec::new();
+    let mut paragraph_start = start;
+    for (idx, _) in text[start..end].match_indices("\n\n") {
+        push_paragraph(text, paragraph_start, start + idx, &mut units);
+        paragraph_start = start + idx + 2;
+    }
+    push_paragraph(text, paragraph_start, end, &mut units);
+    units
+}
+
+fn push_paragraph(text: &str, start: usize, end: usize, out: &mut Vec<Unit>) {
+    if start >= end || text[start..end].trim().is_empty() {
+        return;
+    }
+    if end - start > CHUNK_TARGET_BYTES {
+        split_long_unit(text, start, end, out);
+    } else {
+        out.push(Unit { start, end });
+    }
+}
+
+/// Groups consecutive units into chunks of about `CHUNK_TARGET_BYTES`, each
+/// starting with the last units of the previous chunk up to
+/// `CHUNK_OVERLAP_BYTES`. Returns index ranges into `lens`.
+fn pack_units(lens: &[usize]) -> Vec<std::ops::Range<usize>> {
+    let mut out = Vec::new();
+    let mut start = 0usize;
+    while start < lens.len() {
+        let mut end = start;
+        let mut size = 0usize;
+        while end < lens.len() && (end == start || size + lens[end] <= CHUNK_TARGET_BYTES) {
+            size += lens[end];
+            end += 1;
+        }
+        out.push(start..end);
+        if end >= lens.len() {
+            break;
+        }
+
+        // Step back over whole units for the overlap, always moving forward.
+        let mut next = end;
+        let mut overlap = 0usize;
+        while next > start + 1 && overlap + lens[next - 1] <= CHUNK_OVERLAP_BYTES {
+            overlap += lens[next - 1];
+            next -= 1;
+        }
+        start = next;
+    }
+    out
+}
+
+fn chunk_range(text: &str, start: usize, end: usize, page: Option<u32>) -> Vec<TextChunk> {
+    let units = paragraph_units(text, start, end);
+    // Count the separator after each unit too, so chunks stay within target.
+    let lens = units
+        .iter()
+        .enumerate()
+        .map(|(i, unit)| units.get(i + 1).map_or(unit.end, |next| next.start) - unit.start)
+        .collect::<Vec<_>>();
+    pack_units(&lens)
+        .into_iter()
+        .map(|range| TextChunk {
+            text: text[units[range.start].start..units[range.end - 1].end]
+                .trim()
+                .to_string(),
+            page,
+            start_ms: None,
+            end_ms: None,
+        })
+        .collect()
+}
+
+/// Splits `text` into overlapping chunks along paragraph and sentence
+/// boundaries. `page_offsets` are the byte offsets where pages 1, 2, …
+/// start, if the text is paged; chunks never cross a page boundary.
+pub fn chunk_text(text: &str, page_offsets: &[usize]) -> Vec<TextChunk> {
+    if page_offsets.is_empty() {
+        return chunk_range(text, 0, text.len(), None);
+    }
+
+    let mut out = Vec::new();
+    for (i, &start) in page_offsets.iter().enumerate() {
+        let end = page_offsets
+            .get(i + 1)
+            .copied()
+            .unwrap_or(text.len())
+            .min(text.len());
+        if start >= end || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+            continue;
+        }
+        out.extend(chunk_range(text, start, end, u32::try_from(i + 1).ok()));
+    }
+    out
+}
+
+/// Groups transcript segments into overlapping chunks that keep their time
+/// span.
+pub fn chunk_segments(segments: &[TimedSegment]) -> Vec<TextChunk> {
+    let segments = segments
+        .iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .collect::<Vec<_>>();
+    let lens = segments
+        .iter()
+        .map(|segment| segment.text.trim().len() + 1)
+        .collect::<Vec<_>>();
+    pack_units(&lens)
+        .into_iter()
+        .map(|range| {
+            let picked = &segments[range];
+            TextChunk {
+                text: picked
+                    .iter()
+                    .map(|segment| segment.text.trim())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                page: None,
+                start_ms: picked.first().map(|segment| segment.start_ms),
+                end_ms: picked.last().map(|segment| segment.end_ms),
+            }
+        })
+        .collect()
+}
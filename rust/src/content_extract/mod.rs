@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 
+pub mod chunk;
 mod docx;
 mod pdf;
 
@@ -12,6 +13,9 @@ pub struct DocumentExtractResult {
     pub excerpt: String,
     pub page_count: Option<u32>,
     pub needs_ocr: bool,
+    /// Byte offsets in `full_text` where pages 1, 2, … start. Empty for
+    /// documents without pages.
+    pub page_offsets: Vec<usize>,
 }
 
 fn truncate_utf8_to_max_bytes(s: &str, max_bytes: usize) -> &str {
@@ -255,6 +259,23 @@ fn is_text_like_mime(mime_type: &str) -> bool {
     )
 }
 
+/// Normalizes each page and joins them as paragraphs, returning the text and
+/// the offset each page starts at. Empty pages keep the offset of the next
+/// page so page numbers stay aligned.
+fn join_pdf_pages(pages: &[String]) -> (String, Vec<usize>) {
+    let mut out = String::new();
+    let mut offsets = Vec::with_capacity(pages.len());
+    for page in pages {
+        let normalized = normalize_text_keep_paragraphs(page);
+        if !normalized.is_empty() && !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        offsets.push(out.len());
+        out.push_str(&normalized);
+    }
+    (out, offsets)
+}
+
 /// Extracts a searchable text layer from document-like attachments.
 ///
 /// v1 behavior:
@@ -269,15 +290,21 @@ pub fn extract_document(mime_type: &str, bytes: &[u8]) -> Result<DocumentExtract
 
     if mt == "application/pdf" {
         let pdf = pdf::extract_pdf_text(bytes)?;
-        let normalized = normalize_text_keep_paragraphs(&pdf.text);
+        let (mut normalized, mut page_offsets) = join_pdf_pages(&pdf.pages);
+        if normalized.is_empty() {
+            normalized = normalize_text_keep_paragraphs(&pdf.text);
+            page_offsets.clear();
+        }
         let full = truncate_utf8_to_max_bytes(&normalized, MAX_FULL_TEXT_BYTES).to_string();
         let excerpt = truncate_utf8_to_max_bytes(&full, MAX_EXCERPT_TEXT_BYTES).to_string();
         let needs_ocr = full.trim().is_empty();
+        page_offsets.retain(|offset| *offset < full.len());
         return Ok(DocumentExtractResult {
             full_text: full,
             excerpt,
             page_count: Some(pdf.page_count),
             needs_ocr,
+            page_offsets,
         });
     }
 
@@ -291,6 +318,7 @@ pub fn extract_document(mime_type: &str, bytes: &[u8]) -> Result<DocumentExtract
             excerpt,
             page_count: None,
             needs_ocr: false,
+            page_offsets: Vec::new(),
         });
     }
 
@@ -309,6 +337,7 @@ pub fn extract_document(mime_type: &str, bytes: &[u8]) -> Result<DocumentExtract
             excerpt,
             page_count: None,
             needs_ocr: false,
+            page_offsets: Vec::new(),
         });
     }
 
@@ -3,6 +3,8 @@ use lopdf::Document;
 
 pub struct PdfTextExtractResult {
     pub text: String,
+    /// Text of each page, in page order; empty for pages that failed.
+    pub pages: Vec<String>,
     pub page_count: u32,
 }
 
@@ -18,6 +20,7 @@ pub fn extract_pdf_text(bytes: &[u8]) -> Result<PdfTextExtractResult> {
     if pages.is_empty() {
         return Ok(PdfTextExtractResult {
             text: String::new(),
+            pages: Vec::new(),
             page_count: 0,
         });
     }
@@ -25,22 +28,29 @@ pub fn extract_pdf_text(bytes: &[u8]) -> Result<PdfTextExtractResult> {
     let mut page_numbers: Vec<u32> = pages.keys().cloned().collect();
     page_numbers.sort_unstable();
 
+    let pages: Vec<String> = page_numbers
+        .iter()
+        .map(|n| doc.extract_text(&[*n]).unwrap_or_default())
+        .collect();
+
     // Prefer lopdf's built-in text extraction.
     let text = match doc.extract_text(&page_numbers) {
         Ok(t) => t,
         Err(_) => {
             let mut out = String::new();
-            for n in &page_numbers {
-                if let Ok(t) = doc.extract_text(&[*n]) {
-                    if !out.is_empty() && !out.ends_with('\n') {
-                        out.push('\n');
-                    }
-                    out.push_str(&t);
+            for t in pages.iter().filter(|t| !t.is_empty()) {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
                 }
+                out.push_str(t);
             }
             out
         }
     };
 
-    Ok(PdfTextExtractResult { text, page_count })
+    Ok(PdfTextExtractResult {
+        text,
+        pages,
+        page_count,
+    })
 }
@@ -22,6 +22,23 @@ include!("parts/16_tags.rs");
 include!("parts/18_tag_merge_feedback.rs");
 include!("parts/19_suggested_tags.rs");
 include!("parts/20_message_tag_autofill.rs");
+include!("parts/21_import_records.rs");
+include!("parts/22_integrity.rs");
+include!("parts/23_storage_usage.rs");
+include!("parts/24_tag_hierarchy.rs");
+include!("parts/25_tag_rules.rs");
+include!("parts/26_recurrence_rules.rs");
+include!("parts/27_caldav.rs");
+include!("parts/28_event_recurrence.rs");
+include!("parts/29_todo_hierarchy.rs");
+include!("parts/30_review_scheduler.rs");
+include!("parts/31_agenda.rs");
+include!("parts/32_message_citations.rs");
+include!("parts/33_query_rewrite_cache.rs");
+include!("parts/34_attachment_chunks.rs");
+include!("parts/35_llm_fallback_chain.rs");
+include!("parts/36_prompt_templates.rs");
+include!("parts/37_llm_costs.rs");
 
 #[cfg(test)]
 mod semantic_parse_jobs_tests;
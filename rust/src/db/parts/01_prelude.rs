@@ -60,6 +60,15 @@ pub struct Tag {
     pub updated_at_ms: i64,
 }
 
+/// A tag placed in the tag hierarchy; `path` joins ancestor names with `/`.
+#[derive(Clone, Debug)]
+pub struct TagNode {
+    pub tag: Tag,
+    pub parent_id: Option<String>,
+    pub path: String,
+    pub depth: u32,
+}
+
 #[derive(Clone, Debug)]
 pub struct TagMergeSuggestion {
     pub source_tag: Tag,
@@ -199,6 +208,12 @@ pub struct SemanticParseJob {
     pub updated_at_ms: i64,
 }
 
+#[derive(Clone, Debug)]
+pub struct SemanticParseFailureReasonCount {
+    pub reason: String,
+    pub jobs: i64,
+}
+
 #[derive(Clone, Debug)]
 pub struct CloudMediaBackup {
     pub attachment_sha256: String,
@@ -567,6 +582,7 @@ fn ensure_vec_tables_for_space(conn: &Connection, space_id: &str, dim: usize) ->
     let message_table = message_embeddings_table(space_id)?;
     let todo_table = todo_embeddings_table(space_id)?;
     let activity_table = todo_activity_embeddings_table(space_id)?;
+    let chunk_table = attachment_chunk_embeddings_table(space_id)?;
 
     if !sqlite_table_exists(conn, &message_table)? {
         conn.execute_batch(&format!(
@@ -599,6 +615,17 @@ CREATE VIRTUAL TABLE "{activity_table}" USING vec0(
   todo_id TEXT,
   model_name TEXT
 );
+"#
+        ))?;
+    }
+    if !sqlite_table_exists(conn, &chunk_table)? {
+        conn.execute_batch(&format!(
+            r#"
+CREATE VIRTUAL TABLE "{chunk_table}" USING vec0(
+  embedding float[{dim}],
+  attachment_sha256 TEXT,
+  model_name TEXT
+);
 "#
         ))?;
     }
@@ -621,6 +648,12 @@ CREATE VIRTUAL TABLE "{activity_table}" USING vec0(
             "todo-activity vec0 dim mismatch: expected {dim}, got {act_dim} (table={activity_table})"
         ));
     }
+    let chunk_dim = vec0_dim_from_sqlite_master(conn, &chunk_table)?.unwrap_or(0);
+    if chunk_dim != dim {
+        return Err(anyhow!(
+            "attachment-chunk vec0 dim mismatch: expected {dim}, got {chunk_dim} (table={chunk_table})"
+        ));
+    }
 
     Ok(())
 }
@@ -629,6 +662,7 @@ fn recompute_needs_embedding_for_space(conn: &Connection, space_id: &str) -> Res
     let message_table = message_embeddings_table(space_id)?;
     let todo_table = todo_embeddings_table(space_id)?;
     let activity_table = todo_activity_embeddings_table(space_id)?;
+    let chunk_table = attachment_chunk_embeddings_table(space_id)?;
 
     conn.execute_batch(&format!(
         r#"
@@ -657,6 +691,13 @@ SET needs_embedding = CASE
   THEN 1
   ELSE 0
 END;
+
+UPDATE attachment_chunks
+SET needs_embedding = CASE
+  WHEN NOT EXISTS (SELECT 1 FROM "{chunk_table}" ce WHERE ce.rowid = attachment_chunks.id)
+  THEN 1
+  ELSE 0
+END;
 "#
     ))?;
     Ok(())
@@ -747,6 +747,402 @@ PRAGMA user_version = 25;
         )?;
     }
 
+    if user_version < 26 {
+        // v26: import bookkeeping (local-only) so re-running an import is idempotent.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS import_records (
+  source_kind TEXT NOT NULL,
+  source_key TEXT NOT NULL,
+  content_sha256 TEXT NOT NULL,
+  message_id TEXT,
+  created_at_ms INTEGER NOT NULL,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (source_kind, source_key)
+);
+CREATE INDEX IF NOT EXISTS idx_import_records_kind_sha256
+  ON import_records(source_kind, content_sha256);
+PRAGMA user_version = 26;
+"#,
+        )?;
+    }
+
+    if user_version < 27 {
+        // v27: tag hierarchy (custom tags can nest under any tag).
+        let has_parent_id: bool = {
+            let mut stmt = conn.prepare("PRAGMA table_info(tags)")?;
+            let mut rows = stmt.query([])?;
+            let mut found = false;
+            while let Some(row) = rows.next()? {
+                let name: String = row.get(1)?;
+                if name == "parent_id" {
+                    found = true;
+                    break;
+                }
+            }
+            found
+        };
+        if !has_parent_id {
+            conn.execute_batch("ALTER TABLE tags ADD COLUMN parent_id TEXT;")?;
+        }
+        conn.execute_batch(
+            r#"
+CREATE INDEX IF NOT EXISTS idx_tags_parent_id
+  ON tags(parent_id)
+  WHERE parent_id IS NOT NULL;
+PRAGMA user_version = 27;
+"#,
+        )?;
+    }
+
+    if user_version < 28 {
+        // v28: user-defined tagging rules (rule body encrypted, synced).
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS tag_rules (
+  id TEXT PRIMARY KEY,
+  rule BLOB NOT NULL,
+  created_at_ms INTEGER NOT NULL,
+  updated_at_ms INTEGER NOT NULL
+);
+PRAGMA user_version = 28;
+"#,
+        )?;
+    }
+
+    if user_version < 29 {
+        // v29: IANA timezone for todos (recurrence math runs in local time).
+        let has_tz: bool = {
+            let mut stmt = conn.prepare("PRAGMA table_info(todos)")?;
+            let mut rows = stmt.query([])?;
+            let mut found = false;
+            while let Some(row) = rows.next()? {
+                let name: String = row.get(1)?;
+                if name == "tz" {
+                    found = true;
+                    break;
+                }
+            }
+            found
+        };
+        if !has_tz {
+            conn.execute_batch("ALTER TABLE todos ADD COLUMN tz TEXT;")?;
+        }
+        conn.execute_batch("PRAGMA user_version = 29;")?;
+    }
+
+    if user_version < 30 {
+        // v30: event deletion tombstones, and CalDAV sync state (device-local,
+        // never synced).
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS event_deletions (
+  event_id TEXT PRIMARY KEY,
+  deleted_at_ms INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS caldav_collections (
+  collection_id TEXT PRIMARY KEY,
+  ctag TEXT,
+  updated_at_ms INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS caldav_items (
+  collection_id TEXT NOT NULL,
+  href TEXT NOT NULL,
+  item_kind TEXT NOT NULL,
+  item_id TEXT NOT NULL,
+  uid TEXT NOT NULL,
+  etag TEXT,
+  local_sha256 TEXT NOT NULL,
+  remote_deleted INTEGER NOT NULL DEFAULT 0,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (collection_id, href)
+);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_caldav_items_item
+  ON caldav_items(collection_id, item_kind, item_id);
+
+PRAGMA user_version = 30;
+"#,
+        )?;
+    }
+
+    if user_version < 31 {
+        // v31: recurring events. A NULL `rule_json` records a cleared rule so
+        // the change syncs; exception titles are encrypted like event titles.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS event_recurrences (
+  event_id TEXT PRIMARY KEY,
+  rule_json TEXT,
+  updated_at_ms INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS event_exceptions (
+  event_id TEXT NOT NULL,
+  occurrence_start_ms INTEGER NOT NULL,
+  kind TEXT NOT NULL,
+  title BLOB,
+  start_at_ms INTEGER,
+  end_at_ms INTEGER,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (event_id, occurrence_start_ms)
+);
+
+PRAGMA user_version = 31;
+"#,
+        )?;
+    }
+
+    if user_version < 32 {
+        // v32: subtasks (`parent_id`, optional roll-up completion) and
+        // "blocked by" dependencies. Removed dependencies stay as tombstones.
+        let mut existing_columns = BTreeSet::<String>::new();
+        {
+            let mut stmt = conn.prepare("PRAGMA table_info(todos)")?;
+            let mut rows = stmt.query([])?;
+            while let Some(row) = rows.next()? {
+                existing_columns.insert(row.get(1)?);
+            }
+        }
+        if !existing_columns.contains("parent_id") {
+            conn.execute_batch("ALTER TABLE todos ADD COLUMN parent_id TEXT;")?;
+        }
+        if !existing_columns.contains("complete_with_subtasks") {
+            conn.execute_batch(
+                "ALTER TABLE todos ADD COLUMN complete_with_subtasks INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+        conn.execute_batch(
+            r#"
+CREATE INDEX IF NOT EXISTS idx_todos_parent_id
+  ON todos(parent_id)
+  WHERE parent_id IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS todo_dependencies (
+  todo_id TEXT NOT NULL,
+  blocked_by_todo_id TEXT NOT NULL,
+  is_deleted INTEGER NOT NULL DEFAULT 0,
+  created_at_ms INTEGER NOT NULL,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (todo_id, blocked_by_todo_id)
+);
+CREATE INDEX IF NOT EXISTS idx_todo_dependencies_blocked_by
+  ON todo_dependencies(blocked_by_todo_id);
+
+PRAGMA user_version = 32;
+"#,
+        )?;
+    }
+
+    if user_version < 33 {
+        // v33: spaced-repetition state for todos and messages marked for
+        // review, plus an append-only log of every graded review.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS review_items (
+  item_kind TEXT NOT NULL,
+  item_id TEXT NOT NULL,
+  is_active INTEGER NOT NULL DEFAULT 1,
+  ease_factor REAL NOT NULL,
+  interval_days INTEGER NOT NULL,
+  repetitions INTEGER NOT NULL,
+  lapses INTEGER NOT NULL,
+  due_at_ms INTEGER NOT NULL,
+  last_reviewed_at_ms INTEGER,
+  created_at_ms INTEGER NOT NULL,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (item_kind, item_id)
+);
+CREATE INDEX IF NOT EXISTS idx_review_items_due_at_ms
+  ON review_items(due_at_ms)
+  WHERE is_active = 1;
+
+CREATE TABLE IF NOT EXISTS review_activities (
+  id TEXT PRIMARY KEY,
+  item_kind TEXT NOT NULL,
+  item_id TEXT NOT NULL,
+  grade INTEGER NOT NULL,
+  ease_factor REAL NOT NULL,
+  interval_days INTEGER NOT NULL,
+  due_at_ms INTEGER NOT NULL,
+  reviewed_at_ms INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_review_activities_item
+  ON review_activities(item_kind, item_id, reviewed_at_ms);
+
+PRAGMA user_version = 33;
+"#,
+        )?;
+    }
+
+    if user_version < 34 {
+        // v34: Ask AI answers keep the sources behind their `[n]` markers.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS message_citations (
+  message_id TEXT NOT NULL,
+  marker INTEGER NOT NULL,
+  source_kind TEXT NOT NULL,
+  source_id TEXT NOT NULL,
+  created_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (message_id, marker)
+);
+
+PRAGMA user_version = 34;
+"#,
+        )?;
+    }
+
+    if user_version < 35 {
+        // v35: device-local cache of Ask AI search query rewrites.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS query_rewrite_cache (
+  cache_key TEXT PRIMARY KEY,
+  queries BLOB NOT NULL,
+  created_at_ms INTEGER NOT NULL
+);
+
+PRAGMA user_version = 35;
+"#,
+        )?;
+    }
+
+    if user_version < 36 {
+        // v36: device-local chunks of long attachment text, embedded per chunk.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS attachment_chunks (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  attachment_sha256 TEXT NOT NULL,
+  chunk_index INTEGER NOT NULL,
+  text BLOB NOT NULL,
+  page INTEGER,
+  start_ms INTEGER,
+  end_ms INTEGER,
+  needs_embedding INTEGER NOT NULL DEFAULT 1,
+  created_at_ms INTEGER NOT NULL,
+  UNIQUE(attachment_sha256, chunk_index),
+  FOREIGN KEY(attachment_sha256) REFERENCES attachments(sha256) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_attachment_chunks_needs_embedding
+  ON attachment_chunks(needs_embedding);
+
+CREATE TABLE IF NOT EXISTS attachment_chunk_sources (
+  attachment_sha256 TEXT PRIMARY KEY,
+  source_updated_at INTEGER NOT NULL,
+  FOREIGN KEY(attachment_sha256) REFERENCES attachments(sha256) ON DELETE CASCADE
+);
+
+PRAGMA user_version = 36;
+"#,
+        )?;
+    }
+
+    if user_version < 37 {
+        // v37: device-local order of LLM profiles to fall back to.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS llm_fallback_chain (
+  profile_id TEXT PRIMARY KEY,
+  position INTEGER NOT NULL,
+  FOREIGN KEY(profile_id) REFERENCES llm_profiles(id) ON DELETE CASCADE
+);
+
+PRAGMA user_version = 37;
+"#,
+        )?;
+    }
+
+    if user_version < 38 {
+        // v38: synced prompt templates and device-local per-profile selections.
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS prompt_templates (
+  id TEXT PRIMARY KEY,
+  template BLOB NOT NULL,
+  created_at_ms INTEGER NOT NULL,
+  updated_at_ms INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS prompt_template_selections (
+  purpose TEXT NOT NULL,
+  profile_id TEXT NOT NULL,
+  template_id TEXT NOT NULL,
+  PRIMARY KEY(purpose, profile_id),
+  FOREIGN KEY(template_id) REFERENCES prompt_templates(id) ON DELETE CASCADE
+);
+
+PRAGMA user_version = 38;
+"#,
+        )?;
+    }
+
+    if user_version < 39 {
+        // v39: synced per-model prices, usage cost, and device-local spending limits.
+        let has_cost: bool = {
+            let mut stmt = conn.prepare("PRAGMA table_info(llm_usage_daily)")?;
+            let mut rows = stmt.query([])?;
+            let mut found = false;
+            while let Some(row) = rows.next()? {
+                let name: String = row.get(1)?;
+                if name == "cost_usd_micros" {
+                    found = true;
+                    break;
+                }
+            }
+            found
+        };
+        if !has_cost {
+            conn.execute_batch(
+                "ALTER TABLE llm_usage_daily ADD COLUMN cost_usd_micros INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+        conn.execute_batch(
+            r#"
+CREATE TABLE IF NOT EXISTS llm_model_prices (
+  provider_type TEXT NOT NULL,
+  model_name TEXT NOT NULL,
+  input_usd_per_mtok REAL NOT NULL,
+  output_usd_per_mtok REAL NOT NULL,
+  updated_at_ms INTEGER NOT NULL,
+  PRIMARY KEY (provider_type, model_name)
+);
+
+CREATE TABLE IF NOT EXISTS llm_spending_limits (
+  profile_id TEXT PRIMARY KEY,
+  monthly_limit_usd_micros INTEGER NOT NULL
+);
+
+PRAGMA user_version = 39;
+"#,
+        )?;
+    }
+
+    if user_version < 40 {
+        // v40: why semantic parse jobs fail (see `semantic_parse::failure_reason`).
+        let has_failure_reason: bool = {
+            let mut stmt = conn.prepare("PRAGMA table_info(semantic_parse_jobs)")?;
+            let mut rows = stmt.query([])?;
+            let mut found = false;
+            while let Some(row) = rows.next()? {
+                let name: String = row.get(1)?;
+                if name == "last_failure_reason" {
+                    found = true;
+                    break;
+                }
+            }
+            found
+        };
+        if !has_failure_reason {
+            conn.execute_batch(
+                "ALTER TABLE semantic_parse_jobs ADD COLUMN last_failure_reason TEXT;",
+            )?;
+        }
+        conn.execute_batch("PRAGMA user_version = 40;")?;
+    }
+
     Ok(())
 }
 
@@ -794,10 +1190,18 @@ DELETE FROM message_embeddings;
 DELETE FROM todo_embeddings;
 DELETE FROM todo_activity_embeddings;
 DELETE FROM semantic_parse_jobs;
+DELETE FROM import_records;
+DELETE FROM tag_rules;
+DELETE FROM prompt_templates;
+DELETE FROM llm_model_prices;
 DELETE FROM tag_merge_feedback;
 DELETE FROM message_tag_autofill_events;
 DELETE FROM message_tag_autofill_jobs;
 DELETE FROM message_tags;
+DELETE FROM message_citations;
+DELETE FROM query_rewrite_cache;
+DELETE FROM attachment_chunks;
+DELETE FROM attachment_chunk_sources;
 DELETE FROM message_attachments;
 DELETE FROM cloud_media_backup;
 DELETE FROM attachment_variants;
@@ -814,9 +1218,17 @@ DELETE FROM todo_deletions;
 DELETE FROM todos;
 DELETE FROM todo_activity_attachments;
 DELETE FROM todo_activities;
+DELETE FROM todo_dependencies;
+DELETE FROM review_activities;
+DELETE FROM review_items;
 DELETE FROM todo_recurrences;
 DELETE FROM todo_series;
+DELETE FROM event_exceptions;
+DELETE FROM event_recurrences;
+DELETE FROM event_deletions;
 DELETE FROM events;
+DELETE FROM caldav_items;
+DELETE FROM caldav_collections;
 DELETE FROM oplog;
 DELETE FROM kv WHERE key != 'embedding.active_model_name';
 "#,
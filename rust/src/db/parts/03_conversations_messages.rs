@@ -36,11 +36,20 @@ pub fn create_conversation(conn: &Connection, key: &[u8; 32], title: &str) -> Re
 pub fn get_or_create_loop_home_conversation(
     conn: &Connection,
     key: &[u8; 32],
+) -> Result<Conversation> {
+    get_or_create_conversation_with_id(conn, key, LOOP_HOME_CONVERSATION_ID, "Loop")
+}
+
+pub fn get_or_create_conversation_with_id(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    title: &str,
 ) -> Result<Conversation> {
     let existing: Option<(Vec<u8>, i64, i64)> = conn
         .query_row(
             r#"SELECT title, created_at, updated_at FROM conversations WHERE id = ?1"#,
-            params![LOOP_HOME_CONVERSATION_ID],
+            params![conversation_id],
             |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
         )
         .optional()?;
@@ -50,7 +59,7 @@ pub fn get_or_create_loop_home_conversation(
         let title = String::from_utf8(title_bytes)
             .map_err(|_| anyhow!("conversation title is not valid utf-8"))?;
         return Ok(Conversation {
-            id: LOOP_HOME_CONVERSATION_ID.to_string(),
+            id: conversation_id.to_string(),
             title,
             created_at_ms,
             updated_at_ms,
@@ -58,12 +67,11 @@ pub fn get_or_create_loop_home_conversation(
     }
 
     let now = now_ms();
-    let title = "Loop";
 
     let title_blob = encrypt_bytes(key, title.as_bytes(), b"conversation.title")?;
     conn.execute(
         r#"INSERT INTO conversations (id, title, created_at, updated_at) VALUES (?1, ?2, ?3, ?4)"#,
-        params![LOOP_HOME_CONVERSATION_ID, title_blob, now, now],
+        params![conversation_id, title_blob, now, now],
     )?;
 
     let device_id = get_or_create_device_id(conn)?;
@@ -75,7 +83,7 @@ pub fn get_or_create_loop_home_conversation(
         "ts_ms": now,
         "type": "conversation.upsert.v1",
         "payload": {
-            "conversation_id": LOOP_HOME_CONVERSATION_ID,
+            "conversation_id": conversation_id,
             "title": title,
             "created_at_ms": now,
             "updated_at_ms": now,
@@ -84,7 +92,7 @@ pub fn get_or_create_loop_home_conversation(
     insert_oplog(conn, key, &op)?;
 
     Ok(Conversation {
-        id: LOOP_HOME_CONVERSATION_ID.to_string(),
+        id: conversation_id.to_string(),
         title: title.to_string(),
         created_at_ms: now,
         updated_at_ms: now,
@@ -133,6 +141,7 @@ pub fn insert_message(
         role,
         content,
         role != "assistant",
+        None,
     )
 }
 
@@ -143,7 +152,27 @@ pub fn insert_message_non_memory(
     role: &str,
     content: &str,
 ) -> Result<Message> {
-    insert_message_with_is_memory(conn, key, conversation_id, role, content, false)
+    insert_message_with_is_memory(conn, key, conversation_id, role, content, false, None)
+}
+
+/// Inserts a memory message that keeps its original timestamp (e.g. imported notes or chats).
+pub fn insert_message_with_created_at(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    role: &str,
+    content: &str,
+    created_at_ms: i64,
+) -> Result<Message> {
+    insert_message_with_is_memory(
+        conn,
+        key,
+        conversation_id,
+        role,
+        content,
+        role != "assistant",
+        Some(created_at_ms),
+    )
 }
 
 fn insert_message_with_is_memory(
@@ -153,9 +182,11 @@ fn insert_message_with_is_memory(
     role: &str,
     content: &str,
     is_memory: bool,
+    created_at_ms: Option<i64>,
 ) -> Result<Message> {
     let id = uuid::Uuid::new_v4().to_string();
     let now = now_ms();
+    let created_at_ms = created_at_ms.unwrap_or(now);
 
     let device_id = get_or_create_device_id(conn)?;
     let seq = next_device_seq(conn, &device_id)?;
@@ -170,7 +201,7 @@ fn insert_message_with_is_memory(
             conversation_id,
             role,
             content_blob,
-            now,
+            created_at_ms,
             now,
             device_id,
             seq,
@@ -195,7 +226,7 @@ fn insert_message_with_is_memory(
             "conversation_id": conversation_id,
             "role": role,
             "content": content,
-            "created_at_ms": now,
+            "created_at_ms": created_at_ms,
             "is_memory": is_memory,
         }
     });
@@ -203,6 +234,8 @@ fn insert_message_with_is_memory(
 
     if role == "user" {
         run_message_tag_autofill_for_message(conn, key, &id, "message_insert", now)?;
+        // Best-effort: a broken rule must not block saving the message.
+        let _ = apply_tag_rules_to_message(conn, key, &id, now);
     }
 
     Ok(Message {
@@ -210,7 +243,7 @@ fn insert_message_with_is_memory(
         conversation_id: conversation_id.to_string(),
         role: role.to_string(),
         content: content.to_string(),
-        created_at_ms: now,
+        created_at_ms,
         is_memory,
     })
 }
@@ -272,6 +305,10 @@ pub fn edit_message(
         params![conversation_id, now],
     )?;
 
+    if role == "user" {
+        let _ = apply_tag_rules_to_message(conn, key, message_id, now);
+    }
+
     Ok(())
 }
 
@@ -239,6 +239,10 @@ pub fn delete_llm_profile(conn: &Connection, profile_id: &str) -> Result<()> {
     if deleted == 0 {
         return Err(anyhow!("llm profile not found: {profile_id}"));
     }
+    conn.execute(
+        r#"DELETE FROM llm_spending_limits WHERE profile_id = ?1"#,
+        params![profile_id],
+    )?;
 
     Ok(())
 }
@@ -423,17 +427,28 @@ pub fn record_llm_usage_daily(
 
     let has_usage = input_tokens.is_some() && output_tokens.is_some() && total_tokens.is_some();
     let requests_with_usage = if has_usage { 1 } else { 0 };
+    let cost_usd_micros = if has_usage {
+        llm_request_cost_usd_micros(
+            conn,
+            profile_id,
+            input_tokens.unwrap_or(0),
+            output_tokens.unwrap_or(0),
+        )?
+    } else {
+        0
+    };
 
     conn.execute(
         r#"INSERT INTO llm_usage_daily
-           (day, profile_id, purpose, requests, requests_with_usage, input_tokens, output_tokens, total_tokens, created_at_ms, updated_at_ms)
-           VALUES (?1, ?2, ?3, 1, ?4, ?5, ?6, ?7, ?8, ?8)
+           (day, profile_id, purpose, requests, requests_with_usage, input_tokens, output_tokens, total_tokens, cost_usd_micros, created_at_ms, updated_at_ms)
+           VALUES (?1, ?2, ?3, 1, ?4, ?5, ?6, ?7, ?8, ?9, ?9)
            ON CONFLICT(day, profile_id, purpose) DO UPDATE SET
              requests = llm_usage_daily.requests + excluded.requests,
              requests_with_usage = llm_usage_daily.requests_with_usage + excluded.requests_with_usage,
              input_tokens = llm_usage_daily.input_tokens + excluded.input_tokens,
              output_tokens = llm_usage_daily.output_tokens + excluded.output_tokens,
              total_tokens = llm_usage_daily.total_tokens + excluded.total_tokens,
+             cost_usd_micros = llm_usage_daily.cost_usd_micros + excluded.cost_usd_micros,
              updated_at_ms = excluded.updated_at_ms"#,
         params![
             day,
@@ -443,6 +458,7 @@ pub fn record_llm_usage_daily(
             input_tokens.unwrap_or(0),
             output_tokens.unwrap_or(0),
             total_tokens.unwrap_or(0),
+            cost_usd_micros,
             now
         ],
     )?;
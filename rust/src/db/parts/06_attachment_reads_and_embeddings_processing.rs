@@ -51,6 +51,35 @@ fn read_attachment_annotation_caption_long_optional(
     Ok(Some(caption_long.to_string()))
 }
 
+/// Full text extracted from an attachment: document text, OCR or transcript.
+/// Falls back to the excerpt when only that was stored.
+pub fn read_attachment_text(
+    conn: &Connection,
+    key: &[u8; 32],
+    attachment_sha256: &str,
+) -> Result<Option<String>> {
+    let Some(json) = read_attachment_annotation_payload_json(conn, key, attachment_sha256)? else {
+        return Ok(None);
+    };
+    let payload: serde_json::Value = match serde_json::from_str(&json) {
+        Ok(v) => v,
+        Err(_) => return Ok(None),
+    };
+
+    let full = [
+        "extracted_text_full",
+        "readable_text_full",
+        "ocr_text_full",
+        "transcript_full",
+    ]
+    .into_iter()
+    .find_map(|field| payload_str_trimmed(&payload, field));
+    if let Some(full) = full {
+        return Ok(Some(full.to_string()));
+    }
+    read_attachment_annotation_excerpt_optional(conn, key, attachment_sha256)
+}
+
 fn read_attachment_annotation_excerpt_optional(
     conn: &Connection,
     key: &[u8; 32],
@@ -205,6 +205,24 @@ ON CONFLICT(sha256) DO UPDATE SET
         params![sha256],
     )?;
 
+    // Chunk vectors live in a sqlite-vec table keyed by chunk id, which the
+    // cascade from `attachments` does not reach.
+    let mut stmt =
+        conn.prepare(r#"SELECT id FROM attachment_chunks WHERE attachment_sha256 = ?1"#)?;
+    let chunk_ids = stmt
+        .query_map(params![sha256], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    drop(stmt);
+    delete_attachment_chunk_vectors(conn, &chunk_ids)?;
+    let _ = conn.execute(
+        r#"DELETE FROM attachment_chunks WHERE attachment_sha256 = ?1"#,
+        params![sha256],
+    )?;
+    let _ = conn.execute(
+        r#"DELETE FROM attachment_chunk_sources WHERE attachment_sha256 = ?1"#,
+        params![sha256],
+    )?;
+
     best_effort_remove_file(&app_dir.join(format!("attachments/{sha256}.bin")))?;
     best_effort_remove_dir_all(&app_dir.join(format!("attachments/variants/{sha256}")))?;
 
@@ -695,6 +713,7 @@ ON CONFLICT(attachment_sha256) DO UPDATE SET
     insert_oplog(conn, key, &op)?;
 
     mark_messages_linked_to_attachment_for_reembedding(conn, attachment_sha256)?;
+    let _ = apply_tag_rules_to_attachment_messages(conn, key, attachment_sha256, now_ms);
     Ok(())
 }
 
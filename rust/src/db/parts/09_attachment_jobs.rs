@@ -26,6 +26,7 @@ ON CONFLICT(message_id) DO UPDATE SET
   attempts = 0,
   next_retry_at_ms = NULL,
   last_error = NULL,
+  last_failure_reason = NULL,
   applied_action_kind = NULL,
   applied_todo_id = NULL,
   applied_todo_title = NULL,
@@ -180,6 +181,8 @@ WHERE message_id = ?1
     Ok(())
 }
 
+/// Also records the failure reason derived from `last_error` (see
+/// `semantic_parse::failure_reason`).
 pub fn mark_semantic_parse_job_failed(
     conn: &Connection,
     message_id: &str,
@@ -188,6 +191,7 @@ pub fn mark_semantic_parse_job_failed(
     last_error: &str,
     now_ms: i64,
 ) -> Result<()> {
+    let reason = crate::semantic_parse::failure_reason(last_error);
     conn.execute(
         r#"
 UPDATE semantic_parse_jobs
@@ -195,14 +199,62 @@ SET status = 'failed',
     attempts = ?2,
     next_retry_at_ms = ?3,
     last_error = ?4,
+    last_failure_reason = ?6,
     updated_at_ms = ?5
 WHERE message_id = ?1
 "#,
-        params![message_id, attempts, next_retry_at_ms, last_error, now_ms],
+        params![
+            message_id,
+            attempts,
+            next_retry_at_ms,
+            last_error,
+            now_ms,
+            reason
+        ],
     )?;
     Ok(())
 }
 
+pub fn get_semantic_parse_job_failure_reason(
+    conn: &Connection,
+    message_id: &str,
+) -> Result<Option<String>> {
+    let reason: Option<Option<String>> = conn
+        .query_row(
+            r#"SELECT last_failure_reason FROM semantic_parse_jobs WHERE message_id = ?1"#,
+            params![message_id.trim()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(reason.flatten())
+}
+
+/// Number of currently failed jobs per failure reason, most common first.
+/// Jobs that failed before reasons were recorded are not counted.
+pub fn count_semantic_parse_failure_reasons(
+    conn: &Connection,
+) -> Result<Vec<SemanticParseFailureReasonCount>> {
+    let mut stmt = conn.prepare(
+        r#"
+SELECT last_failure_reason, COUNT(*)
+FROM semantic_parse_jobs
+WHERE status = 'failed'
+  AND last_failure_reason IS NOT NULL
+GROUP BY last_failure_reason
+ORDER BY COUNT(*) DESC, last_failure_reason ASC
+"#,
+    )?;
+    let counts = stmt
+        .query_map([], |row| {
+            Ok(SemanticParseFailureReasonCount {
+                reason: row.get(0)?,
+                jobs: row.get(1)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(counts)
+}
+
 pub fn mark_semantic_parse_job_retry(
     conn: &Connection,
     message_id: &str,
@@ -214,6 +266,7 @@ UPDATE semantic_parse_jobs
 SET status = 'pending',
     next_retry_at_ms = NULL,
     last_error = NULL,
+    last_failure_reason = NULL,
     applied_action_kind = NULL,
     applied_todo_id = NULL,
     applied_todo_title = NULL,
@@ -260,6 +313,7 @@ UPDATE semantic_parse_jobs
 SET status = 'succeeded',
     next_retry_at_ms = NULL,
     last_error = NULL,
+    last_failure_reason = NULL,
     applied_action_kind = ?2,
     applied_todo_id = ?3,
     applied_todo_title = ?4,
@@ -290,6 +344,7 @@ UPDATE semantic_parse_jobs
 SET status = 'canceled',
     next_retry_at_ms = NULL,
     last_error = NULL,
+    last_failure_reason = NULL,
     updated_at_ms = ?2
 WHERE message_id = ?1
   AND status != 'succeeded'
@@ -359,6 +414,7 @@ pub fn link_attachment_to_message(
             now,
         );
     }
+    let _ = apply_tag_rules_to_message(conn, key, message_id, now);
     Ok(())
 }
 
@@ -68,6 +68,37 @@ pub fn upsert_todo(
     review_stage: Option<i64>,
     next_review_at_ms: Option<i64>,
     last_review_at_ms: Option<i64>,
+) -> Result<Todo> {
+    upsert_todo_with_timezone(
+        conn,
+        key,
+        id,
+        title,
+        due_at_ms,
+        status,
+        source_entry_id,
+        review_stage,
+        next_review_at_ms,
+        last_review_at_ms,
+        None,
+    )
+}
+
+/// `upsert_todo` that can also set the timezone (`Some(None)` clears it);
+/// `None` keeps whatever the row already has.
+#[allow(clippy::too_many_arguments)]
+fn upsert_todo_with_timezone(
+    conn: &Connection,
+    key: &[u8; 32],
+    id: &str,
+    title: &str,
+    due_at_ms: Option<i64>,
+    status: &str,
+    source_entry_id: Option<&str>,
+    review_stage: Option<i64>,
+    next_review_at_ms: Option<i64>,
+    last_review_at_ms: Option<i64>,
+    tz: Option<Option<&str>>,
 ) -> Result<Todo> {
     let now = now_ms();
 
@@ -114,9 +145,9 @@ pub fn upsert_todo(
     conn.execute(
         r#"
 INSERT INTO todos (
-  id, title, due_at_ms, status, source_entry_id, created_at_ms, updated_at_ms, review_stage, next_review_at_ms, last_review_at_ms, needs_embedding
+  id, title, due_at_ms, status, source_entry_id, created_at_ms, updated_at_ms, review_stage, next_review_at_ms, last_review_at_ms, needs_embedding, tz
 )
-VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
 ON CONFLICT(id) DO UPDATE SET
   title = excluded.title,
   due_at_ms = excluded.due_at_ms,
@@ -126,7 +157,8 @@ ON CONFLICT(id) DO UPDATE SET
   review_stage = excluded.review_stage,
   next_review_at_ms = excluded.next_review_at_ms,
   last_review_at_ms = excluded.last_review_at_ms,
-  needs_embedding = excluded.needs_embedding
+  needs_embedding = excluded.needs_embedding,
+  tz = CASE WHEN ?13 = 1 THEN excluded.tz ELSE todos.tz END
 "#,
         params![
             id,
@@ -140,10 +172,21 @@ ON CONFLICT(id) DO UPDATE SET
             next_review_at_ms,
             last_review_at_ms,
             needs_embedding,
+            tz.flatten(),
+            if tz.is_some() { 1 } else { 0 },
         ],
     )?;
 
     let todo = get_todo_by_id(conn, key, id)?;
+    append_todo_upsert_op(conn, key, &todo)?;
+
+    Ok(todo)
+}
+
+/// Records the full current state of `todo` as a `todo.upsert.v1` op.
+fn append_todo_upsert_op(conn: &Connection, key: &[u8; 32], todo: &Todo) -> Result<()> {
+    let tz = get_todo_timezone(conn, &todo.id)?;
+    let (parent_id, complete_with_subtasks) = read_todo_hierarchy_fields(conn, &todo.id)?;
 
     let device_id = get_or_create_device_id(conn)?;
     let seq = next_device_seq(conn, &device_id)?;
@@ -151,7 +194,7 @@ ON CONFLICT(id) DO UPDATE SET
         "op_id": uuid::Uuid::new_v4().to_string(),
         "device_id": device_id,
         "seq": seq,
-        "ts_ms": now,
+        "ts_ms": todo.updated_at_ms,
         "type": "todo.upsert.v1",
         "payload": {
             "todo_id": todo.id.as_str(),
@@ -164,11 +207,51 @@ ON CONFLICT(id) DO UPDATE SET
             "review_stage": todo.review_stage,
             "next_review_at_ms": todo.next_review_at_ms,
             "last_review_at_ms": todo.last_review_at_ms,
+            "tz": tz,
+            "parent_id": parent_id,
+            "complete_with_subtasks": complete_with_subtasks,
         }
     });
-    insert_oplog(conn, key, &op)?;
+    insert_oplog(conn, key, &op)
+}
 
-    Ok(todo)
+/// IANA timezone the todo's due time is anchored to; `None` means UTC.
+pub fn get_todo_timezone(conn: &Connection, todo_id: &str) -> Result<Option<String>> {
+    let tz: Option<Option<String>> = conn
+        .query_row(
+            r#"SELECT tz FROM todos WHERE id = ?1"#,
+            params![todo_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(tz.flatten())
+}
+
+pub fn set_todo_timezone(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    tz: Option<&str>,
+) -> Result<Todo> {
+    let tz = tz.map(str::trim).filter(|tz| !tz.is_empty());
+    if let Some(tz) = tz {
+        let _ = parse_timezone(tz)?;
+    }
+
+    let existing = get_todo_by_id(conn, key, todo_id)?;
+    upsert_todo_with_timezone(
+        conn,
+        key,
+        todo_id,
+        &existing.title,
+        existing.due_at_ms,
+        &existing.status,
+        existing.source_entry_id.as_deref(),
+        existing.review_stage,
+        existing.next_review_at_ms,
+        existing.last_review_at_ms,
+        Some(tz),
+    )
 }
 
 pub fn list_todos(conn: &Connection, key: &[u8; 32]) -> Result<Vec<Todo>> {
@@ -742,6 +825,7 @@ VALUES (?1, ?2, 'status_change', ?3, ?4, NULL, ?5, ?6, 1)
     )?;
 
     maybe_spawn_next_recurring_todo(conn, key, &updated, new_status)?;
+    maybe_complete_parent_todo(conn, key, &updated, source_message_id)?;
 
     Ok(updated)
 }
@@ -933,6 +1017,18 @@ ON CONFLICT(todo_id) DO UPDATE SET
             r#"DELETE FROM todo_embeddings WHERE todo_id = ?1"#,
             params![todo_id],
         )?;
+        conn.execute(
+            r#"DELETE FROM todo_dependencies WHERE todo_id = ?1 OR blocked_by_todo_id = ?1"#,
+            params![todo_id],
+        )?;
+        conn.execute(
+            r#"DELETE FROM review_items WHERE item_kind = 'todo' AND item_id = ?1"#,
+            params![todo_id],
+        )?;
+        conn.execute(
+            r#"DELETE FROM review_activities WHERE item_kind = 'todo' AND item_id = ?1"#,
+            params![todo_id],
+        )?;
 
         conn.execute(r#"DELETE FROM todos WHERE id = ?1"#, params![todo_id])?;
 
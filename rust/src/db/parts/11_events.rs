@@ -45,6 +45,10 @@ WHERE id = ?1
     })
 }
 
+pub fn get_event(conn: &Connection, key: &[u8; 32], id: &str) -> Result<Event> {
+    get_event_by_id(conn, key, id)
+}
+
 pub fn upsert_event(
     conn: &Connection,
     key: &[u8; 32],
@@ -109,6 +113,59 @@ ON CONFLICT(id) DO UPDATE SET
     Ok(event)
 }
 
+/// Deletes an event and records a tombstone so the delete syncs.
+pub fn delete_event(conn: &Connection, key: &[u8; 32], id: &str) -> Result<()> {
+    let now = now_ms();
+    conn.execute_batch("BEGIN IMMEDIATE;")?;
+    let result: Result<()> = (|| {
+        conn.execute(r#"DELETE FROM events WHERE id = ?1"#, params![id])?;
+        conn.execute(
+            r#"DELETE FROM event_recurrences WHERE event_id = ?1"#,
+            params![id],
+        )?;
+        conn.execute(
+            r#"DELETE FROM event_exceptions WHERE event_id = ?1"#,
+            params![id],
+        )?;
+        conn.execute(
+            r#"
+INSERT INTO event_deletions(event_id, deleted_at_ms)
+VALUES (?1, ?2)
+ON CONFLICT(event_id) DO UPDATE SET
+  deleted_at_ms = max(event_deletions.deleted_at_ms, excluded.deleted_at_ms)
+"#,
+            params![id, now],
+        )?;
+
+        let device_id = get_or_create_device_id(conn)?;
+        let seq = next_device_seq(conn, &device_id)?;
+        let op = serde_json::json!({
+            "op_id": uuid::Uuid::new_v4().to_string(),
+            "device_id": device_id,
+            "seq": seq,
+            "ts_ms": now,
+            "type": "event.delete.v1",
+            "payload": {
+                "event_id": id,
+                "deleted_at_ms": now,
+            }
+        });
+        insert_oplog(conn, key, &op)?;
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            conn.execute_batch("COMMIT;")?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK;");
+            Err(e)
+        }
+    }
+}
+
 pub fn list_events(conn: &Connection, key: &[u8; 32]) -> Result<Vec<Event>> {
     let mut stmt = conn.prepare(
         r#"
@@ -148,47 +205,23 @@ ORDER BY start_at_ms ASC, end_at_ms ASC
     Ok(result)
 }
 
+/// Events overlapping the range. A recurring event appears once per
+/// occurrence, under its own id, with the occurrence's times and title.
 pub fn list_events_in_range(
     conn: &Connection,
     key: &[u8; 32],
     start_at_ms_inclusive: i64,
     end_at_ms_exclusive: i64,
 ) -> Result<Vec<Event>> {
-    let mut stmt = conn.prepare(
-        r#"
-SELECT id, title, start_at_ms, end_at_ms, tz, source_entry_id, created_at_ms, updated_at_ms
-FROM events
-WHERE start_at_ms < ?2 AND end_at_ms > ?1
-ORDER BY start_at_ms ASC, end_at_ms ASC
-"#,
-    )?;
-
-    let mut rows = stmt.query(params![start_at_ms_inclusive, end_at_ms_exclusive])?;
-    let mut result = Vec::new();
-    while let Some(row) = rows.next()? {
-        let id: String = row.get(0)?;
-        let title_blob: Vec<u8> = row.get(1)?;
-        let start_at_ms: i64 = row.get(2)?;
-        let end_at_ms: i64 = row.get(3)?;
-        let tz: String = row.get(4)?;
-        let source_entry_id: Option<String> = row.get(5)?;
-        let created_at_ms: i64 = row.get(6)?;
-        let updated_at_ms: i64 = row.get(7)?;
-
-        let title_bytes = decrypt_bytes(key, &title_blob, b"event.title")?;
-        let title = String::from_utf8(title_bytes)
-            .map_err(|_| anyhow!("event title is not valid utf-8"))?;
-
-        result.push(Event {
-            id,
-            title,
-            start_at_ms,
-            end_at_ms,
-            tz,
-            source_entry_id,
-            created_at_ms,
-            updated_at_ms,
-        });
-    }
-    Ok(result)
+    Ok(
+        expand_events_in_range(conn, key, start_at_ms_inclusive, end_at_ms_exclusive)?
+            .into_iter()
+            .map(|(event, occurrence)| Event {
+                title: occurrence.title,
+                start_at_ms: occurrence.start_at_ms,
+                end_at_ms: occurrence.end_at_ms,
+                ..event
+            })
+            .collect(),
+    )
 }
@@ -663,6 +663,7 @@ pub fn process_pending_document_extractions(
                     "mime_type": mime_type,
                     "extracted_text_full": extracted.full_text,
                     "extracted_text_excerpt": extracted.excerpt,
+                    "extracted_text_page_offsets": extracted.page_offsets,
                     "needs_ocr": extracted.needs_ocr,
                     "page_count": extracted.page_count,
                     "ocr_text_full": serde_json::Value::Null,
@@ -9,6 +9,15 @@ struct TodoRecurrenceMeta {
 struct RecurrenceRule {
     freq: String,
     interval: i64,
+    by_day: Vec<RecurrenceByDay>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u8>,
+    count: Option<i64>,
+    until_ms: Option<i64>,
+    week_start: time::Weekday,
+    exdates_ms: BTreeSet<i64>,
+    /// IANA timezone the rule is evaluated in; falls back to the todo's `tz`.
+    tz: Option<&'static time_tz::Tz>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -47,7 +56,88 @@ fn parse_recurrence_rule(rule_json: &str) -> Result<RecurrenceRule> {
         .unwrap_or(1)
         .clamp(1, 10_000);
 
-    Ok(RecurrenceRule { freq, interval })
+    let by_day = match &value["byday"] {
+        serde_json::Value::Null => Vec::new(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_str()
+                    .ok_or_else(|| anyhow!("recurrence byday entries must be strings"))
+                    .and_then(parse_recurrence_by_day)
+            })
+            .collect::<Result<Vec<_>>>()?,
+        _ => return Err(anyhow!("recurrence byday must be an array")),
+    };
+    if by_day.iter().any(|day| day.ordinal.is_some()) && freq != "monthly" && freq != "yearly" {
+        return Err(anyhow!("ordinal byday is only supported for monthly and yearly rules"));
+    }
+
+    let by_month_day = parse_recurrence_int_list(&value["bymonthday"], "bymonthday")?
+        .into_iter()
+        .map(|day| {
+            if (1..=31).contains(&day) || (-31..=-1).contains(&day) {
+                Ok(day as i32)
+            } else {
+                Err(anyhow!("invalid recurrence bymonthday: {day}"))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let by_month = parse_recurrence_int_list(&value["bymonth"], "bymonth")?
+        .into_iter()
+        .map(|month| {
+            if (1..=12).contains(&month) {
+                Ok(month as u8)
+            } else {
+                Err(anyhow!("invalid recurrence bymonth: {month}"))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let count = match &value["count"] {
+        serde_json::Value::Null => None,
+        raw => match raw.as_i64() {
+            Some(count) if count >= 1 => Some(count),
+            _ => return Err(anyhow!("recurrence count must be a positive integer")),
+        },
+    };
+    let until_ms = match &value["until_ms"] {
+        serde_json::Value::Null => None,
+        raw => Some(
+            raw.as_i64()
+                .ok_or_else(|| anyhow!("recurrence until_ms must be an integer"))?,
+        ),
+    };
+    if count.is_some() && until_ms.is_some() {
+        return Err(anyhow!("recurrence rule cannot set both count and until_ms"));
+    }
+
+    let week_start = match value["wkst"].as_str() {
+        Some(raw) => parse_recurrence_weekday(raw)?,
+        None if value["wkst"].is_null() => time::Weekday::Monday,
+        None => return Err(anyhow!("recurrence wkst must be a string")),
+    };
+    let exdates_ms = parse_recurrence_int_list(&value["exdates_ms"], "exdates_ms")?
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    let tz = match &value["tz"] {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(name) if name.trim().is_empty() => None,
+        serde_json::Value::String(name) => Some(parse_timezone(name)?),
+        _ => return Err(anyhow!("recurrence tz must be a string")),
+    };
+
+    Ok(RecurrenceRule {
+        freq,
+        interval,
+        by_day,
+        by_month_day,
+        by_month,
+        count,
+        until_ms,
+        week_start,
+        exdates_ms,
+        tz,
+    })
 }
 
 fn is_leap_year(year: i32) -> bool {
@@ -77,52 +167,22 @@ fn days_in_month(year: i32, month: time::Month) -> u8 {
     }
 }
 
-fn add_months_utc_ms(base_ms: i64, month_delta: i32) -> Result<i64> {
-    let base_secs = base_ms.div_euclid(1000);
-    let ms_remainder = base_ms.rem_euclid(1000);
-    let dt = time::OffsetDateTime::from_unix_timestamp(base_secs)
-        .map_err(|e| anyhow!("invalid base timestamp: {e}"))?;
-
-    let base_year = dt.year();
-    let base_month = dt.month() as i32;
-    let total_month = (base_year * 12 + (base_month - 1))
-        .checked_add(month_delta)
-        .ok_or_else(|| anyhow!("recurrence month overflow"))?;
-    let new_year = total_month.div_euclid(12);
-    let new_month_index = total_month.rem_euclid(12) + 1;
-    let new_month = time::Month::try_from(new_month_index as u8)
-        .map_err(|_| anyhow!("invalid month after recurrence add"))?;
-
-    let day = dt.day().min(days_in_month(new_year, new_month));
-    let new_date = time::Date::from_calendar_date(new_year, new_month, day)
-        .map_err(|e| anyhow!("invalid recurrence date: {e}"))?;
-    let new_dt = time::PrimitiveDateTime::new(new_date, dt.time()).assume_utc();
-
-    Ok(new_dt.unix_timestamp() * 1000 + ms_remainder)
-}
-
-fn next_due_at_ms(base_due_at_ms: i64, rule_json: &str) -> Result<i64> {
-    let rule = parse_recurrence_rule(rule_json)?;
-    let interval_days = rule.interval.saturating_mul(24 * 60 * 60 * 1000);
-
-    match rule.freq.as_str() {
-        "daily" => base_due_at_ms
-            .checked_add(interval_days)
-            .ok_or_else(|| anyhow!("daily recurrence overflow")),
-        "weekly" => base_due_at_ms
-            .checked_add(interval_days.saturating_mul(7))
-            .ok_or_else(|| anyhow!("weekly recurrence overflow")),
-        "monthly" => {
-            let months = i32::try_from(rule.interval).map_err(|_| anyhow!("monthly interval overflow"))?;
-            add_months_utc_ms(base_due_at_ms, months)
-        }
-        "yearly" => {
-            let months =
-                i32::try_from(rule.interval.saturating_mul(12)).map_err(|_| anyhow!("yearly interval overflow"))?;
-            add_months_utc_ms(base_due_at_ms, months)
-        }
-        _ => Err(anyhow!("unsupported recurrence freq")),
+/// Next due time after `base_due_at_ms`, or `None` once the series has ended
+/// (`count` reached or past `until_ms`). `next_occurrence_index` is the index
+/// the spawned todo would get, which is what `count` is measured against.
+/// Rules without their own `tz` run in `fallback_tz`.
+fn next_due_at_ms(
+    base_due_at_ms: i64,
+    rule_json: &str,
+    next_occurrence_index: i64,
+    fallback_tz: Option<&'static time_tz::Tz>,
+) -> Result<Option<i64>> {
+    let mut rule = parse_recurrence_rule(rule_json)?;
+    rule.tz = rule.tz.or(fallback_tz);
+    if rule.count.is_some_and(|count| next_occurrence_index >= count) {
+        return Ok(None);
     }
+    next_recurrence_occurrence_ms(&rule, base_due_at_ms, base_due_at_ms)
 }
 
 fn get_todo_recurrence_meta(conn: &Connection, todo_id: &str) -> Result<Option<TodoRecurrenceMeta>> {
@@ -150,6 +210,82 @@ pub fn get_todo_recurrence_rule_json(conn: &Connection, todo_id: &str) -> Result
     Ok(get_todo_recurrence_meta(conn, todo_id)?.map(|meta| meta.rule_json))
 }
 
+/// Id of the newest occurrence of a series, if the series exists.
+pub fn get_latest_todo_id_in_recurrence_series(
+    conn: &Connection,
+    series_id: &str,
+) -> Result<Option<String>> {
+    conn.query_row(
+        r#"
+SELECT todo_id
+FROM todo_recurrences
+WHERE series_id = ?1
+ORDER BY occurrence_index DESC
+LIMIT 1
+"#,
+        params![series_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| anyhow!("failed to load latest recurrence todo: {e}"))
+}
+
+/// The series rule as seen from this occurrence on, i.e. with `count` only
+/// covering the remaining occurrences. This is how a single calendar item
+/// that stands for the series describes it.
+pub fn get_todo_recurrence_rule_json_from_occurrence(
+    conn: &Connection,
+    todo_id: &str,
+) -> Result<Option<String>> {
+    let Some(meta) = get_todo_recurrence_meta(conn, todo_id)? else {
+        return Ok(None);
+    };
+    Ok(Some(rebase_recurrence_rule_json(
+        &meta.rule_json,
+        meta.occurrence_index,
+        None,
+        None,
+    )?))
+}
+
+/// Replaces the rule of the todo's whole series with one counted from this
+/// occurrence on (see `get_todo_recurrence_rule_json_from_occurrence`).
+pub fn set_todo_recurrence_rule_from_occurrence(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    rule_json: &str,
+) -> Result<()> {
+    let rule = parse_recurrence_rule(rule_json)?;
+    let meta = get_todo_recurrence_meta(conn, todo_id)?
+        .ok_or_else(|| anyhow!("todo recurrence metadata missing"))?;
+    let series_rule_json = match rule.count {
+        Some(count) if meta.occurrence_index > 0 => {
+            let mut value: serde_json::Value = serde_json::from_str(rule_json)?;
+            value["count"] = serde_json::json!(count.saturating_add(meta.occurrence_index));
+            value.to_string()
+        }
+        _ => rule_json.to_string(),
+    };
+    update_todo_recurrence_rule_with_scope(
+        conn,
+        key,
+        todo_id,
+        &series_rule_json,
+        TodoRecurrenceEditScope::WholeSeries,
+    )
+}
+
+/// The todo's own timezone, as used when its rule does not name one. Names
+/// this build's tz database does not know (e.g. synced from a newer device)
+/// fall back to UTC rather than blocking edits.
+fn todo_recurrence_fallback_timezone(
+    conn: &Connection,
+    todo_id: &str,
+) -> Result<Option<&'static time_tz::Tz>> {
+    Ok(get_todo_timezone(conn, todo_id)?.and_then(|tz| parse_timezone(&tz).ok()))
+}
+
 fn upsert_todo_recurrence_row(
     conn: &Connection,
     todo_id: &str,
@@ -429,9 +565,9 @@ pub fn update_todo_due_with_scope(
             )?,
             TodoRecurrenceEditScope::ThisAndFuture | TodoRecurrenceEditScope::WholeSeries => {
                 let meta = current_meta.ok_or_else(|| anyhow!("todo recurrence metadata missing"))?;
-                let delta = due_at_ms
-                    .checked_sub(current_due_at_ms)
-                    .ok_or_else(|| anyhow!("due edit overflow while computing delta"))?;
+                let tz = parse_recurrence_rule(&meta.rule_json)?
+                    .tz
+                    .or(todo_recurrence_fallback_timezone(conn, todo_id)?);
 
                 let scoped_todos = list_scoped_recurrence_todos(
                     conn,
@@ -447,11 +583,12 @@ pub fn update_todo_due_with_scope(
                         Some(due_at_ms)
                     } else {
                         match series_todo.due_at_ms {
-                            Some(existing_due) => Some(
-                                existing_due
-                                    .checked_add(delta)
-                                    .ok_or_else(|| anyhow!("due edit overflow while shifting series"))?,
-                            ),
+                            Some(existing_due) => Some(shift_recurrence_instant_ms(
+                                existing_due,
+                                current_due_at_ms,
+                                due_at_ms,
+                                tz,
+                            )?),
                             None => None,
                         }
                     };
@@ -474,14 +611,39 @@ pub fn update_todo_due_with_scope(
                 }
 
                 if apply_scope == TodoRecurrenceEditScope::ThisAndFuture {
+                    let split_rule_json = rebase_recurrence_rule_json(
+                        &meta.rule_json,
+                        meta.occurrence_index,
+                        Some((current_due_at_ms, due_at_ms)),
+                        tz,
+                    )?;
                     let _ = split_scoped_recurrence_series(
                         conn,
                         key,
                         &meta,
                         &scoped_todos,
-                        &meta.rule_json,
+                        &split_rule_json,
                         TodoRecurrenceEditScope::ThisAndFuture,
                     )?;
+                } else {
+                    let shifted_rule_json = rebase_recurrence_rule_json(
+                        &meta.rule_json,
+                        0,
+                        Some((current_due_at_ms, due_at_ms)),
+                        tz,
+                    )?;
+                    if shifted_rule_json != meta.rule_json {
+                        for (series_todo_id, occurrence_index) in &scoped_todos {
+                            let _ = upsert_todo_recurrence_with_sync_in_txn(
+                                conn,
+                                key,
+                                series_todo_id,
+                                &meta.series_id,
+                                &shifted_rule_json,
+                                Some(*occurrence_index),
+                            )?;
+                        }
+                    }
                 }
 
                 updated_current.ok_or_else(|| anyhow!("current todo not found in recurrence scope"))?
@@ -552,12 +714,14 @@ pub fn update_todo_status_with_scope(
                 }
 
                 if apply_scope == TodoRecurrenceEditScope::ThisAndFuture {
+                    let split_rule_json =
+                        rebase_recurrence_rule_json(&meta.rule_json, meta.occurrence_index, None, None)?;
                     let _ = split_scoped_recurrence_series(
                         conn,
                         key,
                         &meta,
                         &scoped_todos,
-                        &meta.rule_json,
+                        &split_rule_json,
                         TodoRecurrenceEditScope::ThisAndFuture,
                     )?;
                 }
@@ -675,10 +839,16 @@ fn maybe_spawn_next_recurring_todo(
         return Ok(());
     }
 
-    let next_due_at_ms = next_due_at_ms(base_due_at_ms, &meta.rule_json)?;
+    let todo_tz = get_todo_timezone(conn, &todo.id)?;
+    let fallback_tz = todo_tz.as_deref().and_then(|tz| parse_timezone(tz).ok());
+    let Some(next_due_at_ms) =
+        next_due_at_ms(base_due_at_ms, &meta.rule_json, next_index, fallback_tz)?
+    else {
+        return Ok(());
+    };
     let next_todo_id = format!("todo:{}:{}", meta.series_id, next_index);
 
-    let _ = upsert_todo(
+    let _ = upsert_todo_with_timezone(
         conn,
         key,
         &next_todo_id,
@@ -689,6 +859,7 @@ fn maybe_spawn_next_recurring_todo(
         None,
         None,
         Some(now_ms()),
+        todo_tz.as_deref().map(Some),
     )?;
 
     let _ = upsert_todo_recurrence_with_sync_in_txn(
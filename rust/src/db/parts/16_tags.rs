@@ -471,6 +471,16 @@ pub fn merge_tags(
     let _target = read_tag_by_id(conn, db_key, target_tag_id)?
         .ok_or_else(|| anyhow!("target tag not found: {target_tag_id}"))?;
 
+    // Child tags follow the merge. A target inside the source subtree first
+    // moves up to the source's parent so re-parenting cannot form a cycle.
+    if is_tag_ancestor(conn, source_tag_id, target_tag_id)? {
+        let source_parent_id = read_tag_parent_id(conn, source_tag_id)?;
+        set_tag_parent(conn, db_key, target_tag_id, source_parent_id.as_deref())?;
+    }
+    for child_id in list_child_tag_ids(conn, source_tag_id)? {
+        set_tag_parent(conn, db_key, &child_id, Some(target_tag_id))?;
+    }
+
     let mut stmt = conn.prepare(
         r#"SELECT DISTINCT message_id
            FROM message_tags
@@ -563,15 +573,26 @@ pub fn upsert_tag(conn: &Connection, db_key: &[u8; 32], name: &str) -> Result<Ta
         return Ok(existing);
     }
 
+    insert_custom_tag(conn, db_key, trimmed, None)
+}
+
+fn insert_custom_tag(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    trimmed: &str,
+    parent_id: Option<&str>,
+) -> Result<Tag> {
     let now = now_ms();
     let id = uuid::Uuid::new_v4().to_string();
     let aad = format!("tag.name:{id}");
     let name_blob = encrypt_bytes(db_key, trimmed.as_bytes(), aad.as_bytes())?;
 
     conn.execute(
-        r#"INSERT INTO tags(id, name, system_key, is_system, color, created_at_ms, updated_at_ms)
-           VALUES (?1, ?2, NULL, 0, NULL, ?3, ?4)"#,
-        params![id, name_blob, now, now],
+        r#"INSERT INTO tags(
+               id, name, system_key, is_system, color, parent_id, created_at_ms, updated_at_ms
+           )
+           VALUES (?1, ?2, NULL, 0, NULL, ?3, ?4, ?5)"#,
+        params![id, name_blob, parent_id, now, now],
     )?;
 
     let device_id = get_or_create_device_id(conn)?;
@@ -588,6 +609,7 @@ pub fn upsert_tag(conn: &Connection, db_key: &[u8; 32], name: &str) -> Result<Ta
             "system_key": null,
             "is_system": false,
             "color": null,
+            "parent_id": parent_id,
             "created_at_ms": now,
             "updated_at_ms": now,
         }
@@ -759,6 +781,7 @@ fn list_message_ids_by_tag_ids_with_optional_conversation(
     if tag_ids.is_empty() {
         return Ok(Vec::new());
     }
+    let tag_ids = expand_tag_ids_with_descendants(conn, &tag_ids)?;
 
     let conversation_clause = if conversation_id.is_some() {
         "m.conversation_id = ?1 AND "
@@ -400,14 +400,25 @@ fn apply_message_tag_autofill_candidate(
         return Ok(false);
     }
 
+    add_tag_to_message(conn, db_key, message_id, &tag.id)
+}
+
+/// Adds one tag to a message, keeping its other tags. Returns false when the
+/// message already had it.
+fn add_tag_to_message(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    message_id: &str,
+    tag_id: &str,
+) -> Result<bool> {
     let existing = list_message_tags(conn, db_key, message_id)?;
-    if existing.iter().any(|item| item.id == tag.id) {
+    if existing.iter().any(|item| item.id == tag_id) {
         return Ok(false);
     }
 
     if conn.is_autocommit() {
         let mut next_tag_ids = existing.into_iter().map(|item| item.id).collect::<Vec<_>>();
-        next_tag_ids.push(tag.id);
+        next_tag_ids.push(tag_id.to_string());
         next_tag_ids.sort();
         next_tag_ids.dedup();
         set_message_tags(conn, db_key, message_id, &next_tag_ids)?;
@@ -417,7 +428,7 @@ fn apply_message_tag_autofill_candidate(
     let inserted = conn.execute(
         r#"INSERT OR IGNORE INTO message_tags(message_id, tag_id, created_at_ms)
            VALUES (?1, ?2, ?3)"#,
-        params![message_id, tag.id, now_ms()],
+        params![message_id, tag_id, now_ms()],
     )?;
     Ok(inserted > 0)
 }
@@ -0,0 +1,105 @@
+#[derive(Clone, Debug)]
+pub struct ImportRecord {
+    pub source_kind: String,
+    pub source_key: String,
+    pub content_sha256: String,
+    pub message_id: Option<String>,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+}
+
+type ImportRecordRow = (String, String, String, Option<String>, i64, i64);
+
+fn import_record_from_row(row: ImportRecordRow) -> ImportRecord {
+    let (source_kind, source_key, content_sha256, message_id, created_at_ms, updated_at_ms) = row;
+    ImportRecord {
+        source_kind,
+        source_key,
+        content_sha256,
+        message_id,
+        created_at_ms,
+        updated_at_ms,
+    }
+}
+
+pub fn get_import_record(
+    conn: &Connection,
+    source_kind: &str,
+    source_key: &str,
+) -> Result<Option<ImportRecord>> {
+    let row: Option<ImportRecordRow> = conn
+        .query_row(
+            r#"SELECT source_kind, source_key, content_sha256, message_id, created_at_ms, updated_at_ms
+               FROM import_records
+               WHERE source_kind = ?1 AND source_key = ?2"#,
+            params![source_kind, source_key],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                ))
+            },
+        )
+        .optional()?;
+    Ok(row.map(import_record_from_row))
+}
+
+pub fn find_import_record_by_content_sha256(
+    conn: &Connection,
+    source_kind: &str,
+    content_sha256: &str,
+) -> Result<Option<ImportRecord>> {
+    let row: Option<ImportRecordRow> = conn
+        .query_row(
+            r#"SELECT source_kind, source_key, content_sha256, message_id, created_at_ms, updated_at_ms
+               FROM import_records
+               WHERE source_kind = ?1 AND content_sha256 = ?2
+               ORDER BY created_at_ms ASC, source_key ASC
+               LIMIT 1"#,
+            params![source_kind, content_sha256],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                ))
+            },
+        )
+        .optional()?;
+    Ok(row.map(import_record_from_row))
+}
+
+pub fn upsert_import_record(
+    conn: &Connection,
+    source_kind: &str,
+    source_key: &str,
+    content_sha256: &str,
+    message_id: Option<&str>,
+    now_ms: i64,
+) -> Result<()> {
+    let source_kind = source_kind.trim();
+    let source_key = source_key.trim();
+    if source_kind.is_empty() || source_key.is_empty() {
+        return Err(anyhow!("import record requires source_kind and source_key"));
+    }
+
+    conn.execute(
+        r#"
+INSERT INTO import_records(source_kind, source_key, content_sha256, message_id, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+ON CONFLICT(source_kind, source_key) DO UPDATE SET
+  content_sha256 = excluded.content_sha256,
+  message_id = COALESCE(excluded.message_id, import_records.message_id),
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![source_kind, source_key, content_sha256, message_id, now_ms],
+    )?;
+    Ok(())
+}
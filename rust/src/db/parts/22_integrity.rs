@@ -0,0 +1,333 @@
+pub const INTEGRITY_MISSING_ATTACHMENT_FILE: &str = "missing_attachment_file";
+pub const INTEGRITY_ORPHAN_ATTACHMENT_FILE: &str = "orphan_attachment_file";
+pub const INTEGRITY_DANGLING_MESSAGE_ATTACHMENT: &str = "dangling_message_attachment";
+pub const INTEGRITY_STALE_MESSAGE_EMBEDDING: &str = "stale_message_embedding";
+pub const INTEGRITY_TODO_MISSING_SOURCE_ENTRY: &str = "todo_missing_source_entry";
+pub const INTEGRITY_EMPTY_RECURRENCE_SERIES: &str = "empty_recurrence_series";
+pub const INTEGRITY_STUCK_RUNNING_JOB: &str = "stuck_running_job";
+
+/// Jobs are marked `running` only for the duration of one worker pass; anything
+/// older was interrupted (app killed, crash). Workers pick such jobs up again,
+/// but until then the job reports a run that is not happening.
+const STUCK_RUNNING_JOB_AFTER_MS: i64 = 30 * 60 * 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityIssue {
+    pub kind: String,
+    pub subject: String,
+    pub fixed: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssue>,
+    pub fixed_count: u32,
+}
+
+impl IntegrityReport {
+    fn push(&mut self, kind: &str, subject: impl Into<String>, fixed: bool) {
+        if fixed {
+            self.fixed_count += 1;
+        }
+        self.issues.push(IntegrityIssue {
+            kind: kind.to_string(),
+            subject: subject.into(),
+            fixed,
+        });
+    }
+}
+
+fn query_string_pairs(conn: &Connection, sql: &str) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query([])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        out.push((row.get(0)?, row.get(1)?));
+    }
+    Ok(out)
+}
+
+fn check_attachment_files(
+    conn: &Connection,
+    app_dir: &Path,
+    report: &mut IntegrityReport,
+    fix: bool,
+) -> Result<()> {
+    let rows = query_string_pairs(
+        conn,
+        r#"SELECT sha256, path FROM attachments ORDER BY sha256"#,
+    )?;
+    let mut known: BTreeSet<String> = BTreeSet::new();
+    for (sha256, rel_path) in rows {
+        // Missing bytes are not repaired here: synced attachments are fetched
+        // again on demand through the sync download APIs.
+        if !app_dir.join(&rel_path).is_file() {
+            report.push(INTEGRITY_MISSING_ATTACHMENT_FILE, sha256.clone(), false);
+        }
+        known.insert(sha256);
+    }
+
+    let attachments_dir = app_dir.join("attachments");
+    if !attachments_dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(&attachments_dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_file())
+        .collect();
+    entries.sort();
+    for path in entries {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Some(sha256) = name.strip_suffix(".bin") else {
+            continue;
+        };
+        if known.contains(sha256) {
+            continue;
+        }
+        if fix {
+            best_effort_remove_file(&path)?;
+        }
+        report.push(
+            INTEGRITY_ORPHAN_ATTACHMENT_FILE,
+            format!("attachments/{name}"),
+            fix,
+        );
+    }
+
+    let variants_dir = attachments_dir.join("variants");
+    if !variants_dir.is_dir() {
+        return Ok(());
+    }
+    let mut variant_dirs: Vec<PathBuf> = fs::read_dir(&variants_dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_dir())
+        .collect();
+    variant_dirs.sort();
+    for path in variant_dirs {
+        let Some(sha256) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if known.contains(sha256) {
+            continue;
+        }
+        let subject = format!("attachments/variants/{sha256}");
+        if fix {
+            best_effort_remove_dir_all(&path)?;
+        }
+        report.push(INTEGRITY_ORPHAN_ATTACHMENT_FILE, subject, fix);
+    }
+
+    Ok(())
+}
+
+fn check_dangling_message_attachments(
+    conn: &Connection,
+    report: &mut IntegrityReport,
+    fix: bool,
+) -> Result<()> {
+    let rows = query_string_pairs(
+        conn,
+        r#"SELECT ma.message_id, ma.attachment_sha256
+           FROM message_attachments ma
+           LEFT JOIN attachments a ON a.sha256 = ma.attachment_sha256
+           WHERE a.sha256 IS NULL
+           ORDER BY ma.message_id, ma.attachment_sha256"#,
+    )?;
+    for (message_id, sha256) in rows {
+        if fix {
+            conn.execute(
+                r#"DELETE FROM message_attachments WHERE message_id = ?1 AND attachment_sha256 = ?2"#,
+                params![message_id, sha256],
+            )?;
+        }
+        report.push(
+            INTEGRITY_DANGLING_MESSAGE_ATTACHMENT,
+            format!("{message_id}:{sha256}"),
+            fix,
+        );
+    }
+    Ok(())
+}
+
+fn message_embedding_tables(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT name FROM sqlite_master
+           WHERE type = 'table'
+             AND (name = 'message_embeddings' OR name LIKE 'message_embeddings\_\_%' ESCAPE '\')
+             AND sql LIKE '%USING vec0%'
+           ORDER BY name"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        if name == "message_embeddings" || is_safe_sqlite_ident(&name) {
+            out.push(name);
+        }
+    }
+    Ok(out)
+}
+
+fn check_stale_message_embeddings(
+    conn: &Connection,
+    report: &mut IntegrityReport,
+    fix: bool,
+) -> Result<()> {
+    for table in message_embedding_tables(conn)? {
+        let stale: Vec<i64> = {
+            let mut stmt = conn.prepare(&format!(
+                r#"SELECT e.rowid
+                   FROM "{table}" e
+                   LEFT JOIN messages m ON m.rowid = e.rowid
+                   WHERE m.rowid IS NULL
+                      OR COALESCE(m.is_deleted, 0) = 1
+                      OR COALESCE(m.is_memory, 1) = 0
+                   ORDER BY e.rowid"#
+            ))?;
+            let rows = stmt.query_map([], |row| row.get(0))?;
+            rows.collect::<rusqlite::Result<Vec<i64>>>()?
+        };
+
+        for rowid in stale {
+            if fix {
+                conn.execute(
+                    &format!(r#"DELETE FROM "{table}" WHERE rowid = ?1"#),
+                    params![rowid],
+                )?;
+            }
+            report.push(
+                INTEGRITY_STALE_MESSAGE_EMBEDDING,
+                format!("{table}:{rowid}"),
+                fix,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn check_todo_source_entries(
+    conn: &Connection,
+    key: &[u8; 32],
+    report: &mut IntegrityReport,
+    fix: bool,
+) -> Result<()> {
+    let rows = query_string_pairs(
+        conn,
+        r#"SELECT t.id, t.source_entry_id
+           FROM todos t
+           LEFT JOIN messages m ON m.id = t.source_entry_id
+           WHERE t.source_entry_id IS NOT NULL
+             AND m.id IS NULL
+           ORDER BY t.id"#,
+    )?;
+    for (todo_id, _source_entry_id) in rows {
+        if fix {
+            // Go through `upsert_todo` so the cleared link syncs to other devices.
+            let todo = get_todo_by_id(conn, key, &todo_id)?;
+            upsert_todo(
+                conn,
+                key,
+                &todo.id,
+                &todo.title,
+                todo.due_at_ms,
+                &todo.status,
+                None,
+                todo.review_stage,
+                todo.next_review_at_ms,
+                todo.last_review_at_ms,
+            )?;
+        }
+        report.push(INTEGRITY_TODO_MISSING_SOURCE_ENTRY, todo_id, fix);
+    }
+    Ok(())
+}
+
+fn check_empty_recurrence_series(
+    conn: &Connection,
+    report: &mut IntegrityReport,
+    fix: bool,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT s.id
+           FROM todo_series s
+           WHERE NOT EXISTS (SELECT 1 FROM todo_recurrences r WHERE r.series_id = s.id)
+           ORDER BY s.id"#,
+    )?;
+    let series_ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    for series_id in series_ids {
+        if fix {
+            conn.execute(
+                r#"DELETE FROM todo_series WHERE id = ?1"#,
+                params![series_id],
+            )?;
+        }
+        report.push(INTEGRITY_EMPTY_RECURRENCE_SERIES, series_id, fix);
+    }
+    Ok(())
+}
+
+/// Only the semantic parse and tag autofill queues have a `running` state.
+/// Attachment annotation, place and cloud backup jobs go straight from
+/// `pending` to done or `failed`, and embeddings are tracked by flags on the
+/// embedded rows, so none of them can be left running.
+fn check_stuck_running_jobs(
+    conn: &Connection,
+    report: &mut IntegrityReport,
+    fix: bool,
+    now_ms: i64,
+) -> Result<()> {
+    let stuck_before_ms = now_ms - STUCK_RUNNING_JOB_AFTER_MS;
+    for table in ["semantic_parse_jobs", "message_tag_autofill_jobs"] {
+        let mut stmt = conn.prepare(&format!(
+            r#"SELECT message_id FROM {table}
+               WHERE status = 'running' AND updated_at_ms < ?1
+               ORDER BY message_id"#
+        ))?;
+        let message_ids = stmt
+            .query_map(params![stuck_before_ms], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        for message_id in message_ids {
+            if fix {
+                conn.execute(
+                    &format!(
+                        r#"UPDATE {table}
+                           SET status = 'pending', next_retry_at_ms = NULL, updated_at_ms = ?2
+                           WHERE message_id = ?1 AND status = 'running'"#
+                    ),
+                    params![message_id, now_ms],
+                )?;
+            }
+            report.push(
+                INTEGRITY_STUCK_RUNNING_JOB,
+                format!("{table}:{message_id}"),
+                fix,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Scans the vault for inconsistencies that otherwise only surface when a
+/// feature breaks. With `fix`, repairable issues are fixed in place; missing
+/// attachment files are always report-only.
+pub fn check_integrity(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    fix: bool,
+    now_ms: i64,
+) -> Result<IntegrityReport> {
+    let mut report = IntegrityReport::default();
+    check_attachment_files(conn, app_dir, &mut report, fix)?;
+    check_dangling_message_attachments(conn, &mut report, fix)?;
+    check_stale_message_embeddings(conn, &mut report, fix)?;
+    check_todo_source_entries(conn, key, &mut report, fix)?;
+    check_empty_recurrence_series(conn, &mut report, fix)?;
+    check_stuck_running_jobs(conn, &mut report, fix, now_ms)?;
+    Ok(report)
+}
@@ -0,0 +1,332 @@
+pub const STORAGE_USAGE_DATABASE: &str = "database";
+pub const STORAGE_USAGE_DATABASE_WAL: &str = "database_wal";
+pub const STORAGE_USAGE_VECTOR_TABLE: &str = "vector_table";
+pub const STORAGE_USAGE_OPLOG: &str = "oplog";
+pub const STORAGE_USAGE_ATTACHMENT_ORIGINALS: &str = "attachment_originals";
+pub const STORAGE_USAGE_ATTACHMENT_VARIANTS: &str = "attachment_variants";
+pub const STORAGE_USAGE_VIDEO_PROXIES: &str = "video_proxies";
+pub const STORAGE_USAGE_EMBEDDING_MODELS: &str = "embedding_models";
+pub const STORAGE_USAGE_OCR_MODELS: &str = "ocr_models";
+pub const STORAGE_USAGE_WHISPER_MODELS: &str = "whisper_models";
+pub const STORAGE_USAGE_LLM_MODELS: &str = "llm_models";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageUsageEntry {
+    pub category: String,
+    pub label: String,
+    pub bytes: i64,
+    pub count: i64,
+}
+
+/// On-disk footprint of an app dir. `categories` add up to `total_bytes`
+/// (vector tables and the oplog are carved out of the database pages);
+/// `by_conversation` and `by_mime_type` are alternative views of attachment
+/// bytes and may overlap each other.
+#[derive(Clone, Debug, Default)]
+pub struct StorageUsageReport {
+    pub total_bytes: i64,
+    pub categories: Vec<StorageUsageEntry>,
+    pub by_conversation: Vec<StorageUsageEntry>,
+    pub by_mime_type: Vec<StorageUsageEntry>,
+}
+
+impl StorageUsageReport {
+    fn push(&mut self, category: &str, label: &str, bytes: i64, count: i64) {
+        self.total_bytes += bytes;
+        self.categories.push(StorageUsageEntry {
+            category: category.to_string(),
+            label: label.to_string(),
+            bytes,
+            count,
+        });
+    }
+}
+
+fn file_size_or_zero(path: &Path) -> i64 {
+    fs::metadata(path).map(|m| m.len() as i64).unwrap_or(0)
+}
+
+/// Recursively sums regular files under `dir`, skipping `exclude` (and
+/// anything below it). Missing directories count as empty.
+fn dir_usage(dir: &Path, exclude: Option<&Path>) -> (i64, i64) {
+    let mut bytes = 0i64;
+    let mut files = 0i64;
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        if exclude.is_some_and(|e| current == e) {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                stack.push(entry.path());
+            } else if file_type.is_file() {
+                bytes += entry.metadata().map(|m| m.len() as i64).unwrap_or(0);
+                files += 1;
+            }
+        }
+    }
+    (bytes, files)
+}
+
+/// Page bytes per table, with index pages attributed to their table.
+fn sqlite_table_page_bytes(conn: &Connection) -> Result<Vec<(String, i64)>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT m.tbl_name, SUM(s.pgsize)
+           FROM dbstat s
+           JOIN sqlite_master m ON m.name = s.name
+           GROUP BY m.tbl_name"#,
+    )?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn vec0_table_names(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT name FROM sqlite_master
+           WHERE type = 'table' AND sql LIKE '%USING vec0%'
+           ORDER BY name"#,
+    )?;
+    let rows = stmt.query_map([], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Sha256s of video proxy segments referenced by video manifests. The
+/// original recording (`video_sha256`) is not a proxy and stays an original.
+fn video_proxy_sha256s(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+) -> Result<BTreeSet<String>> {
+    let mut stmt = conn.prepare(r#"SELECT sha256 FROM attachments WHERE mime_type = ?1"#)?;
+    let manifests = stmt
+        .query_map([VIDEO_MANIFEST_MIME], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut out = BTreeSet::new();
+    for manifest_sha256 in manifests {
+        // Manifests that are not downloaded yet (or fail to parse) simply
+        // leave their segments counted as originals.
+        let Ok(bytes) = read_attachment_bytes(conn, key, app_dir, &manifest_sha256) else {
+            continue;
+        };
+        let Ok(manifest) = parse_video_manifest_payload(&bytes) else {
+            continue;
+        };
+        for segment in manifest.segments {
+            if segment.sha256 != manifest.video_sha256 {
+                out.insert(segment.sha256);
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn sort_usage_entries(entries: &mut [StorageUsageEntry]) {
+    entries.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.label.cmp(&b.label)));
+}
+
+pub fn storage_usage(conn: &Connection, key: &[u8; 32], app_dir: &Path) -> Result<StorageUsageReport> {
+    let mut report = StorageUsageReport::default();
+    // Database: sized by its pages rather than the file, which lags behind
+    // until the WAL is checkpointed. Vector tables and the oplog are reported
+    // on their own and subtracted so categories do not double count.
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    let db_bytes = page_count * page_size;
+    let db_file = db_path(app_dir);
+    let mut wal_bytes = 0i64;
+    for suffix in ["-wal", "-shm"] {
+        let mut name = db_file.as_os_str().to_owned();
+        name.push(suffix);
+        wal_bytes += file_size_or_zero(Path::new(&name));
+    }
+
+    let table_bytes = sqlite_table_page_bytes(conn)?;
+    let vec_tables = vec0_table_names(conn)?;
+    let mut vec_usage: Vec<(String, i64)> = vec_tables.iter().map(|t| (t.clone(), 0)).collect();
+    let mut oplog_bytes = 0i64;
+    for (table, bytes) in &table_bytes {
+        if table == "oplog" {
+            oplog_bytes += bytes;
+            continue;
+        }
+        // vec0 keeps its data in `{table}_*` shadow tables; per-space tables
+        // share the base prefix, so the longest matching owner wins.
+        let owner = vec_usage
+            .iter_mut()
+            .filter(|(name, _)| {
+                table == name
+                    || (table.len() > name.len()
+                        && table.starts_with(name.as_str())
+                        && table.as_bytes()[name.len()] == b'_')
+            })
+            .max_by_key(|(name, _)| name.len());
+        if let Some((_, total)) = owner {
+            *total += bytes;
+        }
+    }
+    let carved: i64 = oplog_bytes + vec_usage.iter().map(|(_, b)| *b).sum::<i64>();
+
+    report.push(
+        STORAGE_USAGE_DATABASE,
+        "secondloop.sqlite3",
+        (db_bytes - carved).max(0),
+        1,
+    );
+    report.push(STORAGE_USAGE_DATABASE_WAL, "wal", wal_bytes, 0);
+    for (table, bytes) in &vec_usage {
+        let rows: i64 = if is_safe_sqlite_ident(table) {
+            conn.query_row(&format!(r#"SELECT COUNT(*) FROM "{table}""#), [], |row| {
+                row.get(0)
+            })
+            .unwrap_or(0)
+        } else {
+            0
+        };
+        report.push(STORAGE_USAGE_VECTOR_TABLE, table, *bytes, rows);
+    }
+    let oplog_count: i64 = conn.query_row(r#"SELECT COUNT(*) FROM oplog"#, [], |row| row.get(0))?;
+    report.push(STORAGE_USAGE_OPLOG, "oplog", oplog_bytes, oplog_count);
+
+    // Attachments: sizes are the encrypted files actually on disk, so
+    // attachments that are not downloaded yet count as zero.
+    let proxies = video_proxy_sha256s(conn, key, app_dir)?;
+    let mut attachment_disk: std::collections::BTreeMap<String, (String, i64)> =
+        std::collections::BTreeMap::new();
+    {
+        let mut stmt = conn.prepare(r#"SELECT sha256, mime_type, path FROM attachments"#)?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let sha256: String = row.get(0)?;
+            let mime_type: String = row.get(1)?;
+            let rel_path: String = row.get(2)?;
+            let bytes = file_size_or_zero(&app_dir.join(rel_path));
+            attachment_disk.insert(sha256, (mime_type, bytes));
+        }
+    }
+    let (mut original_bytes, mut original_count) = (0i64, 0i64);
+    let (mut proxy_bytes, mut proxy_count) = (0i64, 0i64);
+    for (sha256, (_, bytes)) in &attachment_disk {
+        if proxies.contains(sha256) {
+            proxy_bytes += bytes;
+            proxy_count += 1;
+        } else {
+            original_bytes += bytes;
+            original_count += 1;
+        }
+    }
+    report.push(
+        STORAGE_USAGE_ATTACHMENT_ORIGINALS,
+        "attachments",
+        original_bytes,
+        original_count,
+    );
+    let (variant_bytes, variant_count) =
+        dir_usage(&app_dir.join("attachments").join("variants"), None);
+    report.push(
+        STORAGE_USAGE_ATTACHMENT_VARIANTS,
+        "attachments/variants",
+        variant_bytes,
+        variant_count,
+    );
+    report.push(
+        STORAGE_USAGE_VIDEO_PROXIES,
+        "video proxies",
+        proxy_bytes,
+        proxy_count,
+    );
+
+    // Local models. The embedding model shares onnxruntime with nothing else
+    // in the app dir, so the runtime is counted with it.
+    let (models_bytes, models_count) = dir_usage(&app_dir.join("models"), None);
+    let (ort_bytes, ort_count) = dir_usage(&app_dir.join("onnxruntime"), None);
+    report.push(
+        STORAGE_USAGE_EMBEDDING_MODELS,
+        "models",
+        models_bytes + ort_bytes,
+        models_count + ort_count,
+    );
+    let whisper_dir = app_dir
+        .join("ocr")
+        .join("desktop")
+        .join("runtime")
+        .join("whisper");
+    let (ocr_bytes, ocr_count) = dir_usage(&app_dir.join("ocr"), Some(&whisper_dir));
+    report.push(STORAGE_USAGE_OCR_MODELS, "ocr", ocr_bytes, ocr_count);
+    let (whisper_bytes, whisper_count) = dir_usage(&whisper_dir, None);
+    report.push(
+        STORAGE_USAGE_WHISPER_MODELS,
+        "whisper",
+        whisper_bytes,
+        whisper_count,
+    );
+    let llm_dir = crate::llm::local_gguf::local_gguf_models_dir(app_dir);
+    let (llm_bytes, llm_count) = dir_usage(&llm_dir, None);
+    report.push(STORAGE_USAGE_LLM_MODELS, "llm_models", llm_bytes, llm_count);
+
+    // Per conversation: attachments linked from its messages (an attachment
+    // shared by two conversations counts in both).
+    {
+        let mut stmt = conn.prepare(
+            r#"SELECT DISTINCT m.conversation_id, ma.attachment_sha256
+               FROM message_attachments ma
+               JOIN messages m ON m.id = ma.message_id"#,
+        )?;
+        let pairs = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        let mut per_conversation: std::collections::BTreeMap<String, (i64, i64)> =
+            std::collections::BTreeMap::new();
+        for (conversation_id, sha256) in pairs {
+            let bytes = attachment_disk.get(&sha256).map(|(_, b)| *b).unwrap_or(0);
+            let entry = per_conversation.entry(conversation_id).or_default();
+            entry.0 += bytes;
+            entry.1 += 1;
+        }
+        for (conversation_id, (bytes, count)) in per_conversation {
+            let title_blob: Option<Vec<u8>> = conn
+                .query_row(
+                    r#"SELECT title FROM conversations WHERE id = ?1"#,
+                    [&conversation_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let label = title_blob
+                .and_then(|blob| decrypt_bytes(key, &blob, b"conversation.title").ok())
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .unwrap_or_default();
+            report.by_conversation.push(StorageUsageEntry {
+                category: conversation_id,
+                label,
+                bytes,
+                count,
+            });
+        }
+    }
+
+    let mut per_mime: std::collections::BTreeMap<String, (i64, i64)> =
+        std::collections::BTreeMap::new();
+    for (mime_type, bytes) in attachment_disk.values() {
+        let entry = per_mime.entry(mime_type.clone()).or_default();
+        entry.0 += bytes;
+        entry.1 += 1;
+    }
+    for (mime_type, (bytes, count)) in per_mime {
+        report.by_mime_type.push(StorageUsageEntry {
+            category: mime_type.clone(),
+            label: mime_type,
+            bytes,
+            count,
+        });
+    }
+
+    sort_usage_entries(&mut report.by_conversation);
+    sort_usage_entries(&mut report.by_mime_type);
+    Ok(report)
+}
@@ -0,0 +1,267 @@
+const TAG_PATH_SEPARATOR: char = '/';
+
+fn read_tag_parent_id(conn: &Connection, tag_id: &str) -> Result<Option<String>> {
+    let parent_id: Option<Option<String>> = conn
+        .query_row(
+            r#"SELECT parent_id FROM tags WHERE id = ?1"#,
+            params![tag_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(parent_id.flatten())
+}
+
+fn list_child_tag_ids(conn: &Connection, parent_id: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id FROM tags
+           WHERE parent_id = ?1
+           ORDER BY id ASC"#,
+    )?;
+    let rows = stmt.query_map(params![parent_id], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<String>>>()?)
+}
+
+/// Walks up from `tag_id`. Concurrent moves on two devices can leave a cycle
+/// behind, so the walk stops at the first repeated id.
+fn is_tag_ancestor(conn: &Connection, ancestor_id: &str, tag_id: &str) -> Result<bool> {
+    let mut seen = BTreeSet::<String>::new();
+    let mut current = read_tag_parent_id(conn, tag_id)?;
+    while let Some(id) = current {
+        if id == ancestor_id {
+            return Ok(true);
+        }
+        if !seen.insert(id.clone()) {
+            break;
+        }
+        current = read_tag_parent_id(conn, &id)?;
+    }
+    Ok(false)
+}
+
+/// Adds every descendant of `tag_ids` (filtering by a parent tag matches its
+/// whole subtree). `UNION` keeps the recursion finite even with a cycle.
+fn expand_tag_ids_with_descendants(conn: &Connection, tag_ids: &[String]) -> Result<Vec<String>> {
+    if tag_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let placeholders = (0..tag_ids.len())
+        .map(|idx| format!("?{}", idx + 1))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!(
+        r#"WITH RECURSIVE subtree(id) AS (
+             SELECT id FROM tags WHERE parent_id IN ({placeholders})
+             UNION
+             SELECT t.id FROM tags t JOIN subtree s ON t.parent_id = s.id
+           )
+           SELECT id FROM subtree"#
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(rusqlite::params_from_iter(tag_ids.iter()))?;
+
+    let mut out: BTreeSet<String> = tag_ids.iter().cloned().collect();
+    while let Some(row) = rows.next()? {
+        out.insert(row.get(0)?);
+    }
+    Ok(out.into_iter().collect())
+}
+
+fn find_custom_tag_under_parent(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    parent_id: Option<&str>,
+    name: &str,
+) -> Result<Option<Tag>> {
+    let normalized_target = normalize_tag_name(name);
+    if normalized_target.is_empty() {
+        return Ok(None);
+    }
+
+    let mut stmt = conn.prepare(
+        r#"SELECT id, name
+           FROM tags
+           WHERE COALESCE(is_system, 0) = 0
+             AND parent_id IS ?1
+           ORDER BY updated_at_ms DESC, id DESC"#,
+    )?;
+    let mut rows = stmt.query(params![parent_id])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let name_blob: Vec<u8> = row.get(1)?;
+        let current_name = decrypt_tag_name(db_key, &id, &name_blob)?;
+        if normalize_tag_name(&current_name) == normalized_target {
+            return read_tag_by_id(conn, db_key, &id);
+        }
+    }
+    Ok(None)
+}
+
+/// Resolves a path such as `work/clients/acme`, creating missing segments.
+/// The first segment follows `upsert_tag` rules (so `work` is the system tag);
+/// nested segments are always custom tags scoped to their parent.
+pub fn upsert_tag_path(conn: &Connection, db_key: &[u8; 32], path: &str) -> Result<Tag> {
+    ensure_system_tags(conn, db_key)?;
+
+    let segments = path
+        .split(TAG_PATH_SEPARATOR)
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    let Some((first, rest)) = segments.split_first() else {
+        return Err(anyhow!("tag path cannot be empty"));
+    };
+
+    let mut current = match map_to_system_key(first) {
+        Some(system_key) => read_tag_by_system_key(conn, db_key, system_key)?
+            .ok_or_else(|| anyhow!("system tag not found: {system_key}"))?,
+        None => match find_custom_tag_under_parent(conn, db_key, None, first)? {
+            Some(tag) => tag,
+            None => insert_custom_tag(conn, db_key, first, None)?,
+        },
+    };
+    for segment in rest {
+        current = match find_custom_tag_under_parent(conn, db_key, Some(&current.id), segment)? {
+            Some(tag) => tag,
+            None => insert_custom_tag(conn, db_key, segment, Some(&current.id))?,
+        };
+    }
+    Ok(current)
+}
+
+/// Re-parents one tag (`None` makes it a root). Descendants point at the moved
+/// tag through their own `parent_id`, so a whole subtree moves with a single
+/// synced `tag.upsert.v2`.
+pub fn set_tag_parent(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    tag_id: &str,
+    parent_id: Option<&str>,
+) -> Result<Tag> {
+    ensure_system_tags(conn, db_key)?;
+
+    let tag_id = tag_id.trim();
+    let tag = read_tag_by_id(conn, db_key, tag_id)?
+        .ok_or_else(|| anyhow!("tag not found: {tag_id}"))?;
+    if tag.is_system {
+        return Err(anyhow!("system tags cannot be nested"));
+    }
+
+    let parent_id = parent_id.map(str::trim).filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id {
+        if parent_id == tag_id {
+            return Err(anyhow!("a tag cannot be its own parent"));
+        }
+        if read_tag_by_id(conn, db_key, parent_id)?.is_none() {
+            return Err(anyhow!("parent tag not found: {parent_id}"));
+        }
+        if is_tag_ancestor(conn, tag_id, parent_id)? {
+            return Err(anyhow!("cannot move a tag under its own descendant"));
+        }
+    }
+
+    if read_tag_parent_id(conn, tag_id)?.as_deref() == parent_id {
+        return Ok(tag);
+    }
+
+    let now = now_ms();
+    conn.execute(
+        r#"UPDATE tags SET parent_id = ?2, updated_at_ms = ?3 WHERE id = ?1"#,
+        params![tag_id, parent_id, now],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "tag.upsert.v2",
+        "payload": {
+            "tag_id": tag_id,
+            "name": tag.name,
+            "system_key": null,
+            "is_system": false,
+            "color": tag.color,
+            "parent_id": parent_id,
+            "created_at_ms": tag.created_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+
+    read_tag_by_id(conn, db_key, tag_id)?.ok_or_else(|| anyhow!("failed to read moved tag"))
+}
+
+/// All tags in depth-first order, roots in `list_tags` order and siblings by
+/// name. Tags whose parent is missing (or that sit in a cycle) show as roots.
+pub fn list_tag_tree(conn: &Connection, db_key: &[u8; 32]) -> Result<Vec<TagNode>> {
+    let tags = list_tags(conn, db_key)?;
+
+    let mut parent_by_id = std::collections::BTreeMap::<String, Option<String>>::new();
+    {
+        let mut stmt = conn.prepare(r#"SELECT id, parent_id FROM tags"#)?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            parent_by_id.insert(row.get(0)?, row.get(1)?);
+        }
+    }
+    let tag_by_id = tags
+        .iter()
+        .map(|tag| (tag.id.clone(), tag.clone()))
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    let mut children = std::collections::BTreeMap::<String, Vec<Tag>>::new();
+    let mut roots = Vec::<Tag>::new();
+    for tag in &tags {
+        match parent_by_id.get(&tag.id).cloned().flatten() {
+            Some(parent_id) if tag_by_id.contains_key(&parent_id) => {
+                children.entry(parent_id).or_default().push(tag.clone());
+            }
+            _ => roots.push(tag.clone()),
+        }
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by(|a, b| {
+            normalize_tag_name(&a.name)
+                .cmp(&normalize_tag_name(&b.name))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+    }
+
+    fn visit(
+        tag: &Tag,
+        parent: Option<&TagNode>,
+        children: &std::collections::BTreeMap<String, Vec<Tag>>,
+        visited: &mut BTreeSet<String>,
+        out: &mut Vec<TagNode>,
+    ) {
+        if !visited.insert(tag.id.clone()) {
+            return;
+        }
+        let node = TagNode {
+            tag: tag.clone(),
+            parent_id: parent.map(|p| p.tag.id.clone()),
+            path: match parent {
+                Some(p) => format!("{}{TAG_PATH_SEPARATOR}{}", p.path, tag.name),
+                None => tag.name.clone(),
+            },
+            depth: parent.map(|p| p.depth + 1).unwrap_or(0),
+        };
+        out.push(node.clone());
+        for child in children.get(&tag.id).into_iter().flatten() {
+            visit(child, Some(&node), children, visited, out);
+        }
+    }
+
+    let mut visited = BTreeSet::<String>::new();
+    let mut out = Vec::with_capacity(tags.len());
+    for root in &roots {
+        visit(root, None, &children, &mut visited, &mut out);
+    }
+    for tag in &tags {
+        visit(tag, None, &children, &mut visited, &mut out);
+    }
+    Ok(out)
+}
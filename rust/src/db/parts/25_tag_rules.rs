@@ -0,0 +1,465 @@
+pub const TAG_RULE_KIND_KEYWORD: &str = "keyword";
+pub const TAG_RULE_KIND_REGEX: &str = "regex";
+pub const TAG_RULE_KIND_ATTACHMENT_MIME: &str = "attachment_mime";
+pub const TAG_RULE_KIND_CONVERSATION: &str = "conversation";
+pub const TAG_RULE_KIND_PLACE: &str = "place";
+
+const TAG_RULE_KINDS: [&str; 5] = [
+    TAG_RULE_KIND_KEYWORD,
+    TAG_RULE_KIND_REGEX,
+    TAG_RULE_KIND_ATTACHMENT_MIME,
+    TAG_RULE_KIND_CONVERSATION,
+    TAG_RULE_KIND_PLACE,
+];
+
+/// A user-defined, deterministic tagging rule. `pattern` is interpreted per
+/// `kind`: a case-insensitive phrase, a regex, a mime type (`image/*` allowed),
+/// a conversation id, or a case-insensitive place name fragment.
+#[derive(Clone, Debug)]
+pub struct TagRule {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub pattern: String,
+    pub tag_id: String,
+    pub enabled: bool,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+}
+
+fn tag_rule_aad(rule_id: &str) -> String {
+    format!("tag_rule.rule:{rule_id}")
+}
+
+fn validate_tag_rule(kind: &str, pattern: &str) -> Result<()> {
+    if !TAG_RULE_KINDS.contains(&kind) {
+        return Err(anyhow!("unsupported tag rule kind: {kind}"));
+    }
+    if pattern.trim().is_empty() {
+        return Err(anyhow!("tag rule pattern cannot be empty"));
+    }
+    if kind == TAG_RULE_KIND_REGEX {
+        regex::Regex::new(pattern).map_err(|e| anyhow!("invalid tag rule regex: {e}"))?;
+    }
+    Ok(())
+}
+
+fn tag_rule_from_blob(
+    db_key: &[u8; 32],
+    id: String,
+    rule_blob: &[u8],
+    created_at_ms: i64,
+    updated_at_ms: i64,
+) -> Result<TagRule> {
+    let json = decrypt_bytes(db_key, rule_blob, tag_rule_aad(&id).as_bytes())?;
+    let value: serde_json::Value = serde_json::from_slice(&json)?;
+    let field = |name: &str| value[name].as_str().unwrap_or_default().to_string();
+    Ok(TagRule {
+        name: field("name"),
+        kind: field("kind"),
+        pattern: field("pattern"),
+        tag_id: field("tag_id"),
+        enabled: value["enabled"].as_bool().unwrap_or(true),
+        id,
+        created_at_ms,
+        updated_at_ms,
+    })
+}
+
+fn read_tag_rule_by_id(conn: &Connection, db_key: &[u8; 32], rule_id: &str) -> Result<Option<TagRule>> {
+    let row: Option<(Vec<u8>, i64, i64)> = conn
+        .query_row(
+            r#"SELECT rule, created_at_ms, updated_at_ms FROM tag_rules WHERE id = ?1"#,
+            params![rule_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let Some((rule_blob, created_at_ms, updated_at_ms)) = row else {
+        return Ok(None);
+    };
+    tag_rule_from_blob(db_key, rule_id.to_string(), &rule_blob, created_at_ms, updated_at_ms)
+        .map(Some)
+}
+
+pub fn list_tag_rules(conn: &Connection, db_key: &[u8; 32]) -> Result<Vec<TagRule>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, rule, created_at_ms, updated_at_ms
+           FROM tag_rules
+           ORDER BY created_at_ms ASC, id ASC"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let rule_blob: Vec<u8> = row.get(1)?;
+        out.push(tag_rule_from_blob(
+            db_key,
+            id,
+            &rule_blob,
+            row.get(2)?,
+            row.get(3)?,
+        )?);
+    }
+    Ok(out)
+}
+
+/// Creates (`rule_id` = None) or replaces a rule. The rule body is stored
+/// encrypted and synced via `tag_rule.upsert.v1`.
+#[allow(clippy::too_many_arguments)]
+pub fn upsert_tag_rule(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    rule_id: Option<&str>,
+    name: &str,
+    kind: &str,
+    pattern: &str,
+    tag_id: &str,
+    enabled: bool,
+) -> Result<TagRule> {
+    let kind = kind.trim();
+    validate_tag_rule(kind, pattern)?;
+    let tag_id = tag_id.trim();
+    if read_tag_by_id(conn, db_key, tag_id)?.is_none() {
+        return Err(anyhow!("tag not found: {tag_id}"));
+    }
+
+    let now = now_ms();
+    let id = rule_id
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let created_at_ms = read_tag_rule_by_id(conn, db_key, &id)?
+        .map(|existing| existing.created_at_ms)
+        .unwrap_or(now);
+
+    let name = name.trim();
+    let rule = serde_json::json!({
+        "name": name,
+        "kind": kind,
+        "pattern": pattern,
+        "tag_id": tag_id,
+        "enabled": enabled,
+    });
+    let rule_blob = encrypt_bytes(db_key, &serde_json::to_vec(&rule)?, tag_rule_aad(&id).as_bytes())?;
+    conn.execute(
+        r#"INSERT INTO tag_rules(id, rule, created_at_ms, updated_at_ms)
+           VALUES (?1, ?2, ?3, ?4)
+           ON CONFLICT(id) DO UPDATE SET
+             rule = excluded.rule,
+             updated_at_ms = excluded.updated_at_ms"#,
+        params![id, rule_blob, created_at_ms, now],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "tag_rule.upsert.v1",
+        "payload": {
+            "rule_id": id,
+            "name": name,
+            "kind": kind,
+            "pattern": pattern,
+            "tag_id": tag_id,
+            "enabled": enabled,
+            "created_at_ms": created_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+
+    read_tag_rule_by_id(conn, db_key, &id)?.ok_or_else(|| anyhow!("failed to read tag rule"))
+}
+
+pub fn delete_tag_rule(conn: &Connection, db_key: &[u8; 32], rule_id: &str) -> Result<()> {
+    let rule_id = rule_id.trim();
+    if rule_id.is_empty() {
+        return Err(anyhow!("rule_id cannot be empty"));
+    }
+
+    let deleted_at_ms = now_ms();
+    conn.execute(r#"DELETE FROM tag_rules WHERE id = ?1"#, params![rule_id])?;
+    kv_set_i64(conn, &format!("tag_rule.deleted_at:{rule_id}"), deleted_at_ms)?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": deleted_at_ms,
+        "type": "tag_rule.delete.v1",
+        "payload": {
+            "rule_id": rule_id,
+            "deleted_at_ms": deleted_at_ms,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+    Ok(())
+}
+
+fn tag_rule_mime_matches(pattern: &str, mime_type: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    let mime_type = mime_type.trim().to_ascii_lowercase();
+    match pattern.strip_suffix("/*") {
+        Some(prefix) => mime_type
+            .split_once('/')
+            .is_some_and(|(top, _)| top == prefix),
+        None => pattern == mime_type,
+    }
+}
+
+/// Inputs a rule can look at, loaded once per message and only as needed.
+struct TagRuleMessageFacts {
+    conversation_id: String,
+    content: String,
+    attachment_shas: Vec<String>,
+    mime_types: Option<Vec<String>>,
+    place_names: Option<Vec<String>>,
+}
+
+impl TagRuleMessageFacts {
+    fn mime_types(&mut self, conn: &Connection) -> Result<&[String]> {
+        if self.mime_types.is_none() {
+            let mut out = Vec::new();
+            for sha256 in &self.attachment_shas {
+                if let Ok(mime_type) = read_attachment_mime_type(conn, sha256) {
+                    out.push(mime_type);
+                }
+            }
+            self.mime_types = Some(out);
+        }
+        Ok(self.mime_types.as_deref().unwrap_or_default())
+    }
+
+    fn place_names(&mut self, conn: &Connection, db_key: &[u8; 32]) -> Result<&[String]> {
+        if self.place_names.is_none() {
+            let mut out = Vec::new();
+            for sha256 in &self.attachment_shas {
+                if let Some(name) = read_attachment_place_display_name(conn, db_key, sha256)? {
+                    out.push(name);
+                }
+            }
+            self.place_names = Some(out);
+        }
+        Ok(self.place_names.as_deref().unwrap_or_default())
+    }
+}
+
+/// An enabled rule with its regex compiled once per batch.
+struct PreparedTagRule {
+    rule: TagRule,
+    regex: Option<regex::Regex>,
+}
+
+fn prepare_tag_rules(rules: Vec<TagRule>) -> Vec<PreparedTagRule> {
+    rules
+        .into_iter()
+        .filter(|rule| rule.enabled)
+        .filter_map(|rule| {
+            let regex = if rule.kind == TAG_RULE_KIND_REGEX {
+                // Rules synced from elsewhere are not re-validated; skip bad ones.
+                Some(regex::Regex::new(&rule.pattern).ok()?)
+            } else {
+                None
+            };
+            Some(PreparedTagRule { rule, regex })
+        })
+        .collect()
+}
+
+fn tag_rule_matches(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    prepared: &PreparedTagRule,
+    facts: &mut TagRuleMessageFacts,
+) -> Result<bool> {
+    let rule = &prepared.rule;
+    let pattern = rule.pattern.as_str();
+    Ok(match rule.kind.as_str() {
+        TAG_RULE_KIND_KEYWORD => {
+            let needle = normalize_tag_name(pattern);
+            !needle.is_empty() && normalize_tag_name(&facts.content).contains(&needle)
+        }
+        TAG_RULE_KIND_REGEX => prepared
+            .regex
+            .as_ref()
+            .is_some_and(|re| re.is_match(&facts.content)),
+        TAG_RULE_KIND_ATTACHMENT_MIME => facts
+            .mime_types(conn)?
+            .iter()
+            .any(|mime_type| tag_rule_mime_matches(pattern, mime_type)),
+        TAG_RULE_KIND_CONVERSATION => facts.conversation_id == pattern.trim(),
+        TAG_RULE_KIND_PLACE => {
+            let needle = normalize_tag_name(pattern);
+            !needle.is_empty()
+                && facts
+                    .place_names(conn, db_key)?
+                    .iter()
+                    .any(|name| normalize_tag_name(name).contains(&needle))
+        }
+        _ => false,
+    })
+}
+
+fn apply_tag_rules_with(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    rules: &[PreparedTagRule],
+    message_id: &str,
+    now_ms: i64,
+) -> Result<u32> {
+    let Some(message) = get_message_by_id_optional(conn, db_key, message_id)? else {
+        return Ok(0);
+    };
+    if message.role != "user" {
+        return Ok(0);
+    }
+
+    let mut facts = TagRuleMessageFacts {
+        conversation_id: message.conversation_id.clone(),
+        content: message.content.clone(),
+        attachment_shas: list_message_attachment_sha256s(conn, message_id)?,
+        mime_types: None,
+        place_names: None,
+    };
+
+    let mut applied_count = 0u32;
+    for prepared in rules {
+        if !tag_rule_matches(conn, db_key, prepared, &mut facts)? {
+            continue;
+        }
+        let rule = &prepared.rule;
+        let Some(tag) = read_tag_by_id(conn, db_key, &rule.tag_id)? else {
+            continue;
+        };
+        if !add_tag_to_message(conn, db_key, message_id, &tag.id)? {
+            continue;
+        }
+        applied_count = applied_count.saturating_add(1);
+
+        let source = format!("rule:{}", rule.id);
+        let decision = MessageTagAutofillDecision {
+            candidate_tag: Some(normalize_tag_name(&tag.name)),
+            score: 1.0,
+            margin: 1.0,
+            source_count: 1,
+            decision: "rule_match",
+            evidence: serde_json::json!({
+                "sources": [source],
+                "rule_id": rule.id,
+                "rule_kind": rule.kind,
+                "tag_id": tag.id,
+            }),
+        };
+        write_message_tag_autofill_event(conn, message_id, &decision, true, now_ms)?;
+    }
+    Ok(applied_count)
+}
+
+fn list_message_attachment_sha256s(conn: &Connection, message_id: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT attachment_sha256 FROM message_attachments
+           WHERE message_id = ?1
+           ORDER BY attachment_sha256"#,
+    )?;
+    let rows = stmt.query_map(params![message_id], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<String>>>()?)
+}
+
+/// Evaluates all enabled rules against one message and adds matching tags.
+/// Returns how many tags were newly applied; each one is logged as a
+/// `rule:{id}` source in `message_tag_autofill_events`.
+pub fn apply_tag_rules_to_message(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    message_id: &str,
+    now_ms: i64,
+) -> Result<u32> {
+    let rules = prepare_tag_rules(list_tag_rules(conn, db_key)?);
+    if rules.is_empty() {
+        return Ok(0);
+    }
+    apply_tag_rules_with(conn, db_key, &rules, message_id, now_ms)
+}
+
+/// Runs rules (all enabled ones, or just `rule_id`) over existing user
+/// messages. Returns the number of tags applied.
+pub fn backfill_tag_rules(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    rule_id: Option<&str>,
+    now_ms: i64,
+) -> Result<u32> {
+    let mut rules = list_tag_rules(conn, db_key)?;
+    if let Some(rule_id) = rule_id.map(str::trim).filter(|id| !id.is_empty()) {
+        rules.retain(|rule| rule.id == rule_id);
+        if rules.is_empty() {
+            return Err(anyhow!("tag rule not found: {rule_id}"));
+        }
+    }
+    let rules = prepare_tag_rules(rules);
+    if rules.is_empty() {
+        return Ok(0);
+    }
+
+    let mut stmt = conn.prepare(
+        r#"SELECT id FROM messages
+           WHERE role = 'user' AND COALESCE(is_deleted, 0) = 0
+           ORDER BY created_at ASC, id ASC"#,
+    )?;
+    let message_ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut applied = 0u32;
+    for message_id in message_ids {
+        applied = applied.saturating_add(apply_tag_rules_with(
+            conn,
+            db_key,
+            &rules,
+            &message_id,
+            now_ms,
+        )?);
+    }
+    Ok(applied)
+}
+
+/// Re-evaluates rules for messages linked to an attachment whose derived data
+/// (e.g. its resolved place) just changed.
+fn apply_tag_rules_to_attachment_messages(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    attachment_sha256: &str,
+    now_ms: i64,
+) -> Result<u32> {
+    let rules = prepare_tag_rules(list_tag_rules(conn, db_key)?);
+    if rules.is_empty() {
+        return Ok(0);
+    }
+
+    let mut stmt = conn.prepare(
+        r#"SELECT message_id FROM message_attachments
+           WHERE attachment_sha256 = ?1
+           ORDER BY message_id"#,
+    )?;
+    let message_ids = stmt
+        .query_map(params![attachment_sha256], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut applied = 0u32;
+    for message_id in message_ids {
+        applied = applied.saturating_add(apply_tag_rules_with(
+            conn,
+            db_key,
+            &rules,
+            &message_id,
+            now_ms,
+        )?);
+    }
+    Ok(applied)
+}
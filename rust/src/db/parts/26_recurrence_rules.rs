@@ -0,0 +1,515 @@
+/// Upper bound on periods scanned when looking for the next occurrence, so a
+/// rule that can never match again (e.g. the 31st of February) ends the series
+/// instead of spinning forever.
+const RECURRENCE_MAX_PERIOD_SCAN: i64 = 10_000;
+const RECURRENCE_PREVIEW_MAX: u32 = 1_000;
+
+/// One `byday` entry: `TU` (every Tuesday), `2TU` (second Tuesday) or `-1FR`
+/// (last Friday) of the period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct RecurrenceByDay {
+    ordinal: Option<i32>,
+    weekday: time::Weekday,
+}
+
+fn parse_recurrence_weekday(raw: &str) -> Result<time::Weekday> {
+    match raw.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(time::Weekday::Monday),
+        "TU" => Ok(time::Weekday::Tuesday),
+        "WE" => Ok(time::Weekday::Wednesday),
+        "TH" => Ok(time::Weekday::Thursday),
+        "FR" => Ok(time::Weekday::Friday),
+        "SA" => Ok(time::Weekday::Saturday),
+        "SU" => Ok(time::Weekday::Sunday),
+        other => Err(anyhow!("invalid recurrence weekday: {other}")),
+    }
+}
+
+fn parse_recurrence_by_day(raw: &str) -> Result<RecurrenceByDay> {
+    let trimmed = raw.trim();
+    if trimmed.len() < 2 || !trimmed.is_ascii() {
+        return Err(anyhow!("invalid recurrence byday: {raw}"));
+    }
+    let (ordinal_raw, weekday_raw) = trimmed.split_at(trimmed.len() - 2);
+    let weekday = parse_recurrence_weekday(weekday_raw)?;
+    let ordinal = if ordinal_raw.is_empty() {
+        None
+    } else {
+        let ordinal: i32 = ordinal_raw
+            .trim_start_matches('+')
+            .parse()
+            .map_err(|_| anyhow!("invalid recurrence byday ordinal: {raw}"))?;
+        if ordinal == 0 || !(-53..=53).contains(&ordinal) {
+            return Err(anyhow!("invalid recurrence byday ordinal: {raw}"));
+        }
+        Some(ordinal)
+    };
+    Ok(RecurrenceByDay { ordinal, weekday })
+}
+
+fn parse_recurrence_int_list(value: &serde_json::Value, field: &str) -> Result<Vec<i64>> {
+    match value {
+        serde_json::Value::Null => Ok(Vec::new()),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_i64()
+                    .ok_or_else(|| anyhow!("recurrence {field} entries must be integers"))
+            })
+            .collect(),
+        _ => Err(anyhow!("recurrence {field} must be an array")),
+    }
+}
+
+fn recurrence_add_days(date: time::Date, days: i64) -> Result<time::Date> {
+    date.checked_add(time::Duration::days(days))
+        .ok_or_else(|| anyhow!("recurrence date overflow"))
+}
+
+fn recurrence_add_months(year: i32, month: time::Month, months: i64) -> Result<(i32, time::Month)> {
+    let total = i64::from(year)
+        .checked_mul(12)
+        .and_then(|v| v.checked_add(i64::from(month as u8) - 1))
+        .and_then(|v| v.checked_add(months))
+        .ok_or_else(|| anyhow!("recurrence month overflow"))?;
+    let new_year =
+        i32::try_from(total.div_euclid(12)).map_err(|_| anyhow!("recurrence year overflow"))?;
+    let new_month = time::Month::try_from((total.rem_euclid(12) + 1) as u8)
+        .map_err(|_| anyhow!("invalid month after recurrence add"))?;
+    Ok((new_year, new_month))
+}
+
+fn recurrence_month_matches(rule: &RecurrenceRule, month: time::Month) -> bool {
+    rule.by_month.is_empty() || rule.by_month.contains(&(month as u8))
+}
+
+/// Resolves a `bymonthday` entry (negative counts from the end of the month).
+fn resolve_recurrence_month_day(day: i32, days_in_month: u8) -> Option<u8> {
+    let dim = i32::from(days_in_month);
+    let resolved = if day > 0 { day } else { dim + 1 + day };
+    if (1..=dim).contains(&resolved) {
+        Some(resolved as u8)
+    } else {
+        None
+    }
+}
+
+/// Picks the `by_day` matches out of `dates` (one month or one year, in
+/// order); ordinals count within that span.
+fn recurrence_weekday_dates(by_day: &[RecurrenceByDay], dates: &[time::Date]) -> Vec<time::Date> {
+    let mut out = Vec::new();
+    for entry in by_day {
+        let matches = dates
+            .iter()
+            .copied()
+            .filter(|date| date.weekday() == entry.weekday)
+            .collect::<Vec<_>>();
+        match entry.ordinal {
+            None => out.extend(matches),
+            Some(n) => {
+                let index = if n > 0 {
+                    n as usize - 1
+                } else {
+                    match matches.len().checked_sub(n.unsigned_abs() as usize) {
+                        Some(index) => index,
+                        None => continue,
+                    }
+                };
+                if let Some(date) = matches.get(index) {
+                    out.push(*date);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn recurrence_month_dates(
+    rule: &RecurrenceRule,
+    year: i32,
+    month: time::Month,
+    default_day: u8,
+) -> Result<Vec<time::Date>> {
+    let dim = days_in_month(year, month);
+    let date = |day: u8| {
+        time::Date::from_calendar_date(year, month, day)
+            .map_err(|e| anyhow!("invalid recurrence date: {e}"))
+    };
+
+    if !rule.by_month_day.is_empty() {
+        let mut out = Vec::new();
+        for day in &rule.by_month_day {
+            let Some(day) = resolve_recurrence_month_day(*day, dim) else {
+                continue;
+            };
+            let candidate = date(day)?;
+            if rule.by_day.is_empty()
+                || rule
+                    .by_day
+                    .iter()
+                    .any(|entry| entry.weekday == candidate.weekday())
+            {
+                out.push(candidate);
+            }
+        }
+        return Ok(out);
+    }
+
+    if !rule.by_day.is_empty() {
+        let month_dates = (1..=dim).map(date).collect::<Result<Vec<_>>>()?;
+        return Ok(recurrence_weekday_dates(&rule.by_day, &month_dates));
+    }
+
+    // Plain monthly/yearly rules clamp to the month's last day (Jan 31 -> Feb 28).
+    Ok(vec![date(default_day.min(dim))?])
+}
+
+/// Candidate dates of the period `step` periods after the one containing
+/// `anchor`, in ascending order.
+fn recurrence_period_dates(
+    rule: &RecurrenceRule,
+    anchor: time::Date,
+    step: i64,
+) -> Result<Vec<time::Date>> {
+    let mut dates = match rule.freq.as_str() {
+        "daily" => {
+            let date = recurrence_add_days(anchor, step)?;
+            let month_day_ok = rule.by_month_day.is_empty()
+                || rule.by_month_day.iter().any(|day| {
+                    resolve_recurrence_month_day(*day, days_in_month(date.year(), date.month()))
+                        == Some(date.day())
+                });
+            let weekday_ok = rule.by_day.is_empty()
+                || rule
+                    .by_day
+                    .iter()
+                    .any(|entry| entry.weekday == date.weekday());
+            if recurrence_month_matches(rule, date.month()) && month_day_ok && weekday_ok {
+                vec![date]
+            } else {
+                Vec::new()
+            }
+        }
+        "weekly" => {
+            let since_week_start = (7 + i64::from(anchor.weekday().number_days_from_monday())
+                - i64::from(rule.week_start.number_days_from_monday()))
+                % 7;
+            let week_start = recurrence_add_days(
+                anchor,
+                step.checked_mul(7)
+                    .ok_or_else(|| anyhow!("weekly recurrence overflow"))?
+                    - since_week_start,
+            )?;
+            let mut out = Vec::with_capacity(7);
+            for offset in 0..7 {
+                let date = recurrence_add_days(week_start, offset)?;
+                let weekday_ok = if rule.by_day.is_empty() {
+                    date.weekday() == anchor.weekday()
+                } else {
+                    rule.by_day
+                        .iter()
+                        .any(|entry| entry.weekday == date.weekday())
+                };
+                if weekday_ok && recurrence_month_matches(rule, date.month()) {
+                    out.push(date);
+                }
+            }
+            out
+        }
+        "monthly" => {
+            let (year, month) = recurrence_add_months(anchor.year(), anchor.month(), step)?;
+            if recurrence_month_matches(rule, month) {
+                recurrence_month_dates(rule, year, month, anchor.day())?
+            } else {
+                Vec::new()
+            }
+        }
+        "yearly" => {
+            let year = i64::from(anchor.year())
+                .checked_add(step)
+                .and_then(|year| i32::try_from(year).ok())
+                .ok_or_else(|| anyhow!("yearly recurrence overflow"))?;
+            if rule.by_month.is_empty() && rule.by_month_day.is_empty() && !rule.by_day.is_empty() {
+                // `20MO` without `bymonth` counts weeks within the whole year.
+                let first = time::Date::from_calendar_date(year, time::Month::January, 1)
+                    .map_err(|e| anyhow!("invalid recurrence date: {e}"))?;
+                let days = if is_leap_year(year) { 366 } else { 365 };
+                let year_dates = (0..days)
+                    .map(|offset| recurrence_add_days(first, offset))
+                    .collect::<Result<Vec<_>>>()?;
+                recurrence_weekday_dates(&rule.by_day, &year_dates)
+            } else {
+                let months = if !rule.by_month.is_empty() {
+                    rule.by_month
+                        .iter()
+                        .map(|month| {
+                            time::Month::try_from(*month)
+                                .map_err(|_| anyhow!("invalid recurrence month"))
+                        })
+                        .collect::<Result<Vec<_>>>()?
+                } else if !rule.by_month_day.is_empty() {
+                    let mut months = Vec::with_capacity(12);
+                    let mut month = time::Month::January;
+                    for _ in 0..12 {
+                        months.push(month);
+                        month = month.next();
+                    }
+                    months
+                } else {
+                    vec![anchor.month()]
+                };
+                let mut out = Vec::new();
+                for month in months {
+                    out.extend(recurrence_month_dates(rule, year, month, anchor.day())?);
+                }
+                out
+            }
+        }
+        other => return Err(anyhow!("unsupported recurrence freq: {other}")),
+    };
+    dates.sort();
+    dates.dedup();
+    Ok(dates)
+}
+
+fn parse_timezone(name: &str) -> Result<&'static time_tz::Tz> {
+    time_tz::timezones::get_by_name(name.trim())
+        .ok_or_else(|| anyhow!("unknown timezone: {}", name.trim()))
+}
+
+/// Canonical IANA name for `name`, or `None` when this build does not know it.
+pub fn known_timezone_name(name: &str) -> Option<&'static str> {
+    use time_tz::TimeZone;
+
+    parse_timezone(name).ok().map(|tz| tz.name())
+}
+
+/// Local wall-clock time of `at_ms` in the named zone (UTC when `None`).
+pub fn timezone_wall_clock(at_ms: i64, tz: Option<&str>) -> Result<time::PrimitiveDateTime> {
+    let tz = tz.map(parse_timezone).transpose()?;
+    recurrence_wall_clock(at_ms, tz)
+}
+
+/// Instant of a local wall-clock time in the named zone (UTC when `None`),
+/// resolving DST overlaps and gaps like recurrence does.
+pub fn timezone_instant_ms(wall_clock: time::PrimitiveDateTime, tz: Option<&str>) -> Result<i64> {
+    let tz = tz.map(parse_timezone).transpose()?;
+    Ok(recurrence_instant_ms(wall_clock, tz))
+}
+
+/// Local wall-clock time of `at_ms` in `tz` (UTC when `None`).
+fn recurrence_wall_clock(at_ms: i64, tz: Option<&time_tz::Tz>) -> Result<time::PrimitiveDateTime> {
+    use time_tz::OffsetDateTimeExt;
+
+    let dt = time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(at_ms) * 1_000_000)
+        .map_err(|e| anyhow!("invalid recurrence timestamp: {e}"))?;
+    let local = match tz {
+        Some(tz) => dt.to_timezone(tz),
+        None => dt,
+    };
+    Ok(time::PrimitiveDateTime::new(local.date(), local.time()))
+}
+
+/// Instant of a local wall-clock time, resolved like RFC 5545 does: a time
+/// repeated by a DST fall-back uses its first occurrence, and a time skipped
+/// by a spring-forward gap keeps the pre-gap offset (02:30 becomes 03:30).
+fn recurrence_instant_ms(wall_clock: time::PrimitiveDateTime, tz: Option<&time_tz::Tz>) -> i64 {
+    use time_tz::{Offset, OffsetResult, PrimitiveDateTimeExt, TimeZone};
+
+    let dt = match tz {
+        None => wall_clock.assume_utc(),
+        Some(tz) => match wall_clock.assume_timezone(tz) {
+            OffsetResult::Some(dt) => dt,
+            OffsetResult::Ambiguous(first, second) => first.min(second),
+            OffsetResult::None => {
+                let before_gap =
+                    tz.get_offset_utc(&(wall_clock.assume_utc() - time::Duration::days(1)));
+                wall_clock.assume_offset(before_gap.to_utc())
+            }
+        },
+    };
+    (dt.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// Moves `at_ms` by the wall-clock distance between `from_ms` and `to_ms` in
+/// `tz`, so shifting a series keeps every occurrence at the same local time
+/// even when a DST change falls in between.
+fn shift_recurrence_instant_ms(
+    at_ms: i64,
+    from_ms: i64,
+    to_ms: i64,
+    tz: Option<&time_tz::Tz>,
+) -> Result<i64> {
+    let Some(tz) = tz else {
+        return to_ms
+            .checked_sub(from_ms)
+            .and_then(|delta| at_ms.checked_add(delta))
+            .ok_or_else(|| anyhow!("recurrence shift overflow"));
+    };
+    let delta = recurrence_wall_clock(to_ms, Some(tz))? - recurrence_wall_clock(from_ms, Some(tz))?;
+    let shifted = recurrence_wall_clock(at_ms, Some(tz))?
+        .checked_add(delta)
+        .ok_or_else(|| anyhow!("recurrence shift overflow"))?;
+    Ok(recurrence_instant_ms(shifted, Some(tz)))
+}
+
+/// First occurrence strictly after `after_ms`. Periods are counted from the
+/// one containing `anchor_ms`, which also supplies the time of day and the
+/// default weekday/day of month, all in the rule's local time. `None` means
+/// the series has ended.
+fn next_recurrence_occurrence_ms(
+    rule: &RecurrenceRule,
+    anchor_ms: i64,
+    after_ms: i64,
+) -> Result<Option<i64>> {
+    let anchor = recurrence_wall_clock(anchor_ms, rule.tz)?;
+    for period in 0..RECURRENCE_MAX_PERIOD_SCAN {
+        let step = period
+            .checked_mul(rule.interval)
+            .ok_or_else(|| anyhow!("recurrence interval overflow"))?;
+        for date in recurrence_period_dates(rule, anchor.date(), step)? {
+            let at_ms =
+                recurrence_instant_ms(time::PrimitiveDateTime::new(date, anchor.time()), rule.tz);
+            if at_ms <= after_ms {
+                continue;
+            }
+            if rule.until_ms.is_some_and(|until_ms| at_ms > until_ms) {
+                return Ok(None);
+            }
+            if rule.exdates_ms.contains(&at_ms) {
+                continue;
+            }
+            return Ok(Some(at_ms));
+        }
+    }
+    Ok(None)
+}
+
+/// Occurrences of the series anchored at `anchor_ms` that start in
+/// `[from_ms, to_ms)`, ascending. Unlike todo spawning, which steps from one
+/// occurrence to the next, every occurrence is measured from the anchor as in
+/// RFC 5545: the anchor is occurrence 0 even if the rule would not produce it,
+/// `count` includes it, and `exdates_ms` drop occurrences without giving their
+/// slot back.
+fn recurrence_occurrences_in_range_ms(
+    rule: &RecurrenceRule,
+    anchor_ms: i64,
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<Vec<i64>> {
+    let mut out = Vec::new();
+    if anchor_ms >= to_ms {
+        return Ok(out);
+    }
+    let keep = |at_ms: i64| at_ms >= from_ms && !rule.exdates_ms.contains(&at_ms);
+    if keep(anchor_ms) {
+        out.push(anchor_ms);
+    }
+
+    let anchor = recurrence_wall_clock(anchor_ms, rule.tz)?;
+    let mut produced = 1i64;
+    for period in 0..RECURRENCE_MAX_PERIOD_SCAN {
+        let step = period
+            .checked_mul(rule.interval)
+            .ok_or_else(|| anyhow!("recurrence interval overflow"))?;
+        // Running off the calendar ends the series, like the period cap does.
+        let Ok(dates) = recurrence_period_dates(rule, anchor.date(), step) else {
+            break;
+        };
+        for date in dates {
+            let at_ms =
+                recurrence_instant_ms(time::PrimitiveDateTime::new(date, anchor.time()), rule.tz);
+            if at_ms <= anchor_ms {
+                continue;
+            }
+            if at_ms >= to_ms
+                || rule.until_ms.is_some_and(|until_ms| at_ms > until_ms)
+                || rule.count.is_some_and(|count| produced >= count)
+            {
+                return Ok(out);
+            }
+            produced += 1;
+            if keep(at_ms) {
+                out.push(at_ms);
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub fn validate_recurrence_rule(rule_json: &str) -> Result<()> {
+    parse_recurrence_rule(rule_json).map(|_| ())
+}
+
+/// Previews up to `limit` occurrences of a series exactly as completing each
+/// todo would spawn them: `start_at_ms` is occurrence 0, and each following
+/// occurrence is computed from the previous one in the rule's `tz` (UTC when
+/// the rule has none).
+pub fn preview_recurrence_occurrences(
+    rule_json: &str,
+    start_at_ms: i64,
+    limit: u32,
+) -> Result<Vec<i64>> {
+    let rule = parse_recurrence_rule(rule_json)?;
+    let limit = limit.min(RECURRENCE_PREVIEW_MAX) as usize;
+
+    let mut out = Vec::with_capacity(limit);
+    if limit == 0 {
+        return Ok(out);
+    }
+    out.push(start_at_ms);
+    let mut current = start_at_ms;
+    while out.len() < limit {
+        if rule.count.is_some_and(|count| out.len() as i64 >= count) {
+            break;
+        }
+        match next_recurrence_occurrence_ms(&rule, current, current)? {
+            Some(next) => {
+                out.push(next);
+                current = next;
+            }
+            None => break,
+        }
+    }
+    Ok(out)
+}
+
+/// Adjusts a rule carried over to a split or shifted series: `count` shrinks by
+/// the occurrences left behind, and `exdates_ms` move with the due times when
+/// `due_shift` (from, to) is given. Returns `rule_json` unchanged when neither
+/// applies.
+fn rebase_recurrence_rule_json(
+    rule_json: &str,
+    occurrence_offset: i64,
+    due_shift: Option<(i64, i64)>,
+    tz: Option<&time_tz::Tz>,
+) -> Result<String> {
+    let rule = parse_recurrence_rule(rule_json)?;
+    let mut value: serde_json::Value = serde_json::from_str(rule_json)?;
+    let mut changed = false;
+
+    if let Some(count) = rule.count {
+        if occurrence_offset > 0 {
+            value["count"] = serde_json::json!(count.saturating_sub(occurrence_offset).max(1));
+            changed = true;
+        }
+    }
+    if let Some((from_ms, to_ms)) = due_shift.filter(|(from_ms, to_ms)| from_ms != to_ms) {
+        if !rule.exdates_ms.is_empty() {
+            let tz = rule.tz.or(tz);
+            let shifted = rule
+                .exdates_ms
+                .iter()
+                .map(|at_ms| shift_recurrence_instant_ms(*at_ms, from_ms, to_ms, tz))
+                .collect::<Result<Vec<_>>>()?;
+            value["exdates_ms"] = serde_json::json!(shifted);
+            changed = true;
+        }
+    }
+
+    if changed {
+        Ok(value.to_string())
+    } else {
+        Ok(rule_json.to_string())
+    }
+}
@@ -0,0 +1,119 @@
+/// Link between a local event or todo and a calendar object on a CalDAV
+/// server, as of the last sync. Device-local; never synced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalDavItem {
+    pub collection_id: String,
+    pub href: String,
+    /// `event` or `todo`.
+    pub item_kind: String,
+    pub item_id: String,
+    pub uid: String,
+    pub etag: Option<String>,
+    /// Hash of the item's iCalendar rendering when it was last synced.
+    pub local_sha256: String,
+    /// The server object was deleted and the local todo dismissed; the row
+    /// stays so the todo is not uploaded again unless it changes.
+    pub remote_deleted: bool,
+    pub updated_at_ms: i64,
+}
+
+pub fn list_caldav_items(conn: &Connection, collection_id: &str) -> Result<Vec<CalDavItem>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT href, item_kind, item_id, uid, etag, local_sha256, remote_deleted, updated_at_ms
+           FROM caldav_items
+           WHERE collection_id = ?1
+           ORDER BY href ASC"#,
+    )?;
+    let mut rows = stmt.query(params![collection_id])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let remote_deleted: i64 = row.get(6)?;
+        out.push(CalDavItem {
+            collection_id: collection_id.to_string(),
+            href: row.get(0)?,
+            item_kind: row.get(1)?,
+            item_id: row.get(2)?,
+            uid: row.get(3)?,
+            etag: row.get(4)?,
+            local_sha256: row.get(5)?,
+            remote_deleted: remote_deleted != 0,
+            updated_at_ms: row.get(7)?,
+        });
+    }
+    Ok(out)
+}
+
+/// Stores `item`, replacing any row for the same href or the same local item.
+pub fn upsert_caldav_item(conn: &Connection, item: &CalDavItem) -> Result<()> {
+    conn.execute(
+        r#"DELETE FROM caldav_items
+           WHERE collection_id = ?1 AND item_kind = ?2 AND item_id = ?3 AND href != ?4"#,
+        params![item.collection_id, item.item_kind, item.item_id, item.href],
+    )?;
+    conn.execute(
+        r#"
+INSERT INTO caldav_items(
+  collection_id, href, item_kind, item_id, uid, etag, local_sha256, remote_deleted, updated_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+ON CONFLICT(collection_id, href) DO UPDATE SET
+  item_kind = excluded.item_kind,
+  item_id = excluded.item_id,
+  uid = excluded.uid,
+  etag = excluded.etag,
+  local_sha256 = excluded.local_sha256,
+  remote_deleted = excluded.remote_deleted,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![
+            item.collection_id,
+            item.href,
+            item.item_kind,
+            item.item_id,
+            item.uid,
+            item.etag,
+            item.local_sha256,
+            i64::from(item.remote_deleted),
+            item.updated_at_ms
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn delete_caldav_item(conn: &Connection, collection_id: &str, href: &str) -> Result<()> {
+    conn.execute(
+        r#"DELETE FROM caldav_items WHERE collection_id = ?1 AND href = ?2"#,
+        params![collection_id, href],
+    )?;
+    Ok(())
+}
+
+pub fn get_caldav_collection_ctag(conn: &Connection, collection_id: &str) -> Result<Option<String>> {
+    Ok(conn
+        .query_row(
+            r#"SELECT ctag FROM caldav_collections WHERE collection_id = ?1"#,
+            params![collection_id],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .optional()?
+        .flatten())
+}
+
+pub fn set_caldav_collection_ctag(
+    conn: &Connection,
+    collection_id: &str,
+    ctag: Option<&str>,
+    now_ms: i64,
+) -> Result<()> {
+    conn.execute(
+        r#"
+INSERT INTO caldav_collections(collection_id, ctag, updated_at_ms)
+VALUES (?1, ?2, ?3)
+ON CONFLICT(collection_id) DO UPDATE SET
+  ctag = excluded.ctag,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![collection_id, ctag, now_ms],
+    )?;
+    Ok(())
+}
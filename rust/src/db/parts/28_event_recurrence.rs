@@ -0,0 +1,459 @@
+pub const EVENT_EXCEPTION_CANCELLED: &str = "cancelled";
+pub const EVENT_EXCEPTION_MOVED: &str = "moved";
+/// Undoes an earlier cancel or move; kept as a row so the undo syncs.
+pub const EVENT_EXCEPTION_RESTORED: &str = "restored";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventRecurrence {
+    pub event_id: String,
+    /// `None` once the rule was cleared.
+    pub rule_json: Option<String>,
+    pub updated_at_ms: i64,
+}
+
+/// Override of one occurrence of a recurring event, keyed by the start the
+/// rule gives that occurrence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventException {
+    pub event_id: String,
+    pub occurrence_start_ms: i64,
+    /// `cancelled`, `moved` or `restored`.
+    pub kind: String,
+    /// New title of a moved occurrence; `None` keeps the series title.
+    pub title: Option<String>,
+    pub start_at_ms: Option<i64>,
+    pub end_at_ms: Option<i64>,
+    pub updated_at_ms: i64,
+}
+
+/// One event instance in a calendar range: a plain event, or one occurrence
+/// of a recurring event with its exception applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventOccurrence {
+    pub event_id: String,
+    /// Start the rule gives this occurrence, which identifies it even after it
+    /// was moved. Equals `start_at_ms` for plain events.
+    pub occurrence_start_ms: i64,
+    pub title: String,
+    pub start_at_ms: i64,
+    pub end_at_ms: i64,
+    pub tz: String,
+    pub is_recurring: bool,
+    pub is_moved: bool,
+}
+
+/// Parses an event rule; rules without their own `tz` run in the event's zone
+/// (UTC when the event's zone is unknown).
+fn event_recurrence_rule(rule_json: &str, event_tz: &str) -> Result<RecurrenceRule> {
+    let mut rule = parse_recurrence_rule(rule_json)?;
+    rule.tz = rule.tz.or_else(|| parse_timezone(event_tz).ok());
+    Ok(rule)
+}
+
+pub fn get_event_recurrence(conn: &Connection, event_id: &str) -> Result<Option<EventRecurrence>> {
+    Ok(conn
+        .query_row(
+            r#"SELECT rule_json, updated_at_ms FROM event_recurrences WHERE event_id = ?1"#,
+            params![event_id],
+            |row| {
+                Ok(EventRecurrence {
+                    event_id: event_id.to_string(),
+                    rule_json: row.get(0)?,
+                    updated_at_ms: row.get(1)?,
+                })
+            },
+        )
+        .optional()?)
+}
+
+pub fn get_event_recurrence_rule_json(conn: &Connection, event_id: &str) -> Result<Option<String>> {
+    Ok(get_event_recurrence(conn, event_id)?.and_then(|recurrence| recurrence.rule_json))
+}
+
+/// Makes `event_id` recur by `rule_json` (the `todo_series` rule format),
+/// anchored on the event's start, or stops it recurring when `None`.
+pub fn set_event_recurrence_rule(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+    rule_json: Option<&str>,
+) -> Result<()> {
+    let event = get_event_by_id(conn, key, event_id)?;
+    if let Some(rule_json) = rule_json {
+        event_recurrence_rule(rule_json, &event.tz)?;
+    }
+
+    let now = now_ms();
+    conn.execute(
+        r#"
+INSERT INTO event_recurrences(event_id, rule_json, updated_at_ms)
+VALUES (?1, ?2, ?3)
+ON CONFLICT(event_id) DO UPDATE SET
+  rule_json = excluded.rule_json,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![event_id, rule_json, now],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "event.recurrence.set.v1",
+        "payload": {
+            "event_id": event_id,
+            "rule_json": rule_json,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, key, &op)?;
+    Ok(())
+}
+
+pub fn list_event_exceptions(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+) -> Result<Vec<EventException>> {
+    let mut stmt = conn.prepare(
+        r#"
+SELECT occurrence_start_ms, kind, title, start_at_ms, end_at_ms, updated_at_ms
+FROM event_exceptions
+WHERE event_id = ?1
+ORDER BY occurrence_start_ms ASC
+"#,
+    )?;
+    let mut rows = stmt.query(params![event_id])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let title_blob: Option<Vec<u8>> = row.get(2)?;
+        let title = match title_blob {
+            Some(blob) => {
+                let bytes = decrypt_bytes(key, &blob, b"event.exception.title")?;
+                Some(
+                    String::from_utf8(bytes)
+                        .map_err(|_| anyhow!("event exception title is not valid utf-8"))?,
+                )
+            }
+            None => None,
+        };
+        out.push(EventException {
+            event_id: event_id.to_string(),
+            occurrence_start_ms: row.get(0)?,
+            kind: row.get(1)?,
+            title,
+            start_at_ms: row.get(3)?,
+            end_at_ms: row.get(4)?,
+            updated_at_ms: row.get(5)?,
+        });
+    }
+    Ok(out)
+}
+
+/// Whether the series of `event` produces an occurrence at
+/// `occurrence_start_ms`.
+fn is_event_occurrence(event: &Event, rule_json: &str, occurrence_start_ms: i64) -> Result<bool> {
+    let rule = event_recurrence_rule(rule_json, &event.tz)?;
+    Ok(!recurrence_occurrences_in_range_ms(
+        &rule,
+        event.start_at_ms,
+        occurrence_start_ms,
+        occurrence_start_ms.saturating_add(1),
+    )?
+    .is_empty())
+}
+
+fn write_event_exception(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+    occurrence_start_ms: i64,
+    kind: &str,
+    moved_to: Option<(i64, i64, Option<&str>)>,
+) -> Result<()> {
+    let event = get_event_by_id(conn, key, event_id)?;
+    let rule_json = get_event_recurrence_rule_json(conn, event_id)?
+        .ok_or_else(|| anyhow!("event {event_id} does not recur"))?;
+    // Restoring is always allowed, so exceptions orphaned by a rule change
+    // can be cleared.
+    if kind != EVENT_EXCEPTION_RESTORED
+        && !is_event_occurrence(&event, &rule_json, occurrence_start_ms)?
+    {
+        return Err(anyhow!(
+            "event {event_id} has no occurrence at {occurrence_start_ms}"
+        ));
+    }
+    let (start_at_ms, end_at_ms, title) = match moved_to {
+        Some((start_at_ms, end_at_ms, title)) => (Some(start_at_ms), Some(end_at_ms), title),
+        None => (None, None, None),
+    };
+    let title_blob = title
+        .map(|title| encrypt_bytes(key, title.as_bytes(), b"event.exception.title"))
+        .transpose()?;
+
+    let now = now_ms();
+    conn.execute(
+        r#"
+INSERT INTO event_exceptions(
+  event_id, occurrence_start_ms, kind, title, start_at_ms, end_at_ms, updated_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+ON CONFLICT(event_id, occurrence_start_ms) DO UPDATE SET
+  kind = excluded.kind,
+  title = excluded.title,
+  start_at_ms = excluded.start_at_ms,
+  end_at_ms = excluded.end_at_ms,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![
+            event_id,
+            occurrence_start_ms,
+            kind,
+            title_blob,
+            start_at_ms,
+            end_at_ms,
+            now
+        ],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "event.exception.upsert.v1",
+        "payload": {
+            "event_id": event_id,
+            "occurrence_start_ms": occurrence_start_ms,
+            "kind": kind,
+            "title": title,
+            "start_at_ms": start_at_ms,
+            "end_at_ms": end_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, key, &op)?;
+    Ok(())
+}
+
+pub fn cancel_event_occurrence(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+    occurrence_start_ms: i64,
+) -> Result<()> {
+    write_event_exception(
+        conn,
+        key,
+        event_id,
+        occurrence_start_ms,
+        EVENT_EXCEPTION_CANCELLED,
+        None,
+    )
+}
+
+/// Moves one occurrence to `start_at_ms..end_at_ms`, optionally renaming it.
+pub fn move_event_occurrence(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+    occurrence_start_ms: i64,
+    start_at_ms: i64,
+    end_at_ms: i64,
+    title: Option<&str>,
+) -> Result<()> {
+    if end_at_ms < start_at_ms {
+        return Err(anyhow!("event occurrence ends before it starts"));
+    }
+    write_event_exception(
+        conn,
+        key,
+        event_id,
+        occurrence_start_ms,
+        EVENT_EXCEPTION_MOVED,
+        Some((start_at_ms, end_at_ms, title)),
+    )
+}
+
+/// Puts a cancelled or moved occurrence back as the series defines it.
+pub fn restore_event_occurrence(
+    conn: &Connection,
+    key: &[u8; 32],
+    event_id: &str,
+    occurrence_start_ms: i64,
+) -> Result<()> {
+    write_event_exception(
+        conn,
+        key,
+        event_id,
+        occurrence_start_ms,
+        EVENT_EXCEPTION_RESTORED,
+        None,
+    )
+}
+
+/// Events overlapping `[start_at_ms_inclusive, end_at_ms_exclusive)`, with
+/// recurring events expanded into their occurrences. Each entry pairs the
+/// stored event with the instance that falls in the range; ordered by start,
+/// then end.
+fn expand_events_in_range(
+    conn: &Connection,
+    key: &[u8; 32],
+    start_at_ms_inclusive: i64,
+    end_at_ms_exclusive: i64,
+) -> Result<Vec<(Event, EventOccurrence)>> {
+    let mut stmt = conn.prepare(
+        r#"
+SELECT e.id, e.title, e.start_at_ms, e.end_at_ms, e.tz, e.source_entry_id,
+       e.created_at_ms, e.updated_at_ms, r.rule_json
+FROM events e
+LEFT JOIN event_recurrences r ON r.event_id = e.id
+WHERE (r.rule_json IS NULL AND e.start_at_ms < ?2 AND e.end_at_ms > ?1)
+   OR (r.rule_json IS NOT NULL AND (
+         e.start_at_ms < ?2
+         OR EXISTS (
+           SELECT 1 FROM event_exceptions x
+           WHERE x.event_id = e.id AND x.kind = 'moved'
+             AND x.start_at_ms < ?2 AND x.end_at_ms > ?1
+         )
+       ))
+"#,
+    )?;
+
+    let mut rows = stmt.query(params![start_at_ms_inclusive, end_at_ms_exclusive])?;
+    let mut masters = Vec::new();
+    while let Some(row) = rows.next()? {
+        let title_blob: Vec<u8> = row.get(1)?;
+        let title_bytes = decrypt_bytes(key, &title_blob, b"event.title")?;
+        let title = String::from_utf8(title_bytes)
+            .map_err(|_| anyhow!("event title is not valid utf-8"))?;
+        let event = Event {
+            id: row.get(0)?,
+            title,
+            start_at_ms: row.get(2)?,
+            end_at_ms: row.get(3)?,
+            tz: row.get(4)?,
+            source_entry_id: row.get(5)?,
+            created_at_ms: row.get(6)?,
+            updated_at_ms: row.get(7)?,
+        };
+        let rule_json: Option<String> = row.get(8)?;
+        masters.push((event, rule_json));
+    }
+
+    let overlaps = |start_at_ms: i64, end_at_ms: i64| {
+        start_at_ms < end_at_ms_exclusive && end_at_ms > start_at_ms_inclusive
+    };
+    let mut out = Vec::new();
+    for (event, rule_json) in masters {
+        let Some(rule_json) = rule_json else {
+            let occurrence = EventOccurrence {
+                event_id: event.id.clone(),
+                occurrence_start_ms: event.start_at_ms,
+                title: event.title.clone(),
+                start_at_ms: event.start_at_ms,
+                end_at_ms: event.end_at_ms,
+                tz: event.tz.clone(),
+                is_recurring: false,
+                is_moved: false,
+            };
+            out.push((event, occurrence));
+            continue;
+        };
+
+        let rule = event_recurrence_rule(&rule_json, &event.tz)?;
+        let duration_ms = event.end_at_ms.saturating_sub(event.start_at_ms);
+        let exceptions = list_event_exceptions(conn, key, &event.id)?
+            .into_iter()
+            .map(|exception| (exception.occurrence_start_ms, exception))
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let occurrence = |occurrence_start_ms: i64,
+                          start_at_ms: i64,
+                          end_at_ms: i64,
+                          title: &str,
+                          is_moved: bool| EventOccurrence {
+            event_id: event.id.clone(),
+            occurrence_start_ms,
+            title: title.to_string(),
+            start_at_ms,
+            end_at_ms,
+            tz: event.tz.clone(),
+            is_recurring: true,
+            is_moved,
+        };
+
+        let mut instances = Vec::new();
+        for start_at_ms in recurrence_occurrences_in_range_ms(
+            &rule,
+            event.start_at_ms,
+            start_at_ms_inclusive
+                .saturating_sub(duration_ms)
+                .saturating_add(1),
+            end_at_ms_exclusive,
+        )? {
+            let overridden = exceptions.get(&start_at_ms).is_some_and(|exception| {
+                exception.kind == EVENT_EXCEPTION_CANCELLED
+                    || exception.kind == EVENT_EXCEPTION_MOVED
+            });
+            if !overridden {
+                instances.push(occurrence(
+                    start_at_ms,
+                    start_at_ms,
+                    start_at_ms.saturating_add(duration_ms),
+                    &event.title,
+                    false,
+                ));
+            }
+        }
+        for exception in exceptions.values() {
+            let (Some(start_at_ms), Some(end_at_ms)) = (exception.start_at_ms, exception.end_at_ms)
+            else {
+                continue;
+            };
+            if exception.kind != EVENT_EXCEPTION_MOVED
+                || !overlaps(start_at_ms, end_at_ms)
+                || !is_event_occurrence(&event, &rule_json, exception.occurrence_start_ms)?
+            {
+                continue;
+            }
+            instances.push(occurrence(
+                exception.occurrence_start_ms,
+                start_at_ms,
+                end_at_ms,
+                exception.title.as_deref().unwrap_or(&event.title),
+                true,
+            ));
+        }
+        out.extend(
+            instances
+                .into_iter()
+                .map(|instance| (event.clone(), instance)),
+        );
+    }
+
+    out.sort_by(|(_, a), (_, b)| {
+        (a.start_at_ms, a.end_at_ms, &a.event_id).cmp(&(b.start_at_ms, b.end_at_ms, &b.event_id))
+    });
+    Ok(out)
+}
+
+/// Event instances overlapping the range, with recurring events expanded and
+/// their exceptions applied.
+pub fn list_event_occurrences_in_range(
+    conn: &Connection,
+    key: &[u8; 32],
+    start_at_ms_inclusive: i64,
+    end_at_ms_exclusive: i64,
+) -> Result<Vec<EventOccurrence>> {
+    Ok(
+        expand_events_in_range(conn, key, start_at_ms_inclusive, end_at_ms_exclusive)?
+            .into_iter()
+            .map(|(_, occurrence)| occurrence)
+            .collect(),
+    )
+}
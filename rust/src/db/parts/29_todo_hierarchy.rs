@@ -0,0 +1,389 @@
+/// Subtask and "blocked by" summary for one todo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TodoRollup {
+    pub todo_id: String,
+    pub parent_id: Option<String>,
+    /// Direct subtasks, not counting dismissed ones.
+    pub subtasks_total: i64,
+    pub subtasks_done: i64,
+    /// Blockers that are neither done nor dismissed.
+    pub open_blocker_ids: Vec<String>,
+    pub complete_with_subtasks: bool,
+}
+
+fn is_todo_closed_status(status: &str) -> bool {
+    matches!(status, "done" | "dismissed")
+}
+
+fn read_todo_hierarchy_fields(conn: &Connection, todo_id: &str) -> Result<(Option<String>, bool)> {
+    let row: Option<(Option<String>, i64)> = conn
+        .query_row(
+            r#"SELECT parent_id, complete_with_subtasks FROM todos WHERE id = ?1"#,
+            params![todo_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    Ok(row
+        .map(|(parent_id, complete)| (parent_id, complete != 0))
+        .unwrap_or((None, false)))
+}
+
+/// Parent of `todo_id`. A parent that was deleted reads as `None`, so its
+/// former subtasks show up as top-level todos.
+pub fn get_todo_parent_id(conn: &Connection, todo_id: &str) -> Result<Option<String>> {
+    let parent_id: Option<Option<String>> = conn
+        .query_row(
+            r#"SELECT t.parent_id
+               FROM todos t
+               JOIN todos p ON p.id = t.parent_id
+               WHERE t.id = ?1"#,
+            params![todo_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(parent_id.flatten())
+}
+
+/// Walks up from `todo_id`. Concurrent moves on two devices can leave a cycle
+/// behind, so the walk stops at the first repeated id.
+fn is_todo_ancestor(conn: &Connection, ancestor_id: &str, todo_id: &str) -> Result<bool> {
+    let mut seen = BTreeSet::<String>::new();
+    let mut current = get_todo_parent_id(conn, todo_id)?;
+    while let Some(id) = current {
+        if id == ancestor_id {
+            return Ok(true);
+        }
+        if !seen.insert(id.clone()) {
+            break;
+        }
+        current = get_todo_parent_id(conn, &id)?;
+    }
+    Ok(false)
+}
+
+fn todo_exists(conn: &Connection, todo_id: &str) -> Result<bool> {
+    let exists: Option<i64> = conn
+        .query_row(
+            r#"SELECT 1 FROM todos WHERE id = ?1"#,
+            params![todo_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(exists.is_some())
+}
+
+/// Makes `todo_id` a subtask of `parent_id` (`None` makes it top-level again).
+pub fn set_todo_parent(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    parent_id: Option<&str>,
+) -> Result<Todo> {
+    let parent_id = parent_id.map(str::trim).filter(|id| !id.is_empty());
+    if !todo_exists(conn, todo_id)? {
+        return Err(anyhow!("todo not found: {todo_id}"));
+    }
+    if let Some(parent_id) = parent_id {
+        if parent_id == todo_id {
+            return Err(anyhow!("todo cannot be its own parent"));
+        }
+        if !todo_exists(conn, parent_id)? {
+            return Err(anyhow!("parent todo not found: {parent_id}"));
+        }
+        if is_todo_ancestor(conn, todo_id, parent_id)? {
+            return Err(anyhow!("todo cannot be moved under its own subtask"));
+        }
+    }
+
+    conn.execute(
+        r#"UPDATE todos SET parent_id = ?2, updated_at_ms = ?3 WHERE id = ?1"#,
+        params![todo_id, parent_id, now_ms()],
+    )?;
+    let todo = get_todo_by_id(conn, key, todo_id)?;
+    append_todo_upsert_op(conn, key, &todo)?;
+    Ok(todo)
+}
+
+/// When enabled, finishing the last open subtask marks `todo_id` done. If
+/// every subtask is already done, the todo is completed right away.
+pub fn set_todo_complete_with_subtasks(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    enabled: bool,
+) -> Result<Todo> {
+    conn.execute_batch("BEGIN IMMEDIATE;")?;
+
+    let result: Result<Todo> = (|| {
+        if !todo_exists(conn, todo_id)? {
+            return Err(anyhow!("todo not found: {todo_id}"));
+        }
+        conn.execute(
+            r#"UPDATE todos SET complete_with_subtasks = ?2, updated_at_ms = ?3 WHERE id = ?1"#,
+            params![todo_id, if enabled { 1 } else { 0 }, now_ms()],
+        )?;
+        let todo = get_todo_by_id(conn, key, todo_id)?;
+        append_todo_upsert_op(conn, key, &todo)?;
+
+        if enabled && should_complete_with_subtasks(conn, key, &todo)? {
+            return set_todo_status_in_txn(conn, key, todo_id, "done", None);
+        }
+        Ok(todo)
+    })();
+
+    match result {
+        Ok(todo) => {
+            conn.execute_batch("COMMIT;")?;
+            Ok(todo)
+        }
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK;");
+            Err(e)
+        }
+    }
+}
+
+fn should_complete_with_subtasks(conn: &Connection, key: &[u8; 32], todo: &Todo) -> Result<bool> {
+    if is_todo_closed_status(&todo.status) {
+        return Ok(false);
+    }
+    let rollup = get_todo_rollup(conn, key, &todo.id)?;
+    Ok(rollup.complete_with_subtasks
+        && rollup.subtasks_total > 0
+        && rollup.subtasks_done == rollup.subtasks_total)
+}
+
+/// Called after `child` changed status: completes its parent when that was
+/// the last open subtask and the parent opted in. Recurses up the tree.
+fn maybe_complete_parent_todo(
+    conn: &Connection,
+    key: &[u8; 32],
+    child: &Todo,
+    source_message_id: Option<&str>,
+) -> Result<()> {
+    if !is_todo_closed_status(&child.status) {
+        return Ok(());
+    }
+    let Some(parent_id) = get_todo_parent_id(conn, &child.id)? else {
+        return Ok(());
+    };
+    let parent = get_todo_by_id(conn, key, &parent_id)?;
+    if should_complete_with_subtasks(conn, key, &parent)? {
+        set_todo_status_in_txn(conn, key, &parent_id, "done", source_message_id)?;
+    }
+    Ok(())
+}
+
+fn query_todos_by_ids(
+    conn: &Connection,
+    key: &[u8; 32],
+    sql: &str,
+    todo_id: &str,
+) -> Result<Vec<Todo>> {
+    let mut stmt = conn.prepare(sql)?;
+    let ids = stmt
+        .query_map(params![todo_id], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    ids.iter().map(|id| get_todo_by_id(conn, key, id)).collect()
+}
+
+pub fn list_todo_subtasks(conn: &Connection, key: &[u8; 32], parent_id: &str) -> Result<Vec<Todo>> {
+    query_todos_by_ids(
+        conn,
+        key,
+        r#"SELECT id FROM todos
+           WHERE parent_id = ?1 AND id != ?1
+           ORDER BY created_at_ms ASC, id ASC"#,
+        parent_id,
+    )
+}
+
+/// Todos that must be finished before `todo_id`.
+pub fn list_todo_blockers(conn: &Connection, key: &[u8; 32], todo_id: &str) -> Result<Vec<Todo>> {
+    query_todos_by_ids(
+        conn,
+        key,
+        r#"SELECT t.id
+           FROM todo_dependencies d
+           JOIN todos t ON t.id = d.blocked_by_todo_id
+           WHERE d.todo_id = ?1 AND d.is_deleted = 0
+           ORDER BY t.created_at_ms ASC, t.id ASC"#,
+        todo_id,
+    )
+}
+
+/// Todos waiting on `blocker_id`.
+pub fn list_todos_blocked_by(
+    conn: &Connection,
+    key: &[u8; 32],
+    blocker_id: &str,
+) -> Result<Vec<Todo>> {
+    query_todos_by_ids(
+        conn,
+        key,
+        r#"SELECT t.id
+           FROM todo_dependencies d
+           JOIN todos t ON t.id = d.todo_id
+           WHERE d.blocked_by_todo_id = ?1 AND d.is_deleted = 0
+           ORDER BY t.created_at_ms ASC, t.id ASC"#,
+        blocker_id,
+    )
+}
+
+fn list_active_blocker_ids(conn: &Connection, todo_id: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT blocked_by_todo_id
+           FROM todo_dependencies
+           WHERE todo_id = ?1 AND is_deleted = 0"#,
+    )?;
+    let rows = stmt.query_map(params![todo_id], |row| row.get(0))?;
+    Ok(rows.collect::<rusqlite::Result<Vec<String>>>()?)
+}
+
+/// True when `todo_id` already (transitively) waits on `target_id`.
+fn todo_depends_on(conn: &Connection, todo_id: &str, target_id: &str) -> Result<bool> {
+    let mut seen = BTreeSet::<String>::new();
+    let mut queue = vec![todo_id.to_string()];
+    while let Some(id) = queue.pop() {
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        for blocker_id in list_active_blocker_ids(conn, &id)? {
+            if blocker_id == target_id {
+                return Ok(true);
+            }
+            queue.push(blocker_id);
+        }
+    }
+    Ok(false)
+}
+
+fn write_todo_dependency(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    blocked_by_todo_id: &str,
+    is_deleted: bool,
+) -> Result<()> {
+    // Keep updated_at_ms strictly increasing so an add and remove within the
+    // same millisecond still resolve in order on other devices.
+    let previous: Option<i64> = conn
+        .query_row(
+            r#"SELECT updated_at_ms FROM todo_dependencies
+               WHERE todo_id = ?1 AND blocked_by_todo_id = ?2"#,
+            params![todo_id, blocked_by_todo_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let now = previous.map_or(now_ms(), |previous| now_ms().max(previous + 1));
+    conn.execute(
+        r#"
+INSERT INTO todo_dependencies(todo_id, blocked_by_todo_id, is_deleted, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?4)
+ON CONFLICT(todo_id, blocked_by_todo_id) DO UPDATE SET
+  is_deleted = excluded.is_deleted,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![
+            todo_id,
+            blocked_by_todo_id,
+            if is_deleted { 1 } else { 0 },
+            now
+        ],
+    )?;
+    let created_at_ms: i64 = conn.query_row(
+        r#"SELECT created_at_ms FROM todo_dependencies
+           WHERE todo_id = ?1 AND blocked_by_todo_id = ?2"#,
+        params![todo_id, blocked_by_todo_id],
+        |row| row.get(0),
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "todo.dependency.set.v1",
+        "payload": {
+            "todo_id": todo_id,
+            "blocked_by_todo_id": blocked_by_todo_id,
+            "is_deleted": is_deleted,
+            "created_at_ms": created_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, key, &op)
+}
+
+/// Records that `todo_id` is blocked by `blocked_by_todo_id`. Rejects links
+/// that would make two todos wait on each other, directly or through others.
+pub fn add_todo_dependency(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    blocked_by_todo_id: &str,
+) -> Result<()> {
+    if todo_id == blocked_by_todo_id {
+        return Err(anyhow!("todo cannot block itself"));
+    }
+    for id in [todo_id, blocked_by_todo_id] {
+        if !todo_exists(conn, id)? {
+            return Err(anyhow!("todo not found: {id}"));
+        }
+    }
+    if list_active_blocker_ids(conn, todo_id)?
+        .iter()
+        .any(|id| id == blocked_by_todo_id)
+    {
+        return Ok(());
+    }
+    if todo_depends_on(conn, blocked_by_todo_id, todo_id)? {
+        return Err(anyhow!("todo dependency would create a cycle"));
+    }
+
+    write_todo_dependency(conn, key, todo_id, blocked_by_todo_id, false)
+}
+
+pub fn remove_todo_dependency(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo_id: &str,
+    blocked_by_todo_id: &str,
+) -> Result<()> {
+    if !list_active_blocker_ids(conn, todo_id)?
+        .iter()
+        .any(|id| id == blocked_by_todo_id)
+    {
+        return Ok(());
+    }
+    write_todo_dependency(conn, key, todo_id, blocked_by_todo_id, true)
+}
+
+pub fn get_todo_rollup(conn: &Connection, key: &[u8; 32], todo_id: &str) -> Result<TodoRollup> {
+    let (_, complete_with_subtasks) = read_todo_hierarchy_fields(conn, todo_id)?;
+    let (subtasks_total, subtasks_done): (i64, i64) = conn.query_row(
+        r#"SELECT
+             COUNT(*),
+             COALESCE(SUM(CASE WHEN status = 'done' THEN 1 ELSE 0 END), 0)
+           FROM todos
+           WHERE parent_id = ?1 AND id != ?1 AND status != 'dismissed'"#,
+        params![todo_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    let open_blocker_ids = list_todo_blockers(conn, key, todo_id)?
+        .into_iter()
+        .filter(|blocker| !is_todo_closed_status(&blocker.status))
+        .map(|blocker| blocker.id)
+        .collect();
+
+    Ok(TodoRollup {
+        todo_id: todo_id.to_string(),
+        parent_id: get_todo_parent_id(conn, todo_id)?,
+        subtasks_total,
+        subtasks_done,
+        open_blocker_ids,
+        complete_with_subtasks,
+    })
+}
@@ -0,0 +1,528 @@
+pub const REVIEW_ITEM_TODO: &str = "todo";
+pub const REVIEW_ITEM_MESSAGE: &str = "message";
+
+const REVIEW_DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const REVIEW_DEFAULT_EASE: f64 = 2.5;
+const REVIEW_MIN_EASE: f64 = 1.3;
+const REVIEW_MAX_GRADE: i64 = 5;
+const REVIEW_PASS_GRADE: i64 = 3;
+const REVIEW_TITLE_PREVIEW_CHARS: usize = 120;
+
+/// SM-2 scheduling state of one review item.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReviewSchedule {
+    pub ease_factor: f64,
+    pub interval_days: i64,
+    pub repetitions: i64,
+    pub lapses: i64,
+}
+
+impl Default for ReviewSchedule {
+    fn default() -> Self {
+        Self {
+            ease_factor: REVIEW_DEFAULT_EASE,
+            interval_days: 0,
+            repetitions: 0,
+            lapses: 0,
+        }
+    }
+}
+
+/// A todo or message in the review rotation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewItem {
+    pub item_kind: String,
+    pub item_id: String,
+    /// Todo title, or the start of the message.
+    pub title: String,
+    pub ease_factor: f64,
+    pub interval_days: i64,
+    pub repetitions: i64,
+    pub lapses: i64,
+    pub due_at_ms: i64,
+    pub last_reviewed_at_ms: Option<i64>,
+    pub is_active: bool,
+}
+
+/// One graded review, with the schedule it produced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewActivity {
+    pub id: String,
+    pub item_kind: String,
+    pub item_id: String,
+    pub grade: i64,
+    pub ease_factor: f64,
+    pub interval_days: i64,
+    pub due_at_ms: i64,
+    pub reviewed_at_ms: i64,
+}
+
+/// SM-2: grades 0-5, below 3 is a lapse that restarts the item at one day.
+/// Passing grades step 1 day, 6 days, then multiply by the ease factor.
+pub fn schedule_review(current: &ReviewSchedule, grade: i64) -> Result<ReviewSchedule> {
+    if !(0..=REVIEW_MAX_GRADE).contains(&grade) {
+        return Err(anyhow!(
+            "review grade must be between 0 and {REVIEW_MAX_GRADE}"
+        ));
+    }
+
+    let miss = (REVIEW_MAX_GRADE - grade) as f64;
+    let ease_factor =
+        (current.ease_factor + 0.1 - miss * (0.08 + miss * 0.02)).max(REVIEW_MIN_EASE);
+    if grade < REVIEW_PASS_GRADE {
+        return Ok(ReviewSchedule {
+            ease_factor,
+            interval_days: 1,
+            repetitions: 0,
+            lapses: current.lapses + 1,
+        });
+    }
+
+    let interval_days = match current.repetitions {
+        0 => 1,
+        1 => 6,
+        _ => ((current.interval_days.max(1) as f64) * current.ease_factor).round() as i64,
+    };
+    Ok(ReviewSchedule {
+        ease_factor,
+        interval_days,
+        repetitions: current.repetitions + 1,
+        lapses: current.lapses,
+    })
+}
+
+fn normalize_review_item_kind(item_kind: &str) -> Result<&'static str> {
+    match item_kind.trim() {
+        REVIEW_ITEM_TODO => Ok(REVIEW_ITEM_TODO),
+        REVIEW_ITEM_MESSAGE => Ok(REVIEW_ITEM_MESSAGE),
+        other => Err(anyhow!("unsupported review item kind: {other}")),
+    }
+}
+
+/// Title shown in the review queue; `None` once the todo or message is gone.
+fn read_review_item_title(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+) -> Result<Option<String>> {
+    if item_kind == REVIEW_ITEM_TODO {
+        if !todo_exists(conn, item_id)? {
+            return Ok(None);
+        }
+        return Ok(Some(get_todo_by_id(conn, key, item_id)?.title));
+    }
+
+    let Some(message) = get_message_by_id_optional(conn, key, item_id)? else {
+        return Ok(None);
+    };
+    let first_line = message.content.trim().lines().next().unwrap_or_default();
+    let mut title: String = first_line
+        .chars()
+        .take(REVIEW_TITLE_PREVIEW_CHARS)
+        .collect();
+    if first_line.chars().count() > REVIEW_TITLE_PREVIEW_CHARS {
+        title.push('…');
+    }
+    Ok(Some(title))
+}
+
+type ReviewItemRow = (ReviewSchedule, i64, Option<i64>, bool);
+
+fn read_review_item_row(
+    conn: &Connection,
+    item_kind: &str,
+    item_id: &str,
+) -> Result<Option<ReviewItemRow>> {
+    Ok(conn
+        .query_row(
+            r#"SELECT ease_factor, interval_days, repetitions, lapses, due_at_ms,
+                      last_reviewed_at_ms, is_active
+               FROM review_items
+               WHERE item_kind = ?1 AND item_id = ?2"#,
+            params![item_kind, item_id],
+            |row| {
+                Ok((
+                    ReviewSchedule {
+                        ease_factor: row.get(0)?,
+                        interval_days: row.get(1)?,
+                        repetitions: row.get(2)?,
+                        lapses: row.get(3)?,
+                    },
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get::<_, i64>(6)? != 0,
+                ))
+            },
+        )
+        .optional()?)
+}
+
+pub fn get_review_item(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+) -> Result<Option<ReviewItem>> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+    let Some((schedule, due_at_ms, last_reviewed_at_ms, is_active)) =
+        read_review_item_row(conn, item_kind, item_id)?
+    else {
+        return Ok(None);
+    };
+    let Some(title) = read_review_item_title(conn, key, item_kind, item_id)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(ReviewItem {
+        item_kind: item_kind.to_string(),
+        item_id: item_id.to_string(),
+        title,
+        ease_factor: schedule.ease_factor,
+        interval_days: schedule.interval_days,
+        repetitions: schedule.repetitions,
+        lapses: schedule.lapses,
+        due_at_ms,
+        last_reviewed_at_ms,
+        is_active,
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_review_item(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+    schedule: &ReviewSchedule,
+    due_at_ms: i64,
+    last_reviewed_at_ms: Option<i64>,
+    is_active: bool,
+) -> Result<ReviewItem> {
+    let previous: Option<(i64, i64)> = conn
+        .query_row(
+            r#"SELECT created_at_ms, updated_at_ms FROM review_items
+               WHERE item_kind = ?1 AND item_id = ?2"#,
+            params![item_kind, item_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    // Strictly increasing so back-to-back writes keep their order on other
+    // devices, which drop upserts that are not newer.
+    let now = previous.map_or(now_ms(), |(_, updated_at_ms)| {
+        now_ms().max(updated_at_ms + 1)
+    });
+    let created_at_ms = previous.map_or(now, |(created_at_ms, _)| created_at_ms);
+    conn.execute(
+        r#"
+INSERT INTO review_items(
+  item_kind, item_id, is_active, ease_factor, interval_days, repetitions, lapses,
+  due_at_ms, last_reviewed_at_ms, created_at_ms, updated_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+ON CONFLICT(item_kind, item_id) DO UPDATE SET
+  is_active = excluded.is_active,
+  ease_factor = excluded.ease_factor,
+  interval_days = excluded.interval_days,
+  repetitions = excluded.repetitions,
+  lapses = excluded.lapses,
+  due_at_ms = excluded.due_at_ms,
+  last_reviewed_at_ms = excluded.last_reviewed_at_ms,
+  updated_at_ms = excluded.updated_at_ms
+"#,
+        params![
+            item_kind,
+            item_id,
+            if is_active { 1 } else { 0 },
+            schedule.ease_factor,
+            schedule.interval_days,
+            schedule.repetitions,
+            schedule.lapses,
+            due_at_ms,
+            last_reviewed_at_ms,
+            created_at_ms,
+            now,
+        ],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "review.item.upsert.v1",
+        "payload": {
+            "item_kind": item_kind,
+            "item_id": item_id,
+            "is_active": is_active,
+            "ease_factor": schedule.ease_factor,
+            "interval_days": schedule.interval_days,
+            "repetitions": schedule.repetitions,
+            "lapses": schedule.lapses,
+            "due_at_ms": due_at_ms,
+            "last_reviewed_at_ms": last_reviewed_at_ms,
+            "created_at_ms": created_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, key, &op)?;
+
+    // Todos mirror the schedule in their own review fields, which the inbox
+    // review queue and Ask AI agenda already read.
+    if item_kind == REVIEW_ITEM_TODO {
+        let todo = get_todo_by_id(conn, key, item_id)?;
+        let (review_stage, next_review_at_ms) = if is_active {
+            (Some(schedule.repetitions), Some(due_at_ms))
+        } else {
+            (None, None)
+        };
+        upsert_todo(
+            conn,
+            key,
+            item_id,
+            &todo.title,
+            todo.due_at_ms,
+            &todo.status,
+            todo.source_entry_id.as_deref(),
+            review_stage,
+            next_review_at_ms,
+            last_reviewed_at_ms.or(todo.last_review_at_ms),
+        )?;
+    }
+
+    get_review_item(conn, key, item_kind, item_id)?
+        .ok_or_else(|| anyhow!("review item not found: {item_kind}:{item_id}"))
+}
+
+/// Adds a todo or message to the review rotation. The first review is due at
+/// `first_review_at_ms`, or a day from now. Re-marking an item keeps its
+/// schedule.
+pub fn mark_for_review(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+    first_review_at_ms: Option<i64>,
+) -> Result<ReviewItem> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+    if read_review_item_title(conn, key, item_kind, item_id)?.is_none() {
+        return Err(anyhow!("{item_kind} not found: {item_id}"));
+    }
+
+    let (schedule, due_at_ms, last_reviewed_at_ms) =
+        match read_review_item_row(conn, item_kind, item_id)? {
+            Some((schedule, due_at_ms, last_reviewed_at_ms, _)) => (
+                schedule,
+                first_review_at_ms.unwrap_or(due_at_ms),
+                last_reviewed_at_ms,
+            ),
+            None => (
+                ReviewSchedule::default(),
+                first_review_at_ms.unwrap_or_else(|| now_ms().saturating_add(REVIEW_DAY_MS)),
+                None,
+            ),
+        };
+    write_review_item(
+        conn,
+        key,
+        item_kind,
+        item_id,
+        &schedule,
+        due_at_ms,
+        last_reviewed_at_ms,
+        true,
+    )
+}
+
+/// Takes an item out of the rotation; its schedule and history are kept.
+pub fn unmark_for_review(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+) -> Result<()> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+    let Some((schedule, due_at_ms, last_reviewed_at_ms, is_active)) =
+        read_review_item_row(conn, item_kind, item_id)?
+    else {
+        return Ok(());
+    };
+    if !is_active || read_review_item_title(conn, key, item_kind, item_id)?.is_none() {
+        return Ok(());
+    }
+    write_review_item(
+        conn,
+        key,
+        item_kind,
+        item_id,
+        &schedule,
+        due_at_ms,
+        last_reviewed_at_ms,
+        false,
+    )?;
+    Ok(())
+}
+
+/// Active items due at or before `due_before_ms`, most overdue first.
+pub fn list_due_reviews(
+    conn: &Connection,
+    key: &[u8; 32],
+    due_before_ms: i64,
+    limit: u32,
+) -> Result<Vec<ReviewItem>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT item_kind, item_id
+           FROM review_items
+           WHERE is_active = 1 AND due_at_ms <= ?1
+           ORDER BY due_at_ms ASC, item_kind ASC, item_id ASC"#,
+    )?;
+    let keys = stmt
+        .query_map(params![due_before_ms], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut out = Vec::new();
+    for (item_kind, item_id) in keys {
+        if out.len() >= limit as usize {
+            break;
+        }
+        // Skip rows whose todo or message was deleted, or kinds from newer
+        // app versions.
+        if normalize_review_item_kind(&item_kind).is_err() {
+            continue;
+        }
+        if let Some(item) = get_review_item(conn, key, &item_kind, &item_id)? {
+            out.push(item);
+        }
+    }
+    Ok(out)
+}
+
+/// Next interval, in days, for each grade 0-5 of an active item.
+pub fn preview_review_intervals(
+    conn: &Connection,
+    item_kind: &str,
+    item_id: &str,
+) -> Result<Vec<i64>> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+    let schedule = read_review_item_row(conn, item_kind, item_id)?
+        .map(|(schedule, _, _, _)| schedule)
+        .ok_or_else(|| anyhow!("review item not found: {item_kind}:{item_id}"))?;
+    (0..=REVIEW_MAX_GRADE)
+        .map(|grade| Ok(schedule_review(&schedule, grade)?.interval_days))
+        .collect()
+}
+
+/// Grades a review of an active item, reschedules it and logs the review.
+pub fn record_review(
+    conn: &Connection,
+    key: &[u8; 32],
+    item_kind: &str,
+    item_id: &str,
+    grade: i64,
+) -> Result<ReviewItem> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+
+    conn.execute_batch("BEGIN IMMEDIATE;")?;
+    let result: Result<ReviewItem> = (|| {
+        let Some((current, _, _, true)) = read_review_item_row(conn, item_kind, item_id)? else {
+            return Err(anyhow!("{item_kind} is not marked for review: {item_id}"));
+        };
+        let next = schedule_review(&current, grade)?;
+        let reviewed_at_ms = now_ms();
+        let due_at_ms = reviewed_at_ms.saturating_add(next.interval_days * REVIEW_DAY_MS);
+        let activity_id = uuid::Uuid::new_v4().to_string();
+        conn.execute(
+            r#"
+INSERT INTO review_activities(
+  id, item_kind, item_id, grade, ease_factor, interval_days, due_at_ms, reviewed_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+"#,
+            params![
+                activity_id,
+                item_kind,
+                item_id,
+                grade,
+                next.ease_factor,
+                next.interval_days,
+                due_at_ms,
+                reviewed_at_ms
+            ],
+        )?;
+
+        let device_id = get_or_create_device_id(conn)?;
+        let seq = next_device_seq(conn, &device_id)?;
+        let op = serde_json::json!({
+            "op_id": uuid::Uuid::new_v4().to_string(),
+            "device_id": device_id,
+            "seq": seq,
+            "ts_ms": reviewed_at_ms,
+            "type": "review.activity.append.v1",
+            "payload": {
+                "activity_id": activity_id.as_str(),
+                "item_kind": item_kind,
+                "item_id": item_id,
+                "grade": grade,
+                "ease_factor": next.ease_factor,
+                "interval_days": next.interval_days,
+                "due_at_ms": due_at_ms,
+                "reviewed_at_ms": reviewed_at_ms,
+            }
+        });
+        insert_oplog(conn, key, &op)?;
+
+        write_review_item(
+            conn,
+            key,
+            item_kind,
+            item_id,
+            &next,
+            due_at_ms,
+            Some(reviewed_at_ms),
+            true,
+        )
+    })();
+
+    match result {
+        Ok(item) => {
+            conn.execute_batch("COMMIT;")?;
+            Ok(item)
+        }
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK;");
+            Err(e)
+        }
+    }
+}
+
+/// Review history of one item, oldest first.
+pub fn list_review_activities(
+    conn: &Connection,
+    item_kind: &str,
+    item_id: &str,
+) -> Result<Vec<ReviewActivity>> {
+    let item_kind = normalize_review_item_kind(item_kind)?;
+    let mut stmt = conn.prepare(
+        r#"SELECT id, grade, ease_factor, interval_days, due_at_ms, reviewed_at_ms
+           FROM review_activities
+           WHERE item_kind = ?1 AND item_id = ?2
+           ORDER BY reviewed_at_ms ASC, id ASC"#,
+    )?;
+    let rows = stmt.query_map(params![item_kind, item_id], |row| {
+        Ok(ReviewActivity {
+            id: row.get(0)?,
+            item_kind: item_kind.to_string(),
+            item_id: item_id.to_string(),
+            grade: row.get(1)?,
+            ease_factor: row.get(2)?,
+            interval_days: row.get(3)?,
+            due_at_ms: row.get(4)?,
+            reviewed_at_ms: row.get(5)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
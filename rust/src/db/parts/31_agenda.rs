@@ -0,0 +1,243 @@
+pub const AGENDA_ITEM_EVENT: &str = "event";
+pub const AGENDA_ITEM_TODO_DUE: &str = "todo_due";
+pub const AGENDA_ITEM_REVIEW: &str = "review";
+pub const AGENDA_ITEM_COMPLETED: &str = "completed";
+
+/// One row of the agenda. `at_ms` is the event start, due time, review time
+/// or completion time, depending on `kind`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgendaItem {
+    pub kind: String,
+    /// Unique within `kind`; event occurrences use `<event_id>@<occurrence_start_ms>`.
+    pub item_id: String,
+    /// Todo, event or review target the item belongs to.
+    pub source_id: String,
+    pub title: String,
+    pub at_ms: i64,
+    pub end_at_ms: Option<i64>,
+    /// Todo status for todo and completed items.
+    pub status: Option<String>,
+    pub tz: Option<String>,
+    pub is_recurring: bool,
+}
+
+/// Items whose `at_ms` falls on one local calendar day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgendaDay {
+    /// Local date, `YYYY-MM-DD`.
+    pub date: String,
+    pub day_start_ms: i64,
+    pub items: Vec<AgendaItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgendaPage {
+    /// Open todos and reviews due before the range; only on the first page.
+    pub overdue: Vec<AgendaItem>,
+    pub days: Vec<AgendaDay>,
+    /// Pass back as `cursor` to continue after the last returned item.
+    pub next_cursor: Option<String>,
+}
+
+fn agenda_kind_rank(kind: &str) -> u8 {
+    match kind {
+        AGENDA_ITEM_EVENT => 0,
+        AGENDA_ITEM_TODO_DUE => 1,
+        AGENDA_ITEM_REVIEW => 2,
+        _ => 3,
+    }
+}
+
+type AgendaSortKey = (i64, u8, String);
+
+fn agenda_sort_key(item: &AgendaItem) -> AgendaSortKey {
+    (
+        item.at_ms,
+        agenda_kind_rank(&item.kind),
+        item.item_id.clone(),
+    )
+}
+
+fn encode_agenda_cursor(item: &AgendaItem) -> String {
+    format!(
+        "{}:{}:{}",
+        item.at_ms,
+        agenda_kind_rank(&item.kind),
+        item.item_id
+    )
+}
+
+fn decode_agenda_cursor(cursor: &str) -> Result<AgendaSortKey> {
+    let mut parts = cursor.splitn(3, ':');
+    let (Some(at_ms), Some(rank), Some(item_id)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(anyhow!("invalid agenda cursor"));
+    };
+    Ok((
+        at_ms
+            .parse()
+            .map_err(|_| anyhow!("invalid agenda cursor"))?,
+        rank.parse().map_err(|_| anyhow!("invalid agenda cursor"))?,
+        item_id.to_string(),
+    ))
+}
+
+fn todo_agenda_item(todo: &Todo, tz: Option<String>, at_ms: i64) -> AgendaItem {
+    AgendaItem {
+        kind: AGENDA_ITEM_TODO_DUE.to_string(),
+        item_id: todo.id.clone(),
+        source_id: todo.id.clone(),
+        title: todo.title.clone(),
+        at_ms,
+        end_at_ms: None,
+        status: Some(todo.status.clone()),
+        tz,
+        is_recurring: false,
+    }
+}
+
+fn review_agenda_item(item: ReviewItem) -> AgendaItem {
+    AgendaItem {
+        kind: AGENDA_ITEM_REVIEW.to_string(),
+        item_id: format!("{}:{}", item.item_kind, item.item_id),
+        source_id: item.item_id,
+        title: item.title,
+        at_ms: item.due_at_ms,
+        end_at_ms: None,
+        status: None,
+        tz: None,
+        is_recurring: false,
+    }
+}
+
+/// Merged, ordered agenda for `[start_at_ms, end_at_ms)`, bucketed into days
+/// of `tz`. Covers todos due, expanded event occurrences, due reviews and
+/// todos completed in the range. Returns at most `limit` in-range items per
+/// page (0 means no limit).
+pub fn agenda_range(
+    conn: &Connection,
+    key: &[u8; 32],
+    start_at_ms: i64,
+    end_at_ms: i64,
+    tz: &str,
+    cursor: Option<&str>,
+    limit: u32,
+) -> Result<AgendaPage> {
+    let tz = parse_timezone(tz)?;
+    if end_at_ms <= start_at_ms {
+        return Ok(AgendaPage {
+            overdue: Vec::new(),
+            days: Vec::new(),
+            next_cursor: None,
+        });
+    }
+    let after = cursor.map(decode_agenda_cursor).transpose()?;
+
+    let mut items: Vec<AgendaItem> = Vec::new();
+    let mut overdue: Vec<AgendaItem> = Vec::new();
+
+    for todo in list_todos(conn, key)? {
+        let Some(due_at_ms) = todo.due_at_ms else {
+            continue;
+        };
+        let is_open = !is_todo_closed_status(&todo.status);
+        if (start_at_ms..end_at_ms).contains(&due_at_ms) {
+            let tz = get_todo_timezone(conn, &todo.id)?;
+            items.push(todo_agenda_item(&todo, tz, due_at_ms));
+        } else if due_at_ms < start_at_ms && is_open {
+            let tz = get_todo_timezone(conn, &todo.id)?;
+            overdue.push(todo_agenda_item(&todo, tz, due_at_ms));
+        }
+    }
+
+    for occurrence in list_event_occurrences_in_range(conn, key, start_at_ms, end_at_ms)? {
+        items.push(AgendaItem {
+            kind: AGENDA_ITEM_EVENT.to_string(),
+            item_id: format!("{}@{}", occurrence.event_id, occurrence.occurrence_start_ms),
+            source_id: occurrence.event_id,
+            title: occurrence.title,
+            at_ms: occurrence.start_at_ms,
+            end_at_ms: Some(occurrence.end_at_ms),
+            status: None,
+            tz: Some(occurrence.tz),
+            is_recurring: occurrence.is_recurring,
+        });
+    }
+
+    for review in list_due_reviews(conn, key, end_at_ms.saturating_sub(1), u32::MAX)? {
+        let item = review_agenda_item(review);
+        if item.at_ms < start_at_ms {
+            overdue.push(item);
+        } else {
+            items.push(item);
+        }
+    }
+
+    for activity in list_todo_activities_in_range(conn, key, start_at_ms, end_at_ms)? {
+        if activity.activity_type != "status_change"
+            || activity.to_status.as_deref() != Some("done")
+        {
+            continue;
+        }
+        let Ok(todo) = get_todo_by_id(conn, key, &activity.todo_id) else {
+            continue;
+        };
+        items.push(AgendaItem {
+            kind: AGENDA_ITEM_COMPLETED.to_string(),
+            item_id: activity.id,
+            source_id: activity.todo_id,
+            title: todo.title,
+            at_ms: activity.created_at_ms,
+            end_at_ms: None,
+            status: Some(todo.status),
+            tz: None,
+            is_recurring: false,
+        });
+    }
+
+    items.sort_by_key(agenda_sort_key);
+    if let Some(after) = &after {
+        items.retain(|item| &agenda_sort_key(item) > after);
+    }
+    let mut next_cursor = None;
+    if limit > 0 && items.len() > limit as usize {
+        items.truncate(limit as usize);
+        next_cursor = items.last().map(encode_agenda_cursor);
+    }
+
+    let mut days: Vec<AgendaDay> = Vec::new();
+    for item in items {
+        let date = recurrence_wall_clock(item.at_ms, Some(tz))?.date();
+        let label = agenda_date_label(date);
+        if days.last().map(|day| day.date.as_str()) != Some(label.as_str()) {
+            days.push(AgendaDay {
+                date: label,
+                day_start_ms: recurrence_instant_ms(date.midnight(), Some(tz)),
+                items: Vec::new(),
+            });
+        }
+        if let Some(day) = days.last_mut() {
+            day.items.push(item);
+        }
+    }
+
+    if after.is_some() {
+        overdue.clear();
+    }
+    overdue.sort_by_key(agenda_sort_key);
+
+    Ok(AgendaPage {
+        overdue,
+        days,
+        next_cursor,
+    })
+}
+
+fn agenda_date_label(date: time::Date) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
@@ -0,0 +1,88 @@
+pub const CITATION_SOURCE_MESSAGE: &str = "message";
+pub const CITATION_SOURCE_TODO: &str = "todo";
+pub const CITATION_SOURCE_EVENT: &str = "event";
+pub const CITATION_SOURCE_TODO_ACTIVITY: &str = "todo_activity";
+/// Source id is `{attachment_sha256}:{chunk_index}`.
+pub const CITATION_SOURCE_ATTACHMENT_CHUNK: &str = "attachment_chunk";
+
+/// Maps an inline `[marker]` in an assistant answer to the record it quotes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageCitation {
+    pub marker: u32,
+    /// One of the `CITATION_SOURCE_*` kinds.
+    pub source_kind: String,
+    pub source_id: String,
+}
+
+/// Stores the citations of an assistant message. Citations are written once,
+/// together with the answer, and never change afterwards.
+pub fn set_message_citations(
+    conn: &Connection,
+    key: &[u8; 32],
+    message_id: &str,
+    citations: &[MessageCitation],
+) -> Result<()> {
+    if citations.is_empty() {
+        return Ok(());
+    }
+
+    let now = now_ms();
+    for citation in citations {
+        conn.execute(
+            r#"INSERT OR IGNORE INTO message_citations(
+                 message_id, marker, source_kind, source_id, created_at_ms
+               )
+               VALUES (?1, ?2, ?3, ?4, ?5)"#,
+            params![
+                message_id,
+                citation.marker,
+                citation.source_kind,
+                citation.source_id,
+                now
+            ],
+        )?;
+    }
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "message.citations.set.v1",
+        "payload": {
+            "message_id": message_id,
+            "citations": citations
+                .iter()
+                .map(|citation| serde_json::json!({
+                    "marker": citation.marker,
+                    "source_kind": citation.source_kind,
+                    "source_id": citation.source_id,
+                }))
+                .collect::<Vec<_>>(),
+            "created_at_ms": now,
+        }
+    });
+    insert_oplog(conn, key, &op)?;
+    Ok(())
+}
+
+pub fn list_message_citations(conn: &Connection, message_id: &str) -> Result<Vec<MessageCitation>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT marker, source_kind, source_id
+           FROM message_citations
+           WHERE message_id = ?1
+           ORDER BY marker"#,
+    )?;
+    let mut rows = stmt.query([message_id])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        out.push(MessageCitation {
+            marker: row.get(0)?,
+            source_kind: row.get(1)?,
+            source_id: row.get(2)?,
+        });
+    }
+    Ok(out)
+}
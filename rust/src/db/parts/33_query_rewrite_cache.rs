@@ -0,0 +1,68 @@
+const ASK_AI_QUERY_REWRITE_ENABLED_KEY: &str = "ask_ai.query_rewrite_enabled";
+const QUERY_REWRITE_CACHE_MAX_ENTRIES: i64 = 256;
+
+pub fn get_ask_ai_query_rewrite_enabled(conn: &Connection) -> Result<bool> {
+    kv_bool_or(conn, ASK_AI_QUERY_REWRITE_ENABLED_KEY, false)
+}
+
+pub fn set_ask_ai_query_rewrite_enabled(conn: &Connection, enabled: bool) -> Result<()> {
+    kv_set_string(
+        conn,
+        ASK_AI_QUERY_REWRITE_ENABLED_KEY,
+        if enabled { "1" } else { "0" },
+    )
+}
+
+/// Rewritten search queries previously produced for `cache_key`. The cache is
+/// local to this device and never synced.
+pub fn get_cached_query_rewrite(
+    conn: &Connection,
+    key: &[u8; 32],
+    cache_key: &str,
+) -> Result<Option<Vec<String>>> {
+    let blob: Option<Vec<u8>> = conn
+        .query_row(
+            r#"SELECT queries FROM query_rewrite_cache WHERE cache_key = ?1"#,
+            params![cache_key],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(blob) = blob else {
+        return Ok(None);
+    };
+    let bytes = decrypt_bytes(key, &blob, b"query_rewrite_cache.queries")?;
+    Ok(Some(serde_json::from_slice(&bytes)?))
+}
+
+/// Stores `queries` under `cache_key`, evicting the oldest entries beyond the
+/// cache size.
+pub fn put_cached_query_rewrite(
+    conn: &Connection,
+    key: &[u8; 32],
+    cache_key: &str,
+    queries: &[String],
+) -> Result<()> {
+    let blob = encrypt_bytes(
+        key,
+        &serde_json::to_vec(queries)?,
+        b"query_rewrite_cache.queries",
+    )?;
+    conn.execute(
+        r#"INSERT INTO query_rewrite_cache(cache_key, queries, created_at_ms)
+           VALUES (?1, ?2, ?3)
+           ON CONFLICT(cache_key) DO UPDATE SET
+             queries = excluded.queries,
+             created_at_ms = excluded.created_at_ms"#,
+        params![cache_key, blob, now_ms()],
+    )?;
+    conn.execute(
+        r#"DELETE FROM query_rewrite_cache
+           WHERE cache_key NOT IN (
+             SELECT cache_key FROM query_rewrite_cache
+             ORDER BY created_at_ms DESC, cache_key
+             LIMIT ?1
+           )"#,
+        params![QUERY_REWRITE_CACHE_MAX_ENTRIES],
+    )?;
+    Ok(())
+}
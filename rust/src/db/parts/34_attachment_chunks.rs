@@ -0,0 +1,736 @@
+/// A piece of an attachment's extracted text, with where it came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachmentChunk {
+    pub attachment_sha256: String,
+    pub chunk_index: u32,
+    pub text: String,
+    /// 1-based page the chunk starts on, for paged documents.
+    pub page: Option<u32>,
+    /// Time span covered, for transcripts.
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SimilarAttachmentChunk {
+    pub chunk: AttachmentChunk,
+    /// Latest memory message the attachment is linked to.
+    pub message_id: String,
+    pub conversation_id: String,
+    pub message_created_at_ms: i64,
+    pub distance: f64,
+}
+
+fn attachment_chunk_embeddings_table(space_id: &str) -> Result<String> {
+    if !is_safe_sqlite_ident(space_id) {
+        return Err(anyhow!("unsafe embedding space_id: {space_id}"));
+    }
+    Ok(format!("attachment_chunk_embeddings__{space_id}"))
+}
+
+fn attachment_chunk_text_aad(attachment_sha256: &str, chunk_index: u32) -> Vec<u8> {
+    format!("attachment.chunk:{attachment_sha256}:{chunk_index}").into_bytes()
+}
+
+fn payload_page_offsets(payload: &serde_json::Value, key: &str) -> Vec<usize> {
+    payload
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_u64())
+                .filter_map(|v| usize::try_from(v).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Transcript segments from the payload. Accepts `t_ms` (local whisper) or
+/// `start`/`end` in seconds (OpenAI verbose json); a missing end is taken
+/// from the next segment's start.
+fn payload_transcript_segments(
+    payload: &serde_json::Value,
+) -> Vec<crate::content_extract::chunk::TimedSegment> {
+    let Some(items) = payload.get("segments").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    let seconds_to_ms = |v: &serde_json::Value| v.as_f64().map(|s| (s * 1000.0).round() as i64);
+    let mut out = Vec::new();
+    for item in items {
+        let text = item
+            .get("text")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .trim();
+        if text.is_empty() {
+            continue;
+        }
+        let start_ms = item
+            .get("t_ms")
+            .and_then(|v| v.as_i64())
+            .or_else(|| item.get("start").and_then(seconds_to_ms));
+        let Some(start_ms) = start_ms else {
+            continue;
+        };
+        let end_ms = item
+            .get("end_ms")
+            .and_then(|v| v.as_i64())
+            .or_else(|| item.get("end").and_then(seconds_to_ms))
+            .unwrap_or(start_ms);
+        out.push(crate::content_extract::chunk::TimedSegment {
+            start_ms,
+            end_ms: end_ms.max(start_ms),
+            text: text.to_string(),
+        });
+    }
+
+    for i in 1..out.len() {
+        if out[i - 1].end_ms == out[i - 1].start_ms {
+            out[i - 1].end_ms = out[i].start_ms.max(out[i - 1].start_ms);
+        }
+    }
+    out
+}
+
+/// Chunks the best full text of an annotation payload, in the same order of
+/// preference as the message excerpt.
+fn chunk_attachment_payload(
+    payload: &serde_json::Value,
+) -> Vec<crate::content_extract::chunk::TextChunk> {
+    use crate::content_extract::chunk::{chunk_segments, chunk_text};
+
+    let extracted = payload_str_trimmed(payload, "extracted_text_full");
+    let ocr = payload_str_trimmed(payload, "ocr_text_full");
+    let prefer_ocr = match (ocr, extracted) {
+        (Some(_), Some(extracted_text)) => looks_degraded_ascii_text(extracted_text),
+        _ => false,
+    };
+
+    if let (Some(extracted_text), false) = (extracted, prefer_ocr) {
+        let page_offsets = payload_page_offsets(payload, "extracted_text_page_offsets");
+        return chunk_text(extracted_text, &page_offsets);
+    }
+    if let Some(readable) = payload_str_trimmed(payload, "readable_text_full") {
+        return chunk_text(readable, &[]);
+    }
+    if let Some(ocr_text) = ocr {
+        return chunk_text(ocr_text, &[]);
+    }
+
+    let segments = payload_transcript_segments(payload);
+    if !segments.is_empty() {
+        return chunk_segments(&segments);
+    }
+    if let Some(transcript) = payload_str_trimmed(payload, "transcript_full") {
+        return chunk_text(transcript, &[]);
+    }
+    Vec::new()
+}
+
+fn delete_attachment_chunk_vectors(conn: &Connection, chunk_ids: &[i64]) -> Result<()> {
+    if chunk_ids.is_empty() {
+        return Ok(());
+    }
+    let Some(model_name) = get_active_embedding_model_name(conn)? else {
+        return Ok(());
+    };
+    let Some(dim) = get_active_embedding_dim(conn)? else {
+        return Ok(());
+    };
+    let table = attachment_chunk_embeddings_table(&embedding_space_id(&model_name, dim)?)?;
+    if !sqlite_table_exists(conn, &table)? {
+        return Ok(());
+    }
+    let sql = format!(r#"DELETE FROM "{table}" WHERE rowid = ?1"#);
+    for id in chunk_ids {
+        conn.execute(&sql, params![id])?;
+    }
+    Ok(())
+}
+
+/// Re-chunks one attachment from its current annotation payload. Returns
+/// the number of chunks stored.
+pub fn rebuild_attachment_chunks(
+    conn: &Connection,
+    key: &[u8; 32],
+    attachment_sha256: &str,
+) -> Result<usize> {
+    let source_updated_at: Option<i64> = conn
+        .query_row(
+            r#"SELECT updated_at
+               FROM attachment_annotations
+               WHERE attachment_sha256 = ?1
+                 AND status = 'ok'
+                 AND payload IS NOT NULL"#,
+            params![attachment_sha256],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let chunks = match read_attachment_annotation_payload_json(conn, key, attachment_sha256)? {
+        Some(json) => serde_json::from_str::<serde_json::Value>(&json)
+            .map(|payload| chunk_attachment_payload(&payload))
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    let mut stmt =
+        conn.prepare(r#"SELECT id FROM attachment_chunks WHERE attachment_sha256 = ?1"#)?;
+    let old_ids = stmt
+        .query_map(params![attachment_sha256], |row| row.get::<_, i64>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    drop(stmt);
+    delete_attachment_chunk_vectors(conn, &old_ids)?;
+    conn.execute(
+        r#"DELETE FROM attachment_chunks WHERE attachment_sha256 = ?1"#,
+        params![attachment_sha256],
+    )?;
+
+    let now = now_ms();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let chunk_index = u32::try_from(index).unwrap_or(u32::MAX);
+        let text_blob = encrypt_bytes(
+            key,
+            chunk.text.as_bytes(),
+            &attachment_chunk_text_aad(attachment_sha256, chunk_index),
+        )?;
+        conn.execute(
+            r#"INSERT INTO attachment_chunks(
+                 attachment_sha256, chunk_index, text, page, start_ms, end_ms,
+                 needs_embedding, created_at_ms
+               )
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1, ?7)"#,
+            params![
+                attachment_sha256,
+                chunk_index,
+                text_blob,
+                chunk.page,
+                chunk.start_ms,
+                chunk.end_ms,
+                now
+            ],
+        )?;
+    }
+
+    match source_updated_at {
+        Some(updated_at) => {
+            conn.execute(
+                r#"INSERT INTO attachment_chunk_sources(attachment_sha256, source_updated_at)
+                   VALUES (?1, ?2)
+                   ON CONFLICT(attachment_sha256) DO UPDATE SET
+                     source_updated_at = excluded.source_updated_at"#,
+                params![attachment_sha256, updated_at],
+            )?;
+        }
+        None => {
+            conn.execute(
+                r#"DELETE FROM attachment_chunk_sources WHERE attachment_sha256 = ?1"#,
+                params![attachment_sha256],
+            )?;
+        }
+    }
+
+    Ok(chunks.len())
+}
+
+/// Chunks attachments whose annotation changed since they were last chunked.
+pub fn process_pending_attachment_chunks(
+    conn: &Connection,
+    key: &[u8; 32],
+    limit: usize,
+) -> Result<usize> {
+    let mut stmt = conn.prepare(
+        r#"SELECT a.attachment_sha256
+           FROM attachment_annotations a
+           LEFT JOIN attachment_chunk_sources s
+             ON s.attachment_sha256 = a.attachment_sha256
+           WHERE a.status = 'ok'
+             AND a.payload IS NOT NULL
+             AND (s.source_updated_at IS NULL OR s.source_updated_at != a.updated_at)
+           ORDER BY a.updated_at ASC
+           LIMIT ?1"#,
+    )?;
+    let due = stmt
+        .query_map(params![i64::try_from(limit).unwrap_or(i64::MAX)], |row| {
+            row.get::<_, String>(0)
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    for attachment_sha256 in &due {
+        rebuild_attachment_chunks(conn, key, attachment_sha256)?;
+    }
+    Ok(due.len())
+}
+
+fn read_attachment_chunk_row(
+    key: &[u8; 32],
+    row: &rusqlite::Row<'_>,
+) -> Result<(i64, AttachmentChunk)> {
+    let id: i64 = row.get(0)?;
+    let attachment_sha256: String = row.get(1)?;
+    let chunk_index: u32 = row.get(2)?;
+    let text_blob: Vec<u8> = row.get(3)?;
+    let text_bytes = decrypt_bytes(
+        key,
+        &text_blob,
+        &attachment_chunk_text_aad(&attachment_sha256, chunk_index),
+    )?;
+    let text = String::from_utf8(text_bytes)
+        .map_err(|_| anyhow!("attachment chunk is not valid utf-8"))?;
+    Ok((
+        id,
+        AttachmentChunk {
+            attachment_sha256,
+            chunk_index,
+            text,
+            page: row.get(4)?,
+            start_ms: row.get(5)?,
+            end_ms: row.get(6)?,
+        },
+    ))
+}
+
+pub fn list_attachment_chunks(
+    conn: &Connection,
+    key: &[u8; 32],
+    attachment_sha256: &str,
+) -> Result<Vec<AttachmentChunk>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, attachment_sha256, chunk_index, text, page, start_ms, end_ms
+           FROM attachment_chunks
+           WHERE attachment_sha256 = ?1
+           ORDER BY chunk_index ASC"#,
+    )?;
+    let mut rows = stmt.query(params![attachment_sha256])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        out.push(read_attachment_chunk_row(key, row)?.1);
+    }
+    Ok(out)
+}
+
+fn store_attachment_chunk_embeddings(
+    conn: &Connection,
+    table: &str,
+    model_name: &str,
+    rows: &[(i64, String)],
+    embeddings: &[Vec<f32>],
+) -> Result<()> {
+    let update_sql = format!(
+        r#"UPDATE "{table}"
+           SET embedding = ?2, attachment_sha256 = ?3, model_name = ?4
+           WHERE rowid = ?1"#
+    );
+    let insert_sql = format!(
+        r#"INSERT INTO "{table}"(rowid, embedding, attachment_sha256, model_name)
+           VALUES (?1, ?2, ?3, ?4)"#
+    );
+    for ((id, attachment_sha256), embedding) in rows.iter().zip(embeddings) {
+        let updated = conn.execute(
+            &update_sql,
+            params![id, embedding.as_bytes(), attachment_sha256, model_name],
+        )?;
+        if updated == 0 {
+            conn.execute(
+                &insert_sql,
+                params![id, embedding.as_bytes(), attachment_sha256, model_name],
+            )?;
+        }
+        conn.execute(
+            r#"UPDATE attachment_chunks SET needs_embedding = 0 WHERE id = ?1"#,
+            params![id],
+        )?;
+    }
+    Ok(())
+}
+
+/// Chunk ids with their attachment sha, and the texts to embed.
+type PendingAttachmentChunkEmbeddings = (Vec<(i64, String)>, Vec<String>);
+
+fn list_attachment_chunks_needing_embedding(
+    conn: &Connection,
+    key: &[u8; 32],
+    limit: usize,
+) -> Result<PendingAttachmentChunkEmbeddings> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, attachment_sha256, chunk_index, text, page, start_ms, end_ms
+           FROM attachment_chunks
+           WHERE needs_embedding = 1
+           ORDER BY id ASC
+           LIMIT ?1"#,
+    )?;
+    let mut rows = stmt.query(params![i64::try_from(limit).unwrap_or(i64::MAX)])?;
+    let mut ids = Vec::new();
+    let mut plaintexts = Vec::new();
+    while let Some(row) = rows.next()? {
+        let (id, chunk) = read_attachment_chunk_row(key, row)?;
+        plaintexts.push(format!("passage: {}", chunk.text));
+        ids.push((id, chunk.attachment_sha256));
+    }
+    Ok((ids, plaintexts))
+}
+
+pub fn process_pending_attachment_chunk_embeddings<E: Embedder + ?Sized>(
+    conn: &Connection,
+    key: &[u8; 32],
+    embedder: &E,
+    limit: usize,
+) -> Result<usize> {
+    let expected_dim = current_embedding_dim(conn)?;
+    let space_id = embedding_space_id(embedder.model_name(), expected_dim)?;
+    ensure_vec_tables_for_space(conn, &space_id, expected_dim)?;
+    let table = attachment_chunk_embeddings_table(&space_id)?;
+
+    let (ids, plaintexts) = list_attachment_chunks_needing_embedding(conn, key, limit)?;
+    if plaintexts.is_empty() {
+        return Ok(0);
+    }
+
+    let embeddings = embedder.embed(&plaintexts)?;
+    if embeddings.len() != plaintexts.len() {
+        return Err(anyhow!(
+            "embedder output length mismatch: expected {}, got {}",
+            plaintexts.len(),
+            embeddings.len()
+        ));
+    }
+    for embedding in &embeddings {
+        if embedding.len() != expected_dim {
+            return Err(anyhow!(
+                "embedder dim mismatch: expected {expected_dim}, got {} (model_name={})",
+                embedding.len(),
+                embedder.model_name()
+            ));
+        }
+    }
+
+    store_attachment_chunk_embeddings(conn, &table, embedder.model_name(), &ids, &embeddings)?;
+    Ok(ids.len())
+}
+
+pub fn process_pending_attachment_chunk_embeddings_default(
+    conn: &Connection,
+    key: &[u8; 32],
+    limit: usize,
+) -> Result<usize> {
+    let space_id = embedding_space_id(crate::embedding::DEFAULT_MODEL_NAME, DEFAULT_EMBEDDING_DIM)?;
+    ensure_vec_tables_for_space(conn, &space_id, DEFAULT_EMBEDDING_DIM)?;
+    let table = attachment_chunk_embeddings_table(&space_id)?;
+
+    let (ids, plaintexts) = list_attachment_chunks_needing_embedding(conn, key, limit)?;
+    if plaintexts.is_empty() {
+        return Ok(0);
+    }
+
+    let embeddings = plaintexts
+        .iter()
+        .map(|text| default_embed_text(text))
+        .collect::<Vec<_>>();
+    store_attachment_chunk_embeddings(
+        conn,
+        &table,
+        crate::embedding::DEFAULT_MODEL_NAME,
+        &ids,
+        &embeddings,
+    )?;
+    Ok(ids.len())
+}
+
+pub fn process_pending_attachment_chunk_embeddings_active(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    limit: usize,
+) -> Result<usize> {
+    let desired = desired_embedding_model_name(conn)?;
+
+    if desired == crate::embedding::DEFAULT_MODEL_NAME {
+        set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+        return process_pending_attachment_chunk_embeddings_default(conn, key, limit);
+    }
+
+    if desired == crate::embedding::PRODUCTION_MODEL_NAME {
+        #[cfg(all(
+            any(target_os = "windows", target_os = "macos", target_os = "linux"),
+            not(frb_expand)
+        ))]
+        {
+            match crate::embedding::FastEmbedder::get_or_try_init(app_dir) {
+                Ok(embedder) => {
+                    set_active_embedding_model_name(conn, crate::embedding::PRODUCTION_MODEL_NAME)?;
+                    return process_pending_attachment_chunk_embeddings(
+                        conn, key, &embedder, limit,
+                    );
+                }
+                Err(e) => return Err(anyhow!("production embeddings unavailable: {e}")),
+            }
+        }
+
+        #[cfg(not(all(
+            any(target_os = "windows", target_os = "macos", target_os = "linux"),
+            not(frb_expand)
+        )))]
+        {
+            set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+            return process_pending_attachment_chunk_embeddings_default(conn, key, limit);
+        }
+    }
+
+    set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+    process_pending_attachment_chunk_embeddings_default(conn, key, limit)
+}
+
+fn latest_memory_message_for_attachment(
+    conn: &Connection,
+    attachment_sha256: &str,
+    conversation_id: Option<&str>,
+) -> Result<Option<(String, String, i64)>> {
+    Ok(conn
+        .query_row(
+            r#"SELECT m.id, m.conversation_id, m.created_at
+               FROM message_attachments ma
+               JOIN messages m ON m.id = ma.message_id
+               WHERE ma.attachment_sha256 = ?1
+                 AND COALESCE(m.is_deleted, 0) = 0
+                 AND COALESCE(m.is_memory, 1) = 1
+                 AND (?2 IS NULL OR m.conversation_id = ?2)
+               ORDER BY m.created_at DESC
+               LIMIT 1"#,
+            params![attachment_sha256, conversation_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?)
+}
+
+fn sort_similar_attachment_chunks(result: &mut [SimilarAttachmentChunk]) {
+    result.sort_by(|a, b| {
+        a.distance
+            .partial_cmp(&b.distance)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.chunk.attachment_sha256.cmp(&b.chunk.attachment_sha256))
+            .then_with(|| a.chunk.chunk_index.cmp(&b.chunk.chunk_index))
+    });
+}
+
+pub fn search_similar_attachment_chunks<E: Embedder + ?Sized>(
+    conn: &Connection,
+    key: &[u8; 32],
+    embedder: &E,
+    conversation_id: Option<&str>,
+    query: &str,
+    top_k: usize,
+) -> Result<Vec<SimilarAttachmentChunk>> {
+    let query = format!("query: {query}");
+    let mut vectors = embedder.embed(&[query])?;
+    if vectors.len() != 1 {
+        return Err(anyhow!(
+            "embedder output length mismatch: expected 1, got {}",
+            vectors.len()
+        ));
+    }
+    let query_vector = vectors.remove(0);
+    search_similar_attachment_chunks_by_embedding(
+        conn,
+        key,
+        embedder.model_name(),
+        conversation_id,
+        &query_vector,
+        top_k,
+    )
+}
+
+pub fn search_similar_attachment_chunks_by_embedding(
+    conn: &Connection,
+    key: &[u8; 32],
+    model_name: &str,
+    conversation_id: Option<&str>,
+    query_vector: &[f32],
+    top_k: usize,
+) -> Result<Vec<SimilarAttachmentChunk>> {
+    let expected_dim = current_embedding_dim(conn)?;
+    let space_id = embedding_space_id(model_name, expected_dim)?;
+    ensure_vec_tables_for_space(conn, &space_id, expected_dim)?;
+    let table = attachment_chunk_embeddings_table(&space_id)?;
+
+    if query_vector.len() != expected_dim {
+        return Err(anyhow!(
+            "query vector dim mismatch: expected {expected_dim}, got {} (model_name={model_name})",
+            query_vector.len(),
+        ));
+    }
+
+    let top_k = top_k.max(1);
+    let candidate_k = (top_k.saturating_mul(10)).min(1000);
+
+    let mut stmt = conn.prepare(&format!(
+        r#"SELECT c.id, c.attachment_sha256, c.chunk_index, c.text, c.page, c.start_ms, c.end_ms,
+                  ce.distance
+           FROM "{table}" ce
+           JOIN attachment_chunks c ON c.id = ce.rowid
+           WHERE ce.embedding match ?1 AND ce.k = ?2 AND ce.model_name = ?3
+           ORDER BY ce.distance ASC"#
+    ))?;
+    let mut rows = stmt.query(params![
+        query_vector.as_bytes(),
+        i64::try_from(candidate_k).unwrap_or(i64::MAX),
+        model_name
+    ])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        // Keep retrieval resilient: stale/corrupt rows should not fail the whole query.
+        let Ok((_, chunk)) = read_attachment_chunk_row(key, row) else {
+            continue;
+        };
+        let distance: f64 = row.get(7)?;
+        let Some((message_id, conversation_id, message_created_at_ms)) =
+            latest_memory_message_for_attachment(conn, &chunk.attachment_sha256, conversation_id)?
+        else {
+            continue;
+        };
+        result.push(SimilarAttachmentChunk {
+            chunk,
+            message_id,
+            conversation_id,
+            message_created_at_ms,
+            distance,
+        });
+        if result.len() >= top_k {
+            break;
+        }
+    }
+    Ok(result)
+}
+
+pub fn search_similar_attachment_chunks_default(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: Option<&str>,
+    query: &str,
+    top_k: usize,
+) -> Result<Vec<SimilarAttachmentChunk>> {
+    let top_k = top_k.max(1);
+
+    let query_norm = lite_normalize_text(query);
+    let query_compact = lite_compact_text(&query_norm);
+    if query_compact.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query_chars: Vec<char> = query_compact.chars().collect();
+    let query_bigrams = lite_collect_bigrams(&query_chars);
+    let query_trigrams = lite_collect_trigrams(&query_chars);
+
+    let mut stmt = conn.prepare(
+        r#"SELECT id, attachment_sha256, chunk_index, text, page, start_ms, end_ms
+           FROM attachment_chunks
+           ORDER BY id ASC"#,
+    )?;
+    let mut rows = stmt.query([])?;
+
+    let mut linked_messages: std::collections::HashMap<String, Option<(String, String, i64)>> =
+        std::collections::HashMap::new();
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        let Ok((_, chunk)) = read_attachment_chunk_row(key, row) else {
+            continue;
+        };
+
+        let score = lite_score(
+            &query_norm,
+            &query_compact,
+            &query_bigrams,
+            &query_trigrams,
+            &chunk.text,
+        );
+        if score == 0 {
+            continue;
+        }
+
+        let linked = match linked_messages.get(&chunk.attachment_sha256) {
+            Some(v) => v.clone(),
+            None => {
+                let v = latest_memory_message_for_attachment(
+                    conn,
+                    &chunk.attachment_sha256,
+                    conversation_id,
+                )?;
+                linked_messages.insert(chunk.attachment_sha256.clone(), v.clone());
+                v
+            }
+        };
+        let Some((message_id, conversation_id, message_created_at_ms)) = linked else {
+            continue;
+        };
+
+        result.push(SimilarAttachmentChunk {
+            chunk,
+            message_id,
+            conversation_id,
+            message_created_at_ms,
+            distance: 1.0 / (score as f64 + 1.0),
+        });
+    }
+
+    sort_similar_attachment_chunks(&mut result);
+    result.truncate(top_k);
+    Ok(result)
+}
+
+pub fn search_similar_attachment_chunks_active(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    conversation_id: Option<&str>,
+    query: &str,
+    top_k: usize,
+) -> Result<Vec<SimilarAttachmentChunk>> {
+    let desired = desired_embedding_model_name(conn)?;
+
+    if desired == crate::embedding::DEFAULT_MODEL_NAME {
+        set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+        return search_similar_attachment_chunks_default(conn, key, conversation_id, query, top_k);
+    }
+
+    if desired == crate::embedding::PRODUCTION_MODEL_NAME {
+        #[cfg(all(
+            any(target_os = "windows", target_os = "macos", target_os = "linux"),
+            not(frb_expand)
+        ))]
+        {
+            match crate::embedding::FastEmbedder::get_or_try_init(app_dir) {
+                Ok(embedder) => {
+                    set_active_embedding_model_name(conn, crate::embedding::PRODUCTION_MODEL_NAME)?;
+                    return search_similar_attachment_chunks(
+                        conn,
+                        key,
+                        &embedder,
+                        conversation_id,
+                        query,
+                        top_k,
+                    );
+                }
+                Err(e) => return Err(anyhow!("production embeddings unavailable: {e}")),
+            }
+        }
+
+        #[cfg(not(all(
+            any(target_os = "windows", target_os = "macos", target_os = "linux"),
+            not(frb_expand)
+        )))]
+        {
+            set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+            return search_similar_attachment_chunks_default(
+                conn,
+                key,
+                conversation_id,
+                query,
+                top_k,
+            );
+        }
+    }
+
+    set_active_embedding_model_name(conn, crate::embedding::DEFAULT_MODEL_NAME)?;
+    search_similar_attachment_chunks_default(conn, key, conversation_id, query, top_k)
+}
@@ -0,0 +1,88 @@
+/// Profiles tried, in order, after the primary one fails. Device-local like
+/// the profiles themselves.
+pub fn list_llm_fallback_chain(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt =
+        conn.prepare(r#"SELECT profile_id FROM llm_fallback_chain ORDER BY position ASC"#)?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(ids)
+}
+
+/// Replaces the fallback chain. Duplicates keep their first position.
+pub fn set_llm_fallback_chain(conn: &Connection, profile_ids: &[String]) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    tx.execute("DELETE FROM llm_fallback_chain", [])?;
+    let mut seen = std::collections::HashSet::new();
+    for profile_id in profile_ids.iter().map(|id| id.trim()) {
+        if !seen.insert(profile_id) {
+            continue;
+        }
+        let exists: bool = tx.query_row(
+            r#"SELECT EXISTS(SELECT 1 FROM llm_profiles WHERE id = ?1)"#,
+            params![profile_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(anyhow!("llm profile not found: {profile_id}"));
+        }
+        tx.execute(
+            r#"INSERT INTO llm_fallback_chain(profile_id, position) VALUES (?1, ?2)"#,
+            params![
+                profile_id,
+                i64::try_from(seen.len() - 1).unwrap_or(i64::MAX)
+            ],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// `primary_profile_id` (or the active profile when `None`) followed by the
+/// fallback chain. Empty when there is no primary profile. Profiles over
+/// their monthly spending limit are skipped; errors with
+/// `llm_spending_limit_exceeded` when that leaves none, or when the limit
+/// on all profiles is reached. Limits apply to the month of `local_day`
+/// (the current month when it is empty).
+pub fn load_llm_profile_chain_configs(
+    conn: &Connection,
+    key: &[u8; 32],
+    primary_profile_id: Option<&str>,
+    local_day: &str,
+) -> Result<Vec<(String, LlmProfileConfig)>> {
+    let month = llm_usage_month(local_day);
+    check_llm_spending_limit(conn, "", &month)?;
+
+    let primary = match primary_profile_id {
+        Some(id) => load_llm_profile_config_by_id(conn, key, id)?.map(|p| (id.to_string(), p)),
+        None => load_active_llm_profile_config(conn, key)?,
+    };
+    let Some(primary) = primary else {
+        return Ok(Vec::new());
+    };
+
+    let mut out = vec![primary];
+    for profile_id in list_llm_fallback_chain(conn)? {
+        if out.iter().any(|(id, _)| *id == profile_id) {
+            continue;
+        }
+        if let Some(profile) = load_llm_profile_config_by_id(conn, key, &profile_id)? {
+            out.push((profile_id, profile));
+        }
+    }
+
+    let mut limit_error = None;
+    out.retain(
+        |(profile_id, _)| match check_llm_spending_limit(conn, profile_id, &month) {
+            Ok(()) => true,
+            Err(e) => {
+                limit_error.get_or_insert(e);
+                false
+            }
+        },
+    );
+    match limit_error {
+        Some(e) if out.is_empty() => Err(e),
+        _ => Ok(out),
+    }
+}
@@ -0,0 +1,327 @@
+/// A user-edited prompt for one purpose (`ask_ai`, `semantic_parse`,
+/// `media_annotation`). See `crate::prompt_templates` for its variables.
+#[derive(Clone, Debug)]
+pub struct PromptTemplate {
+    pub id: String,
+    pub name: String,
+    pub purpose: String,
+    pub body: String,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+}
+
+/// Which template a purpose uses. `profile_id` is empty for the selection
+/// that applies to every LLM profile without its own.
+#[derive(Clone, Debug)]
+pub struct PromptTemplateSelection {
+    pub purpose: String,
+    pub profile_id: String,
+    pub template_id: String,
+}
+
+fn prompt_template_aad(template_id: &str) -> String {
+    format!("prompt_template.template:{template_id}")
+}
+
+fn prompt_template_from_blob(
+    db_key: &[u8; 32],
+    id: String,
+    template_blob: &[u8],
+    created_at_ms: i64,
+    updated_at_ms: i64,
+) -> Result<PromptTemplate> {
+    let json = decrypt_bytes(db_key, template_blob, prompt_template_aad(&id).as_bytes())?;
+    let value: serde_json::Value = serde_json::from_slice(&json)?;
+    let field = |name: &str| value[name].as_str().unwrap_or_default().to_string();
+    Ok(PromptTemplate {
+        name: field("name"),
+        purpose: field("purpose"),
+        body: field("body"),
+        id,
+        created_at_ms,
+        updated_at_ms,
+    })
+}
+
+pub fn get_prompt_template(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    template_id: &str,
+) -> Result<Option<PromptTemplate>> {
+    let row: Option<(Vec<u8>, i64, i64)> = conn
+        .query_row(
+            r#"SELECT template, created_at_ms, updated_at_ms FROM prompt_templates WHERE id = ?1"#,
+            params![template_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let Some((template_blob, created_at_ms, updated_at_ms)) = row else {
+        return Ok(None);
+    };
+    prompt_template_from_blob(
+        db_key,
+        template_id.to_string(),
+        &template_blob,
+        created_at_ms,
+        updated_at_ms,
+    )
+    .map(Some)
+}
+
+pub fn list_prompt_templates(conn: &Connection, db_key: &[u8; 32]) -> Result<Vec<PromptTemplate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, template, created_at_ms, updated_at_ms
+           FROM prompt_templates
+           ORDER BY created_at_ms ASC, id ASC"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let template_blob: Vec<u8> = row.get(1)?;
+        out.push(prompt_template_from_blob(
+            db_key,
+            id,
+            &template_blob,
+            row.get(2)?,
+            row.get(3)?,
+        )?);
+    }
+    Ok(out)
+}
+
+/// Creates (`template_id` = None) or replaces a template after validating
+/// its variables. Synced via `prompt_template.upsert.v1`.
+pub fn upsert_prompt_template(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    template_id: Option<&str>,
+    name: &str,
+    purpose: &str,
+    body: &str,
+) -> Result<PromptTemplate> {
+    let purpose = purpose.trim();
+    crate::prompt_templates::validate_prompt_template(purpose, body)?;
+
+    let now = now_ms();
+    let id = template_id
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let existing = get_prompt_template(conn, db_key, &id)?;
+    if let Some(existing) = &existing {
+        if existing.purpose != purpose {
+            return Err(anyhow!(
+                "prompt template purpose cannot change: {} -> {purpose}",
+                existing.purpose
+            ));
+        }
+    }
+    let created_at_ms = existing
+        .map(|existing| existing.created_at_ms)
+        .unwrap_or(now);
+
+    let name = name.trim();
+    let template = serde_json::json!({
+        "name": name,
+        "purpose": purpose,
+        "body": body,
+    });
+    let template_blob = encrypt_bytes(
+        db_key,
+        &serde_json::to_vec(&template)?,
+        prompt_template_aad(&id).as_bytes(),
+    )?;
+    conn.execute(
+        r#"INSERT INTO prompt_templates(id, template, created_at_ms, updated_at_ms)
+           VALUES (?1, ?2, ?3, ?4)
+           ON CONFLICT(id) DO UPDATE SET
+             template = excluded.template,
+             updated_at_ms = excluded.updated_at_ms"#,
+        params![id, template_blob, created_at_ms, now],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "prompt_template.upsert.v1",
+        "payload": {
+            "template_id": id,
+            "name": name,
+            "purpose": purpose,
+            "body": body,
+            "created_at_ms": created_at_ms,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+
+    get_prompt_template(conn, db_key, &id)?.ok_or_else(|| anyhow!("failed to read prompt template"))
+}
+
+/// Replaces the template body with the built-in prompt for its purpose.
+pub fn reset_prompt_template_to_default(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    template_id: &str,
+) -> Result<PromptTemplate> {
+    let template = get_prompt_template(conn, db_key, template_id.trim())?
+        .ok_or_else(|| anyhow!("prompt template not found: {template_id}"))?;
+    let body = crate::prompt_templates::default_prompt_template(&template.purpose)?;
+    upsert_prompt_template(
+        conn,
+        db_key,
+        Some(&template.id),
+        &template.name,
+        &template.purpose,
+        body,
+    )
+}
+
+pub fn delete_prompt_template(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    template_id: &str,
+) -> Result<()> {
+    let template_id = template_id.trim();
+    if template_id.is_empty() {
+        return Err(anyhow!("template_id cannot be empty"));
+    }
+
+    let deleted_at_ms = now_ms();
+    conn.execute(
+        r#"DELETE FROM prompt_templates WHERE id = ?1"#,
+        params![template_id],
+    )?;
+    kv_set_i64(
+        conn,
+        &format!("prompt_template.deleted_at:{template_id}"),
+        deleted_at_ms,
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": deleted_at_ms,
+        "type": "prompt_template.delete.v1",
+        "payload": {
+            "template_id": template_id,
+            "deleted_at_ms": deleted_at_ms,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+    Ok(())
+}
+
+/// Device-local, like the LLM profiles the selections refer to.
+pub fn list_prompt_template_selections(conn: &Connection) -> Result<Vec<PromptTemplateSelection>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT purpose, profile_id, template_id
+           FROM prompt_template_selections
+           ORDER BY purpose ASC, profile_id ASC"#,
+    )?;
+    let selections = stmt
+        .query_map([], |row| {
+            Ok(PromptTemplateSelection {
+                purpose: row.get(0)?,
+                profile_id: row.get(1)?,
+                template_id: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(selections)
+}
+
+/// Selects `template_id` for `purpose` on `profile_id` (empty = all
+/// profiles). `None` clears the selection, falling back to the built-in prompt.
+pub fn set_prompt_template_selection(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    purpose: &str,
+    profile_id: &str,
+    template_id: Option<&str>,
+) -> Result<()> {
+    let purpose = purpose.trim();
+    let profile_id = profile_id.trim();
+    crate::prompt_templates::default_prompt_template(purpose)?;
+
+    let Some(template_id) = template_id.map(str::trim).filter(|id| !id.is_empty()) else {
+        conn.execute(
+            r#"DELETE FROM prompt_template_selections WHERE purpose = ?1 AND profile_id = ?2"#,
+            params![purpose, profile_id],
+        )?;
+        return Ok(());
+    };
+
+    let template = get_prompt_template(conn, db_key, template_id)?
+        .ok_or_else(|| anyhow!("prompt template not found: {template_id}"))?;
+    if template.purpose != purpose {
+        return Err(anyhow!(
+            "prompt template {template_id} is for {} not {purpose}",
+            template.purpose
+        ));
+    }
+    if !profile_id.is_empty() && load_llm_profile_config_by_id(conn, db_key, profile_id)?.is_none()
+    {
+        return Err(anyhow!("llm profile not found: {profile_id}"));
+    }
+    conn.execute(
+        r#"INSERT INTO prompt_template_selections(purpose, profile_id, template_id)
+           VALUES (?1, ?2, ?3)
+           ON CONFLICT(purpose, profile_id) DO UPDATE SET
+             template_id = excluded.template_id"#,
+        params![purpose, profile_id, template_id],
+    )?;
+    Ok(())
+}
+
+/// Clears every selection for `purpose`, so it uses the built-in prompt again.
+pub fn reset_prompt_template_selections(conn: &Connection, purpose: &str) -> Result<()> {
+    conn.execute(
+        r#"DELETE FROM prompt_template_selections WHERE purpose = ?1"#,
+        params![purpose.trim()],
+    )?;
+    Ok(())
+}
+
+/// Body of the template selected for `purpose` on `profile_id` (the active
+/// profile when `None`), else the one selected for all profiles. `None`
+/// means the built-in prompt should be used.
+pub fn resolve_prompt_template(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    purpose: &str,
+    profile_id: Option<&str>,
+) -> Result<Option<String>> {
+    let profile_id = match profile_id {
+        Some(id) => id.trim().to_string(),
+        None => load_active_llm_profile_config(conn, db_key)?
+            .map(|(id, _)| id)
+            .unwrap_or_default(),
+    };
+    let template_id: Option<String> = conn
+        .query_row(
+            r#"SELECT template_id
+               FROM prompt_template_selections
+               WHERE purpose = ?1 AND profile_id IN (?2, '')
+               ORDER BY profile_id DESC
+               LIMIT 1"#,
+            params![purpose, profile_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(template_id) = template_id else {
+        return Ok(None);
+    };
+    Ok(get_prompt_template(conn, db_key, &template_id)?
+        .filter(|template| template.purpose == purpose)
+        .map(|template| template.body))
+}
@@ -0,0 +1,325 @@
+/// Price of one model, in USD per million tokens. Synced, keyed by provider
+/// type and model name so every device prices the same model the same way.
+#[derive(Clone, Debug)]
+pub struct LlmModelPrice {
+    pub provider_type: String,
+    pub model_name: String,
+    pub input_usd_per_million_tokens: f64,
+    pub output_usd_per_million_tokens: f64,
+    pub updated_at_ms: i64,
+}
+
+/// Usage and cost of one profile for one purpose in one month.
+#[derive(Clone, Debug)]
+pub struct LlmMonthlyCost {
+    pub profile_id: String,
+    pub purpose: String,
+    pub requests: i64,
+    pub requests_with_usage: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_usd_micros: i64,
+}
+
+/// A monthly spending limit. `profile_id` is empty for the limit on all
+/// profiles together.
+#[derive(Clone, Debug)]
+pub struct LlmSpendingLimit {
+    pub profile_id: String,
+    pub monthly_limit_usd_micros: i64,
+}
+
+fn llm_model_price_deleted_at_key(provider_type: &str, model_name: &str) -> String {
+    format!("llm_model_price.deleted_at:{provider_type}:{model_name}")
+}
+
+fn validate_llm_model_price(input: f64, output: f64) -> Result<()> {
+    if !input.is_finite() || !output.is_finite() || input < 0.0 || output < 0.0 {
+        return Err(anyhow!("llm model price must be a non-negative number"));
+    }
+    Ok(())
+}
+
+pub fn list_llm_model_prices(conn: &Connection) -> Result<Vec<LlmModelPrice>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT provider_type, model_name, input_usd_per_mtok, output_usd_per_mtok, updated_at_ms
+           FROM llm_model_prices
+           ORDER BY provider_type ASC, model_name ASC"#,
+    )?;
+    let prices = stmt
+        .query_map([], |row| {
+            Ok(LlmModelPrice {
+                provider_type: row.get(0)?,
+                model_name: row.get(1)?,
+                input_usd_per_million_tokens: row.get(2)?,
+                output_usd_per_million_tokens: row.get(3)?,
+                updated_at_ms: row.get(4)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(prices)
+}
+
+/// Creates or replaces the price of a model. Synced via
+/// `llm_model_price.upsert.v1`. Only usage recorded afterwards is priced.
+pub fn upsert_llm_model_price(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    provider_type: &str,
+    model_name: &str,
+    input_usd_per_million_tokens: f64,
+    output_usd_per_million_tokens: f64,
+) -> Result<LlmModelPrice> {
+    let provider_type = provider_type.trim();
+    let model_name = model_name.trim();
+    if provider_type.is_empty() || model_name.is_empty() {
+        return Err(anyhow!("provider_type and model_name are required"));
+    }
+    validate_llm_model_price(input_usd_per_million_tokens, output_usd_per_million_tokens)?;
+
+    let now = now_ms();
+    conn.execute(
+        r#"INSERT INTO llm_model_prices
+             (provider_type, model_name, input_usd_per_mtok, output_usd_per_mtok, updated_at_ms)
+           VALUES (?1, ?2, ?3, ?4, ?5)
+           ON CONFLICT(provider_type, model_name) DO UPDATE SET
+             input_usd_per_mtok = excluded.input_usd_per_mtok,
+             output_usd_per_mtok = excluded.output_usd_per_mtok,
+             updated_at_ms = excluded.updated_at_ms"#,
+        params![
+            provider_type,
+            model_name,
+            input_usd_per_million_tokens,
+            output_usd_per_million_tokens,
+            now
+        ],
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": now,
+        "type": "llm_model_price.upsert.v1",
+        "payload": {
+            "provider_type": provider_type,
+            "model_name": model_name,
+            "input_usd_per_million_tokens": input_usd_per_million_tokens,
+            "output_usd_per_million_tokens": output_usd_per_million_tokens,
+            "updated_at_ms": now,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+
+    Ok(LlmModelPrice {
+        provider_type: provider_type.to_string(),
+        model_name: model_name.to_string(),
+        input_usd_per_million_tokens,
+        output_usd_per_million_tokens,
+        updated_at_ms: now,
+    })
+}
+
+pub fn delete_llm_model_price(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    provider_type: &str,
+    model_name: &str,
+) -> Result<()> {
+    let provider_type = provider_type.trim();
+    let model_name = model_name.trim();
+
+    let deleted_at_ms = now_ms();
+    conn.execute(
+        r#"DELETE FROM llm_model_prices WHERE provider_type = ?1 AND model_name = ?2"#,
+        params![provider_type, model_name],
+    )?;
+    kv_set_i64(
+        conn,
+        &llm_model_price_deleted_at_key(provider_type, model_name),
+        deleted_at_ms,
+    )?;
+
+    let device_id = get_or_create_device_id(conn)?;
+    let seq = next_device_seq(conn, &device_id)?;
+    let op = serde_json::json!({
+        "op_id": uuid::Uuid::new_v4().to_string(),
+        "device_id": device_id,
+        "seq": seq,
+        "ts_ms": deleted_at_ms,
+        "type": "llm_model_price.delete.v1",
+        "payload": {
+            "provider_type": provider_type,
+            "model_name": model_name,
+            "deleted_at_ms": deleted_at_ms,
+        }
+    });
+    insert_oplog(conn, db_key, &op)?;
+    Ok(())
+}
+
+/// Cost of one request to `profile_id`'s model, from the price table.
+/// Zero when the model has no price.
+fn llm_request_cost_usd_micros(
+    conn: &Connection,
+    profile_id: &str,
+    input_tokens: i64,
+    output_tokens: i64,
+) -> Result<i64> {
+    let price: Option<(f64, f64)> = conn
+        .query_row(
+            r#"SELECT p.input_usd_per_mtok, p.output_usd_per_mtok
+               FROM llm_profiles AS l
+               JOIN llm_model_prices AS p
+                 ON p.provider_type = l.provider_type AND p.model_name = l.model_name
+               WHERE l.id = ?1"#,
+            params![profile_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some((input_price, output_price)) = price else {
+        return Ok(0);
+    };
+    // USD per million tokens times tokens is micro-USD.
+    let cost =
+        input_tokens.max(0) as f64 * input_price + output_tokens.max(0) as f64 * output_price;
+    Ok(cost.round() as i64)
+}
+
+/// Usage and cost per profile and purpose for `month` (`YYYY-MM`, matching
+/// the local days usage is recorded under).
+pub fn sum_llm_cost_monthly(conn: &Connection, month: &str) -> Result<Vec<LlmMonthlyCost>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT profile_id,
+                  purpose,
+                  COALESCE(SUM(requests), 0),
+                  COALESCE(SUM(requests_with_usage), 0),
+                  COALESCE(SUM(input_tokens), 0),
+                  COALESCE(SUM(output_tokens), 0),
+                  COALESCE(SUM(cost_usd_micros), 0)
+           FROM llm_usage_daily
+           WHERE substr(day, 1, 7) = ?1
+           GROUP BY profile_id, purpose
+           ORDER BY profile_id ASC, purpose ASC"#,
+    )?;
+    let costs = stmt
+        .query_map(params![month.trim()], |row| {
+            Ok(LlmMonthlyCost {
+                profile_id: row.get(0)?,
+                purpose: row.get(1)?,
+                requests: row.get(2)?,
+                requests_with_usage: row.get(3)?,
+                input_tokens: row.get(4)?,
+                output_tokens: row.get(5)?,
+                cost_usd_micros: row.get(6)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(costs)
+}
+
+pub fn list_llm_spending_limits(conn: &Connection) -> Result<Vec<LlmSpendingLimit>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT profile_id, monthly_limit_usd_micros
+           FROM llm_spending_limits
+           ORDER BY profile_id ASC"#,
+    )?;
+    let limits = stmt
+        .query_map([], |row| {
+            Ok(LlmSpendingLimit {
+                profile_id: row.get(0)?,
+                monthly_limit_usd_micros: row.get(1)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(limits)
+}
+
+/// Sets (or with `None` removes) the monthly limit for `profile_id`, or for
+/// all profiles together when it is empty. Device-local, like usage.
+pub fn set_llm_spending_limit(
+    conn: &Connection,
+    profile_id: &str,
+    monthly_limit_usd_micros: Option<i64>,
+) -> Result<()> {
+    let profile_id = profile_id.trim();
+    let Some(limit) = monthly_limit_usd_micros else {
+        conn.execute(
+            r#"DELETE FROM llm_spending_limits WHERE profile_id = ?1"#,
+            params![profile_id],
+        )?;
+        return Ok(());
+    };
+    if limit < 0 {
+        return Err(anyhow!("spending limit must not be negative"));
+    }
+    if !profile_id.is_empty() {
+        let exists: bool = conn.query_row(
+            r#"SELECT EXISTS(SELECT 1 FROM llm_profiles WHERE id = ?1)"#,
+            params![profile_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(anyhow!("llm profile not found: {profile_id}"));
+        }
+    }
+    conn.execute(
+        r#"INSERT INTO llm_spending_limits(profile_id, monthly_limit_usd_micros)
+           VALUES (?1, ?2)
+           ON CONFLICT(profile_id) DO UPDATE SET
+             monthly_limit_usd_micros = excluded.monthly_limit_usd_micros"#,
+        params![profile_id, limit],
+    )?;
+    Ok(())
+}
+
+/// The month (`YYYY-MM`) of `local_day`, the local `YYYY-MM-DD` day usage
+/// is recorded under, so limits apply to the same month the rollup sums.
+/// Falls back to the current UTC month when `local_day` is empty or not
+/// such a day, so callers without a local day are still limited.
+pub fn llm_usage_month(local_day: &str) -> String {
+    let day = time::format_description::parse("[year]-[month]-[day]")
+        .ok()
+        .and_then(|format| time::Date::parse(local_day.trim(), &format).ok())
+        .unwrap_or_else(|| time::OffsetDateTime::now_utc().date());
+    format!("{:04}-{:02}", day.year(), u8::from(day.month()))
+}
+
+fn llm_month_cost_usd_micros(conn: &Connection, month: &str, profile_id: &str) -> Result<i64> {
+    Ok(conn.query_row(
+        r#"SELECT COALESCE(SUM(cost_usd_micros), 0)
+           FROM llm_usage_daily
+           WHERE substr(day, 1, 7) = ?1 AND (?2 = '' OR profile_id = ?2)"#,
+        params![month, profile_id],
+        |row| row.get(0),
+    )?)
+}
+
+/// Errors with `llm_spending_limit_exceeded` once this month's cost for
+/// `profile_id` (or all profiles, when empty) reaches its limit.
+pub fn check_llm_spending_limit(conn: &Connection, profile_id: &str, month: &str) -> Result<()> {
+    let limit: Option<i64> = conn
+        .query_row(
+            r#"SELECT monthly_limit_usd_micros FROM llm_spending_limits WHERE profile_id = ?1"#,
+            params![profile_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(limit) = limit else {
+        return Ok(());
+    };
+    let spent = llm_month_cost_usd_micros(conn, month, profile_id)?;
+    if spent >= limit {
+        let scope = if profile_id.is_empty() {
+            "all profiles"
+        } else {
+            profile_id
+        };
+        return Err(anyhow!(
+            "llm_spending_limit_exceeded: {scope} spent {spent} of {limit} micro-USD in {month}"
+        ));
+    }
+    Ok(())
+}
@@ -8,7 +8,7 @@ use flate2::read::GzDecoder;
 use tar::Archive;
 use zip::ZipArchive;
 
-use super::{Embedder, DEFAULT_EMBED_DIM, PRODUCTION_MODEL_NAME};
+use super::{Embedder, Reranker, DEFAULT_EMBED_DIM, PRODUCTION_MODEL_NAME, RERANKER_MODEL_NAME};
 
 const ONNXRUNTIME_VERSION: &str = "1.23.0";
 
@@ -109,6 +109,109 @@ impl Embedder for FastEmbedder {
     }
 }
 
+const RERANKER_HF_REPO_DIR: &str = "models--jinaai--jina-reranker-v2-base-multilingual";
+const RERANKER_MODEL_FILE: &str = "onnx/model.onnx";
+
+#[derive(Clone)]
+pub struct FastReranker {
+    inner: Arc<Mutex<fastembed::TextRerank>>,
+}
+
+impl FastReranker {
+    pub fn get_or_try_init(app_dir: &Path) -> Result<Self> {
+        static CACHE: OnceLock<Mutex<Option<FastReranker>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(|| Mutex::new(None));
+
+        {
+            let guard = match cache.lock() {
+                Ok(g) => g,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            if let Some(existing) = guard.as_ref() {
+                return Ok(existing.clone());
+            }
+        }
+
+        let reranker =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| Self::try_new(app_dir)))
+                .map_err(|p| {
+                    anyhow!(
+                        "fastembed reranker init panicked: {}",
+                        panic_payload_to_string(&p)
+                    )
+                })??;
+
+        let mut guard = match cache.lock() {
+            Ok(g) => g,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *guard = Some(reranker.clone());
+        Ok(reranker)
+    }
+
+    /// Downloads the model into the fastembed cache on first use.
+    pub fn try_new(app_dir: &Path) -> Result<Self> {
+        ensure_onnxruntime_loaded(app_dir)?;
+
+        let cache_dir = app_dir.join("models").join("fastembed");
+        fs::create_dir_all(&cache_dir)?;
+
+        let options = fastembed::RerankInitOptions::new(
+            fastembed::RerankerModel::JINARerankerV2BaseMultiligual,
+        )
+        .with_cache_dir(cache_dir)
+        .with_show_download_progress(false);
+        let model = fastembed::TextRerank::try_new(options).context("fastembed: init reranker")?;
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(model)),
+        })
+    }
+
+    /// Whether a model snapshot is already in the fastembed cache, so loading
+    /// it will not hit the network.
+    pub fn is_installed(app_dir: &Path) -> bool {
+        let snapshots = app_dir
+            .join("models")
+            .join("fastembed")
+            .join(RERANKER_HF_REPO_DIR)
+            .join("snapshots");
+        let Ok(entries) = fs::read_dir(snapshots) else {
+            return false;
+        };
+        entries.filter_map(|e| e.ok()).any(|e| {
+            let dir = e.path();
+            dir.join(RERANKER_MODEL_FILE).is_file() && dir.join("tokenizer.json").is_file()
+        })
+    }
+}
+
+impl Reranker for FastReranker {
+    fn model_name(&self) -> &str {
+        RERANKER_MODEL_NAME
+    }
+
+    fn score(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        let mut model = match self.inner.lock() {
+            Ok(g) => g,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let documents: Vec<&str> = documents.iter().map(|d| d.as_str()).collect();
+        let results = model
+            .rerank(query, &documents, false, None)
+            .context("fastembed: rerank")?;
+
+        // Results come back sorted by score; the model emits logits.
+        let mut scores = vec![0.0f32; documents.len()];
+        for result in results {
+            if let Some(slot) = scores.get_mut(result.index) {
+                *slot = 1.0 / (1.0 + (-result.score).exp());
+            }
+        }
+        Ok(scores)
+    }
+}
+
 fn ensure_onnxruntime_loaded(app_dir: &Path) -> Result<()> {
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     enum InitState {
@@ -1,8 +1,11 @@
+use std::path::Path;
+
 use anyhow::Result;
 
 pub const DEFAULT_EMBED_DIM: usize = 384;
 pub const DEFAULT_MODEL_NAME: &str = "secondloop-default-embed-v0";
 pub const PRODUCTION_MODEL_NAME: &str = "fastembed:intfloat/multilingual-e5-small";
+pub const RERANKER_MODEL_NAME: &str = "fastembed:jinaai/jina-reranker-v2-base-multilingual";
 
 pub mod brok;
 pub use brok::{brok_embeddings_url, BrokEmbedder};
@@ -19,10 +22,76 @@ mod fastembed;
     any(target_os = "windows", target_os = "macos", target_os = "linux"),
     not(frb_expand)
 ))]
-pub use fastembed::FastEmbedder;
+pub use fastembed::{FastEmbedder, FastReranker};
 
 pub trait Embedder {
     fn model_name(&self) -> &str;
     fn dim(&self) -> usize;
     fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
 }
+
+/// Cross-encoder that scores how well each document answers a query.
+pub trait Reranker {
+    fn model_name(&self) -> &str;
+    /// One relevance score per document, in `0..=1`.
+    fn score(&self, query: &str, documents: &[String]) -> Result<Vec<f32>>;
+}
+
+/// The local reranker, if its model has been installed with
+/// `install_reranker`. Never downloads anything, and returns `None` when the
+/// model or onnxruntime cannot be loaded.
+#[cfg(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+))]
+pub fn installed_reranker(app_dir: &Path) -> Option<Box<dyn Reranker>> {
+    if !FastReranker::is_installed(app_dir) {
+        return None;
+    }
+    FastReranker::get_or_try_init(app_dir)
+        .ok()
+        .map(|reranker| Box::new(reranker) as Box<dyn Reranker>)
+}
+
+#[cfg(not(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+)))]
+pub fn installed_reranker(_app_dir: &Path) -> Option<Box<dyn Reranker>> {
+    None
+}
+
+#[cfg(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+))]
+pub fn is_reranker_installed(app_dir: &Path) -> bool {
+    FastReranker::is_installed(app_dir)
+}
+
+#[cfg(not(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+)))]
+pub fn is_reranker_installed(_app_dir: &Path) -> bool {
+    false
+}
+
+/// Downloads the reranker model (and onnxruntime) into `app_dir`.
+#[cfg(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+))]
+pub fn install_reranker(app_dir: &Path) -> Result<()> {
+    FastReranker::get_or_try_init(app_dir).map(|_| ())
+}
+
+#[cfg(not(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+)))]
+pub fn install_reranker(_app_dir: &Path) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "local reranker is not supported on this platform"
+    ))
+}
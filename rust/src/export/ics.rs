@@ -0,0 +1,471 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+use sha2::{Digest, Sha256};
+
+use crate::db;
+use crate::import::ics::ics_uid_for_id;
+
+pub const ICS_PRODID: &str = "-//SecondLoop//SecondLoop//EN";
+pub const ICS_ITEM_KIND_EVENT: &str = "event";
+pub const ICS_ITEM_KIND_TODO: &str = "todo";
+const ICS_MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcsExportSummary {
+    pub events: u64,
+    pub todos: u64,
+}
+
+/// Appends `line` folded to 75 octets (RFC 5545 3.1) and CRLF-terminated.
+fn push_folded(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > ICS_MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn escape_ics_text(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn utc_stamp(at_ms: i64) -> Result<String> {
+    let wall_clock = db::timezone_wall_clock(at_ms, None)?;
+    Ok(format!("{}Z", wall_stamp(wall_clock)))
+}
+
+fn wall_stamp(wall_clock: time::PrimitiveDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        wall_clock.year(),
+        u8::from(wall_clock.month()),
+        wall_clock.day(),
+        wall_clock.hour(),
+        wall_clock.minute(),
+        wall_clock.second()
+    )
+}
+
+/// IANA zone to write a `TZID` for; unknown names and UTC use the `Z` form.
+fn export_timezone(tz: Option<&str>) -> Option<&'static str> {
+    db::known_timezone_name(tz?).filter(|name| !matches!(*name, "UTC" | "Etc/UTC"))
+}
+
+/// A DATE-TIME property, in local time with `TZID` when `tz` is a known
+/// zone. No `VTIMEZONE` is emitted; calendar clients resolve IANA ids.
+fn datetime_line(name: &str, at_ms: i64, tz: Option<&str>) -> Result<String> {
+    match export_timezone(tz) {
+        Some(tz) => Ok(format!(
+            "{name};TZID={tz}:{}",
+            wall_stamp(db::timezone_wall_clock(at_ms, Some(tz))?)
+        )),
+        None => Ok(format!("{name}:{}", utc_stamp(at_ms)?)),
+    }
+}
+
+fn todo_status_to_ics(status: &str) -> &'static str {
+    match status {
+        "in_progress" => "IN-PROCESS",
+        "done" => "COMPLETED",
+        "dismissed" => "CANCELLED",
+        _ => "NEEDS-ACTION",
+    }
+}
+
+fn join_json_values(value: &serde_json::Value) -> Option<String> {
+    let items = value.as_array()?;
+    let parts = items
+        .iter()
+        .map(|item| match item {
+            serde_json::Value::String(s) => s.trim().to_ascii_uppercase(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>();
+    (!parts.is_empty()).then(|| parts.join(","))
+}
+
+/// `RRULE` value and `EXDATE` instants for a recurrence rule JSON.
+fn recurrence_rule_to_rrule(rule_json: &str) -> Result<(String, Vec<i64>)> {
+    let rule: serde_json::Value =
+        serde_json::from_str(rule_json).map_err(|e| anyhow!("invalid recurrence rule: {e}"))?;
+    let freq = rule["freq"]
+        .as_str()
+        .ok_or_else(|| anyhow!("recurrence rule has no freq"))?;
+
+    let mut parts = vec![format!("FREQ={}", freq.trim().to_ascii_uppercase())];
+    if let Some(interval) = rule["interval"].as_i64().filter(|interval| *interval > 1) {
+        parts.push(format!("INTERVAL={interval}"));
+    }
+    if let Some(count) = rule["count"].as_i64() {
+        parts.push(format!("COUNT={count}"));
+    }
+    if let Some(until_ms) = rule["until_ms"].as_i64() {
+        parts.push(format!("UNTIL={}", utc_stamp(until_ms)?));
+    }
+    for (field, name) in [
+        ("byday", "BYDAY"),
+        ("bymonthday", "BYMONTHDAY"),
+        ("bymonth", "BYMONTH"),
+    ] {
+        if let Some(values) = join_json_values(&rule[field]) {
+            parts.push(format!("{name}={values}"));
+        }
+    }
+    if let Some(week_start) = rule["wkst"].as_str() {
+        parts.push(format!("WKST={}", week_start.trim().to_ascii_uppercase()));
+    }
+
+    let exdates_ms = rule["exdates_ms"]
+        .as_array()
+        .map(|values| values.iter().filter_map(|v| v.as_i64()).collect())
+        .unwrap_or_default();
+    Ok((parts.join(";"), exdates_ms))
+}
+
+/// Series id and whether the todo is the series' newest occurrence.
+fn load_recurrence_series_by_todo(conn: &Connection) -> Result<HashMap<String, (String, bool)>> {
+    let mut stmt = conn.prepare(
+        r#"
+SELECT r.todo_id, r.series_id,
+       r.occurrence_index = (
+         SELECT MAX(r2.occurrence_index)
+         FROM todo_recurrences r2
+         WHERE r2.series_id = r.series_id
+       )
+FROM todo_recurrences r
+"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut out = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let todo_id: String = row.get(0)?;
+        let series_id: String = row.get(1)?;
+        let is_latest: bool = row.get(2)?;
+        out.insert(todo_id, (series_id, is_latest));
+    }
+    Ok(out)
+}
+
+/// One event or todo rendered as calendar components (a recurring event is
+/// followed by its moved occurrences), without `DTSTAMP` so the text only
+/// changes when the item does.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcsItem {
+    /// `event` or `todo`.
+    pub kind: String,
+    /// Event id, todo id, or series id for a recurring todo.
+    pub item_id: String,
+    pub uid: String,
+    pub updated_at_ms: i64,
+    pub component: String,
+}
+
+impl IcsItem {
+    pub fn content_sha256(&self) -> String {
+        let digest = Sha256::digest(self.component.as_bytes());
+        let mut out = String::with_capacity(64);
+        for b in digest {
+            use std::fmt::Write;
+            let _ = write!(&mut out, "{:02x}", b);
+        }
+        out
+    }
+}
+
+fn event_item(conn: &Connection, key: &[u8; 32], event: &db::Event) -> Result<IcsItem> {
+    let recurrence = db::get_event_recurrence(conn, &event.id)?;
+    let rule_json = recurrence
+        .as_ref()
+        .and_then(|recurrence| recurrence.rule_json.as_deref());
+    let exceptions = match rule_json {
+        Some(_) => db::list_event_exceptions(conn, key, &event.id)?,
+        None => Vec::new(),
+    };
+    // A rule's own zone wins over the event's, as it does for expansion.
+    let rule_tz = rule_json
+        .and_then(|rule_json| serde_json::from_str::<serde_json::Value>(rule_json).ok())
+        .and_then(|rule| rule["tz"].as_str().map(str::to_string));
+    let tz = Some(rule_tz.as_deref().unwrap_or(event.tz.as_str()));
+
+    // Changes to the rule or an occurrence count as changes to the event.
+    let updated_at_ms = exceptions
+        .iter()
+        .map(|exception| exception.updated_at_ms)
+        .chain(
+            recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.updated_at_ms),
+        )
+        .fold(event.updated_at_ms, i64::max);
+
+    let uid = ics_uid_for_id(&event.id);
+    let mut out = String::new();
+    push_folded(&mut out, "BEGIN:VEVENT");
+    push_folded(&mut out, &format!("UID:{uid}"));
+    push_folded(
+        &mut out,
+        &format!("SUMMARY:{}", escape_ics_text(&event.title)),
+    );
+    push_folded(&mut out, &datetime_line("DTSTART", event.start_at_ms, tz)?);
+    push_folded(&mut out, &datetime_line("DTEND", event.end_at_ms, tz)?);
+    if let Some(rule_json) = rule_json {
+        let (rrule, mut exdates_ms) = recurrence_rule_to_rrule(rule_json)?;
+        push_folded(&mut out, &format!("RRULE:{rrule}"));
+        exdates_ms.extend(
+            exceptions
+                .iter()
+                .filter(|exception| exception.kind == db::EVENT_EXCEPTION_CANCELLED)
+                .map(|exception| exception.occurrence_start_ms),
+        );
+        exdates_ms.sort_unstable();
+        exdates_ms.dedup();
+        for exdate_ms in exdates_ms {
+            push_folded(&mut out, &datetime_line("EXDATE", exdate_ms, tz)?);
+        }
+    }
+    push_folded(
+        &mut out,
+        &format!("CREATED:{}", utc_stamp(event.created_at_ms)?),
+    );
+    push_folded(
+        &mut out,
+        &format!("LAST-MODIFIED:{}", utc_stamp(updated_at_ms)?),
+    );
+    push_folded(&mut out, "END:VEVENT");
+
+    // Moved occurrences follow the series as overrides sharing its UID.
+    for exception in exceptions
+        .iter()
+        .filter(|exception| exception.kind == db::EVENT_EXCEPTION_MOVED)
+    {
+        let (Some(start_at_ms), Some(end_at_ms)) = (exception.start_at_ms, exception.end_at_ms)
+        else {
+            continue;
+        };
+        let title = exception.title.as_deref().unwrap_or(&event.title);
+        push_folded(&mut out, "BEGIN:VEVENT");
+        push_folded(&mut out, &format!("UID:{uid}"));
+        push_folded(
+            &mut out,
+            &datetime_line("RECURRENCE-ID", exception.occurrence_start_ms, tz)?,
+        );
+        push_folded(&mut out, &format!("SUMMARY:{}", escape_ics_text(title)));
+        push_folded(&mut out, &datetime_line("DTSTART", start_at_ms, tz)?);
+        push_folded(&mut out, &datetime_line("DTEND", end_at_ms, tz)?);
+        push_folded(
+            &mut out,
+            &format!("LAST-MODIFIED:{}", utc_stamp(exception.updated_at_ms)?),
+        );
+        push_folded(&mut out, "END:VEVENT");
+    }
+
+    Ok(IcsItem {
+        kind: ICS_ITEM_KIND_EVENT.to_string(),
+        item_id: event.id.clone(),
+        uid,
+        updated_at_ms,
+        component: out,
+    })
+}
+
+fn todo_item(conn: &Connection, todo: &db::Todo, series_id: Option<&str>) -> Result<IcsItem> {
+    let todo_tz = db::get_todo_timezone(conn, &todo.id)?;
+    let rule_json = match series_id {
+        Some(_) => db::get_todo_recurrence_rule_json_from_occurrence(conn, &todo.id)?,
+        None => None,
+    };
+    let recurrence = match rule_json.as_deref() {
+        Some(rule_json) => {
+            let rule_tz = serde_json::from_str::<serde_json::Value>(rule_json)
+                .ok()
+                .and_then(|rule| rule["tz"].as_str().map(str::to_string));
+            Some((recurrence_rule_to_rrule(rule_json)?, rule_tz))
+        }
+        None => None,
+    };
+    // A rule's own zone wins over the todo's, as it does for spawning.
+    let tz = recurrence
+        .as_ref()
+        .and_then(|(_, rule_tz)| rule_tz.clone())
+        .or(todo_tz);
+
+    let item_id = series_id.unwrap_or(&todo.id);
+    let uid = ics_uid_for_id(item_id);
+    let mut out = String::new();
+    push_folded(&mut out, "BEGIN:VTODO");
+    push_folded(&mut out, &format!("UID:{uid}"));
+    push_folded(
+        &mut out,
+        &format!("SUMMARY:{}", escape_ics_text(&todo.title)),
+    );
+    push_folded(
+        &mut out,
+        &format!("STATUS:{}", todo_status_to_ics(&todo.status)),
+    );
+    if let Some(due_at_ms) = todo.due_at_ms {
+        if recurrence.is_some() {
+            // RRULE is anchored on DTSTART.
+            push_folded(
+                &mut out,
+                &datetime_line("DTSTART", due_at_ms, tz.as_deref())?,
+            );
+        }
+        push_folded(&mut out, &datetime_line("DUE", due_at_ms, tz.as_deref())?);
+        if let Some(((rrule, exdates_ms), _)) = &recurrence {
+            push_folded(&mut out, &format!("RRULE:{rrule}"));
+            for exdate_ms in exdates_ms {
+                push_folded(
+                    &mut out,
+                    &datetime_line("EXDATE", *exdate_ms, tz.as_deref())?,
+                );
+            }
+        }
+    }
+    if todo.status == "done" {
+        push_folded(
+            &mut out,
+            &format!("COMPLETED:{}", utc_stamp(todo.updated_at_ms)?),
+        );
+    }
+    push_folded(
+        &mut out,
+        &format!("CREATED:{}", utc_stamp(todo.created_at_ms)?),
+    );
+    push_folded(
+        &mut out,
+        &format!("LAST-MODIFIED:{}", utc_stamp(todo.updated_at_ms)?),
+    );
+    push_folded(&mut out, "END:VTODO");
+    Ok(IcsItem {
+        kind: ICS_ITEM_KIND_TODO.to_string(),
+        item_id: item_id.to_string(),
+        uid,
+        updated_at_ms: todo.updated_at_ms,
+        component: out,
+    })
+}
+
+/// Every event and todo as an [`IcsItem`]. Recurring todos are exported once
+/// per series: the newest occurrence carries the series UID and its `RRULE`,
+/// older occurrences are left out.
+pub fn list_ics_items(conn: &Connection, key: &[u8; 32]) -> Result<Vec<IcsItem>> {
+    let mut out = Vec::new();
+    for event in db::list_events(conn, key)? {
+        out.push(event_item(conn, key, &event)?);
+    }
+
+    let series_by_todo = load_recurrence_series_by_todo(conn)?;
+    for todo in db::list_todos(conn, key)? {
+        let series_id = match series_by_todo.get(&todo.id) {
+            Some((_, false)) => continue,
+            Some((series_id, true)) => Some(series_id.as_str()),
+            None => None,
+        };
+        out.push(todo_item(conn, &todo, series_id)?);
+    }
+    Ok(out)
+}
+
+/// The [`IcsItem`] for one `kind`/`item_id` pair as produced by
+/// [`list_ics_items`], or `None` when there is no such item.
+pub fn get_ics_item(
+    conn: &Connection,
+    key: &[u8; 32],
+    kind: &str,
+    item_id: &str,
+) -> Result<Option<IcsItem>> {
+    if kind == ICS_ITEM_KIND_EVENT {
+        return match db::get_event(conn, key, item_id) {
+            Ok(event) => Ok(Some(event_item(conn, key, &event)?)),
+            Err(_) => Ok(None),
+        };
+    }
+
+    if let Some(todo_id) = db::get_latest_todo_id_in_recurrence_series(conn, item_id)? {
+        let todo = db::get_todo(conn, key, &todo_id)?;
+        return Ok(Some(todo_item(conn, &todo, Some(item_id))?));
+    }
+    // Occurrences of a series are only exported through the series.
+    if db::get_todo_recurrence_rule_json(conn, item_id)?.is_some() {
+        return Ok(None);
+    }
+    match db::get_todo(conn, key, item_id) {
+        Ok(todo) => Ok(Some(todo_item(conn, &todo, None)?)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Wraps items into a VCALENDAR document, stamping each with `now_ms`.
+pub fn ics_calendar_document(items: &[&IcsItem], now_ms: i64) -> Result<String> {
+    let dtstamp = format!("DTSTAMP:{}\r\n", utc_stamp(now_ms)?);
+    let mut out = String::new();
+    push_folded(&mut out, "BEGIN:VCALENDAR");
+    push_folded(&mut out, "VERSION:2.0");
+    push_folded(&mut out, &format!("PRODID:{ICS_PRODID}"));
+    push_folded(&mut out, "CALSCALE:GREGORIAN");
+    for item in items {
+        // DTSTAMP goes right after each BEGIN line.
+        for line in item.component.split_inclusive("\r\n") {
+            out.push_str(line);
+            if line.starts_with("BEGIN:VEVENT") || line.starts_with("BEGIN:VTODO") {
+                out.push_str(&dtstamp);
+            }
+        }
+    }
+    push_folded(&mut out, "END:VCALENDAR");
+    Ok(out)
+}
+
+/// Renders all events and todos as one iCalendar document. Events keep their
+/// zone via `TZID`; todos use their rule's or their own zone.
+pub fn export_ics(
+    conn: &Connection,
+    key: &[u8; 32],
+    now_ms: i64,
+) -> Result<(String, IcsExportSummary)> {
+    let items = list_ics_items(conn, key)?;
+    let events = items
+        .iter()
+        .filter(|item| item.kind == ICS_ITEM_KIND_EVENT)
+        .count() as u64;
+    let summary = IcsExportSummary {
+        events,
+        todos: items.len() as u64 - events,
+    };
+    let text = ics_calendar_document(&items.iter().collect::<Vec<_>>(), now_ms)?;
+    Ok((text, summary))
+}
+
+pub fn export_ics_file(
+    conn: &Connection,
+    key: &[u8; 32],
+    out_path: &Path,
+    now_ms: i64,
+) -> Result<IcsExportSummary> {
+    let (text, summary) = export_ics(conn, key, now_ms)?;
+    if let Some(parent) = out_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(out_path, text).map_err(|e| anyhow!("failed to write ics file: {e}"))?;
+    Ok(summary)
+}
@@ -0,0 +1,938 @@
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+use crate::crypto::decrypt_bytes;
+use crate::db;
+
+/// Bump when a stream gains/loses fields or changes meaning.
+///
+/// 2: tag parents, `tag_rules`, todo timezones and parents,
+/// `todo_dependencies`, `event_recurrences`, `event_exceptions`, the review
+/// streams and `message_citations`.
+/// 3: `prompt_templates`.
+/// 4: `llm_model_prices`.
+pub const JSONL_EXPORT_SCHEMA_VERSION: i64 = 4;
+pub const JSONL_EXPORT_FORMAT: &str = "secondloop.jsonl";
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Export order; referenced streams come before the streams referencing them.
+pub const JSONL_EXPORT_STREAMS: &[&str] = &[
+    "conversations",
+    "messages",
+    "message_citations",
+    "tags",
+    "tag_rules",
+    "message_tags",
+    "attachments",
+    "attachment_annotations",
+    "message_attachments",
+    "todos",
+    "todo_activities",
+    "todo_activity_attachments",
+    "todo_series",
+    "todo_recurrences",
+    "todo_dependencies",
+    "events",
+    "event_recurrences",
+    "event_exceptions",
+    "review_items",
+    "review_activities",
+    "prompt_templates",
+    "llm_model_prices",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonlStreamCount {
+    pub stream: String,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct JsonlExportSummary {
+    pub schema_version: i64,
+    pub exported_at_ms: i64,
+    pub streams: Vec<JsonlStreamCount>,
+}
+
+#[derive(Clone, Debug)]
+pub struct JsonlExportVerification {
+    pub schema_version: i64,
+    pub streams: Vec<JsonlStreamCount>,
+    pub problems: Vec<String>,
+}
+
+struct StreamWriter {
+    out: BufWriter<File>,
+    count: u64,
+}
+
+impl StreamWriter {
+    fn create(dir: &Path, stream: &str) -> Result<Self> {
+        let file = File::create(dir.join(format!("{stream}.jsonl")))?;
+        Ok(Self {
+            out: BufWriter::new(file),
+            count: 0,
+        })
+    }
+
+    fn write(&mut self, value: &Value) -> Result<()> {
+        serde_json::to_writer(&mut self.out, value)?;
+        self.out.write_all(b"\n")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<u64> {
+        self.out.flush()?;
+        Ok(self.count)
+    }
+}
+
+fn decrypt_string(key: &[u8; 32], blob: &[u8], aad: &[u8], what: &str) -> Result<String> {
+    let bytes = decrypt_bytes(key, blob, aad)?;
+    String::from_utf8(bytes).map_err(|_| anyhow!("{what} is not valid utf-8"))
+}
+
+fn decrypt_optional_json(key: &[u8; 32], blob: Option<Vec<u8>>, aad: &str) -> Result<Value> {
+    let Some(blob) = blob else {
+        return Ok(Value::Null);
+    };
+    let bytes = decrypt_bytes(key, &blob, aad.as_bytes())?;
+    Ok(serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+fn export_conversations(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, title, created_at, updated_at FROM conversations ORDER BY created_at, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let title_blob: Vec<u8> = row.get(1)?;
+        let title = decrypt_string(
+            key,
+            &title_blob,
+            b"conversation.title",
+            "conversation title",
+        )?;
+        w.write(&json!({
+            "id": id,
+            "title": title,
+            "created_at_ms": row.get::<_, i64>(2)?,
+            "updated_at_ms": row.get::<_, i64>(3)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_messages(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, conversation_id, role, content, created_at,
+                  COALESCE(updated_at, created_at), COALESCE(is_deleted, 0), COALESCE(is_memory, 1)
+           FROM messages
+           ORDER BY created_at, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let content_blob: Vec<u8> = row.get(3)?;
+        let content = decrypt_string(key, &content_blob, b"message.content", "message content")?;
+        w.write(&json!({
+            "id": row.get::<_, String>(0)?,
+            "conversation_id": row.get::<_, String>(1)?,
+            "role": row.get::<_, String>(2)?,
+            "content": content,
+            "created_at_ms": row.get::<_, i64>(4)?,
+            "updated_at_ms": row.get::<_, i64>(5)?,
+            "is_deleted": row.get::<_, i64>(6)? != 0,
+            "is_memory": row.get::<_, i64>(7)? != 0,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_tags(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, name, system_key, COALESCE(is_system, 0), color, parent_id,
+                  created_at_ms, updated_at_ms
+           FROM tags
+           ORDER BY created_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let name_blob: Vec<u8> = row.get(1)?;
+        let aad = format!("tag.name:{id}");
+        let name = decrypt_string(key, &name_blob, aad.as_bytes(), "tag name")?;
+        w.write(&json!({
+            "id": id,
+            "name": name,
+            "system_key": row.get::<_, Option<String>>(2)?,
+            "is_system": row.get::<_, i64>(3)? != 0,
+            "color": row.get::<_, Option<String>>(4)?,
+            "parent_id": row.get::<_, Option<String>>(5)?,
+            "created_at_ms": row.get::<_, i64>(6)?,
+            "updated_at_ms": row.get::<_, i64>(7)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_tag_rules(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    for rule in db::list_tag_rules(conn, key)? {
+        w.write(&json!({
+            "id": rule.id,
+            "name": rule.name,
+            "kind": rule.kind,
+            "pattern": rule.pattern,
+            "tag_id": rule.tag_id,
+            "enabled": rule.enabled,
+            "created_at_ms": rule.created_at_ms,
+            "updated_at_ms": rule.updated_at_ms,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_prompt_templates(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    for template in db::list_prompt_templates(conn, key)? {
+        w.write(&json!({
+            "id": template.id,
+            "name": template.name,
+            "purpose": template.purpose,
+            "body": template.body,
+            "created_at_ms": template.created_at_ms,
+            "updated_at_ms": template.updated_at_ms,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_llm_model_prices(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    for price in db::list_llm_model_prices(conn)? {
+        w.write(&json!({
+            "provider_type": price.provider_type,
+            "model_name": price.model_name,
+            "input_usd_per_million_tokens": price.input_usd_per_million_tokens,
+            "output_usd_per_million_tokens": price.output_usd_per_million_tokens,
+            "updated_at_ms": price.updated_at_ms,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_link_table(
+    conn: &Connection,
+    w: &mut StreamWriter,
+    sql: &str,
+    left: &str,
+    right: &str,
+) -> Result<()> {
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            left: row.get::<_, String>(0)?,
+            right: row.get::<_, String>(1)?,
+            "created_at_ms": row.get::<_, i64>(2)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_attachments(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT a.sha256, a.mime_type, a.byte_len, a.created_at,
+                  m.title, m.filenames, m.source_urls
+           FROM attachments a
+           LEFT JOIN attachment_metadata m ON m.attachment_sha256 = a.sha256
+           ORDER BY a.created_at, a.sha256"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let sha256: String = row.get(0)?;
+        let title = match row.get::<_, Option<Vec<u8>>>(4)? {
+            Some(blob) => Some(decrypt_string(
+                key,
+                &blob,
+                format!("attachment.metadata.title:{sha256}").as_bytes(),
+                "attachment title",
+            )?),
+            None => None,
+        };
+        let filenames = decrypt_optional_json(
+            key,
+            row.get(5)?,
+            &format!("attachment.metadata.filenames:{sha256}"),
+        )?;
+        let source_urls = decrypt_optional_json(
+            key,
+            row.get(6)?,
+            &format!("attachment.metadata.source_urls:{sha256}"),
+        )?;
+        w.write(&json!({
+            "sha256": sha256,
+            "mime_type": row.get::<_, String>(1)?,
+            "byte_len": row.get::<_, i64>(2)?,
+            "created_at_ms": row.get::<_, i64>(3)?,
+            "title": title,
+            "filenames": if filenames.is_null() { json!([]) } else { filenames },
+            "source_urls": if source_urls.is_null() { json!([]) } else { source_urls },
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_attachment_annotations(
+    conn: &Connection,
+    key: &[u8; 32],
+    w: &mut StreamWriter,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT attachment_sha256, status, lang, model_name, payload, created_at, updated_at
+           FROM attachment_annotations
+           ORDER BY created_at, attachment_sha256"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let sha256: String = row.get(0)?;
+        let lang: String = row.get(2)?;
+        let payload = decrypt_optional_json(
+            key,
+            row.get(4)?,
+            &format!("attachment.annotation:{sha256}:{lang}"),
+        )?;
+        w.write(&json!({
+            "attachment_sha256": sha256,
+            "status": row.get::<_, String>(1)?,
+            "lang": lang,
+            "model_name": row.get::<_, Option<String>>(3)?,
+            "payload": payload,
+            "created_at_ms": row.get::<_, i64>(5)?,
+            "updated_at_ms": row.get::<_, i64>(6)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_todos(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, title, due_at_ms, status, source_entry_id, created_at_ms, updated_at_ms,
+                  review_stage, next_review_at_ms, last_review_at_ms, tz,
+                  CASE WHEN EXISTS (SELECT 1 FROM todos p WHERE p.id = todos.parent_id)
+                       THEN parent_id END,
+                  complete_with_subtasks
+           FROM todos
+           ORDER BY created_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let title_blob: Vec<u8> = row.get(1)?;
+        let title = decrypt_string(key, &title_blob, b"todo.title", "todo title")?;
+        w.write(&json!({
+            "id": row.get::<_, String>(0)?,
+            "title": title,
+            "due_at_ms": row.get::<_, Option<i64>>(2)?,
+            "status": row.get::<_, String>(3)?,
+            "source_entry_id": row.get::<_, Option<String>>(4)?,
+            "created_at_ms": row.get::<_, i64>(5)?,
+            "updated_at_ms": row.get::<_, i64>(6)?,
+            "review_stage": row.get::<_, Option<i64>>(7)?,
+            "next_review_at_ms": row.get::<_, Option<i64>>(8)?,
+            "last_review_at_ms": row.get::<_, Option<i64>>(9)?,
+            "tz": row.get::<_, Option<String>>(10)?,
+            "parent_id": row.get::<_, Option<String>>(11)?,
+            "complete_with_subtasks": row.get::<_, i64>(12)? != 0,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_todo_activities(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, todo_id, type, from_status, to_status, content, source_message_id, created_at_ms
+           FROM todo_activities
+           ORDER BY created_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let content = match row.get::<_, Option<Vec<u8>>>(5)? {
+            Some(blob) => Some(decrypt_string(
+                key,
+                &blob,
+                format!("todo_activity.content:{id}").as_bytes(),
+                "todo activity content",
+            )?),
+            None => None,
+        };
+        w.write(&json!({
+            "id": id,
+            "todo_id": row.get::<_, String>(1)?,
+            "type": row.get::<_, String>(2)?,
+            "from_status": row.get::<_, Option<String>>(3)?,
+            "to_status": row.get::<_, Option<String>>(4)?,
+            "content": content,
+            "source_message_id": row.get::<_, Option<String>>(6)?,
+            "created_at_ms": row.get::<_, i64>(7)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_todo_series(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, rule_json, created_at_ms, updated_at_ms FROM todo_series ORDER BY created_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let rule_json: String = row.get(1)?;
+        w.write(&json!({
+            "id": row.get::<_, String>(0)?,
+            "rule": serde_json::from_str::<Value>(&rule_json).unwrap_or(Value::String(rule_json)),
+            "created_at_ms": row.get::<_, i64>(2)?,
+            "updated_at_ms": row.get::<_, i64>(3)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_todo_recurrences(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT todo_id, series_id, occurrence_index, created_at_ms, updated_at_ms
+           FROM todo_recurrences
+           ORDER BY series_id, occurrence_index, todo_id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            "todo_id": row.get::<_, String>(0)?,
+            "series_id": row.get::<_, String>(1)?,
+            "occurrence_index": row.get::<_, i64>(2)?,
+            "created_at_ms": row.get::<_, i64>(3)?,
+            "updated_at_ms": row.get::<_, i64>(4)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_events(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, title, start_at_ms, end_at_ms, tz, source_entry_id, created_at_ms, updated_at_ms
+           FROM events
+           ORDER BY start_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let title_blob: Vec<u8> = row.get(1)?;
+        let title = decrypt_string(key, &title_blob, b"event.title", "event title")?;
+        w.write(&json!({
+            "id": row.get::<_, String>(0)?,
+            "title": title,
+            "start_at_ms": row.get::<_, i64>(2)?,
+            "end_at_ms": row.get::<_, i64>(3)?,
+            "tz": row.get::<_, String>(4)?,
+            "source_entry_id": row.get::<_, Option<String>>(5)?,
+            "created_at_ms": row.get::<_, i64>(6)?,
+            "updated_at_ms": row.get::<_, i64>(7)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_todo_dependencies(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT d.todo_id, d.blocked_by_todo_id, d.created_at_ms, d.updated_at_ms
+           FROM todo_dependencies d
+           JOIN todos t ON t.id = d.todo_id
+           JOIN todos b ON b.id = d.blocked_by_todo_id
+           WHERE d.is_deleted = 0
+           ORDER BY d.todo_id, d.blocked_by_todo_id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            "todo_id": row.get::<_, String>(0)?,
+            "blocked_by_todo_id": row.get::<_, String>(1)?,
+            "created_at_ms": row.get::<_, i64>(2)?,
+            "updated_at_ms": row.get::<_, i64>(3)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_event_recurrences(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT event_id, rule_json, updated_at_ms
+           FROM event_recurrences
+           WHERE rule_json IS NOT NULL
+           ORDER BY event_id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let rule_json: String = row.get(1)?;
+        w.write(&json!({
+            "event_id": row.get::<_, String>(0)?,
+            "rule": serde_json::from_str::<Value>(&rule_json).unwrap_or(Value::String(rule_json)),
+            "updated_at_ms": row.get::<_, i64>(2)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_event_exceptions(conn: &Connection, key: &[u8; 32], w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT event_id, occurrence_start_ms, kind, title, start_at_ms, end_at_ms, updated_at_ms
+           FROM event_exceptions
+           WHERE kind != 'restored'
+           ORDER BY event_id, occurrence_start_ms"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let title = match row.get::<_, Option<Vec<u8>>>(3)? {
+            Some(blob) => Some(decrypt_string(
+                key,
+                &blob,
+                b"event.exception.title",
+                "event exception title",
+            )?),
+            None => None,
+        };
+        w.write(&json!({
+            "event_id": row.get::<_, String>(0)?,
+            "occurrence_start_ms": row.get::<_, i64>(1)?,
+            "kind": row.get::<_, String>(2)?,
+            "title": title,
+            "start_at_ms": row.get::<_, Option<i64>>(4)?,
+            "end_at_ms": row.get::<_, Option<i64>>(5)?,
+            "updated_at_ms": row.get::<_, i64>(6)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_message_citations(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT message_id, marker, source_kind, source_id, created_at_ms
+           FROM message_citations
+           ORDER BY message_id, marker"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            "message_id": row.get::<_, String>(0)?,
+            "marker": row.get::<_, i64>(1)?,
+            "source_kind": row.get::<_, String>(2)?,
+            "source_id": row.get::<_, String>(3)?,
+            "created_at_ms": row.get::<_, i64>(4)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_review_items(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT item_kind, item_id, is_active, ease_factor, interval_days, repetitions, lapses,
+                  due_at_ms, last_reviewed_at_ms, created_at_ms, updated_at_ms
+           FROM review_items
+           ORDER BY item_kind, item_id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            "item_kind": row.get::<_, String>(0)?,
+            "item_id": row.get::<_, String>(1)?,
+            "is_active": row.get::<_, i64>(2)? != 0,
+            "ease_factor": row.get::<_, f64>(3)?,
+            "interval_days": row.get::<_, i64>(4)?,
+            "repetitions": row.get::<_, i64>(5)?,
+            "lapses": row.get::<_, i64>(6)?,
+            "due_at_ms": row.get::<_, i64>(7)?,
+            "last_reviewed_at_ms": row.get::<_, Option<i64>>(8)?,
+            "created_at_ms": row.get::<_, i64>(9)?,
+            "updated_at_ms": row.get::<_, i64>(10)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_review_activities(conn: &Connection, w: &mut StreamWriter) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"SELECT id, item_kind, item_id, grade, ease_factor, interval_days, due_at_ms, reviewed_at_ms
+           FROM review_activities
+           ORDER BY reviewed_at_ms, id"#,
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        w.write(&json!({
+            "id": row.get::<_, String>(0)?,
+            "item_kind": row.get::<_, String>(1)?,
+            "item_id": row.get::<_, String>(2)?,
+            "grade": row.get::<_, i64>(3)?,
+            "ease_factor": row.get::<_, f64>(4)?,
+            "interval_days": row.get::<_, i64>(5)?,
+            "due_at_ms": row.get::<_, i64>(6)?,
+            "reviewed_at_ms": row.get::<_, i64>(7)?,
+        }))?;
+    }
+    Ok(())
+}
+
+fn export_stream(
+    conn: &Connection,
+    key: &[u8; 32],
+    stream: &str,
+    w: &mut StreamWriter,
+) -> Result<()> {
+    match stream {
+        "conversations" => export_conversations(conn, key, w),
+        "messages" => export_messages(conn, key, w),
+        "message_citations" => export_message_citations(conn, w),
+        "tags" => export_tags(conn, key, w),
+        "tag_rules" => export_tag_rules(conn, key, w),
+        "message_tags" => export_link_table(
+            conn,
+            w,
+            r#"SELECT message_id, tag_id, created_at_ms FROM message_tags ORDER BY message_id, tag_id"#,
+            "message_id",
+            "tag_id",
+        ),
+        "attachments" => export_attachments(conn, key, w),
+        "attachment_annotations" => export_attachment_annotations(conn, key, w),
+        "message_attachments" => export_link_table(
+            conn,
+            w,
+            r#"SELECT message_id, attachment_sha256, created_at FROM message_attachments ORDER BY message_id, attachment_sha256"#,
+            "message_id",
+            "attachment_sha256",
+        ),
+        "todos" => export_todos(conn, key, w),
+        "todo_activities" => export_todo_activities(conn, key, w),
+        "todo_activity_attachments" => export_link_table(
+            conn,
+            w,
+            r#"SELECT activity_id, attachment_sha256, created_at_ms FROM todo_activity_attachments ORDER BY activity_id, attachment_sha256"#,
+            "activity_id",
+            "attachment_sha256",
+        ),
+        "todo_series" => export_todo_series(conn, w),
+        "todo_recurrences" => export_todo_recurrences(conn, w),
+        "todo_dependencies" => export_todo_dependencies(conn, w),
+        "events" => export_events(conn, key, w),
+        "event_recurrences" => export_event_recurrences(conn, w),
+        "event_exceptions" => export_event_exceptions(conn, key, w),
+        "review_items" => export_review_items(conn, w),
+        "review_activities" => export_review_activities(conn, w),
+        "prompt_templates" => export_prompt_templates(conn, key, w),
+        "llm_model_prices" => export_llm_model_prices(conn, w),
+        other => Err(anyhow!("unknown export stream: {other}")),
+    }
+}
+
+/// Writes a decrypted, lossless export of the vault: `manifest.json` plus one
+/// `<stream>.jsonl` file per entity type.
+///
+/// The export reads from a single snapshot so streams stay consistent with
+/// each other even while sync is writing.
+pub fn export_vault_jsonl(
+    conn: &Connection,
+    key: &[u8; 32],
+    out_dir: &Path,
+    now_ms: i64,
+) -> Result<JsonlExportSummary> {
+    fs::create_dir_all(out_dir)?;
+
+    conn.execute_batch("BEGIN;")?;
+    let result = (|| -> Result<Vec<JsonlStreamCount>> {
+        let mut counts = Vec::with_capacity(JSONL_EXPORT_STREAMS.len());
+        for stream in JSONL_EXPORT_STREAMS {
+            let mut writer = StreamWriter::create(out_dir, stream)?;
+            export_stream(conn, key, stream, &mut writer)?;
+            counts.push(JsonlStreamCount {
+                stream: stream.to_string(),
+                count: writer.finish()?,
+            });
+        }
+        Ok(counts)
+    })();
+    conn.execute_batch("COMMIT;")?;
+    let streams = result?;
+
+    let db_user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let mut manifest_streams = serde_json::Map::new();
+    for s in &streams {
+        manifest_streams.insert(
+            s.stream.clone(),
+            json!({ "file": format!("{}.jsonl", s.stream), "count": s.count }),
+        );
+    }
+    let manifest = json!({
+        "format": JSONL_EXPORT_FORMAT,
+        "schema_version": JSONL_EXPORT_SCHEMA_VERSION,
+        "db_user_version": db_user_version,
+        "exported_at_ms": now_ms,
+        "streams": manifest_streams,
+    });
+    fs::write(
+        out_dir.join(MANIFEST_FILE_NAME),
+        serde_json::to_vec_pretty(&manifest)?,
+    )?;
+
+    Ok(JsonlExportSummary {
+        schema_version: JSONL_EXPORT_SCHEMA_VERSION,
+        exported_at_ms: now_ms,
+        streams,
+    })
+}
+
+fn read_stream(dir: &Path, stream: &str, problems: &mut Vec<String>) -> Result<Vec<Value>> {
+    let path = dir.join(format!("{stream}.jsonl"));
+    let file = match File::open(&path) {
+        Ok(f) => f,
+        Err(_) => {
+            problems.push(format!("{stream}: missing {stream}.jsonl"));
+            return Ok(Vec::new());
+        }
+    };
+
+    let mut out = Vec::new();
+    for (idx, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Value>(&line) {
+            Ok(v) if v.is_object() => out.push(v),
+            _ => problems.push(format!("{stream}:{}: not a JSON object", idx + 1)),
+        }
+    }
+    Ok(out)
+}
+
+fn str_field<'a>(row: &'a Value, field: &str) -> Option<&'a str> {
+    row.get(field).and_then(|v| v.as_str())
+}
+
+fn collect_ids(
+    stream: &str,
+    rows: &[Value],
+    field: &str,
+    problems: &mut Vec<String>,
+) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    for (idx, row) in rows.iter().enumerate() {
+        match str_field(row, field) {
+            Some(id) => {
+                if !ids.insert(id.to_string()) {
+                    problems.push(format!("{stream}: duplicate {field} {id}"));
+                }
+            }
+            None => problems.push(format!("{stream}:{}: missing {field}", idx + 1)),
+        }
+    }
+    ids
+}
+
+fn check_refs(
+    stream: &str,
+    rows: &[Value],
+    field: &str,
+    targets: &HashSet<String>,
+    target_stream: &str,
+    optional: bool,
+    problems: &mut Vec<String>,
+) {
+    for (idx, row) in rows.iter().enumerate() {
+        match str_field(row, field) {
+            Some(id) if !targets.contains(id) => problems.push(format!(
+                "{stream}:{}: {field} {id} not found in {target_stream}",
+                idx + 1
+            )),
+            Some(_) => {}
+            None if optional => {}
+            None => problems.push(format!("{stream}:{}: missing {field}", idx + 1)),
+        }
+    }
+}
+
+/// Checks an export directory: manifest/schema version, per-stream counts,
+/// unique keys and that every reference points at an exported entity.
+pub fn verify_vault_jsonl_export(dir: &Path) -> Result<JsonlExportVerification> {
+    let manifest_bytes = fs::read(dir.join(MANIFEST_FILE_NAME))
+        .map_err(|e| anyhow!("missing {MANIFEST_FILE_NAME}: {e}"))?;
+    let manifest: Value = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| anyhow!("invalid {MANIFEST_FILE_NAME}: {e}"))?;
+
+    // Streams of another schema version cannot be checked against this one.
+    let schema_version = manifest
+        .get("schema_version")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    if schema_version != JSONL_EXPORT_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "unsupported export schema_version {schema_version} (expected {JSONL_EXPORT_SCHEMA_VERSION})"
+        ));
+    }
+
+    let mut problems = Vec::new();
+    if manifest.get("format").and_then(|v| v.as_str()) != Some(JSONL_EXPORT_FORMAT) {
+        problems.push("manifest: unexpected format".to_string());
+    }
+
+    let mut loaded: Vec<(&str, Vec<Value>)> = Vec::with_capacity(JSONL_EXPORT_STREAMS.len());
+    let mut streams = Vec::with_capacity(JSONL_EXPORT_STREAMS.len());
+    for stream in JSONL_EXPORT_STREAMS {
+        let rows = read_stream(dir, stream, &mut problems)?;
+        let expected = manifest
+            .get("streams")
+            .and_then(|v| v.get(*stream))
+            .and_then(|v| v.get("count"))
+            .and_then(|v| v.as_u64());
+        if expected != Some(rows.len() as u64) {
+            problems.push(format!(
+                "{stream}: manifest count {expected:?} != {} rows",
+                rows.len()
+            ));
+        }
+        streams.push(JsonlStreamCount {
+            stream: stream.to_string(),
+            count: rows.len() as u64,
+        });
+        loaded.push((stream, rows));
+    }
+    let rows = |name: &str| -> &[Value] {
+        loaded
+            .iter()
+            .find(|(stream, _)| *stream == name)
+            .map(|(_, rows)| rows.as_slice())
+            .unwrap_or_default()
+    };
+
+    let conversations = collect_ids("conversations", rows("conversations"), "id", &mut problems);
+    let messages = collect_ids("messages", rows("messages"), "id", &mut problems);
+    let tags = collect_ids("tags", rows("tags"), "id", &mut problems);
+    collect_ids("tag_rules", rows("tag_rules"), "id", &mut problems);
+    collect_ids(
+        "prompt_templates",
+        rows("prompt_templates"),
+        "id",
+        &mut problems,
+    );
+    let attachments = collect_ids("attachments", rows("attachments"), "sha256", &mut problems);
+    let todos = collect_ids("todos", rows("todos"), "id", &mut problems);
+    let activities = collect_ids(
+        "todo_activities",
+        rows("todo_activities"),
+        "id",
+        &mut problems,
+    );
+    let series = collect_ids("todo_series", rows("todo_series"), "id", &mut problems);
+    let events = collect_ids("events", rows("events"), "id", &mut problems);
+
+    let checks: &[(&str, &str, &HashSet<String>, &str, bool)] = &[
+        (
+            "messages",
+            "conversation_id",
+            &conversations,
+            "conversations",
+            false,
+        ),
+        ("message_tags", "message_id", &messages, "messages", false),
+        (
+            "message_citations",
+            "message_id",
+            &messages,
+            "messages",
+            false,
+        ),
+        ("message_tags", "tag_id", &tags, "tags", false),
+        ("tags", "parent_id", &tags, "tags", true),
+        ("tag_rules", "tag_id", &tags, "tags", false),
+        (
+            "attachment_annotations",
+            "attachment_sha256",
+            &attachments,
+            "attachments",
+            false,
+        ),
+        (
+            "message_attachments",
+            "message_id",
+            &messages,
+            "messages",
+            false,
+        ),
+        (
+            "message_attachments",
+            "attachment_sha256",
+            &attachments,
+            "attachments",
+            false,
+        ),
+        ("todos", "source_entry_id", &messages, "messages", true),
+        ("todo_activities", "todo_id", &todos, "todos", false),
+        (
+            "todo_activities",
+            "source_message_id",
+            &messages,
+            "messages",
+            true,
+        ),
+        (
+            "todo_activity_attachments",
+            "activity_id",
+            &activities,
+            "todo_activities",
+            false,
+        ),
+        (
+            "todo_activity_attachments",
+            "attachment_sha256",
+            &attachments,
+            "attachments",
+            false,
+        ),
+        ("todos", "parent_id", &todos, "todos", true),
+        ("todo_recurrences", "todo_id", &todos, "todos", false),
+        (
+            "todo_recurrences",
+            "series_id",
+            &series,
+            "todo_series",
+            false,
+        ),
+        ("todo_dependencies", "todo_id", &todos, "todos", false),
+        (
+            "todo_dependencies",
+            "blocked_by_todo_id",
+            &todos,
+            "todos",
+            false,
+        ),
+        ("events", "source_entry_id", &messages, "messages", true),
+        ("event_recurrences", "event_id", &events, "events", false),
+        ("event_exceptions", "event_id", &events, "events", false),
+    ];
+    for (stream, field, targets, target_stream, optional) in checks {
+        check_refs(
+            stream,
+            rows(stream),
+            field,
+            targets,
+            target_stream,
+            *optional,
+            &mut problems,
+        );
+    }
+
+    Ok(JsonlExportVerification {
+        schema_version,
+        streams,
+        problems,
+    })
+}
@@ -33,7 +33,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.0.0-dev.38";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -849116207;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1827002556;
 
 // Section: executor
 
@@ -1056,6 +1056,92 @@ fn wire__crate__api__core__auth_validate_key_impl(
         },
     )
 }
+fn wire__crate__api__core__db_add_todo_dependency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_add_todo_dependency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_blocked_by_todo_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::db_add_todo_dependency(
+                        api_app_dir,
+                        api_key,
+                        api_todo_id,
+                        api_blocked_by_todo_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__db_agenda_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_agenda_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
+            let api_tz = <String>::sse_decode(&mut deserializer);
+            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::db_agenda_range(
+                        api_app_dir,
+                        api_key,
+                        api_start_at_ms_inclusive,
+                        api_end_at_ms_exclusive,
+                        api_tz,
+                        api_cursor,
+                        api_limit,
+                    )
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__core__db_append_todo_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1138,6 +1224,46 @@ fn wire__crate__api__core__db_backfill_cloud_media_backup_images_impl(
         },
     )
 }
+fn wire__crate__api__core__db_cancel_event_occurrence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_cancel_event_occurrence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_occurrence_start_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::db_cancel_event_occurrence(
+                        api_app_dir,
+                        api_key,
+                        api_event_id,
+                        api_occurrence_start_ms,
+                    )
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__core__db_clear_local_attachment_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1204,6 +1330,39 @@ fn wire__crate__api__core__db_cloud_media_backup_summary_impl(
         },
     )
 }
+fn wire__crate__api__core__db_count_semantic_parse_failure_reasons_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_count_semantic_parse_failure_reasons",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::db_count_semantic_parse_failure_reasons(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__core__db_create_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1372,6 +1531,46 @@ fn wire__crate__api__core__db_delete_embedding_profile_impl(
         },
     )
 }
+fn wire__crate__api__core__db_delete_llm_model_price_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_delete_llm_model_price",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_provider_type = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::db_delete_llm_model_price(
+                        api_app_dir,
+                        api_key,
+                        api_provider_type,
+                        api_model_name,
+                    )
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__core__db_delete_llm_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -1683,7 +1882,7 @@ fn wire__crate__api__core__db_get_active_embedding_model_name_impl(
         },
     )
 }
-fn wire__crate__api__core__db_get_message_by_id_impl(
+fn wire__crate__api__core__db_get_ask_ai_query_rewrite_enabled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1691,7 +1890,7 @@ fn wire__crate__api__core__db_get_message_by_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_get_message_by_id",
+            debug_name: "db_get_ask_ai_query_rewrite_enabled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1706,18 +1905,16 @@ fn wire__crate__api__core__db_get_message_by_id_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_get_message_by_id(api_app_dir, api_key, api_message_id)
+                    crate::api::core::db_get_ask_ai_query_rewrite_enabled(api_app_dir)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_get_or_create_device_id_impl(
+fn wire__crate__api__core__db_get_event_recurrence_rule_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1725,7 +1922,7 @@ fn wire__crate__api__core__db_get_or_create_device_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_get_or_create_device_id",
+            debug_name: "db_get_event_recurrence_rule_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1740,16 +1937,17 @@ fn wire__crate__api__core__db_get_or_create_device_id_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_get_or_create_device_id(api_app_dir)
+                    crate::api::core::db_get_event_recurrence_rule_json(api_app_dir, api_event_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
+fn wire__crate__api__core__db_get_message_by_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1757,7 +1955,7 @@ fn wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_get_or_create_loop_home_conversation",
+            debug_name: "db_get_message_by_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1773,16 +1971,17 @@ fn wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_get_or_create_loop_home_conversation(api_app_dir, api_key)
+                    crate::api::core::db_get_message_by_id(api_app_dir, api_key, api_message_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
+fn wire__crate__api__core__db_get_or_create_device_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1790,7 +1989,7 @@ fn wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_get_todo_recurrence_rule_json",
+            debug_name: "db_get_or_create_device_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1805,17 +2004,16 @@ fn wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_get_todo_recurrence_rule_json(api_app_dir, api_todo_id)
+                    crate::api::core::db_get_or_create_device_id(api_app_dir)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_insert_attachment_impl(
+fn wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1823,7 +2021,7 @@ fn wire__crate__api__core__db_insert_attachment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_insert_attachment",
+            debug_name: "db_get_or_create_loop_home_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1839,23 +2037,16 @@ fn wire__crate__api__core__db_insert_attachment_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_insert_attachment(
-                        api_app_dir,
-                        api_key,
-                        api_bytes,
-                        api_mime_type,
-                    )
+                    crate::api::core::db_get_or_create_loop_home_conversation(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_insert_message_impl(
+fn wire__crate__api__core__db_get_semantic_parse_job_failure_reason_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1863,7 +2054,7 @@ fn wire__crate__api__core__db_insert_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_insert_message",
+            debug_name: "db_get_semantic_parse_job_failure_reason",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1879,25 +2070,21 @@ fn wire__crate__api__core__db_insert_message_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_role = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_insert_message(
+                    crate::api::core::db_get_semantic_parse_job_failure_reason(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_role,
-                        api_content,
+                        api_message_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_link_attachment_to_message_impl(
+fn wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1905,7 +2092,7 @@ fn wire__crate__api__core__db_link_attachment_to_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_link_attachment_to_message",
+            debug_name: "db_get_todo_recurrence_rule_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1920,24 +2107,17 @@ fn wire__crate__api__core__db_link_attachment_to_message_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_link_attachment_to_message(
-                        api_app_dir,
-                        api_key,
-                        api_message_id,
-                        api_attachment_sha256,
-                    )
+                    crate::api::core::db_get_todo_recurrence_rule_json(api_app_dir, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
+fn wire__crate__api__core__db_get_todo_rollup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1945,7 +2125,7 @@ fn wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_link_attachment_to_todo_activity",
+            debug_name: "db_get_todo_rollup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1961,23 +2141,17 @@ fn wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_activity_id = <String>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_link_attachment_to_todo_activity(
-                        api_app_dir,
-                        api_key,
-                        api_activity_id,
-                        api_attachment_sha256,
-                    )
+                    crate::api::core::db_get_todo_rollup(api_app_dir, api_key, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_conversations_impl(
+fn wire__crate__api__core__db_get_todo_timezone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1985,7 +2159,7 @@ fn wire__crate__api__core__db_list_conversations_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_conversations",
+            debug_name: "db_get_todo_timezone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2000,17 +2174,17 @@ fn wire__crate__api__core__db_list_conversations_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_conversations(api_app_dir, api_key)
+                    crate::api::core::db_get_todo_timezone(api_app_dir, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_due_attachment_annotations_impl(
+fn wire__crate__api__core__db_insert_attachment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2018,7 +2192,7 @@ fn wire__crate__api__core__db_list_due_attachment_annotations_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_due_attachment_annotations",
+            debug_name: "db_insert_attachment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2034,23 +2208,23 @@ fn wire__crate__api__core__db_list_due_attachment_annotations_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_due_attachment_annotations(
+                    crate::api::core::db_insert_attachment(
                         api_app_dir,
                         api_key,
-                        api_now_ms,
-                        api_limit,
+                        api_bytes,
+                        api_mime_type,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_due_attachment_places_impl(
+fn wire__crate__api__core__db_insert_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2058,7 +2232,7 @@ fn wire__crate__api__core__db_list_due_attachment_places_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_due_attachment_places",
+            debug_name: "db_insert_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2074,23 +2248,25 @@ fn wire__crate__api__core__db_list_due_attachment_places_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_role = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_due_attachment_places(
+                    crate::api::core::db_insert_message(
                         api_app_dir,
                         api_key,
-                        api_now_ms,
-                        api_limit,
+                        api_conversation_id,
+                        api_role,
+                        api_content,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_due_cloud_media_backups_impl(
+fn wire__crate__api__core__db_link_attachment_to_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2098,7 +2274,7 @@ fn wire__crate__api__core__db_list_due_cloud_media_backups_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_due_cloud_media_backups",
+            debug_name: "db_link_attachment_to_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2114,23 +2290,23 @@ fn wire__crate__api__core__db_list_due_cloud_media_backups_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_due_cloud_media_backups(
+                    crate::api::core::db_link_attachment_to_message(
                         api_app_dir,
                         api_key,
-                        api_now_ms,
-                        api_limit,
+                        api_message_id,
+                        api_attachment_sha256,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
+fn wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2138,7 +2314,7 @@ fn wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_due_semantic_parse_jobs",
+            debug_name: "db_link_attachment_to_todo_activity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2154,23 +2330,23 @@ fn wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_activity_id = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_due_semantic_parse_jobs(
+                    crate::api::core::db_link_attachment_to_todo_activity(
                         api_app_dir,
                         api_key,
-                        api_now_ms,
-                        api_limit,
+                        api_activity_id,
+                        api_attachment_sha256,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_embedding_model_names_impl(
+fn wire__crate__api__core__db_list_conversations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2178,7 +2354,7 @@ fn wire__crate__api__core__db_list_embedding_model_names_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_embedding_model_names",
+            debug_name: "db_list_conversations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2197,13 +2373,13 @@ fn wire__crate__api__core__db_list_embedding_model_names_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_embedding_model_names(api_app_dir, api_key)
+                    crate::api::core::db_list_conversations(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_embedding_profiles_impl(
+fn wire__crate__api__core__db_list_due_attachment_annotations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2211,7 +2387,7 @@ fn wire__crate__api__core__db_list_embedding_profiles_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_embedding_profiles",
+            debug_name: "db_list_due_attachment_annotations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2227,16 +2403,23 @@ fn wire__crate__api__core__db_list_embedding_profiles_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_embedding_profiles(api_app_dir, api_key)
+                    crate::api::core::db_list_due_attachment_annotations(
+                        api_app_dir,
+                        api_key,
+                        api_now_ms,
+                        api_limit,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_events_impl(
+fn wire__crate__api__core__db_list_due_attachment_places_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2244,7 +2427,7 @@ fn wire__crate__api__core__db_list_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_events",
+            debug_name: "db_list_due_attachment_places",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2260,16 +2443,23 @@ fn wire__crate__api__core__db_list_events_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_events(api_app_dir, api_key)
+                    crate::api::core::db_list_due_attachment_places(
+                        api_app_dir,
+                        api_key,
+                        api_now_ms,
+                        api_limit,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_llm_profiles_impl(
+fn wire__crate__api__core__db_list_due_cloud_media_backups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2277,7 +2467,7 @@ fn wire__crate__api__core__db_list_llm_profiles_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_llm_profiles",
+            debug_name: "db_list_due_cloud_media_backups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2293,16 +2483,23 @@ fn wire__crate__api__core__db_list_llm_profiles_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_llm_profiles(api_app_dir, api_key)
+                    crate::api::core::db_list_due_cloud_media_backups(
+                        api_app_dir,
+                        api_key,
+                        api_now_ms,
+                        api_limit,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_message_attachments_impl(
+fn wire__crate__api__core__db_list_due_reviews_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2310,7 +2507,7 @@ fn wire__crate__api__core__db_list_message_attachments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_message_attachments",
+            debug_name: "db_list_due_reviews",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2326,21 +2523,23 @@ fn wire__crate__api__core__db_list_message_attachments_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_due_before_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_message_attachments(
+                    crate::api::core::db_list_due_reviews(
                         api_app_dir,
                         api_key,
-                        api_message_id,
+                        api_due_before_ms,
+                        api_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_messages_impl(
+fn wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2348,7 +2547,7 @@ fn wire__crate__api__core__db_list_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_messages",
+            debug_name: "db_list_due_semantic_parse_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2364,17 +2563,23 @@ fn wire__crate__api__core__db_list_messages_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_messages(api_app_dir, api_key, api_conversation_id)
+                    crate::api::core::db_list_due_semantic_parse_jobs(
+                        api_app_dir,
+                        api_key,
+                        api_now_ms,
+                        api_limit,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_messages_page_impl(
+fn wire__crate__api__core__db_list_embedding_model_names_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2382,7 +2587,7 @@ fn wire__crate__api__core__db_list_messages_page_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_messages_page",
+            debug_name: "db_list_embedding_model_names",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2398,27 +2603,16 @@ fn wire__crate__api__core__db_list_messages_page_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_before_created_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_before_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_messages_page(
-                        api_app_dir,
-                        api_key,
-                        api_conversation_id,
-                        api_before_created_at_ms,
-                        api_before_id,
-                        api_limit,
-                    )
+                    crate::api::core::db_list_embedding_model_names(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_recent_attachments_impl(
+fn wire__crate__api__core__db_list_embedding_profiles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2426,7 +2620,7 @@ fn wire__crate__api__core__db_list_recent_attachments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_recent_attachments",
+            debug_name: "db_list_embedding_profiles",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2442,17 +2636,16 @@ fn wire__crate__api__core__db_list_recent_attachments_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_recent_attachments(api_app_dir, api_key, api_limit)
+                    crate::api::core::db_list_embedding_profiles(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
+fn wire__crate__api__core__db_list_event_occurrences_in_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2460,7 +2653,7 @@ fn wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_semantic_parse_jobs_by_message_ids",
+            debug_name: "db_list_event_occurrences_in_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2476,21 +2669,23 @@ fn wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_semantic_parse_jobs_by_message_ids(
+                    crate::api::core::db_list_event_occurrences_in_range(
                         api_app_dir,
                         api_key,
-                        api_message_ids,
+                        api_start_at_ms_inclusive,
+                        api_end_at_ms_exclusive,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_todo_activities_impl(
+fn wire__crate__api__core__db_list_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2498,7 +2693,7 @@ fn wire__crate__api__core__db_list_todo_activities_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_todo_activities",
+            debug_name: "db_list_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2514,17 +2709,16 @@ fn wire__crate__api__core__db_list_todo_activities_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_todo_activities(api_app_dir, api_key, api_todo_id)
+                    crate::api::core::db_list_events(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_todo_activities_in_range_impl(
+fn wire__crate__api__core__db_list_llm_fallback_chain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2532,7 +2726,7 @@ fn wire__crate__api__core__db_list_todo_activities_in_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_todo_activities_in_range",
+            debug_name: "db_list_llm_fallback_chain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2548,23 +2742,16 @@ fn wire__crate__api__core__db_list_todo_activities_in_range_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
-            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_todo_activities_in_range(
-                        api_app_dir,
-                        api_key,
-                        api_start_at_ms_inclusive,
-                        api_end_at_ms_exclusive,
-                    )
+                    crate::api::core::db_list_llm_fallback_chain(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_todo_activity_attachments_impl(
+fn wire__crate__api__core__db_list_llm_model_prices_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2572,7 +2759,7 @@ fn wire__crate__api__core__db_list_todo_activity_attachments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_todo_activity_attachments",
+            debug_name: "db_list_llm_model_prices",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2588,21 +2775,16 @@ fn wire__crate__api__core__db_list_todo_activity_attachments_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_activity_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_todo_activity_attachments(
-                        api_app_dir,
-                        api_key,
-                        api_activity_id,
-                    )
+                    crate::api::core::db_list_llm_model_prices(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_todos_impl(
+fn wire__crate__api__core__db_list_llm_profiles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2610,7 +2792,7 @@ fn wire__crate__api__core__db_list_todos_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_todos",
+            debug_name: "db_list_llm_profiles",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2629,13 +2811,13 @@ fn wire__crate__api__core__db_list_todos_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_todos(api_app_dir, api_key)
+                    crate::api::core::db_list_llm_profiles(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_list_todos_created_in_range_impl(
+fn wire__crate__api__core__db_list_llm_spending_limits_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2643,7 +2825,7 @@ fn wire__crate__api__core__db_list_todos_created_in_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_todos_created_in_range",
+            debug_name: "db_list_llm_spending_limits",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2659,23 +2841,16 @@ fn wire__crate__api__core__db_list_todos_created_in_range_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
-            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_list_todos_created_in_range(
-                        api_app_dir,
-                        api_key,
-                        api_start_at_ms_inclusive,
-                        api_end_at_ms_exclusive,
-                    )
+                    crate::api::core::db_list_llm_spending_limits(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
+fn wire__crate__api__core__db_list_message_attachments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2683,7 +2858,7 @@ fn wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_attachment_annotation_failed",
+            debug_name: "db_list_message_attachments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2699,29 +2874,21 @@ fn wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_attempts = <i64>::sse_decode(&mut deserializer);
-            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_last_error = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_attachment_annotation_failed(
+                    crate::api::core::db_list_message_attachments(
                         api_app_dir,
                         api_key,
-                        api_attachment_sha256,
-                        api_attempts,
-                        api_next_retry_at_ms,
-                        api_last_error,
-                        api_now_ms,
+                        api_message_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
+fn wire__crate__api__core__db_list_message_citations_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2729,7 +2896,7 @@ fn wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_attachment_annotation_ok_json",
+            debug_name: "db_list_message_citations",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2744,30 +2911,17 @@ fn wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_lang = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_attachment_annotation_ok_json(
-                        api_app_dir,
-                        api_key,
-                        api_attachment_sha256,
-                        api_lang,
-                        api_model_name,
-                        api_payload_json,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_message_citations(api_app_dir, api_message_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_attachment_place_failed_impl(
+fn wire__crate__api__core__db_list_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2775,7 +2929,7 @@ fn wire__crate__api__core__db_mark_attachment_place_failed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_attachment_place_failed",
+            debug_name: "db_list_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2791,29 +2945,17 @@ fn wire__crate__api__core__db_mark_attachment_place_failed_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_attempts = <i64>::sse_decode(&mut deserializer);
-            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_last_error = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_attachment_place_failed(
-                        api_app_dir,
-                        api_key,
-                        api_attachment_sha256,
-                        api_attempts,
-                        api_next_retry_at_ms,
-                        api_last_error,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_messages(api_app_dir, api_key, api_conversation_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
+fn wire__crate__api__core__db_list_messages_page_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2821,7 +2963,7 @@ fn wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_attachment_place_ok_json",
+            debug_name: "db_list_messages_page",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2837,27 +2979,27 @@ fn wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_lang = <String>::sse_decode(&mut deserializer);
-            let api_payload_json = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_before_created_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_before_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_attachment_place_ok_json(
+                    crate::api::core::db_list_messages_page(
                         api_app_dir,
                         api_key,
-                        api_attachment_sha256,
-                        api_lang,
-                        api_payload_json,
-                        api_now_ms,
+                        api_conversation_id,
+                        api_before_created_at_ms,
+                        api_before_id,
+                        api_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
+fn wire__crate__api__core__db_list_recent_attachments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2865,7 +3007,7 @@ fn wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_cloud_media_backup_failed",
+            debug_name: "db_list_recent_attachments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2881,29 +3023,17 @@ fn wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_attempts = <i64>::sse_decode(&mut deserializer);
-            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_last_error = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_cloud_media_backup_failed(
-                        api_app_dir,
-                        api_key,
-                        api_attachment_sha256,
-                        api_attempts,
-                        api_next_retry_at_ms,
-                        api_last_error,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_recent_attachments(api_app_dir, api_key, api_limit)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
+fn wire__crate__api__core__db_list_review_activities_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2911,7 +3041,7 @@ fn wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_cloud_media_backup_uploaded",
+            debug_name: "db_list_review_activities",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2926,24 +3056,22 @@ fn wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_item_kind = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_cloud_media_backup_uploaded(
+                    crate::api::core::db_list_review_activities(
                         api_app_dir,
-                        api_key,
-                        api_attachment_sha256,
-                        api_now_ms,
+                        api_item_kind,
+                        api_item_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
+fn wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2951,7 +3079,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_canceled",
+            debug_name: "db_list_semantic_parse_jobs_by_message_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2967,23 +3095,21 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_canceled(
+                    crate::api::core::db_list_semantic_parse_jobs_by_message_ids(
                         api_app_dir,
                         api_key,
-                        api_message_id,
-                        api_now_ms,
+                        api_message_ids,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
+fn wire__crate__api__core__db_list_todo_activities_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2991,7 +3117,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_failed",
+            debug_name: "db_list_todo_activities",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3007,29 +3133,17 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_attempts = <i64>::sse_decode(&mut deserializer);
-            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_last_error = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_failed(
-                        api_app_dir,
-                        api_key,
-                        api_message_id,
-                        api_attempts,
-                        api_next_retry_at_ms,
-                        api_last_error,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_todo_activities(api_app_dir, api_key, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
+fn wire__crate__api__core__db_list_todo_activities_in_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3037,7 +3151,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_retry",
+            debug_name: "db_list_todo_activities_in_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3053,23 +3167,23 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_retry(
+                    crate::api::core::db_list_todo_activities_in_range(
                         api_app_dir,
                         api_key,
-                        api_message_id,
-                        api_now_ms,
+                        api_start_at_ms_inclusive,
+                        api_end_at_ms_exclusive,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
+fn wire__crate__api__core__db_list_todo_activity_attachments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3077,7 +3191,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_running",
+            debug_name: "db_list_todo_activity_attachments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3093,23 +3207,21 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_activity_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_running(
+                    crate::api::core::db_list_todo_activity_attachments(
                         api_app_dir,
                         api_key,
-                        api_message_id,
-                        api_now_ms,
+                        api_activity_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
+fn wire__crate__api__core__db_list_todo_blockers_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3117,7 +3229,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_succeeded",
+            debug_name: "db_list_todo_blockers",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3133,31 +3245,17 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_applied_action_kind = <String>::sse_decode(&mut deserializer);
-            let api_applied_todo_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_applied_todo_title = <Option<String>>::sse_decode(&mut deserializer);
-            let api_applied_prev_todo_status = <Option<String>>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_succeeded(
-                        api_app_dir,
-                        api_key,
-                        api_message_id,
-                        api_applied_action_kind,
-                        api_applied_todo_id,
-                        api_applied_todo_title,
-                        api_applied_prev_todo_status,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_todo_blockers(api_app_dir, api_key, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
+fn wire__crate__api__core__db_list_todo_subtasks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3165,7 +3263,7 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_mark_semantic_parse_job_undone",
+            debug_name: "db_list_todo_subtasks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3181,23 +3279,17 @@ fn wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_parent_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_mark_semantic_parse_job_undone(
-                        api_app_dir,
-                        api_key,
-                        api_message_id,
-                        api_now_ms,
-                    )
+                    crate::api::core::db_list_todo_subtasks(api_app_dir, api_key, api_parent_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_move_todo_activity_impl(
+fn wire__crate__api__core__db_list_todos_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3205,7 +3297,7 @@ fn wire__crate__api__core__db_move_todo_activity_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_move_todo_activity",
+            debug_name: "db_list_todos",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3221,23 +3313,16 @@ fn wire__crate__api__core__db_move_todo_activity_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_activity_id = <String>::sse_decode(&mut deserializer);
-            let api_to_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_move_todo_activity(
-                        api_app_dir,
-                        api_key,
-                        api_activity_id,
-                        api_to_todo_id,
-                    )
+                    crate::api::core::db_list_todos(api_app_dir, api_key)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_process_pending_message_embeddings_impl(
+fn wire__crate__api__core__db_list_todos_blocked_by_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3245,7 +3330,7 @@ fn wire__crate__api__core__db_process_pending_message_embeddings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_process_pending_message_embeddings",
+            debug_name: "db_list_todos_blocked_by",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3261,21 +3346,17 @@ fn wire__crate__api__core__db_process_pending_message_embeddings_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_process_pending_message_embeddings(
-                        api_app_dir,
-                        api_key,
-                        api_limit,
-                    )
+                    crate::api::core::db_list_todos_blocked_by(api_app_dir, api_key, api_todo_id)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
+fn wire__crate__api__core__db_list_todos_created_in_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3283,7 +3364,7 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_process_pending_todo_thread_embeddings",
+            debug_name: "db_list_todos_created_in_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3299,23 +3380,23 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
-            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
+            let api_start_at_ms_inclusive = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms_exclusive = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_process_pending_todo_thread_embeddings(
+                    crate::api::core::db_list_todos_created_in_range(
                         api_app_dir,
                         api_key,
-                        api_todo_limit,
-                        api_activity_limit,
+                        api_start_at_ms_inclusive,
+                        api_end_at_ms_exclusive,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
+fn wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3323,7 +3404,7 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_process_pending_todo_thread_embeddings_brok",
+            debug_name: "db_mark_attachment_annotation_failed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3339,23 +3420,29 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
-            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_attempts = <i64>::sse_decode(&mut deserializer);
+            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_last_error = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_process_pending_todo_thread_embeddings_brok(
+                    crate::api::core::db_mark_attachment_annotation_failed(
                         api_app_dir,
                         api_key,
-                        api_todo_limit,
-                        api_activity_limit,
+                        api_attachment_sha256,
+                        api_attempts,
+                        api_next_retry_at_ms,
+                        api_last_error,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gateway_impl(
+fn wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3363,7 +3450,7 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gatew
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_process_pending_todo_thread_embeddings_cloud_gateway",
+            debug_name: "db_mark_attachment_annotation_ok_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3379,29 +3466,29 @@ fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gatew
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
-            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
             let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_process_pending_todo_thread_embeddings_cloud_gateway(
+                    crate::api::core::db_mark_attachment_annotation_ok_json(
                         api_app_dir,
                         api_key,
-                        api_todo_limit,
-                        api_activity_limit,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
+                        api_attachment_sha256,
+                        api_lang,
                         api_model_name,
+                        api_payload_json,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_purge_message_attachments_impl(
+fn wire__crate__api__core__db_mark_attachment_place_failed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3409,7 +3496,7 @@ fn wire__crate__api__core__db_purge_message_attachments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_purge_message_attachments",
+            debug_name: "db_mark_attachment_place_failed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3425,21 +3512,29 @@ fn wire__crate__api__core__db_purge_message_attachments_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_attempts = <i64>::sse_decode(&mut deserializer);
+            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_last_error = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_purge_message_attachments(
+                    crate::api::core::db_mark_attachment_place_failed(
                         api_app_dir,
                         api_key,
-                        api_message_id,
+                        api_attachment_sha256,
+                        api_attempts,
+                        api_next_retry_at_ms,
+                        api_last_error,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
+fn wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3447,7 +3542,7 @@ fn wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_read_attachment_annotation_caption_long",
+            debug_name: "db_mark_attachment_place_ok_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3464,20 +3559,26 @@ fn wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_payload_json = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_read_attachment_annotation_caption_long(
+                    crate::api::core::db_mark_attachment_place_ok_json(
                         api_app_dir,
                         api_key,
                         api_attachment_sha256,
+                        api_lang,
+                        api_payload_json,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_read_attachment_bytes_impl(
+fn wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3485,7 +3586,7 @@ fn wire__crate__api__core__db_read_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_read_attachment_bytes",
+            debug_name: "db_mark_cloud_media_backup_failed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3501,17 +3602,29 @@ fn wire__crate__api__core__db_read_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_attempts = <i64>::sse_decode(&mut deserializer);
+            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_last_error = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_read_attachment_bytes(api_app_dir, api_key, api_sha256)
-                })())
-            }
-        },
-    )
+                    crate::api::core::db_mark_cloud_media_backup_failed(
+                        api_app_dir,
+                        api_key,
+                        api_attachment_sha256,
+                        api_attempts,
+                        api_next_retry_at_ms,
+                        api_last_error,
+                        api_now_ms,
+                    )
+                })())
+            }
+        },
+    )
 }
-fn wire__crate__api__core__db_read_attachment_exif_metadata_impl(
+fn wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3519,7 +3632,7 @@ fn wire__crate__api__core__db_read_attachment_exif_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_read_attachment_exif_metadata",
+            debug_name: "db_mark_cloud_media_backup_uploaded",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3536,20 +3649,22 @@ fn wire__crate__api__core__db_read_attachment_exif_metadata_impl(
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_read_attachment_exif_metadata(
+                    crate::api::core::db_mark_cloud_media_backup_uploaded(
                         api_app_dir,
                         api_key,
                         api_attachment_sha256,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_read_attachment_place_display_name_impl(
+fn wire__crate__api__core__db_mark_for_review_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3557,7 +3672,7 @@ fn wire__crate__api__core__db_read_attachment_place_display_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_read_attachment_place_display_name",
+            debug_name: "db_mark_for_review",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3573,21 +3688,25 @@ fn wire__crate__api__core__db_read_attachment_place_display_name_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_item_kind = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_first_review_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_read_attachment_place_display_name(
+                    crate::api::core::db_mark_for_review(
                         api_app_dir,
                         api_key,
-                        api_attachment_sha256,
+                        api_item_kind,
+                        api_item_id,
+                        api_first_review_at_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_read_attachment_variant_bytes_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3595,7 +3714,7 @@ fn wire__crate__api__core__db_read_attachment_variant_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_read_attachment_variant_bytes",
+            debug_name: "db_mark_semantic_parse_job_canceled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3611,23 +3730,23 @@ fn wire__crate__api__core__db_read_attachment_variant_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_variant = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_read_attachment_variant_bytes(
+                    crate::api::core::db_mark_semantic_parse_job_canceled(
                         api_app_dir,
                         api_key,
-                        api_attachment_sha256,
-                        api_variant,
+                        api_message_id,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_rebuild_message_embeddings_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3635,7 +3754,7 @@ fn wire__crate__api__core__db_rebuild_message_embeddings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_rebuild_message_embeddings",
+            debug_name: "db_mark_semantic_parse_job_failed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3651,21 +3770,29 @@ fn wire__crate__api__core__db_rebuild_message_embeddings_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_batch_limit = <u32>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_attempts = <i64>::sse_decode(&mut deserializer);
+            let api_next_retry_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_last_error = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_rebuild_message_embeddings(
+                    crate::api::core::db_mark_semantic_parse_job_failed(
                         api_app_dir,
                         api_key,
-                        api_batch_limit,
+                        api_message_id,
+                        api_attempts,
+                        api_next_retry_at_ms,
+                        api_last_error,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_record_llm_usage_daily_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3673,7 +3800,7 @@ fn wire__crate__api__core__db_record_llm_usage_daily_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_record_llm_usage_daily",
+            debug_name: "db_mark_semantic_parse_job_retry",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3689,31 +3816,23 @@ fn wire__crate__api__core__db_record_llm_usage_daily_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_day = <String>::sse_decode(&mut deserializer);
-            let api_profile_id = <String>::sse_decode(&mut deserializer);
-            let api_purpose = <String>::sse_decode(&mut deserializer);
-            let api_input_tokens = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_output_tokens = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_total_tokens = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_record_llm_usage_daily(
+                    crate::api::core::db_mark_semantic_parse_job_retry(
                         api_app_dir,
                         api_key,
-                        api_day,
-                        api_profile_id,
-                        api_purpose,
-                        api_input_tokens,
-                        api_output_tokens,
-                        api_total_tokens,
+                        api_message_id,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3721,7 +3840,7 @@ fn wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_reset_vault_data_preserving_llm_profiles",
+            debug_name: "db_mark_semantic_parse_job_running",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3737,19 +3856,23 @@ fn wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_reset_vault_data_preserving_llm_profiles(
+                    crate::api::core::db_mark_semantic_parse_job_running(
                         api_app_dir,
                         api_key,
+                        api_message_id,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_messages_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3757,7 +3880,7 @@ fn wire__crate__api__core__db_search_similar_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_messages",
+            debug_name: "db_mark_semantic_parse_job_succeeded",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3773,23 +3896,31 @@ fn wire__crate__api__core__db_search_similar_messages_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_applied_action_kind = <String>::sse_decode(&mut deserializer);
+            let api_applied_todo_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_applied_todo_title = <Option<String>>::sse_decode(&mut deserializer);
+            let api_applied_prev_todo_status = <Option<String>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_messages(
+                    crate::api::core::db_mark_semantic_parse_job_succeeded(
                         api_app_dir,
                         api_key,
-                        api_query,
-                        api_top_k,
+                        api_message_id,
+                        api_applied_action_kind,
+                        api_applied_todo_id,
+                        api_applied_todo_title,
+                        api_applied_prev_todo_status,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_messages_brok_impl(
+fn wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3797,7 +3928,7 @@ fn wire__crate__api__core__db_search_similar_messages_brok_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_messages_brok",
+            debug_name: "db_mark_semantic_parse_job_undone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3813,23 +3944,23 @@ fn wire__crate__api__core__db_search_similar_messages_brok_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_messages_brok(
+                    crate::api::core::db_mark_semantic_parse_job_undone(
                         api_app_dir,
                         api_key,
-                        api_query,
-                        api_top_k,
+                        api_message_id,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
+fn wire__crate__api__core__db_move_event_occurrence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3837,7 +3968,7 @@ fn wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_messages_cloud_gateway",
+            debug_name: "db_move_event_occurrence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3853,29 +3984,29 @@ fn wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_occurrence_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_start_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_messages_cloud_gateway(
+                    crate::api::core::db_move_event_occurrence(
                         api_app_dir,
                         api_key,
-                        api_query,
-                        api_top_k,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
+                        api_event_id,
+                        api_occurrence_start_ms,
+                        api_start_at_ms,
+                        api_end_at_ms,
+                        api_title,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_todo_threads_impl(
+fn wire__crate__api__core__db_move_todo_activity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3883,7 +4014,7 @@ fn wire__crate__api__core__db_search_similar_todo_threads_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_todo_threads",
+            debug_name: "db_move_todo_activity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3899,23 +4030,23 @@ fn wire__crate__api__core__db_search_similar_todo_threads_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_activity_id = <String>::sse_decode(&mut deserializer);
+            let api_to_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_todo_threads(
+                    crate::api::core::db_move_todo_activity(
                         api_app_dir,
                         api_key,
-                        api_query,
-                        api_top_k,
+                        api_activity_id,
+                        api_to_todo_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
+fn wire__crate__api__core__db_preview_review_intervals_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3923,7 +4054,7 @@ fn wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_todo_threads_brok",
+            debug_name: "db_preview_review_intervals",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3938,24 +4069,22 @@ fn wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_item_kind = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_todo_threads_brok(
+                    crate::api::core::db_preview_review_intervals(
                         api_app_dir,
-                        api_key,
-                        api_query,
-                        api_top_k,
+                        api_item_kind,
+                        api_item_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
+fn wire__crate__api__core__db_preview_todo_recurrence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3963,7 +4092,7 @@ fn wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_search_similar_todo_threads_cloud_gateway",
+            debug_name: "db_preview_todo_recurrence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3977,31 +4106,23 @@ fn wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_rule_json = <String>::sse_decode(&mut deserializer);
+            let api_start_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_search_similar_todo_threads_cloud_gateway(
-                        api_app_dir,
-                        api_key,
-                        api_query,
-                        api_top_k,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
+                    crate::api::core::db_preview_todo_recurrence(
+                        api_rule_json,
+                        api_start_at_ms,
+                        api_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_set_active_embedding_model_name_impl(
+fn wire__crate__api__core__db_process_pending_message_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4009,7 +4130,7 @@ fn wire__crate__api__core__db_set_active_embedding_model_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_active_embedding_model_name",
+            debug_name: "db_process_pending_message_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4025,21 +4146,21 @@ fn wire__crate__api__core__db_set_active_embedding_model_name_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_set_active_embedding_model_name(
+                    crate::api::core::db_process_pending_message_embeddings(
                         api_app_dir,
                         api_key,
-                        api_model_name,
+                        api_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_set_active_embedding_profile_impl(
+fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4047,7 +4168,7 @@ fn wire__crate__api__core__db_set_active_embedding_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_active_embedding_profile",
+            debug_name: "db_process_pending_todo_thread_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4063,21 +4184,23 @@ fn wire__crate__api__core__db_set_active_embedding_profile_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
+            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_set_active_embedding_profile(
+                    crate::api::core::db_process_pending_todo_thread_embeddings(
                         api_app_dir,
                         api_key,
-                        api_profile_id,
+                        api_todo_limit,
+                        api_activity_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_set_active_llm_profile_impl(
+fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4085,7 +4208,7 @@ fn wire__crate__api__core__db_set_active_llm_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_active_llm_profile",
+            debug_name: "db_process_pending_todo_thread_embeddings_brok",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4101,21 +4224,23 @@ fn wire__crate__api__core__db_set_active_llm_profile_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
+            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_set_active_llm_profile(
+                    crate::api::core::db_process_pending_todo_thread_embeddings_brok(
                         api_app_dir,
                         api_key,
-                        api_profile_id,
+                        api_todo_limit,
+                        api_activity_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_set_message_deleted_impl(
+fn wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4123,7 +4248,7 @@ fn wire__crate__api__core__db_set_message_deleted_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_message_deleted",
+            debug_name: "db_process_pending_todo_thread_embeddings_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4139,23 +4264,29 @@ fn wire__crate__api__core__db_set_message_deleted_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_is_deleted = <bool>::sse_decode(&mut deserializer);
+            let api_todo_limit = <u32>::sse_decode(&mut deserializer);
+            let api_activity_limit = <u32>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_set_message_deleted(
+                    crate::api::core::db_process_pending_todo_thread_embeddings_cloud_gateway(
                         api_app_dir,
                         api_key,
-                        api_message_id,
-                        api_is_deleted,
+                        api_todo_limit,
+                        api_activity_limit,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_set_todo_status_impl(
+fn wire__crate__api__core__db_purge_message_attachments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4163,7 +4294,7 @@ fn wire__crate__api__core__db_set_todo_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_todo_status",
+            debug_name: "db_purge_message_attachments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4179,25 +4310,21 @@ fn wire__crate__api__core__db_set_todo_status_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
-            let api_new_status = <String>::sse_decode(&mut deserializer);
-            let api_source_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_set_todo_status(
+                    crate::api::core::db_purge_message_attachments(
                         api_app_dir,
                         api_key,
-                        api_todo_id,
-                        api_new_status,
-                        api_source_message_id,
+                        api_message_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
+fn wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4205,7 +4332,7 @@ fn wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_sum_llm_usage_daily_by_purpose",
+            debug_name: "db_read_attachment_annotation_caption_long",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4221,25 +4348,21 @@ fn wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_profile_id = <String>::sse_decode(&mut deserializer);
-            let api_start_day = <String>::sse_decode(&mut deserializer);
-            let api_end_day = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_sum_llm_usage_daily_by_purpose(
+                    crate::api::core::db_read_attachment_annotation_caption_long(
                         api_app_dir,
                         api_key,
-                        api_profile_id,
-                        api_start_day,
-                        api_end_day,
+                        api_attachment_sha256,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_update_todo_due_with_scope_impl(
+fn wire__crate__api__core__db_read_attachment_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4247,7 +4370,7 @@ fn wire__crate__api__core__db_update_todo_due_with_scope_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_update_todo_due_with_scope",
+            debug_name: "db_read_attachment_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4263,25 +4386,17 @@ fn wire__crate__api__core__db_update_todo_due_with_scope_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
-            let api_due_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_scope = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_update_todo_due_with_scope(
-                        api_app_dir,
-                        api_key,
-                        api_todo_id,
-                        api_due_at_ms,
-                        api_scope,
-                    )
+                    crate::api::core::db_read_attachment_bytes(api_app_dir, api_key, api_sha256)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
+fn wire__crate__api__core__db_read_attachment_exif_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4289,7 +4404,7 @@ fn wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_update_todo_recurrence_rule_with_scope",
+            debug_name: "db_read_attachment_exif_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4305,25 +4420,21 @@ fn wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
-            let api_rule_json = <String>::sse_decode(&mut deserializer);
-            let api_scope = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_update_todo_recurrence_rule_with_scope(
+                    crate::api::core::db_read_attachment_exif_metadata(
                         api_app_dir,
                         api_key,
-                        api_todo_id,
-                        api_rule_json,
-                        api_scope,
+                        api_attachment_sha256,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_update_todo_status_with_scope_impl(
+fn wire__crate__api__core__db_read_attachment_place_display_name_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4331,7 +4442,7 @@ fn wire__crate__api__core__db_update_todo_status_with_scope_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_update_todo_status_with_scope",
+            debug_name: "db_read_attachment_place_display_name",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4347,27 +4458,21 @@ fn wire__crate__api__core__db_update_todo_status_with_scope_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_todo_id = <String>::sse_decode(&mut deserializer);
-            let api_new_status = <String>::sse_decode(&mut deserializer);
-            let api_source_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_scope = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_update_todo_status_with_scope(
+                    crate::api::core::db_read_attachment_place_display_name(
                         api_app_dir,
                         api_key,
-                        api_todo_id,
-                        api_new_status,
-                        api_source_message_id,
-                        api_scope,
+                        api_attachment_sha256,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
+fn wire__crate__api__core__db_read_attachment_variant_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4375,7 +4480,7 @@ fn wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_attachment_exif_metadata",
+            debug_name: "db_read_attachment_variant_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4392,26 +4497,22 @@ fn wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_captured_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_latitude = <Option<f64>>::sse_decode(&mut deserializer);
-            let api_longitude = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_variant = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_upsert_attachment_exif_metadata(
+                    crate::api::core::db_read_attachment_variant_bytes(
                         api_app_dir,
                         api_key,
                         api_attachment_sha256,
-                        api_captured_at_ms,
-                        api_latitude,
-                        api_longitude,
+                        api_variant,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_upsert_attachment_variant_impl(
+fn wire__crate__api__core__db_rebuild_message_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4419,7 +4520,7 @@ fn wire__crate__api__core__db_upsert_attachment_variant_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_attachment_variant",
+            debug_name: "db_rebuild_message_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4435,27 +4536,21 @@ fn wire__crate__api__core__db_upsert_attachment_variant_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
-            let api_variant = <String>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_batch_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_upsert_attachment_variant(
+                    crate::api::core::db_rebuild_message_embeddings(
                         api_app_dir,
                         api_key,
-                        api_attachment_sha256,
-                        api_variant,
-                        api_bytes,
-                        api_mime_type,
+                        api_batch_limit,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_upsert_event_impl(
+fn wire__crate__api__core__db_record_llm_usage_daily_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4463,7 +4558,7 @@ fn wire__crate__api__core__db_upsert_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_event",
+            debug_name: "db_record_llm_usage_daily",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4479,31 +4574,31 @@ fn wire__crate__api__core__db_upsert_event_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_start_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_at_ms = <i64>::sse_decode(&mut deserializer);
-            let api_tz = <String>::sse_decode(&mut deserializer);
-            let api_source_entry_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_day = <String>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            let api_input_tokens = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_output_tokens = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_total_tokens = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_upsert_event(
+                    crate::api::core::db_record_llm_usage_daily(
                         api_app_dir,
                         api_key,
-                        api_id,
-                        api_title,
-                        api_start_at_ms,
-                        api_end_at_ms,
-                        api_tz,
-                        api_source_entry_id,
+                        api_day,
+                        api_profile_id,
+                        api_purpose,
+                        api_input_tokens,
+                        api_output_tokens,
+                        api_total_tokens,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_upsert_todo_impl(
+fn wire__crate__api__core__db_record_review_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4511,7 +4606,7 @@ fn wire__crate__api__core__db_upsert_todo_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_todo",
+            debug_name: "db_record_review",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4527,35 +4622,25 @@ fn wire__crate__api__core__db_upsert_todo_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_id = <String>::sse_decode(&mut deserializer);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_due_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_status = <String>::sse_decode(&mut deserializer);
-            let api_source_entry_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_review_stage = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_next_review_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_last_review_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_item_kind = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_grade = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_upsert_todo(
+                    crate::api::core::db_record_review(
                         api_app_dir,
                         api_key,
-                        api_id,
-                        api_title,
-                        api_due_at_ms,
-                        api_status,
-                        api_source_entry_id,
-                        api_review_stage,
-                        api_next_review_at_ms,
-                        api_last_review_at_ms,
+                        api_item_kind,
+                        api_item_id,
+                        api_grade,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__db_upsert_todo_recurrence_impl(
+fn wire__crate__api__core__db_remove_todo_dependency_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4563,7 +4648,7 @@ fn wire__crate__api__core__db_upsert_todo_recurrence_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_todo_recurrence",
+            debug_name: "db_remove_todo_dependency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4580,24 +4665,22 @@ fn wire__crate__api__core__db_upsert_todo_recurrence_impl(
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_todo_id = <String>::sse_decode(&mut deserializer);
-            let api_series_id = <String>::sse_decode(&mut deserializer);
-            let api_rule_json = <String>::sse_decode(&mut deserializer);
+            let api_blocked_by_todo_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::db_upsert_todo_recurrence(
+                    crate::api::core::db_remove_todo_dependency(
                         api_app_dir,
                         api_key,
                         api_todo_id,
-                        api_series_id,
-                        api_rule_json,
+                        api_blocked_by_todo_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__geo_reverse_cloud_gateway_impl(
+fn wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4605,7 +4688,7 @@ fn wire__crate__api__core__geo_reverse_cloud_gateway_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "geo_reverse_cloud_gateway",
+            debug_name: "db_reset_vault_data_preserving_llm_profiles",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4619,27 +4702,21 @@ fn wire__crate__api__core__geo_reverse_cloud_gateway_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_lat = <f64>::sse_decode(&mut deserializer);
-            let api_lon = <f64>::sse_decode(&mut deserializer);
-            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::geo_reverse_cloud_gateway(
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_lat,
-                        api_lon,
-                        api_lang,
+                    crate::api::core::db_reset_vault_data_preserving_llm_profiles(
+                        api_app_dir,
+                        api_key,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__media_annotation_cloud_gateway_impl(
+fn wire__crate__api__core__db_restore_event_occurrence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4647,7 +4724,7 @@ fn wire__crate__api__core__media_annotation_cloud_gateway_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "media_annotation_cloud_gateway",
+            debug_name: "db_restore_event_occurrence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4661,29 +4738,25 @@ fn wire__crate__api__core__media_annotation_cloud_gateway_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_lang = <String>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_occurrence_start_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::media_annotation_cloud_gateway(
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
-                        api_lang,
-                        api_mime_type,
-                        api_image_bytes,
+                    crate::api::core::db_restore_event_occurrence(
+                        api_app_dir,
+                        api_key,
+                        api_event_id,
+                        api_occurrence_start_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_impl(
+fn wire__crate__api__core__db_search_similar_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4691,7 +4764,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream",
+            debug_name: "db_search_similar_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4707,34 +4780,23 @@ fn wire__crate__api__core__rag_ask_ai_stream_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_local_day = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream(
+                    crate::api::core::db_search_similar_messages(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_local_day,
-                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
+fn wire__crate__api__core__db_search_similar_messages_brok_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4742,7 +4804,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_cloud_gateway",
+            debug_name: "db_search_similar_messages_brok",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4758,38 +4820,23 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_cloud_gateway(
+                    crate::api::core::db_search_similar_messages_brok(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
-                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
+fn wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4797,7 +4844,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_cloud_gateway_time_window",
+            debug_name: "db_search_similar_messages_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4813,42 +4860,29 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
             let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
             let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
             let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_cloud_gateway_time_window(
+                    crate::api::core::db_search_similar_messages_cloud_gateway(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_time_start_ms,
-                        api_time_end_ms,
                         api_gateway_base_url,
                         api_firebase_id_token,
                         api_model_name,
-                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
+fn wire__crate__api__core__db_search_similar_todo_threads_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4856,7 +4890,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_cloud_gateway_with_embeddings",
+            debug_name: "db_search_similar_todo_threads",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4872,40 +4906,23 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_embeddings_model_name = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_cloud_gateway_with_embeddings(
+                    crate::api::core::db_search_similar_todo_threads(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
-                        api_embeddings_model_name,
-                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window_impl(
+fn wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4913,7 +4930,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_time_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window",
+            debug_name: "db_search_similar_todo_threads_brok",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4929,44 +4946,23 @@ fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_time_
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
-            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_model_name = <String>::sse_decode(&mut deserializer);
-            let api_embeddings_model_name = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window(
+                    crate::api::core::db_search_similar_todo_threads_brok(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_time_start_ms,
-                        api_time_end_ms,
-                        api_gateway_base_url,
-                        api_firebase_id_token,
-                        api_model_name,
-                        api_embeddings_model_name,
-                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
+fn wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4974,7 +4970,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_time_window",
+            debug_name: "db_search_similar_todo_threads_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4990,38 +4986,29 @@ fn wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
-            let api_local_day = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_time_window(
+                    crate::api::core::db_search_similar_todo_threads_cloud_gateway(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
+                        api_query,
                         api_top_k,
-                        api_this_thread_only,
-                        api_time_start_ms,
-                        api_time_end_ms,
-                        api_local_day,
-                        api_sink,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
+fn wire__crate__api__core__db_set_active_embedding_model_name_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5029,7 +5016,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_with_brok_embeddings",
+            debug_name: "db_set_active_embedding_model_name",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5045,34 +5032,21 @@ fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_local_day = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_model_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_with_brok_embeddings(
+                    crate::api::core::db_set_active_embedding_model_name(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
-                        api_top_k,
-                        api_this_thread_only,
-                        api_local_day,
-                        api_sink,
+                        api_model_name,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_impl(
+fn wire__crate__api__core__db_set_active_embedding_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5080,7 +5054,7 @@ fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rag_ask_ai_stream_with_brok_embeddings_time_window",
+            debug_name: "db_set_active_embedding_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5096,38 +5070,21 @@ fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_im
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <u32>::sse_decode(&mut deserializer);
-            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
-            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
-            let api_local_day = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::rag_ask_ai_stream_with_brok_embeddings_time_window(
+                    crate::api::core::db_set_active_embedding_profile(
                         api_app_dir,
                         api_key,
-                        api_conversation_id,
-                        api_question,
-                        api_top_k,
-                        api_this_thread_only,
-                        api_time_start_ms,
-                        api_time_end_ms,
-                        api_local_day,
-                        api_sink,
+                        api_profile_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_derive_key_impl(
+fn wire__crate__api__core__db_set_active_llm_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5135,7 +5092,7 @@ fn wire__crate__api__core__sync_derive_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_derive_key",
+            debug_name: "db_set_active_llm_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5149,15 +5106,23 @@ fn wire__crate__api__core__sync_derive_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse((move || crate::api::core::sync_derive_key(api_passphrase))())
+                transform_result_sse((move || {
+                    crate::api::core::db_set_active_llm_profile(
+                        api_app_dir,
+                        api_key,
+                        api_profile_id,
+                    )
+                })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_clear_remote_root_impl(
+fn wire__crate__api__core__db_set_ask_ai_query_rewrite_enabled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5165,7 +5130,7 @@ fn wire__crate__api__core__sync_localdir_clear_remote_root_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_clear_remote_root",
+            debug_name: "db_set_ask_ai_query_rewrite_enabled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5179,21 +5144,18 @@ fn wire__crate__api__core__sync_localdir_clear_remote_root_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_clear_remote_root(
-                        api_local_dir,
-                        api_remote_root,
-                    )
+                    crate::api::core::db_set_ask_ai_query_rewrite_enabled(api_app_dir, api_enabled)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
+fn wire__crate__api__core__db_set_event_recurrence_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5201,7 +5163,7 @@ fn wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_download_attachment_bytes",
+            debug_name: "db_set_event_recurrence_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5217,27 +5179,23 @@ fn wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_event_id = <String>::sse_decode(&mut deserializer);
+            let api_rule_json = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_download_attachment_bytes(
+                    crate::api::core::db_set_event_recurrence_rule(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
-                        api_sha256,
+                        api_event_id,
+                        api_rule_json,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_pull_impl(
+fn wire__crate__api__core__db_set_llm_fallback_chain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5245,7 +5203,7 @@ fn wire__crate__api__core__sync_localdir_pull_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_pull",
+            debug_name: "db_set_llm_fallback_chain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5261,25 +5219,21 @@ fn wire__crate__api__core__sync_localdir_pull_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_profile_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_pull(
+                    crate::api::core::db_set_llm_fallback_chain(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
+                        api_profile_ids,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_push_impl(
+fn wire__crate__api__core__db_set_llm_spending_limit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5287,7 +5241,7 @@ fn wire__crate__api__core__sync_localdir_push_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_push",
+            debug_name: "db_set_llm_spending_limit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5303,25 +5257,23 @@ fn wire__crate__api__core__sync_localdir_push_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_monthly_limit_usd_micros = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_push(
+                    crate::api::core::db_set_llm_spending_limit(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
+                        api_profile_id,
+                        api_monthly_limit_usd_micros,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_push_ops_only_impl(
+fn wire__crate__api__core__db_set_message_deleted_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5329,7 +5281,7 @@ fn wire__crate__api__core__sync_localdir_push_ops_only_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_push_ops_only",
+            debug_name: "db_set_message_deleted",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5345,25 +5297,23 @@ fn wire__crate__api__core__sync_localdir_push_ops_only_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_is_deleted = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_push_ops_only(
+                    crate::api::core::db_set_message_deleted(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
+                        api_message_id,
+                        api_is_deleted,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_test_connection_impl(
+fn wire__crate__api__core__db_set_todo_complete_with_subtasks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5371,7 +5321,7 @@ fn wire__crate__api__core__sync_localdir_test_connection_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_test_connection",
+            debug_name: "db_set_todo_complete_with_subtasks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5385,18 +5335,25 @@ fn wire__crate__api__core__sync_localdir_test_connection_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_test_connection(api_local_dir, api_remote_root)
+                    crate::api::core::db_set_todo_complete_with_subtasks(
+                        api_app_dir,
+                        api_key,
+                        api_todo_id,
+                        api_enabled,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
+fn wire__crate__api__core__db_set_todo_parent_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5404,7 +5361,7 @@ fn wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_upload_attachment_bytes",
+            debug_name: "db_set_todo_parent",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5420,27 +5377,23 @@ fn wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_localdir_upload_attachment_bytes(
+                    crate::api::core::db_set_todo_parent(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
-                        api_sha256,
+                        api_todo_id,
+                        api_parent_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_clear_device_impl(
+fn wire__crate__api__core__db_set_todo_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5448,7 +5401,7 @@ fn wire__crate__api__core__sync_managed_vault_clear_device_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_clear_device",
+            debug_name: "db_set_todo_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5462,25 +5415,27 @@ fn wire__crate__api__core__sync_managed_vault_clear_device_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_device_id = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_new_status = <String>::sse_decode(&mut deserializer);
+            let api_source_message_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_clear_device(
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
-                        api_device_id,
+                    crate::api::core::db_set_todo_status(
+                        api_app_dir,
+                        api_key,
+                        api_todo_id,
+                        api_new_status,
+                        api_source_message_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_clear_vault_impl(
+fn wire__crate__api__core__db_set_todo_timezone_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5488,7 +5443,7 @@ fn wire__crate__api__core__sync_managed_vault_clear_vault_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_clear_vault",
+            debug_name: "db_set_todo_timezone",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5502,23 +5457,25 @@ fn wire__crate__api__core__sync_managed_vault_clear_vault_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_clear_vault(
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
+                    crate::api::core::db_set_todo_timezone(
+                        api_app_dir,
+                        api_key,
+                        api_todo_id,
+                        api_tz,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
+fn wire__crate__api__core__db_sum_llm_cost_monthly_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5526,7 +5483,7 @@ fn wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_download_attachment_bytes",
+            debug_name: "db_sum_llm_cost_monthly",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5542,29 +5499,17 @@ fn wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_month = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_download_attachment_bytes(
-                        api_app_dir,
-                        api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
-                        api_sha256,
-                    )
+                    crate::api::core::db_sum_llm_cost_monthly(api_app_dir, api_key, api_month)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_pull_impl(
+fn wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5572,7 +5517,7 @@ fn wire__crate__api__core__sync_managed_vault_pull_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_pull",
+            debug_name: "db_sum_llm_usage_daily_by_purpose",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5588,27 +5533,25 @@ fn wire__crate__api__core__sync_managed_vault_pull_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_start_day = <String>::sse_decode(&mut deserializer);
+            let api_end_day = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_pull(
+                    crate::api::core::db_sum_llm_usage_daily_by_purpose(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
+                        api_profile_id,
+                        api_start_day,
+                        api_end_day,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_push_impl(
+fn wire__crate__api__core__db_unmark_for_review_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5616,7 +5559,7 @@ fn wire__crate__api__core__sync_managed_vault_push_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_push",
+            debug_name: "db_unmark_for_review",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5632,27 +5575,23 @@ fn wire__crate__api__core__sync_managed_vault_push_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_item_kind = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_push(
+                    crate::api::core::db_unmark_for_review(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
+                        api_item_kind,
+                        api_item_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
+fn wire__crate__api__core__db_update_todo_due_with_scope_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5660,7 +5599,7 @@ fn wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_push_ops_only",
+            debug_name: "db_update_todo_due_with_scope",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5676,27 +5615,25 @@ fn wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_due_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_push_ops_only(
+                    crate::api::core::db_update_todo_due_with_scope(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
+                        api_todo_id,
+                        api_due_at_ms,
+                        api_scope,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
+fn wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5704,7 +5641,7 @@ fn wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_upload_attachment_bytes",
+            debug_name: "db_update_todo_recurrence_rule_with_scope",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5720,29 +5657,25 @@ fn wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_rule_json = <String>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_managed_vault_upload_attachment_bytes(
+                    crate::api::core::db_update_todo_recurrence_rule_with_scope(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_firebase_id_token,
-                        api_sha256,
+                        api_todo_id,
+                        api_rule_json,
+                        api_scope,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_clear_remote_root_impl(
+fn wire__crate__api__core__db_update_todo_status_with_scope_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5750,7 +5683,7 @@ fn wire__crate__api__core__sync_webdav_clear_remote_root_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_clear_remote_root",
+            debug_name: "db_update_todo_status_with_scope",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5764,25 +5697,29 @@ fn wire__crate__api__core__sync_webdav_clear_remote_root_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_new_status = <String>::sse_decode(&mut deserializer);
+            let api_source_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_clear_remote_root(
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                    crate::api::core::db_update_todo_status_with_scope(
+                        api_app_dir,
+                        api_key,
+                        api_todo_id,
+                        api_new_status,
+                        api_source_message_id,
+                        api_scope,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
+fn wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5790,7 +5727,7 @@ fn wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_download_attachment_bytes",
+            debug_name: "db_upsert_attachment_exif_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5806,31 +5743,27 @@ fn wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_captured_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_latitude = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_longitude = <Option<f64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_download_attachment_bytes(
+                    crate::api::core::db_upsert_attachment_exif_metadata(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
-                        api_sha256,
+                        api_attachment_sha256,
+                        api_captured_at_ms,
+                        api_latitude,
+                        api_longitude,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_pull_impl(
+fn wire__crate__api__core__db_upsert_attachment_variant_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5838,7 +5771,7 @@ fn wire__crate__api__core__sync_webdav_pull_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_pull",
+            debug_name: "db_upsert_attachment_variant",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5854,29 +5787,27 @@ fn wire__crate__api__core__sync_webdav_pull_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_attachment_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_variant = <String>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_pull(
+                    crate::api::core::db_upsert_attachment_variant(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                        api_attachment_sha256,
+                        api_variant,
+                        api_bytes,
+                        api_mime_type,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_push_impl(
+fn wire__crate__api__core__db_upsert_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5884,7 +5815,7 @@ fn wire__crate__api__core__sync_webdav_push_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_push",
+            debug_name: "db_upsert_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5900,29 +5831,31 @@ fn wire__crate__api__core__sync_webdav_push_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_start_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_at_ms = <i64>::sse_decode(&mut deserializer);
+            let api_tz = <String>::sse_decode(&mut deserializer);
+            let api_source_entry_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_push(
+                    crate::api::core::db_upsert_event(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                        api_id,
+                        api_title,
+                        api_start_at_ms,
+                        api_end_at_ms,
+                        api_tz,
+                        api_source_entry_id,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_push_ops_only_impl(
+fn wire__crate__api__core__db_upsert_llm_model_price_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5930,7 +5863,7 @@ fn wire__crate__api__core__sync_webdav_push_ops_only_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_push_ops_only",
+            debug_name: "db_upsert_llm_model_price",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5946,29 +5879,27 @@ fn wire__crate__api__core__sync_webdav_push_ops_only_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_provider_type = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_input_usd_per_million_tokens = <f64>::sse_decode(&mut deserializer);
+            let api_output_usd_per_million_tokens = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_push_ops_only(
+                    crate::api::core::db_upsert_llm_model_price(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                        api_provider_type,
+                        api_model_name,
+                        api_input_usd_per_million_tokens,
+                        api_output_usd_per_million_tokens,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_test_connection_impl(
+fn wire__crate__api__core__db_upsert_todo_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5976,7 +5907,7 @@ fn wire__crate__api__core__sync_webdav_test_connection_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_test_connection",
+            debug_name: "db_upsert_todo",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5990,25 +5921,37 @@ fn wire__crate__api__core__sync_webdav_test_connection_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_due_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_status = <String>::sse_decode(&mut deserializer);
+            let api_source_entry_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_review_stage = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_next_review_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_last_review_at_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_test_connection(
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
-                    )
+                    crate::api::core::db_upsert_todo(
+                        api_app_dir,
+                        api_key,
+                        api_id,
+                        api_title,
+                        api_due_at_ms,
+                        api_status,
+                        api_source_entry_id,
+                        api_review_stage,
+                        api_next_review_at_ms,
+                        api_last_review_at_ms,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
+fn wire__crate__api__core__db_upsert_todo_recurrence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6016,7 +5959,7 @@ fn wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_upload_attachment_bytes",
+            debug_name: "db_upsert_todo_recurrence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6032,31 +5975,25 @@ fn wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
-            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            let api_todo_id = <String>::sse_decode(&mut deserializer);
+            let api_series_id = <String>::sse_decode(&mut deserializer);
+            let api_rule_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::core::sync_webdav_upload_attachment_bytes(
+                    crate::api::core::db_upsert_todo_recurrence(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
-                        api_sha256,
+                        api_todo_id,
+                        api_series_id,
+                        api_rule_json,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__desktop_media__desktop_ocr_image_impl(
+fn wire__crate__api__core__embedding_install_reranker_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6064,7 +6001,7 @@ fn wire__crate__api__desktop_media__desktop_ocr_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "desktop_ocr_image",
+            debug_name: "embedding_install_reranker",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6078,18 +6015,17 @@ fn wire__crate__api__desktop_media__desktop_ocr_image_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_language_hints = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::desktop_media::desktop_ocr_image(api_bytes, api_language_hints)
+                    crate::api::core::embedding_install_reranker(api_app_dir)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__desktop_media__desktop_ocr_pdf_impl(
+fn wire__crate__api__core__embedding_is_reranker_installed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6097,7 +6033,7 @@ fn wire__crate__api__desktop_media__desktop_ocr_pdf_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "desktop_ocr_pdf",
+            debug_name: "embedding_is_reranker_installed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6111,25 +6047,19 @@ fn wire__crate__api__desktop_media__desktop_ocr_pdf_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_max_pages = <u32>::sse_decode(&mut deserializer);
-            let api_dpi = <u32>::sse_decode(&mut deserializer);
-            let api_language_hints = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::desktop_media::desktop_ocr_pdf(
-                        api_bytes,
-                        api_max_pages,
-                        api_dpi,
-                        api_language_hints,
-                    )
+                    Result::<_, ()>::Ok(crate::api::core::embedding_is_reranker_installed(
+                        api_app_dir,
+                    ))
                 })())
             }
         },
     )
 }
-fn wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
+fn wire__crate__api__core__geo_reverse_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6137,7 +6067,7 @@ fn wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_get_media_annotation_config",
+            debug_name: "geo_reverse_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6151,21 +6081,27 @@ fn wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_lat = <f64>::sse_decode(&mut deserializer);
+            let api_lon = <f64>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::media_annotation::db_get_media_annotation_config(
-                        api_app_dir,
-                        api_key,
+                    crate::api::core::geo_reverse_cloud_gateway(
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_lat,
+                        api_lon,
+                        api_lang,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
+fn wire__crate__api__core__media_annotation_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6173,7 +6109,7 @@ fn wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_media_annotation_config",
+            debug_name: "media_annotation_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6187,23 +6123,29 @@ fn wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_config = <crate::db::MediaAnnotationConfig>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::media_annotation::db_set_media_annotation_config(
-                        api_app_dir,
-                        api_key,
-                        api_config,
+                    crate::api::core::media_annotation_cloud_gateway(
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_lang,
+                        api_mime_type,
+                        api_image_bytes,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
+fn wire__crate__api__core__rag_ask_ai_agent_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6211,7 +6153,7 @@ fn wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "media_annotation_byok_profile",
+            debug_name: "rag_ask_ai_agent_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6227,38 +6169,40 @@ fn wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
             let api_local_day = <String>::sse_decode(&mut deserializer);
-            let api_lang = <String>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::media_annotation::media_annotation_byok_profile(
+                    crate::api::core::rag_ask_ai_agent_stream(
                         api_app_dir,
                         api_key,
-                        api_profile_id,
+                        api_conversation_id,
+                        api_question,
                         api_local_day,
-                        api_lang,
-                        api_mime_type,
-                        api_image_bytes,
+                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__core__rag_ask_ai_agent_stream_cloud_gateway_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "rag_ask_ai_agent_stream_cloud_gateway",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -6270,15 +6214,36 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::greet(api_name))
-            })())
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::rag_ask_ai_agent_stream_cloud_gateway(
+                        api_app_dir,
+                        api_key,
+                        api_conversation_id,
+                        api_question,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_sink,
+                    )
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6286,7 +6251,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "rag_ask_ai_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6300,18 +6265,38 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_local_day = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })
+                    crate::api::core::rag_ask_ai_stream(
+                        api_app_dir,
+                        api_key,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_local_day,
+                        api_tz,
+                        api_sink,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6319,7 +6304,7 @@ fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_pull_progress",
+            debug_name: "rag_ask_ai_stream_cloud_gateway",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6335,9 +6320,14 @@ fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             let api_sink =
                 <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
@@ -6345,12 +6335,17 @@ fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_localdir_pull_progress(
+                    crate::api::core::rag_ask_ai_stream_cloud_gateway(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6358,7 +6353,7 @@ fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6366,7 +6361,7 @@ fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_localdir_push_progress",
+            debug_name: "rag_ask_ai_stream_cloud_gateway_time_window",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6382,9 +6377,16 @@ fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_local_dir = <String>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             let api_sink =
                 <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
@@ -6392,12 +6394,19 @@ fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_localdir_push_progress(
+                    crate::api::core::rag_ask_ai_stream_cloud_gateway_time_window(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_local_dir,
-                        api_remote_root,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_time_start_ms,
+                        api_time_end_ms,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6405,7 +6414,7 @@ fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6413,7 +6422,7 @@ fn wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_pull_progress",
+            debug_name: "rag_ask_ai_stream_cloud_gateway_with_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6429,24 +6438,34 @@ fn wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_id_token = <String>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_embeddings_model_name = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
                 );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_managed_vault_pull_progress(
+                    crate::api::core::rag_ask_ai_stream_cloud_gateway_with_embeddings(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_id_token,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_embeddings_model_name,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6454,7 +6473,7 @@ fn wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6462,7 +6481,7 @@ fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_managed_vault_push_ops_only_progress",
+            debug_name: "rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6478,10 +6497,17 @@ fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_im
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_vault_id = <String>::sse_decode(&mut deserializer);
-            let api_id_token = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_gateway_base_url = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <String>::sse_decode(&mut deserializer);
+            let api_embeddings_model_name = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             let api_sink =
                 <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
@@ -6489,13 +6515,20 @@ fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_im
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_managed_vault_push_ops_only_progress(
+                    crate::api::core::rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_vault_id,
-                        api_id_token,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_time_start_ms,
+                        api_time_end_ms,
+                        api_gateway_base_url,
+                        api_firebase_id_token,
+                        api_model_name,
+                        api_embeddings_model_name,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6503,7 +6536,7 @@ fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_im
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6511,7 +6544,7 @@ fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_pull_progress",
+            debug_name: "rag_ask_ai_stream_time_window",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6527,11 +6560,14 @@ fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_local_day = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             let api_sink =
                 <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
@@ -6539,14 +6575,17 @@ fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_webdav_pull_progress(
+                    crate::api::core::rag_ask_ai_stream_time_window(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_time_start_ms,
+                        api_time_end_ms,
+                        api_local_day,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6554,7 +6593,7 @@ fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
         },
     )
 }
-fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6562,7 +6601,7 @@ fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_webdav_push_ops_only_progress",
+            debug_name: "rag_ask_ai_stream_with_brok_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6578,11 +6617,12 @@ fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_base_url = <String>::sse_decode(&mut deserializer);
-            let api_username = <Option<String>>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_local_day = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
             let api_sink =
                 <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
                     &mut deserializer,
@@ -6590,14 +6630,15 @@ fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::sync_progress::sync_webdav_push_ops_only_progress(
+                    crate::api::core::rag_ask_ai_stream_with_brok_embeddings(
                         api_app_dir,
                         api_key,
-                        api_sync_key,
-                        api_base_url,
-                        api_username,
-                        api_password,
-                        api_remote_root,
+                        api_conversation_id,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_local_day,
+                        api_tz,
                         api_sink,
                     )
                 })())
@@ -6605,7 +6646,7 @@ fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
         },
     )
 }
-fn wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
+fn wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6613,7 +6654,7 @@ fn wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_message_ids_by_tag_ids",
+            debug_name: "rag_ask_ai_stream_with_brok_embeddings_time_window",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6630,22 +6671,39 @@ fn wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_tag_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <u32>::sse_decode(&mut deserializer);
+            let api_this_thread_only = <bool>::sse_decode(&mut deserializer);
+            let api_time_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_time_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_local_day = <String>::sse_decode(&mut deserializer);
+            let api_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_list_message_ids_by_tag_ids(
+                    crate::api::core::rag_ask_ai_stream_with_brok_embeddings_time_window(
                         api_app_dir,
                         api_key,
                         api_conversation_id,
-                        api_tag_ids,
+                        api_question,
+                        api_top_k,
+                        api_this_thread_only,
+                        api_time_start_ms,
+                        api_time_end_ms,
+                        api_local_day,
+                        api_tz,
+                        api_sink,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_list_message_suggested_tags_impl(
+fn wire__crate__api__core__sync_caldav_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6653,7 +6711,7 @@ fn wire__crate__api__tags__db_list_message_suggested_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_message_suggested_tags",
+            debug_name: "sync_caldav",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6669,21 +6727,29 @@ fn wire__crate__api__tags__db_list_message_suggested_tags_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_collection_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_default_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_list_message_suggested_tags(
+                    crate::api::core::sync_caldav(
                         api_app_dir,
                         api_key,
-                        api_message_id,
+                        api_collection_url,
+                        api_username,
+                        api_password,
+                        api_default_tz,
+                        api_now_ms,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_list_message_tags_impl(
+fn wire__crate__api__core__sync_derive_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6691,7 +6757,7 @@ fn wire__crate__api__tags__db_list_message_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_message_tags",
+            debug_name: "sync_derive_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6705,19 +6771,15 @@ fn wire__crate__api__tags__db_list_message_tags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse((move || {
-                    crate::api::tags::db_list_message_tags(api_app_dir, api_key, api_message_id)
-                })())
+                transform_result_sse((move || crate::api::core::sync_derive_key(api_passphrase))())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
+fn wire__crate__api__core__sync_localdir_clear_remote_root_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6725,7 +6787,7 @@ fn wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_tag_merge_suggestions",
+            debug_name: "sync_localdir_clear_remote_root",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6739,19 +6801,21 @@ fn wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_list_tag_merge_suggestions(api_app_dir, api_key, api_limit)
+                    crate::api::core::sync_localdir_clear_remote_root(
+                        api_local_dir,
+                        api_remote_root,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_list_tags_impl(
+fn wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6759,7 +6823,7 @@ fn wire__crate__api__tags__db_list_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_list_tags",
+            debug_name: "sync_localdir_download_attachment_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6775,16 +6839,27 @@ fn wire__crate__api__tags__db_list_tags_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_list_tags(api_app_dir, api_key)
+                    crate::api::core::sync_localdir_download_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
+                        api_sha256,
+                    )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_merge_tags_impl(
+fn wire__crate__api__core__sync_localdir_pull_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6792,7 +6867,7 @@ fn wire__crate__api__tags__db_merge_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_merge_tags",
+            debug_name: "sync_localdir_pull",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6808,23 +6883,25 @@ fn wire__crate__api__tags__db_merge_tags_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_source_tag_id = <String>::sse_decode(&mut deserializer);
-            let api_target_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_merge_tags(
+                    crate::api::core::sync_localdir_pull(
                         api_app_dir,
                         api_key,
-                        api_source_tag_id,
-                        api_target_tag_id,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_record_tag_merge_feedback_impl(
+fn wire__crate__api__core__sync_localdir_push_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6832,7 +6909,7 @@ fn wire__crate__api__tags__db_record_tag_merge_feedback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_record_tag_merge_feedback",
+            debug_name: "sync_localdir_push",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6848,27 +6925,25 @@ fn wire__crate__api__tags__db_record_tag_merge_feedback_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_source_tag_id = <String>::sse_decode(&mut deserializer);
-            let api_target_tag_id = <String>::sse_decode(&mut deserializer);
-            let api_reason = <String>::sse_decode(&mut deserializer);
-            let api_action = <String>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_record_tag_merge_feedback(
+                    crate::api::core::sync_localdir_push(
                         api_app_dir,
                         api_key,
-                        api_source_tag_id,
-                        api_target_tag_id,
-                        api_reason,
-                        api_action,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_set_message_tags_impl(
+fn wire__crate__api__core__sync_localdir_push_ops_only_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6876,7 +6951,7 @@ fn wire__crate__api__tags__db_set_message_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_set_message_tags",
+            debug_name: "sync_localdir_push_ops_only",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6892,23 +6967,25 @@ fn wire__crate__api__tags__db_set_message_tags_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_app_dir = <String>::sse_decode(&mut deserializer);
             let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_tag_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_set_message_tags(
+                    crate::api::core::sync_localdir_push_ops_only(
                         api_app_dir,
                         api_key,
-                        api_message_id,
-                        api_tag_ids,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
                     )
                 })())
             }
         },
     )
 }
-fn wire__crate__api__tags__db_upsert_tag_impl(
+fn wire__crate__api__core__sync_localdir_test_connection_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6916,7 +6993,7 @@ fn wire__crate__api__tags__db_upsert_tag_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "db_upsert_tag",
+            debug_name: "sync_localdir_test_connection",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6930,13 +7007,2681 @@ fn wire__crate__api__tags__db_upsert_tag_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_dir = <String>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_localdir_test_connection(api_local_dir, api_remote_root)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_localdir_upload_attachment_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_localdir_upload_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
+                        api_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_clear_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_clear_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_device_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_clear_device(
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                        api_device_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_clear_vault_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_clear_vault",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_clear_vault(
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_download_attachment_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_download_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                        api_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_pull_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_pull",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_pull(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_push_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_push",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_push(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_push_ops_only",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_push_ops_only(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_upload_attachment_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_firebase_id_token = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_managed_vault_upload_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_firebase_id_token,
+                        api_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_clear_remote_root_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_clear_remote_root",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_clear_remote_root(
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_download_attachment_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_download_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                        api_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_pull_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_pull",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_pull(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_push_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_push",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_push(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_push_ops_only_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_push_ops_only",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_push_ops_only(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_test_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_test_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_test_connection(
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_upload_attachment_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sha256 = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::core::sync_webdav_upload_attachment_bytes(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                        api_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop_media__desktop_ocr_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "desktop_ocr_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_language_hints = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::desktop_media::desktop_ocr_image(api_bytes, api_language_hints)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop_media__desktop_ocr_pdf_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "desktop_ocr_pdf",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_pages = <u32>::sse_decode(&mut deserializer);
+            let api_dpi = <u32>::sse_decode(&mut deserializer);
+            let api_language_hints = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::desktop_media::desktop_ocr_pdf(
+                        api_bytes,
+                        api_max_pages,
+                        api_dpi,
+                        api_language_hints,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__export__db_export_ics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_export_ics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::export::db_export_ics(
+                        api_app_dir,
+                        api_key,
+                        api_out_path,
+                        api_now_ms,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__export__db_export_vault_jsonl_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_export_vault_jsonl",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_out_dir = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::export::db_export_vault_jsonl(
+                        api_app_dir,
+                        api_key,
+                        api_out_dir,
+                        api_now_ms,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__export__verify_vault_jsonl_export_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_vault_jsonl_export",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_export_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::export::verify_vault_jsonl_export(api_export_dir)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import__db_import_ics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_import_ics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_ics_path = <String>::sse_decode(&mut deserializer);
+            let api_default_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_preview = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::import::db_import_ics(
+                        api_app_dir,
+                        api_key,
+                        api_ics_path,
+                        api_default_tz,
+                        api_preview,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import__db_import_markdown_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_import_markdown_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_root_dir = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_default_tz = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::import::db_import_markdown_folder(
+                        api_app_dir,
+                        api_key,
+                        api_root_dir,
+                        api_lang,
+                        api_default_tz,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import__db_import_telegram_export_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_import_telegram_export",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_json_path = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_default_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_preview = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::import::db_import_telegram_export(
+                        api_app_dir,
+                        api_key,
+                        api_json_path,
+                        api_lang,
+                        api_default_tz,
+                        api_preview,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__import__db_import_whatsapp_export_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_import_whatsapp_export",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_txt_path = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_default_tz = <Option<String>>::sse_decode(&mut deserializer);
+            let api_preview = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::import::db_import_whatsapp_export(
+                        api_app_dir,
+                        api_key,
+                        api_txt_path,
+                        api_lang,
+                        api_default_tz,
+                        api_preview,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_llm__local_llm_delete_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_llm_delete_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::local_llm::local_llm_delete_model(api_app_dir, api_file_name)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_llm__local_llm_install_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_llm_install_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_source_path = <String>::sse_decode(&mut deserializer);
+            let api_expected_sha256 = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::local_llm::local_llm_install_model(
+                        api_app_dir,
+                        api_source_path,
+                        api_expected_sha256,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_llm__local_llm_list_models_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_llm_list_models",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::local_llm::local_llm_list_models(api_app_dir)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_llm__local_llm_model_sha256_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_llm_model_sha256",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::local_llm::local_llm_model_sha256(api_app_dir, api_file_name)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__local_llm__local_llm_runtime_available_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_llm_runtime_available",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::local_llm::local_llm_runtime_available(
+                        api_app_dir,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__maintenance__db_check_integrity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_check_integrity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_fix = <bool>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::maintenance::db_check_integrity(
+                        api_app_dir,
+                        api_key,
+                        api_fix,
+                        api_now_ms,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__maintenance__db_storage_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_storage_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::maintenance::db_storage_usage(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_get_media_annotation_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::media_annotation::db_get_media_annotation_config(
+                        api_app_dir,
+                        api_key,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_set_media_annotation_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_config = <crate::db::MediaAnnotationConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::media_annotation::db_set_media_annotation_config(
+                        api_app_dir,
+                        api_key,
+                        api_config,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "media_annotation_byok_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_local_day = <String>::sse_decode(&mut deserializer);
+            let api_lang = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::media_annotation::media_annotation_byok_profile(
+                        api_app_dir,
+                        api_key,
+                        api_profile_id,
+                        api_local_day,
+                        api_lang,
+                        api_mime_type,
+                        api_image_bytes,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_delete_prompt_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_delete_prompt_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_template_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_delete_prompt_template(
+                        api_app_dir,
+                        api_key,
+                        api_template_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_list_prompt_template_selections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_prompt_template_selections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_list_prompt_template_selections(
+                        api_app_dir,
+                        api_key,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_list_prompt_templates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_prompt_templates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_list_prompt_templates(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_reset_prompt_template_selections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_reset_prompt_template_selections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_reset_prompt_template_selections(
+                        api_app_dir,
+                        api_key,
+                        api_purpose,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_reset_prompt_template_to_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_reset_prompt_template_to_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_template_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_reset_prompt_template_to_default(
+                        api_app_dir,
+                        api_key,
+                        api_template_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_set_prompt_template_selection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_set_prompt_template_selection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            let api_profile_id = <String>::sse_decode(&mut deserializer);
+            let api_template_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_set_prompt_template_selection(
+                        api_app_dir,
+                        api_key,
+                        api_purpose,
+                        api_profile_id,
+                        api_template_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__db_upsert_prompt_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_upsert_prompt_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_template_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::db_upsert_prompt_template(
+                        api_app_dir,
+                        api_key,
+                        api_template_id,
+                        api_name,
+                        api_purpose,
+                        api_body,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__prompt_template_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prompt_template_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::prompt_template_default(api_purpose)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__prompt_templates__prompt_template_validate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prompt_template_validate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_purpose = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::prompt_templates::prompt_template_validate(api_purpose, api_body)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::greet(api_name))
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_localdir_pull_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_localdir_pull_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_localdir_push_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_localdir_push_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_local_dir,
+                        api_remote_root,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_pull_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_id_token = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_managed_vault_pull_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_id_token,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_managed_vault_push_ops_only_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_vault_id = <String>::sse_decode(&mut deserializer);
+            let api_id_token = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_managed_vault_push_ops_only_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_vault_id,
+                        api_id_token,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_pull_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_webdav_pull_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_webdav_push_ops_only_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_sync_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_username = <Option<String>>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_remote_root = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::sync_progress::sync_webdav_push_ops_only_progress(
+                        api_app_dir,
+                        api_key,
+                        api_sync_key,
+                        api_base_url,
+                        api_username,
+                        api_password,
+                        api_remote_root,
+                        api_sink,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_backfill_tag_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_backfill_tag_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rule_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_backfill_tag_rules(
+                        api_app_dir,
+                        api_key,
+                        api_rule_id,
+                        api_now_ms,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_delete_tag_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_delete_tag_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rule_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_delete_tag_rule(api_app_dir, api_key, api_rule_id)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_message_ids_by_tag_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_tag_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_message_ids_by_tag_ids(
+                        api_app_dir,
+                        api_key,
+                        api_conversation_id,
+                        api_tag_ids,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_message_suggested_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_message_suggested_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_message_suggested_tags(
+                        api_app_dir,
+                        api_key,
+                        api_message_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_message_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_message_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_message_tags(api_app_dir, api_key, api_message_id)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_tag_merge_suggestions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_tag_merge_suggestions(api_app_dir, api_key, api_limit)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_tag_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_tag_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_tag_rules(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_tag_tree_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_tag_tree",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_tag_tree(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_list_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_list_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_list_tags(api_app_dir, api_key)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_merge_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_merge_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_source_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_target_tag_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_merge_tags(
+                        api_app_dir,
+                        api_key,
+                        api_source_tag_id,
+                        api_target_tag_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_record_tag_merge_feedback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_record_tag_merge_feedback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_source_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_target_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
+            let api_action = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_record_tag_merge_feedback(
+                        api_app_dir,
+                        api_key,
+                        api_source_tag_id,
+                        api_target_tag_id,
+                        api_reason,
+                        api_action,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_set_message_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_set_message_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_tag_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_set_message_tags(
+                        api_app_dir,
+                        api_key,
+                        api_message_id,
+                        api_tag_ids,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_set_tag_parent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_set_tag_parent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_set_tag_parent(
+                        api_app_dir,
+                        api_key,
+                        api_tag_id,
+                        api_parent_id,
+                    )
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_upsert_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_upsert_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_upsert_tag(api_app_dir, api_key, api_name)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_upsert_tag_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_upsert_tag_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::tags::db_upsert_tag_path(api_app_dir, api_key, api_path)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__db_upsert_tag_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "db_upsert_tag_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_app_dir = <String>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rule_id = <Option<String>>::sse_decode(&mut deserializer);
             let api_name = <String>::sse_decode(&mut deserializer);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_pattern = <String>::sse_decode(&mut deserializer);
+            let api_tag_id = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse((move || {
-                    crate::api::tags::db_upsert_tag(api_app_dir, api_key, api_name)
+                    crate::api::tags::db_upsert_tag_rule(
+                        api_app_dir,
+                        api_key,
+                        api_rule_id,
+                        api_name,
+                        api_kind,
+                        api_pattern,
+                        api_tag_id,
+                        api_enabled,
+                    )
                 })())
             }
         },
@@ -6969,6 +9714,60 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::db::AgendaDay {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_dayStartMs = <i64>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::db::AgendaItem>>::sse_decode(deserializer);
+        return crate::db::AgendaDay {
+            date: var_date,
+            day_start_ms: var_dayStartMs,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::db::AgendaItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_itemId = <String>::sse_decode(deserializer);
+        let mut var_sourceId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_atMs = <i64>::sse_decode(deserializer);
+        let mut var_endAtMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_status = <Option<String>>::sse_decode(deserializer);
+        let mut var_tz = <Option<String>>::sse_decode(deserializer);
+        let mut var_isRecurring = <bool>::sse_decode(deserializer);
+        return crate::db::AgendaItem {
+            kind: var_kind,
+            item_id: var_itemId,
+            source_id: var_sourceId,
+            title: var_title,
+            at_ms: var_atMs,
+            end_at_ms: var_endAtMs,
+            status: var_status,
+            tz: var_tz,
+            is_recurring: var_isRecurring,
+        };
+    }
+}
+
+impl SseDecode for crate::db::AgendaPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_overdue = <Vec<crate::db::AgendaItem>>::sse_decode(deserializer);
+        let mut var_days = <Vec<crate::db::AgendaDay>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        return crate::db::AgendaPage {
+            overdue: var_overdue,
+            days: var_days,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
 impl SseDecode for crate::db::Attachment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7098,6 +9897,30 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::sync::caldav::CalDavSyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pulledCreated = <u32>::sse_decode(deserializer);
+        let mut var_pulledUpdated = <u32>::sse_decode(deserializer);
+        let mut var_pulledDeleted = <u32>::sse_decode(deserializer);
+        let mut var_pushedCreated = <u32>::sse_decode(deserializer);
+        let mut var_pushedUpdated = <u32>::sse_decode(deserializer);
+        let mut var_pushedDeleted = <u32>::sse_decode(deserializer);
+        let mut var_conflicts = <u32>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::sync::caldav::CalDavSyncReport {
+            pulled_created: var_pulledCreated,
+            pulled_updated: var_pulledUpdated,
+            pulled_deleted: var_pulledDeleted,
+            pushed_created: var_pushedCreated,
+            pushed_updated: var_pushedUpdated,
+            pushed_deleted: var_pushedDeleted,
+            conflicts: var_conflicts,
+            errors: var_errors,
+        };
+    }
+}
+
 impl SseDecode for crate::db::CloudMediaBackup {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7250,58 +10073,386 @@ impl SseDecode for crate::db::Event {
     }
 }
 
-impl SseDecode for f64 {
+impl SseDecode for crate::db::EventOccurrence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventId = <String>::sse_decode(deserializer);
+        let mut var_occurrenceStartMs = <i64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_startAtMs = <i64>::sse_decode(deserializer);
+        let mut var_endAtMs = <i64>::sse_decode(deserializer);
+        let mut var_tz = <String>::sse_decode(deserializer);
+        let mut var_isRecurring = <bool>::sse_decode(deserializer);
+        let mut var_isMoved = <bool>::sse_decode(deserializer);
+        return crate::db::EventOccurrence {
+            event_id: var_eventId,
+            occurrence_start_ms: var_occurrenceStartMs,
+            title: var_title,
+            start_at_ms: var_startAtMs,
+            end_at_ms: var_endAtMs,
+            tz: var_tz,
+            is_recurring: var_isRecurring,
+            is_moved: var_isMoved,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::export::ics::IcsExportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_events = <u64>::sse_decode(deserializer);
+        let mut var_todos = <u64>::sse_decode(deserializer);
+        return crate::export::ics::IcsExportSummary {
+            events: var_events,
+            todos: var_todos,
+        };
+    }
+}
+
+impl SseDecode for crate::import::ImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_itemsScanned = <u32>::sse_decode(deserializer);
+        let mut var_itemsImported = <u32>::sse_decode(deserializer);
+        let mut var_itemsUpdated = <u32>::sse_decode(deserializer);
+        let mut var_itemsSkippedUnchanged = <u32>::sse_decode(deserializer);
+        let mut var_itemsSkippedDuplicate = <u32>::sse_decode(deserializer);
+        let mut var_messagesCreated = <u32>::sse_decode(deserializer);
+        let mut var_todosCreated = <u32>::sse_decode(deserializer);
+        let mut var_attachmentsLinked = <u32>::sse_decode(deserializer);
+        let mut var_tagsApplied = <u32>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::import::ImportReport {
+            items_scanned: var_itemsScanned,
+            items_imported: var_itemsImported,
+            items_updated: var_itemsUpdated,
+            items_skipped_unchanged: var_itemsSkippedUnchanged,
+            items_skipped_duplicate: var_itemsSkippedDuplicate,
+            messages_created: var_messagesCreated,
+            todos_created: var_todosCreated,
+            attachments_linked: var_attachmentsLinked,
+            tags_applied: var_tagsApplied,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for crate::db::IntegrityIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_fixed = <bool>::sse_decode(deserializer);
+        return crate::db::IntegrityIssue {
+            kind: var_kind,
+            subject: var_subject,
+            fixed: var_fixed,
+        };
+    }
+}
+
+impl SseDecode for crate::db::IntegrityReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_issues = <Vec<crate::db::IntegrityIssue>>::sse_decode(deserializer);
+        let mut var_fixedCount = <u32>::sse_decode(deserializer);
+        return crate::db::IntegrityReport {
+            issues: var_issues,
+            fixed_count: var_fixedCount,
+        };
+    }
+}
+
+impl SseDecode for crate::export::jsonl::JsonlExportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_schemaVersion = <i64>::sse_decode(deserializer);
+        let mut var_exportedAtMs = <i64>::sse_decode(deserializer);
+        let mut var_streams =
+            <Vec<crate::export::jsonl::JsonlStreamCount>>::sse_decode(deserializer);
+        return crate::export::jsonl::JsonlExportSummary {
+            schema_version: var_schemaVersion,
+            exported_at_ms: var_exportedAtMs,
+            streams: var_streams,
+        };
+    }
+}
+
+impl SseDecode for crate::export::jsonl::JsonlExportVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_schemaVersion = <i64>::sse_decode(deserializer);
+        let mut var_streams =
+            <Vec<crate::export::jsonl::JsonlStreamCount>>::sse_decode(deserializer);
+        let mut var_problems = <Vec<String>>::sse_decode(deserializer);
+        return crate::export::jsonl::JsonlExportVerification {
+            schema_version: var_schemaVersion,
+            streams: var_streams,
+            problems: var_problems,
+        };
+    }
+}
+
+impl SseDecode for crate::export::jsonl::JsonlStreamCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stream = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::export::jsonl::JsonlStreamCount {
+            stream: var_stream,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::AgendaDay> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::AgendaDay>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::AgendaItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::AgendaItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::Attachment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::Attachment>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::AttachmentAnnotationJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::AttachmentAnnotationJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::AttachmentPlaceJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::AttachmentPlaceJob>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::CloudMediaBackup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::CloudMediaBackup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::Conversation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::Conversation>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::EmbeddingProfile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::EmbeddingProfile>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::Event> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::Event>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::EventOccurrence> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::EventOccurrence>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::IntegrityIssue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::IntegrityIssue>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for Vec<crate::export::jsonl::JsonlStreamCount> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::export::jsonl::JsonlStreamCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for i64 {
+impl SseDecode for Vec<crate::db::LlmModelPrice> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::LlmModelPrice>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<crate::db::LlmMonthlyCost> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<crate::db::LlmMonthlyCost>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::Attachment> {
+impl SseDecode for Vec<crate::db::LlmProfile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::Attachment>::sse_decode(deserializer));
+            ans_.push(<crate::db::LlmProfile>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::AttachmentAnnotationJob> {
+impl SseDecode for Vec<crate::db::LlmSpendingLimit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::AttachmentAnnotationJob>::sse_decode(
+            ans_.push(<crate::db::LlmSpendingLimit>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::LlmUsageAggregate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::LlmUsageAggregate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::llm::local_gguf::LocalGgufModelInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::llm::local_gguf::LocalGgufModelInfo>::sse_decode(
                 deserializer,
             ));
         }
@@ -7309,109 +10460,113 @@ impl SseDecode for Vec<crate::db::AttachmentAnnotationJob> {
     }
 }
 
-impl SseDecode for Vec<crate::db::AttachmentPlaceJob> {
+impl SseDecode for Vec<crate::db::Message> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::AttachmentPlaceJob>::sse_decode(deserializer));
+            ans_.push(<crate::db::Message>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::CloudMediaBackup> {
+impl SseDecode for Vec<crate::db::MessageCitation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::CloudMediaBackup>::sse_decode(deserializer));
+            ans_.push(<crate::db::MessageCitation>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::Conversation> {
+impl SseDecode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::Conversation>::sse_decode(deserializer));
+            ans_.push(<i64>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::EmbeddingProfile> {
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::EmbeddingProfile>::sse_decode(deserializer));
+            ans_.push(<u8>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::Event> {
+impl SseDecode for Vec<crate::db::PromptTemplate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::Event>::sse_decode(deserializer));
+            ans_.push(<crate::db::PromptTemplate>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::LlmProfile> {
+impl SseDecode for Vec<crate::db::PromptTemplateSelection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::LlmProfile>::sse_decode(deserializer));
+            ans_.push(<crate::db::PromptTemplateSelection>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::LlmUsageAggregate> {
+impl SseDecode for Vec<crate::db::ReviewActivity> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::LlmUsageAggregate>::sse_decode(deserializer));
+            ans_.push(<crate::db::ReviewActivity>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::db::Message> {
+impl SseDecode for Vec<crate::db::ReviewItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::db::Message>::sse_decode(deserializer));
+            ans_.push(<crate::db::ReviewItem>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::db::SemanticParseFailureReasonCount> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::db::SemanticParseFailureReasonCount>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
@@ -7453,6 +10608,18 @@ impl SseDecode for Vec<crate::db::SimilarTodoThread> {
     }
 }
 
+impl SseDecode for Vec<crate::db::StorageUsageEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::StorageUsageEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::db::Tag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7477,6 +10644,30 @@ impl SseDecode for Vec<crate::db::TagMergeSuggestion> {
     }
 }
 
+impl SseDecode for Vec<crate::db::TagNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::TagNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::db::TagRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::db::TagRule>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::db::Todo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7515,6 +10706,46 @@ impl SseDecode for Vec<crate::semantic_parse::TodoCandidate> {
     }
 }
 
+impl SseDecode for crate::db::LlmModelPrice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_providerType = <String>::sse_decode(deserializer);
+        let mut var_modelName = <String>::sse_decode(deserializer);
+        let mut var_inputUsdPerMillionTokens = <f64>::sse_decode(deserializer);
+        let mut var_outputUsdPerMillionTokens = <f64>::sse_decode(deserializer);
+        let mut var_updatedAtMs = <i64>::sse_decode(deserializer);
+        return crate::db::LlmModelPrice {
+            provider_type: var_providerType,
+            model_name: var_modelName,
+            input_usd_per_million_tokens: var_inputUsdPerMillionTokens,
+            output_usd_per_million_tokens: var_outputUsdPerMillionTokens,
+            updated_at_ms: var_updatedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::db::LlmMonthlyCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_profileId = <String>::sse_decode(deserializer);
+        let mut var_purpose = <String>::sse_decode(deserializer);
+        let mut var_requests = <i64>::sse_decode(deserializer);
+        let mut var_requestsWithUsage = <i64>::sse_decode(deserializer);
+        let mut var_inputTokens = <i64>::sse_decode(deserializer);
+        let mut var_outputTokens = <i64>::sse_decode(deserializer);
+        let mut var_costUsdMicros = <i64>::sse_decode(deserializer);
+        return crate::db::LlmMonthlyCost {
+            profile_id: var_profileId,
+            purpose: var_purpose,
+            requests: var_requests,
+            requests_with_usage: var_requestsWithUsage,
+            input_tokens: var_inputTokens,
+            output_tokens: var_outputTokens,
+            cost_usd_micros: var_costUsdMicros,
+        };
+    }
+}
+
 impl SseDecode for crate::db::LlmProfile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7539,6 +10770,18 @@ impl SseDecode for crate::db::LlmProfile {
     }
 }
 
+impl SseDecode for crate::db::LlmSpendingLimit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_profileId = <String>::sse_decode(deserializer);
+        let mut var_monthlyLimitUsdMicros = <i64>::sse_decode(deserializer);
+        return crate::db::LlmSpendingLimit {
+            profile_id: var_profileId,
+            monthly_limit_usd_micros: var_monthlyLimitUsdMicros,
+        };
+    }
+}
+
 impl SseDecode for crate::db::LlmUsageAggregate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7559,6 +10802,24 @@ impl SseDecode for crate::db::LlmUsageAggregate {
     }
 }
 
+impl SseDecode for crate::llm::local_gguf::LocalGgufModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fileName = <String>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_architecture = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_contextLength = <Option<u32>>::sse_decode(deserializer);
+        return crate::llm::local_gguf::LocalGgufModelInfo {
+            file_name: var_fileName,
+            size_bytes: var_sizeBytes,
+            architecture: var_architecture,
+            name: var_name,
+            context_length: var_contextLength,
+        };
+    }
+}
+
 impl SseDecode for crate::db::MediaAnnotationConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7599,6 +10860,20 @@ impl SseDecode for crate::db::Message {
     }
 }
 
+impl SseDecode for crate::db::MessageCitation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_marker = <u32>::sse_decode(deserializer);
+        let mut var_sourceKind = <String>::sse_decode(deserializer);
+        let mut var_sourceId = <String>::sse_decode(deserializer);
+        return crate::db::MessageCitation {
+            marker: var_marker,
+            source_kind: var_sourceKind,
+            source_id: var_sourceId,
+        };
+    }
+}
+
 impl SseDecode for crate::desktop_media::ocr::OcrPayload {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7687,6 +10962,115 @@ impl SseDecode for Option<crate::db::Message> {
     }
 }
 
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::db::PromptTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_purpose = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_createdAtMs = <i64>::sse_decode(deserializer);
+        let mut var_updatedAtMs = <i64>::sse_decode(deserializer);
+        return crate::db::PromptTemplate {
+            id: var_id,
+            name: var_name,
+            purpose: var_purpose,
+            body: var_body,
+            created_at_ms: var_createdAtMs,
+            updated_at_ms: var_updatedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::db::PromptTemplateSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_purpose = <String>::sse_decode(deserializer);
+        let mut var_profileId = <String>::sse_decode(deserializer);
+        let mut var_templateId = <String>::sse_decode(deserializer);
+        return crate::db::PromptTemplateSelection {
+            purpose: var_purpose,
+            profile_id: var_profileId,
+            template_id: var_templateId,
+        };
+    }
+}
+
+impl SseDecode for crate::db::ReviewActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_itemKind = <String>::sse_decode(deserializer);
+        let mut var_itemId = <String>::sse_decode(deserializer);
+        let mut var_grade = <i64>::sse_decode(deserializer);
+        let mut var_easeFactor = <f64>::sse_decode(deserializer);
+        let mut var_intervalDays = <i64>::sse_decode(deserializer);
+        let mut var_dueAtMs = <i64>::sse_decode(deserializer);
+        let mut var_reviewedAtMs = <i64>::sse_decode(deserializer);
+        return crate::db::ReviewActivity {
+            id: var_id,
+            item_kind: var_itemKind,
+            item_id: var_itemId,
+            grade: var_grade,
+            ease_factor: var_easeFactor,
+            interval_days: var_intervalDays,
+            due_at_ms: var_dueAtMs,
+            reviewed_at_ms: var_reviewedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::db::ReviewItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_itemKind = <String>::sse_decode(deserializer);
+        let mut var_itemId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_easeFactor = <f64>::sse_decode(deserializer);
+        let mut var_intervalDays = <i64>::sse_decode(deserializer);
+        let mut var_repetitions = <i64>::sse_decode(deserializer);
+        let mut var_lapses = <i64>::sse_decode(deserializer);
+        let mut var_dueAtMs = <i64>::sse_decode(deserializer);
+        let mut var_lastReviewedAtMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_isActive = <bool>::sse_decode(deserializer);
+        return crate::db::ReviewItem {
+            item_kind: var_itemKind,
+            item_id: var_itemId,
+            title: var_title,
+            ease_factor: var_easeFactor,
+            interval_days: var_intervalDays,
+            repetitions: var_repetitions,
+            lapses: var_lapses,
+            due_at_ms: var_dueAtMs,
+            last_reviewed_at_ms: var_lastReviewedAtMs,
+            is_active: var_isActive,
+        };
+    }
+}
+
+impl SseDecode for crate::db::SemanticParseFailureReasonCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reason = <String>::sse_decode(deserializer);
+        let mut var_jobs = <i64>::sse_decode(deserializer);
+        return crate::db::SemanticParseFailureReasonCount {
+            reason: var_reason,
+            jobs: var_jobs,
+        };
+    }
+}
+
 impl SseDecode for crate::db::SemanticParseJob {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7761,6 +11145,38 @@ impl SseDecode for crate::db::StoragePolicyConfig {
     }
 }
 
+impl SseDecode for crate::db::StorageUsageEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_bytes = <i64>::sse_decode(deserializer);
+        let mut var_count = <i64>::sse_decode(deserializer);
+        return crate::db::StorageUsageEntry {
+            category: var_category,
+            label: var_label,
+            bytes: var_bytes,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::db::StorageUsageReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalBytes = <i64>::sse_decode(deserializer);
+        let mut var_categories = <Vec<crate::db::StorageUsageEntry>>::sse_decode(deserializer);
+        let mut var_byConversation = <Vec<crate::db::StorageUsageEntry>>::sse_decode(deserializer);
+        let mut var_byMimeType = <Vec<crate::db::StorageUsageEntry>>::sse_decode(deserializer);
+        return crate::db::StorageUsageReport {
+            total_bytes: var_totalBytes,
+            categories: var_categories,
+            by_conversation: var_byConversation,
+            by_mime_type: var_byMimeType,
+        };
+    }
+}
+
 impl SseDecode for crate::db::Tag {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7786,19 +11202,59 @@ impl SseDecode for crate::db::Tag {
 impl SseDecode for crate::db::TagMergeSuggestion {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourceTag = <crate::db::Tag>::sse_decode(deserializer);
-        let mut var_targetTag = <crate::db::Tag>::sse_decode(deserializer);
-        let mut var_reason = <String>::sse_decode(deserializer);
-        let mut var_score = <f64>::sse_decode(deserializer);
-        let mut var_sourceUsageCount = <i64>::sse_decode(deserializer);
-        let mut var_targetUsageCount = <i64>::sse_decode(deserializer);
-        return crate::db::TagMergeSuggestion {
-            source_tag: var_sourceTag,
-            target_tag: var_targetTag,
-            reason: var_reason,
-            score: var_score,
-            source_usage_count: var_sourceUsageCount,
-            target_usage_count: var_targetUsageCount,
+        let mut var_sourceTag = <crate::db::Tag>::sse_decode(deserializer);
+        let mut var_targetTag = <crate::db::Tag>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_sourceUsageCount = <i64>::sse_decode(deserializer);
+        let mut var_targetUsageCount = <i64>::sse_decode(deserializer);
+        return crate::db::TagMergeSuggestion {
+            source_tag: var_sourceTag,
+            target_tag: var_targetTag,
+            reason: var_reason,
+            score: var_score,
+            source_usage_count: var_sourceUsageCount,
+            target_usage_count: var_targetUsageCount,
+        };
+    }
+}
+
+impl SseDecode for crate::db::TagNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tag = <crate::db::Tag>::sse_decode(deserializer);
+        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        return crate::db::TagNode {
+            tag: var_tag,
+            parent_id: var_parentId,
+            path: var_path,
+            depth: var_depth,
+        };
+    }
+}
+
+impl SseDecode for crate::db::TagRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_pattern = <String>::sse_decode(deserializer);
+        let mut var_tagId = <String>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_createdAtMs = <i64>::sse_decode(deserializer);
+        let mut var_updatedAtMs = <i64>::sse_decode(deserializer);
+        return crate::db::TagRule {
+            id: var_id,
+            name: var_name,
+            kind: var_kind,
+            pattern: var_pattern,
+            tag_id: var_tagId,
+            enabled: var_enabled,
+            created_at_ms: var_createdAtMs,
+            updated_at_ms: var_updatedAtMs,
         };
     }
 }
@@ -7871,6 +11327,26 @@ impl SseDecode for crate::semantic_parse::TodoCandidate {
     }
 }
 
+impl SseDecode for crate::db::TodoRollup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_todoId = <String>::sse_decode(deserializer);
+        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_subtasksTotal = <i64>::sse_decode(deserializer);
+        let mut var_subtasksDone = <i64>::sse_decode(deserializer);
+        let mut var_openBlockerIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_completeWithSubtasks = <bool>::sse_decode(deserializer);
+        return crate::db::TodoRollup {
+            todo_id: var_todoId,
+            parent_id: var_parentId,
+            subtasks_total: var_subtasksTotal,
+            subtasks_done: var_subtasksDone,
+            open_blocker_ids: var_openBlockerIds,
+            complete_with_subtasks: var_completeWithSubtasks,
+        };
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -8042,475 +11518,652 @@ fn pde_ffi_dispatcher_primary_impl(
             data_len,
         ),
         24 => wire__crate__api__core__auth_validate_key_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__core__db_append_todo_note_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__core__db_backfill_cloud_media_backup_images_impl(
+        25 => {
+            wire__crate__api__core__db_add_todo_dependency_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__core__db_agenda_range_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__core__db_append_todo_note_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__core__db_backfill_cloud_media_backup_images_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__core__db_cancel_event_occurrence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__core__db_clear_local_attachment_cache_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        27 => wire__crate__api__core__db_clear_local_attachment_cache_impl(
+        31 => wire__crate__api__core__db_cloud_media_backup_summary_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__core__db_cloud_media_backup_summary_impl(
+        32 => wire__crate__api__core__db_count_semantic_parse_failure_reasons_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => {
+        33 => {
             wire__crate__api__core__db_create_conversation_impl(port, ptr, rust_vec_len, data_len)
         }
-        30 => wire__crate__api__core__db_create_embedding_profile_impl(
+        34 => wire__crate__api__core__db_create_embedding_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__core__db_create_llm_profile_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__core__db_delete_embedding_profile_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__core__db_delete_llm_model_price_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__core__db_delete_llm_profile_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__core__db_delete_todo_and_associated_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__core__db_edit_message_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__core__db_enqueue_attachment_annotation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__core__db_enqueue_attachment_place_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__core__db_enqueue_cloud_media_backup_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__core__db_enqueue_semantic_parse_job_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__core__db_get_active_embedding_model_name_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__core__db_create_llm_profile_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__core__db_delete_embedding_profile_impl(
+        46 => wire__crate__api__core__db_get_ask_ai_query_rewrite_enabled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => wire__crate__api__core__db_delete_llm_profile_impl(port, ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__core__db_delete_todo_and_associated_messages_impl(
+        47 => wire__crate__api__core__db_get_event_recurrence_rule_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__crate__api__core__db_edit_message_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__core__db_enqueue_attachment_annotation_impl(
+        48 => wire__crate__api__core__db_get_message_by_id_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__core__db_get_or_create_device_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__core__db_enqueue_attachment_place_impl(
+        50 => wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__core__db_enqueue_cloud_media_backup_impl(
+        51 => wire__crate__api__core__db_get_semantic_parse_job_failure_reason_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => wire__crate__api__core__db_enqueue_semantic_parse_job_impl(
+        52 => wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => wire__crate__api__core__db_get_active_embedding_model_name_impl(
+        53 => wire__crate__api__core__db_get_todo_rollup_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__core__db_get_todo_timezone_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__core__db_insert_attachment_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__core__db_insert_message_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__core__db_link_attachment_to_message_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => wire__crate__api__core__db_get_message_by_id_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__core__db_get_or_create_device_id_impl(
+        58 => wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => wire__crate__api__core__db_get_or_create_loop_home_conversation_impl(
+        59 => wire__crate__api__core__db_list_conversations_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__core__db_list_due_attachment_annotations_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__crate__api__core__db_get_todo_recurrence_rule_json_impl(
+        61 => wire__crate__api__core__db_list_due_attachment_places_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__crate__api__core__db_insert_attachment_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__core__db_insert_message_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__core__db_link_attachment_to_message_impl(
+        62 => wire__crate__api__core__db_list_due_cloud_media_backups_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__core__db_link_attachment_to_todo_activity_impl(
+        63 => wire__crate__api__core__db_list_due_reviews_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        49 => wire__crate__api__core__db_list_conversations_impl(port, ptr, rust_vec_len, data_len),
-        50 => wire__crate__api__core__db_list_due_attachment_annotations_impl(
+        65 => wire__crate__api__core__db_list_embedding_model_names_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        51 => wire__crate__api__core__db_list_due_attachment_places_impl(
+        66 => wire__crate__api__core__db_list_embedding_profiles_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        52 => wire__crate__api__core__db_list_due_cloud_media_backups_impl(
+        67 => wire__crate__api__core__db_list_event_occurrences_in_range_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => wire__crate__api__core__db_list_due_semantic_parse_jobs_impl(
+        68 => wire__crate__api__core__db_list_events_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__core__db_list_llm_fallback_chain_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__core__db_list_embedding_model_names_impl(
+        70 => {
+            wire__crate__api__core__db_list_llm_model_prices_impl(port, ptr, rust_vec_len, data_len)
+        }
+        71 => wire__crate__api__core__db_list_llm_profiles_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__core__db_list_llm_spending_limits_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__core__db_list_message_attachments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        55 => wire__crate__api__core__db_list_embedding_profiles_impl(
+        74 => wire__crate__api__core__db_list_message_citations_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__crate__api__core__db_list_events_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__core__db_list_llm_profiles_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__core__db_list_message_attachments_impl(
+        75 => wire__crate__api__core__db_list_messages_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__core__db_list_messages_page_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__core__db_list_recent_attachments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__crate__api__core__db_list_messages_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__core__db_list_messages_page_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__core__db_list_recent_attachments_impl(
+        78 => wire__crate__api__core__db_list_review_activities_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
+        79 => wire__crate__api__core__db_list_semantic_parse_jobs_by_message_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => {
+        80 => {
             wire__crate__api__core__db_list_todo_activities_impl(port, ptr, rust_vec_len, data_len)
         }
-        64 => wire__crate__api__core__db_list_todo_activities_in_range_impl(
+        81 => wire__crate__api__core__db_list_todo_activities_in_range_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__api__core__db_list_todo_activity_attachments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        65 => wire__crate__api__core__db_list_todo_activity_attachments_impl(
+        83 => wire__crate__api__core__db_list_todo_blockers_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__core__db_list_todo_subtasks_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__core__db_list_todos_impl(port, ptr, rust_vec_len, data_len),
+        86 => {
+            wire__crate__api__core__db_list_todos_blocked_by_impl(port, ptr, rust_vec_len, data_len)
+        }
+        87 => wire__crate__api__core__db_list_todos_created_in_range_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        66 => wire__crate__api__core__db_list_todos_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__core__db_list_todos_created_in_range_impl(
+        88 => wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__core__db_mark_attachment_annotation_failed_impl(
+        89 => wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__core__db_mark_attachment_annotation_ok_json_impl(
+        90 => wire__crate__api__core__db_mark_attachment_place_failed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__core__db_mark_attachment_place_failed_impl(
+        91 => wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__api__core__db_mark_attachment_place_ok_json_impl(
+        92 => wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__crate__api__core__db_mark_cloud_media_backup_failed_impl(
+        93 => wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__core__db_mark_cloud_media_backup_uploaded_impl(
+        94 => wire__crate__api__core__db_mark_for_review_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        74 => wire__crate__api__core__db_mark_semantic_parse_job_canceled_impl(
+        96 => wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__core__db_mark_semantic_parse_job_failed_impl(
+        97 => wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__core__db_mark_semantic_parse_job_retry_impl(
+        98 => wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__core__db_mark_semantic_parse_job_running_impl(
+        99 => wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__core__db_mark_semantic_parse_job_succeeded_impl(
+        100 => wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__core__db_mark_semantic_parse_job_undone_impl(
+        101 => {
+            wire__crate__api__core__db_move_event_occurrence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        102 => {
+            wire__crate__api__core__db_move_todo_activity_impl(port, ptr, rust_vec_len, data_len)
+        }
+        103 => wire__crate__api__core__db_preview_review_intervals_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__core__db_move_todo_activity_impl(port, ptr, rust_vec_len, data_len),
-        81 => wire__crate__api__core__db_process_pending_message_embeddings_impl(
+        104 => wire__crate__api__core__db_preview_todo_recurrence_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
+        105 => wire__crate__api__core__db_process_pending_message_embeddings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        83 => wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
+        106 => wire__crate__api__core__db_process_pending_todo_thread_embeddings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gateway_impl(
+        107 => wire__crate__api__core__db_process_pending_todo_thread_embeddings_brok_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        85 => wire__crate__api__core__db_purge_message_attachments_impl(
+        108 => {
+            wire__crate__api__core__db_process_pending_todo_thread_embeddings_cloud_gateway_impl(
+                port,
+                ptr,
+                rust_vec_len,
+                data_len,
+            )
+        }
+        109 => wire__crate__api__core__db_purge_message_attachments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
+        110 => wire__crate__api__core__db_read_attachment_annotation_caption_long_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        87 => {
+        111 => {
             wire__crate__api__core__db_read_attachment_bytes_impl(port, ptr, rust_vec_len, data_len)
         }
-        88 => wire__crate__api__core__db_read_attachment_exif_metadata_impl(
+        112 => wire__crate__api__core__db_read_attachment_exif_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__core__db_read_attachment_place_display_name_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__crate__api__core__db_read_attachment_variant_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__api__core__db_rebuild_message_embeddings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__core__db_record_llm_usage_daily_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__core__db_record_review_impl(port, ptr, rust_vec_len, data_len),
+        118 => wire__crate__api__core__db_remove_todo_dependency_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        89 => wire__crate__api__core__db_read_attachment_place_display_name_impl(
+        120 => wire__crate__api__core__db_restore_event_occurrence_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        90 => wire__crate__api__core__db_read_attachment_variant_bytes_impl(
+        121 => wire__crate__api__core__db_search_similar_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__core__db_rebuild_message_embeddings_impl(
+        122 => wire__crate__api__core__db_search_similar_messages_brok_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        92 => wire__crate__api__core__db_record_llm_usage_daily_impl(
+        123 => wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__core__db_reset_vault_data_preserving_llm_profiles_impl(
+        124 => wire__crate__api__core__db_search_similar_todo_threads_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        94 => wire__crate__api__core__db_search_similar_messages_impl(
+        125 => wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__api__core__db_search_similar_messages_brok_impl(
+        126 => wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        96 => wire__crate__api__core__db_search_similar_messages_cloud_gateway_impl(
+        127 => wire__crate__api__core__db_set_active_embedding_model_name_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__crate__api__core__db_search_similar_todo_threads_impl(
+        128 => wire__crate__api__core__db_set_active_embedding_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        98 => wire__crate__api__core__db_search_similar_todo_threads_brok_impl(
+        129 => wire__crate__api__core__db_set_active_llm_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        99 => wire__crate__api__core__db_search_similar_todo_threads_cloud_gateway_impl(
+        130 => wire__crate__api__core__db_set_ask_ai_query_rewrite_enabled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => wire__crate__api__core__db_set_active_embedding_model_name_impl(
+        131 => wire__crate__api__core__db_set_event_recurrence_rule_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__crate__api__core__db_set_active_embedding_profile_impl(
+        132 => wire__crate__api__core__db_set_llm_fallback_chain_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__crate__api__core__db_set_active_llm_profile_impl(
+        133 => wire__crate__api__core__db_set_llm_spending_limit_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => {
+        134 => {
             wire__crate__api__core__db_set_message_deleted_impl(port, ptr, rust_vec_len, data_len)
         }
-        104 => wire__crate__api__core__db_set_todo_status_impl(port, ptr, rust_vec_len, data_len),
-        105 => wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
+        135 => wire__crate__api__core__db_set_todo_complete_with_subtasks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        136 => wire__crate__api__core__db_set_todo_parent_impl(port, ptr, rust_vec_len, data_len),
+        137 => wire__crate__api__core__db_set_todo_status_impl(port, ptr, rust_vec_len, data_len),
+        138 => wire__crate__api__core__db_set_todo_timezone_impl(port, ptr, rust_vec_len, data_len),
+        139 => {
+            wire__crate__api__core__db_sum_llm_cost_monthly_impl(port, ptr, rust_vec_len, data_len)
+        }
+        140 => wire__crate__api__core__db_sum_llm_usage_daily_by_purpose_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        141 => wire__crate__api__core__db_unmark_for_review_impl(port, ptr, rust_vec_len, data_len),
+        142 => wire__crate__api__core__db_update_todo_due_with_scope_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        143 => wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        144 => wire__crate__api__core__db_update_todo_status_with_scope_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        145 => wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        106 => wire__crate__api__core__db_update_todo_due_with_scope_impl(
+        146 => wire__crate__api__core__db_upsert_attachment_variant_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__crate__api__core__db_update_todo_recurrence_rule_with_scope_impl(
+        147 => wire__crate__api__core__db_upsert_event_impl(port, ptr, rust_vec_len, data_len),
+        148 => wire__crate__api__core__db_upsert_llm_model_price_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__crate__api__core__db_update_todo_status_with_scope_impl(
+        149 => wire__crate__api__core__db_upsert_todo_impl(port, ptr, rust_vec_len, data_len),
+        150 => wire__crate__api__core__db_upsert_todo_recurrence_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__core__db_upsert_attachment_exif_metadata_impl(
+        151 => wire__crate__api__core__embedding_install_reranker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__core__db_upsert_attachment_variant_impl(
+        152 => wire__crate__api__core__embedding_is_reranker_installed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        111 => wire__crate__api__core__db_upsert_event_impl(port, ptr, rust_vec_len, data_len),
-        112 => wire__crate__api__core__db_upsert_todo_impl(port, ptr, rust_vec_len, data_len),
-        113 => wire__crate__api__core__db_upsert_todo_recurrence_impl(
+        153 => wire__crate__api__core__geo_reverse_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        114 => wire__crate__api__core__geo_reverse_cloud_gateway_impl(
+        154 => wire__crate__api__core__media_annotation_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        115 => wire__crate__api__core__media_annotation_cloud_gateway_impl(
+        155 => {
+            wire__crate__api__core__rag_ask_ai_agent_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        156 => wire__crate__api__core__rag_ask_ai_agent_stream_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__crate__api__core__rag_ask_ai_stream_impl(port, ptr, rust_vec_len, data_len),
-        117 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
+        157 => wire__crate__api__core__rag_ask_ai_stream_impl(port, ptr, rust_vec_len, data_len),
+        158 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        118 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
+        159 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_time_window_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
+        160 => wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        120 => {
+        161 => {
             wire__crate__api__core__rag_ask_ai_stream_cloud_gateway_with_embeddings_time_window_impl(
                 port,
                 ptr,
@@ -8518,218 +12171,350 @@ fn pde_ffi_dispatcher_primary_impl(
                 data_len,
             )
         }
-        121 => wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
+        162 => wire__crate__api__core__rag_ask_ai_stream_time_window_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        164 => wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        165 => wire__crate__api__core__sync_caldav_impl(port, ptr, rust_vec_len, data_len),
+        166 => wire__crate__api__core__sync_derive_key_impl(port, ptr, rust_vec_len, data_len),
+        167 => wire__crate__api__core__sync_localdir_clear_remote_root_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        168 => wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        169 => wire__crate__api__core__sync_localdir_pull_impl(port, ptr, rust_vec_len, data_len),
+        170 => wire__crate__api__core__sync_localdir_push_impl(port, ptr, rust_vec_len, data_len),
+        171 => wire__crate__api__core__sync_localdir_push_ops_only_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        172 => wire__crate__api__core__sync_localdir_test_connection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        173 => wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        174 => wire__crate__api__core__sync_managed_vault_clear_device_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        175 => wire__crate__api__core__sync_managed_vault_clear_vault_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        176 => wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        177 => {
+            wire__crate__api__core__sync_managed_vault_pull_impl(port, ptr, rust_vec_len, data_len)
+        }
+        178 => {
+            wire__crate__api__core__sync_managed_vault_push_impl(port, ptr, rust_vec_len, data_len)
+        }
+        179 => wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        180 => wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        181 => wire__crate__api__core__sync_webdav_clear_remote_root_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        182 => wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        183 => wire__crate__api__core__sync_webdav_pull_impl(port, ptr, rust_vec_len, data_len),
+        184 => wire__crate__api__core__sync_webdav_push_impl(port, ptr, rust_vec_len, data_len),
+        185 => wire__crate__api__core__sync_webdav_push_ops_only_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        186 => wire__crate__api__core__sync_webdav_test_connection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        187 => wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        188 => wire__crate__api__desktop_media__desktop_ocr_image_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        189 => {
+            wire__crate__api__desktop_media__desktop_ocr_pdf_impl(port, ptr, rust_vec_len, data_len)
+        }
+        190 => wire__crate__api__export__db_export_ics_impl(port, ptr, rust_vec_len, data_len),
+        191 => {
+            wire__crate__api__export__db_export_vault_jsonl_impl(port, ptr, rust_vec_len, data_len)
+        }
+        192 => wire__crate__api__export__verify_vault_jsonl_export_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_impl(
+        193 => wire__crate__api__import__db_import_ics_impl(port, ptr, rust_vec_len, data_len),
+        194 => wire__crate__api__import__db_import_markdown_folder_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__crate__api__core__rag_ask_ai_stream_with_brok_embeddings_time_window_impl(
+        195 => wire__crate__api__import__db_import_telegram_export_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => wire__crate__api__core__sync_derive_key_impl(port, ptr, rust_vec_len, data_len),
-        125 => wire__crate__api__core__sync_localdir_clear_remote_root_impl(
+        196 => wire__crate__api__import__db_import_whatsapp_export_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__crate__api__core__sync_localdir_download_attachment_bytes_impl(
+        197 => wire__crate__api__local_llm__local_llm_delete_model_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        127 => wire__crate__api__core__sync_localdir_pull_impl(port, ptr, rust_vec_len, data_len),
-        128 => wire__crate__api__core__sync_localdir_push_impl(port, ptr, rust_vec_len, data_len),
-        129 => wire__crate__api__core__sync_localdir_push_ops_only_impl(
+        198 => wire__crate__api__local_llm__local_llm_install_model_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        130 => wire__crate__api__core__sync_localdir_test_connection_impl(
+        199 => wire__crate__api__local_llm__local_llm_list_models_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        131 => wire__crate__api__core__sync_localdir_upload_attachment_bytes_impl(
+        200 => wire__crate__api__local_llm__local_llm_model_sha256_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        132 => wire__crate__api__core__sync_managed_vault_clear_device_impl(
+        201 => wire__crate__api__local_llm__local_llm_runtime_available_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        133 => wire__crate__api__core__sync_managed_vault_clear_vault_impl(
+        202 => wire__crate__api__maintenance__db_check_integrity_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        134 => wire__crate__api__core__sync_managed_vault_download_attachment_bytes_impl(
+        203 => {
+            wire__crate__api__maintenance__db_storage_usage_impl(port, ptr, rust_vec_len, data_len)
+        }
+        204 => wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        135 => {
-            wire__crate__api__core__sync_managed_vault_pull_impl(port, ptr, rust_vec_len, data_len)
-        }
-        136 => {
-            wire__crate__api__core__sync_managed_vault_push_impl(port, ptr, rust_vec_len, data_len)
-        }
-        137 => wire__crate__api__core__sync_managed_vault_push_ops_only_impl(
+        205 => wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        138 => wire__crate__api__core__sync_managed_vault_upload_attachment_bytes_impl(
+        206 => wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        139 => wire__crate__api__core__sync_webdav_clear_remote_root_impl(
+        207 => wire__crate__api__prompt_templates__db_delete_prompt_template_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => wire__crate__api__core__sync_webdav_download_attachment_bytes_impl(
+        208 => wire__crate__api__prompt_templates__db_list_prompt_template_selections_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        141 => wire__crate__api__core__sync_webdav_pull_impl(port, ptr, rust_vec_len, data_len),
-        142 => wire__crate__api__core__sync_webdav_push_impl(port, ptr, rust_vec_len, data_len),
-        143 => wire__crate__api__core__sync_webdav_push_ops_only_impl(
+        209 => wire__crate__api__prompt_templates__db_list_prompt_templates_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        144 => wire__crate__api__core__sync_webdav_test_connection_impl(
+        210 => wire__crate__api__prompt_templates__db_reset_prompt_template_selections_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        145 => wire__crate__api__core__sync_webdav_upload_attachment_bytes_impl(
+        211 => wire__crate__api__prompt_templates__db_reset_prompt_template_to_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        146 => wire__crate__api__desktop_media__desktop_ocr_image_impl(
+        212 => wire__crate__api__prompt_templates__db_set_prompt_template_selection_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        147 => {
-            wire__crate__api__desktop_media__desktop_ocr_pdf_impl(port, ptr, rust_vec_len, data_len)
-        }
-        148 => wire__crate__api__media_annotation__db_get_media_annotation_config_impl(
+        213 => wire__crate__api__prompt_templates__db_upsert_prompt_template_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__crate__api__media_annotation__db_set_media_annotation_config_impl(
+        214 => wire__crate__api__prompt_templates__prompt_template_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        150 => wire__crate__api__media_annotation__media_annotation_byok_profile_impl(
+        215 => wire__crate__api__prompt_templates__prompt_template_validate_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        152 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        153 => wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
+        217 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        218 => wire__crate__api__sync_progress__sync_localdir_pull_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        154 => wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
+        219 => wire__crate__api__sync_progress__sync_localdir_push_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        155 => wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
+        220 => wire__crate__api__sync_progress__sync_managed_vault_pull_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        156 => wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_impl(
+        221 => wire__crate__api__sync_progress__sync_managed_vault_push_ops_only_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        157 => wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
+        222 => wire__crate__api__sync_progress__sync_webdav_pull_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        158 => wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
+        223 => wire__crate__api__sync_progress__sync_webdav_push_ops_only_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        159 => wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
+        224 => {
+            wire__crate__api__tags__db_backfill_tag_rules_impl(port, ptr, rust_vec_len, data_len)
+        }
+        225 => wire__crate__api__tags__db_delete_tag_rule_impl(port, ptr, rust_vec_len, data_len),
+        226 => wire__crate__api__tags__db_list_message_ids_by_tag_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        160 => wire__crate__api__tags__db_list_message_suggested_tags_impl(
+        227 => wire__crate__api__tags__db_list_message_suggested_tags_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        161 => wire__crate__api__tags__db_list_message_tags_impl(port, ptr, rust_vec_len, data_len),
-        162 => wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
+        228 => wire__crate__api__tags__db_list_message_tags_impl(port, ptr, rust_vec_len, data_len),
+        229 => wire__crate__api__tags__db_list_tag_merge_suggestions_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        163 => wire__crate__api__tags__db_list_tags_impl(port, ptr, rust_vec_len, data_len),
-        164 => wire__crate__api__tags__db_merge_tags_impl(port, ptr, rust_vec_len, data_len),
-        165 => wire__crate__api__tags__db_record_tag_merge_feedback_impl(
+        230 => wire__crate__api__tags__db_list_tag_rules_impl(port, ptr, rust_vec_len, data_len),
+        231 => wire__crate__api__tags__db_list_tag_tree_impl(port, ptr, rust_vec_len, data_len),
+        232 => wire__crate__api__tags__db_list_tags_impl(port, ptr, rust_vec_len, data_len),
+        233 => wire__crate__api__tags__db_merge_tags_impl(port, ptr, rust_vec_len, data_len),
+        234 => wire__crate__api__tags__db_record_tag_merge_feedback_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        166 => wire__crate__api__tags__db_set_message_tags_impl(port, ptr, rust_vec_len, data_len),
-        167 => wire__crate__api__tags__db_upsert_tag_impl(port, ptr, rust_vec_len, data_len),
+        235 => wire__crate__api__tags__db_set_message_tags_impl(port, ptr, rust_vec_len, data_len),
+        236 => wire__crate__api__tags__db_set_tag_parent_impl(port, ptr, rust_vec_len, data_len),
+        237 => wire__crate__api__tags__db_upsert_tag_impl(port, ptr, rust_vec_len, data_len),
+        238 => wire__crate__api__tags__db_upsert_tag_path_impl(port, ptr, rust_vec_len, data_len),
+        239 => wire__crate__api__tags__db_upsert_tag_rule_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -8742,13 +12527,70 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        151 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        216 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
 
 // Section: rust2dart
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::AgendaDay {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.date.into_into_dart().into_dart(),
+            self.day_start_ms.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::AgendaDay {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::AgendaDay> for crate::db::AgendaDay {
+    fn into_into_dart(self) -> crate::db::AgendaDay {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::AgendaItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.item_id.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.at_ms.into_into_dart().into_dart(),
+            self.end_at_ms.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.tz.into_into_dart().into_dart(),
+            self.is_recurring.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::AgendaItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::AgendaItem> for crate::db::AgendaItem {
+    fn into_into_dart(self) -> crate::db::AgendaItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::AgendaPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.overdue.into_into_dart().into_dart(),
+            self.days.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::AgendaPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::AgendaPage> for crate::db::AgendaPage {
+    fn into_into_dart(self) -> crate::db::AgendaPage {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::Attachment {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -8887,6 +12729,33 @@ impl flutter_rust_bridge::IntoIntoDart<crate::db::AttachmentVariant>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::sync::caldav::CalDavSyncReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pulled_created.into_into_dart().into_dart(),
+            self.pulled_updated.into_into_dart().into_dart(),
+            self.pulled_deleted.into_into_dart().into_dart(),
+            self.pushed_created.into_into_dart().into_dart(),
+            self.pushed_updated.into_into_dart().into_dart(),
+            self.pushed_deleted.into_into_dart().into_dart(),
+            self.conflicts.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::sync::caldav::CalDavSyncReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::sync::caldav::CalDavSyncReport>
+    for crate::sync::caldav::CalDavSyncReport
+{
+    fn into_into_dart(self) -> crate::sync::caldav::CalDavSyncReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::CloudMediaBackup {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8902,145 +12771,356 @@ impl flutter_rust_bridge::IntoDart for crate::db::CloudMediaBackup {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::CloudMediaBackup {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::CloudMediaBackup>
-    for crate::db::CloudMediaBackup
-{
-    fn into_into_dart(self) -> crate::db::CloudMediaBackup {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::CloudMediaBackup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::CloudMediaBackup>
+    for crate::db::CloudMediaBackup
+{
+    fn into_into_dart(self) -> crate::db::CloudMediaBackup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::CloudMediaBackupSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pending.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.uploaded.into_into_dart().into_dart(),
+            self.last_uploaded_at_ms.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+            self.last_error_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::db::CloudMediaBackupSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::CloudMediaBackupSummary>
+    for crate::db::CloudMediaBackupSummary
+{
+    fn into_into_dart(self) -> crate::db::CloudMediaBackupSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::ContentEnrichmentConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url_fetch_enabled.into_into_dart().into_dart(),
+            self.document_extract_enabled.into_into_dart().into_dart(),
+            self.document_keep_original_max_bytes
+                .into_into_dart()
+                .into_dart(),
+            self.audio_transcribe_enabled.into_into_dart().into_dart(),
+            self.audio_transcribe_engine.into_into_dart().into_dart(),
+            self.video_extract_enabled.into_into_dart().into_dart(),
+            self.video_proxy_enabled.into_into_dart().into_dart(),
+            self.video_proxy_max_duration_ms
+                .into_into_dart()
+                .into_dart(),
+            self.video_proxy_max_bytes.into_into_dart().into_dart(),
+            self.ocr_enabled.into_into_dart().into_dart(),
+            self.ocr_engine_mode.into_into_dart().into_dart(),
+            self.ocr_language_hints.into_into_dart().into_dart(),
+            self.ocr_pdf_dpi.into_into_dart().into_dart(),
+            self.ocr_pdf_auto_max_pages.into_into_dart().into_dart(),
+            self.ocr_pdf_max_pages.into_into_dart().into_dart(),
+            self.mobile_background_enabled.into_into_dart().into_dart(),
+            self.mobile_background_requires_wifi
+                .into_into_dart()
+                .into_dart(),
+            self.mobile_background_requires_charging
+                .into_into_dart()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::db::ContentEnrichmentConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::ContentEnrichmentConfig>
+    for crate::db::ContentEnrichmentConfig
+{
+    fn into_into_dart(self) -> crate::db::ContentEnrichmentConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::Conversation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Conversation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::Conversation> for crate::db::Conversation {
+    fn into_into_dart(self) -> crate::db::Conversation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::EmbeddingProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.provider_type.into_into_dart().into_dart(),
+            self.base_url.into_into_dart().into_dart(),
+            self.model_name.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::EmbeddingProfile {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::EmbeddingProfile>
+    for crate::db::EmbeddingProfile
+{
+    fn into_into_dart(self) -> crate::db::EmbeddingProfile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::Event {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.start_at_ms.into_into_dart().into_dart(),
+            self.end_at_ms.into_into_dart().into_dart(),
+            self.tz.into_into_dart().into_dart(),
+            self.source_entry_id.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Event {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::Event> for crate::db::Event {
+    fn into_into_dart(self) -> crate::db::Event {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::EventOccurrence {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id.into_into_dart().into_dart(),
+            self.occurrence_start_ms.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.start_at_ms.into_into_dart().into_dart(),
+            self.end_at_ms.into_into_dart().into_dart(),
+            self.tz.into_into_dart().into_dart(),
+            self.is_recurring.into_into_dart().into_dart(),
+            self.is_moved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::EventOccurrence {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::EventOccurrence> for crate::db::EventOccurrence {
+    fn into_into_dart(self) -> crate::db::EventOccurrence {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::export::ics::IcsExportSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.events.into_into_dart().into_dart(),
+            self.todos.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::export::ics::IcsExportSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::export::ics::IcsExportSummary>
+    for crate::export::ics::IcsExportSummary
+{
+    fn into_into_dart(self) -> crate::export::ics::IcsExportSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::import::ImportReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.items_scanned.into_into_dart().into_dart(),
+            self.items_imported.into_into_dart().into_dart(),
+            self.items_updated.into_into_dart().into_dart(),
+            self.items_skipped_unchanged.into_into_dart().into_dart(),
+            self.items_skipped_duplicate.into_into_dart().into_dart(),
+            self.messages_created.into_into_dart().into_dart(),
+            self.todos_created.into_into_dart().into_dart(),
+            self.attachments_linked.into_into_dart().into_dart(),
+            self.tags_applied.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::import::ImportReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::import::ImportReport>
+    for crate::import::ImportReport
+{
+    fn into_into_dart(self) -> crate::import::ImportReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::IntegrityIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.subject.into_into_dart().into_dart(),
+            self.fixed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::IntegrityIssue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::IntegrityIssue> for crate::db::IntegrityIssue {
+    fn into_into_dart(self) -> crate::db::IntegrityIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::IntegrityReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.issues.into_into_dart().into_dart(),
+            self.fixed_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::IntegrityReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::IntegrityReport> for crate::db::IntegrityReport {
+    fn into_into_dart(self) -> crate::db::IntegrityReport {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::CloudMediaBackupSummary {
+impl flutter_rust_bridge::IntoDart for crate::export::jsonl::JsonlExportSummary {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.pending.into_into_dart().into_dart(),
-            self.failed.into_into_dart().into_dart(),
-            self.uploaded.into_into_dart().into_dart(),
-            self.last_uploaded_at_ms.into_into_dart().into_dart(),
-            self.last_error.into_into_dart().into_dart(),
-            self.last_error_at_ms.into_into_dart().into_dart(),
+            self.schema_version.into_into_dart().into_dart(),
+            self.exported_at_ms.into_into_dart().into_dart(),
+            self.streams.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::db::CloudMediaBackupSummary
+    for crate::export::jsonl::JsonlExportSummary
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::db::CloudMediaBackupSummary>
-    for crate::db::CloudMediaBackupSummary
+impl flutter_rust_bridge::IntoIntoDart<crate::export::jsonl::JsonlExportSummary>
+    for crate::export::jsonl::JsonlExportSummary
 {
-    fn into_into_dart(self) -> crate::db::CloudMediaBackupSummary {
+    fn into_into_dart(self) -> crate::export::jsonl::JsonlExportSummary {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::ContentEnrichmentConfig {
+impl flutter_rust_bridge::IntoDart for crate::export::jsonl::JsonlExportVerification {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.url_fetch_enabled.into_into_dart().into_dart(),
-            self.document_extract_enabled.into_into_dart().into_dart(),
-            self.document_keep_original_max_bytes
-                .into_into_dart()
-                .into_dart(),
-            self.audio_transcribe_enabled.into_into_dart().into_dart(),
-            self.audio_transcribe_engine.into_into_dart().into_dart(),
-            self.video_extract_enabled.into_into_dart().into_dart(),
-            self.video_proxy_enabled.into_into_dart().into_dart(),
-            self.video_proxy_max_duration_ms
-                .into_into_dart()
-                .into_dart(),
-            self.video_proxy_max_bytes.into_into_dart().into_dart(),
-            self.ocr_enabled.into_into_dart().into_dart(),
-            self.ocr_engine_mode.into_into_dart().into_dart(),
-            self.ocr_language_hints.into_into_dart().into_dart(),
-            self.ocr_pdf_dpi.into_into_dart().into_dart(),
-            self.ocr_pdf_auto_max_pages.into_into_dart().into_dart(),
-            self.ocr_pdf_max_pages.into_into_dart().into_dart(),
-            self.mobile_background_enabled.into_into_dart().into_dart(),
-            self.mobile_background_requires_wifi
-                .into_into_dart()
-                .into_dart(),
-            self.mobile_background_requires_charging
-                .into_into_dart()
-                .into_dart(),
+            self.schema_version.into_into_dart().into_dart(),
+            self.streams.into_into_dart().into_dart(),
+            self.problems.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::db::ContentEnrichmentConfig
+    for crate::export::jsonl::JsonlExportVerification
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::db::ContentEnrichmentConfig>
-    for crate::db::ContentEnrichmentConfig
+impl flutter_rust_bridge::IntoIntoDart<crate::export::jsonl::JsonlExportVerification>
+    for crate::export::jsonl::JsonlExportVerification
 {
-    fn into_into_dart(self) -> crate::db::ContentEnrichmentConfig {
+    fn into_into_dart(self) -> crate::export::jsonl::JsonlExportVerification {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::Conversation {
+impl flutter_rust_bridge::IntoDart for crate::export::jsonl::JsonlStreamCount {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.created_at_ms.into_into_dart().into_dart(),
-            self.updated_at_ms.into_into_dart().into_dart(),
+            self.stream.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Conversation {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::Conversation> for crate::db::Conversation {
-    fn into_into_dart(self) -> crate::db::Conversation {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::export::jsonl::JsonlStreamCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::export::jsonl::JsonlStreamCount>
+    for crate::export::jsonl::JsonlStreamCount
+{
+    fn into_into_dart(self) -> crate::export::jsonl::JsonlStreamCount {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::EmbeddingProfile {
+impl flutter_rust_bridge::IntoDart for crate::db::LlmModelPrice {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
             self.provider_type.into_into_dart().into_dart(),
-            self.base_url.into_into_dart().into_dart(),
             self.model_name.into_into_dart().into_dart(),
-            self.is_active.into_into_dart().into_dart(),
-            self.created_at_ms.into_into_dart().into_dart(),
+            self.input_usd_per_million_tokens
+                .into_into_dart()
+                .into_dart(),
+            self.output_usd_per_million_tokens
+                .into_into_dart()
+                .into_dart(),
             self.updated_at_ms.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::EmbeddingProfile {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::EmbeddingProfile>
-    for crate::db::EmbeddingProfile
-{
-    fn into_into_dart(self) -> crate::db::EmbeddingProfile {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::LlmModelPrice {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::LlmModelPrice> for crate::db::LlmModelPrice {
+    fn into_into_dart(self) -> crate::db::LlmModelPrice {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::Event {
+impl flutter_rust_bridge::IntoDart for crate::db::LlmMonthlyCost {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.start_at_ms.into_into_dart().into_dart(),
-            self.end_at_ms.into_into_dart().into_dart(),
-            self.tz.into_into_dart().into_dart(),
-            self.source_entry_id.into_into_dart().into_dart(),
-            self.created_at_ms.into_into_dart().into_dart(),
-            self.updated_at_ms.into_into_dart().into_dart(),
+            self.profile_id.into_into_dart().into_dart(),
+            self.purpose.into_into_dart().into_dart(),
+            self.requests.into_into_dart().into_dart(),
+            self.requests_with_usage.into_into_dart().into_dart(),
+            self.input_tokens.into_into_dart().into_dart(),
+            self.output_tokens.into_into_dart().into_dart(),
+            self.cost_usd_micros.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Event {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::Event> for crate::db::Event {
-    fn into_into_dart(self) -> crate::db::Event {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::LlmMonthlyCost {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::LlmMonthlyCost> for crate::db::LlmMonthlyCost {
+    fn into_into_dart(self) -> crate::db::LlmMonthlyCost {
         self
     }
 }
@@ -9067,6 +13147,24 @@ impl flutter_rust_bridge::IntoIntoDart<crate::db::LlmProfile> for crate::db::Llm
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::LlmSpendingLimit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.profile_id.into_into_dart().into_dart(),
+            self.monthly_limit_usd_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::LlmSpendingLimit {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::LlmSpendingLimit>
+    for crate::db::LlmSpendingLimit
+{
+    fn into_into_dart(self) -> crate::db::LlmSpendingLimit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::LlmUsageAggregate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9089,6 +13187,30 @@ impl flutter_rust_bridge::IntoIntoDart<crate::db::LlmUsageAggregate>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::llm::local_gguf::LocalGgufModelInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file_name.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.architecture.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.context_length.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::llm::local_gguf::LocalGgufModelInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::llm::local_gguf::LocalGgufModelInfo>
+    for crate::llm::local_gguf::LocalGgufModelInfo
+{
+    fn into_into_dart(self) -> crate::llm::local_gguf::LocalGgufModelInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::MediaAnnotationConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9134,6 +13256,23 @@ impl flutter_rust_bridge::IntoIntoDart<crate::db::Message> for crate::db::Messag
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::MessageCitation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.marker.into_into_dart().into_dart(),
+            self.source_kind.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::MessageCitation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::MessageCitation> for crate::db::MessageCitation {
+    fn into_into_dart(self) -> crate::db::MessageCitation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::desktop_media::ocr::OcrPayload {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9159,6 +13298,115 @@ impl flutter_rust_bridge::IntoIntoDart<crate::desktop_media::ocr::OcrPayload>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::PromptTemplate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.purpose.into_into_dart().into_dart(),
+            self.body.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::PromptTemplate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::PromptTemplate> for crate::db::PromptTemplate {
+    fn into_into_dart(self) -> crate::db::PromptTemplate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::PromptTemplateSelection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.purpose.into_into_dart().into_dart(),
+            self.profile_id.into_into_dart().into_dart(),
+            self.template_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::db::PromptTemplateSelection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::PromptTemplateSelection>
+    for crate::db::PromptTemplateSelection
+{
+    fn into_into_dart(self) -> crate::db::PromptTemplateSelection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::ReviewActivity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.item_kind.into_into_dart().into_dart(),
+            self.item_id.into_into_dart().into_dart(),
+            self.grade.into_into_dart().into_dart(),
+            self.ease_factor.into_into_dart().into_dart(),
+            self.interval_days.into_into_dart().into_dart(),
+            self.due_at_ms.into_into_dart().into_dart(),
+            self.reviewed_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::ReviewActivity {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::ReviewActivity> for crate::db::ReviewActivity {
+    fn into_into_dart(self) -> crate::db::ReviewActivity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::ReviewItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.item_kind.into_into_dart().into_dart(),
+            self.item_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.ease_factor.into_into_dart().into_dart(),
+            self.interval_days.into_into_dart().into_dart(),
+            self.repetitions.into_into_dart().into_dart(),
+            self.lapses.into_into_dart().into_dart(),
+            self.due_at_ms.into_into_dart().into_dart(),
+            self.last_reviewed_at_ms.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::ReviewItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::ReviewItem> for crate::db::ReviewItem {
+    fn into_into_dart(self) -> crate::db::ReviewItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::SemanticParseFailureReasonCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reason.into_into_dart().into_dart(),
+            self.jobs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::db::SemanticParseFailureReasonCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::SemanticParseFailureReasonCount>
+    for crate::db::SemanticParseFailureReasonCount
+{
+    fn into_into_dart(self) -> crate::db::SemanticParseFailureReasonCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::SemanticParseJob {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9249,6 +13497,46 @@ impl flutter_rust_bridge::IntoIntoDart<crate::db::StoragePolicyConfig>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::StorageUsageEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::StorageUsageEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::StorageUsageEntry>
+    for crate::db::StorageUsageEntry
+{
+    fn into_into_dart(self) -> crate::db::StorageUsageEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::StorageUsageReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_bytes.into_into_dart().into_dart(),
+            self.categories.into_into_dart().into_dart(),
+            self.by_conversation.into_into_dart().into_dart(),
+            self.by_mime_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::StorageUsageReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::StorageUsageReport>
+    for crate::db::StorageUsageReport
+{
+    fn into_into_dart(self) -> crate::db::StorageUsageReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::db::Tag {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -9263,31 +13551,71 @@ impl flutter_rust_bridge::IntoDart for crate::db::Tag {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Tag {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::Tag> for crate::db::Tag {
-    fn into_into_dart(self) -> crate::db::Tag {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::Tag {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::Tag> for crate::db::Tag {
+    fn into_into_dart(self) -> crate::db::Tag {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::TagMergeSuggestion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_tag.into_into_dart().into_dart(),
+            self.target_tag.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.source_usage_count.into_into_dart().into_dart(),
+            self.target_usage_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::TagMergeSuggestion {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::TagMergeSuggestion>
+    for crate::db::TagMergeSuggestion
+{
+    fn into_into_dart(self) -> crate::db::TagMergeSuggestion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::TagNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tag.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.depth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::TagNode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::TagNode> for crate::db::TagNode {
+    fn into_into_dart(self) -> crate::db::TagNode {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::db::TagMergeSuggestion {
+impl flutter_rust_bridge::IntoDart for crate::db::TagRule {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.source_tag.into_into_dart().into_dart(),
-            self.target_tag.into_into_dart().into_dart(),
-            self.reason.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-            self.source_usage_count.into_into_dart().into_dart(),
-            self.target_usage_count.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.pattern.into_into_dart().into_dart(),
+            self.tag_id.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::TagMergeSuggestion {}
-impl flutter_rust_bridge::IntoIntoDart<crate::db::TagMergeSuggestion>
-    for crate::db::TagMergeSuggestion
-{
-    fn into_into_dart(self) -> crate::db::TagMergeSuggestion {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::TagRule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::TagRule> for crate::db::TagRule {
+    fn into_into_dart(self) -> crate::db::TagRule {
         self
     }
 }
@@ -9360,6 +13688,26 @@ impl flutter_rust_bridge::IntoIntoDart<crate::semantic_parse::TodoCandidate>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::db::TodoRollup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.todo_id.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+            self.subtasks_total.into_into_dart().into_dart(),
+            self.subtasks_done.into_into_dart().into_dart(),
+            self.open_blocker_ids.into_into_dart().into_dart(),
+            self.complete_with_subtasks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::db::TodoRollup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::db::TodoRollup> for crate::db::TodoRollup {
+    fn into_into_dart(self) -> crate::db::TodoRollup {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9382,6 +13730,39 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for crate::db::AgendaDay {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.date, serializer);
+        <i64>::sse_encode(self.day_start_ms, serializer);
+        <Vec<crate::db::AgendaItem>>::sse_encode(self.items, serializer);
+    }
+}
+
+impl SseEncode for crate::db::AgendaItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.item_id, serializer);
+        <String>::sse_encode(self.source_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.at_ms, serializer);
+        <Option<i64>>::sse_encode(self.end_at_ms, serializer);
+        <Option<String>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.tz, serializer);
+        <bool>::sse_encode(self.is_recurring, serializer);
+    }
+}
+
+impl SseEncode for crate::db::AgendaPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::db::AgendaItem>>::sse_encode(self.overdue, serializer);
+        <Vec<crate::db::AgendaDay>>::sse_encode(self.days, serializer);
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
+    }
+}
+
 impl SseEncode for crate::db::Attachment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9462,6 +13843,20 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::sync::caldav::CalDavSyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pulled_created, serializer);
+        <u32>::sse_encode(self.pulled_updated, serializer);
+        <u32>::sse_encode(self.pulled_deleted, serializer);
+        <u32>::sse_encode(self.pushed_created, serializer);
+        <u32>::sse_encode(self.pushed_updated, serializer);
+        <u32>::sse_encode(self.pushed_deleted, serializer);
+        <u32>::sse_encode(self.conflicts, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+    }
+}
+
 impl SseEncode for crate::db::CloudMediaBackup {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9476,217 +13871,458 @@ impl SseEncode for crate::db::CloudMediaBackup {
     }
 }
 
-impl SseEncode for crate::db::CloudMediaBackupSummary {
+impl SseEncode for crate::db::CloudMediaBackupSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.pending, serializer);
+        <i64>::sse_encode(self.failed, serializer);
+        <i64>::sse_encode(self.uploaded, serializer);
+        <Option<i64>>::sse_encode(self.last_uploaded_at_ms, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <Option<i64>>::sse_encode(self.last_error_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::ContentEnrichmentConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.url_fetch_enabled, serializer);
+        <bool>::sse_encode(self.document_extract_enabled, serializer);
+        <i64>::sse_encode(self.document_keep_original_max_bytes, serializer);
+        <bool>::sse_encode(self.audio_transcribe_enabled, serializer);
+        <String>::sse_encode(self.audio_transcribe_engine, serializer);
+        <bool>::sse_encode(self.video_extract_enabled, serializer);
+        <bool>::sse_encode(self.video_proxy_enabled, serializer);
+        <i64>::sse_encode(self.video_proxy_max_duration_ms, serializer);
+        <i64>::sse_encode(self.video_proxy_max_bytes, serializer);
+        <bool>::sse_encode(self.ocr_enabled, serializer);
+        <String>::sse_encode(self.ocr_engine_mode, serializer);
+        <String>::sse_encode(self.ocr_language_hints, serializer);
+        <i64>::sse_encode(self.ocr_pdf_dpi, serializer);
+        <i64>::sse_encode(self.ocr_pdf_auto_max_pages, serializer);
+        <i64>::sse_encode(self.ocr_pdf_max_pages, serializer);
+        <bool>::sse_encode(self.mobile_background_enabled, serializer);
+        <bool>::sse_encode(self.mobile_background_requires_wifi, serializer);
+        <bool>::sse_encode(self.mobile_background_requires_charging, serializer);
+    }
+}
+
+impl SseEncode for crate::db::Conversation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.created_at_ms, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::EmbeddingProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.provider_type, serializer);
+        <Option<String>>::sse_encode(self.base_url, serializer);
+        <String>::sse_encode(self.model_name, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
+        <i64>::sse_encode(self.created_at_ms, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::Event {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.start_at_ms, serializer);
+        <i64>::sse_encode(self.end_at_ms, serializer);
+        <String>::sse_encode(self.tz, serializer);
+        <Option<String>>::sse_encode(self.source_entry_id, serializer);
+        <i64>::sse_encode(self.created_at_ms, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::EventOccurrence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id, serializer);
+        <i64>::sse_encode(self.occurrence_start_ms, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.start_at_ms, serializer);
+        <i64>::sse_encode(self.end_at_ms, serializer);
+        <String>::sse_encode(self.tz, serializer);
+        <bool>::sse_encode(self.is_recurring, serializer);
+        <bool>::sse_encode(self.is_moved, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::export::ics::IcsExportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.events, serializer);
+        <u64>::sse_encode(self.todos, serializer);
+    }
+}
+
+impl SseEncode for crate::import::ImportReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.items_scanned, serializer);
+        <u32>::sse_encode(self.items_imported, serializer);
+        <u32>::sse_encode(self.items_updated, serializer);
+        <u32>::sse_encode(self.items_skipped_unchanged, serializer);
+        <u32>::sse_encode(self.items_skipped_duplicate, serializer);
+        <u32>::sse_encode(self.messages_created, serializer);
+        <u32>::sse_encode(self.todos_created, serializer);
+        <u32>::sse_encode(self.attachments_linked, serializer);
+        <u32>::sse_encode(self.tags_applied, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+    }
+}
+
+impl SseEncode for crate::db::IntegrityIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.subject, serializer);
+        <bool>::sse_encode(self.fixed, serializer);
+    }
+}
+
+impl SseEncode for crate::db::IntegrityReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::db::IntegrityIssue>>::sse_encode(self.issues, serializer);
+        <u32>::sse_encode(self.fixed_count, serializer);
+    }
+}
+
+impl SseEncode for crate::export::jsonl::JsonlExportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.schema_version, serializer);
+        <i64>::sse_encode(self.exported_at_ms, serializer);
+        <Vec<crate::export::jsonl::JsonlStreamCount>>::sse_encode(self.streams, serializer);
+    }
+}
+
+impl SseEncode for crate::export::jsonl::JsonlExportVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.schema_version, serializer);
+        <Vec<crate::export::jsonl::JsonlStreamCount>>::sse_encode(self.streams, serializer);
+        <Vec<String>>::sse_encode(self.problems, serializer);
+    }
+}
+
+impl SseEncode for crate::export::jsonl::JsonlStreamCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.stream, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::AgendaDay> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::AgendaDay>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::AgendaItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::AgendaItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::Attachment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::Attachment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::AttachmentAnnotationJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::AttachmentAnnotationJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::AttachmentPlaceJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::AttachmentPlaceJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::CloudMediaBackup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::CloudMediaBackup>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::Conversation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.pending, serializer);
-        <i64>::sse_encode(self.failed, serializer);
-        <i64>::sse_encode(self.uploaded, serializer);
-        <Option<i64>>::sse_encode(self.last_uploaded_at_ms, serializer);
-        <Option<String>>::sse_encode(self.last_error, serializer);
-        <Option<i64>>::sse_encode(self.last_error_at_ms, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::Conversation>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::db::ContentEnrichmentConfig {
+impl SseEncode for Vec<crate::db::EmbeddingProfile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.url_fetch_enabled, serializer);
-        <bool>::sse_encode(self.document_extract_enabled, serializer);
-        <i64>::sse_encode(self.document_keep_original_max_bytes, serializer);
-        <bool>::sse_encode(self.audio_transcribe_enabled, serializer);
-        <String>::sse_encode(self.audio_transcribe_engine, serializer);
-        <bool>::sse_encode(self.video_extract_enabled, serializer);
-        <bool>::sse_encode(self.video_proxy_enabled, serializer);
-        <i64>::sse_encode(self.video_proxy_max_duration_ms, serializer);
-        <i64>::sse_encode(self.video_proxy_max_bytes, serializer);
-        <bool>::sse_encode(self.ocr_enabled, serializer);
-        <String>::sse_encode(self.ocr_engine_mode, serializer);
-        <String>::sse_encode(self.ocr_language_hints, serializer);
-        <i64>::sse_encode(self.ocr_pdf_dpi, serializer);
-        <i64>::sse_encode(self.ocr_pdf_auto_max_pages, serializer);
-        <i64>::sse_encode(self.ocr_pdf_max_pages, serializer);
-        <bool>::sse_encode(self.mobile_background_enabled, serializer);
-        <bool>::sse_encode(self.mobile_background_requires_wifi, serializer);
-        <bool>::sse_encode(self.mobile_background_requires_charging, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::EmbeddingProfile>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::db::Conversation {
+impl SseEncode for Vec<crate::db::Event> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.title, serializer);
-        <i64>::sse_encode(self.created_at_ms, serializer);
-        <i64>::sse_encode(self.updated_at_ms, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::Event>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::db::EmbeddingProfile {
+impl SseEncode for Vec<crate::db::EventOccurrence> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.provider_type, serializer);
-        <Option<String>>::sse_encode(self.base_url, serializer);
-        <String>::sse_encode(self.model_name, serializer);
-        <bool>::sse_encode(self.is_active, serializer);
-        <i64>::sse_encode(self.created_at_ms, serializer);
-        <i64>::sse_encode(self.updated_at_ms, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::EventOccurrence>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::db::Event {
+impl SseEncode for Vec<crate::db::IntegrityIssue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.title, serializer);
-        <i64>::sse_encode(self.start_at_ms, serializer);
-        <i64>::sse_encode(self.end_at_ms, serializer);
-        <String>::sse_encode(self.tz, serializer);
-        <Option<String>>::sse_encode(self.source_entry_id, serializer);
-        <i64>::sse_encode(self.created_at_ms, serializer);
-        <i64>::sse_encode(self.updated_at_ms, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::IntegrityIssue>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Vec<crate::export::jsonl::JsonlStreamCount> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::export::jsonl::JsonlStreamCount>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Vec<crate::db::LlmModelPrice> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::LlmModelPrice>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Vec<crate::db::LlmMonthlyCost> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::LlmMonthlyCost>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<crate::db::LlmProfile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <crate::db::LlmProfile>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::Attachment> {
+impl SseEncode for Vec<crate::db::LlmSpendingLimit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::Attachment>::sse_encode(item, serializer);
+            <crate::db::LlmSpendingLimit>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::AttachmentAnnotationJob> {
+impl SseEncode for Vec<crate::db::LlmUsageAggregate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::AttachmentAnnotationJob>::sse_encode(item, serializer);
+            <crate::db::LlmUsageAggregate>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::AttachmentPlaceJob> {
+impl SseEncode for Vec<crate::llm::local_gguf::LocalGgufModelInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::AttachmentPlaceJob>::sse_encode(item, serializer);
+            <crate::llm::local_gguf::LocalGgufModelInfo>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::CloudMediaBackup> {
+impl SseEncode for Vec<crate::db::Message> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::CloudMediaBackup>::sse_encode(item, serializer);
+            <crate::db::Message>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::Conversation> {
+impl SseEncode for Vec<crate::db::MessageCitation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::Conversation>::sse_encode(item, serializer);
+            <crate::db::MessageCitation>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::EmbeddingProfile> {
+impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::EmbeddingProfile>::sse_encode(item, serializer);
+            <i64>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::Event> {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::Event>::sse_encode(item, serializer);
+            <u8>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::LlmProfile> {
+impl SseEncode for Vec<crate::db::PromptTemplate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::LlmProfile>::sse_encode(item, serializer);
+            <crate::db::PromptTemplate>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::LlmUsageAggregate> {
+impl SseEncode for Vec<crate::db::PromptTemplateSelection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::LlmUsageAggregate>::sse_encode(item, serializer);
+            <crate::db::PromptTemplateSelection>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::db::Message> {
+impl SseEncode for Vec<crate::db::ReviewActivity> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::db::Message>::sse_encode(item, serializer);
+            <crate::db::ReviewActivity>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::db::ReviewItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::db::ReviewItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::SemanticParseFailureReasonCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::SemanticParseFailureReasonCount>::sse_encode(item, serializer);
         }
     }
 }
@@ -9721,6 +14357,16 @@ impl SseEncode for Vec<crate::db::SimilarTodoThread> {
     }
 }
 
+impl SseEncode for Vec<crate::db::StorageUsageEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::StorageUsageEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::db::Tag> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9741,6 +14387,26 @@ impl SseEncode for Vec<crate::db::TagMergeSuggestion> {
     }
 }
 
+impl SseEncode for Vec<crate::db::TagNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::TagNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::db::TagRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::db::TagRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::db::Todo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9771,6 +14437,30 @@ impl SseEncode for Vec<crate::semantic_parse::TodoCandidate> {
     }
 }
 
+impl SseEncode for crate::db::LlmModelPrice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.provider_type, serializer);
+        <String>::sse_encode(self.model_name, serializer);
+        <f64>::sse_encode(self.input_usd_per_million_tokens, serializer);
+        <f64>::sse_encode(self.output_usd_per_million_tokens, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::LlmMonthlyCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.profile_id, serializer);
+        <String>::sse_encode(self.purpose, serializer);
+        <i64>::sse_encode(self.requests, serializer);
+        <i64>::sse_encode(self.requests_with_usage, serializer);
+        <i64>::sse_encode(self.input_tokens, serializer);
+        <i64>::sse_encode(self.output_tokens, serializer);
+        <i64>::sse_encode(self.cost_usd_micros, serializer);
+    }
+}
+
 impl SseEncode for crate::db::LlmProfile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9785,6 +14475,14 @@ impl SseEncode for crate::db::LlmProfile {
     }
 }
 
+impl SseEncode for crate::db::LlmSpendingLimit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.profile_id, serializer);
+        <i64>::sse_encode(self.monthly_limit_usd_micros, serializer);
+    }
+}
+
 impl SseEncode for crate::db::LlmUsageAggregate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9797,6 +14495,17 @@ impl SseEncode for crate::db::LlmUsageAggregate {
     }
 }
 
+impl SseEncode for crate::llm::local_gguf::LocalGgufModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.file_name, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <Option<String>>::sse_encode(self.architecture, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<u32>>::sse_encode(self.context_length, serializer);
+    }
+}
+
 impl SseEncode for crate::db::MediaAnnotationConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9821,6 +14530,15 @@ impl SseEncode for crate::db::Message {
     }
 }
 
+impl SseEncode for crate::db::MessageCitation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.marker, serializer);
+        <String>::sse_encode(self.source_kind, serializer);
+        <String>::sse_encode(self.source_id, serializer);
+    }
+}
+
 impl SseEncode for crate::desktop_media::ocr::OcrPayload {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9893,6 +14611,75 @@ impl SseEncode for Option<crate::db::Message> {
     }
 }
 
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::db::PromptTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.purpose, serializer);
+        <String>::sse_encode(self.body, serializer);
+        <i64>::sse_encode(self.created_at_ms, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::PromptTemplateSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.purpose, serializer);
+        <String>::sse_encode(self.profile_id, serializer);
+        <String>::sse_encode(self.template_id, serializer);
+    }
+}
+
+impl SseEncode for crate::db::ReviewActivity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.item_kind, serializer);
+        <String>::sse_encode(self.item_id, serializer);
+        <i64>::sse_encode(self.grade, serializer);
+        <f64>::sse_encode(self.ease_factor, serializer);
+        <i64>::sse_encode(self.interval_days, serializer);
+        <i64>::sse_encode(self.due_at_ms, serializer);
+        <i64>::sse_encode(self.reviewed_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::db::ReviewItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.item_kind, serializer);
+        <String>::sse_encode(self.item_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <f64>::sse_encode(self.ease_factor, serializer);
+        <i64>::sse_encode(self.interval_days, serializer);
+        <i64>::sse_encode(self.repetitions, serializer);
+        <i64>::sse_encode(self.lapses, serializer);
+        <i64>::sse_encode(self.due_at_ms, serializer);
+        <Option<i64>>::sse_encode(self.last_reviewed_at_ms, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
+    }
+}
+
+impl SseEncode for crate::db::SemanticParseFailureReasonCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.reason, serializer);
+        <i64>::sse_encode(self.jobs, serializer);
+    }
+}
+
 impl SseEncode for crate::db::SemanticParseJob {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9938,6 +14725,26 @@ impl SseEncode for crate::db::StoragePolicyConfig {
     }
 }
 
+impl SseEncode for crate::db::StorageUsageEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <i64>::sse_encode(self.bytes, serializer);
+        <i64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::db::StorageUsageReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.total_bytes, serializer);
+        <Vec<crate::db::StorageUsageEntry>>::sse_encode(self.categories, serializer);
+        <Vec<crate::db::StorageUsageEntry>>::sse_encode(self.by_conversation, serializer);
+        <Vec<crate::db::StorageUsageEntry>>::sse_encode(self.by_mime_type, serializer);
+    }
+}
+
 impl SseEncode for crate::db::Tag {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9963,6 +14770,30 @@ impl SseEncode for crate::db::TagMergeSuggestion {
     }
 }
 
+impl SseEncode for crate::db::TagNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::db::Tag>::sse_encode(self.tag, serializer);
+        <Option<String>>::sse_encode(self.parent_id, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+    }
+}
+
+impl SseEncode for crate::db::TagRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.pattern, serializer);
+        <String>::sse_encode(self.tag_id, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <i64>::sse_encode(self.created_at_ms, serializer);
+        <i64>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::db::Todo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10003,6 +14834,18 @@ impl SseEncode for crate::semantic_parse::TodoCandidate {
     }
 }
 
+impl SseEncode for crate::db::TodoRollup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.todo_id, serializer);
+        <Option<String>>::sse_encode(self.parent_id, serializer);
+        <i64>::sse_encode(self.subtasks_total, serializer);
+        <i64>::sse_encode(self.subtasks_done, serializer);
+        <Vec<String>>::sse_encode(self.open_blocker_ids, serializer);
+        <bool>::sse_encode(self.complete_with_subtasks, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -0,0 +1,576 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+use serde_json::Value;
+
+use super::{file_inside_dir, mime_type_for_path, now_ms, sha256_hex, ImportReport};
+use crate::db;
+
+pub const TELEGRAM_IMPORT_SOURCE_KIND: &str = "telegram";
+pub const WHATSAPP_IMPORT_SOURCE_KIND: &str = "whatsapp";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChatExportMessage {
+    /// Stable per-export identity used for idempotent re-imports.
+    pub source_key: String,
+    pub created_at_ms: i64,
+    pub sender: Option<String>,
+    pub text: String,
+    pub attachments: Vec<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChatExport {
+    pub source_kind: &'static str,
+    pub chat_key: String,
+    pub title: String,
+    pub messages: Vec<ChatExportMessage>,
+}
+
+impl ChatExportMessage {
+    fn content_identity(&self) -> String {
+        let mut material = format!(
+            "{}\0{}\0{}",
+            self.created_at_ms,
+            self.sender.as_deref().unwrap_or_default(),
+            self.text
+        );
+        for path in &self.attachments {
+            material.push('\0');
+            material.push_str(
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default(),
+            );
+        }
+        material
+    }
+
+    /// Content hash used to spot the same message in another export of the
+    /// chat. `occurrence` numbers identical messages within one export, so
+    /// two "ok"s sent in the same minute stay two messages.
+    fn content_sha256(&self, occurrence: usize) -> String {
+        sha256_hex(format!("{}\0{occurrence}", self.content_identity()).as_bytes())
+    }
+}
+
+// Telegram Desktop "Export chat history" (JSON).
+
+fn telegram_text(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .map(|part| match part {
+                Value::String(s) => s.as_str(),
+                Value::Object(obj) => obj.get("text").and_then(|v| v.as_str()).unwrap_or(""),
+                _ => "",
+            })
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+fn telegram_created_at_ms(message: &Value, default_tz: Option<&str>) -> Option<i64> {
+    if let Some(unix) = message.get("date_unixtime") {
+        let secs = match unix {
+            Value::String(s) => s.trim().parse::<i64>().ok(),
+            Value::Number(n) => n.as_i64(),
+            _ => None,
+        };
+        if let Some(secs) = secs {
+            return Some(secs.saturating_mul(1000));
+        }
+    }
+
+    // Older exports only carry the wall-clock time of the exporting device.
+    let raw = message.get("date")?.as_str()?;
+    let format =
+        time::format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]").ok()?;
+    let dt = time::PrimitiveDateTime::parse(raw, &format).ok()?;
+    db::timezone_instant_ms(dt, default_tz).ok()
+}
+
+fn telegram_media_path(export_dir: &Path, value: Option<&Value>) -> Option<PathBuf> {
+    let rel = value?.as_str()?.trim();
+    // Media skipped during export is recorded as "(File not included. ...)".
+    if rel.is_empty() || rel.starts_with('(') {
+        return None;
+    }
+    let path = file_inside_dir(export_dir, &export_dir.join(rel))?;
+    mime_type_for_path(&path).is_some().then_some(path)
+}
+
+fn parse_telegram_chat(
+    export_dir: &Path,
+    chat: &Value,
+    default_tz: Option<&str>,
+) -> Option<ChatExport> {
+    let messages = chat.get("messages")?.as_array()?;
+    let chat_id = match chat.get("id") {
+        Some(Value::Number(n)) => n.to_string(),
+        Some(Value::String(s)) => s.clone(),
+        _ => String::new(),
+    };
+    let title = chat
+        .get("name")
+        .and_then(|v| v.as_str())
+        .filter(|v| !v.trim().is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| match chat.get("type").and_then(|v| v.as_str()) {
+            Some("saved_messages") => "Saved Messages".to_string(),
+            _ => "Telegram".to_string(),
+        });
+    let chat_key = if chat_id.is_empty() {
+        title.clone()
+    } else {
+        chat_id
+    };
+
+    let mut out = Vec::new();
+    for message in messages {
+        if message.get("type").and_then(|v| v.as_str()) != Some("message") {
+            continue;
+        }
+        let Some(created_at_ms) = telegram_created_at_ms(message, default_tz) else {
+            continue;
+        };
+        let message_id = match message.get("id") {
+            Some(Value::Number(n)) => n.to_string(),
+            Some(Value::String(s)) => s.clone(),
+            _ => continue,
+        };
+
+        let attachments: Vec<PathBuf> = ["photo", "file"]
+            .iter()
+            .filter_map(|k| telegram_media_path(export_dir, message.get(*k)))
+            .collect();
+        let text = telegram_text(message.get("text")).trim().to_string();
+        if text.is_empty() && attachments.is_empty() {
+            continue;
+        }
+
+        out.push(ChatExportMessage {
+            source_key: format!("{chat_key}:{message_id}"),
+            created_at_ms,
+            sender: message
+                .get("from")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            text,
+            attachments,
+        });
+    }
+
+    Some(ChatExport {
+        source_kind: TELEGRAM_IMPORT_SOURCE_KIND,
+        chat_key,
+        title,
+        messages: out,
+    })
+}
+
+/// Parses a Telegram Desktop JSON export (`result.json`), either a single chat
+/// or a full account export with `chats.list`. Messages without
+/// `date_unixtime` are wall-clock times in `default_tz` (UTC when `None`).
+pub fn parse_telegram_export(
+    json_path: &Path,
+    default_tz: Option<&str>,
+) -> Result<Vec<ChatExport>> {
+    let bytes = fs::read(json_path)?;
+    let root: Value =
+        serde_json::from_slice(&bytes).map_err(|e| anyhow!("invalid telegram export: {e}"))?;
+    let export_dir = json_path.parent().unwrap_or(Path::new("."));
+
+    if root.get("messages").is_some() {
+        return parse_telegram_chat(export_dir, &root, default_tz)
+            .map(|chat| vec![chat])
+            .ok_or_else(|| anyhow!("invalid telegram export: missing messages"));
+    }
+
+    let chats = root
+        .get("chats")
+        .and_then(|v| v.get("list"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| anyhow!("invalid telegram export: missing chats"))?;
+    Ok(chats
+        .iter()
+        .filter_map(|chat| parse_telegram_chat(export_dir, chat, default_tz))
+        .collect())
+}
+
+// WhatsApp "Export chat" (`_chat.txt` / `WhatsApp Chat with X.txt`).
+
+struct WhatsAppLine<'a> {
+    date: (u32, u32, i32),
+    time: (u8, u8, u8),
+    rest: &'a str,
+}
+
+fn parse_whatsapp_date(raw: &str) -> Option<(u32, u32, i32)> {
+    let mut fields = raw.trim().split(['/', '.', '-']);
+    let a: u32 = fields.next()?.trim().parse().ok()?;
+    let b: u32 = fields.next()?.trim().parse().ok()?;
+    let year: i32 = fields.next()?.trim().parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    let year = if year < 100 { 2000 + year } else { year };
+    Some((a, b, year))
+}
+
+fn parse_whatsapp_time(raw: &str) -> Option<(u8, u8, u8)> {
+    let raw = raw.trim().replace(['\u{202f}', '\u{a0}'], " ");
+    let lower = raw.to_ascii_lowercase();
+    let (clock, pm) = if let Some(c) = lower.strip_suffix("pm") {
+        (c.trim().to_string(), Some(true))
+    } else if let Some(c) = lower.strip_suffix("am") {
+        (c.trim().to_string(), Some(false))
+    } else {
+        (lower.trim().to_string(), None)
+    };
+
+    let mut fields = clock.split([':', '.']);
+    let mut hour: u8 = fields.next()?.parse().ok()?;
+    let minute: u8 = fields.next()?.parse().ok()?;
+    let second: u8 = match fields.next() {
+        Some(s) => s.parse().ok()?,
+        None => 0,
+    };
+    match pm {
+        Some(true) if hour < 12 => hour += 12,
+        Some(false) if hour == 12 => hour = 0,
+        _ => {}
+    }
+    (hour < 24 && minute < 60 && second < 60).then_some((hour, minute, second))
+}
+
+/// Recognises both `12/31/23, 9:15 PM - Name: text` (Android) and
+/// `[31/12/2023, 21:15:03] Name: text` (iOS) line headers.
+fn parse_whatsapp_line(line: &str) -> Option<WhatsAppLine<'_>> {
+    let line = line.trim_start_matches(['\u{feff}', '\u{200e}']);
+    let (header, rest) = if let Some(inner) = line.strip_prefix('[') {
+        let end = inner.find(']')?;
+        (&inner[..end], inner[end + 1..].trim_start())
+    } else {
+        let end = line.find(" - ")?;
+        (&line[..end], &line[end + 3..])
+    };
+
+    let (date_raw, time_raw) = header.split_once(',')?;
+    Some(WhatsAppLine {
+        date: parse_whatsapp_date(date_raw)?,
+        time: parse_whatsapp_time(time_raw)?,
+        rest,
+    })
+}
+
+fn whatsapp_attachment_name(text: &str) -> Option<&str> {
+    let text = text.trim_start_matches('\u{200e}').trim();
+    if let Some(inner) = text
+        .strip_prefix("<attached:")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        return Some(inner.trim());
+    }
+    text.strip_suffix("(file attached)").map(str::trim)
+}
+
+fn whatsapp_chat_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let name = name
+        .strip_prefix("WhatsApp Chat with ")
+        .or_else(|| name.strip_prefix("WhatsApp Chat - "))
+        .unwrap_or(name)
+        .trim();
+    (!name.is_empty() && name != "_chat").then(|| name.to_string())
+}
+
+/// Parses a WhatsApp text export. Media files are looked up next to the text
+/// file, which is how both platforms lay out an unzipped export. iOS always
+/// names the text file `_chat.txt`, so its chat name comes from the folder the
+/// export zip was extracted to. Timestamps are wall-clock times of the
+/// exporting phone, read in `default_tz` (UTC when `None`).
+pub fn parse_whatsapp_export(txt_path: &Path, default_tz: Option<&str>) -> Result<ChatExport> {
+    let text = fs::read_to_string(txt_path)?;
+    let media_dir = txt_path.parent().unwrap_or(Path::new("."));
+    let title = txt_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(whatsapp_chat_name)
+        .or_else(|| {
+            media_dir
+                .file_name()
+                .and_then(|s| s.to_str())
+                .and_then(whatsapp_chat_name)
+        })
+        .unwrap_or_else(|| "WhatsApp".to_string());
+
+    struct Pending {
+        date: (u32, u32, i32),
+        time: (u8, u8, u8),
+        sender: Option<String>,
+        text: String,
+    }
+
+    let mut pending: Vec<Pending> = Vec::new();
+    for line in text.lines() {
+        let Some(parsed) = parse_whatsapp_line(line) else {
+            if let Some(last) = pending.last_mut() {
+                last.text.push('\n');
+                last.text.push_str(line);
+            }
+            continue;
+        };
+        // Lines without "Name: " are system notices (encryption banner, joins).
+        let Some((sender, body)) = parsed.rest.split_once(": ") else {
+            continue;
+        };
+        pending.push(Pending {
+            date: parsed.date,
+            time: parsed.time,
+            sender: Some(sender.trim().to_string()),
+            text: body.to_string(),
+        });
+    }
+
+    // Exports use the phone's locale; a first field above 12 means day-first.
+    // Without a telling date, iOS exports (`[...]` headers) default to day-first.
+    let day_first = pending.iter().any(|p| p.date.0 > 12)
+        || (!pending.iter().any(|p| p.date.1 > 12) && text.trim_start().starts_with('['));
+
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    let mut messages = Vec::new();
+    for p in pending {
+        let (day, month) = if day_first {
+            (p.date.0, p.date.1)
+        } else {
+            (p.date.1, p.date.0)
+        };
+        let Ok(month) = time::Month::try_from(month as u8) else {
+            continue;
+        };
+        let Ok(date) = time::Date::from_calendar_date(p.date.2, month, day as u8) else {
+            continue;
+        };
+        let Ok(time_of_day) = time::Time::from_hms(p.time.0, p.time.1, p.time.2) else {
+            continue;
+        };
+        let Ok(created_at_ms) =
+            db::timezone_instant_ms(time::PrimitiveDateTime::new(date, time_of_day), default_tz)
+        else {
+            continue;
+        };
+
+        let mut text = p.text.trim().to_string();
+        let mut attachments = Vec::new();
+        if let Some(name) = whatsapp_attachment_name(&text) {
+            let path = file_inside_dir(media_dir, &media_dir.join(name));
+            if let Some(path) = path.filter(|p| mime_type_for_path(p).is_some()) {
+                attachments.push(path);
+                text.clear();
+            }
+        }
+        if text.is_empty() && attachments.is_empty() {
+            continue;
+        }
+
+        let identity = format!(
+            "{created_at_ms}\0{}\0{}",
+            p.sender.as_deref().unwrap_or_default(),
+            p.text
+        );
+        let occurrence = occurrences.entry(identity.clone()).or_insert(0);
+        let source_key = format!(
+            "{title}:{}",
+            &sha256_hex(format!("{identity}\0{occurrence}").as_bytes())[..32]
+        );
+        *occurrence += 1;
+
+        messages.push(ChatExportMessage {
+            source_key,
+            created_at_ms,
+            sender: p.sender,
+            text,
+            attachments,
+        });
+    }
+
+    Ok(ChatExport {
+        source_kind: WHATSAPP_IMPORT_SOURCE_KIND,
+        chat_key: title.clone(),
+        title,
+        messages,
+    })
+}
+
+fn chat_conversation_id(chat: &ChatExport) -> String {
+    format!(
+        "import.{}:{}",
+        chat.source_kind,
+        &sha256_hex(chat.chat_key.as_bytes())[..32]
+    )
+}
+
+fn message_content(chat_has_many_senders: bool, message: &ChatExportMessage) -> String {
+    match message.sender.as_deref() {
+        Some(sender) if chat_has_many_senders && !message.text.is_empty() => {
+            format!("{sender}: {}", message.text)
+        }
+        _ => message.text.clone(),
+    }
+}
+
+fn message_exists(conn: &Connection, key: &[u8; 32], message_id: Option<&str>) -> bool {
+    message_id
+        .is_some_and(|id| matches!(db::get_message_by_id_optional(conn, key, id), Ok(Some(_))))
+}
+
+/// Imports (or, with `preview`, only counts) the messages of a parsed chat export.
+///
+/// Messages already imported from the same export, or with identical timestamp,
+/// sender and content from another export, are reported as duplicates and
+/// skipped.
+pub fn import_chat_export(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    chat: &ChatExport,
+    lang: &str,
+    preview: bool,
+    report: &mut ImportReport,
+) -> Result<()> {
+    let senders: HashSet<&str> = chat
+        .messages
+        .iter()
+        .filter_map(|m| m.sender.as_deref())
+        .collect();
+    let many_senders = senders.len() > 1;
+    let annotate_enabled = db::get_media_annotation_config(conn)?.annotate_enabled;
+
+    let mut conversation_id: Option<String> = None;
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for message in &chat.messages {
+        report.items_scanned += 1;
+        let occurrence = occurrences.entry(message.content_identity()).or_insert(0);
+        let content_sha256 = message.content_sha256(*occurrence);
+        *occurrence += 1;
+        let existing = db::get_import_record(conn, chat.source_kind, &message.source_key)?;
+        if existing
+            .as_ref()
+            .is_some_and(|r| message_exists(conn, key, r.message_id.as_deref()))
+        {
+            report.items_skipped_unchanged += 1;
+            continue;
+        }
+        let duplicate =
+            db::find_import_record_by_content_sha256(conn, chat.source_kind, &content_sha256)?
+                .filter(|r| message_exists(conn, key, r.message_id.as_deref()));
+        if let Some(duplicate) = duplicate {
+            report.items_skipped_duplicate += 1;
+            if !preview {
+                db::upsert_import_record(
+                    conn,
+                    chat.source_kind,
+                    &message.source_key,
+                    &content_sha256,
+                    duplicate.message_id.as_deref(),
+                    now_ms(),
+                )?;
+            }
+            continue;
+        }
+
+        report.items_imported += 1;
+        report.messages_created += 1;
+        report.attachments_linked += message.attachments.len() as u32;
+        if preview {
+            continue;
+        }
+
+        let conversation_id = match conversation_id.as_ref() {
+            Some(id) => id.clone(),
+            None => {
+                let id = chat_conversation_id(chat);
+                db::get_or_create_conversation_with_id(conn, key, &id, &chat.title)?;
+                conversation_id = Some(id.clone());
+                id
+            }
+        };
+
+        let content = message_content(many_senders, message);
+        let inserted = db::insert_message_with_created_at(
+            conn,
+            key,
+            &conversation_id,
+            "user",
+            &content,
+            message.created_at_ms,
+        )?;
+        db::upsert_import_record(
+            conn,
+            chat.source_kind,
+            &message.source_key,
+            &content_sha256,
+            Some(&inserted.id),
+            now_ms(),
+        )?;
+
+        for path in &message.attachments {
+            let Some(mime_type) = mime_type_for_path(path) else {
+                continue;
+            };
+            let bytes = fs::read(path)?;
+            let attachment = db::insert_attachment(conn, key, app_dir, &bytes, mime_type)?;
+            db::link_attachment_to_message(conn, key, &inserted.id, &attachment.sha256)?;
+            if annotate_enabled && mime_type.starts_with("image/") {
+                db::enqueue_attachment_annotation(conn, &attachment.sha256, lang, now_ms())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_timezone(default_tz: Option<&str>) -> Result<()> {
+    if let Some(tz) = default_tz {
+        db::known_timezone_name(tz).ok_or_else(|| anyhow!("unknown timezone: {tz}"))?;
+    }
+    Ok(())
+}
+
+pub fn import_telegram_export(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    json_path: &Path,
+    lang: &str,
+    default_tz: Option<&str>,
+    preview: bool,
+) -> Result<ImportReport> {
+    check_timezone(default_tz)?;
+    let mut report = ImportReport::default();
+    for chat in parse_telegram_export(json_path, default_tz)? {
+        import_chat_export(conn, key, app_dir, &chat, lang, preview, &mut report)?;
+    }
+    Ok(report)
+}
+
+pub fn import_whatsapp_export(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    txt_path: &Path,
+    lang: &str,
+    default_tz: Option<&str>,
+    preview: bool,
+) -> Result<ImportReport> {
+    check_timezone(default_tz)?;
+    let chat = parse_whatsapp_export(txt_path, default_tz)?;
+    let mut report = ImportReport::default();
+    import_chat_export(conn, key, app_dir, &chat, lang, preview, &mut report)?;
+    Ok(report)
+}
@@ -0,0 +1,903 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+
+use super::{now_ms, sha256_hex, ImportReport};
+use crate::db;
+
+pub const ICS_EVENT_IMPORT_SOURCE_KIND: &str = "ics.event";
+pub const ICS_TODO_IMPORT_SOURCE_KIND: &str = "ics.todo";
+
+/// UIDs of items exported from this app carry this suffix; re-importing them
+/// updates the original row instead of creating an `ics:` copy.
+pub const ICS_UID_SUFFIX: &str = "@secondloop";
+/// Ids of items first seen in an `.ics` file are `ics:{UID}`.
+pub const ICS_IMPORTED_ID_PREFIX: &str = "ics:";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcsProperty {
+    pub name: String,
+    pub params: Vec<(String, String)>,
+    pub value: String,
+}
+
+impl IcsProperty {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A top-level `VEVENT` or `VTODO`; nested components such as `VALARM` are
+/// dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcsComponent {
+    pub kind: String,
+    pub properties: Vec<IcsProperty>,
+}
+
+impl IcsComponent {
+    pub fn property(&self, name: &str) -> Option<&IcsProperty> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    pub fn text(&self, name: &str) -> Option<String> {
+        self.property(name)
+            .map(|p| unescape_ics_text(&p.value))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn content_sha256(&self) -> String {
+        let mut material = self.kind.clone();
+        for property in &self.properties {
+            material.push('\n');
+            material.push_str(&property.name);
+            for (key, value) in &property.params {
+                material.push(';');
+                material.push_str(key);
+                material.push('=');
+                material.push_str(value);
+            }
+            material.push(':');
+            material.push_str(&property.value);
+        }
+        sha256_hex(material.as_bytes())
+    }
+}
+
+/// Maps an iCalendar UID to the id of the row it imports into.
+pub fn id_for_ics_uid(uid: &str) -> String {
+    match uid.strip_suffix(ICS_UID_SUFFIX) {
+        Some(id) if !id.is_empty() => id.to_string(),
+        _ => format!("{ICS_IMPORTED_ID_PREFIX}{uid}"),
+    }
+}
+
+/// Inverse of [`id_for_ics_uid`].
+pub fn ics_uid_for_id(id: &str) -> String {
+    match id.strip_prefix(ICS_IMPORTED_ID_PREFIX) {
+        Some(uid) if !uid.is_empty() => uid.to_string(),
+        _ => format!("{id}{ICS_UID_SUFFIX}"),
+    }
+}
+
+fn unescape_ics_text(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Splits `raw` on `sep`, ignoring separators inside double quotes.
+fn split_unquoted(raw: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (idx, c) in raw.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == sep && !in_quotes {
+            parts.push(&raw[start..idx]);
+            start = idx + c.len_utf8();
+        }
+    }
+    parts.push(&raw[start..]);
+    parts
+}
+
+fn parse_ics_property(line: &str) -> Option<IcsProperty> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(idx, c)| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        (c == ':' && !in_quotes).then_some(idx)
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut head_parts = split_unquoted(head, ';').into_iter();
+    let name = head_parts.next()?.trim().to_ascii_uppercase();
+    if name.is_empty() {
+        return None;
+    }
+    let params = head_parts
+        .filter_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            Some((
+                key.trim().to_ascii_uppercase(),
+                value.trim().trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+    Some(IcsProperty {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+/// Parses the `VEVENT` and `VTODO` components of an iCalendar document.
+pub fn parse_ics(text: &str) -> Result<Vec<IcsComponent>> {
+    // Unfold continuation lines (RFC 5545 3.1).
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(continuation) = raw.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(continuation);
+                continue;
+            }
+        }
+        lines.push(raw.to_string());
+    }
+
+    let mut saw_calendar = false;
+    let mut out = Vec::new();
+    let mut current: Option<IcsComponent> = None;
+    let mut nested_depth = 0usize;
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some(property) = parse_ics_property(&line) else {
+            continue;
+        };
+        let value = property.value.trim().to_ascii_uppercase();
+        match property.name.as_str() {
+            "BEGIN" if value == "VCALENDAR" => saw_calendar = true,
+            "BEGIN" if current.is_some() => nested_depth += 1,
+            "BEGIN" if value == "VEVENT" || value == "VTODO" => {
+                current = Some(IcsComponent {
+                    kind: value,
+                    properties: Vec::new(),
+                });
+            }
+            "END" if nested_depth > 0 => nested_depth -= 1,
+            "END" if current.as_ref().is_some_and(|c| c.kind == value) => {
+                out.extend(current.take());
+            }
+            _ if nested_depth > 0 => {}
+            _ => {
+                if let Some(component) = current.as_mut() {
+                    component.properties.push(property);
+                }
+            }
+        }
+    }
+
+    if !saw_calendar {
+        return Err(anyhow!("not an iCalendar file: missing BEGIN:VCALENDAR"));
+    }
+    Ok(out)
+}
+
+/// Resolves a `TZID`. Besides plain IANA names this accepts the prefixed ids
+/// some calendar servers emit, e.g. `/citadel.org/20190101_1/Europe/Berlin`.
+fn resolve_ics_tzid(raw: &str) -> Option<&'static str> {
+    let raw = raw.trim().trim_matches('"');
+    if let Some(name) = db::known_timezone_name(raw) {
+        return Some(name);
+    }
+    let segments = raw.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+    (2..=3).rev().find_map(|take| {
+        let start = segments.len().checked_sub(take)?;
+        db::known_timezone_name(&segments[start..].join("/"))
+    })
+}
+
+struct IcsTime {
+    at_ms: i64,
+    /// IANA zone the value was written in; `None` for UTC (`Z`) values.
+    tz: Option<String>,
+    all_day: bool,
+}
+
+/// Reads a DATE or DATE-TIME property. Floating times and dates are taken as
+/// local time in `default_tz`.
+fn ics_time(property: &IcsProperty, default_tz: Option<&str>) -> Result<IcsTime> {
+    let raw = property.value.trim();
+    let all_day = property
+        .param("VALUE")
+        .is_some_and(|v| v.eq_ignore_ascii_case("DATE"))
+        || (raw.len() == 8 && raw.bytes().all(|b| b.is_ascii_digit()));
+
+    let (tz, utc) = match property.param("TZID") {
+        _ if raw.ends_with('Z') || raw.ends_with('z') => (None, true),
+        Some(tzid) => match resolve_ics_tzid(tzid) {
+            Some(name) => (Some(name.to_string()), false),
+            None => return Err(anyhow!("unknown TZID: {tzid}")),
+        },
+        None => (default_tz.map(str::to_string), false),
+    };
+
+    let digits = raw.trim_end_matches(['Z', 'z']);
+    let wall_clock = if all_day {
+        let format = time::format_description::parse("[year][month][day]")?;
+        let date = time::Date::parse(&digits[..digits.len().min(8)], &format)
+            .map_err(|e| anyhow!("invalid date {raw}: {e}"))?;
+        time::PrimitiveDateTime::new(date, time::Time::MIDNIGHT)
+    } else {
+        let format = time::format_description::parse("[year][month][day]T[hour][minute][second]")?;
+        time::PrimitiveDateTime::parse(digits, &format)
+            .map_err(|e| anyhow!("invalid date-time {raw}: {e}"))?
+    };
+    let at_ms = if utc {
+        (wall_clock.assume_utc().unix_timestamp_nanos() / 1_000_000) as i64
+    } else {
+        db::timezone_instant_ms(wall_clock, tz.as_deref())?
+    };
+    Ok(IcsTime { at_ms, tz, all_day })
+}
+
+/// `DURATION` value (e.g. `PT1H30M`, `P1D`, `-P1W`) in milliseconds.
+fn ics_duration_ms(raw: &str) -> Result<i64> {
+    let raw = raw.trim();
+    let (sign, rest) = match raw.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, raw.strip_prefix('+').unwrap_or(raw)),
+    };
+    let rest = rest
+        .strip_prefix('P')
+        .ok_or_else(|| anyhow!("invalid duration: {raw}"))?;
+
+    let mut total_secs = 0i64;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let value: i64 = number
+                    .parse()
+                    .map_err(|_| anyhow!("invalid duration: {raw}"))?;
+                number.clear();
+                let unit_secs = match (unit, in_time) {
+                    ('W', false) => 7 * 86_400,
+                    ('D', false) => 86_400,
+                    ('H', true) => 3_600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return Err(anyhow!("invalid duration: {raw}")),
+                };
+                total_secs = total_secs.saturating_add(value.saturating_mul(unit_secs));
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(anyhow!("invalid duration: {raw}"));
+    }
+    Ok(sign * total_secs.saturating_mul(1000))
+}
+
+/// Converts an `RRULE` value plus `EXDATE`s into the recurrence rule JSON used
+/// by `todo_series`. `tz` is the zone of `DTSTART`, needed for a local
+/// `UNTIL`; the rule itself carries no zone and follows the todo's.
+pub fn rrule_to_recurrence_rule_json(
+    rrule: &str,
+    exdates_ms: &[i64],
+    tz: Option<&str>,
+    default_tz: Option<&str>,
+) -> Result<String> {
+    let mut rule = serde_json::Map::new();
+    for part in rrule.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid RRULE part: {part}"))?;
+        let value = value.trim();
+        let int = || -> Result<i64> {
+            value
+                .parse::<i64>()
+                .map_err(|_| anyhow!("invalid RRULE {key}: {value}"))
+        };
+        let int_list = || -> Result<Vec<i64>> {
+            value
+                .split(',')
+                .map(|v| {
+                    v.trim()
+                        .parse::<i64>()
+                        .map_err(|_| anyhow!("invalid RRULE {key}: {value}"))
+                })
+                .collect()
+        };
+        match key.trim().to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                rule.insert("freq".into(), value.to_ascii_lowercase().into());
+            }
+            "INTERVAL" => {
+                rule.insert("interval".into(), int()?.into());
+            }
+            "COUNT" => {
+                rule.insert("count".into(), int()?.into());
+            }
+            "UNTIL" => {
+                let property = IcsProperty {
+                    name: "UNTIL".to_string(),
+                    params: tz
+                        .map(|tz| vec![("TZID".to_string(), tz.to_string())])
+                        .unwrap_or_default(),
+                    value: value.to_string(),
+                };
+                let until = ics_time(&property, default_tz)?;
+                // A date-only UNTIL includes that whole day.
+                let until_ms = if until.all_day {
+                    until.at_ms + 86_400_000 - 1
+                } else {
+                    until.at_ms
+                };
+                rule.insert("until_ms".into(), until_ms.into());
+            }
+            "BYDAY" => {
+                let days = value
+                    .split(',')
+                    .map(|v| serde_json::Value::from(v.trim().to_ascii_uppercase()))
+                    .collect::<Vec<_>>();
+                rule.insert("byday".into(), days.into());
+            }
+            "BYMONTHDAY" => {
+                rule.insert("bymonthday".into(), int_list()?.into());
+            }
+            "BYMONTH" => {
+                rule.insert("bymonth".into(), int_list()?.into());
+            }
+            "WKST" => {
+                rule.insert("wkst".into(), value.to_ascii_uppercase().into());
+            }
+            other => return Err(anyhow!("unsupported RRULE part: {other}")),
+        }
+    }
+    if !exdates_ms.is_empty() {
+        rule.insert("exdates_ms".into(), exdates_ms.to_vec().into());
+    }
+
+    let rule_json = serde_json::Value::Object(rule).to_string();
+    db::validate_recurrence_rule(&rule_json)?;
+    Ok(rule_json)
+}
+
+fn ics_exdates_ms(component: &IcsComponent, default_tz: Option<&str>) -> Result<Vec<i64>> {
+    let mut out = Vec::new();
+    for property in component.properties.iter().filter(|p| p.name == "EXDATE") {
+        for value in property
+            .value
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+        {
+            let single = IcsProperty {
+                name: property.name.clone(),
+                params: property.params.clone(),
+                value: value.to_string(),
+            };
+            out.push(ics_time(&single, default_tz)?.at_ms);
+        }
+    }
+    out.sort_unstable();
+    out.dedup();
+    Ok(out)
+}
+
+/// Compares rules ignoring defaults that the `RRULE` form drops, so
+/// re-importing an exported series does not rewrite it.
+fn same_recurrence_rule(current: &str, imported: &str) -> bool {
+    fn normalized(rule_json: &str) -> Option<serde_json::Value> {
+        let mut rule: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(rule_json).ok()?;
+        rule.remove("tz");
+        if rule.get("interval").and_then(|v| v.as_i64()) == Some(1) {
+            rule.remove("interval");
+        }
+        rule.retain(|_, value| !value.is_null() && value.as_array().is_none_or(|a| !a.is_empty()));
+        for (_, value) in rule.iter_mut() {
+            match value {
+                serde_json::Value::String(s) => *s = s.trim().to_ascii_uppercase(),
+                serde_json::Value::Array(items) => {
+                    for item in items.iter_mut() {
+                        if let serde_json::Value::String(s) = item {
+                            *s = s.trim().to_ascii_uppercase();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(serde_json::Value::Object(rule))
+    }
+    normalized(current).is_some_and(|current| Some(current) == normalized(imported))
+}
+
+fn todo_status_from_ics(status: Option<&str>) -> &'static str {
+    match status.map(|s| s.trim().to_ascii_uppercase()).as_deref() {
+        Some("COMPLETED") => "done",
+        Some("IN-PROCESS") => "in_progress",
+        Some("CANCELLED") => "dismissed",
+        _ => "open",
+    }
+}
+
+enum IcsImportOutcome {
+    Created,
+    Updated,
+}
+
+/// Applies one `RECURRENCE-ID` override to a recurring event; returns the
+/// occurrence it overrides.
+fn import_ics_event_override(
+    conn: &Connection,
+    key: &[u8; 32],
+    event: &db::Event,
+    component: &IcsComponent,
+    existing: &[db::EventException],
+    default_tz: Option<&str>,
+) -> Result<i64> {
+    let recurrence_id = component
+        .property("RECURRENCE-ID")
+        .ok_or_else(|| anyhow!("override has no RECURRENCE-ID"))?;
+    let occurrence_start_ms = ics_time(recurrence_id, default_tz)?.at_ms;
+    let current = existing
+        .iter()
+        .find(|exception| exception.occurrence_start_ms == occurrence_start_ms);
+
+    let cancelled = component
+        .property("STATUS")
+        .is_some_and(|status| status.value.trim().eq_ignore_ascii_case("CANCELLED"));
+    if cancelled {
+        if current.is_none_or(|current| current.kind != db::EVENT_EXCEPTION_CANCELLED) {
+            db::cancel_event_occurrence(conn, key, &event.id, occurrence_start_ms)?;
+        }
+        return Ok(occurrence_start_ms);
+    }
+
+    let start_at_ms = match component.property("DTSTART") {
+        Some(start) => ics_time(start, default_tz)?.at_ms,
+        None => occurrence_start_ms,
+    };
+    let end_at_ms = match (component.property("DTEND"), component.property("DURATION")) {
+        (Some(end), _) => ics_time(end, default_tz)?.at_ms,
+        (None, Some(duration)) => start_at_ms.saturating_add(ics_duration_ms(&duration.value)?),
+        (None, None) => start_at_ms.saturating_add(event.end_at_ms - event.start_at_ms),
+    };
+    // A summary equal to the series' keeps following later renames.
+    let title = component
+        .text("SUMMARY")
+        .filter(|title| *title != event.title);
+    let unchanged = current.is_some_and(|current| {
+        current.kind == db::EVENT_EXCEPTION_MOVED
+            && current.start_at_ms == Some(start_at_ms)
+            && current.end_at_ms == Some(end_at_ms)
+            && current.title == title
+    });
+    if !unchanged {
+        db::move_event_occurrence(
+            conn,
+            key,
+            &event.id,
+            occurrence_start_ms,
+            start_at_ms,
+            end_at_ms,
+            title.as_deref(),
+        )?;
+    }
+    Ok(occurrence_start_ms)
+}
+
+/// Imports a `VEVENT` together with the `RECURRENCE-ID` overrides that share
+/// its UID. The document is taken as the whole series: local exceptions it no
+/// longer mentions are restored.
+#[allow(clippy::too_many_arguments)]
+fn import_ics_event(
+    conn: &Connection,
+    key: &[u8; 32],
+    component: &IcsComponent,
+    overrides: &[IcsComponent],
+    uid: &str,
+    default_tz: Option<&str>,
+    preview: bool,
+    report: &mut ImportReport,
+) -> Result<IcsImportOutcome> {
+    let start_property = component
+        .property("DTSTART")
+        .ok_or_else(|| anyhow!("event {uid} has no DTSTART"))?;
+    let start = ics_time(start_property, default_tz)?;
+    let end_at_ms = match (component.property("DTEND"), component.property("DURATION")) {
+        (Some(end), _) => ics_time(end, default_tz)?.at_ms,
+        (None, Some(duration)) => start
+            .at_ms
+            .saturating_add(ics_duration_ms(&duration.value)?),
+        (None, None) if start.all_day => start.at_ms + 86_400_000,
+        (None, None) => start.at_ms,
+    };
+    if end_at_ms < start.at_ms {
+        return Err(anyhow!("event {uid} ends before it starts"));
+    }
+
+    let id = id_for_ics_uid(uid);
+    let exists = db::get_event(conn, key, &id).is_ok();
+    let exceptions = if exists {
+        db::list_event_exceptions(conn, key, &id)?
+    } else {
+        Vec::new()
+    };
+    let exdates_ms = ics_exdates_ms(component, default_tz)?;
+    let rule_json = match component.property("RRULE") {
+        Some(rrule) => {
+            // Our own cancelled occurrences come back as EXDATEs; they stay
+            // exceptions so restoring them keeps working.
+            let rule_exdates_ms = exdates_ms
+                .iter()
+                .copied()
+                .filter(|at_ms| {
+                    !exceptions.iter().any(|exception| {
+                        exception.kind == db::EVENT_EXCEPTION_CANCELLED
+                            && exception.occurrence_start_ms == *at_ms
+                    })
+                })
+                .collect::<Vec<_>>();
+            match rrule_to_recurrence_rule_json(
+                &rrule.value,
+                &rule_exdates_ms,
+                start.tz.as_deref(),
+                default_tz,
+            ) {
+                Ok(rule_json) => Some(rule_json),
+                Err(e) => {
+                    report.errors.push(format!(
+                        "event {uid}: recurrence not imported, only the first occurrence was: {e}"
+                    ));
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    if preview {
+        return Ok(if exists {
+            IcsImportOutcome::Updated
+        } else {
+            IcsImportOutcome::Created
+        });
+    }
+
+    let title = component.text("SUMMARY").unwrap_or_default();
+    let tz = start
+        .tz
+        .as_deref()
+        .or(default_tz)
+        .unwrap_or("UTC")
+        .to_string();
+    let event = db::upsert_event(conn, key, &id, &title, start.at_ms, end_at_ms, &tz, None)?;
+
+    match (db::get_event_recurrence_rule_json(conn, &id)?, rule_json) {
+        (None, Some(rule_json)) => db::set_event_recurrence_rule(conn, key, &id, Some(&rule_json))?,
+        (Some(current), Some(rule_json)) if !same_recurrence_rule(&current, &rule_json) => {
+            db::set_event_recurrence_rule(conn, key, &id, Some(&rule_json))?
+        }
+        (Some(_), None) => db::set_event_recurrence_rule(conn, key, &id, None)?,
+        _ => {}
+    }
+
+    if db::get_event_recurrence_rule_json(conn, &id)?.is_none() {
+        if !overrides.is_empty() {
+            report.errors.push(format!(
+                "event {uid}: overridden occurrences of a non-recurring event skipped"
+            ));
+        }
+        return Ok(if exists {
+            IcsImportOutcome::Updated
+        } else {
+            IcsImportOutcome::Created
+        });
+    }
+
+    let mut overridden = Vec::with_capacity(overrides.len());
+    for component in overrides {
+        match import_ics_event_override(conn, key, &event, component, &exceptions, default_tz) {
+            Ok(occurrence_start_ms) => overridden.push(occurrence_start_ms),
+            Err(e) => report.errors.push(format!("event {uid}: {e}")),
+        }
+    }
+    for exception in &exceptions {
+        let still_listed = overridden.contains(&exception.occurrence_start_ms)
+            || (exception.kind == db::EVENT_EXCEPTION_CANCELLED
+                && exdates_ms.contains(&exception.occurrence_start_ms));
+        if exception.kind != db::EVENT_EXCEPTION_RESTORED && !still_listed {
+            db::restore_event_occurrence(conn, key, &id, exception.occurrence_start_ms)?;
+        }
+    }
+
+    Ok(if exists {
+        IcsImportOutcome::Updated
+    } else {
+        IcsImportOutcome::Created
+    })
+}
+
+fn import_ics_todo(
+    conn: &Connection,
+    key: &[u8; 32],
+    component: &IcsComponent,
+    uid: &str,
+    default_tz: Option<&str>,
+    preview: bool,
+    report: &mut ImportReport,
+) -> Result<IcsImportOutcome> {
+    let due = match component
+        .property("DUE")
+        .or_else(|| component.property("DTSTART"))
+    {
+        Some(property) => Some(ics_time(property, default_tz)?),
+        None => None,
+    };
+    let tz = due
+        .as_ref()
+        .and_then(|due| due.tz.clone())
+        .or_else(|| default_tz.map(str::to_string));
+
+    let rule_json = match (component.property("RRULE"), due.as_ref()) {
+        (Some(rrule), Some(_)) => {
+            let exdates_ms = ics_exdates_ms(component, default_tz)?;
+            match rrule_to_recurrence_rule_json(
+                &rrule.value,
+                &exdates_ms,
+                tz.as_deref(),
+                default_tz,
+            ) {
+                Ok(rule_json) => Some(rule_json),
+                Err(e) => {
+                    report
+                        .errors
+                        .push(format!("todo {uid}: recurrence not imported: {e}"));
+                    None
+                }
+            }
+        }
+        (Some(_), None) => {
+            report
+                .errors
+                .push(format!("todo {uid}: RRULE without DUE or DTSTART ignored"));
+            None
+        }
+        _ => None,
+    };
+
+    // Recurring todos imported earlier live on as a series; updates go to
+    // its newest occurrence.
+    let base_id = id_for_ics_uid(uid);
+    let todo_id =
+        db::get_latest_todo_id_in_recurrence_series(conn, &base_id)?.unwrap_or(base_id.clone());
+    let existing = db::get_todo(conn, key, &todo_id).ok();
+    let outcome = if existing.is_some() {
+        IcsImportOutcome::Updated
+    } else {
+        IcsImportOutcome::Created
+    };
+    if preview {
+        return Ok(outcome);
+    }
+
+    let title = component.text("SUMMARY").unwrap_or_default();
+    let status = todo_status_from_ics(component.property("STATUS").map(|p| p.value.as_str()));
+    // Imports start outside the review flow; existing rows keep theirs.
+    let (source_entry_id, review_stage, next_review_at_ms, last_review_at_ms) = match &existing {
+        Some(todo) => (
+            todo.source_entry_id.clone(),
+            todo.review_stage,
+            todo.next_review_at_ms,
+            todo.last_review_at_ms,
+        ),
+        None => (None, None, None, None),
+    };
+    db::upsert_todo(
+        conn,
+        key,
+        &todo_id,
+        &title,
+        due.as_ref().map(|due| due.at_ms),
+        status,
+        source_entry_id.as_deref(),
+        review_stage,
+        next_review_at_ms,
+        last_review_at_ms,
+    )?;
+    if db::get_todo_timezone(conn, &todo_id)? != tz {
+        db::set_todo_timezone(conn, key, &todo_id, tz.as_deref())?;
+    }
+
+    if let Some(rule_json) = rule_json {
+        match db::get_todo_recurrence_rule_json_from_occurrence(conn, &todo_id)? {
+            None => {
+                db::upsert_todo_recurrence_with_sync(conn, key, &todo_id, &base_id, &rule_json)?
+            }
+            Some(current) if !same_recurrence_rule(&current, &rule_json) => {
+                db::set_todo_recurrence_rule_from_occurrence(conn, key, &todo_id, &rule_json)?
+            }
+            Some(_) => {}
+        }
+    }
+
+    if existing.is_none() {
+        report.todos_created += 1;
+    }
+    Ok(outcome)
+}
+
+/// Imports (or, with `preview`, only counts) the events and todos of an
+/// iCalendar document. Items are keyed by UID, so importing the same calendar
+/// again updates them; components unchanged since the last import are
+/// skipped. Floating times and all-day dates are read in `default_tz`.
+pub fn import_ics(
+    conn: &Connection,
+    key: &[u8; 32],
+    text: &str,
+    default_tz: Option<&str>,
+    preview: bool,
+) -> Result<ImportReport> {
+    let default_tz = match default_tz.map(str::trim).filter(|tz| !tz.is_empty()) {
+        Some(tz) => {
+            Some(db::known_timezone_name(tz).ok_or_else(|| anyhow!("unknown timezone: {tz}"))?)
+        }
+        None => None,
+    };
+
+    let mut report = ImportReport::default();
+    let mut items = Vec::new();
+    let mut overrides: HashMap<String, Vec<IcsComponent>> = HashMap::new();
+    for component in parse_ics(text)? {
+        report.items_scanned += 1;
+        let Some(uid) = component.text("UID") else {
+            report
+                .errors
+                .push(format!("{} without UID skipped", component.kind));
+            continue;
+        };
+        if component.property("RECURRENCE-ID").is_some() {
+            if component.kind == "VEVENT" {
+                overrides.entry(uid).or_default().push(component);
+            } else {
+                report.errors.push(format!(
+                    "{uid}: overridden todo occurrences are not supported; skipped"
+                ));
+            }
+            continue;
+        }
+        items.push((uid, component));
+    }
+    let mut orphaned = overrides
+        .keys()
+        .filter(|uid| {
+            !items
+                .iter()
+                .any(|(item_uid, component)| item_uid == *uid && component.kind == "VEVENT")
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    orphaned.sort();
+    for uid in orphaned {
+        report.errors.push(format!(
+            "{uid}: overridden occurrences without their series; skipped"
+        ));
+    }
+
+    for (uid, component) in items {
+        let event_overrides = match component.kind.as_str() {
+            "VEVENT" => overrides.get(&uid).map(Vec::as_slice).unwrap_or_default(),
+            _ => &[],
+        };
+        let source_kind = if component.kind == "VEVENT" {
+            ICS_EVENT_IMPORT_SOURCE_KIND
+        } else {
+            ICS_TODO_IMPORT_SOURCE_KIND
+        };
+        let content_sha256 = if event_overrides.is_empty() {
+            component.content_sha256()
+        } else {
+            let mut material = component.content_sha256();
+            for item in event_overrides {
+                material.push('\n');
+                material.push_str(&item.content_sha256());
+            }
+            sha256_hex(material.as_bytes())
+        };
+        let unchanged = db::get_import_record(conn, source_kind, &uid)?
+            .is_some_and(|record| record.content_sha256 == content_sha256);
+        let id = id_for_ics_uid(&uid);
+        let still_present = if component.kind == "VEVENT" {
+            db::get_event(conn, key, &id).is_ok()
+        } else {
+            db::get_latest_todo_id_in_recurrence_series(conn, &id)?.is_some()
+                || db::get_todo(conn, key, &id).is_ok()
+        };
+        if unchanged && still_present {
+            report.items_skipped_unchanged += 1;
+            continue;
+        }
+
+        let result = if component.kind == "VEVENT" {
+            import_ics_event(
+                conn,
+                key,
+                &component,
+                event_overrides,
+                &uid,
+                default_tz,
+                preview,
+                &mut report,
+            )
+        } else {
+            import_ics_todo(
+                conn,
+                key,
+                &component,
+                &uid,
+                default_tz,
+                preview,
+                &mut report,
+            )
+        };
+        match result {
+            Ok(IcsImportOutcome::Created) => report.items_imported += 1,
+            Ok(IcsImportOutcome::Updated) => report.items_updated += 1,
+            Err(e) => {
+                report.errors.push(format!("{uid}: {e}"));
+                continue;
+            }
+        }
+        if !preview {
+            db::upsert_import_record(conn, source_kind, &uid, &content_sha256, None, now_ms())?;
+        }
+    }
+    Ok(report)
+}
+
+pub fn import_ics_file(
+    conn: &Connection,
+    key: &[u8; 32],
+    ics_path: &Path,
+    default_tz: Option<&str>,
+    preview: bool,
+) -> Result<ImportReport> {
+    let bytes = fs::read(ics_path).map_err(|e| anyhow!("failed to read ics file: {e}"))?;
+    let text = String::from_utf8_lossy(&bytes);
+    import_ics(
+        conn,
+        key,
+        text.trim_start_matches('\u{feff}'),
+        default_tz,
+        preview,
+    )
+}
@@ -0,0 +1,730 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+
+use super::{
+    file_inside_dir, file_modified_at_ms, mime_type_for_path, now_ms, sha256_hex, ImportReport,
+};
+use crate::db;
+
+pub const MARKDOWN_IMPORT_SOURCE_KIND: &str = "markdown";
+
+const SKIPPED_DIR_NAMES: &[&str] = &[".obsidian", ".git", ".trash", "node_modules"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkdownTodo {
+    pub title: String,
+    pub done: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkdownEmbed {
+    pub target: String,
+    /// Obsidian `![[...]]` embeds resolve by file name anywhere in the vault.
+    pub is_wiki_link: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkdownNote {
+    pub title: String,
+    pub body: String,
+    pub tags: Vec<String>,
+    pub created_at_ms: Option<i64>,
+    pub todos: Vec<MarkdownTodo>,
+    pub embeds: Vec<MarkdownEmbed>,
+}
+
+impl MarkdownNote {
+    /// Message content stored for the note: the body, headed by the title when it
+    /// doesn't already start with one.
+    pub fn message_content(&self) -> String {
+        let body = self.body.trim();
+        if body.starts_with("# ") {
+            return body.to_string();
+        }
+        if body.is_empty() {
+            return format!("# {}", self.title);
+        }
+        format!("# {}\n\n{}", self.title, body)
+    }
+}
+
+fn split_front_matter(text: &str) -> (Option<&str>, &str) {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let Some(rest) = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))
+    else {
+        return (None, text);
+    };
+
+    let mut offset = 0usize;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, text)
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+/// Parses the small YAML subset notes use in practice: `key: value`, inline
+/// lists (`[a, b]`) and block lists (`- a`).
+fn parse_front_matter(raw: &str) -> BTreeMap<String, Vec<String>> {
+    let mut out: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut current_key: Option<String> = None;
+
+    for line in raw.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            if let Some(key) = current_key.as_ref() {
+                let item = unquote(item);
+                if !item.is_empty() {
+                    out.entry(key.clone()).or_default().push(item.to_string());
+                }
+            }
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once(':') else {
+            current_key = None;
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim();
+        let values = out.entry(key.clone()).or_default();
+        if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            values.extend(
+                inner
+                    .split(',')
+                    .map(unquote)
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string),
+            );
+        } else if !value.is_empty() {
+            values.push(unquote(value).to_string());
+        }
+        current_key = Some(key);
+    }
+
+    out
+}
+
+fn normalize_tags(raw_values: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for raw in raw_values {
+        // `tags: a, b` and `tags: a b` are both common in Obsidian vaults.
+        let pieces: Vec<&str> = if raw.contains(',') {
+            raw.split(',').collect()
+        } else {
+            raw.split_whitespace().collect()
+        };
+        for piece in pieces {
+            let tag = unquote(piece).trim().trim_start_matches('#').trim();
+            if tag.is_empty() {
+                continue;
+            }
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.to_string());
+            }
+        }
+    }
+    tags
+}
+
+/// Front-matter dates without an offset are local times in `default_tz`
+/// (UTC when `None`).
+fn parse_note_date_ms(raw: &str, default_tz: Option<&str>) -> Option<i64> {
+    let raw = raw.trim();
+    if let Ok(dt) = time::OffsetDateTime::parse(raw, &time::format_description::well_known::Rfc3339)
+    {
+        return Some((dt.unix_timestamp_nanos() / 1_000_000) as i64);
+    }
+
+    let (date_part, time_part) = match raw.split_once(['T', ' ']) {
+        Some((d, t)) => (d, Some(t.trim())),
+        None => (raw, None),
+    };
+
+    let mut date_fields = date_part.split('-');
+    let year: i32 = date_fields.next()?.parse().ok()?;
+    let month: u8 = date_fields.next()?.parse().ok()?;
+    let day: u8 = date_fields.next()?.parse().ok()?;
+    if date_fields.next().is_some() {
+        return None;
+    }
+    let date =
+        time::Date::from_calendar_date(year, time::Month::try_from(month).ok()?, day).ok()?;
+
+    let time_of_day = match time_part {
+        None | Some("") => time::Time::MIDNIGHT,
+        Some(t) => {
+            let mut fields = t.split(':');
+            let hour: u8 = fields.next()?.parse().ok()?;
+            let minute: u8 = fields.next()?.parse().ok()?;
+            let second: u8 = match fields.next() {
+                Some(s) => s.split('.').next()?.parse().ok()?,
+                None => 0,
+            };
+            time::Time::from_hms(hour, minute, second).ok()?
+        }
+    };
+
+    db::timezone_instant_ms(time::PrimitiveDateTime::new(date, time_of_day), default_tz).ok()
+}
+
+fn parse_todo_line(line: &str) -> Option<MarkdownTodo> {
+    let trimmed = line.trim_start();
+    let rest = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+        .or_else(|| trimmed.strip_prefix("+ "))?;
+    let (done, title) = if let Some(t) = rest.strip_prefix("[ ]") {
+        (false, t)
+    } else if let Some(t) = rest
+        .strip_prefix("[x]")
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        (true, t)
+    } else {
+        return None;
+    };
+
+    let title = title.trim();
+    if title.is_empty() {
+        return None;
+    }
+    Some(MarkdownTodo {
+        title: title.to_string(),
+        done,
+    })
+}
+
+fn is_external_target(target: &str) -> bool {
+    let lower = target.to_ascii_lowercase();
+    lower.starts_with("http://")
+        || lower.starts_with("https://")
+        || lower.starts_with("data:")
+        || lower.starts_with("mailto:")
+}
+
+fn percent_decode_spaces(target: &str) -> String {
+    target.replace("%20", " ")
+}
+
+fn parse_embeds_in_line(line: &str, out: &mut Vec<MarkdownEmbed>) {
+    let mut rest = line;
+    while let Some(start) = rest.find("![") {
+        let after = &rest[start + 2..];
+
+        if let Some(inner) = after.strip_prefix('[') {
+            let Some(end) = inner.find("]]") else {
+                break;
+            };
+            let raw = &inner[..end];
+            let target = raw.split(['|', '#']).next().unwrap_or_default().trim();
+            if !target.is_empty() && !is_external_target(target) {
+                out.push(MarkdownEmbed {
+                    target: target.to_string(),
+                    is_wiki_link: true,
+                });
+            }
+            rest = &inner[end + 2..];
+            continue;
+        }
+
+        let Some(alt_end) = after.find("](") else {
+            break;
+        };
+        let link = &after[alt_end + 2..];
+        let Some(link_end) = link.find(')') else {
+            break;
+        };
+        let raw = link[..link_end].trim();
+        let raw = match raw.strip_prefix('<') {
+            Some(r) => r.split('>').next().unwrap_or_default(),
+            // Drop an optional `"title"` after the path.
+            None => raw.split_whitespace().next().unwrap_or_default(),
+        };
+        if !raw.is_empty() && !is_external_target(raw) {
+            out.push(MarkdownEmbed {
+                target: percent_decode_spaces(raw),
+                is_wiki_link: false,
+            });
+        }
+        rest = &link[link_end + 1..];
+    }
+}
+
+/// Parses one note. Dates without an offset are read as local times in
+/// `default_tz` (an IANA name; UTC when `None`).
+pub fn parse_markdown_note(file_stem: &str, text: &str, default_tz: Option<&str>) -> MarkdownNote {
+    let (front_matter_raw, body) = split_front_matter(text);
+    let front_matter = front_matter_raw.map(parse_front_matter).unwrap_or_default();
+
+    let mut raw_tags: Vec<String> = Vec::new();
+    for key in ["tags", "tag"] {
+        if let Some(values) = front_matter.get(key) {
+            raw_tags.extend(values.iter().cloned());
+        }
+    }
+    let tags = normalize_tags(&raw_tags);
+
+    let created_at_ms = ["created", "date", "created_at"]
+        .iter()
+        .filter_map(|k| front_matter.get(*k).and_then(|v| v.first()))
+        .find_map(|v| parse_note_date_ms(v, default_tz));
+
+    let mut todos = Vec::new();
+    let mut embeds = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut in_code_fence = false;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_fence = !in_code_fence;
+            continue;
+        }
+        if in_code_fence {
+            continue;
+        }
+        if heading.is_none() {
+            if let Some(h) = trimmed.strip_prefix("# ") {
+                let h = h.trim();
+                if !h.is_empty() {
+                    heading = Some(h.to_string());
+                }
+            }
+        }
+        if let Some(todo) = parse_todo_line(line) {
+            todos.push(todo);
+        }
+        parse_embeds_in_line(line, &mut embeds);
+    }
+
+    let title = front_matter
+        .get("title")
+        .and_then(|v| v.first())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .or(heading)
+        .unwrap_or_else(|| file_stem.to_string());
+
+    MarkdownNote {
+        title,
+        body: body.trim().to_string(),
+        tags,
+        created_at_ms,
+        todos,
+        embeds,
+    }
+}
+
+fn is_markdown_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.eq_ignore_ascii_case("md") || e.eq_ignore_ascii_case("markdown"))
+        .unwrap_or(false)
+}
+
+fn collect_files(
+    dir: &Path,
+    notes: &mut Vec<PathBuf>,
+    files_by_name: &mut HashMap<String, PathBuf>,
+) -> Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string();
+        if path.is_dir() {
+            if name.starts_with('.') || SKIPPED_DIR_NAMES.contains(&name.as_str()) {
+                continue;
+            }
+            collect_files(&path, notes, files_by_name)?;
+            continue;
+        }
+        if is_markdown_file(&path) {
+            notes.push(path.clone());
+        }
+        files_by_name
+            .entry(name.to_lowercase())
+            .or_insert_with(|| path.clone());
+    }
+    Ok(())
+}
+
+fn relative_display(root: &Path, path: &Path) -> String {
+    let rel = path.strip_prefix(root).unwrap_or(path);
+    rel.components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn resolve_embed(
+    root: &Path,
+    note_dir: &Path,
+    embed: &MarkdownEmbed,
+    files_by_name: &HashMap<String, PathBuf>,
+) -> Option<PathBuf> {
+    let target = embed.target.trim_start_matches("./");
+    let candidates = [note_dir.join(target), root.join(target)];
+    if let Some(found) = candidates.iter().find_map(|p| file_inside_dir(root, p)) {
+        return Some(found);
+    }
+    if embed.is_wiki_link {
+        let name = Path::new(target).file_name()?.to_str()?.to_lowercase();
+        return files_by_name
+            .get(&name)
+            .and_then(|p| file_inside_dir(root, p));
+    }
+    None
+}
+
+fn folder_conversation(
+    conn: &Connection,
+    key: &[u8; 32],
+    root: &Path,
+    root_name: &str,
+    folder: &Path,
+    cache: &mut HashMap<PathBuf, String>,
+) -> Result<String> {
+    if let Some(id) = cache.get(folder) {
+        return Ok(id.clone());
+    }
+
+    let folder_key = folder.to_string_lossy().to_string();
+    let id = format!(
+        "import.markdown:{}",
+        &sha256_hex(folder_key.as_bytes())[..32]
+    );
+    let rel = relative_display(root, folder);
+    let title = if rel.is_empty() {
+        root_name.to_string()
+    } else {
+        format!("{root_name}/{rel}")
+    };
+    db::get_or_create_conversation_with_id(conn, key, &id, &title)?;
+    cache.insert(folder.to_path_buf(), id.clone());
+    Ok(id)
+}
+
+fn imported_todo_id(source_key: &str, title: &str, occurrence: usize) -> String {
+    let digest = sha256_hex(format!("{source_key}\0{title}\0{occurrence}").as_bytes());
+    format!("todo:import:{}", &digest[..32])
+}
+
+fn message_still_exists(conn: &Connection, key: &[u8; 32], message_id: &str) -> bool {
+    matches!(
+        db::get_message_by_id_optional(conn, key, message_id),
+        Ok(Some(_))
+    )
+}
+
+struct NoteContext<'a> {
+    root: &'a Path,
+    app_dir: &'a Path,
+    lang: &'a str,
+    default_tz: Option<&'a str>,
+    files_by_name: &'a HashMap<String, PathBuf>,
+}
+
+fn apply_note_tags(
+    conn: &Connection,
+    key: &[u8; 32],
+    message_id: &str,
+    tags: &[String],
+    report: &mut ImportReport,
+) -> Result<()> {
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    let existing = db::list_message_tags(conn, key, message_id)?;
+    let mut tag_ids: Vec<String> = existing.iter().map(|t| t.id.clone()).collect();
+    for name in tags {
+        let tag = db::upsert_tag(conn, key, name)?;
+        if !tag_ids.contains(&tag.id) {
+            tag_ids.push(tag.id);
+            report.tags_applied += 1;
+        }
+    }
+    if tag_ids.len() != existing.len() {
+        db::set_message_tags(conn, key, message_id, &tag_ids)?;
+    }
+    Ok(())
+}
+
+/// Creates the note's todos and syncs their checkbox state both ways: a
+/// checked box marks the todo done, an unchecked one reopens a done todo.
+/// Other statuses set in the app (e.g. in progress) are left alone.
+fn apply_note_todos(
+    conn: &Connection,
+    key: &[u8; 32],
+    source_key: &str,
+    message_id: &str,
+    todos: &[MarkdownTodo],
+    report: &mut ImportReport,
+) -> Result<()> {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for todo in todos {
+        let occurrence = seen.entry(todo.title.as_str()).or_insert(0);
+        let todo_id = imported_todo_id(source_key, &todo.title, *occurrence);
+        *occurrence += 1;
+
+        match db::get_todo(conn, key, &todo_id) {
+            Ok(existing) => {
+                if todo.done && existing.status != "done" {
+                    db::set_todo_status(conn, key, &todo_id, "done", Some(message_id))?;
+                } else if !todo.done && existing.status == "done" {
+                    db::set_todo_status(conn, key, &todo_id, "open", Some(message_id))?;
+                }
+            }
+            Err(_) => {
+                let status = if todo.done { "done" } else { "open" };
+                db::upsert_todo(
+                    conn,
+                    key,
+                    &todo_id,
+                    &todo.title,
+                    None,
+                    status,
+                    Some(message_id),
+                    None,
+                    None,
+                    None,
+                )?;
+                report.todos_created += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_note_embeds(
+    conn: &Connection,
+    key: &[u8; 32],
+    ctx: &NoteContext<'_>,
+    note_dir: &Path,
+    message_id: &str,
+    embeds: &[MarkdownEmbed],
+    report: &mut ImportReport,
+) -> Result<()> {
+    let annotate_enabled = db::get_media_annotation_config(conn)?.annotate_enabled;
+    // Re-imports of an edited note only count links that are new.
+    let mut linked: HashSet<String> = db::list_message_attachments(conn, key, message_id)?
+        .into_iter()
+        .map(|a| a.sha256)
+        .collect();
+
+    for embed in embeds {
+        let Some(path) = resolve_embed(ctx.root, note_dir, embed, ctx.files_by_name) else {
+            report
+                .errors
+                .push(format!("embed not found: {}", embed.target));
+            continue;
+        };
+        // Notes linking other notes (`![[Other note]]`) are transclusions, not files.
+        let Some(mime_type) = mime_type_for_path(&path) else {
+            continue;
+        };
+
+        let bytes = fs::read(&path)?;
+        let attachment = db::insert_attachment(conn, key, ctx.app_dir, &bytes, mime_type)?;
+        if linked.contains(&attachment.sha256) {
+            continue;
+        }
+        db::link_attachment_to_message(conn, key, message_id, &attachment.sha256)?;
+        if annotate_enabled && mime_type.starts_with("image/") {
+            db::enqueue_attachment_annotation(conn, &attachment.sha256, ctx.lang, now_ms())?;
+        }
+        linked.insert(attachment.sha256);
+        report.attachments_linked += 1;
+    }
+    Ok(())
+}
+
+fn import_note(
+    conn: &Connection,
+    key: &[u8; 32],
+    ctx: &NoteContext<'_>,
+    conversation_id: &str,
+    path: &Path,
+    report: &mut ImportReport,
+) -> Result<()> {
+    let bytes = fs::read(path)?;
+    let text = String::from_utf8(bytes.clone()).map_err(|_| anyhow!("not valid utf-8"))?;
+    let content_sha256 = sha256_hex(&bytes);
+    let source_key = path.to_string_lossy().to_string();
+
+    let existing = db::get_import_record(conn, MARKDOWN_IMPORT_SOURCE_KIND, &source_key)?;
+    let existing_message_id = existing
+        .as_ref()
+        .and_then(|r| r.message_id.clone())
+        .filter(|id| message_still_exists(conn, key, id));
+
+    if let (Some(record), Some(_)) = (existing.as_ref(), existing_message_id.as_ref()) {
+        if record.content_sha256 == content_sha256 {
+            report.items_skipped_unchanged += 1;
+            return Ok(());
+        }
+    }
+
+    if existing_message_id.is_none() {
+        if let Some(duplicate) = db::find_import_record_by_content_sha256(
+            conn,
+            MARKDOWN_IMPORT_SOURCE_KIND,
+            &content_sha256,
+        )? {
+            if duplicate
+                .message_id
+                .as_deref()
+                .is_some_and(|id| message_still_exists(conn, key, id))
+            {
+                db::upsert_import_record(
+                    conn,
+                    MARKDOWN_IMPORT_SOURCE_KIND,
+                    &source_key,
+                    &content_sha256,
+                    duplicate.message_id.as_deref(),
+                    now_ms(),
+                )?;
+                report.items_skipped_duplicate += 1;
+                return Ok(());
+            }
+        }
+    }
+
+    let file_stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Untitled");
+    let note = parse_markdown_note(file_stem, &text, ctx.default_tz);
+    let content = note.message_content();
+
+    let message_id = match existing_message_id {
+        Some(id) => {
+            db::edit_message(conn, key, &id, &content)?;
+            report.items_updated += 1;
+            id
+        }
+        None => {
+            let created_at_ms = note
+                .created_at_ms
+                .or_else(|| file_modified_at_ms(path))
+                .unwrap_or_else(now_ms);
+            let message = db::insert_message_with_created_at(
+                conn,
+                key,
+                conversation_id,
+                "user",
+                &content,
+                created_at_ms,
+            )?;
+            report.items_imported += 1;
+            report.messages_created += 1;
+            message.id
+        }
+    };
+
+    db::upsert_import_record(
+        conn,
+        MARKDOWN_IMPORT_SOURCE_KIND,
+        &source_key,
+        &content_sha256,
+        Some(&message_id),
+        now_ms(),
+    )?;
+
+    apply_note_tags(conn, key, &message_id, &note.tags, report)?;
+    apply_note_todos(conn, key, &source_key, &message_id, &note.todos, report)?;
+    let note_dir = path.parent().unwrap_or(ctx.root);
+    apply_note_embeds(conn, key, ctx, note_dir, &message_id, &note.embeds, report)?;
+    Ok(())
+}
+
+/// Imports every Markdown note under `root_dir` (one conversation per folder).
+///
+/// Re-running the import is idempotent: unchanged notes are skipped by content
+/// hash, edited notes update their existing message, and todos/attachments are
+/// keyed deterministically so they are not duplicated. Front-matter dates
+/// without an offset are local times in `default_tz` (the importing device's
+/// zone; UTC when `None`).
+pub fn import_markdown_dir(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    root_dir: &Path,
+    lang: &str,
+    default_tz: Option<&str>,
+) -> Result<ImportReport> {
+    if let Some(tz) = default_tz {
+        db::known_timezone_name(tz).ok_or_else(|| anyhow!("unknown timezone: {tz}"))?;
+    }
+    let root = root_dir
+        .canonicalize()
+        .map_err(|e| anyhow!("import folder not found: {e}"))?;
+    if !root.is_dir() {
+        return Err(anyhow!("import path is not a folder"));
+    }
+    let root_name = root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Notes")
+        .to_string();
+
+    let mut notes = Vec::new();
+    let mut files_by_name = HashMap::new();
+    collect_files(&root, &mut notes, &mut files_by_name)?;
+
+    let ctx = NoteContext {
+        root: &root,
+        app_dir,
+        lang,
+        default_tz,
+        files_by_name: &files_by_name,
+    };
+
+    let mut report = ImportReport::default();
+    let mut conversations: HashMap<PathBuf, String> = HashMap::new();
+    for path in notes {
+        report.items_scanned += 1;
+        let folder = path.parent().unwrap_or(&root).to_path_buf();
+        let result = folder_conversation(conn, key, &root, &root_name, &folder, &mut conversations)
+            .and_then(|conversation_id| {
+                import_note(conn, key, &ctx, &conversation_id, &path, &mut report)
+            });
+        if let Err(e) = result {
+            report
+                .errors
+                .push(format!("{}: {e}", relative_display(&root, &path)));
+        }
+    }
+
+    Ok(report)
+}
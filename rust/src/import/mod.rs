@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+pub mod chat;
+pub mod ics;
+pub mod markdown;
+
+#[derive(Clone, Debug, Default)]
+pub struct ImportReport {
+    pub items_scanned: u32,
+    pub items_imported: u32,
+    pub items_updated: u32,
+    pub items_skipped_unchanged: u32,
+    pub items_skipped_duplicate: u32,
+    pub messages_created: u32,
+    pub todos_created: u32,
+    pub attachments_linked: u32,
+    pub tags_applied: u32,
+    pub errors: Vec<String>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let mut out = String::with_capacity(64);
+    for b in digest {
+        use std::fmt::Write;
+        let _ = write!(&mut out, "{:02x}", b);
+    }
+    out
+}
+
+fn mime_type_for_path(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    let mime = match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "heic" => "image/heic",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "ogg" | "opus" => "audio/ogg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// `path` resolved to a file inside `dir`, so references such as
+/// `../../.ssh/id_rsa`, absolute paths and symlinks in an export cannot
+/// pull in files from elsewhere on disk.
+fn file_inside_dir(dir: &Path, path: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    let path = path.canonicalize().ok()?;
+    (path.starts_with(&dir) && path.is_file()).then_some(path)
+}
+
+fn file_modified_at_ms(path: &Path) -> Option<i64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_millis() as i64)
+}
+
+fn now_ms() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        .try_into()
+        .unwrap_or(i64::MAX)
+}
@@ -5,10 +5,13 @@ pub mod crypto;
 pub mod db;
 pub mod desktop_media;
 pub mod embedding;
+pub mod export;
 mod frb_generated;
 pub mod geo;
+pub mod import;
 pub mod llm;
 pub mod media_annotation;
+pub mod prompt_templates;
 pub mod rag;
 pub mod semantic_parse;
 pub mod sync;
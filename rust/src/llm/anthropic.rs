@@ -5,8 +5,10 @@ use reqwest::blocking::Client;
 use reqwest::header;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::{json, Value};
 
-use super::ChatDelta;
+use super::tools::{ToolCall, ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn};
+use super::{ChatDelta, LlmUsage};
 
 #[derive(Debug, Serialize)]
 struct AnthropicMessagesRequest {
@@ -28,6 +30,26 @@ struct AnthropicMessagesResponse {
     role: Option<String>,
     #[serde(default)]
     content: Vec<AnthropicContentBlock>,
+    #[serde(default)]
+    usage: Option<Value>,
+}
+
+/// Input tokens of a `usage` object, cache reads and writes included.
+fn usage_input_tokens(usage: &Value) -> Option<i64> {
+    let input = usage.get("input_tokens")?.as_i64()?;
+    let cached = ["cache_creation_input_tokens", "cache_read_input_tokens"]
+        .iter()
+        .filter_map(|key| usage.get(*key).and_then(Value::as_i64))
+        .sum::<i64>();
+    Some(input + cached)
+}
+
+fn usage_output_tokens(usage: &Value) -> Option<i64> {
+    usage.get("output_tokens")?.as_i64()
+}
+
+fn llm_usage(input_tokens: Option<i64>, output_tokens: Option<i64>) -> Option<LlmUsage> {
+    Some(LlmUsage::new(input_tokens?, output_tokens?, None))
 }
 
 #[derive(Debug, Deserialize)]
@@ -117,6 +139,44 @@ impl crate::rag::AnswerProvider for AnthropicCompatibleProvider {
 
         Ok(())
     }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        super::budget::model_context_window_tokens(&self.model_name)
+    }
+}
+
+impl ToolCallingProvider for AnthropicCompatibleProvider {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        let url = messages_url(&self.base_url);
+        let req = build_messages_tools_request(
+            &self.model_name,
+            self.max_tokens,
+            messages,
+            tools,
+            choice,
+        );
+
+        let resp = self
+            .client
+            .post(url)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .json(&req)
+            .send()?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!("anthropic request failed: HTTP {status} {body}"));
+        }
+
+        parse_messages_tool_turn(resp)
+    }
 }
 
 pub fn parse_messages_sse(reader: impl Read) -> Result<Vec<ChatDelta>> {
@@ -145,6 +205,10 @@ pub fn read_messages_sse(
     let mut line = String::new();
     let mut role_emitted = false;
     let mut done_emitted = false;
+    // `message_start` reports the input tokens, `message_delta` the output
+    // tokens so far.
+    let mut input_tokens: Option<i64> = None;
+    let mut output_tokens: Option<i64> = None;
 
     loop {
         line.clear();
@@ -167,6 +231,7 @@ pub fn read_messages_sse(
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: llm_usage(input_tokens, output_tokens),
             })?;
             done_emitted = true;
             break;
@@ -177,6 +242,10 @@ pub fn read_messages_sse(
 
         match ty {
             "message_start" => {
+                if let Some(usage) = v.pointer("/message/usage") {
+                    input_tokens = usage_input_tokens(usage).or(input_tokens);
+                    output_tokens = usage_output_tokens(usage).or(output_tokens);
+                }
                 if role_emitted {
                     continue;
                 }
@@ -192,6 +261,7 @@ pub fn read_messages_sse(
                     role: Some(role.to_string()),
                     text_delta: String::new(),
                     done: false,
+                    usage: None,
                 })?;
                 role_emitted = true;
             }
@@ -216,13 +286,21 @@ pub fn read_messages_sse(
                     role: None,
                     text_delta: text.to_string(),
                     done: false,
+                    usage: None,
                 })?;
             }
+            "message_delta" => {
+                if let Some(usage) = v.get("usage") {
+                    input_tokens = usage_input_tokens(usage).or(input_tokens);
+                    output_tokens = usage_output_tokens(usage).or(output_tokens);
+                }
+            }
             "message_stop" => {
                 on_event(ChatDelta {
                     role: None,
                     text_delta: String::new(),
                     done: true,
+                    usage: llm_usage(input_tokens, output_tokens),
                 })?;
                 done_emitted = true;
                 break;
@@ -236,6 +314,7 @@ pub fn read_messages_sse(
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: llm_usage(input_tokens, output_tokens),
         })?;
     }
 
@@ -248,6 +327,10 @@ pub fn read_messages_json(
 ) -> Result<()> {
     let resp: AnthropicMessagesResponse = serde_json::from_reader(reader)?;
     let role = resp.role.or_else(|| Some("assistant".to_string()));
+    let usage = resp
+        .usage
+        .as_ref()
+        .and_then(|usage| llm_usage(usage_input_tokens(usage), usage_output_tokens(usage)));
 
     let mut text = String::new();
     for block in resp.content {
@@ -264,6 +347,7 @@ pub fn read_messages_json(
             role,
             text_delta: text,
             done: false,
+            usage: None,
         })?;
     }
 
@@ -271,7 +355,130 @@ pub fn read_messages_json(
         role: None,
         text_delta: String::new(),
         done: true,
+        usage,
     })?;
 
     Ok(())
 }
+
+/// Messages body for one non-streaming tool-calling turn. Consecutive entries
+/// with the same role are merged, since the API requires alternating roles.
+pub fn build_messages_tools_request(
+    model_name: &str,
+    max_tokens: u32,
+    messages: &[ToolMessage],
+    tools: &[ToolSpec],
+    choice: ToolChoice,
+) -> Value {
+    let mut out: Vec<Value> = Vec::new();
+    for message in messages {
+        let (role, blocks) = match message {
+            ToolMessage::User(text) => ("user", vec![json!({ "type": "text", "text": text })]),
+            ToolMessage::Assistant { text, tool_calls } => {
+                let mut blocks = Vec::new();
+                if !text.is_empty() {
+                    blocks.push(json!({ "type": "text", "text": text }));
+                }
+                for call in tool_calls {
+                    let input = if call.arguments.is_object() {
+                        call.arguments.clone()
+                    } else {
+                        json!({})
+                    };
+                    blocks.push(json!({
+                        "type": "tool_use",
+                        "id": call.id,
+                        "name": call.name,
+                        "input": input,
+                    }));
+                }
+                ("assistant", blocks)
+            }
+            ToolMessage::ToolResult {
+                call_id, content, ..
+            } => (
+                "user",
+                vec![json!({
+                    "type": "tool_result",
+                    "tool_use_id": call_id,
+                    "content": content,
+                })],
+            ),
+        };
+
+        match out.last_mut() {
+            Some(last) if last["role"] == role => {
+                if let Some(content) = last["content"].as_array_mut() {
+                    content.extend(blocks);
+                }
+            }
+            _ => out.push(json!({ "role": role, "content": blocks })),
+        }
+    }
+
+    let mut req = json!({
+        "model": model_name,
+        "max_tokens": max_tokens,
+        "messages": out,
+        "stream": false,
+    });
+    if !tools.is_empty() {
+        req["tools"] = tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "input_schema": tool.parameters,
+                })
+            })
+            .collect();
+        if choice == ToolChoice::None {
+            req["tool_choice"] = json!({ "type": "none" });
+        }
+    }
+    req
+}
+
+pub fn parse_messages_tool_turn(reader: impl Read) -> Result<ToolTurn> {
+    let root: Value = serde_json::from_reader(reader)?;
+    let blocks = root
+        .get("content")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("anthropic response has no content"))?;
+
+    let mut turn = ToolTurn {
+        usage: root
+            .get("usage")
+            .and_then(|usage| llm_usage(usage_input_tokens(usage), usage_output_tokens(usage))),
+        ..ToolTurn::default()
+    };
+    for block in blocks {
+        match block.get("type").and_then(Value::as_str) {
+            Some("text") => {
+                turn.text.push_str(
+                    block
+                        .get("text")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                );
+            }
+            Some("tool_use") => {
+                let Some(name) = block.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                turn.tool_calls.push(ToolCall {
+                    id: block
+                        .get("id")
+                        .and_then(Value::as_str)
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("call_{}", turn.tool_calls.len())),
+                    name: name.to_string(),
+                    arguments: block.get("input").cloned().unwrap_or_else(|| json!({})),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(turn)
+}
@@ -0,0 +1,219 @@
+/// Tokens kept free for the answer when sizing a prompt.
+const DEFAULT_RESERVED_OUTPUT_TOKENS: usize = 1024;
+/// Instructions, question and actions around contexts and history.
+const PROMPT_OVERHEAD_TOKENS: usize = 600;
+/// Caps that keep prompts (and cost) small on large context windows.
+/// 1500 tokens is about the 6000 characters contexts used to be limited to.
+const DEFAULT_MAX_CONTEXT_TOKENS: usize = 1500;
+const DEFAULT_MAX_HISTORY_TOKENS: usize = 1800;
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x11FF // Hangul Jamo
+            | 0x3040..=0x30FF // Hiragana, Katakana
+            | 0x3400..=0x4DBF // CJK Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xAC00..=0xD7AF // Hangul Syllables
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+            | 0xFF00..=0xFFEF // Half/full-width forms
+    )
+}
+
+/// Estimated token count for `text` under the BPE tokenizers used by the
+/// supported providers: about 4 ASCII characters per token, one token per
+/// CJK character and two other characters per token. Errs on the high side
+/// for non-English text. Only used for models whose tokenizer we don't ship.
+pub fn estimate_tokens(text: &str) -> usize {
+    let (mut ascii, mut cjk, mut other) = (0usize, 0usize, 0usize);
+    for c in text.chars() {
+        if c.is_ascii() {
+            ascii += 1;
+        } else if is_cjk(c) {
+            cjk += 1;
+        } else {
+            other += 1;
+        }
+    }
+    ascii.div_ceil(4) + cjk + other.div_ceil(2)
+}
+
+/// Longest prefix of `text` (on a char boundary) estimated to fit in
+/// `max_tokens`.
+pub fn truncate_to_tokens(text: &str, max_tokens: usize) -> &str {
+    if estimate_tokens(text) <= max_tokens {
+        return text;
+    }
+    let (mut ascii, mut cjk, mut other) = (0usize, 0usize, 0usize);
+    for (i, c) in text.char_indices() {
+        if c.is_ascii() {
+            ascii += 1;
+        } else if is_cjk(c) {
+            cjk += 1;
+        } else {
+            other += 1;
+        }
+        if ascii.div_ceil(4) + cjk + other.div_ceil(2) > max_tokens {
+            return &text[..i];
+        }
+    }
+    text
+}
+
+/// Counts tokens the way the model behind a provider does: with its BPE
+/// tokenizer when we ship it, with `estimate_tokens` otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TokenCounter {
+    /// `o200k_base`: GPT-4o, GPT-4.1, GPT-5 and the o-series.
+    O200kBase,
+    /// `cl100k_base`: GPT-4 and GPT-3.5.
+    Cl100kBase,
+    #[default]
+    Estimate,
+}
+
+impl TokenCounter {
+    /// The tokenizer of `model_name`, matched on the model family.
+    pub fn for_model(model_name: &str) -> Self {
+        let model = model_base_name(model_name);
+        let model = model.strip_prefix("ft:").unwrap_or(&model);
+        let families: [(&str, TokenCounter); 11] = [
+            ("gpt-4o", Self::O200kBase),
+            ("chatgpt-4o", Self::O200kBase),
+            ("gpt-4.1", Self::O200kBase),
+            ("gpt-4.5", Self::O200kBase),
+            ("gpt-5", Self::O200kBase),
+            ("o1", Self::O200kBase),
+            ("o3", Self::O200kBase),
+            ("o4", Self::O200kBase),
+            ("gpt-4", Self::Cl100kBase),
+            ("gpt-3.5", Self::Cl100kBase),
+            ("gpt-35", Self::Cl100kBase),
+        ];
+        families
+            .iter()
+            .find(|(prefix, _)| model.starts_with(prefix))
+            .map(|(_, counter)| *counter)
+            .unwrap_or_default()
+    }
+
+    fn bpe(self) -> Option<&'static tiktoken_rs::CoreBPE> {
+        match self {
+            Self::O200kBase => Some(tiktoken_rs::o200k_base_singleton()),
+            Self::Cl100kBase => Some(tiktoken_rs::cl100k_base_singleton()),
+            Self::Estimate => None,
+        }
+    }
+
+    /// Token count of `text`.
+    pub fn count(self, text: &str) -> usize {
+        match self.bpe() {
+            Some(bpe) => bpe.encode_ordinary(text).len(),
+            None => estimate_tokens(text),
+        }
+    }
+
+    /// Longest prefix of `text` (on a char boundary) that fits in
+    /// `max_tokens`.
+    pub fn truncate(self, text: &str, max_tokens: usize) -> &str {
+        let Some(bpe) = self.bpe() else {
+            return truncate_to_tokens(text, max_tokens);
+        };
+        let mut tokens = bpe.encode_ordinary(text);
+        if tokens.len() <= max_tokens {
+            return text;
+        }
+        tokens.truncate(max_tokens);
+        // A cut inside a multi-byte character does not decode; drop tokens
+        // until the prefix does. Ordinary encoding is lossless, so the
+        // decoded prefix is a prefix of `text`.
+        while !tokens.is_empty() {
+            if let Ok(prefix) = bpe.decode(tokens.clone()) {
+                return &text[..prefix.len()];
+            }
+            tokens.pop();
+        }
+        ""
+    }
+}
+
+/// Lowercased model name without its provider prefix (`openai/gpt-4o`).
+pub(crate) fn model_base_name(model_name: &str) -> String {
+    let model = model_name.trim().to_ascii_lowercase();
+    model.rsplit('/').next().unwrap_or_default().to_string()
+}
+
+/// Context window of well-known models, matched on the model name. `None`
+/// for models we know nothing about.
+pub fn model_context_window_tokens(model_name: &str) -> Option<usize> {
+    let model = model_base_name(model_name);
+    let model = model.strip_prefix("ft:").unwrap_or(&model);
+    // The first matching prefix wins, so every newer `gpt-4*` family has to
+    // be listed before the original 8k `gpt-4`.
+    let windows: [(&str, usize); 24] = [
+        ("gpt-4.1", 1_047_576),
+        ("gpt-4.5", 128_000),
+        ("gpt-4o", 128_000),
+        ("chatgpt-4o", 128_000),
+        ("gpt-4-turbo", 128_000),
+        ("gpt-4-1106", 128_000),
+        ("gpt-4-0125", 128_000),
+        ("gpt-4-vision", 128_000),
+        ("gpt-4-32k", 32_768),
+        ("gpt-4", 8_192),
+        ("gpt-3.5-turbo-instruct", 4_096),
+        ("gpt-3.5-turbo", 16_385),
+        ("gpt-35-turbo", 16_385),
+        ("gpt-5", 400_000),
+        ("o1-mini", 128_000),
+        ("o1-preview", 128_000),
+        ("o1", 200_000),
+        ("o3", 200_000),
+        ("o4-mini", 200_000),
+        ("claude", 200_000),
+        ("gemini-1.5", 1_048_576),
+        ("gemini-2", 1_048_576),
+        ("gemini", 32_768),
+        ("deepseek", 64_000),
+    ];
+    windows
+        .iter()
+        .find(|(prefix, _)| model.starts_with(prefix))
+        .map(|(_, window)| *window)
+}
+
+/// Token budgets for the retrieved contexts and conversation history of one
+/// prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContextBudget {
+    pub context_tokens: usize,
+    pub history_tokens: usize,
+}
+
+impl Default for ContextBudget {
+    fn default() -> Self {
+        Self {
+            context_tokens: DEFAULT_MAX_CONTEXT_TOKENS,
+            history_tokens: DEFAULT_MAX_HISTORY_TOKENS,
+        }
+    }
+}
+
+impl ContextBudget {
+    /// Budgets that fit a model with `context_window` tokens (the defaults
+    /// when unknown). Contexts get 60% of what is left after the answer and
+    /// prompt overhead, history the rest.
+    pub fn for_context_window(context_window: Option<usize>) -> Self {
+        let Some(window) = context_window else {
+            return Self::default();
+        };
+        let reserved_output = DEFAULT_RESERVED_OUTPUT_TOKENS.min(window / 4);
+        let available = window.saturating_sub(reserved_output + PROMPT_OVERHEAD_TOKENS);
+        let context_tokens = (available * 3 / 5).min(DEFAULT_MAX_CONTEXT_TOKENS);
+        let history_tokens = (available - context_tokens).min(DEFAULT_MAX_HISTORY_TOKENS);
+        Self {
+            context_tokens,
+            history_tokens,
+        }
+    }
+}
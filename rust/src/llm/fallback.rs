@@ -0,0 +1,522 @@
+use std::cell::Cell;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use rand::Rng;
+use rusqlite::Connection;
+
+use crate::llm::budget::TokenCounter;
+use crate::llm::tools::{ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn};
+use crate::llm::{ChatDelta, LlmUsage};
+
+/// What to do after a profile's request failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LlmErrorKind {
+    /// Transient (rate limit, 5xx, timeout): retry, then fall back.
+    Retryable,
+    /// The profile can't serve this request (auth, quota, missing model):
+    /// fall back without retrying.
+    Fallback,
+    /// No profile would do better (bad request, cancelled stream).
+    Fatal,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts per profile, including the first.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff before retry `retry` (1-based), jittered to
+    /// between half and all of the nominal delay.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32 << retry.saturating_sub(1).min(16);
+        let nominal = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if nominal.is_zero() {
+            return nominal;
+        }
+        let half = nominal / 2;
+        half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+    }
+}
+
+fn http_status(message: &str) -> Option<u16> {
+    let rest = &message[message.find("HTTP ")? + "HTTP ".len()..];
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Classifies an error from a provider of `provider_type`. Providers report
+/// HTTP failures as `... request failed: HTTP {status} {body}`.
+pub fn classify_llm_error(provider_type: &str, err: &anyhow::Error) -> LlmErrorKind {
+    if err.is::<crate::rag::StreamCancelled>() {
+        return LlmErrorKind::Fatal;
+    }
+    if provider_type == super::local_gguf::LOCAL_GGUF_PROVIDER_TYPE {
+        return LlmErrorKind::Fallback;
+    }
+    if let Some(e) = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<reqwest::Error>())
+    {
+        if e.is_timeout() || e.is_connect() {
+            return LlmErrorKind::Retryable;
+        }
+    }
+
+    let message = format!("{err:#}");
+    let Some(status) = http_status(&message) else {
+        return LlmErrorKind::Fallback;
+    };
+    match (provider_type, status) {
+        ("openai-compatible", 429) if message.contains("insufficient_quota") => {
+            LlmErrorKind::Fallback
+        }
+        ("gemini-compatible", 429) if message.contains("PerDay") => LlmErrorKind::Fallback,
+        ("anthropic-compatible", 529) => LlmErrorKind::Retryable,
+        (_, 408 | 409 | 425 | 429) => LlmErrorKind::Retryable,
+        (_, 500..=599) => LlmErrorKind::Retryable,
+        (_, 400 | 422) => LlmErrorKind::Fatal,
+        _ => LlmErrorKind::Fallback,
+    }
+}
+
+/// Runs `attempt` on each candidate in order, retrying retryable errors
+/// with backoff, until one succeeds or an error is fatal. Returns the index
+/// of the candidate that produced the result.
+fn run_chain<T>(
+    candidate_count: usize,
+    policy: &RetryPolicy,
+    mut classify: impl FnMut(usize, &anyhow::Error) -> LlmErrorKind,
+    mut attempt: impl FnMut(usize) -> Result<T>,
+) -> (usize, Result<T>) {
+    let mut last = (0, Err(anyhow!("no llm profiles to try")));
+    for index in 0..candidate_count {
+        let mut tries = 0u32;
+        loop {
+            tries += 1;
+            let err = match attempt(index) {
+                Ok(v) => return (index, Ok(v)),
+                Err(e) => e,
+            };
+            match classify(index, &err) {
+                LlmErrorKind::Fatal => return (index, Err(err)),
+                LlmErrorKind::Retryable if tries < policy.max_attempts.max(1) => {
+                    std::thread::sleep(policy.backoff(tries));
+                }
+                LlmErrorKind::Retryable | LlmErrorKind::Fallback => {
+                    last = (index, Err(err));
+                    break;
+                }
+            }
+        }
+    }
+    last
+}
+
+/// Runs `attempt` for each `(profile_id, config)` in order. Returns the id
+/// of the profile whose outcome is returned.
+pub fn run_with_fallback<T>(
+    profiles: &[(String, crate::db::LlmProfileConfig)],
+    policy: &RetryPolicy,
+    mut attempt: impl FnMut(&str, &crate::db::LlmProfileConfig) -> Result<T>,
+) -> (Option<String>, Result<T>) {
+    let (index, result) = run_chain(
+        profiles.len(),
+        policy,
+        |i, e| classify_llm_error(&profiles[i].1.provider_type, e),
+        |i| attempt(&profiles[i].0, &profiles[i].1),
+    );
+    (profiles.get(index).map(|(id, _)| id.clone()), result)
+}
+
+fn active_profile_chain(
+    conn: &Connection,
+    key: &[u8; 32],
+    local_day: &str,
+) -> Result<Vec<(String, crate::db::LlmProfileConfig)>> {
+    let profiles = crate::db::load_llm_profile_chain_configs(conn, key, None, local_day)?;
+    if profiles.is_empty() {
+        return Err(anyhow!("no active LLM profile configured"));
+    }
+    Ok(profiles)
+}
+
+/// The active profile followed by its fallback chain, without the profiles
+/// over their spending limit for the month of `local_day`.
+pub fn answer_provider_for_active_profile(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    local_day: &str,
+) -> Result<FallbackAnswerProvider> {
+    let profiles = active_profile_chain(conn, key, local_day)?;
+    Ok(FallbackAnswerProvider::from_profiles(
+        app_dir,
+        &profiles,
+        RetryPolicy::default(),
+    ))
+}
+
+/// Tool-calling counterpart of `answer_provider_for_active_profile`.
+pub fn tool_provider_for_active_profile(
+    conn: &Connection,
+    key: &[u8; 32],
+    local_day: &str,
+) -> Result<FallbackToolProvider> {
+    let profiles = active_profile_chain(conn, key, local_day)?;
+    Ok(FallbackToolProvider::from_profiles(
+        &profiles,
+        RetryPolicy::default(),
+    ))
+}
+
+struct Candidate<P: ?Sized> {
+    profile_id: String,
+    provider_type: String,
+    /// Build errors are kept so a broken profile falls through to the next.
+    provider: std::result::Result<Box<P>, String>,
+}
+
+fn usage_profile_id<P: ?Sized>(
+    last_profile_id: &Mutex<Option<String>>,
+    candidates: &[Candidate<P>],
+) -> String {
+    last_profile_id
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .or_else(|| candidates.first().map(|c| c.profile_id.clone()))
+        .unwrap_or_default()
+}
+
+fn shared_token_counter(mut counters: impl Iterator<Item = TokenCounter>) -> TokenCounter {
+    let Some(first) = counters.next() else {
+        return TokenCounter::Estimate;
+    };
+    if counters.all(|counter| counter == first) {
+        first
+    } else {
+        TokenCounter::Estimate
+    }
+}
+
+fn add_usage(total: &Mutex<Option<LlmUsage>>, usage: Option<LlmUsage>) {
+    let Some(usage) = usage else {
+        return;
+    };
+    total
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get_or_insert_with(LlmUsage::default)
+        .add(usage);
+}
+
+fn take_usage(total: &Mutex<Option<LlmUsage>>) -> Option<LlmUsage> {
+    total
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take()
+}
+
+/// Records one request in `llm_usage_daily` for `day`, with the tokens
+/// reported since the previous record. Nothing is recorded without a day.
+fn record_usage(
+    conn: &Connection,
+    day: &str,
+    profile_id: &str,
+    purpose: &str,
+    usage: Option<LlmUsage>,
+) -> Result<()> {
+    let day = day.trim();
+    if day.is_empty() {
+        return Ok(());
+    }
+    crate::db::record_llm_usage_daily(
+        conn,
+        day,
+        profile_id,
+        purpose,
+        usage.map(|u| u.input_tokens),
+        usage.map(|u| u.output_tokens),
+        usage.map(|u| u.total_tokens),
+    )
+}
+
+impl<P: ?Sized> Candidate<P> {
+    fn get(&self) -> Result<&P> {
+        match &self.provider {
+            Ok(p) => Ok(p.as_ref()),
+            Err(e) => Err(anyhow!("{e}")),
+        }
+    }
+}
+
+/// Answers with the first profile of a fallback chain that succeeds. Once
+/// a profile has streamed any delta, its errors are returned as is.
+pub struct FallbackAnswerProvider {
+    candidates: Vec<Candidate<dyn crate::rag::AnswerProvider>>,
+    policy: RetryPolicy,
+    last_profile_id: Mutex<Option<String>>,
+    usage: Mutex<Option<LlmUsage>>,
+}
+
+impl FallbackAnswerProvider {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self {
+            candidates: Vec::new(),
+            policy,
+            last_profile_id: Mutex::new(None),
+            usage: Mutex::new(None),
+        }
+    }
+
+    /// One candidate per profile, in order.
+    pub fn from_profiles(
+        app_dir: &Path,
+        profiles: &[(String, crate::db::LlmProfileConfig)],
+        policy: RetryPolicy,
+    ) -> Self {
+        let mut out = Self::new(policy);
+        for (profile_id, profile) in profiles {
+            out.candidates.push(Candidate {
+                profile_id: profile_id.clone(),
+                provider_type: profile.provider_type.clone(),
+                provider: super::answer_provider_from_profile_in_app_dir(app_dir, profile)
+                    .map_err(|e| format!("{e:#}")),
+            });
+        }
+        out
+    }
+
+    pub fn push(
+        &mut self,
+        profile_id: &str,
+        provider_type: &str,
+        provider: Box<dyn crate::rag::AnswerProvider>,
+    ) {
+        self.candidates.push(Candidate {
+            profile_id: profile_id.to_string(),
+            provider_type: provider_type.to_string(),
+            provider: Ok(provider),
+        });
+    }
+
+    /// The profile that produced the outcome of the latest call (the one
+    /// that answered, or the last one that failed), for usage accounting.
+    /// Before any call, the first profile of the chain.
+    pub fn usage_profile_id(&self) -> String {
+        usage_profile_id(&self.last_profile_id, &self.candidates)
+    }
+
+    /// Tokens the providers reported since the last call to `take_usage`
+    /// or `record_usage`.
+    pub fn take_usage(&self) -> Option<LlmUsage> {
+        take_usage(&self.usage)
+    }
+
+    /// Records the calls since the last record as one `purpose` request of
+    /// `usage_profile_id`, priced by the tokens they reported.
+    pub fn record_usage(&self, conn: &Connection, day: &str, purpose: &str) -> Result<()> {
+        record_usage(
+            conn,
+            day,
+            &self.usage_profile_id(),
+            purpose,
+            self.take_usage(),
+        )
+    }
+}
+
+impl FallbackAnswerProvider {
+    fn stream_with_fallback(
+        &self,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+        stream: impl Fn(
+            &dyn crate::rag::AnswerProvider,
+            &mut dyn FnMut(ChatDelta) -> Result<()>,
+        ) -> Result<()>,
+    ) -> Result<()> {
+        let streamed = Cell::new(false);
+        let (index, result) = run_chain(
+            self.candidates.len(),
+            &self.policy,
+            |i, e| {
+                if streamed.get() {
+                    LlmErrorKind::Fatal
+                } else {
+                    classify_llm_error(&self.candidates[i].provider_type, e)
+                }
+            },
+            |i| {
+                stream(self.candidates[i].get()?, &mut |ev| {
+                    streamed.set(true);
+                    add_usage(&self.usage, ev.usage);
+                    on_event(ev)
+                })
+            },
+        );
+        if let Some(c) = self.candidates.get(index) {
+            *self
+                .last_profile_id
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(c.profile_id.clone());
+        }
+        result
+    }
+}
+
+impl crate::rag::AnswerProvider for FallbackAnswerProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.stream_with_fallback(on_event, |provider, on_event| {
+            provider.stream_answer(prompt, on_event)
+        })
+    }
+
+    fn stream_answer_json(
+        &self,
+        prompt: &str,
+        schema: &serde_json::Value,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.stream_with_fallback(on_event, |provider, on_event| {
+            provider.stream_answer_json(prompt, schema, on_event)
+        })
+    }
+
+    /// The smallest known window in the chain, since any profile may answer.
+    fn context_window_tokens(&self) -> Option<usize> {
+        self.candidates
+            .iter()
+            .filter_map(|c| c.provider.as_ref().ok()?.context_window_tokens())
+            .min()
+    }
+
+    /// The chain's tokenizer when all its profiles share one, since any
+    /// profile may answer; the estimate otherwise.
+    fn token_counter(&self) -> TokenCounter {
+        shared_token_counter(
+            self.candidates
+                .iter()
+                .filter_map(|c| Some(c.provider.as_ref().ok()?.token_counter())),
+        )
+    }
+}
+
+/// Tool-calling counterpart of `FallbackAnswerProvider`. Each turn may be
+/// answered by a different profile.
+pub struct FallbackToolProvider {
+    candidates: Vec<Candidate<dyn ToolCallingProvider>>,
+    policy: RetryPolicy,
+    last_profile_id: Mutex<Option<String>>,
+    usage: Mutex<Option<LlmUsage>>,
+}
+
+impl FallbackToolProvider {
+    pub fn from_profiles(
+        profiles: &[(String, crate::db::LlmProfileConfig)],
+        policy: RetryPolicy,
+    ) -> Self {
+        let candidates = profiles
+            .iter()
+            .map(|(profile_id, profile)| Candidate {
+                profile_id: profile_id.clone(),
+                provider_type: profile.provider_type.clone(),
+                provider: super::tool_provider_from_profile(profile).map_err(|e| format!("{e:#}")),
+            })
+            .collect();
+        Self {
+            candidates,
+            policy,
+            last_profile_id: Mutex::new(None),
+            usage: Mutex::new(None),
+        }
+    }
+
+    /// See `FallbackAnswerProvider::usage_profile_id`.
+    pub fn usage_profile_id(&self) -> String {
+        usage_profile_id(&self.last_profile_id, &self.candidates)
+    }
+
+    /// See `FallbackAnswerProvider::take_usage`.
+    pub fn take_usage(&self) -> Option<LlmUsage> {
+        take_usage(&self.usage)
+    }
+
+    /// See `FallbackAnswerProvider::record_usage`.
+    pub fn record_usage(&self, conn: &Connection, day: &str, purpose: &str) -> Result<()> {
+        record_usage(
+            conn,
+            day,
+            &self.usage_profile_id(),
+            purpose,
+            self.take_usage(),
+        )
+    }
+}
+
+impl ToolCallingProvider for FallbackToolProvider {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        let (index, result) = run_chain(
+            self.candidates.len(),
+            &self.policy,
+            |i, e| classify_llm_error(&self.candidates[i].provider_type, e),
+            |i| {
+                let turn = self.candidates[i]
+                    .get()?
+                    .complete_with_tools(messages, tools, choice)?;
+                add_usage(&self.usage, turn.usage);
+                Ok(turn)
+            },
+        );
+        if let Some(c) = self.candidates.get(index) {
+            *self
+                .last_profile_id
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(c.profile_id.clone());
+        }
+        result
+    }
+
+    /// The smallest known window in the chain, since any profile may answer.
+    fn context_window_tokens(&self) -> Option<usize> {
+        self.candidates
+            .iter()
+            .filter_map(|c| c.provider.as_ref().ok()?.context_window_tokens())
+            .min()
+    }
+
+    /// The chain's tokenizer when all its profiles share one, since any
+    /// profile may answer; the estimate otherwise.
+    fn token_counter(&self) -> TokenCounter {
+        shared_token_counter(
+            self.candidates
+                .iter()
+                .filter_map(|c| Some(c.provider.as_ref().ok()?.token_counter())),
+        )
+    }
+}
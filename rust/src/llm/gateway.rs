@@ -4,6 +4,9 @@ use reqwest::header;
 use serde::Serialize;
 use uuid::Uuid;
 
+use super::tools::{
+    tool_messages_chars, ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn,
+};
 use super::ChatDelta;
 
 const REQUEST_ID_ROLE_PREFIX: &str = "secondloop_request_id:";
@@ -102,6 +105,7 @@ impl crate::rag::AnswerProvider for CloudGatewayProvider {
             role: Some(format!("{REQUEST_ID_ROLE_PREFIX}{request_id}")),
             text_delta: String::new(),
             done: false,
+            usage: None,
         })?;
 
         let mut resp = self
@@ -139,4 +143,60 @@ impl crate::rag::AnswerProvider for CloudGatewayProvider {
 
         Ok(())
     }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        super::budget::model_context_window_tokens(&self.model_name)
+    }
+
+    fn token_counter(&self) -> super::budget::TokenCounter {
+        super::budget::TokenCounter::for_model(&self.model_name)
+    }
+}
+
+impl ToolCallingProvider for CloudGatewayProvider {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        let url = gateway_chat_completions_url(&self.gateway_base_url);
+        let req = super::openai::build_chat_completions_tools_request(
+            &self.model_name,
+            self.temperature,
+            messages,
+            tools,
+            choice,
+        );
+        let request_timeout =
+            crate::llm::timeouts::ask_ai_timeout_for_prompt_chars(tool_messages_chars(messages));
+
+        let resp = self
+            .client
+            .post(url)
+            .bearer_auth(&self.id_token)
+            .header("x-secondloop-purpose", &self.purpose_header)
+            .header("x-secondloop-request-id", build_request_id())
+            .json(&req)
+            .timeout(request_timeout)
+            .send()?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!(
+                "cloud-gateway request failed: HTTP {status} {body}"
+            ));
+        }
+
+        super::openai::parse_chat_completions_tool_turn(resp)
+    }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        super::budget::model_context_window_tokens(&self.model_name)
+    }
+
+    fn token_counter(&self) -> super::budget::TokenCounter {
+        super::budget::TokenCounter::for_model(&self.model_name)
+    }
 }
@@ -5,8 +5,10 @@ use reqwest::blocking::Client;
 use reqwest::header;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::{json, Value};
 
-use super::ChatDelta;
+use super::tools::{ToolCall, ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn};
+use super::{ChatDelta, LlmUsage};
 
 fn normalize_role(role: Option<&str>) -> Option<String> {
     let role = role?.trim();
@@ -23,6 +25,32 @@ fn normalize_role(role: Option<&str>) -> Option<String> {
 struct GeminiGenerateContentResponse {
     #[serde(default)]
     candidates: Vec<GeminiCandidate>,
+    #[serde(default, rename = "usageMetadata")]
+    usage_metadata: Option<GeminiUsageMetadata>,
+}
+
+/// Streamed chunks each carry the usage so far; the last one is final.
+#[derive(Debug, Deserialize)]
+struct GeminiUsageMetadata {
+    #[serde(default, rename = "promptTokenCount")]
+    prompt_token_count: Option<i64>,
+    #[serde(default, rename = "candidatesTokenCount")]
+    candidates_token_count: Option<i64>,
+    /// Billed as output.
+    #[serde(default, rename = "thoughtsTokenCount")]
+    thoughts_token_count: Option<i64>,
+    #[serde(default, rename = "totalTokenCount")]
+    total_token_count: Option<i64>,
+}
+
+impl GeminiUsageMetadata {
+    fn to_llm_usage(&self) -> Option<LlmUsage> {
+        Some(LlmUsage::new(
+            self.prompt_token_count?,
+            self.candidates_token_count.unwrap_or(0) + self.thoughts_token_count.unwrap_or(0),
+            self.total_token_count,
+        ))
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,6 +78,8 @@ struct GeminiPart {
 #[derive(Debug, Serialize)]
 struct GeminiGenerateContentRequest {
     contents: Vec<GeminiRequestContent>,
+    #[serde(rename = "generationConfig", skip_serializing_if = "Option::is_none")]
+    generation_config: Option<Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -81,7 +111,10 @@ pub fn stream_generate_content_url(base_url: &str, model_name: &str, api_key: &s
     )
 }
 
-fn build_generate_content_request(prompt: &str) -> GeminiGenerateContentRequest {
+fn build_generate_content_request(
+    prompt: &str,
+    generation_config: Option<Value>,
+) -> GeminiGenerateContentRequest {
     GeminiGenerateContentRequest {
         contents: vec![GeminiRequestContent {
             role: "user".to_string(),
@@ -89,9 +122,49 @@ fn build_generate_content_request(prompt: &str) -> GeminiGenerateContentRequest
                 text: prompt.to_string(),
             }],
         }],
+        generation_config,
     }
 }
 
+/// Converts a JSON schema (the subset `llm::json_schema` validates) into a
+/// Gemini `responseSchema`: upper-case types, `nullable` instead of a
+/// `null` type, and only the keywords Gemini accepts. Conditions such as
+/// `if`/`then` and `pattern` are dropped; the caller validates them.
+pub fn gemini_response_schema(schema: &Value) -> Value {
+    let mut out = serde_json::Map::new();
+    let types: Vec<&str> = match &schema["type"] {
+        Value::String(name) => vec![name.as_str()],
+        Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if types.contains(&"null") {
+        out.insert("nullable".to_string(), json!(true));
+    }
+    if let Some(name) = types.iter().find(|name| **name != "null") {
+        out.insert("type".to_string(), json!(name.to_ascii_uppercase()));
+    }
+    if let Some(values) = schema["enum"].as_array() {
+        let values: Vec<&Value> = values.iter().filter(|v| v.is_string()).collect();
+        if !values.is_empty() {
+            out.insert("enum".to_string(), json!(values));
+        }
+    }
+    if let Some(properties) = schema["properties"].as_object() {
+        let properties: serde_json::Map<String, Value> = properties
+            .iter()
+            .map(|(name, property)| (name.clone(), gemini_response_schema(property)))
+            .collect();
+        out.insert("properties".to_string(), Value::Object(properties));
+    }
+    if let Some(required) = schema.get("required") {
+        out.insert("required".to_string(), required.clone());
+    }
+    if let Some(items) = schema.get("items") {
+        out.insert("items".to_string(), gemini_response_schema(items));
+    }
+    Value::Object(out)
+}
+
 pub struct GeminiCompatibleProvider {
     client: Client,
     base_url: String,
@@ -110,14 +183,15 @@ impl GeminiCompatibleProvider {
     }
 }
 
-impl crate::rag::AnswerProvider for GeminiCompatibleProvider {
-    fn stream_answer(
+impl GeminiCompatibleProvider {
+    fn stream_generate(
         &self,
         prompt: &str,
+        generation_config: Option<Value>,
         on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
     ) -> Result<()> {
         let url = stream_generate_content_url(&self.base_url, &self.model_name, &self.api_key);
-        let req = build_generate_content_request(prompt);
+        let req = build_generate_content_request(prompt, generation_config);
 
         let mut resp = self
             .client
@@ -148,6 +222,55 @@ impl crate::rag::AnswerProvider for GeminiCompatibleProvider {
     }
 }
 
+impl crate::rag::AnswerProvider for GeminiCompatibleProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.stream_generate(prompt, None, on_event)
+    }
+
+    fn stream_answer_json(
+        &self,
+        prompt: &str,
+        schema: &Value,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        let generation_config = json!({
+            "responseMimeType": "application/json",
+            "responseSchema": gemini_response_schema(schema),
+        });
+        self.stream_generate(prompt, Some(generation_config), on_event)
+    }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        super::budget::model_context_window_tokens(&self.model_name)
+    }
+}
+
+impl ToolCallingProvider for GeminiCompatibleProvider {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        let url = generate_content_url(&self.base_url, &self.model_name, &self.api_key);
+        let req = build_generate_content_tools_request(messages, tools, choice);
+
+        let resp = self.client.post(url).json(&req).send()?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!("gemini request failed: HTTP {status} {body}"));
+        }
+
+        parse_generate_content_tool_turn(resp)
+    }
+}
+
 pub fn parse_generate_content_sse(reader: impl Read) -> Result<Vec<ChatDelta>> {
     let mut out = Vec::new();
     read_generate_content_sse(reader, |ev| {
@@ -174,6 +297,7 @@ pub fn read_generate_content_sse(
     let mut line = String::new();
     let mut role_emitted = false;
     let mut done_emitted = false;
+    let mut usage: Option<LlmUsage> = None;
 
     loop {
         line.clear();
@@ -196,12 +320,20 @@ pub fn read_generate_content_sse(
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage,
             })?;
             done_emitted = true;
             break;
         }
 
         let chunk: GeminiGenerateContentResponse = serde_json::from_str(data)?;
+        if let Some(chunk_usage) = chunk
+            .usage_metadata
+            .as_ref()
+            .and_then(GeminiUsageMetadata::to_llm_usage)
+        {
+            usage = Some(chunk_usage);
+        }
         let candidate = match chunk.candidates.first() {
             Some(v) => v,
             None => continue,
@@ -217,6 +349,7 @@ pub fn read_generate_content_sse(
                     role: Some(role),
                     text_delta: String::new(),
                     done: false,
+                    usage: None,
                 })?;
                 role_emitted = true;
             }
@@ -232,6 +365,7 @@ pub fn read_generate_content_sse(
                     role: None,
                     text_delta: text,
                     done: false,
+                    usage: None,
                 })?;
             }
         }
@@ -241,6 +375,7 @@ pub fn read_generate_content_sse(
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage,
             })?;
             done_emitted = true;
             break;
@@ -252,6 +387,7 @@ pub fn read_generate_content_sse(
             role: None,
             text_delta: String::new(),
             done: true,
+            usage,
         })?;
     }
 
@@ -263,6 +399,10 @@ pub fn read_generate_content_json(
     mut on_event: impl FnMut(ChatDelta) -> Result<()>,
 ) -> Result<()> {
     let resp: GeminiGenerateContentResponse = serde_json::from_reader(reader)?;
+    let usage = resp
+        .usage_metadata
+        .as_ref()
+        .and_then(GeminiUsageMetadata::to_llm_usage);
     let candidate = resp
         .candidates
         .into_iter()
@@ -288,6 +428,7 @@ pub fn read_generate_content_json(
             role,
             text_delta: text,
             done: false,
+            usage: None,
         })?;
     }
 
@@ -295,7 +436,110 @@ pub fn read_generate_content_json(
         role: None,
         text_delta: String::new(),
         done: true,
+        usage,
     })?;
 
     Ok(())
 }
+
+/// generateContent body for one tool-calling turn. Gemini has no call ids;
+/// results are matched to calls by function name.
+pub fn build_generate_content_tools_request(
+    messages: &[ToolMessage],
+    tools: &[ToolSpec],
+    choice: ToolChoice,
+) -> Value {
+    let mut contents: Vec<Value> = Vec::new();
+    for message in messages {
+        let (role, parts) = match message {
+            ToolMessage::User(text) => ("user", vec![json!({ "text": text })]),
+            ToolMessage::Assistant { text, tool_calls } => {
+                let mut parts = Vec::new();
+                if !text.is_empty() {
+                    parts.push(json!({ "text": text }));
+                }
+                for call in tool_calls {
+                    parts.push(json!({
+                        "functionCall": { "name": call.name, "args": call.arguments },
+                    }));
+                }
+                ("model", parts)
+            }
+            ToolMessage::ToolResult { name, content, .. } => {
+                let result = serde_json::from_str::<Value>(content)
+                    .unwrap_or_else(|_| Value::String(content.clone()));
+                (
+                    "user",
+                    vec![json!({
+                        "functionResponse": {
+                            "name": name,
+                            "response": { "result": result },
+                        },
+                    })],
+                )
+            }
+        };
+
+        match contents.last_mut() {
+            Some(last) if last["role"] == role => {
+                if let Some(existing) = last["parts"].as_array_mut() {
+                    existing.extend(parts);
+                }
+            }
+            _ => contents.push(json!({ "role": role, "parts": parts })),
+        }
+    }
+
+    let mut req = json!({ "contents": contents });
+    if !tools.is_empty() {
+        let declarations = tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "parameters": tool.parameters,
+                })
+            })
+            .collect::<Vec<_>>();
+        req["tools"] = json!([{ "functionDeclarations": declarations }]);
+        if choice == ToolChoice::None {
+            req["toolConfig"] = json!({ "functionCallingConfig": { "mode": "NONE" } });
+        }
+    }
+    req
+}
+
+pub fn parse_generate_content_tool_turn(reader: impl Read) -> Result<ToolTurn> {
+    let root: Value = serde_json::from_reader(reader)?;
+    let parts = root
+        .pointer("/candidates/0/content/parts")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("gemini response has no candidates"))?;
+
+    let mut turn = ToolTurn {
+        usage: root
+            .get("usageMetadata")
+            .cloned()
+            .and_then(|v| serde_json::from_value::<GeminiUsageMetadata>(v).ok())
+            .and_then(|v| v.to_llm_usage()),
+        ..ToolTurn::default()
+    };
+    for part in parts {
+        if let Some(text) = part.get("text").and_then(Value::as_str) {
+            turn.text.push_str(text);
+        }
+        let Some(call) = part.get("functionCall") else {
+            continue;
+        };
+        let Some(name) = call.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        turn.tool_calls.push(ToolCall {
+            id: format!("call_{}", turn.tool_calls.len()),
+            name: name.to_string(),
+            arguments: call.get("args").cloned().unwrap_or_else(|| json!({})),
+        });
+    }
+    Ok(turn)
+}
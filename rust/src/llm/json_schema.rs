@@ -0,0 +1,116 @@
+use regex::Regex;
+use serde_json::Value;
+
+/// Validates `value` against `schema`, a small subset of JSON Schema:
+/// `type` (a name or list of names), `enum`, `properties`, `required`,
+/// `additionalProperties: false`, `items`, `minimum`, `maximum`, `pattern`,
+/// `allOf` and `if`/`then`. Other keywords are ignored. The error names the
+/// first offending path, e.g. `$.recurrence.interval: below minimum 1`.
+pub fn validate(value: &Value, schema: &Value) -> Result<(), String> {
+    validate_at(value, schema, "$")
+}
+
+fn type_matches(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value
+            .as_f64()
+            .is_some_and(|n| n.fract() == 0.0 && n.is_finite()),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn validate_at(value: &Value, schema: &Value, path: &str) -> Result<(), String> {
+    match &schema["type"] {
+        Value::String(name) if !type_matches(value, name) => {
+            return Err(format!("{path}: expected {name}"));
+        }
+        Value::Array(names)
+            if !names
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|name| type_matches(value, name)) =>
+        {
+            let names = names
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" | ");
+            return Err(format!("{path}: expected {names}"));
+        }
+        _ => {}
+    }
+
+    if let Some(allowed) = schema["enum"].as_array() {
+        if !allowed.contains(value) {
+            return Err(format!("{path}: {value} is not one of {}", schema["enum"]));
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema["minimum"].as_f64() {
+            if n < min {
+                return Err(format!("{path}: below minimum {min}"));
+            }
+        }
+        if let Some(max) = schema["maximum"].as_f64() {
+            if n > max {
+                return Err(format!("{path}: above maximum {max}"));
+            }
+        }
+    }
+
+    if let (Some(s), Some(pattern)) = (value.as_str(), schema["pattern"].as_str()) {
+        let re = Regex::new(pattern).map_err(|e| format!("{path}: bad pattern: {e}"))?;
+        if !re.is_match(s) {
+            return Err(format!("{path}: {s:?} does not match {pattern}"));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(required) = schema["required"].as_array() {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    return Err(format!("{path}: missing required property {name}"));
+                }
+            }
+        }
+        let properties = schema["properties"].as_object();
+        for (name, property_value) in object {
+            match properties.and_then(|p| p.get(name)) {
+                Some(property_schema) => {
+                    validate_at(property_value, property_schema, &format!("{path}.{name}"))?
+                }
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    return Err(format!("{path}: unexpected property {name}"));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Some(items), Some(item_schema)) = (value.as_array(), schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, &format!("{path}[{i}]"))?;
+        }
+    }
+
+    if let Some(all_of) = schema["allOf"].as_array() {
+        for sub_schema in all_of {
+            validate_at(value, sub_schema, path)?;
+        }
+    }
+
+    if let (Some(condition), Some(then)) = (schema.get("if"), schema.get("then")) {
+        if validate_at(value, condition, path).is_ok() {
+            validate_at(value, then, path)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,537 @@
+use std::fs;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
+
+use crate::llm::ChatDelta;
+
+pub const LOCAL_GGUF_PROVIDER_TYPE: &str = "local-gguf";
+/// Used when the model file does not declare its training context length.
+pub const DEFAULT_CONTEXT_LENGTH: u32 = 4096;
+pub const DEFAULT_MAX_OUTPUT_TOKENS: u32 = 1024;
+const PROMPT_ELISION: &str = "\n…\n";
+
+const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+const MAX_GGUF_KEY_BYTES: u64 = 64 * 1024;
+const MAX_GGUF_STRING_VALUE_BYTES: u64 = 1024;
+
+pub fn local_gguf_models_dir(app_dir: &Path) -> PathBuf {
+    app_dir.join("llm_models")
+}
+
+/// Header fields of a GGUF model file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GgufMetadata {
+    pub version: u32,
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    pub context_length: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalGgufModelInfo {
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    pub context_length: Option<u32>,
+}
+
+fn read_array<const N: usize>(r: &mut impl Read) -> Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    r.read_exact(&mut buf)
+        .map_err(|e| anyhow!("invalid gguf: truncated header: {e}"))?;
+    Ok(buf)
+}
+
+fn read_u32(r: &mut impl Read) -> Result<u32> {
+    Ok(u32::from_le_bytes(read_array(r)?))
+}
+
+fn read_u64(r: &mut impl Read) -> Result<u64> {
+    Ok(u64::from_le_bytes(read_array(r)?))
+}
+
+fn skip_bytes(r: &mut impl Read, len: u64) -> Result<()> {
+    let skipped = std::io::copy(&mut r.take(len), &mut std::io::sink())?;
+    if skipped != len {
+        return Err(anyhow!("invalid gguf: truncated value"));
+    }
+    Ok(())
+}
+
+/// Reads a string of at most `max_len` bytes, skipping longer ones.
+fn read_gguf_string(r: &mut impl Read, max_len: u64) -> Result<Option<String>> {
+    let len = read_u64(r)?;
+    if len > max_len {
+        skip_bytes(r, len)?;
+        return Ok(None);
+    }
+    let mut buf = vec![0u8; len as usize];
+    r.read_exact(&mut buf)
+        .map_err(|e| anyhow!("invalid gguf: truncated string: {e}"))?;
+    Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+}
+
+fn gguf_scalar_size(value_type: u32) -> Option<u64> {
+    match value_type {
+        0 | 1 | 7 => Some(1),
+        2 | 3 => Some(2),
+        4..=6 => Some(4),
+        10..=12 => Some(8),
+        _ => None,
+    }
+}
+
+/// Reads an integer value of any width, or skips a value of another type.
+fn read_gguf_value(r: &mut impl Read, value_type: u32) -> Result<Option<u64>> {
+    match value_type {
+        0 | 7 => Ok(Some(u64::from(read_array::<1>(r)?[0]))),
+        2 => Ok(Some(u64::from(u16::from_le_bytes(read_array(r)?)))),
+        4 => Ok(Some(u64::from(read_u32(r)?))),
+        5 => Ok(u64::try_from(i32::from_le_bytes(read_array(r)?)).ok()),
+        10 => Ok(Some(read_u64(r)?)),
+        11 => Ok(u64::try_from(i64::from_le_bytes(read_array(r)?)).ok()),
+        8 => {
+            let len = read_u64(r)?;
+            skip_bytes(r, len)?;
+            Ok(None)
+        }
+        9 => {
+            let item_type = read_u32(r)?;
+            let count = read_u64(r)?;
+            match gguf_scalar_size(item_type) {
+                Some(size) => skip_bytes(r, count.saturating_mul(size))?,
+                None => {
+                    for _ in 0..count {
+                        read_gguf_value(r, item_type)?;
+                    }
+                }
+            }
+            Ok(None)
+        }
+        other => match gguf_scalar_size(other) {
+            Some(size) => {
+                skip_bytes(r, size)?;
+                Ok(None)
+            }
+            None => Err(anyhow!("invalid gguf: unknown value type {other}")),
+        },
+    }
+}
+
+/// Reads the architecture, name and context length from a GGUF header
+/// without loading the tensors.
+pub fn read_gguf_metadata(path: &Path) -> Result<GgufMetadata> {
+    let mut r = BufReader::new(fs::File::open(path)?);
+    if &read_array::<4>(&mut r)? != GGUF_MAGIC {
+        return Err(anyhow!("invalid gguf: bad magic"));
+    }
+    let version = read_u32(&mut r)?;
+    if !(2..=3).contains(&version) {
+        return Err(anyhow!("unsupported gguf version: {version}"));
+    }
+    let _tensor_count = read_u64(&mut r)?;
+    let kv_count = read_u64(&mut r)?;
+
+    let mut meta = GgufMetadata {
+        version,
+        ..Default::default()
+    };
+    for _ in 0..kv_count {
+        let Some(key) = read_gguf_string(&mut r, MAX_GGUF_KEY_BYTES)? else {
+            return Err(anyhow!("invalid gguf: oversized key"));
+        };
+        let value_type = read_u32(&mut r)?;
+        match (key.as_str(), value_type) {
+            ("general.architecture", 8) => {
+                meta.architecture = read_gguf_string(&mut r, MAX_GGUF_STRING_VALUE_BYTES)?;
+            }
+            ("general.name", 8) => {
+                meta.name = read_gguf_string(&mut r, MAX_GGUF_STRING_VALUE_BYTES)?;
+            }
+            (k, _) if k.ends_with(".context_length") => {
+                let value = read_gguf_value(&mut r, value_type)?;
+                let arch_prefix = k.trim_end_matches(".context_length");
+                if meta.context_length.is_none()
+                    || meta.architecture.as_deref() == Some(arch_prefix)
+                {
+                    meta.context_length = value.and_then(|v| u32::try_from(v).ok());
+                }
+            }
+            _ => {
+                read_gguf_value(&mut r, value_type)?;
+            }
+        }
+        if meta.architecture.is_some() && meta.name.is_some() && meta.context_length.is_some() {
+            break;
+        }
+    }
+    Ok(meta)
+}
+
+fn validate_model_file_name(file_name: &str) -> Result<&str> {
+    let file_name = file_name.trim();
+    let is_plain = !file_name.is_empty()
+        && !file_name.starts_with('.')
+        && !file_name.contains(['/', '\\'])
+        && file_name.to_ascii_lowercase().ends_with(".gguf");
+    if !is_plain {
+        return Err(anyhow!("invalid gguf model file name: {file_name}"));
+    }
+    Ok(file_name)
+}
+
+pub fn local_gguf_model_path(app_dir: &Path, file_name: &str) -> Result<PathBuf> {
+    let file_name = validate_model_file_name(file_name)?;
+    let path = local_gguf_models_dir(app_dir).join(file_name);
+    if !path.is_file() {
+        return Err(anyhow!("local_gguf_model_missing:{file_name}"));
+    }
+    Ok(path)
+}
+
+fn model_info(path: &Path) -> Result<LocalGgufModelInfo> {
+    let meta = read_gguf_metadata(path)?;
+    Ok(LocalGgufModelInfo {
+        file_name: path
+            .file_name()
+            .map(|v| v.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        size_bytes: fs::metadata(path)?.len(),
+        architecture: meta.architecture,
+        name: meta.name,
+        context_length: meta.context_length,
+    })
+}
+
+/// Installed models, by file name. Unreadable files are skipped.
+pub fn list_local_gguf_models(app_dir: &Path) -> Result<Vec<LocalGgufModelInfo>> {
+    let dir = local_gguf_models_dir(app_dir);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let is_model = path
+            .file_name()
+            .and_then(|v| v.to_str())
+            .is_some_and(|name| validate_model_file_name(name).is_ok());
+        if !is_model || !path.is_file() {
+            continue;
+        }
+        if let Ok(info) = model_info(&path) {
+            out.push(info);
+        }
+    }
+    out.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    Ok(out)
+}
+
+fn sha256_file_hex(path: &Path) -> Result<String> {
+    let mut r = BufReader::new(fs::File::open(path)?);
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut r, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+pub fn local_gguf_model_sha256(app_dir: &Path, file_name: &str) -> Result<String> {
+    sha256_file_hex(&local_gguf_model_path(app_dir, file_name)?)
+}
+
+/// Copies a GGUF file into the models dir, checking its header and, when
+/// given, its SHA-256. The file keeps its name; an existing model with the
+/// same name is replaced.
+pub fn install_local_gguf_model(
+    app_dir: &Path,
+    source_path: &Path,
+    expected_sha256: Option<&str>,
+) -> Result<LocalGgufModelInfo> {
+    let file_name = source_path
+        .file_name()
+        .and_then(|v| v.to_str())
+        .ok_or_else(|| anyhow!("invalid gguf model path: {}", source_path.display()))?;
+    let file_name = validate_model_file_name(file_name)?.to_string();
+    read_gguf_metadata(source_path)?;
+
+    let dir = local_gguf_models_dir(app_dir);
+    fs::create_dir_all(&dir)?;
+    let tmp_path = dir.join(format!(".{file_name}.part"));
+
+    let copied = (|| -> Result<String> {
+        let mut r = BufReader::new(fs::File::open(source_path)?);
+        let mut w = fs::File::create(&tmp_path)?;
+        let mut hasher = Sha256::new();
+        let mut buf = vec![0u8; 1 << 20];
+        loop {
+            let n = r.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+            w.write_all(&buf[..n])?;
+        }
+        w.sync_all()?;
+        Ok(format!("{:x}", hasher.finalize()))
+    })();
+
+    let actual = match copied {
+        Ok(v) => v,
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+    };
+    if let Some(expected) = expected_sha256.map(str::trim).filter(|v| !v.is_empty()) {
+        if !actual.eq_ignore_ascii_case(expected) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(anyhow!(
+                "local_gguf_model_checksum_mismatch: expected {expected}, got {actual}"
+            ));
+        }
+    }
+
+    let path = dir.join(&file_name);
+    fs::rename(&tmp_path, &path)?;
+    model_info(&path)
+}
+
+/// Returns whether a model was deleted.
+pub fn delete_local_gguf_model(app_dir: &Path, file_name: &str) -> Result<bool> {
+    let file_name = validate_model_file_name(file_name)?;
+    let path = local_gguf_models_dir(app_dir).join(file_name);
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// One completion on a local model. `prompt` is the user turn; the runtime
+/// applies the model's chat template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalGenerateRequest {
+    pub model_path: PathBuf,
+    pub prompt: String,
+    pub context_length: u32,
+    pub max_output_tokens: u32,
+    pub temperature: f32,
+}
+
+/// Runs GGUF models. Desktop builds load llama.cpp
+/// (`crate::llm::local_llama`) from the app dir on first use.
+pub trait LocalLlmRuntime: Send + Sync {
+    fn generate(
+        &self,
+        request: &LocalGenerateRequest,
+        on_token: &mut dyn FnMut(&str) -> Result<()>,
+    ) -> Result<()>;
+
+    /// Token ids of `text` with the model's own tokenizer, without BOS or
+    /// chat template.
+    fn tokenize(&self, model_path: &Path, text: &str) -> Result<Vec<u32>>;
+
+    /// Text of `tokens`, the inverse of `tokenize`.
+    fn detokenize(&self, model_path: &Path, tokens: &[u32]) -> Result<String>;
+
+    /// Tokens the chat template and BOS add around a prompt.
+    fn prompt_overhead_tokens(&self, _model_path: &Path) -> Result<usize> {
+        Ok(0)
+    }
+
+    /// Longest context the runtime supports for the model, when shorter
+    /// than the one the file declares.
+    fn max_context_length(&self, _model_path: &Path) -> Result<Option<u32>> {
+        Ok(None)
+    }
+}
+
+static LOCAL_LLM_RUNTIME: RwLock<Option<Arc<dyn LocalLlmRuntime>>> = RwLock::new(None);
+
+pub fn set_local_llm_runtime(runtime: Option<Arc<dyn LocalLlmRuntime>>) {
+    let mut guard = LOCAL_LLM_RUNTIME
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *guard = runtime;
+}
+
+pub fn local_llm_runtime() -> Option<Arc<dyn LocalLlmRuntime>> {
+    LOCAL_LLM_RUNTIME
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// The registered runtime, or else the llama.cpp library installed under
+/// `app_dir`, which is loaded and registered on first use.
+pub fn ensure_local_llm_runtime(app_dir: &Path) -> Result<Arc<dyn LocalLlmRuntime>> {
+    if let Some(runtime) = local_llm_runtime() {
+        return Ok(runtime);
+    }
+    let runtime = load_llama_cpp_runtime(app_dir)?;
+    set_local_llm_runtime(Some(runtime.clone()));
+    Ok(runtime)
+}
+
+#[cfg(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+))]
+fn load_llama_cpp_runtime(app_dir: &Path) -> Result<Arc<dyn LocalLlmRuntime>> {
+    let path = crate::llm::local_llama::llama_cpp_library_path(app_dir);
+    if !path.is_file() {
+        return Err(anyhow!(
+            "local_gguf_runtime_unavailable: {} not found",
+            path.display()
+        ));
+    }
+    let runtime = crate::llm::local_llama::LlamaCppRuntime::load(&path)
+        .map_err(|e| anyhow!("local_gguf_runtime_unavailable: {e:#}"))?;
+    Ok(Arc::new(runtime))
+}
+
+#[cfg(not(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+)))]
+fn load_llama_cpp_runtime(_app_dir: &Path) -> Result<Arc<dyn LocalLlmRuntime>> {
+    Err(anyhow!("local_gguf_runtime_unavailable"))
+}
+
+/// Splits the longest complete UTF-8 prefix off bytes streamed piece by
+/// piece. A character cut at the end stays in `pending` for the next piece;
+/// bytes that can never form one are replaced.
+pub fn take_complete_utf8(pending: &mut Vec<u8>) -> String {
+    let complete = match std::str::from_utf8(pending) {
+        Ok(_) => pending.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => pending.len(),
+    };
+    let rest = pending.split_off(complete);
+    let text = String::from_utf8_lossy(pending).into_owned();
+    *pending = rest;
+    text
+}
+
+/// Shortens `prompt` to fit the context window with room for the answer.
+/// The prompt is tokenized once; the prompt builders put instructions first
+/// and the question last, so tokens are dropped from the middle (retrieved
+/// context).
+pub fn fit_prompt_to_context(
+    runtime: &dyn LocalLlmRuntime,
+    model_path: &Path,
+    prompt: &str,
+    context_length: u32,
+    max_output_tokens: u32,
+) -> Result<String> {
+    let input_tokens = (context_length.saturating_sub(max_output_tokens).max(1) as usize)
+        .saturating_sub(runtime.prompt_overhead_tokens(model_path)?);
+    let tokens = runtime.tokenize(model_path, prompt)?;
+    if tokens.len() <= input_tokens {
+        return Ok(prompt.to_string());
+    }
+
+    // Re-tokenizing the joined text can merge or split a token at each seam.
+    let elision_tokens = runtime.tokenize(model_path, PROMPT_ELISION)?.len() + 2;
+    let keep = input_tokens.saturating_sub(elision_tokens);
+    let head = keep / 3;
+    let tail = keep - head;
+    let head_text = runtime.detokenize(model_path, &tokens[..head])?;
+    let tail_text = runtime.detokenize(model_path, &tokens[tokens.len() - tail..])?;
+    Ok(format!(
+        "{}{PROMPT_ELISION}{}",
+        head_text.trim_end_matches(char::REPLACEMENT_CHARACTER),
+        tail_text.trim_start_matches(char::REPLACEMENT_CHARACTER)
+    ))
+}
+
+pub struct LocalGgufProvider {
+    model_path: PathBuf,
+    context_length: u32,
+    max_output_tokens: u32,
+    temperature: f32,
+    runtime: Arc<dyn LocalLlmRuntime>,
+}
+
+impl LocalGgufProvider {
+    /// The context is the model's `n_ctx` from its GGUF metadata, capped
+    /// by what the runtime supports.
+    pub fn new(model_path: PathBuf, runtime: Arc<dyn LocalLlmRuntime>) -> Result<Self> {
+        let meta = read_gguf_metadata(&model_path)?;
+        let mut context_length = meta
+            .context_length
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_CONTEXT_LENGTH);
+        if let Some(cap) = runtime.max_context_length(&model_path)?.filter(|v| *v > 0) {
+            context_length = context_length.min(cap);
+        }
+        Ok(Self {
+            model_path,
+            context_length,
+            max_output_tokens: DEFAULT_MAX_OUTPUT_TOKENS.min(context_length / 2),
+            temperature: 0.2,
+            runtime,
+        })
+    }
+
+    /// Provider for a model installed under `app_dir`, using the
+    /// registered runtime or the llama.cpp one installed there.
+    pub fn from_app_dir(app_dir: &Path, model_file_name: &str) -> Result<Self> {
+        let model_path = local_gguf_model_path(app_dir, model_file_name)?;
+        Self::new(model_path, ensure_local_llm_runtime(app_dir)?)
+    }
+
+    pub fn context_length(&self) -> u32 {
+        self.context_length
+    }
+}
+
+impl crate::rag::AnswerProvider for LocalGgufProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        let request = LocalGenerateRequest {
+            model_path: self.model_path.clone(),
+            prompt: fit_prompt_to_context(
+                self.runtime.as_ref(),
+                &self.model_path,
+                prompt,
+                self.context_length,
+                self.max_output_tokens,
+            )?,
+            context_length: self.context_length,
+            max_output_tokens: self.max_output_tokens,
+            temperature: self.temperature,
+        };
+
+        let mut role = Some("assistant".to_string());
+        self.runtime.generate(&request, &mut |token| {
+            if token.is_empty() {
+                return Ok(());
+            }
+            on_event(ChatDelta {
+                role: role.take(),
+                text_delta: token.to_string(),
+                done: false,
+                usage: None,
+            })
+        })?;
+
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        usize::try_from(self.context_length).ok()
+    }
+}
@@ -0,0 +1,496 @@
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use libloading::Library;
+
+use super::local_gguf::{take_complete_utf8, LocalGenerateRequest, LocalLlmRuntime};
+
+#[cfg(target_os = "windows")]
+const LLAMA_CPP_LIBRARY_NAME: &str = "llama.dll";
+#[cfg(target_os = "macos")]
+const LLAMA_CPP_LIBRARY_NAME: &str = "libllama.dylib";
+#[cfg(target_os = "linux")]
+const LLAMA_CPP_LIBRARY_NAME: &str = "libllama.so";
+
+/// Where the llama.cpp shared library is installed, next to the `ggml`
+/// libraries it links against.
+pub fn llama_cpp_library_path(app_dir: &Path) -> PathBuf {
+    app_dir.join("llama_cpp").join(LLAMA_CPP_LIBRARY_NAME)
+}
+
+type LlamaToken = i32;
+
+/// `llama_model_params`, only ever taken from `llama_model_default_params`
+/// and handed back. The struct gains fields between llama.cpp releases, so
+/// it is carried as an over-sized buffer: structs this large are returned
+/// and passed through memory on every desktop ABI, and the library only
+/// reads its own prefix.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ModelParams([u64; 64]);
+
+/// `llama_context_params`, carried like `ModelParams`. Only the leading
+/// fields, whose layout has not changed since they were added, are named.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ContextParams {
+    n_ctx: u32,
+    n_batch: u32,
+    n_ubatch: u32,
+    n_seq_max: u32,
+    n_threads: i32,
+    n_threads_batch: i32,
+    rest: [u64; 64],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SamplerChainParams {
+    no_perf: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Batch {
+    n_tokens: i32,
+    token: *mut LlamaToken,
+    embd: *mut f32,
+    pos: *mut i32,
+    n_seq_id: *mut i32,
+    seq_id: *mut *mut i32,
+    logits: *mut i8,
+}
+
+#[repr(C)]
+struct ChatMessage {
+    role: *const c_char,
+    content: *const c_char,
+}
+
+/// The `llama.h` functions the runtime uses (the `llama_vocab` and sampler
+/// chain API of llama.cpp releases since early 2025).
+struct LlamaApi {
+    model_default_params: unsafe extern "C" fn() -> ModelParams,
+    context_default_params: unsafe extern "C" fn() -> ContextParams,
+    sampler_chain_default_params: unsafe extern "C" fn() -> SamplerChainParams,
+    model_load_from_file: unsafe extern "C" fn(*const c_char, ModelParams) -> *mut c_void,
+    model_free: unsafe extern "C" fn(*mut c_void),
+    model_get_vocab: unsafe extern "C" fn(*const c_void) -> *const c_void,
+    model_chat_template: unsafe extern "C" fn(*const c_void, *const c_char) -> *const c_char,
+    init_from_model: unsafe extern "C" fn(*mut c_void, ContextParams) -> *mut c_void,
+    free: unsafe extern "C" fn(*mut c_void),
+    n_batch: unsafe extern "C" fn(*const c_void) -> u32,
+    chat_apply_template: unsafe extern "C" fn(
+        *const c_char,
+        *const ChatMessage,
+        usize,
+        bool,
+        *mut c_char,
+        i32,
+    ) -> i32,
+    tokenize: unsafe extern "C" fn(
+        *const c_void,
+        *const c_char,
+        i32,
+        *mut LlamaToken,
+        i32,
+        bool,
+        bool,
+    ) -> i32,
+    token_to_piece:
+        unsafe extern "C" fn(*const c_void, LlamaToken, *mut c_char, i32, i32, bool) -> i32,
+    vocab_is_eog: unsafe extern "C" fn(*const c_void, LlamaToken) -> bool,
+    batch_get_one: unsafe extern "C" fn(*mut LlamaToken, i32) -> Batch,
+    decode: unsafe extern "C" fn(*mut c_void, Batch) -> i32,
+    sampler_chain_init: unsafe extern "C" fn(SamplerChainParams) -> *mut c_void,
+    sampler_chain_add: unsafe extern "C" fn(*mut c_void, *mut c_void),
+    sampler_init_greedy: unsafe extern "C" fn() -> *mut c_void,
+    sampler_init_temp: unsafe extern "C" fn(f32) -> *mut c_void,
+    sampler_init_dist: unsafe extern "C" fn(u32) -> *mut c_void,
+    sampler_sample: unsafe extern "C" fn(*mut c_void, *mut c_void, i32) -> LlamaToken,
+    sampler_free: unsafe extern "C" fn(*mut c_void),
+    // Keeps the function pointers above valid.
+    _library: Library,
+}
+
+/// # Safety
+/// `T` must be the function pointer type of the exported symbol `name`.
+unsafe fn symbol<T: Copy>(library: &Library, name: &str) -> Result<T> {
+    let symbol = library
+        .get::<T>(format!("{name}\0").as_bytes())
+        .map_err(|e| anyhow!("llama.cpp: missing {name}: {e}"))?;
+    Ok(*symbol)
+}
+
+impl LlamaApi {
+    fn load(path: &Path) -> Result<Self> {
+        // SAFETY: loading runs the library's initializers; the signatures
+        // below follow `llama.h`.
+        unsafe {
+            let library = Library::new(path)
+                .map_err(|e| anyhow!("llama.cpp: load {}: {e}", path.display()))?;
+            let backend_init: unsafe extern "C" fn() = symbol(&library, "llama_backend_init")?;
+            let api = Self {
+                model_default_params: symbol(&library, "llama_model_default_params")?,
+                context_default_params: symbol(&library, "llama_context_default_params")?,
+                sampler_chain_default_params: symbol(
+                    &library,
+                    "llama_sampler_chain_default_params",
+                )?,
+                model_load_from_file: symbol(&library, "llama_model_load_from_file")?,
+                model_free: symbol(&library, "llama_model_free")?,
+                model_get_vocab: symbol(&library, "llama_model_get_vocab")?,
+                model_chat_template: symbol(&library, "llama_model_chat_template")?,
+                init_from_model: symbol(&library, "llama_init_from_model")?,
+                free: symbol(&library, "llama_free")?,
+                n_batch: symbol(&library, "llama_n_batch")?,
+                chat_apply_template: symbol(&library, "llama_chat_apply_template")?,
+                tokenize: symbol(&library, "llama_tokenize")?,
+                token_to_piece: symbol(&library, "llama_token_to_piece")?,
+                vocab_is_eog: symbol(&library, "llama_vocab_is_eog")?,
+                batch_get_one: symbol(&library, "llama_batch_get_one")?,
+                decode: symbol(&library, "llama_decode")?,
+                sampler_chain_init: symbol(&library, "llama_sampler_chain_init")?,
+                sampler_chain_add: symbol(&library, "llama_sampler_chain_add")?,
+                sampler_init_greedy: symbol(&library, "llama_sampler_init_greedy")?,
+                sampler_init_temp: symbol(&library, "llama_sampler_init_temp")?,
+                sampler_init_dist: symbol(&library, "llama_sampler_init_dist")?,
+                sampler_sample: symbol(&library, "llama_sampler_sample")?,
+                sampler_free: symbol(&library, "llama_sampler_free")?,
+                _library: library,
+            };
+            backend_init();
+            Ok(api)
+        }
+    }
+}
+
+struct LoadedModel {
+    path: PathBuf,
+    model: *mut c_void,
+    vocab: *const c_void,
+    /// The model's Jinja chat template, `None` when the file has none.
+    chat_template: Option<CString>,
+    model_free: unsafe extern "C" fn(*mut c_void),
+}
+
+// SAFETY: the model is only used while holding the runtime's mutex.
+unsafe impl Send for LoadedModel {}
+
+impl Drop for LoadedModel {
+    fn drop(&mut self) {
+        // SAFETY: `model` came from `llama_model_load_from_file`.
+        unsafe { (self.model_free)(self.model) }
+    }
+}
+
+/// A `llama_context` for one completion.
+struct Context<'a> {
+    api: &'a LlamaApi,
+    ctx: *mut c_void,
+}
+
+impl Context<'_> {
+    fn decode(&self, tokens: &mut [LlamaToken]) -> Result<()> {
+        // SAFETY: the batch borrows `tokens` for the duration of the call.
+        let status = unsafe {
+            let batch = (self.api.batch_get_one)(tokens.as_mut_ptr(), tokens.len() as i32);
+            (self.api.decode)(self.ctx, batch)
+        };
+        if status != 0 {
+            return Err(anyhow!("local_gguf_decode_failed: {status}"));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Context<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `ctx` came from `llama_init_from_model`.
+        unsafe { (self.api.free)(self.ctx) }
+    }
+}
+
+struct Sampler<'a> {
+    api: &'a LlamaApi,
+    chain: *mut c_void,
+}
+
+impl Drop for Sampler<'_> {
+    fn drop(&mut self) {
+        // SAFETY: the chain owns the samplers added to it.
+        unsafe { (self.api.sampler_free)(self.chain) }
+    }
+}
+
+/// Runs GGUF models with llama.cpp, loaded from a shared library at
+/// runtime the way `ort`'s `load-dynamic` loads onnxruntime, so builds need
+/// neither the llama.cpp sources nor bindgen. The last model used stays
+/// loaded.
+pub struct LlamaCppRuntime {
+    api: LlamaApi,
+    loaded: Mutex<Option<LoadedModel>>,
+}
+
+impl LlamaCppRuntime {
+    pub fn load(library_path: &Path) -> Result<Self> {
+        Ok(Self {
+            api: LlamaApi::load(library_path)?,
+            loaded: Mutex::new(None),
+        })
+    }
+
+    /// Locks the runtime with `model_path` loaded.
+    fn model(&self, model_path: &Path) -> Result<MutexGuard<'_, Option<LoadedModel>>> {
+        let mut loaded = self
+            .loaded
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if loaded
+            .as_ref()
+            .is_some_and(|loaded| loaded.path == model_path)
+        {
+            return Ok(loaded);
+        }
+        *loaded = None;
+
+        let c_path = CString::new(model_path.to_string_lossy().as_bytes())
+            .map_err(|_| anyhow!("invalid gguf model path: {}", model_path.display()))?;
+        // SAFETY: the params come from the library; the template string is
+        // owned by the model and copied before the model can be freed.
+        unsafe {
+            let model =
+                (self.api.model_load_from_file)(c_path.as_ptr(), (self.api.model_default_params)());
+            if model.is_null() {
+                return Err(anyhow!(
+                    "local_gguf_model_load_failed: {}",
+                    model_path.display()
+                ));
+            }
+            let template = (self.api.model_chat_template)(model, std::ptr::null());
+            *loaded = Some(LoadedModel {
+                path: model_path.to_path_buf(),
+                model,
+                vocab: (self.api.model_get_vocab)(model),
+                chat_template: (!template.is_null()).then(|| CStr::from_ptr(template).to_owned()),
+                model_free: self.api.model_free,
+            });
+        }
+        Ok(loaded)
+    }
+
+    fn tokenize_with(
+        &self,
+        model: &LoadedModel,
+        text: &str,
+        special: bool,
+    ) -> Result<Vec<LlamaToken>> {
+        let len = i32::try_from(text.len()).map_err(|_| anyhow!("local_gguf_prompt_too_long"))?;
+        let mut tokens: Vec<LlamaToken> = vec![0; text.len() + 2];
+        for _ in 0..2 {
+            // SAFETY: `tokens` has room for the count passed; a negative
+            // result is the count needed and writes nothing.
+            let n = unsafe {
+                (self.api.tokenize)(
+                    model.vocab,
+                    text.as_ptr().cast(),
+                    len,
+                    tokens.as_mut_ptr(),
+                    tokens.len() as i32,
+                    special,
+                    special,
+                )
+            };
+            if n >= 0 {
+                tokens.truncate(n as usize);
+                return Ok(tokens);
+            }
+            tokens.resize(n.unsigned_abs() as usize, 0);
+        }
+        Err(anyhow!("local_gguf_tokenize_failed"))
+    }
+
+    /// Appends the bytes of `token` to `out`. Control tokens have none.
+    fn push_piece(&self, model: &LoadedModel, token: LlamaToken, out: &mut Vec<u8>) -> Result<()> {
+        let mut buf = vec![0u8; 64];
+        for _ in 0..2 {
+            // SAFETY: as in `tokenize_with`.
+            let n = unsafe {
+                (self.api.token_to_piece)(
+                    model.vocab,
+                    token,
+                    buf.as_mut_ptr().cast(),
+                    buf.len() as i32,
+                    0,
+                    false,
+                )
+            };
+            if n >= 0 {
+                out.extend_from_slice(&buf[..n as usize]);
+                return Ok(());
+            }
+            buf.resize(n.unsigned_abs() as usize, 0);
+        }
+        Err(anyhow!("local_gguf_detokenize_failed"))
+    }
+
+    /// `prompt` as one user turn of the model's chat template, followed by
+    /// the opening of the assistant turn. Sent as is when the model has no
+    /// template or llama.cpp does not know it.
+    fn apply_chat_template(&self, model: &LoadedModel, prompt: &str) -> Result<String> {
+        let Some(template) = &model.chat_template else {
+            return Ok(prompt.to_string());
+        };
+        let content = CString::new(prompt.replace('\0', ""))?;
+        let message = ChatMessage {
+            role: c"user".as_ptr(),
+            content: content.as_ptr(),
+        };
+        let mut buf = vec![0u8; prompt.len() * 2 + 256];
+        for _ in 0..2 {
+            // SAFETY: `buf` has room for the length passed; a longer result
+            // is the length needed.
+            let n = unsafe {
+                (self.api.chat_apply_template)(
+                    template.as_ptr(),
+                    &message,
+                    1,
+                    true,
+                    buf.as_mut_ptr().cast(),
+                    buf.len() as i32,
+                )
+            };
+            if n < 0 {
+                return Ok(prompt.to_string());
+            }
+            if (n as usize) <= buf.len() {
+                buf.truncate(n as usize);
+                return Ok(String::from_utf8_lossy(&buf).into_owned());
+            }
+            buf.resize(n as usize, 0);
+        }
+        Err(anyhow!("local_gguf_chat_template_failed"))
+    }
+
+    fn new_context(&self, model: &LoadedModel, context_length: u32) -> Result<Context<'_>> {
+        // SAFETY: the params come from the library with only the named
+        // leading fields changed.
+        let ctx = unsafe {
+            let mut params = (self.api.context_default_params)();
+            params.n_ctx = context_length;
+            if let Ok(threads) = std::thread::available_parallelism() {
+                params.n_threads = threads.get() as i32;
+                params.n_threads_batch = threads.get() as i32;
+            }
+            (self.api.init_from_model)(model.model, params)
+        };
+        if ctx.is_null() {
+            return Err(anyhow!("local_gguf_context_failed: n_ctx {context_length}"));
+        }
+        Ok(Context {
+            api: &self.api,
+            ctx,
+        })
+    }
+
+    fn new_sampler(&self, temperature: f32) -> Sampler<'_> {
+        // SAFETY: the chain takes ownership of each sampler added to it.
+        unsafe {
+            let chain = (self.api.sampler_chain_init)((self.api.sampler_chain_default_params)());
+            if temperature > 0.0 {
+                (self.api.sampler_chain_add)(chain, (self.api.sampler_init_temp)(temperature));
+                (self.api.sampler_chain_add)(chain, (self.api.sampler_init_dist)(rand::random()));
+            } else {
+                (self.api.sampler_chain_add)(chain, (self.api.sampler_init_greedy)());
+            }
+            Sampler {
+                api: &self.api,
+                chain,
+            }
+        }
+    }
+}
+
+impl LocalLlmRuntime for LlamaCppRuntime {
+    fn generate(
+        &self,
+        request: &LocalGenerateRequest,
+        on_token: &mut dyn FnMut(&str) -> Result<()>,
+    ) -> Result<()> {
+        let loaded = self.model(&request.model_path)?;
+        let model = loaded.as_ref().expect("model loaded");
+
+        let prompt = self.apply_chat_template(model, &request.prompt)?;
+        let mut prompt_tokens = self.tokenize_with(model, &prompt, true)?;
+        let context_length = request.context_length as usize;
+        if prompt_tokens.len() >= context_length {
+            return Err(anyhow!(
+                "local_gguf_prompt_too_long: {} tokens, context {context_length}",
+                prompt_tokens.len()
+            ));
+        }
+        let max_new_tokens =
+            (request.max_output_tokens as usize).min(context_length - prompt_tokens.len());
+
+        let ctx = self.new_context(model, request.context_length)?;
+        let sampler = self.new_sampler(request.temperature);
+        // SAFETY: `ctx` is alive.
+        let n_batch = unsafe { (self.api.n_batch)(ctx.ctx) }.max(1) as usize;
+        for chunk in prompt_tokens.chunks_mut(n_batch) {
+            ctx.decode(chunk)?;
+        }
+
+        // Pieces can end inside a multi-byte character; those bytes wait for
+        // the next piece instead of re-decoding the whole answer.
+        let mut pending: Vec<u8> = Vec::new();
+        for step in 0..max_new_tokens {
+            // SAFETY: `ctx` holds the logits of the last decoded token.
+            let mut token = unsafe { (self.api.sampler_sample)(sampler.chain, ctx.ctx, -1) };
+            // SAFETY: `vocab` belongs to the loaded model.
+            if unsafe { (self.api.vocab_is_eog)(model.vocab, token) } {
+                break;
+            }
+            self.push_piece(model, token, &mut pending)?;
+            let text = take_complete_utf8(&mut pending);
+            if !text.is_empty() {
+                on_token(&text)?;
+            }
+            if step + 1 < max_new_tokens {
+                ctx.decode(std::slice::from_mut(&mut token))?;
+            }
+        }
+        if !pending.is_empty() {
+            on_token(&String::from_utf8_lossy(&pending))?;
+        }
+        Ok(())
+    }
+
+    fn tokenize(&self, model_path: &Path, text: &str) -> Result<Vec<u32>> {
+        let loaded = self.model(model_path)?;
+        let model = loaded.as_ref().expect("model loaded");
+        Ok(self
+            .tokenize_with(model, text, false)?
+            .into_iter()
+            .map(|token| token as u32)
+            .collect())
+    }
+
+    fn detokenize(&self, model_path: &Path, tokens: &[u32]) -> Result<String> {
+        let loaded = self.model(model_path)?;
+        let model = loaded.as_ref().expect("model loaded");
+        let mut bytes = Vec::new();
+        for token in tokens {
+            self.push_piece(model, *token as LlamaToken, &mut bytes)?;
+        }
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    fn prompt_overhead_tokens(&self, model_path: &Path) -> Result<usize> {
+        let loaded = self.model(model_path)?;
+        let model = loaded.as_ref().expect("model loaded");
+        let empty_turn = self.apply_chat_template(model, "")?;
+        Ok(self.tokenize_with(model, &empty_turn, true)?.len())
+    }
+}
@@ -1,8 +1,20 @@
 pub mod anthropic;
+pub mod budget;
+pub mod fallback;
 pub mod gateway;
 pub mod gemini;
+pub mod json_schema;
+pub mod local_gguf;
+#[cfg(all(
+    any(target_os = "windows", target_os = "macos", target_os = "linux"),
+    not(frb_expand)
+))]
+pub mod local_llama;
 pub mod openai;
 pub mod timeouts;
+pub mod tools;
+
+use std::path::Path;
 
 use anyhow::{anyhow, Result};
 
@@ -11,6 +23,52 @@ pub struct ChatDelta {
     pub role: Option<String>,
     pub text_delta: String,
     pub done: bool,
+    /// Tokens the provider reported for the whole response, on at most one
+    /// of its events.
+    pub usage: Option<LlmUsage>,
+}
+
+/// Token counts reported by a provider, for `llm_usage_daily`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LlmUsage {
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub total_tokens: i64,
+}
+
+impl LlmUsage {
+    /// `total_tokens` defaults to input plus output, for providers that
+    /// don't report it.
+    pub fn new(input_tokens: i64, output_tokens: i64, total_tokens: Option<i64>) -> Self {
+        Self {
+            input_tokens,
+            output_tokens,
+            total_tokens: total_tokens.unwrap_or(input_tokens + output_tokens),
+        }
+    }
+
+    pub fn add(&mut self, other: LlmUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+}
+
+fn profile_api_key(profile: &crate::db::LlmProfileConfig) -> Result<String> {
+    profile.api_key.clone().ok_or_else(|| {
+        anyhow!(
+            "missing api_key for {} provider",
+            profile.provider_type.as_str()
+        )
+    })
+}
+
+fn profile_base_url(profile: &crate::db::LlmProfileConfig, default: &str) -> String {
+    profile
+        .base_url
+        .clone()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| default.to_string())
 }
 
 pub fn answer_provider_from_profile(
@@ -20,51 +78,83 @@ pub fn answer_provider_from_profile(
     let model_name = profile.model_name.clone();
 
     match provider_type {
-        "openai-compatible" => {
-            let api_key = profile
-                .api_key
-                .clone()
-                .ok_or_else(|| anyhow!("missing api_key for openai-compatible provider"))?;
-            let base_url = profile
-                .base_url
-                .clone()
-                .filter(|v| !v.trim().is_empty())
-                .unwrap_or_else(|| "https://api.openai.com/v1".to_string());
-
-            Ok(Box::new(openai::OpenAiCompatibleProvider::new(
-                base_url, api_key, model_name, None,
-            )))
+        "openai-compatible" => Ok(Box::new(openai::OpenAiCompatibleProvider::new(
+            profile_base_url(profile, "https://api.openai.com/v1"),
+            profile_api_key(profile)?,
+            model_name,
+            None,
+        ))),
+        "gemini-compatible" => Ok(Box::new(gemini::GeminiCompatibleProvider::new(
+            profile_base_url(profile, "https://generativelanguage.googleapis.com/v1beta"),
+            profile_api_key(profile)?,
+            model_name,
+        ))),
+        "anthropic-compatible" => Ok(Box::new(anthropic::AnthropicCompatibleProvider::new(
+            profile_base_url(profile, "https://api.anthropic.com/v1"),
+            profile_api_key(profile)?,
+            model_name,
+            1024,
+        ))),
+        local_gguf::LOCAL_GGUF_PROVIDER_TYPE => {
+            let model_path = Path::new(&model_name);
+            if !model_path.is_absolute() {
+                return Err(anyhow!(
+                    "local-gguf model path must be absolute: {model_name}"
+                ));
+            }
+            let runtime = local_gguf::local_llm_runtime()
+                .ok_or_else(|| anyhow!("local_gguf_runtime_unavailable"))?;
+            Ok(Box::new(local_gguf::LocalGgufProvider::new(
+                model_path.to_path_buf(),
+                runtime,
+            )?))
         }
-        "gemini-compatible" => {
-            let api_key = profile
-                .api_key
-                .clone()
-                .ok_or_else(|| anyhow!("missing api_key for gemini-compatible provider"))?;
-            let base_url = profile
-                .base_url
-                .clone()
-                .filter(|v| !v.trim().is_empty())
-                .unwrap_or_else(|| "https://generativelanguage.googleapis.com/v1beta".to_string());
+        _ => Err(anyhow!("unsupported provider_type: {provider_type}")),
+    }
+}
 
-            Ok(Box::new(gemini::GeminiCompatibleProvider::new(
-                base_url, api_key, model_name,
-            )))
-        }
-        "anthropic-compatible" => {
-            let api_key = profile
-                .api_key
-                .clone()
-                .ok_or_else(|| anyhow!("missing api_key for anthropic-compatible provider"))?;
-            let base_url = profile
-                .base_url
-                .clone()
-                .filter(|v| !v.trim().is_empty())
-                .unwrap_or_else(|| "https://api.anthropic.com/v1".to_string());
+/// Like `answer_provider_from_profile`, but a local-gguf profile may name a
+/// model file installed under `app_dir` instead of an absolute path.
+pub fn answer_provider_from_profile_in_app_dir(
+    app_dir: &Path,
+    profile: &crate::db::LlmProfileConfig,
+) -> Result<Box<dyn crate::rag::AnswerProvider>> {
+    if profile.provider_type == local_gguf::LOCAL_GGUF_PROVIDER_TYPE
+        && !Path::new(&profile.model_name).is_absolute()
+    {
+        return Ok(Box::new(local_gguf::LocalGgufProvider::from_app_dir(
+            app_dir,
+            &profile.model_name,
+        )?));
+    }
+    answer_provider_from_profile(profile)
+}
 
-            Ok(Box::new(anthropic::AnthropicCompatibleProvider::new(
-                base_url, api_key, model_name, 1024,
-            )))
-        }
+/// Same providers as `answer_provider_from_profile`, for agent mode.
+pub fn tool_provider_from_profile(
+    profile: &crate::db::LlmProfileConfig,
+) -> Result<Box<dyn tools::ToolCallingProvider>> {
+    let provider_type = profile.provider_type.as_str();
+    let model_name = profile.model_name.clone();
+
+    match provider_type {
+        "openai-compatible" => Ok(Box::new(openai::OpenAiCompatibleProvider::new(
+            profile_base_url(profile, "https://api.openai.com/v1"),
+            profile_api_key(profile)?,
+            model_name,
+            None,
+        ))),
+        "gemini-compatible" => Ok(Box::new(gemini::GeminiCompatibleProvider::new(
+            profile_base_url(profile, "https://generativelanguage.googleapis.com/v1beta"),
+            profile_api_key(profile)?,
+            model_name,
+        ))),
+        "anthropic-compatible" => Ok(Box::new(anthropic::AnthropicCompatibleProvider::new(
+            profile_base_url(profile, "https://api.anthropic.com/v1"),
+            profile_api_key(profile)?,
+            model_name,
+            1024,
+        ))),
         _ => Err(anyhow!("unsupported provider_type: {provider_type}")),
     }
 }
@@ -4,9 +4,13 @@ use anyhow::{anyhow, Result};
 use reqwest::blocking::Client;
 use reqwest::header;
 use serde::Serialize;
-use serde_json::Value;
+use serde_json::{json, Value};
 
-use super::ChatDelta;
+use super::tools::{
+    parse_tool_arguments, tool_arguments_string, tool_messages_chars, ToolCall,
+    ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn,
+};
+use super::{ChatDelta, LlmUsage};
 
 fn extract_text_from_json_value(value: &Value) -> String {
     match value {
@@ -37,6 +41,18 @@ struct ParsedSseEvent {
     role: Option<String>,
     text_delta: String,
     done: bool,
+    usage: Option<LlmUsage>,
+}
+
+/// `usage` of a response, or of the final chunk of a stream requested with
+/// `stream_options.include_usage`.
+fn extract_usage(value: &Value) -> Option<LlmUsage> {
+    let usage = value.get("usage")?;
+    Some(LlmUsage::new(
+        usage.get("prompt_tokens")?.as_i64()?,
+        usage.get("completion_tokens")?.as_i64()?,
+        usage.get("total_tokens").and_then(Value::as_i64),
+    ))
 }
 
 pub fn chat_completions_url(base_url: &str) -> String {
@@ -50,6 +66,14 @@ struct OpenAiChatCompletionsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
     stream: bool,
+    stream_options: OpenAiStreamOptions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAiStreamOptions {
+    include_usage: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -81,12 +105,11 @@ impl OpenAiCompatibleProvider {
             temperature,
         }
     }
-}
 
-impl crate::rag::AnswerProvider for OpenAiCompatibleProvider {
-    fn stream_answer(
+    fn stream_chat(
         &self,
         prompt: &str,
+        response_format: Option<Value>,
         on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
     ) -> Result<()> {
         let url = chat_completions_url(&self.base_url);
@@ -98,6 +121,10 @@ impl crate::rag::AnswerProvider for OpenAiCompatibleProvider {
             }],
             temperature: self.temperature,
             stream: true,
+            stream_options: OpenAiStreamOptions {
+                include_usage: true,
+            },
+            response_format,
         };
 
         let request_timeout =
@@ -136,6 +163,117 @@ impl crate::rag::AnswerProvider for OpenAiCompatibleProvider {
     }
 }
 
+/// `response_format` for JSON mode, which most OpenAI-compatible servers
+/// support (unlike `json_schema`). The API rejects JSON mode unless the
+/// prompt mentions JSON, so it is only requested then.
+pub fn json_mode_response_format(prompt: &str) -> Option<Value> {
+    prompt
+        .to_ascii_lowercase()
+        .contains("json")
+        .then(|| json!({ "type": "json_object" }))
+}
+
+/// Whether `model_name` is an OpenAI model that accepts a `json_schema`
+/// `response_format` (structured outputs). Fine-tunes (`ft:<base>:...`)
+/// follow their base model.
+pub fn supports_json_schema_response_format(model_name: &str) -> bool {
+    let model = super::budget::model_base_name(model_name);
+    let model = model.strip_prefix("ft:").unwrap_or(&model);
+    // The first gpt-4o snapshot and the o1 previews predate structured outputs.
+    if model.starts_with("gpt-4o-2024-05-13")
+        || model.starts_with("o1-mini")
+        || model.starts_with("o1-preview")
+    {
+        return false;
+    }
+    ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "o1", "o3", "o4"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+}
+
+/// `response_format` for an answer matching `schema`: structured outputs
+/// where the model supports them, JSON mode otherwise. The schema is not
+/// sent as `strict`, since strict mode only accepts a subset of JSON schema.
+pub fn json_response_format(model_name: &str, prompt: &str, schema: &Value) -> Option<Value> {
+    if !supports_json_schema_response_format(model_name) {
+        return json_mode_response_format(prompt);
+    }
+    Some(json!({
+        "type": "json_schema",
+        "json_schema": { "name": "response", "schema": schema, "strict": false },
+    }))
+}
+
+impl crate::rag::AnswerProvider for OpenAiCompatibleProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.stream_chat(prompt, None, on_event)
+    }
+
+    fn stream_answer_json(
+        &self,
+        prompt: &str,
+        schema: &Value,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        let response_format = json_response_format(&self.model_name, prompt, schema);
+        self.stream_chat(prompt, response_format, on_event)
+    }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        super::budget::model_context_window_tokens(&self.model_name)
+    }
+
+    fn token_counter(&self) -> super::budget::TokenCounter {
+        super::budget::TokenCounter::for_model(&self.model_name)
+    }
+}
+
+impl ToolCallingProvider for OpenAiCompatibleProvider {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        let url = chat_completions_url(&self.base_url);
+        let req = build_chat_completions_tools_request(
+            &self.model_name,
+            self.temperature,
+            messages,
+            tools,
+            choice,
+        );
+        let request_timeout =
+            crate::llm::timeouts::ask_ai_timeout_for_prompt_chars(tool_messages_chars(messages));
+
+        let resp = self
+            .client
+            .post(url)
+            .bearer_auth(&self.api_key)
+            .json(&req)
+            .timeout(request_timeout)
+            .send()?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!(
+                "openai-compatible request failed: HTTP {status} {body}"
+            ));
+        }
+
+        parse_chat_completions_tool_turn(resp)
+    }
+
+    fn token_counter(&self) -> super::budget::TokenCounter {
+        super::budget::TokenCounter::for_model(&self.model_name)
+    }
+}
+
 pub fn parse_chat_completions_sse(reader: impl Read) -> Result<Vec<ChatDelta>> {
     let mut out = Vec::new();
     read_chat_completions_sse(reader, |ev| {
@@ -169,13 +307,18 @@ pub fn read_chat_completions_sse(
         event: ParsedSseEvent,
         on_event: &mut impl FnMut(ChatDelta) -> Result<()>,
     ) -> Result<()> {
-        if !event.done && event.role.is_none() && event.text_delta.is_empty() {
+        if !event.done
+            && event.role.is_none()
+            && event.text_delta.is_empty()
+            && event.usage.is_none()
+        {
             return Ok(());
         }
         on_event(ChatDelta {
             role: event.role,
             text_delta: event.text_delta,
             done: event.done,
+            usage: event.usage,
         })
     }
 
@@ -259,6 +402,7 @@ pub fn read_chat_completions_sse(
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: None,
             });
         }
 
@@ -277,6 +421,7 @@ pub fn read_chat_completions_sse(
             role: extract_role(&parsed_value),
             text_delta: extract_delta_text(&parsed_value),
             done: explicit_done,
+            usage: extract_usage(&parsed_value),
         })
     }
 
@@ -393,6 +538,7 @@ pub fn read_chat_completions_json(
             role,
             text_delta,
             done: false,
+            usage: None,
         })?;
     }
 
@@ -400,7 +546,122 @@ pub fn read_chat_completions_json(
         role: None,
         text_delta: String::new(),
         done: true,
+        usage: extract_usage(&root),
     })?;
 
     Ok(())
 }
+
+/// Chat completions body for one non-streaming tool-calling turn.
+pub fn build_chat_completions_tools_request(
+    model_name: &str,
+    temperature: Option<f32>,
+    messages: &[ToolMessage],
+    tools: &[ToolSpec],
+    choice: ToolChoice,
+) -> Value {
+    let messages = messages
+        .iter()
+        .map(|message| match message {
+            ToolMessage::User(text) => json!({ "role": "user", "content": text }),
+            ToolMessage::Assistant { text, tool_calls } => {
+                let mut out = json!({
+                    "role": "assistant",
+                    "content": if text.is_empty() { Value::Null } else { json!(text) },
+                });
+                if !tool_calls.is_empty() {
+                    out["tool_calls"] = tool_calls
+                        .iter()
+                        .map(|call| {
+                            json!({
+                                "id": call.id,
+                                "type": "function",
+                                "function": {
+                                    "name": call.name,
+                                    "arguments": tool_arguments_string(&call.arguments),
+                                },
+                            })
+                        })
+                        .collect();
+                }
+                out
+            }
+            ToolMessage::ToolResult {
+                call_id, content, ..
+            } => json!({ "role": "tool", "tool_call_id": call_id, "content": content }),
+        })
+        .collect::<Vec<_>>();
+
+    let mut req = json!({
+        "model": model_name,
+        "messages": messages,
+        "stream": false,
+    });
+    if let Some(temperature) = temperature {
+        req["temperature"] = json!(temperature);
+    }
+    if !tools.is_empty() {
+        req["tools"] = tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "parameters": tool.parameters,
+                    },
+                })
+            })
+            .collect();
+        if choice == ToolChoice::None {
+            req["tool_choice"] = json!("none");
+        }
+    }
+    req
+}
+
+pub fn parse_chat_completions_tool_turn(reader: impl Read) -> Result<ToolTurn> {
+    let root: Value = serde_json::from_reader(reader)?;
+    let message = root
+        .pointer("/choices/0/message")
+        .ok_or_else(|| anyhow!("openai-compatible response has no message"))?;
+
+    let text = message
+        .get("content")
+        .map(extract_text_from_json_value)
+        .unwrap_or_default();
+    let tool_calls = message
+        .get("tool_calls")
+        .and_then(Value::as_array)
+        .map(|calls| {
+            calls
+                .iter()
+                .enumerate()
+                .filter_map(|(i, call)| {
+                    let name = call.pointer("/function/name").and_then(Value::as_str)?;
+                    let arguments = match call.pointer("/function/arguments") {
+                        Some(Value::String(raw)) => parse_tool_arguments(raw),
+                        Some(value) => value.clone(),
+                        None => json!({}),
+                    };
+                    Some(ToolCall {
+                        id: call
+                            .get("id")
+                            .and_then(Value::as_str)
+                            .map(ToString::to_string)
+                            .unwrap_or_else(|| format!("call_{i}")),
+                        name: name.to_string(),
+                        arguments,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ToolTurn {
+        text,
+        tool_calls,
+        usage: extract_usage(&root),
+    })
+}
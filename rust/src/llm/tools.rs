@@ -0,0 +1,104 @@
+use anyhow::Result;
+use serde_json::Value;
+
+/// A local function the model may call during an agent turn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolSpec {
+    pub name: String,
+    pub description: String,
+    /// JSON schema of the arguments object.
+    pub parameters: Value,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolCall {
+    /// Provider-assigned id, echoed back with the result.
+    pub id: String,
+    pub name: String,
+    /// Decoded arguments; the raw string when the model sent invalid JSON.
+    pub arguments: Value,
+}
+
+/// One entry of a tool-calling conversation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ToolMessage {
+    User(String),
+    Assistant {
+        text: String,
+        tool_calls: Vec<ToolCall>,
+    },
+    ToolResult {
+        call_id: String,
+        name: String,
+        content: String,
+    },
+}
+
+/// What the model answered: text, tool calls, or both.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ToolTurn {
+    pub text: String,
+    pub tool_calls: Vec<ToolCall>,
+    pub usage: Option<super::LlmUsage>,
+}
+
+/// Whether the model may call the tools it is offered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToolChoice {
+    #[default]
+    Auto,
+    /// The tools stay declared, since providers reject a history with tool
+    /// calls to undeclared tools, but the model has to answer in text.
+    None,
+}
+
+pub trait ToolCallingProvider {
+    /// Runs one non-streaming completion over `messages`. An empty `tools`
+    /// slice asks for a plain answer.
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn>;
+
+    /// Context window of the model behind this provider, in tokens, when
+    /// known. Used to size the conversation history.
+    fn context_window_tokens(&self) -> Option<usize> {
+        None
+    }
+
+    /// How the model behind this provider counts tokens.
+    fn token_counter(&self) -> super::budget::TokenCounter {
+        super::budget::TokenCounter::Estimate
+    }
+}
+
+pub(crate) fn parse_tool_arguments(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// Arguments as the JSON string providers expect back in the history.
+pub(crate) fn tool_arguments_string(arguments: &Value) -> String {
+    match arguments {
+        Value::String(raw) => raw.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn tool_messages_chars(messages: &[ToolMessage]) -> usize {
+    messages
+        .iter()
+        .map(|message| match message {
+            ToolMessage::User(text) => text.chars().count(),
+            ToolMessage::Assistant { text, tool_calls } => {
+                text.chars().count()
+                    + tool_calls
+                        .iter()
+                        .map(|call| call.arguments.to_string().len())
+                        .sum::<usize>()
+            }
+            ToolMessage::ToolResult { content, .. } => content.chars().count(),
+        })
+        .sum()
+}
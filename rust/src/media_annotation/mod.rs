@@ -10,6 +10,8 @@ use std::time::Duration;
 
 use sha2::{Digest, Sha256};
 
+use crate::prompt_templates::{self, PromptVariables};
+
 const OCR_MARKDOWN_LANG_PREFIX: &str = "ocr_markdown:";
 const VIDEO_EXTRACT_LANG_PREFIX: &str = "video_extract:";
 const DETACHED_JOB_STATUS_TIMEOUT_SECONDS: u64 = 12;
@@ -363,6 +365,14 @@ fn parse_prompt_mode_and_lang(raw_lang: &str) -> (MediaAnnotationPromptMode, Str
     (MediaAnnotationPromptMode::Annotation, trimmed.to_string())
 }
 
+fn annotation_prompt_from_template(template: &str, lang: &str) -> String {
+    let lang = lang.trim();
+    let variables = PromptVariables::new()
+        .set("lang", if lang.is_empty() { "und" } else { lang })
+        .set("now", prompt_templates::now_utc_rfc3339());
+    prompt_templates::render_prompt_template(template, &variables)
+}
+
 #[derive(Clone, Debug)]
 pub struct MediaAnnotationUsage {
     pub input_tokens: Option<i64>,
@@ -378,6 +388,21 @@ fn build_request(
 ) -> Result<(
     MediaAnnotationChatCompletionsRequest,
     MediaAnnotationPromptMode,
+)> {
+    build_request_with_template(model_name, lang, mime_type, image_bytes, None)
+}
+
+/// `annotation_template` replaces the built-in annotation prompt; OCR and
+/// video extraction prompts are not templated.
+fn build_request_with_template(
+    model_name: &str,
+    lang: &str,
+    mime_type: &str,
+    image_bytes: &[u8],
+    annotation_template: Option<&str>,
+) -> Result<(
+    MediaAnnotationChatCompletionsRequest,
+    MediaAnnotationPromptMode,
 )> {
     if image_bytes.is_empty() {
         return Err(anyhow!("image_bytes is empty"));
@@ -395,7 +420,10 @@ fn build_request(
     let image_b64 = STANDARD.encode(image_bytes);
     let data_url = format!("data:{mime_type};base64,{image_b64}");
     let prompt = match prompt_mode {
-        MediaAnnotationPromptMode::Annotation => annotation_prompt(&normalized_lang),
+        MediaAnnotationPromptMode::Annotation => match annotation_template {
+            Some(template) => annotation_prompt_from_template(template, &normalized_lang),
+            None => annotation_prompt(&normalized_lang),
+        },
         MediaAnnotationPromptMode::OcrMarkdown => ocr_markdown_prompt(),
         MediaAnnotationPromptMode::VideoExtract => video_extract_prompt(&normalized_lang),
     };
@@ -794,6 +822,7 @@ pub struct OpenAiCompatibleMediaAnnotationClient {
     base_url: String,
     api_key: String,
     model_name: String,
+    prompt_template: Option<String>,
 }
 
 impl OpenAiCompatibleMediaAnnotationClient {
@@ -803,16 +832,29 @@ impl OpenAiCompatibleMediaAnnotationClient {
             base_url,
             api_key,
             model_name,
+            prompt_template: None,
         }
     }
 
+    /// Uses a `media_annotation` prompt template instead of the built-in prompt.
+    pub fn with_prompt_template(mut self, prompt_template: Option<String>) -> Self {
+        self.prompt_template = prompt_template;
+        self
+    }
+
     pub fn annotate_image_with_usage(
         &self,
         lang: &str,
         mime_type: &str,
         image_bytes: &[u8],
     ) -> Result<(Value, MediaAnnotationUsage)> {
-        let (req, prompt_mode) = build_request(&self.model_name, lang, mime_type, image_bytes)?;
+        let (req, prompt_mode) = build_request_with_template(
+            &self.model_name,
+            lang,
+            mime_type,
+            image_bytes,
+            self.prompt_template.as_deref(),
+        )?;
         let request_timeout = crate::llm::timeouts::media_annotation_timeout_for_image_bytes(
             image_bytes.len(),
             prompt_mode == MediaAnnotationPromptMode::OcrMarkdown
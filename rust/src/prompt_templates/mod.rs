@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+
+pub const PURPOSE_ASK_AI: &str = "ask_ai";
+pub const PURPOSE_SEMANTIC_PARSE: &str = "semantic_parse";
+pub const PURPOSE_MEDIA_ANNOTATION: &str = "media_annotation";
+
+pub const PROMPT_TEMPLATE_PURPOSES: [&str; 3] = [
+    PURPOSE_ASK_AI,
+    PURPOSE_SEMANTIC_PARSE,
+    PURPOSE_MEDIA_ANNOTATION,
+];
+
+/// Variables a template for `purpose` may use, and those it must use.
+fn purpose_variables(purpose: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    match purpose {
+        PURPOSE_ASK_AI => Some((
+            &["question", "contexts", "history", "actions", "now"],
+            &["question", "contexts"],
+        )),
+        PURPOSE_SEMANTIC_PARSE => Some((
+            &["question", "contexts", "lang", "now", "day_end_minutes"],
+            &["question", "contexts"],
+        )),
+        PURPOSE_MEDIA_ANNOTATION => Some((&["lang", "now"], &[])),
+        _ => None,
+    }
+}
+
+/// `{name}` placeholders in `body`, in order. Other braces (JSON examples)
+/// are literal text.
+fn placeholders(body: &str) -> Vec<(usize, usize, &str)> {
+    let mut out = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = body[rest..].find('{').map(|i| rest + i) {
+        let name_len = body[open + 1..]
+            .bytes()
+            .take_while(|b| b.is_ascii_lowercase() || *b == b'_')
+            .count();
+        let close = open + 1 + name_len;
+        if name_len > 0 && body.as_bytes().get(close) == Some(&b'}') {
+            out.push((open, close + 1, &body[open + 1..close]));
+            rest = close + 1;
+        } else {
+            rest = open + 1;
+        }
+    }
+    out
+}
+
+/// Checks that `purpose` is known, the body only uses its variables and
+/// uses all required ones.
+pub fn validate_prompt_template(purpose: &str, body: &str) -> Result<()> {
+    let (allowed, required) = purpose_variables(purpose)
+        .ok_or_else(|| anyhow!("unsupported prompt purpose: {purpose}"))?;
+    if body.trim().is_empty() {
+        return Err(anyhow!("prompt template cannot be empty"));
+    }
+    let used = placeholders(body)
+        .into_iter()
+        .map(|(_, _, name)| name)
+        .collect::<Vec<_>>();
+    if let Some(unknown) = used.iter().find(|name| !allowed.contains(name)) {
+        return Err(anyhow!(
+            "prompt template uses unknown variable {{{unknown}}} for {purpose}"
+        ));
+    }
+    if let Some(missing) = required.iter().find(|name| !used.contains(name)) {
+        return Err(anyhow!(
+            "prompt template for {purpose} must include {{{missing}}}"
+        ));
+    }
+    Ok(())
+}
+
+/// Values for one rendering. Unset variables are left as `{name}` text.
+#[derive(Clone, Debug, Default)]
+pub struct PromptVariables {
+    values: BTreeMap<&'static str, String>,
+}
+
+impl PromptVariables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.values.insert(name, value.into());
+        self
+    }
+}
+
+/// Substitutes variables in one pass, so values containing `{...}` are
+/// never expanded again.
+pub fn render_prompt_template(body: &str, variables: &PromptVariables) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut last = 0;
+    for (start, end, name) in placeholders(body) {
+        out.push_str(&body[last..start]);
+        match variables.values.get(name) {
+            Some(value) => out.push_str(value),
+            None => out.push_str(&body[start..end]),
+        }
+        last = end;
+    }
+    out.push_str(&body[last..]);
+    out
+}
+
+const DEFAULT_ASK_AI_TEMPLATE: &str = r#"You are SecondLoop, a helpful personal assistant.
+IMPORTANT: Reply in the same language as the user's question. Ignore any configured UI language. Only switch languages if the user explicitly asks.
+
+Recent conversation (most recent last):
+{history}
+
+Relevant memories (quoted):
+{contexts}
+
+{actions}
+
+Answer the user's question. If the memories are irrelevant, answer normally.
+When a statement relies on a memory, cite it inline with its marker, e.g. [1] or [2][3].
+
+If you suggest actionable todos or calendar events, append ONE machine-readable block like:
+```secondloop_actions
+{"version":1,"suggestions":[{"type":"todo","title":"...","when":"..."}]}
+```
+- `suggestions[].type` must be `todo` or `event`
+- `title` is required
+- `when` is optional natural language (do NOT compute absolute dates)
+- Omit the block entirely if you have no suggestions
+
+Question: {question}
+"#;
+
+const DEFAULT_SEMANTIC_PARSE_TEMPLATE: &str = r#"You are a strict JSON generator.
+Output ONLY JSON. No markdown. No code fences. No extra text.
+
+Task: classify the user message as one of:
+- followup: updating an existing todo from the candidate list
+- create: creating a new todo
+- none: neither
+
+Return this exact JSON schema:
+{
+  "kind": "none" | "followup" | "create",
+  "confidence": number, // 0..1
+  "todo_id": string, // only when kind=followup
+  "new_status": "in_progress" | "done" | "dismissed", // only when kind=followup
+  "title": string, // only when kind=create
+  "status": "open" | "inbox", // only when kind=create
+  "due_local_iso": string | null, // only when kind=create
+  "recurrence": { // only when kind=create
+    "freq": "daily" | "weekly" | "monthly" | "yearly",
+    "interval": number // >=1
+  } | null
+}
+
+Constraints:
+- If kind=followup, todo_id MUST be one of the candidate IDs.
+- Use kind=followup ONLY when the user clearly refers to a specific candidate.
+- If the message describes a new task, use kind=create even if no candidates match.
+- If unsure, use kind=none.
+- due_local_iso must be local ISO 8601 without timezone, like 2026-02-04T15:00:00.
+- If the user provides a date but no time, use day_end_minutes.
+- The user message may be in any language; infer intent from that language.
+- recurrence is optional. If absent, set recurrence to null.
+- recurrence.freq MUST use the canonical enum values: daily|weekly|monthly|yearly.
+- recurrence.interval defaults to 1 when omitted by user intent.
+- status/new_status MUST use canonical enum values even if user text is non-English.
+
+now_local_iso: {now}
+locale: {lang}
+day_end_minutes: {day_end_minutes}
+
+todo_candidates:
+{contexts}
+
+user_message:
+{question}
+"#;
+
+const DEFAULT_MEDIA_ANNOTATION_TEMPLATE: &str = "Describe the image and respond ONLY as JSON with keys: tag (array of strings), summary (string), full_text (string). summary should be concise. full_text should contain readable text from the image when available, otherwise use an empty string. If text with visual layout is present, full_text should use Markdown and preserve layout (headings, lists, tables, line breaks) as much as possible. Respond in the user's device language ({lang}).";
+
+/// Template text equivalent to the built-in prompt for `purpose`, as a
+/// starting point for editing and for resetting a template.
+pub fn default_prompt_template(purpose: &str) -> Result<&'static str> {
+    match purpose {
+        PURPOSE_ASK_AI => Ok(DEFAULT_ASK_AI_TEMPLATE),
+        PURPOSE_SEMANTIC_PARSE => Ok(DEFAULT_SEMANTIC_PARSE_TEMPLATE),
+        PURPOSE_MEDIA_ANNOTATION => Ok(DEFAULT_MEDIA_ANNOTATION_TEMPLATE),
+        _ => Err(anyhow!("unsupported prompt purpose: {purpose}")),
+    }
+}
+
+/// Current UTC time for `{now}` where no local time is known.
+pub fn now_utc_rfc3339() -> String {
+    time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_default()
+}
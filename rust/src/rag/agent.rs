@@ -0,0 +1,421 @@
+use anyhow::{anyhow, Result};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+use std::path::Path;
+
+use super::{build_recent_conversation_history, now_ms, AskAiResult};
+use crate::db;
+use crate::llm::budget::ContextBudget;
+use crate::llm::tools::{ToolCall, ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec};
+use crate::llm::ChatDelta;
+
+pub const DEFAULT_MAX_AGENT_STEPS: usize = 6;
+
+/// ChatDelta role prefix carrying `{"name":..,"arguments":..}` for every tool
+/// call the agent runs, so the UI can show what happened.
+pub const TOOL_CALL_ROLE_PREFIX: &str = "secondloop_tool_call:";
+
+const MAX_TOOL_RESULT_CHARS: usize = 6000;
+const MAX_LISTED_ITEMS: usize = 50;
+const DEFAULT_SEARCH_LIMIT: usize = 5;
+const MAX_SEARCH_LIMIT: usize = 10;
+const TODO_STATUSES: [&str; 4] = ["open", "in_progress", "done", "dismissed"];
+
+pub const TOOL_SEARCH_MEMORIES: &str = "search_memories";
+pub const TOOL_LIST_TODOS: &str = "list_todos";
+pub const TOOL_LIST_EVENTS: &str = "list_events";
+pub const TOOL_CREATE_TODO: &str = "create_todo";
+pub const TOOL_UPDATE_TODO: &str = "update_todo";
+pub const TOOL_READ_ATTACHMENT_TEXT: &str = "read_attachment_text";
+
+fn tool(name: &str, description: &str, parameters: Value) -> ToolSpec {
+    ToolSpec {
+        name: name.to_string(),
+        description: description.to_string(),
+        parameters,
+    }
+}
+
+/// The tools offered to the model in agent mode.
+pub fn agent_tools() -> Vec<ToolSpec> {
+    vec![
+        tool(
+            TOOL_SEARCH_MEMORIES,
+            "Semantic search over the user's saved messages and notes.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "What to look for."},
+                    "limit": {"type": "integer", "description": "Maximum results, 1-10."}
+                },
+                "required": ["query"]
+            }),
+        ),
+        tool(
+            TOOL_LIST_TODOS,
+            "List todos, optionally only those due within a time range.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "status": {"type": "string", "enum": ["open", "done", "all"]},
+                    "due_start_ms": {"type": "integer", "description": "Inclusive, epoch ms."},
+                    "due_end_ms": {"type": "integer", "description": "Exclusive, epoch ms."}
+                }
+            }),
+        ),
+        tool(
+            TOOL_LIST_EVENTS,
+            "List calendar events overlapping a time range, with recurring events expanded.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "start_ms": {"type": "integer", "description": "Inclusive, epoch ms."},
+                    "end_ms": {"type": "integer", "description": "Exclusive, epoch ms."}
+                },
+                "required": ["start_ms", "end_ms"]
+            }),
+        ),
+        tool(
+            TOOL_CREATE_TODO,
+            "Create a todo. Only use this when the user asks for it.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "title": {"type": "string"},
+                    "due_at_ms": {"type": "integer", "description": "Epoch ms."}
+                },
+                "required": ["title"]
+            }),
+        ),
+        tool(
+            TOOL_UPDATE_TODO,
+            "Change the title, due time or status of an existing todo. Only use this when the user asks for it.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "todo_id": {"type": "string"},
+                    "title": {"type": "string"},
+                    "due_at_ms": {"type": "integer", "description": "Epoch ms."},
+                    "status": {"type": "string", "enum": TODO_STATUSES}
+                },
+                "required": ["todo_id"]
+            }),
+        ),
+        tool(
+            TOOL_READ_ATTACHMENT_TEXT,
+            "Read the extracted text of an attachment, e.g. a document, OCR or transcript.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "attachment_sha256": {"type": "string"}
+                },
+                "required": ["attachment_sha256"]
+            }),
+        ),
+    ]
+}
+
+fn format_utc(ms: i64) -> String {
+    time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(ms) * 1_000_000)
+        .ok()
+        .and_then(|dt| {
+            dt.format(&time::format_description::well_known::Rfc3339)
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+fn build_agent_prompt(question: &str, history: Option<&str>, now: i64) -> String {
+    let mut out = String::new();
+    out.push_str("You are SecondLoop, a helpful personal assistant with access to the user's notes, todos and calendar through tools.\n");
+    out.push_str("IMPORTANT: Reply in the same language as the user's question. Ignore any configured UI language. Only switch languages if the user explicitly asks.\n");
+    out.push_str("Use the tools to look things up instead of guessing. Only create or change todos when the user asks you to.\n");
+    out.push_str(&format!(
+        "Times are Unix epoch milliseconds. Current time: {now} ({}).\n",
+        format_utc(now)
+    ));
+
+    if let Some(history) = history {
+        if !history.trim().is_empty() {
+            out.push_str("\nRecent conversation (most recent last):\n");
+            out.push_str(history);
+        }
+    }
+
+    out.push_str("\nQuestion: ");
+    out.push_str(question);
+    out.push('\n');
+    out
+}
+
+fn arg_str<'a>(args: &'a Value, name: &str) -> Option<&'a str> {
+    args.get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+fn arg_i64(args: &Value, name: &str) -> Result<Option<i64>> {
+    match args.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_i64()
+            .or_else(|| v.as_f64().map(|f| f as i64))
+            .map(Some)
+            .ok_or_else(|| anyhow!("`{name}` must be an integer")),
+    }
+}
+
+fn todo_json(todo: &db::Todo) -> Value {
+    json!({
+        "todo_id": todo.id,
+        "title": todo.title,
+        "status": todo.status,
+        "due_at_ms": todo.due_at_ms,
+    })
+}
+
+/// Runs one tool call and returns its JSON result.
+pub fn run_agent_tool(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    call: &ToolCall,
+) -> Result<Value> {
+    let args = &call.arguments;
+    if !args.is_object() {
+        return Err(anyhow!("arguments must be a JSON object"));
+    }
+
+    match call.name.as_str() {
+        TOOL_SEARCH_MEMORIES => {
+            let query = arg_str(args, "query").ok_or_else(|| anyhow!("`query` is required"))?;
+            let limit = arg_i64(args, "limit")?
+                .map(|v| v.clamp(1, MAX_SEARCH_LIMIT as i64) as usize)
+                .unwrap_or(DEFAULT_SEARCH_LIMIT);
+            db::process_pending_message_embeddings_active(conn, key, app_dir, 1024)?;
+            let mut results = Vec::new();
+            for similar in db::search_similar_messages_active(conn, key, app_dir, query, limit)? {
+                let message = similar.message;
+                let attachments = db::list_message_attachments(conn, key, &message.id)?
+                    .into_iter()
+                    .map(|attachment| attachment.sha256)
+                    .collect::<Vec<_>>();
+                results.push(json!({
+                    "message_id": message.id,
+                    "created_at_ms": message.created_at_ms,
+                    "text": message.content,
+                    "attachment_sha256s": attachments,
+                }));
+            }
+            Ok(json!({ "results": results }))
+        }
+        TOOL_LIST_TODOS => {
+            let status = arg_str(args, "status").unwrap_or("open");
+            let due_start = arg_i64(args, "due_start_ms")?;
+            let due_end = arg_i64(args, "due_end_ms")?;
+            let todos = db::list_todos(conn, key)?
+                .into_iter()
+                .filter(|todo| match status {
+                    "all" => true,
+                    "done" => todo.status == "done",
+                    _ => todo.status != "done" && todo.status != "dismissed",
+                })
+                .filter(|todo| {
+                    if due_start.is_none() && due_end.is_none() {
+                        return true;
+                    }
+                    todo.due_at_ms.is_some_and(|due| {
+                        due_start.is_none_or(|start| due >= start)
+                            && due_end.is_none_or(|end| due < end)
+                    })
+                })
+                .take(MAX_LISTED_ITEMS)
+                .map(|todo| todo_json(&todo))
+                .collect::<Vec<_>>();
+            Ok(json!({ "todos": todos }))
+        }
+        TOOL_LIST_EVENTS => {
+            let start =
+                arg_i64(args, "start_ms")?.ok_or_else(|| anyhow!("`start_ms` is required"))?;
+            let end = arg_i64(args, "end_ms")?.ok_or_else(|| anyhow!("`end_ms` is required"))?;
+            let events = db::list_event_occurrences_in_range(conn, key, start, end)?
+                .into_iter()
+                .take(MAX_LISTED_ITEMS)
+                .map(|occurrence| {
+                    json!({
+                        "event_id": occurrence.event_id,
+                        "title": occurrence.title,
+                        "start_at_ms": occurrence.start_at_ms,
+                        "end_at_ms": occurrence.end_at_ms,
+                        "tz": occurrence.tz,
+                        "is_recurring": occurrence.is_recurring,
+                    })
+                })
+                .collect::<Vec<_>>();
+            Ok(json!({ "events": events }))
+        }
+        TOOL_CREATE_TODO => {
+            let title = arg_str(args, "title").ok_or_else(|| anyhow!("`title` is required"))?;
+            let due_at_ms = arg_i64(args, "due_at_ms")?;
+            let id = format!("todo:{}", uuid::Uuid::new_v4());
+            let todo = db::upsert_todo(
+                conn, key, &id, title, due_at_ms, "open", None, None, None, None,
+            )?;
+            Ok(json!({ "created": todo_json(&todo) }))
+        }
+        TOOL_UPDATE_TODO => {
+            let todo_id =
+                arg_str(args, "todo_id").ok_or_else(|| anyhow!("`todo_id` is required"))?;
+            let mut todo = db::get_todo(conn, key, todo_id)?;
+            let title = arg_str(args, "title");
+            let due_at_ms = arg_i64(args, "due_at_ms")?;
+            if title.is_some() || due_at_ms.is_some() {
+                todo = db::upsert_todo(
+                    conn,
+                    key,
+                    &todo.id,
+                    title.unwrap_or(&todo.title),
+                    due_at_ms.or(todo.due_at_ms),
+                    &todo.status,
+                    todo.source_entry_id.as_deref(),
+                    todo.review_stage,
+                    todo.next_review_at_ms,
+                    todo.last_review_at_ms,
+                )?;
+            }
+            if let Some(status) = arg_str(args, "status") {
+                if !TODO_STATUSES.contains(&status) {
+                    return Err(anyhow!("unknown todo status: {status}"));
+                }
+                if status != todo.status {
+                    todo = db::set_todo_status(conn, key, &todo.id, status, None)?;
+                }
+            }
+            Ok(json!({ "updated": todo_json(&todo) }))
+        }
+        TOOL_READ_ATTACHMENT_TEXT => {
+            let sha256 = arg_str(args, "attachment_sha256")
+                .ok_or_else(|| anyhow!("`attachment_sha256` is required"))?;
+            let text = db::read_attachment_text(conn, key, sha256)?;
+            Ok(json!({ "text": text }))
+        }
+        other => Err(anyhow!("unknown tool: {other}")),
+    }
+}
+
+fn tool_result_content(result: Result<Value>) -> String {
+    let value = match result {
+        Ok(value) => value,
+        Err(e) => json!({ "error": e.to_string() }),
+    };
+    let content = value.to_string();
+    if content.chars().count() <= MAX_TOOL_RESULT_CHARS {
+        return content;
+    }
+    // Truncated JSON is still readable for the model.
+    let mut truncated: String = content.chars().take(MAX_TOOL_RESULT_CHARS).collect();
+    truncated.push_str("…(truncated)");
+    truncated
+}
+
+/// Answers `question` in agent mode. The model may call `agent_tools()` for
+/// up to `max_steps` turns before it has to answer; mutations go through the
+/// regular `db` functions, so they are logged and synced. Tool calls are reported
+/// through `on_event` with `TOOL_CALL_ROLE_PREFIX`, then the answer is
+/// emitted and both messages are stored like a regular Ask AI exchange.
+#[allow(clippy::too_many_arguments)]
+pub fn ask_ai_agent(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    conversation_id: &str,
+    question: &str,
+    max_steps: usize,
+    provider: &(impl ToolCallingProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    let history = build_recent_conversation_history(
+        conn,
+        key,
+        conversation_id,
+        ContextBudget::for_context_window(provider.context_window_tokens()).history_tokens,
+        provider.token_counter(),
+    )?;
+    let mut messages = vec![ToolMessage::User(build_agent_prompt(
+        question,
+        history.as_deref(),
+        now_ms(),
+    ))];
+    let tools = agent_tools();
+
+    let mut answer = None;
+    for _ in 0..max_steps {
+        let turn = provider.complete_with_tools(&messages, &tools, ToolChoice::Auto)?;
+        if turn.tool_calls.is_empty() {
+            answer = Some(turn.text);
+            break;
+        }
+
+        let calls = turn.tool_calls.clone();
+        messages.push(ToolMessage::Assistant {
+            text: turn.text,
+            tool_calls: turn.tool_calls,
+        });
+        for call in calls {
+            on_event(ChatDelta {
+                role: Some(format!(
+                    "{TOOL_CALL_ROLE_PREFIX}{}",
+                    json!({ "name": call.name, "arguments": call.arguments })
+                )),
+                text_delta: String::new(),
+                done: false,
+                usage: None,
+            })?;
+            let content = tool_result_content(run_agent_tool(conn, key, app_dir, &call));
+            messages.push(ToolMessage::ToolResult {
+                call_id: call.id,
+                name: call.name,
+                content,
+            });
+        }
+    }
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            // Out of steps: ask for an answer with what was gathered so far.
+            messages.push(ToolMessage::User(
+                "Stop calling tools and answer the question now.".to_string(),
+            ));
+            provider
+                .complete_with_tools(&messages, &tools, ToolChoice::None)?
+                .text
+        }
+    };
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(anyhow!("empty response from LLM"));
+    }
+
+    on_event(ChatDelta {
+        role: Some("assistant".to_string()),
+        text_delta: answer.to_string(),
+        done: false,
+        usage: None,
+    })?;
+    on_event(ChatDelta {
+        role: None,
+        text_delta: String::new(),
+        done: true,
+        usage: None,
+    })?;
+
+    let user_message = db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
+    let assistant_message =
+        db::insert_message_non_memory(conn, key, conversation_id, "assistant", answer)?;
+    Ok(AskAiResult {
+        user_message_id: user_message.id,
+        assistant_message_id: assistant_message.id,
+    })
+}
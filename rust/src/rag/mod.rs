@@ -3,14 +3,20 @@ use rusqlite::Connection;
 use std::path::Path;
 
 use crate::db;
-use crate::embedding::Embedder;
+use crate::embedding::{Embedder, Reranker};
+use crate::llm::budget::{ContextBudget, TokenCounter};
 use crate::llm::ChatDelta;
+use crate::prompt_templates::{self, PromptVariables};
+
+pub mod agent;
 
-const DEFAULT_MAX_CONTEXT_CHARS: usize = 6000;
 const DEFAULT_MAX_HISTORY_MESSAGES: usize = 6;
 const DEFAULT_MAX_HISTORY_MESSAGE_CHARS: usize = 1200;
 const DEFAULT_COMPRESS_SENTENCES: usize = 3;
 const DEFAULT_MMR_LAMBDA: f64 = 0.55;
+const MAX_RERANK_CANDIDATES: usize = 40;
+const MAX_REWRITTEN_QUERIES: usize = 3;
+const MAX_REWRITTEN_QUERY_CHARS: usize = 300;
 
 #[derive(Debug)]
 pub struct StreamCancelled;
@@ -41,14 +47,73 @@ pub trait AnswerProvider {
         prompt: &str,
         on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
     ) -> Result<()>;
+
+    /// Like `stream_answer`, but asks for a single JSON value matching
+    /// `schema`, using the provider's JSON mode or structured outputs when it
+    /// has them. Callers still validate what comes back.
+    fn stream_answer_json(
+        &self,
+        prompt: &str,
+        _schema: &serde_json::Value,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.stream_answer(prompt, on_event)
+    }
+
+    /// Context window of the model behind this provider, in tokens, when
+    /// known. Used to size retrieved contexts and history.
+    fn context_window_tokens(&self) -> Option<usize> {
+        None
+    }
+
+    /// How the model behind this provider counts tokens.
+    fn token_counter(&self) -> TokenCounter {
+        TokenCounter::Estimate
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum ContextSource {
     Message,
     TodoThread,
     Event,
     TodoActivity,
+    AttachmentChunk,
+}
+
+impl ContextSource {
+    fn citation_kind(self) -> &'static str {
+        match self {
+            ContextSource::Message => db::CITATION_SOURCE_MESSAGE,
+            ContextSource::TodoThread => db::CITATION_SOURCE_TODO,
+            ContextSource::Event => db::CITATION_SOURCE_EVENT,
+            ContextSource::TodoActivity => db::CITATION_SOURCE_TODO_ACTIVITY,
+            ContextSource::AttachmentChunk => db::CITATION_SOURCE_ATTACHMENT_CHUNK,
+        }
+    }
+}
+
+/// ChatDelta role prefix carrying the JSON citation list for the prompt's
+/// `[n]` markers. Emitted once, before the answer text.
+pub const CITATIONS_ROLE_PREFIX: &str = "secondloop_citations:";
+
+/// A quoted prompt context and the record it was built from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourcedContext {
+    /// One of the `db::CITATION_SOURCE_*` kinds.
+    pub source_kind: String,
+    pub source_id: String,
+    pub text: String,
+}
+
+impl SourcedContext {
+    fn new(source: ContextSource, source_id: &str, text: String) -> Self {
+        Self {
+            source_kind: source.citation_kind().to_string(),
+            source_id: source_id.to_string(),
+            text,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -58,6 +123,8 @@ struct ContextItem {
     created_at_ms: i64,
     distance: Option<f64>,
     text: String,
+    /// Page or time span within the source, for attachment chunks.
+    location: Option<String>,
 }
 
 fn now_ms() -> i64 {
@@ -110,14 +177,78 @@ fn agenda_horizon_ms(question: &str, now_ms: i64) -> Option<i64> {
     None
 }
 
+/// Questions about subtasks or "what's blocking X" need the todo hierarchy
+/// even when they don't mention a time range.
+fn is_todo_hierarchy_question(question: &str) -> bool {
+    let q = question.trim().to_lowercase();
+    q.contains("block")
+        || q.contains("depend")
+        || q.contains("waiting on")
+        || q.contains("subtask")
+        || q.contains("sub-task")
+        || q.contains("checklist")
+        || question.contains("阻塞")
+        || question.contains("卡住")
+        || question.contains("依赖")
+        || question.contains("依賴")
+        || question.contains("子任务")
+        || question.contains("子任務")
+}
+
 fn should_include_actions_context(question: &str) -> bool {
-    agenda_horizon_ms(question, 0).is_some()
+    agenda_horizon_ms(question, 0).is_some() || is_todo_hierarchy_question(question)
+}
+
+fn describe_todo_hierarchy(
+    conn: &Connection,
+    key: &[u8; 32],
+    todo: &db::Todo,
+    titles: &std::collections::HashMap<String, String>,
+) -> Result<Option<String>> {
+    let rollup = db::get_todo_rollup(conn, key, &todo.id)?;
+    let blockers = db::list_todo_blockers(conn, key, &todo.id)?;
+    let blocks = db::list_todos_blocked_by(conn, key, &todo.id)?;
+
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(title) = rollup.parent_id.as_ref().and_then(|id| titles.get(id)) {
+        parts.push(format!("subtask of \"{title}\""));
+    }
+    if rollup.subtasks_total > 0 {
+        parts.push(format!(
+            "subtasks {}/{} done",
+            rollup.subtasks_done, rollup.subtasks_total
+        ));
+    }
+    if !blockers.is_empty() {
+        let list = blockers
+            .iter()
+            .map(|b| format!("\"{}\" [{}]", b.title, b.status))
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!("blocked by {list}"));
+    }
+    if !blocks.is_empty() {
+        let list = blocks
+            .iter()
+            .map(|b| format!("\"{}\"", b.title))
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!("blocks {list}"));
+    }
+
+    if parts.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(parts.join("; ")))
 }
 
+/// Todos, events and reviews coming up for agenda-style questions. Agenda
+/// days follow `tz`, the user's IANA timezone (UTC when `None` or unknown).
 fn build_actions_context(
     conn: &Connection,
     key: &[u8; 32],
     question: &str,
+    tz: Option<&str>,
 ) -> Result<Option<String>> {
     if !should_include_actions_context(question) {
         return Ok(None);
@@ -125,9 +256,15 @@ fn build_actions_context(
 
     let now = now_ms();
     let horizon = agenda_horizon_ms(question, now).unwrap_or(now);
+    let include_hierarchy = is_todo_hierarchy_question(question);
     let mut lines: Vec<String> = Vec::new();
 
-    for todo in db::list_todos(conn, key)? {
+    let todos = db::list_todos(conn, key)?;
+    let titles = todos
+        .iter()
+        .map(|todo| (todo.id.clone(), todo.title.clone()))
+        .collect::<std::collections::HashMap<_, _>>();
+    for todo in &todos {
         if todo.status == "done" || todo.status == "dismissed" {
             continue;
         }
@@ -136,7 +273,13 @@ fn build_actions_context(
         let review = todo.next_review_at_ms;
         let is_due = due.is_some_and(|ms| ms <= horizon);
         let is_review_due = review.is_some_and(|ms| ms <= horizon);
-        if !is_due && !is_review_due {
+        // The hierarchy costs queries per todo; only describe todos that may be listed.
+        if !is_due && !is_review_due && !include_hierarchy {
+            continue;
+        }
+        let hierarchy = describe_todo_hierarchy(conn, key, todo, &titles)?;
+        let is_related = include_hierarchy && hierarchy.is_some();
+        if !is_due && !is_review_due && !is_related {
             continue;
         }
 
@@ -147,20 +290,41 @@ fn build_actions_context(
         if let Some(ms) = review {
             item.push_str(&format!(" (next_review_at_ms={ms})"));
         }
+        if let Some(hierarchy) = hierarchy {
+            item.push_str(&format!(" ({hierarchy})"));
+        }
         lines.push(item);
     }
 
-    for event in db::list_events(conn, key)? {
-        if event.end_at_ms < now {
-            continue;
-        }
-        if event.start_at_ms > horizon {
-            continue;
+    // Recurring events appear once per upcoming occurrence. Todo reviews are
+    // already covered by `next_review_at_ms` above, so only messages marked
+    // for review are added here.
+    let tz = tz.and_then(db::known_timezone_name).unwrap_or("UTC");
+    let agenda = db::agenda_range(conn, key, now, horizon.saturating_add(1), tz, None, 0)?;
+    let overdue = agenda.overdue.iter().map(|item| (None, item));
+    let upcoming = agenda.days.iter().flat_map(|day| {
+        let local_day = Some(day.date.as_str());
+        day.items.iter().map(move |item| (local_day, item))
+    });
+    for (local_day, item) in overdue.chain(upcoming) {
+        let mut line = match item.kind.as_str() {
+            db::AGENDA_ITEM_EVENT => format!(
+                "EVENT {} (start_at_ms={}, end_at_ms={}, tz={}",
+                item.title,
+                item.at_ms,
+                item.end_at_ms.unwrap_or(item.at_ms),
+                item.tz.as_deref().unwrap_or("UTC")
+            ),
+            db::AGENDA_ITEM_REVIEW if item.item_id.starts_with(db::REVIEW_ITEM_MESSAGE) => {
+                format!("REVIEW {} (next_review_at_ms={}", item.title, item.at_ms)
+            }
+            _ => continue,
+        };
+        if let Some(local_day) = local_day {
+            line.push_str(&format!(", local_day={local_day}"));
         }
-        lines.push(format!(
-            "EVENT {} (start_at_ms={}, end_at_ms={}, tz={})",
-            event.title, event.start_at_ms, event.end_at_ms, event.tz
-        ));
+        line.push(')');
+        lines.push(line);
     }
 
     if lines.is_empty() {
@@ -201,7 +365,7 @@ fn build_prompt_with_actions_and_history(
     if !contexts.is_empty() {
         out.push_str("\nRelevant memories (quoted):\n");
         for (i, ctx) in contexts.iter().enumerate() {
-            out.push_str(&format!("{}. \"{}\"\n", i + 1, ctx));
+            out.push_str(&format!("[{}] \"{}\"\n", i + 1, ctx));
         }
     }
 
@@ -213,6 +377,11 @@ fn build_prompt_with_actions_and_history(
     out.push_str(
         "\nAnswer the user's question. If the memories are irrelevant, answer normally.\n",
     );
+    if !contexts.is_empty() {
+        out.push_str(
+            "When a statement relies on a memory, cite it inline with its marker, e.g. [1] or [2][3].\n",
+        );
+    }
     out.push_str(
         "\nIf you suggest actionable todos or calendar events, append ONE machine-readable block like:\n",
     );
@@ -228,10 +397,88 @@ fn build_prompt_with_actions_and_history(
     out
 }
 
+/// Renders the Ask AI template selected for the active LLM profile, or the
+/// built-in prompt when none is selected.
+fn build_ask_ai_prompt(
+    conn: &Connection,
+    key: &[u8; 32],
+    question: &str,
+    contexts: &[String],
+    actions: Option<&str>,
+    history: Option<&str>,
+) -> Result<String> {
+    let Some(template) =
+        db::resolve_prompt_template(conn, key, prompt_templates::PURPOSE_ASK_AI, None)?
+    else {
+        return Ok(build_prompt_with_actions_and_history(
+            question, contexts, actions, history,
+        ));
+    };
+
+    let quoted = if contexts.is_empty() {
+        "(none)".to_string()
+    } else {
+        contexts
+            .iter()
+            .enumerate()
+            .map(|(i, ctx)| format!("[{}] \"{}\"", i + 1, ctx))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let history = history
+        .map(str::trim)
+        .filter(|history| !history.is_empty())
+        .unwrap_or("(none)");
+    let variables = PromptVariables::new()
+        .set("question", question)
+        .set("contexts", quoted)
+        .set("history", history)
+        .set("actions", actions.map(str::trim).unwrap_or_default())
+        .set("now", prompt_templates::now_utc_rfc3339());
+    Ok(prompt_templates::render_prompt_template(
+        &template, &variables,
+    ))
+}
+
+/// Formats history (newest first in `kept`) oldest first, dropping the oldest
+/// messages that do not fit in `max_tokens`.
+fn format_recent_history(
+    kept: Vec<(String, String)>,
+    max_tokens: usize,
+    counter: TokenCounter,
+) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut used_tokens: usize = 0;
+    for (role, content) in kept {
+        let line = format!("{role}: {content}\n");
+        let tokens = counter.count(&line);
+        if used_tokens.saturating_add(tokens) > max_tokens {
+            if lines.is_empty() {
+                // Keep the start of the latest message rather than nothing.
+                let line = counter.truncate(&line, max_tokens).trim_end();
+                if !line.is_empty() {
+                    lines.push(format!("{line}\n"));
+                }
+            }
+            break;
+        }
+        used_tokens += tokens;
+        lines.push(line);
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.concat())
+}
+
 fn build_recent_conversation_history(
     conn: &Connection,
     key: &[u8; 32],
     conversation_id: &str,
+    max_tokens: usize,
+    counter: TokenCounter,
 ) -> Result<Option<String>> {
     let page = db::list_messages_page(conn, key, conversation_id, None, None, 32)?;
 
@@ -258,21 +505,7 @@ fn build_recent_conversation_history(
         }
     }
 
-    if kept.is_empty() {
-        return Ok(None);
-    }
-
-    kept.reverse();
-
-    let mut out = String::new();
-    for (role, content) in kept {
-        out.push_str(&role);
-        out.push_str(": ");
-        out.push_str(&content);
-        out.push('\n');
-    }
-
-    Ok(Some(out))
+    Ok(format_recent_history(kept, max_tokens, counter))
 }
 
 fn build_recent_conversation_history_in_range(
@@ -281,6 +514,8 @@ fn build_recent_conversation_history_in_range(
     conversation_id: &str,
     start_at_ms_inclusive: i64,
     end_at_ms_exclusive: i64,
+    max_tokens: usize,
+    counter: TokenCounter,
 ) -> Result<Option<String>> {
     // Use a larger page so that "last week" (or similar) can skip current messages and still
     // include enough in-range history.
@@ -316,21 +551,57 @@ fn build_recent_conversation_history_in_range(
         }
     }
 
-    if kept.is_empty() {
-        return Ok(None);
+    Ok(format_recent_history(kept, max_tokens, counter))
+}
+
+fn attachment_chunk_source_id(chunk: &db::AttachmentChunk) -> String {
+    format!("{}:{}", chunk.attachment_sha256, chunk.chunk_index)
+}
+
+fn format_offset_ms(ms: i64) -> String {
+    let total_secs = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (total_secs / 3600, (total_secs / 60) % 60, total_secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
     }
+}
 
-    kept.reverse();
+fn attachment_chunk_location(chunk: &db::AttachmentChunk) -> Option<String> {
+    if let Some(page) = chunk.page {
+        return Some(format!("page={page}"));
+    }
+    let start_ms = chunk.start_ms?;
+    let end_ms = chunk.end_ms.unwrap_or(start_ms);
+    Some(format!(
+        "time={}-{}",
+        format_offset_ms(start_ms),
+        format_offset_ms(end_ms)
+    ))
+}
 
-    let mut out = String::new();
-    for (role, content) in kept {
-        out.push_str(&role);
-        out.push_str(": ");
-        out.push_str(&content);
-        out.push('\n');
+fn attachment_chunk_context(chunk: &db::AttachmentChunk) -> String {
+    match attachment_chunk_location(chunk) {
+        Some(location) => format!("ATTACHMENT {location}\n{}", chunk.text),
+        None => chunk.text.clone(),
     }
+}
 
-    Ok(Some(out))
+/// Prompt text for a retrieved message. Messages whose attachment already
+/// matched at chunk level skip the attachment excerpt, since the chunk
+/// quotes the relevant part.
+fn message_context(
+    conn: &Connection,
+    key: &[u8; 32],
+    message: &db::Message,
+    chunk_message_ids: &std::collections::HashSet<String>,
+) -> String {
+    if chunk_message_ids.contains(&message.id) {
+        return message.content.clone();
+    }
+    db::build_message_rag_context(conn, key, &message.id, &message.content)
+        .unwrap_or_else(|_| message.content.clone())
 }
 
 fn build_todo_thread_context(conn: &Connection, key: &[u8; 32], todo_id: &str) -> Result<String> {
@@ -581,9 +852,48 @@ fn compress_context_text(query: &str, text: &str) -> String {
     selected.join("\n")
 }
 
-fn rank_context_items(question: &str, candidates: &[ContextItem]) -> Vec<(f64, usize)> {
+/// Cross-encoder scores for the most promising candidates, `None` for the
+/// rest or when no reranker is available or it fails.
+fn rerank_context_items(
+    question: &str,
+    candidates: &[ContextItem],
+    reranker: Option<&dyn Reranker>,
+) -> Vec<Option<f64>> {
+    let mut out = vec![None; candidates.len()];
+    let Some(reranker) = reranker else {
+        return out;
+    };
+
+    // Cross-encoding is slow, so only the heuristic front-runners are scored.
+    let shortlist: Vec<usize> = rank_context_items(question, candidates, &out)
+        .into_iter()
+        .take(MAX_RERANK_CANDIDATES)
+        .map(|(_, idx)| idx)
+        .collect();
+    let documents: Vec<String> = shortlist
+        .iter()
+        .map(|&idx| candidates[idx].text.clone())
+        .collect();
+    let Ok(scores) = reranker.score(question, &documents) else {
+        return out;
+    };
+    if scores.len() != shortlist.len() {
+        return out;
+    }
+    for (idx, score) in shortlist.into_iter().zip(scores) {
+        out[idx] = Some(f64::from(score).clamp(0.0, 1.0));
+    }
+    out
+}
+
+fn rank_context_items(
+    question: &str,
+    candidates: &[ContextItem],
+    rerank_scores: &[Option<f64>],
+) -> Vec<(f64, usize)> {
     let now = now_ms();
     let mut scored: Vec<(f64, usize)> = Vec::new();
+    let reranked = rerank_scores.iter().any(Option::is_some);
 
     for (i, item) in candidates.iter().enumerate() {
         let semantic = item
@@ -608,7 +918,14 @@ fn rank_context_items(question: &str, candidates: &[ContextItem]) -> Vec<(f64, u
         let lexical_w = if item.distance.is_some() { 0.3 } else { 0.9 };
         let recency_w = 0.1;
 
-        let relevance = (semantic_w * semantic) + (lexical_w * lexical) + (recency_w * recency);
+        let relevance = match rerank_scores.get(i).copied().flatten() {
+            Some(rerank) => (0.85 * rerank) + (0.15 * recency),
+            // Unscored candidates stay behind every cross-encoded one.
+            None if reranked => {
+                (semantic_w * semantic) + (lexical_w * lexical) + (recency_w * recency) - 1.0
+            }
+            None => (semantic_w * semantic) + (lexical_w * lexical) + (recency_w * recency),
+        };
         scored.push((relevance, i));
     }
 
@@ -616,8 +933,13 @@ fn rank_context_items(question: &str, candidates: &[ContextItem]) -> Vec<(f64, u
     scored
 }
 
-fn mmr_select_indices(question: &str, candidates: &[ContextItem], max_items: usize) -> Vec<usize> {
-    let ranked = rank_context_items(question, candidates);
+fn mmr_select_indices(
+    question: &str,
+    candidates: &[ContextItem],
+    max_items: usize,
+    rerank_scores: &[Option<f64>],
+) -> Vec<usize> {
+    let ranked = rank_context_items(question, candidates, rerank_scores);
     let max_items = max_items.min(ranked.len());
     if max_items == 0 {
         return Vec::new();
@@ -666,13 +988,42 @@ fn mmr_select_indices(question: &str, candidates: &[ContextItem], max_items: usi
     selected
 }
 
-fn build_contexts_v2(question: &str, candidates: Vec<ContextItem>, top_k: usize) -> Vec<String> {
+/// Keeps contexts in order while they fit in `max_tokens`. A first context
+/// that is too large on its own is truncated rather than dropped.
+fn fit_contexts_to_budget(
+    contexts: Vec<SourcedContext>,
+    max_tokens: usize,
+    counter: TokenCounter,
+) -> Vec<SourcedContext> {
+    let mut out = Vec::new();
+    let mut used_tokens: usize = 0;
+    for mut ctx in contexts {
+        let tokens = counter.count(&ctx.text);
+        if used_tokens.saturating_add(tokens) > max_tokens {
+            if out.is_empty() {
+                ctx.text = counter.truncate(&ctx.text, max_tokens).to_string();
+                out.push(ctx);
+            }
+            break;
+        }
+        used_tokens += tokens;
+        out.push(ctx);
+    }
+    out
+}
+
+fn build_contexts_v2(
+    question: &str,
+    candidates: Vec<ContextItem>,
+    top_k: usize,
+    reranker: Option<&dyn Reranker>,
+) -> Vec<SourcedContext> {
     let max_items = top_k.max(1);
-    let selected_indices = mmr_select_indices(question, &candidates, max_items);
+    let rerank_scores = rerank_context_items(question, &candidates, reranker);
+    let selected_indices = mmr_select_indices(question, &candidates, max_items, &rerank_scores);
 
-    let mut out: Vec<String> = Vec::new();
+    let mut out: Vec<SourcedContext> = Vec::new();
     let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
-    let mut used_chars: usize = 0;
 
     for idx in selected_indices {
         let item = &candidates[idx];
@@ -687,6 +1038,10 @@ fn build_contexts_v2(question: &str, candidates: Vec<ContextItem>, top_k: usize)
             ContextSource::TodoThread => Some(format!("TODO_THREAD id={}\n", item.id)),
             ContextSource::Event => Some(format!("EVENT id={}\n", item.id)),
             ContextSource::TodoActivity => Some(format!("TODO_ACTIVITY id={}\n", item.id)),
+            ContextSource::AttachmentChunk => Some(match item.location.as_deref() {
+                Some(location) => format!("ATTACHMENT {location}\n"),
+                None => "ATTACHMENT\n".to_string(),
+            }),
         };
         if let Some(p) = prefix {
             let mut combined = String::with_capacity(p.len() + text.len());
@@ -699,27 +1054,239 @@ fn build_contexts_v2(question: &str, candidates: Vec<ContextItem>, top_k: usize)
             continue;
         }
 
-        let len = text.len();
-        if used_chars > 0 && used_chars.saturating_add(len) > DEFAULT_MAX_CONTEXT_CHARS {
-            break;
+        out.push(SourcedContext::new(item.source, &item.id, text));
+    }
+
+    out
+}
+
+/// Retrieval queries for one Ask AI turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchQueries {
+    pub queries: Vec<String>,
+    /// Whether producing the queries took an LLM call (for usage accounting).
+    pub llm_called: bool,
+}
+
+fn query_rewrite_cache_key(history: &str, question: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(format!("v1\n{history}\n\u{1f}{}", question.trim()).as_bytes());
+    let mut out = String::with_capacity(digest.len() * 2);
+    for b in digest {
+        use std::fmt::Write;
+        let _ = write!(&mut out, "{:02x}", b);
+    }
+    out
+}
+
+/// Rewrites a follow-up question into standalone search queries using the
+/// recent conversation. Questions without history are searched as is, rewrites
+/// are cached per question and history, and a failed rewrite falls back to the
+/// question.
+pub fn rewrite_search_queries(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    question: &str,
+    provider: &dyn AnswerProvider,
+) -> Result<SearchQueries> {
+    let question_only = SearchQueries {
+        queries: vec![question.trim().to_string()],
+        llm_called: false,
+    };
+    let history_tokens =
+        ContextBudget::for_context_window(provider.context_window_tokens()).history_tokens;
+    let Some(history) = build_recent_conversation_history(
+        conn,
+        key,
+        conversation_id,
+        history_tokens,
+        provider.token_counter(),
+    )?
+    else {
+        return Ok(question_only);
+    };
+
+    let cache_key = query_rewrite_cache_key(&history, question);
+    if let Some(queries) = db::get_cached_query_rewrite(conn, key, &cache_key)? {
+        return Ok(SearchQueries {
+            queries,
+            llm_called: false,
+        });
+    }
+
+    let rewritten =
+        crate::semantic_parse::semantic_parse_ask_ai_search_queries(provider, question, &history);
+    let Ok(rewritten) = rewritten else {
+        return Ok(SearchQueries {
+            llm_called: true,
+            ..question_only
+        });
+    };
+
+    let mut queries: Vec<String> = Vec::new();
+    for query in rewritten {
+        let query: String = query.chars().take(MAX_REWRITTEN_QUERY_CHARS).collect();
+        if !queries.contains(&query) {
+            queries.push(query);
         }
-        if used_chars == 0 && len > DEFAULT_MAX_CONTEXT_CHARS {
-            // Still include one context rather than returning empty.
-            out.push(text);
+        if queries.len() >= MAX_REWRITTEN_QUERIES {
             break;
         }
-
-        used_chars = used_chars.saturating_add(len);
-        out.push(text);
     }
+    db::put_cached_query_rewrite(conn, key, &cache_key, &queries)?;
+    Ok(SearchQueries {
+        queries,
+        llm_called: true,
+    })
+}
 
-    out
+/// Retrieval queries for an Ask AI turn, shared by every Ask AI path: the
+/// rewritten queries when query rewriting is enabled and the turn retrieves
+/// memories, otherwise none (retrieval then searches with the question).
+pub fn search_queries_for_question(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    question: &str,
+    top_k: usize,
+    rewriter: &dyn AnswerProvider,
+) -> Result<SearchQueries> {
+    if top_k == 0 || !db::get_ask_ai_query_rewrite_enabled(conn)? {
+        return Ok(SearchQueries {
+            queries: Vec::new(),
+            llm_called: false,
+        });
+    }
+    rewrite_search_queries(conn, key, conversation_id, question, rewriter)
 }
 
 pub fn build_prompt(question: &str, contexts: &[String]) -> String {
     build_prompt_with_actions(question, contexts, None)
 }
 
+/// Texts to quote in the prompt, in marker order.
+pub fn context_texts(contexts: &[SourcedContext]) -> Vec<String> {
+    contexts.iter().map(|ctx| ctx.text.clone()).collect()
+}
+
+/// Citation `[i + 1]` for the i-th prompt context.
+pub fn citations_for_contexts(contexts: &[SourcedContext]) -> Vec<db::MessageCitation> {
+    contexts
+        .iter()
+        .enumerate()
+        .map(|(i, ctx)| db::MessageCitation {
+            marker: (i + 1) as u32,
+            source_kind: ctx.source_kind.clone(),
+            source_id: ctx.source_id.clone(),
+        })
+        .collect()
+}
+
+/// Markers cited in `answer`, written as `[1]`, `[1][2]` or `[1, 2]`.
+pub fn cited_markers(answer: &str) -> std::collections::BTreeSet<u32> {
+    let mut out = std::collections::BTreeSet::new();
+    let mut rest = answer;
+    while let Some(open) = rest.find('[') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find(']') else {
+            break;
+        };
+        let inner = &rest[..close];
+        let markers = inner
+            .split(',')
+            .map(|part| part.trim().parse::<u32>())
+            .collect::<std::result::Result<Vec<_>, _>>();
+        if let Ok(markers) = markers {
+            out.extend(markers.into_iter().filter(|marker| *marker > 0));
+            rest = &rest[close + 1..];
+        }
+    }
+    out
+}
+
+fn emit_citations(
+    citations: &[db::MessageCitation],
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<()> {
+    if citations.is_empty() {
+        return Ok(());
+    }
+    let payload = citations
+        .iter()
+        .map(|citation| {
+            serde_json::json!({
+                "marker": citation.marker,
+                "source_kind": citation.source_kind,
+                "source_id": citation.source_id,
+            })
+        })
+        .collect::<Vec<_>>();
+    on_event(ChatDelta {
+        role: Some(format!(
+            "{CITATIONS_ROLE_PREFIX}{}",
+            serde_json::Value::Array(payload)
+        )),
+        text_delta: String::new(),
+        done: false,
+        usage: None,
+    })
+}
+
+/// Streams the answer to `prompt`, then stores the question, the answer and
+/// the citations the answer actually uses. The full citation list is emitted
+/// up front so markers can be resolved while the text streams.
+#[allow(clippy::too_many_arguments)]
+pub fn stream_answer_with_citations(
+    conn: &Connection,
+    key: &[u8; 32],
+    conversation_id: &str,
+    question: &str,
+    prompt: &str,
+    citations: &[db::MessageCitation],
+    provider: &(impl AnswerProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    emit_citations(citations, on_event)?;
+
+    let mut has_text = false;
+    let mut assistant_text = String::new();
+    provider.stream_answer(prompt, &mut |ev| {
+        let done = ev.done;
+        let text_delta = ev.text_delta.clone();
+        on_event(ev)?;
+
+        if !done && !text_delta.is_empty() {
+            has_text = true;
+            assistant_text.push_str(&text_delta);
+        }
+
+        Ok(())
+    })?;
+
+    if !has_text {
+        return Err(anyhow!("empty response from LLM"));
+    }
+
+    let user_message = db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
+    let assistant_message =
+        db::insert_message_non_memory(conn, key, conversation_id, "assistant", &assistant_text)?;
+
+    let cited = cited_markers(&assistant_text);
+    let used = citations
+        .iter()
+        .filter(|citation| cited.contains(&citation.marker))
+        .cloned()
+        .collect::<Vec<_>>();
+    db::set_message_citations(conn, key, &assistant_message.id, &used)?;
+
+    Ok(AskAiResult {
+        user_message_id: user_message.id,
+        assistant_message_id: assistant_message.id,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn ask_ai_with_provider(
     conn: &Connection,
     key: &[u8; 32],
@@ -727,6 +1294,7 @@ pub fn ask_ai_with_provider(
     question: &str,
     top_k: usize,
     focus: Focus,
+    tz: Option<&str>,
     provider: &(impl AnswerProvider + ?Sized),
     on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
 ) -> Result<AskAiResult> {
@@ -734,6 +1302,8 @@ pub fn ask_ai_with_provider(
     db::process_pending_message_embeddings_default(conn, key, 1024)?;
     db::process_pending_todo_embeddings_default(conn, key, 1024)?;
     db::process_pending_todo_activity_embeddings_default(conn, key, 1024)?;
+    db::process_pending_attachment_chunks(conn, key, 1024)?;
+    db::process_pending_attachment_chunk_embeddings_default(conn, key, 1024)?;
 
     let top_k = top_k.max(1);
 
@@ -748,12 +1318,42 @@ pub fn ask_ai_with_provider(
         )?,
     };
     let similar_todos = db::search_similar_todo_threads_default(conn, key, question, top_k)?;
-
-    let mut contexts_with_distance: Vec<(f64, String)> = Vec::new();
+    let chunk_conversation_id = match focus {
+        Focus::AllMemories => None,
+        Focus::ThisThread => Some(conversation_id),
+    };
+    let similar_chunks = db::search_similar_attachment_chunks_default(
+        conn,
+        key,
+        chunk_conversation_id,
+        question,
+        top_k,
+    )?;
+    let chunk_message_ids = similar_chunks
+        .iter()
+        .map(|sc| sc.message_id.clone())
+        .collect::<std::collections::HashSet<_>>();
+
+    let mut contexts_with_distance: Vec<(f64, SourcedContext)> = Vec::new();
+    for sc in similar_chunks {
+        contexts_with_distance.push((
+            sc.distance,
+            SourcedContext::new(
+                ContextSource::AttachmentChunk,
+                &attachment_chunk_source_id(&sc.chunk),
+                attachment_chunk_context(&sc.chunk),
+            ),
+        ));
+    }
     for sm in similar_messages {
-        let context = db::build_message_rag_context(conn, key, &sm.message.id, &sm.message.content)
-            .unwrap_or_else(|_| sm.message.content.clone());
-        contexts_with_distance.push((sm.distance, context));
+        let context = message_context(conn, key, &sm.message, &chunk_message_ids);
+        if context.trim().is_empty() {
+            continue;
+        }
+        contexts_with_distance.push((
+            sm.distance,
+            SourcedContext::new(ContextSource::Message, &sm.message.id, context),
+        ));
     }
     let mut seen_todos = std::collections::HashSet::new();
     for st in similar_todos {
@@ -764,62 +1364,48 @@ pub fn ask_ai_with_provider(
             Ok(v) => v,
             Err(_) => continue,
         };
-        contexts_with_distance.push((st.distance, ctx));
+        contexts_with_distance.push((
+            st.distance,
+            SourcedContext::new(ContextSource::TodoThread, &st.todo_id, ctx),
+        ));
     }
     contexts_with_distance
         .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
     contexts_with_distance.truncate(top_k);
-    let contexts: Vec<String> = contexts_with_distance
+    let contexts: Vec<SourcedContext> = contexts_with_distance
         .into_iter()
         .map(|(_, ctx)| ctx)
         .collect();
-    let actions = build_actions_context(conn, key, question)?;
-    let history = build_recent_conversation_history(conn, key, conversation_id)?;
-    let prompt = build_prompt_with_actions_and_history(
+    let budget = ContextBudget::for_context_window(provider.context_window_tokens());
+    let counter = provider.token_counter();
+    let contexts = fit_contexts_to_budget(contexts, budget.context_tokens, counter);
+    let actions = build_actions_context(conn, key, question, tz)?;
+    let history = build_recent_conversation_history(
+        conn,
+        key,
+        conversation_id,
+        budget.history_tokens,
+        counter,
+    )?;
+    let prompt = build_ask_ai_prompt(
+        conn,
+        key,
         question,
-        &contexts,
+        &context_texts(&contexts),
         actions.as_deref(),
         history.as_deref(),
-    );
-
-    let mut has_text = false;
-    let mut assistant_text = String::new();
-    let result = provider.stream_answer(&prompt, &mut |ev| {
-        let done = ev.done;
-        let text_delta = ev.text_delta.clone();
-        on_event(ev)?;
-
-        if !done && !text_delta.is_empty() {
-            has_text = true;
-            assistant_text.push_str(&text_delta);
-        }
-
-        Ok(())
-    });
-
-    match result {
-        Ok(()) => {
-            if !has_text {
-                return Err(anyhow!("empty response from LLM"));
-            }
-
-            let user_message =
-                db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
-            let assistant_message = db::insert_message_non_memory(
-                conn,
-                key,
-                conversation_id,
-                "assistant",
-                &assistant_text,
-            )?;
+    )?;
 
-            Ok(AskAiResult {
-                user_message_id: user_message.id,
-                assistant_message_id: assistant_message.id,
-            })
-        }
-        Err(e) => Err(e),
-    }
+    stream_answer_with_citations(
+        conn,
+        key,
+        conversation_id,
+        question,
+        &prompt,
+        &citations_for_contexts(&contexts),
+        provider,
+        on_event,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -831,21 +1417,65 @@ pub fn ask_ai_with_provider_using_embedder<E: Embedder + ?Sized>(
     question: &str,
     top_k: usize,
     focus: Focus,
+    tz: Option<&str>,
+    provider: &(impl AnswerProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    ask_ai_with_provider_using_embedder_and_queries(
+        conn,
+        key,
+        embedder,
+        conversation_id,
+        question,
+        &[],
+        top_k,
+        focus,
+        tz,
+        provider,
+        on_event,
+    )
+}
+
+/// Like `ask_ai_with_provider_using_embedder`, but retrieves with
+/// `search_queries` (see `search_queries_for_question`) instead of the raw
+/// question. An empty slice searches with the question.
+#[allow(clippy::too_many_arguments)]
+pub fn ask_ai_with_provider_using_embedder_and_queries<E: Embedder + ?Sized>(
+    conn: &Connection,
+    key: &[u8; 32],
+    embedder: &E,
+    conversation_id: &str,
+    question: &str,
+    search_queries: &[String],
+    top_k: usize,
+    focus: Focus,
+    tz: Option<&str>,
     provider: &(impl AnswerProvider + ?Sized),
     on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
 ) -> Result<AskAiResult> {
-    let mut contexts: Vec<String> = Vec::new();
+    let question_only = [question.to_string()];
+    let search_queries = if search_queries.is_empty() {
+        &question_only[..]
+    } else {
+        search_queries
+    };
+
+    let mut contexts: Vec<SourcedContext> = Vec::new();
     if top_k > 0 {
         // Avoid wiping the current index if the embedder is misconfigured/unreachable.
-        let mut probe = embedder.embed(&[format!("query: {question}")])?;
-        if probe.len() != 1 {
+        let inputs = search_queries
+            .iter()
+            .map(|query| format!("query: {query}"))
+            .collect::<Vec<_>>();
+        let query_vectors = embedder.embed(&inputs)?;
+        if query_vectors.len() != inputs.len() {
             return Err(anyhow!(
-                "embedder output length mismatch: expected 1, got {}",
-                probe.len()
+                "embedder output length mismatch: expected {}, got {}",
+                inputs.len(),
+                query_vectors.len()
             ));
         }
-        let query_vector = probe.pop().unwrap_or_default();
-        let dim = query_vector.len();
+        let dim = query_vectors[0].len();
         if dim == 0 {
             return Err(anyhow!("embedder returned empty embeddings"));
         }
@@ -854,51 +1484,116 @@ pub fn ask_ai_with_provider_using_embedder<E: Embedder + ?Sized>(
         db::process_pending_message_embeddings(conn, key, embedder, 1024)?;
         db::process_pending_todo_embeddings(conn, key, embedder, 1024)?;
         db::process_pending_todo_activity_embeddings(conn, key, embedder, 1024)?;
+        db::process_pending_attachment_chunks(conn, key, 1024)?;
+        db::process_pending_attachment_chunk_embeddings(conn, key, embedder, 1024)?;
 
         let top_k = top_k.max(1);
 
-        let similar_messages = match focus {
-            Focus::AllMemories => db::search_similar_messages_by_embedding(
+        // Each context is kept once, at its best distance over all queries.
+        let mut contexts_with_distance: Vec<(f64, SourcedContext)> = Vec::new();
+        let mut context_index: std::collections::HashMap<(ContextSource, String), usize> =
+            std::collections::HashMap::new();
+        let mut push_context = |contexts_with_distance: &mut Vec<(f64, SourcedContext)>,
+                                source: ContextSource,
+                                id: &str,
+                                distance: f64,
+                                text: String| {
+            match context_index.get(&(source, id.to_string())) {
+                Some(&idx) => {
+                    let existing = &mut contexts_with_distance[idx];
+                    if distance < existing.0 {
+                        existing.0 = distance;
+                    }
+                }
+                None => {
+                    context_index.insert((source, id.to_string()), contexts_with_distance.len());
+                    contexts_with_distance.push((distance, SourcedContext::new(source, id, text)));
+                }
+            }
+        };
+
+        // Chunks go first so that messages they belong to can skip the excerpt.
+        let chunk_conversation_id = match focus {
+            Focus::AllMemories => None,
+            Focus::ThisThread => Some(conversation_id),
+        };
+        let mut chunk_message_ids = std::collections::HashSet::new();
+        for query_vector in &query_vectors {
+            let similar_chunks = db::search_similar_attachment_chunks_by_embedding(
                 conn,
                 key,
                 embedder.model_name(),
-                &query_vector,
+                chunk_conversation_id,
+                query_vector,
                 top_k,
-            )?,
-            Focus::ThisThread => db::search_similar_messages_in_conversation_by_embedding(
+            )?;
+            for sc in similar_chunks {
+                chunk_message_ids.insert(sc.message_id.clone());
+                push_context(
+                    &mut contexts_with_distance,
+                    ContextSource::AttachmentChunk,
+                    &attachment_chunk_source_id(&sc.chunk),
+                    sc.distance,
+                    attachment_chunk_context(&sc.chunk),
+                );
+            }
+        }
+
+        for query_vector in &query_vectors {
+            let similar_messages = match focus {
+                Focus::AllMemories => db::search_similar_messages_by_embedding(
+                    conn,
+                    key,
+                    embedder.model_name(),
+                    query_vector,
+                    top_k,
+                )?,
+                Focus::ThisThread => db::search_similar_messages_in_conversation_by_embedding(
+                    conn,
+                    key,
+                    embedder.model_name(),
+                    conversation_id,
+                    query_vector,
+                    top_k,
+                )?,
+            };
+            for sm in similar_messages {
+                let context = message_context(conn, key, &sm.message, &chunk_message_ids);
+                if context.trim().is_empty() {
+                    continue;
+                }
+                push_context(
+                    &mut contexts_with_distance,
+                    ContextSource::Message,
+                    &sm.message.id,
+                    sm.distance,
+                    context,
+                );
+            }
+
+            let similar_todos = db::search_similar_todo_threads_by_embedding(
                 conn,
-                key,
                 embedder.model_name(),
-                conversation_id,
-                &query_vector,
+                query_vector,
                 top_k,
-            )?,
-        };
-
-        let similar_todos = db::search_similar_todo_threads_by_embedding(
-            conn,
-            embedder.model_name(),
-            &query_vector,
-            top_k,
-        )?;
-
-        let mut contexts_with_distance: Vec<(f64, String)> = Vec::new();
-        for sm in similar_messages {
-            let context =
-                db::build_message_rag_context(conn, key, &sm.message.id, &sm.message.content)
-                    .unwrap_or_else(|_| sm.message.content.clone());
-            contexts_with_distance.push((sm.distance, context));
-        }
-        let mut seen_todos = std::collections::HashSet::new();
-        for st in similar_todos {
-            if !seen_todos.insert(st.todo_id.clone()) {
-                continue;
+            )?;
+            let mut seen_todos = std::collections::HashSet::new();
+            for st in similar_todos {
+                if !seen_todos.insert(st.todo_id.clone()) {
+                    continue;
+                }
+                let ctx = match build_todo_thread_context(conn, key, &st.todo_id) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+                push_context(
+                    &mut contexts_with_distance,
+                    ContextSource::TodoThread,
+                    &st.todo_id,
+                    st.distance,
+                    ctx,
+                );
             }
-            let ctx = match build_todo_thread_context(conn, key, &st.todo_id) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            contexts_with_distance.push((st.distance, ctx));
         }
         contexts_with_distance
             .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
@@ -908,53 +1603,36 @@ pub fn ask_ai_with_provider_using_embedder<E: Embedder + ?Sized>(
             .map(|(_, ctx)| ctx)
             .collect();
     }
-    let actions = build_actions_context(conn, key, question)?;
-    let history = build_recent_conversation_history(conn, key, conversation_id)?;
-    let prompt = build_prompt_with_actions_and_history(
+    let budget = ContextBudget::for_context_window(provider.context_window_tokens());
+    let counter = provider.token_counter();
+    let contexts = fit_contexts_to_budget(contexts, budget.context_tokens, counter);
+    let actions = build_actions_context(conn, key, question, tz)?;
+    let history = build_recent_conversation_history(
+        conn,
+        key,
+        conversation_id,
+        budget.history_tokens,
+        counter,
+    )?;
+    let prompt = build_ask_ai_prompt(
+        conn,
+        key,
         question,
-        &contexts,
+        &context_texts(&contexts),
         actions.as_deref(),
         history.as_deref(),
-    );
-
-    let mut has_text = false;
-    let mut assistant_text = String::new();
-    let result = provider.stream_answer(&prompt, &mut |ev| {
-        let done = ev.done;
-        let text_delta = ev.text_delta.clone();
-        on_event(ev)?;
-
-        if !done && !text_delta.is_empty() {
-            has_text = true;
-            assistant_text.push_str(&text_delta);
-        }
-
-        Ok(())
-    });
-
-    match result {
-        Ok(()) => {
-            if !has_text {
-                return Err(anyhow!("empty response from LLM"));
-            }
-
-            let user_message =
-                db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
-            let assistant_message = db::insert_message_non_memory(
-                conn,
-                key,
-                conversation_id,
-                "assistant",
-                &assistant_text,
-            )?;
+    )?;
 
-            Ok(AskAiResult {
-                user_message_id: user_message.id,
-                assistant_message_id: assistant_message.id,
-            })
-        }
-        Err(e) => Err(e),
-    }
+    stream_answer_with_citations(
+        conn,
+        key,
+        conversation_id,
+        question,
+        &prompt,
+        &citations_for_contexts(&contexts),
+        provider,
+        on_event,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -966,148 +1644,311 @@ pub fn ask_ai_with_provider_using_active_embeddings(
     question: &str,
     top_k: usize,
     focus: Focus,
+    tz: Option<&str>,
+    provider: &(impl AnswerProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    ask_ai_with_provider_using_active_embeddings_and_queries(
+        conn,
+        key,
+        app_dir,
+        conversation_id,
+        question,
+        &[],
+        top_k,
+        focus,
+        None,
+        tz,
+        provider,
+        on_event,
+    )
+}
+
+/// Like `ask_ai_with_provider_using_active_embeddings`, but retrieves with
+/// `search_queries` (see `rewrite_search_queries`) instead of the raw
+/// question. An empty slice searches with the question. When a `reranker` is
+/// given, it rescores the best candidates before the final selection.
+#[allow(clippy::too_many_arguments)]
+pub fn ask_ai_with_provider_using_active_embeddings_and_queries(
+    conn: &Connection,
+    key: &[u8; 32],
+    app_dir: &Path,
+    conversation_id: &str,
+    question: &str,
+    search_queries: &[String],
+    top_k: usize,
+    focus: Focus,
+    reranker: Option<&dyn Reranker>,
+    tz: Option<&str>,
     provider: &(impl AnswerProvider + ?Sized),
     on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
 ) -> Result<AskAiResult> {
-    let mut contexts: Vec<String> = Vec::new();
+    let question_only = [question.to_string()];
+    let search_queries = if search_queries.is_empty() {
+        &question_only[..]
+    } else {
+        search_queries
+    };
+
+    let mut contexts: Vec<SourcedContext> = Vec::new();
     if top_k > 0 {
         db::process_pending_message_embeddings_active(conn, key, app_dir, 1024)?;
         db::process_pending_todo_embeddings_active(conn, key, app_dir, 1024)?;
         db::process_pending_todo_activity_embeddings_active(conn, key, app_dir, 1024)?;
+        db::process_pending_attachment_chunks(conn, key, 1024)?;
+        db::process_pending_attachment_chunk_embeddings_active(conn, key, app_dir, 1024)?;
 
         let top_k = top_k.max(1);
 
         let top_k_candidate_messages = (top_k.saturating_mul(8)).min(200).max(top_k);
         let top_k_candidate_todos = (top_k.saturating_mul(4)).min(80).max(top_k);
 
-        let similar_messages = match focus {
-            Focus::AllMemories => db::search_similar_messages_active(
+        let mut candidates: Vec<ContextItem> = Vec::new();
+        // Each message or todo is kept once, at its best distance over all queries.
+        let mut candidate_index: std::collections::HashMap<(ContextSource, String), usize> =
+            std::collections::HashMap::new();
+        let mut push_candidate = |candidates: &mut Vec<ContextItem>, item: ContextItem| {
+            let slot = (item.source, item.id.clone());
+            match candidate_index.get(&slot) {
+                Some(&idx) => {
+                    let existing = &mut candidates[idx];
+                    if item.distance < existing.distance {
+                        existing.distance = item.distance;
+                    }
+                }
+                None => {
+                    candidate_index.insert(slot, candidates.len());
+                    candidates.push(item);
+                }
+            }
+        };
+
+        // Chunks go first so that messages they belong to can skip the excerpt.
+        let chunk_conversation_id = match focus {
+            Focus::AllMemories => None,
+            Focus::ThisThread => Some(conversation_id),
+        };
+        let mut chunk_message_ids = std::collections::HashSet::new();
+        for query in search_queries {
+            let similar_chunks = db::search_similar_attachment_chunks_active(
                 conn,
                 key,
                 app_dir,
-                question,
-                top_k_candidate_messages,
-            )?,
-            Focus::ThisThread => db::search_similar_messages_in_conversation_active(
+                chunk_conversation_id,
+                query,
+                top_k_candidate_todos,
+            )?;
+            for sc in similar_chunks {
+                chunk_message_ids.insert(sc.message_id.clone());
+                push_candidate(
+                    &mut candidates,
+                    ContextItem {
+                        source: ContextSource::AttachmentChunk,
+                        id: attachment_chunk_source_id(&sc.chunk),
+                        created_at_ms: sc.message_created_at_ms,
+                        distance: Some(sc.distance),
+                        location: attachment_chunk_location(&sc.chunk),
+                        text: sc.chunk.text,
+                    },
+                );
+            }
+        }
+
+        for query in search_queries {
+            let similar_messages = match focus {
+                Focus::AllMemories => db::search_similar_messages_active(
+                    conn,
+                    key,
+                    app_dir,
+                    query,
+                    top_k_candidate_messages,
+                )?,
+                Focus::ThisThread => db::search_similar_messages_in_conversation_active(
+                    conn,
+                    key,
+                    app_dir,
+                    conversation_id,
+                    query,
+                    top_k_candidate_messages,
+                )?,
+            };
+
+            let similar_todos = db::search_similar_todo_threads_active(
                 conn,
                 key,
                 app_dir,
-                conversation_id,
-                question,
-                top_k_candidate_messages,
-            )?,
-        };
-
-        let similar_todos = db::search_similar_todo_threads_active(
-            conn,
-            key,
-            app_dir,
-            question,
-            top_k_candidate_todos,
-        )?;
+                query,
+                top_k_candidate_todos,
+            )?;
 
-        let mut candidates: Vec<ContextItem> = Vec::new();
-        for sm in similar_messages {
-            let context =
-                db::build_message_rag_context(conn, key, &sm.message.id, &sm.message.content)
-                    .unwrap_or_else(|_| sm.message.content.clone());
-            candidates.push(ContextItem {
-                source: ContextSource::Message,
-                id: sm.message.id.clone(),
-                created_at_ms: sm.message.created_at_ms,
-                distance: Some(sm.distance),
-                text: context,
-            });
-        }
+            for sm in similar_messages {
+                let context = message_context(conn, key, &sm.message, &chunk_message_ids);
+                if context.trim().is_empty() {
+                    continue;
+                }
+                push_candidate(
+                    &mut candidates,
+                    ContextItem {
+                        source: ContextSource::Message,
+                        id: sm.message.id.clone(),
+                        created_at_ms: sm.message.created_at_ms,
+                        distance: Some(sm.distance),
+                        text: context,
+                        location: None,
+                    },
+                );
+            }
 
-        let mut seen_todos = std::collections::HashSet::new();
-        for st in similar_todos {
-            if !seen_todos.insert(st.todo_id.clone()) {
-                continue;
+            let mut seen_todos = std::collections::HashSet::new();
+            for st in similar_todos {
+                if !seen_todos.insert(st.todo_id.clone()) {
+                    continue;
+                }
+                let todo = match db::get_todo(conn, key, &st.todo_id) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+                let ctx = match build_todo_thread_context(conn, key, &st.todo_id) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+                push_candidate(
+                    &mut candidates,
+                    ContextItem {
+                        source: ContextSource::TodoThread,
+                        id: st.todo_id,
+                        created_at_ms: todo.created_at_ms,
+                        distance: Some(st.distance),
+                        text: ctx,
+                        location: None,
+                    },
+                );
             }
-            let todo = match db::get_todo(conn, key, &st.todo_id) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let ctx = match build_todo_thread_context(conn, key, &st.todo_id) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            candidates.push(ContextItem {
-                source: ContextSource::TodoThread,
-                id: st.todo_id,
-                created_at_ms: todo.created_at_ms,
-                distance: Some(st.distance),
-                text: ctx,
-            });
         }
 
-        contexts = build_contexts_v2(question, candidates, top_k);
+        contexts = build_contexts_v2(&search_queries.join("\n"), candidates, top_k, reranker);
     }
-    let actions = build_actions_context(conn, key, question)?;
-    let history = build_recent_conversation_history(conn, key, conversation_id)?;
-    let prompt = build_prompt_with_actions_and_history(
+    let budget = ContextBudget::for_context_window(provider.context_window_tokens());
+    let counter = provider.token_counter();
+    let contexts = fit_contexts_to_budget(contexts, budget.context_tokens, counter);
+    let actions = build_actions_context(conn, key, question, tz)?;
+    let history = build_recent_conversation_history(
+        conn,
+        key,
+        conversation_id,
+        budget.history_tokens,
+        counter,
+    )?;
+    let prompt = build_ask_ai_prompt(
+        conn,
+        key,
         question,
-        &contexts,
+        &context_texts(&contexts),
         actions.as_deref(),
         history.as_deref(),
-    );
-
-    let mut has_text = false;
-    let mut assistant_text = String::new();
-    let result = provider.stream_answer(&prompt, &mut |ev| {
-        let done = ev.done;
-        let text_delta = ev.text_delta.clone();
-        on_event(ev)?;
-
-        if !done && !text_delta.is_empty() {
-            has_text = true;
-            assistant_text.push_str(&text_delta);
-        }
-
-        Ok(())
-    });
+    )?;
 
-    match result {
-        Ok(()) => {
-            if !has_text {
-                return Err(anyhow!("empty response from LLM"));
-            }
+    stream_answer_with_citations(
+        conn,
+        key,
+        conversation_id,
+        question,
+        &prompt,
+        &citations_for_contexts(&contexts),
+        provider,
+        on_event,
+    )
+}
 
-            let user_message =
-                db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
-            let assistant_message = db::insert_message_non_memory(
-                conn,
-                key,
-                conversation_id,
-                "assistant",
-                &assistant_text,
-            )?;
+#[allow(clippy::too_many_arguments)]
+pub fn ask_ai_with_provider_using_active_embeddings_time_window(
+    conn: &Connection,
+    key: &[u8; 32],
+    _app_dir: &Path,
+    conversation_id: &str,
+    question: &str,
+    top_k: usize,
+    focus: Focus,
+    time_start_ms: i64,
+    time_end_ms: i64,
+    tz: Option<&str>,
+    provider: &(impl AnswerProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    ask_ai_with_provider_using_active_embeddings_time_window_reranked(
+        conn,
+        key,
+        _app_dir,
+        conversation_id,
+        question,
+        top_k,
+        focus,
+        time_start_ms,
+        time_end_ms,
+        None,
+        tz,
+        provider,
+        on_event,
+    )
+}
 
-            Ok(AskAiResult {
-                user_message_id: user_message.id,
-                assistant_message_id: assistant_message.id,
-            })
-        }
-        Err(e) => Err(e),
-    }
+/// Like `ask_ai_with_provider_using_active_embeddings_time_window`, with an
+/// optional cross-encoder `reranker` for the candidates in the window.
+#[allow(clippy::too_many_arguments)]
+pub fn ask_ai_with_provider_using_active_embeddings_time_window_reranked(
+    conn: &Connection,
+    key: &[u8; 32],
+    _app_dir: &Path,
+    conversation_id: &str,
+    question: &str,
+    top_k: usize,
+    focus: Focus,
+    time_start_ms: i64,
+    time_end_ms: i64,
+    reranker: Option<&dyn Reranker>,
+    tz: Option<&str>,
+    provider: &(impl AnswerProvider + ?Sized),
+    on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+) -> Result<AskAiResult> {
+    ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
+        conn,
+        key,
+        _app_dir,
+        conversation_id,
+        question,
+        &[],
+        top_k,
+        focus,
+        time_start_ms,
+        time_end_ms,
+        reranker,
+        tz,
+        provider,
+        on_event,
+    )
 }
 
+/// Like `ask_ai_with_provider_using_active_embeddings_time_window_reranked`,
+/// but ranks the candidates in the window against `search_queries` (see
+/// `search_queries_for_question`). An empty slice ranks with the question.
 #[allow(clippy::too_many_arguments)]
-pub fn ask_ai_with_provider_using_active_embeddings_time_window(
+pub fn ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
     conn: &Connection,
     key: &[u8; 32],
     _app_dir: &Path,
     conversation_id: &str,
     question: &str,
+    search_queries: &[String],
     top_k: usize,
     focus: Focus,
     time_start_ms: i64,
     time_end_ms: i64,
+    reranker: Option<&dyn Reranker>,
+    tz: Option<&str>,
     provider: &(impl AnswerProvider + ?Sized),
     on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
 ) -> Result<AskAiResult> {
-    let mut contexts: Vec<String> = Vec::new();
+    let mut contexts: Vec<SourcedContext> = Vec::new();
     if top_k > 0 {
         let conversation_filter = match focus {
             Focus::AllMemories => None,
@@ -1132,6 +1973,7 @@ pub fn ask_ai_with_provider_using_active_embeddings_time_window(
                 created_at_ms: m.created_at_ms,
                 distance: None,
                 text: context,
+                location: None,
             });
         }
 
@@ -1158,6 +2000,7 @@ pub fn ask_ai_with_provider_using_active_embeddings_time_window(
                 created_at_ms: a.created_at_ms,
                 distance: None,
                 text,
+                location: None,
             });
         }
 
@@ -1175,6 +2018,7 @@ pub fn ask_ai_with_provider_using_active_embeddings_time_window(
                 created_at_ms: e.start_at_ms,
                 distance: None,
                 text,
+                location: None,
             });
         }
 
@@ -1200,63 +2044,48 @@ pub fn ask_ai_with_provider_using_active_embeddings_time_window(
                 created_at_ms: todo.created_at_ms,
                 distance: None,
                 text: ctx,
+                location: None,
             });
         }
 
-        contexts = build_contexts_v2(question, candidates, top_k.max(1));
+        let ranking_query = if search_queries.is_empty() {
+            question.to_string()
+        } else {
+            search_queries.join("\n")
+        };
+        contexts = build_contexts_v2(&ranking_query, candidates, top_k.max(1), reranker);
     }
 
-    let actions = build_actions_context(conn, key, question)?;
+    let budget = ContextBudget::for_context_window(provider.context_window_tokens());
+    let counter = provider.token_counter();
+    let contexts = fit_contexts_to_budget(contexts, budget.context_tokens, counter);
+    let actions = build_actions_context(conn, key, question, tz)?;
     let history = build_recent_conversation_history_in_range(
         conn,
         key,
         conversation_id,
         time_start_ms,
         time_end_ms,
+        budget.history_tokens,
+        counter,
     )?;
-    let prompt = build_prompt_with_actions_and_history(
+    let prompt = build_ask_ai_prompt(
+        conn,
+        key,
         question,
-        &contexts,
+        &context_texts(&contexts),
         actions.as_deref(),
         history.as_deref(),
-    );
-
-    let mut has_text = false;
-    let mut assistant_text = String::new();
-    let result = provider.stream_answer(&prompt, &mut |ev| {
-        let done = ev.done;
-        let text_delta = ev.text_delta.clone();
-        on_event(ev)?;
-
-        if !done && !text_delta.is_empty() {
-            has_text = true;
-            assistant_text.push_str(&text_delta);
-        }
-
-        Ok(())
-    });
-
-    match result {
-        Ok(()) => {
-            if !has_text {
-                return Err(anyhow!("empty response from LLM"));
-            }
-
-            let user_message =
-                db::insert_message_non_memory(conn, key, conversation_id, "user", question)?;
-            let assistant_message = db::insert_message_non_memory(
-                conn,
-                key,
-                conversation_id,
-                "assistant",
-                &assistant_text,
-            )?;
+    )?;
 
-            Ok(AskAiResult {
-                user_message_id: user_message.id,
-                assistant_message_id: assistant_message.id,
-            })
-        }
-        Err(e) => Err(e),
-    }
+    stream_answer_with_citations(
+        conn,
+        key,
+        conversation_id,
+        question,
+        &prompt,
+        &citations_for_contexts(&contexts),
+        provider,
+        on_event,
+    )
 }
@@ -1,7 +1,10 @@
 use anyhow::{anyhow, Result};
-use serde_json::Value;
+use serde_json::{json, Value};
 
+use crate::llm::budget::TokenCounter;
+use crate::llm::json_schema;
 use crate::llm::ChatDelta;
+use crate::prompt_templates::{self, PromptVariables};
 use crate::rag::AnswerProvider;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,6 +68,210 @@ fn extract_first_json_value(raw: &str) -> Result<Value> {
     Err(anyhow!("unterminated json object"))
 }
 
+/// Errors for output that is still unusable after the repair round trip
+/// start with this, followed by the failure reason.
+pub const FAILURE_PREFIX: &str = "semantic_parse_failed:";
+pub const FAILURE_REASON_NO_JSON: &str = "no_json";
+pub const FAILURE_REASON_INVALID_JSON: &str = "invalid_json";
+pub const FAILURE_REASON_SCHEMA_MISMATCH: &str = "schema_mismatch";
+pub const FAILURE_REASON_SPENDING_LIMIT: &str = "spending_limit_exceeded";
+pub const FAILURE_REASON_REQUEST_FAILED: &str = "request_failed";
+
+/// Tokens of a rejected output quoted back to the model for repair.
+const MAX_REPAIR_OUTPUT_TOKENS: usize = 1000;
+
+/// Local ISO 8601 without timezone, like `2026-02-04T15:00:00`.
+const LOCAL_ISO_PATTERN: &str = r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}(:\d{2}(\.\d+)?)?$";
+
+/// The reason recorded on a failed semantic parse job for `error`: one of
+/// the `FAILURE_REASON_*` values.
+pub fn failure_reason(error: &str) -> &str {
+    if let Some(start) = error.find(FAILURE_PREFIX) {
+        let reason = error[start + FAILURE_PREFIX.len()..]
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        if !reason.is_empty() {
+            return reason;
+        }
+    }
+    if error.contains("llm_spending_limit_exceeded") {
+        return FAILURE_REASON_SPENDING_LIMIT;
+    }
+    FAILURE_REASON_REQUEST_FAILED
+}
+
+/// Schema of the `semantic_parse_message_action_json` output. `todo_id`
+/// must be one of the candidate IDs.
+pub fn message_action_schema(candidates: &[TodoCandidate]) -> Value {
+    let candidate_ids: Vec<&str> = candidates.iter().map(|c| c.id.as_str()).collect();
+    json!({
+        "type": "object",
+        "required": ["kind", "confidence"],
+        "properties": {
+            "kind": { "type": "string", "enum": ["none", "followup", "create"] },
+            "confidence": { "type": "number", "minimum": 0, "maximum": 1 },
+            "todo_id": { "type": ["string", "null"] },
+            "new_status": {
+                "type": ["string", "null"],
+                "enum": ["in_progress", "done", "dismissed", null],
+            },
+            "title": { "type": ["string", "null"] },
+            "status": { "type": ["string", "null"], "enum": ["open", "inbox", null] },
+            "due_local_iso": { "type": ["string", "null"], "pattern": LOCAL_ISO_PATTERN },
+            "recurrence": {
+                "type": ["object", "null"],
+                "required": ["freq"],
+                "properties": {
+                    "freq": {
+                        "type": "string",
+                        "enum": ["daily", "weekly", "monthly", "yearly"],
+                    },
+                    "interval": { "type": "integer", "minimum": 1 },
+                },
+            },
+        },
+        "allOf": [
+            {
+                "if": { "properties": { "kind": { "enum": ["followup"] } } },
+                "then": {
+                    "required": ["todo_id", "new_status"],
+                    "properties": {
+                        "todo_id": { "type": "string", "enum": candidate_ids },
+                        "new_status": { "type": "string" },
+                    },
+                },
+            },
+            {
+                "if": { "properties": { "kind": { "enum": ["create"] } } },
+                "then": {
+                    "required": ["title"],
+                    "properties": { "title": { "type": "string" } },
+                },
+            },
+        ],
+    })
+}
+
+/// Schema of the `semantic_parse_ask_ai_time_window_json` output.
+pub fn time_window_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["kind", "confidence"],
+        "properties": {
+            "kind": { "type": "string", "enum": ["none", "past", "future", "both"] },
+            "confidence": { "type": "number", "minimum": 0, "maximum": 1 },
+            "start_local_iso": { "type": ["string", "null"], "pattern": LOCAL_ISO_PATTERN },
+            "end_local_iso": { "type": ["string", "null"], "pattern": LOCAL_ISO_PATTERN },
+        },
+        "if": { "properties": { "kind": { "enum": ["past", "future", "both"] } } },
+        "then": {
+            "required": ["start_local_iso", "end_local_iso"],
+            "properties": {
+                "start_local_iso": { "type": "string" },
+                "end_local_iso": { "type": "string" },
+            },
+        },
+    })
+}
+
+struct OutputFailure {
+    reason: &'static str,
+    detail: String,
+}
+
+fn parse_structured_output(raw: &str, schema: &Value) -> Result<Value, OutputFailure> {
+    let value = match serde_json::from_str::<Value>(raw.trim()) {
+        Ok(value) => value,
+        Err(_) => extract_first_json_value(raw).map_err(|e| OutputFailure {
+            reason: if raw.contains('{') {
+                FAILURE_REASON_INVALID_JSON
+            } else {
+                FAILURE_REASON_NO_JSON
+            },
+            detail: e.to_string(),
+        })?,
+    };
+    json_schema::validate(&value, schema).map_err(|detail| OutputFailure {
+        reason: FAILURE_REASON_SCHEMA_MISMATCH,
+        detail,
+    })?;
+    Ok(value)
+}
+
+fn stream_json_output(
+    provider: &dyn AnswerProvider,
+    prompt: &str,
+    schema: &Value,
+) -> Result<String> {
+    let mut out = String::new();
+    provider.stream_answer_json(prompt, schema, &mut |ev: ChatDelta| {
+        out.push_str(&ev.text_delta);
+        Ok(())
+    })?;
+    Ok(out)
+}
+
+fn build_repair_prompt(prompt: &str, output: &str, problem: &str, counter: TokenCounter) -> String {
+    let mut out = String::new();
+    out.push_str(prompt.trim_end());
+    out.push_str("\n\nYour previous output was rejected: ");
+    out.push_str(problem);
+    out.push_str("\nprevious_output:\n");
+    out.push_str(counter.truncate(output.trim(), MAX_REPAIR_OUTPUT_TOKENS));
+    out.push_str("\n\nReturn the corrected JSON only, following the schema above.\n");
+    out
+}
+
+/// Runs `prompt` in the provider's JSON mode and validates the answer
+/// against `schema`. An invalid answer gets one repair round trip; if that
+/// answer is invalid too, errors with `FAILURE_PREFIX` and the reason.
+fn generate_structured_json(
+    provider: &dyn AnswerProvider,
+    prompt: &str,
+    schema: &Value,
+) -> Result<Value> {
+    let out = stream_json_output(provider, prompt, schema)?;
+    let first = match parse_structured_output(&out, schema) {
+        Ok(value) => return Ok(value),
+        Err(failure) => failure,
+    };
+
+    let repair_prompt = build_repair_prompt(prompt, &out, &first.detail, provider.token_counter());
+    let repaired = stream_json_output(provider, &repair_prompt, schema)?;
+    parse_structured_output(&repaired, schema).map_err(|failure| {
+        anyhow!(
+            "{FAILURE_PREFIX}{}: {} (before repair: {})",
+            failure.reason,
+            failure.detail,
+            first.detail
+        )
+    })
+}
+
+fn format_todo_candidates(candidates: &[TodoCandidate]) -> String {
+    if candidates.is_empty() {
+        return "- (none)\n".to_string();
+    }
+    let mut out = String::new();
+    for c in candidates {
+        out.push_str(&format!(
+            "- id={id} title={title} status={status}",
+            id = c.id,
+            title = c.title,
+            status = c.status
+        ));
+        if let Some(due) = &c.due_local_iso {
+            if !due.trim().is_empty() {
+                out.push_str(&format!(" due_local_iso={due}"));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
 fn build_message_action_prompt(
     text: &str,
     now_local_iso: &str,
@@ -126,24 +333,7 @@ fn build_message_action_prompt(
     out.push_str(&format!("day_end_minutes: {day_end_minutes}\n\n"));
 
     out.push_str("todo_candidates:\n");
-    if candidates.is_empty() {
-        out.push_str("- (none)\n");
-    } else {
-        for c in candidates {
-            out.push_str(&format!(
-                "- id={id} title={title} status={status}",
-                id = c.id,
-                title = c.title,
-                status = c.status
-            ));
-            if let Some(due) = &c.due_local_iso {
-                if !due.trim().is_empty() {
-                    out.push_str(&format!(" due_local_iso={due}"));
-                }
-            }
-            out.push('\n');
-        }
-    }
+    out.push_str(&format_todo_candidates(candidates));
 
     out.push_str("\nuser_message:\n");
     out.push_str(text.trim());
@@ -160,14 +350,43 @@ pub fn semantic_parse_message_action_json(
     day_end_minutes: i32,
     candidates: &[TodoCandidate],
 ) -> Result<String> {
-    let prompt =
-        build_message_action_prompt(text, now_local_iso, locale, day_end_minutes, candidates);
-    let mut out = String::new();
-    provider.stream_answer(&prompt, &mut |ev: ChatDelta| {
-        out.push_str(&ev.text_delta);
-        Ok(())
-    })?;
-    let value = extract_first_json_value(&out)?;
+    semantic_parse_message_action_json_with_template(
+        provider,
+        None,
+        text,
+        now_local_iso,
+        locale,
+        day_end_minutes,
+        candidates,
+    )
+}
+
+/// Like `semantic_parse_message_action_json`, but renders `template` (see
+/// `crate::prompt_templates`) instead of the built-in prompt when given.
+pub fn semantic_parse_message_action_json_with_template(
+    provider: &dyn AnswerProvider,
+    template: Option<&str>,
+    text: &str,
+    now_local_iso: &str,
+    locale: &str,
+    day_end_minutes: i32,
+    candidates: &[TodoCandidate],
+) -> Result<String> {
+    let prompt = match template {
+        Some(template) => {
+            let variables = PromptVariables::new()
+                .set("question", text.trim())
+                .set("contexts", format_todo_candidates(candidates))
+                .set("lang", locale)
+                .set("now", now_local_iso)
+                .set("day_end_minutes", day_end_minutes.to_string());
+            prompt_templates::render_prompt_template(template, &variables)
+        }
+        None => {
+            build_message_action_prompt(text, now_local_iso, locale, day_end_minutes, candidates)
+        }
+    };
+    let value = generate_structured_json(provider, &prompt, &message_action_schema(candidates))?;
     Ok(serde_json::to_string(&value)?)
 }
 
@@ -222,13 +441,67 @@ pub fn semantic_parse_ask_ai_time_window_json(
 ) -> Result<String> {
     let prompt =
         build_ask_ai_time_window_prompt(question, now_local_iso, locale, first_day_of_week_index);
+    let value = generate_structured_json(provider, &prompt, &time_window_schema())?;
+    Ok(serde_json::to_string(&value)?)
+}
+
+fn build_ask_ai_search_queries_prompt(question: &str, history: &str) -> String {
+    let mut out = String::new();
+    out.push_str("You are a strict JSON generator.\n");
+    out.push_str("Output ONLY JSON. No markdown. No code fences. No extra text.\n\n");
+
+    out.push_str("Task: rewrite the user's latest question into standalone search queries for their personal notes.\n");
+    out.push_str("Resolve pronouns and references (\"she\", \"that\", \"after that\") using the conversation.\n\n");
+
+    out.push_str("Return this exact JSON schema:\n");
+    out.push_str("{\n");
+    out.push_str("  \"queries\": string[] // 1 to 3 queries, most useful first\n");
+    out.push_str("}\n\n");
+
+    out.push_str("Constraints:\n");
+    out.push_str("- Keep the language of the user's question.\n");
+    out.push_str("- Each query must make sense without the conversation.\n");
+    out.push_str(
+        "- If the question is already standalone, return it unchanged as the only query.\n\n",
+    );
+
+    out.push_str("conversation (most recent last):\n");
+    out.push_str(history.trim());
+    out.push_str("\n\n");
+
+    out.push_str("user_question:\n");
+    out.push_str(question.trim());
+    out.push('\n');
+
+    out
+}
+
+/// Standalone search queries for a follow-up `question`, given the recent
+/// conversation `history`.
+pub fn semantic_parse_ask_ai_search_queries(
+    provider: &dyn AnswerProvider,
+    question: &str,
+    history: &str,
+) -> Result<Vec<String>> {
+    let prompt = build_ask_ai_search_queries_prompt(question, history);
     let mut out = String::new();
     provider.stream_answer(&prompt, &mut |ev: ChatDelta| {
         out.push_str(&ev.text_delta);
         Ok(())
     })?;
     let value = extract_first_json_value(&out)?;
-    Ok(serde_json::to_string(&value)?)
+    let queries = value["queries"]
+        .as_array()
+        .ok_or_else(|| anyhow!("missing queries"))?
+        .iter()
+        .filter_map(|query| query.as_str())
+        .map(|query| query.trim().to_string())
+        .filter(|query| !query.is_empty())
+        .collect::<Vec<_>>();
+    if queries.is_empty() {
+        return Err(anyhow!("no queries"));
+    }
+    Ok(queries)
 }
 
 #[cfg(test)]
@@ -249,11 +522,13 @@ mod tests {
                 role: Some("assistant".to_string()),
                 text_delta: self.response.clone(),
                 done: true,
+                usage: None,
             })?;
             on_event(ChatDelta {
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: None,
             })?;
             Ok(())
         }
@@ -306,13 +581,19 @@ mod tests {
 ```"#
                 .to_string(),
         };
+        let candidates = [TodoCandidate {
+            id: "todo:1".to_string(),
+            title: "Taxes".to_string(),
+            status: "open".to_string(),
+            due_local_iso: None,
+        }];
         let result = semantic_parse_message_action_json(
             &provider,
             "I finished taxes",
             "2026-02-03T12:00:00",
             "en",
             21 * 60,
-            &[],
+            &candidates,
         )
         .expect("should parse");
 
@@ -0,0 +1,696 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use reqwest::blocking::Client;
+use reqwest::header::{HeaderMap, HeaderValue, CACHE_CONTROL, PRAGMA};
+use reqwest::Method;
+use rusqlite::Connection;
+use sha2::{Digest, Sha256};
+
+use super::webdav::{parse_multistatus, MultistatusEntry};
+use crate::db;
+use crate::export::ics::{
+    get_ics_item, ics_calendar_document, list_ics_items, IcsItem, ICS_ITEM_KIND_EVENT,
+    ICS_ITEM_KIND_TODO,
+};
+use crate::import::ics::{id_for_ics_uid, import_ics, parse_ics};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalDavResource {
+    pub href: String,
+    pub etag: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CalDavPutCondition {
+    /// `If-None-Match: *`
+    Create,
+    /// `If-Match: {etag}`
+    Replace(String),
+}
+
+/// The server rejected a write because the resource changed (HTTP 412).
+#[derive(Debug)]
+pub struct CalDavPreconditionFailed {
+    pub href: String,
+}
+
+impl std::fmt::Display for CalDavPreconditionFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "caldav precondition failed: {}", self.href)
+    }
+}
+
+impl std::error::Error for CalDavPreconditionFailed {}
+
+pub trait CalDavCollection: Send + Sync {
+    /// Stable id of the collection; keys the local mapping tables.
+    fn collection_id(&self) -> &str;
+    /// `CS:getctag`, or `None` when the server does not report one.
+    fn ctag(&self) -> Result<Option<String>>;
+    fn list(&self) -> Result<Vec<CalDavResource>>;
+    /// Body and ETag of a calendar object.
+    fn get(&self, href: &str) -> Result<(String, Option<String>)>;
+    /// Writes a calendar object; returns its new ETag when the server sends one.
+    fn put(&self, href: &str, ics: &str, condition: &CalDavPutCondition) -> Result<Option<String>>;
+    fn delete(&self, href: &str, etag: Option<&str>) -> Result<()>;
+    /// Href for a new calendar object with `uid`.
+    fn href_for_uid(&self, uid: &str) -> String;
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let mut out = String::with_capacity(64);
+    for b in digest {
+        use std::fmt::Write;
+        let _ = write!(&mut out, "{:02x}", b);
+    }
+    out
+}
+
+/// File name for a calendar object: UIDs may contain characters that are not
+/// safe in a path (ours contain `:`), so it is derived from their hash.
+fn object_name_for_uid(uid: &str) -> String {
+    format!("{}.ics", &sha256_hex(uid.as_bytes())[..32])
+}
+
+pub struct CalDavHttpCollection {
+    client: Client,
+    collection_id: String,
+    collection_url: reqwest::Url,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl CalDavHttpCollection {
+    pub fn new(
+        collection_url: String,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self> {
+        let mut url =
+            reqwest::Url::parse(&collection_url).map_err(|_| anyhow!("invalid collection_url"))?;
+        if !url.path().ends_with('/') {
+            let path = format!("{}/", url.path());
+            url.set_path(&path);
+        }
+        let _ = url.set_username("");
+        let _ = url.set_password(None);
+        url.set_query(None);
+        url.set_fragment(None);
+
+        Ok(Self {
+            client: Client::new(),
+            collection_id: format!("caldav:{url}"),
+            collection_url: url,
+            username,
+            password,
+        })
+    }
+
+    fn request(&self, method: Method, href: &str) -> Result<reqwest::blocking::RequestBuilder> {
+        let url = self
+            .collection_url
+            .join(href)
+            .map_err(|_| anyhow!("invalid caldav href: {href}"))?;
+        let mut builder = self
+            .client
+            .request(method, url)
+            .header(CACHE_CONTROL, "no-cache")
+            .header(PRAGMA, "no-cache");
+        if let Some(user) = &self.username {
+            builder = builder.basic_auth(user, self.password.as_deref());
+        }
+        Ok(builder)
+    }
+
+    fn propfind(&self, depth: &'static str, prop: &str) -> Result<Vec<MultistatusEntry>> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Depth", HeaderValue::from_static(depth));
+        headers.insert(
+            "Content-Type",
+            HeaderValue::from_static("application/xml; charset=utf-8"),
+        );
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8" ?>
+<d:propfind xmlns:d="DAV:" xmlns:cs="http://calendarserver.org/ns/">
+  <d:prop>
+    <d:resourcetype/>
+    {prop}
+  </d:prop>
+</d:propfind>
+"#
+        );
+        let collection_path = self.collection_url.path().to_string();
+        let resp = self
+            .request(Method::from_bytes(b"PROPFIND")?, &collection_path)?
+            .headers(headers)
+            .body(body)
+            .send()?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!("PROPFIND failed: HTTP {status} {body}"));
+        }
+        parse_multistatus(&resp.bytes()?)
+    }
+}
+
+fn etag_header(resp: &reqwest::blocking::Response) -> Option<String> {
+    resp.headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+impl CalDavCollection for CalDavHttpCollection {
+    fn collection_id(&self) -> &str {
+        &self.collection_id
+    }
+
+    fn ctag(&self) -> Result<Option<String>> {
+        let entries = self.propfind("0", "<cs:getctag/>")?;
+        Ok(entries.into_iter().find_map(|entry| entry.ctag))
+    }
+
+    fn list(&self) -> Result<Vec<CalDavResource>> {
+        let collection_path = self.collection_url.path();
+        Ok(self
+            .propfind("1", "<d:getetag/>")?
+            .into_iter()
+            .filter(|entry| !entry.is_collection)
+            .filter_map(|entry| {
+                let path = match reqwest::Url::parse(&entry.href) {
+                    Ok(url) => url.path().to_string(),
+                    Err(_) => entry.href,
+                };
+                (path.trim_end_matches('/') != collection_path.trim_end_matches('/')).then_some(
+                    CalDavResource {
+                        href: path,
+                        etag: entry.etag,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    fn get(&self, href: &str) -> Result<(String, Option<String>)> {
+        let resp = self.request(Method::GET, href)?.send()?;
+        if resp.status().as_u16() == 404 {
+            return Err(super::NotFound {
+                path: href.to_string(),
+            }
+            .into());
+        }
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!("GET failed: HTTP {status} {body}"));
+        }
+        let etag = etag_header(&resp);
+        Ok((resp.text()?, etag))
+    }
+
+    fn put(&self, href: &str, ics: &str, condition: &CalDavPutCondition) -> Result<Option<String>> {
+        let mut builder = self
+            .request(Method::PUT, href)?
+            .header("Content-Type", "text/calendar; charset=utf-8")
+            .body(ics.to_string());
+        builder = match condition {
+            CalDavPutCondition::Create => builder.header("If-None-Match", "*"),
+            CalDavPutCondition::Replace(etag) => builder.header("If-Match", etag.as_str()),
+        };
+        let resp = builder.send()?;
+        if resp.status().as_u16() == 412 {
+            return Err(CalDavPreconditionFailed {
+                href: href.to_string(),
+            }
+            .into());
+        }
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            return Err(anyhow!("PUT failed: HTTP {status} {body}"));
+        }
+        Ok(etag_header(&resp))
+    }
+
+    fn delete(&self, href: &str, etag: Option<&str>) -> Result<()> {
+        let mut builder = self.request(Method::DELETE, href)?;
+        if let Some(etag) = etag {
+            builder = builder.header("If-Match", etag);
+        }
+        let resp = builder.send()?;
+        match resp.status().as_u16() {
+            404 => Ok(()),
+            412 => Err(CalDavPreconditionFailed {
+                href: href.to_string(),
+            }
+            .into()),
+            _ if resp.status().is_success() => Ok(()),
+            _ => {
+                let status = resp.status();
+                let body = resp.text().unwrap_or_default();
+                Err(anyhow!("DELETE failed: HTTP {status} {body}"))
+            }
+        }
+    }
+
+    fn href_for_uid(&self, uid: &str) -> String {
+        format!("{}{}", self.collection_url.path(), object_name_for_uid(uid))
+    }
+}
+
+static INMEM_CALDAV_NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Default)]
+struct InMemoryCalDavState {
+    objects: BTreeMap<String, (String, String)>,
+    next_etag: u64,
+    ctag: u64,
+}
+
+/// CalDAV collection kept in memory, with the ETag/ctag behaviour of a real
+/// server; used by tests.
+pub struct InMemoryCalDavCollection {
+    collection_id: String,
+    state: Mutex<InMemoryCalDavState>,
+}
+
+impl InMemoryCalDavCollection {
+    pub fn new() -> Self {
+        let id = INMEM_CALDAV_NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        Self {
+            collection_id: format!("caldav-inmem:{id}"),
+            state: Mutex::new(InMemoryCalDavState::default()),
+        }
+    }
+}
+
+impl Default for InMemoryCalDavCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalDavCollection for InMemoryCalDavCollection {
+    fn collection_id(&self) -> &str {
+        &self.collection_id
+    }
+
+    fn ctag(&self) -> Result<Option<String>> {
+        let state = self.state.lock().map_err(|_| anyhow!("poisoned lock"))?;
+        Ok(Some(format!("ctag-{}", state.ctag)))
+    }
+
+    fn list(&self) -> Result<Vec<CalDavResource>> {
+        let state = self.state.lock().map_err(|_| anyhow!("poisoned lock"))?;
+        Ok(state
+            .objects
+            .iter()
+            .map(|(href, (_, etag))| CalDavResource {
+                href: href.clone(),
+                etag: Some(etag.clone()),
+            })
+            .collect())
+    }
+
+    fn get(&self, href: &str) -> Result<(String, Option<String>)> {
+        let state = self.state.lock().map_err(|_| anyhow!("poisoned lock"))?;
+        state
+            .objects
+            .get(href)
+            .map(|(ics, etag)| (ics.clone(), Some(etag.clone())))
+            .ok_or_else(|| {
+                super::NotFound {
+                    path: href.to_string(),
+                }
+                .into()
+            })
+    }
+
+    fn put(&self, href: &str, ics: &str, condition: &CalDavPutCondition) -> Result<Option<String>> {
+        let mut state = self.state.lock().map_err(|_| anyhow!("poisoned lock"))?;
+        let current = state.objects.get(href).map(|(_, etag)| etag.as_str());
+        let allowed = match condition {
+            CalDavPutCondition::Create => current.is_none(),
+            CalDavPutCondition::Replace(etag) => current == Some(etag.as_str()),
+        };
+        if !allowed {
+            return Err(CalDavPreconditionFailed {
+                href: href.to_string(),
+            }
+            .into());
+        }
+        state.next_etag += 1;
+        state.ctag += 1;
+        let etag = format!("\"{}\"", state.next_etag);
+        state
+            .objects
+            .insert(href.to_string(), (ics.to_string(), etag.clone()));
+        Ok(Some(etag))
+    }
+
+    fn delete(&self, href: &str, etag: Option<&str>) -> Result<()> {
+        let mut state = self.state.lock().map_err(|_| anyhow!("poisoned lock"))?;
+        let Some((_, current)) = state.objects.get(href) else {
+            return Ok(());
+        };
+        if etag.is_some_and(|etag| etag != current) {
+            return Err(CalDavPreconditionFailed {
+                href: href.to_string(),
+            }
+            .into());
+        }
+        state.objects.remove(href);
+        state.ctag += 1;
+        Ok(())
+    }
+
+    fn href_for_uid(&self, uid: &str) -> String {
+        format!("/calendar/{}", object_name_for_uid(uid))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CalDavSyncReport {
+    pub pulled_created: u32,
+    pub pulled_updated: u32,
+    pub pulled_deleted: u32,
+    pub pushed_created: u32,
+    pub pushed_updated: u32,
+    pub pushed_deleted: u32,
+    pub conflicts: u32,
+    pub errors: Vec<String>,
+}
+
+fn is_precondition_failed(e: &anyhow::Error) -> bool {
+    e.downcast_ref::<CalDavPreconditionFailed>().is_some()
+}
+
+/// `LAST-MODIFIED` (or `DTSTAMP`) of the first component, in ms.
+fn remote_last_modified_ms(ics: &str) -> Option<i64> {
+    let component = parse_ics(ics).ok()?.into_iter().next()?;
+    let property = component
+        .property("LAST-MODIFIED")
+        .or_else(|| component.property("DTSTAMP"))?;
+    let format =
+        time::format_description::parse("[year][month][day]T[hour][minute][second]Z").ok()?;
+    let at = time::PrimitiveDateTime::parse(property.value.trim(), &format).ok()?;
+    Some((at.assume_utc().unix_timestamp_nanos() / 1_000_000) as i64)
+}
+
+struct CalDavSession<'a> {
+    conn: &'a Connection,
+    key: &'a [u8; 32],
+    collection: &'a dyn CalDavCollection,
+    default_tz: Option<&'a str>,
+    now_ms: i64,
+    report: CalDavSyncReport,
+    wrote_remote: bool,
+}
+
+impl CalDavSession<'_> {
+    fn record(&self, href: &str, item: &IcsItem, etag: Option<String>) -> Result<()> {
+        db::upsert_caldav_item(
+            self.conn,
+            &db::CalDavItem {
+                collection_id: self.collection.collection_id().to_string(),
+                href: href.to_string(),
+                item_kind: item.kind.clone(),
+                item_id: item.item_id.clone(),
+                uid: item.uid.clone(),
+                etag,
+                local_sha256: item.content_sha256(),
+                remote_deleted: false,
+                updated_at_ms: self.now_ms,
+            },
+        )
+    }
+
+    fn forget(&self, href: &str) -> Result<()> {
+        db::delete_caldav_item(self.conn, self.collection.collection_id(), href)
+    }
+
+    /// Imports the remote object at `href`; returns the local item it landed in.
+    fn pull(&mut self, href: &str) -> Result<Option<IcsItem>> {
+        let (ics, etag) = self.collection.get(href)?;
+        let Some(component) = parse_ics(&ics)?.into_iter().next() else {
+            self.report
+                .errors
+                .push(format!("{href}: no VEVENT or VTODO; skipped"));
+            return Ok(None);
+        };
+        let Some(uid) = component.text("UID") else {
+            self.report.errors.push(format!("{href}: no UID; skipped"));
+            return Ok(None);
+        };
+        let kind = if component.kind == "VEVENT" {
+            ICS_ITEM_KIND_EVENT
+        } else {
+            ICS_ITEM_KIND_TODO
+        };
+
+        let imported = import_ics(self.conn, self.key, &ics, self.default_tz, false)?;
+        self.report
+            .errors
+            .extend(imported.errors.into_iter().map(|e| format!("{href}: {e}")));
+
+        let item = get_ics_item(self.conn, self.key, kind, &id_for_ics_uid(&uid))?;
+        if let Some(item) = &item {
+            self.record(href, item, etag)?;
+        }
+        Ok(item)
+    }
+
+    fn push(&mut self, href: &str, item: &IcsItem, condition: &CalDavPutCondition) -> Result<()> {
+        let ics = ics_calendar_document(&[item], self.now_ms)?;
+        let etag = self.collection.put(href, &ics, condition)?;
+        self.wrote_remote = true;
+        self.record(href, item, etag)
+    }
+
+    /// Both sides changed: the side modified last wins, the remote on a tie.
+    fn resolve_conflict(&mut self, href: &str, item: &IcsItem) -> Result<()> {
+        self.report.conflicts += 1;
+        let (ics, etag) = match self.collection.get(href) {
+            Ok(remote) => remote,
+            Err(e) if e.downcast_ref::<super::NotFound>().is_some() => {
+                self.push(href, item, &CalDavPutCondition::Create)?;
+                self.report.pushed_created += 1;
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let remote_newer =
+            remote_last_modified_ms(&ics).is_none_or(|remote_ms| remote_ms >= item.updated_at_ms);
+        match (remote_newer, etag) {
+            (false, Some(etag)) => {
+                self.push(href, item, &CalDavPutCondition::Replace(etag))?;
+                self.report.pushed_updated += 1;
+            }
+            _ => {
+                self.pull(href)?;
+                self.report.pulled_updated += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn delete_local(&mut self, mapping: &db::CalDavItem) -> Result<()> {
+        if mapping.item_kind == ICS_ITEM_KIND_EVENT {
+            db::delete_event(self.conn, self.key, &mapping.item_id)?;
+            self.forget(&mapping.href)?;
+        } else {
+            // Todos may carry notes and linked messages, so a remote delete
+            // dismisses them; the mapping stays to remember that.
+            let todo_id = db::get_latest_todo_id_in_recurrence_series(self.conn, &mapping.item_id)?
+                .unwrap_or_else(|| mapping.item_id.clone());
+            db::set_todo_status(self.conn, self.key, &todo_id, "dismissed", None)?;
+            if let Some(item) =
+                get_ics_item(self.conn, self.key, &mapping.item_kind, &mapping.item_id)?
+            {
+                db::upsert_caldav_item(
+                    self.conn,
+                    &db::CalDavItem {
+                        etag: None,
+                        local_sha256: item.content_sha256(),
+                        remote_deleted: true,
+                        updated_at_ms: self.now_ms,
+                        ..mapping.clone()
+                    },
+                )?;
+            }
+        }
+        self.report.pulled_deleted += 1;
+        Ok(())
+    }
+
+    fn sync_known(
+        &mut self,
+        mapping: &db::CalDavItem,
+        remote_etag: Option<Option<&str>>,
+        local: Option<&IcsItem>,
+    ) -> Result<()> {
+        let href = mapping.href.as_str();
+        let local_changed = local.is_some_and(|item| item.content_sha256() != mapping.local_sha256);
+        match (remote_etag, local) {
+            (None, None) => self.forget(href)?,
+            (None, Some(_)) if mapping.remote_deleted && !local_changed => {}
+            (None, Some(item)) => {
+                if local_changed {
+                    // Edited here, deleted there: keep the edit.
+                    if !mapping.remote_deleted {
+                        self.report.conflicts += 1;
+                    }
+                    self.push(href, item, &CalDavPutCondition::Create)?;
+                    self.report.pushed_created += 1;
+                } else {
+                    self.delete_local(mapping)?;
+                }
+            }
+            (Some(etag), None) => {
+                if etag.is_some() && etag == mapping.etag.as_deref() {
+                    match self.collection.delete(href, etag) {
+                        Ok(()) => {
+                            self.wrote_remote = true;
+                            self.forget(href)?;
+                            self.report.pushed_deleted += 1;
+                        }
+                        Err(e) if is_precondition_failed(&e) => {
+                            self.report.conflicts += 1;
+                            self.pull(href)?;
+                            self.report.pulled_created += 1;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                } else {
+                    // Deleted here, edited there: bring the edit back.
+                    self.report.conflicts += 1;
+                    self.pull(href)?;
+                    self.report.pulled_created += 1;
+                }
+            }
+            (Some(etag), Some(item)) => {
+                let remote_changed = etag.is_none() || etag != mapping.etag.as_deref();
+                match (remote_changed, local_changed) {
+                    (false, false) => {}
+                    (true, false) => {
+                        self.pull(href)?;
+                        self.report.pulled_updated += 1;
+                    }
+                    (false, true) => {
+                        let condition =
+                            CalDavPutCondition::Replace(etag.unwrap_or_default().to_string());
+                        match self.push(href, item, &condition) {
+                            Ok(()) => self.report.pushed_updated += 1,
+                            Err(e) if is_precondition_failed(&e) => {
+                                self.resolve_conflict(href, item)?
+                            }
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    (true, true) => self.resolve_conflict(href, item)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Two-way sync of events and todos with one CalDAV collection.
+///
+/// Remote changes are detected with the collection ctag (listing is skipped
+/// when it did not move) and per-object ETags; local changes by hashing each
+/// item's iCalendar rendering against the one last synced. Remote changes are
+/// imported through the regular ICS import, so they reach the oplog. When both
+/// sides changed, the side modified last wins. Floating remote times are read
+/// in `default_tz`.
+pub fn sync_caldav(
+    conn: &Connection,
+    key: &[u8; 32],
+    collection: &dyn CalDavCollection,
+    default_tz: Option<&str>,
+    now_ms: i64,
+) -> Result<CalDavSyncReport> {
+    let collection_id = collection.collection_id().to_string();
+    let mut session = CalDavSession {
+        conn,
+        key,
+        collection,
+        default_tz,
+        now_ms,
+        report: CalDavSyncReport::default(),
+        wrote_remote: false,
+    };
+
+    let mappings = db::list_caldav_items(conn, &collection_id)?;
+    let ctag = collection.ctag()?;
+    let remote: BTreeMap<String, Option<String>> =
+        if ctag.is_some() && ctag == db::get_caldav_collection_ctag(conn, &collection_id)? {
+            mappings
+                .iter()
+                .filter(|mapping| !mapping.remote_deleted)
+                .map(|mapping| (mapping.href.clone(), mapping.etag.clone()))
+                .collect()
+        } else {
+            collection
+                .list()?
+                .into_iter()
+                .map(|resource| (resource.href, resource.etag))
+                .collect()
+        };
+    let local: HashMap<(String, String), IcsItem> = list_ics_items(conn, key)?
+        .into_iter()
+        .map(|item| ((item.kind.clone(), item.item_id.clone()), item))
+        .collect();
+
+    let mut seen_hrefs: HashSet<String> = HashSet::new();
+    let mut synced_items: HashSet<(String, String)> = HashSet::new();
+    for mapping in &mappings {
+        seen_hrefs.insert(mapping.href.clone());
+        let item_key = (mapping.item_kind.clone(), mapping.item_id.clone());
+        synced_items.insert(item_key.clone());
+        let remote_etag = remote.get(&mapping.href).map(|etag| etag.as_deref());
+        if let Err(e) = session.sync_known(mapping, remote_etag, local.get(&item_key)) {
+            session.report.errors.push(format!("{}: {e}", mapping.href));
+        }
+    }
+
+    for href in remote.keys().filter(|href| !seen_hrefs.contains(*href)) {
+        match session.pull(href) {
+            Ok(Some(item)) => {
+                synced_items.insert((item.kind, item.item_id));
+                session.report.pulled_created += 1;
+            }
+            Ok(None) => {}
+            Err(e) => session.report.errors.push(format!("{href}: {e}")),
+        }
+    }
+
+    let mut unsynced = local
+        .iter()
+        .filter(|(item_key, _)| !synced_items.contains(*item_key))
+        .map(|(_, item)| item)
+        .collect::<Vec<_>>();
+    unsynced.sort_by(|a, b| a.uid.cmp(&b.uid));
+    for item in unsynced {
+        let href = collection.href_for_uid(&item.uid);
+        match session.push(&href, item, &CalDavPutCondition::Create) {
+            Ok(()) => session.report.pushed_created += 1,
+            Err(e) if is_precondition_failed(&e) => match session.resolve_conflict(&href, item) {
+                Ok(()) => {}
+                Err(e) => session.report.errors.push(format!("{href}: {e}")),
+            },
+            Err(e) => session.report.errors.push(format!("{href}: {e}")),
+        }
+    }
+
+    // Our own writes move the ctag too; forget it so the next sync lists the
+    // collection instead of trusting a tag that may also cover others' edits.
+    let next_ctag = if session.wrote_remote { None } else { ctag };
+    db::set_caldav_collection_ctag(conn, &collection_id, next_ctag.as_deref(), now_ms)?;
+    Ok(session.report)
+}
@@ -8,6 +8,12 @@ fn apply_op(conn: &Connection, db_key: &[u8; 32], op: &serde_json::Value) -> Res
         "message.set.v2" => apply_message_set_v2(conn, db_key, op),
         "tag.upsert.v2" => apply_tag_upsert(conn, db_key, &op["payload"]),
         "tag.delete.v1" => apply_tag_delete(conn, &op["payload"]),
+        "tag_rule.upsert.v1" => apply_tag_rule_upsert(conn, db_key, &op["payload"]),
+        "tag_rule.delete.v1" => apply_tag_rule_delete(conn, &op["payload"]),
+        "prompt_template.upsert.v1" => apply_prompt_template_upsert(conn, db_key, &op["payload"]),
+        "prompt_template.delete.v1" => apply_prompt_template_delete(conn, &op["payload"]),
+        "llm_model_price.upsert.v1" => apply_llm_model_price_upsert(conn, &op["payload"]),
+        "llm_model_price.delete.v1" => apply_llm_model_price_delete(conn, &op["payload"]),
         "message.tag_set.v1" => apply_message_tag_set(conn, db_key, &op["payload"]),
         "attachment.upsert.v1" => apply_attachment_upsert(conn, db_key, &op["payload"]),
         "attachment.delete.v1" => apply_attachment_delete(conn, db_key, op),
@@ -20,15 +26,22 @@ fn apply_op(conn: &Connection, db_key: &[u8; 32], op: &serde_json::Value) -> Res
             apply_attachment_annotation_upsert(conn, db_key, &op["payload"])
         }
         "message.attachment.link.v1" => apply_message_attachment_link(conn, db_key, &op["payload"]),
+        "message.citations.set.v1" => apply_message_citations_set(conn, &op["payload"]),
         "todo.upsert.v1" => apply_todo_upsert(conn, db_key, &op["payload"]),
         "todo.recurrence.upsert.v1" => apply_todo_recurrence_upsert(conn, &op["payload"]),
         "todo.delete.v1" => apply_todo_delete(conn, op),
+        "todo.dependency.set.v1" => apply_todo_dependency_set(conn, &op["payload"]),
+        "review.item.upsert.v1" => apply_review_item_upsert(conn, &op["payload"]),
+        "review.activity.append.v1" => apply_review_activity_append(conn, &op["payload"]),
         "todo.activity.append.v1" => apply_todo_activity_append(conn, db_key, &op["payload"]),
         "todo.activity.move.v1" => apply_todo_activity_move(conn, op),
         "todo.activity_attachment.link.v1" => {
             apply_todo_activity_attachment_link(conn, db_key, &op["payload"])
         }
         "event.upsert.v1" => apply_event_upsert(conn, db_key, &op["payload"]),
+        "event.delete.v1" => apply_event_delete(conn, &op["payload"]),
+        "event.recurrence.set.v1" => apply_event_recurrence_set(conn, &op["payload"]),
+        "event.exception.upsert.v1" => apply_event_exception_upsert(conn, db_key, &op["payload"]),
         other => Err(anyhow!("unsupported sync op type: {other}")),
     }
 }
@@ -198,13 +211,23 @@ fn apply_tag_upsert(
         .map(std::string::ToString::to_string);
     let color = if target_is_system { None } else { color };
 
+    // Ops from devices without tag nesting omit `parent_id`; keep the local
+    // parent then instead of flattening the tag.
+    let has_parent_id = !target_is_system && payload.get("parent_id").is_some();
+    let parent_id = payload["parent_id"]
+        .as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && *s != target_tag_id)
+        .map(std::string::ToString::to_string);
+    let parent_id = if has_parent_id { parent_id } else { None };
+
     let aad = format!("tag.name:{target_tag_id}");
     let name_blob = encrypt_bytes(db_key, target_name.as_bytes(), aad.as_bytes())?;
 
     conn.execute(
         r#"
-INSERT INTO tags(id, name, system_key, is_system, color, created_at_ms, updated_at_ms)
-VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+INSERT INTO tags(id, name, system_key, is_system, color, parent_id, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?5, ?8, ?6, ?7)
 ON CONFLICT(id) DO UPDATE SET
   name = CASE
     WHEN excluded.updated_at_ms >= tags.updated_at_ms THEN excluded.name
@@ -222,6 +245,10 @@ ON CONFLICT(id) DO UPDATE SET
     WHEN excluded.updated_at_ms >= tags.updated_at_ms THEN excluded.color
     ELSE tags.color
   END,
+  parent_id = CASE
+    WHEN ?9 = 1 AND excluded.updated_at_ms >= tags.updated_at_ms THEN excluded.parent_id
+    ELSE tags.parent_id
+  END,
   created_at_ms = min(tags.created_at_ms, excluded.created_at_ms),
   updated_at_ms = max(tags.updated_at_ms, excluded.updated_at_ms)
 "#,
@@ -232,7 +259,9 @@ ON CONFLICT(id) DO UPDATE SET
             if target_is_system { 1 } else { 0 },
             color,
             created_at_ms,
-            updated_at_ms
+            updated_at_ms,
+            parent_id,
+            if has_parent_id { 1 } else { 0 }
         ],
     )?;
 
@@ -271,6 +300,14 @@ fn apply_tag_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()
         return Err(anyhow!("tag.delete.v1 cannot delete system tag: {tag_id}"));
     }
 
+    // Children normally moved already (merge re-parents them first); anything
+    // left over moves up one level rather than dangling.
+    conn.execute(
+        r#"UPDATE tags
+           SET parent_id = (SELECT parent_id FROM tags WHERE id = ?1)
+           WHERE parent_id = ?1"#,
+        params![tag_id],
+    )?;
     conn.execute(
         r#"DELETE FROM message_tags WHERE tag_id = ?1"#,
         params![tag_id],
@@ -281,6 +318,246 @@ fn apply_tag_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()
     Ok(())
 }
 
+fn apply_tag_rule_upsert(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    payload: &serde_json::Value,
+) -> Result<()> {
+    let rule_id = payload["rule_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("tag_rule.upsert.v1 missing rule_id"))?
+        .trim();
+    if rule_id.is_empty() {
+        return Err(anyhow!("tag_rule.upsert.v1 rule_id cannot be empty"));
+    }
+    let created_at_ms = payload["created_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("tag_rule.upsert.v1 missing created_at_ms"))?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("tag_rule.upsert.v1 missing updated_at_ms"))?;
+
+    let deleted_at_key = format!("tag_rule.deleted_at:{rule_id}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if existing_deleted_at_ms > 0 && updated_at_ms <= existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    let rule = serde_json::json!({
+        "name": payload["name"].as_str().unwrap_or_default(),
+        "kind": payload["kind"].as_str().unwrap_or_default(),
+        "pattern": payload["pattern"].as_str().unwrap_or_default(),
+        "tag_id": payload["tag_id"].as_str().unwrap_or_default(),
+        "enabled": payload["enabled"].as_bool().unwrap_or(true),
+    });
+    let aad = format!("tag_rule.rule:{rule_id}");
+    let rule_blob = encrypt_bytes(db_key, &serde_json::to_vec(&rule)?, aad.as_bytes())?;
+
+    conn.execute(
+        r#"
+INSERT INTO tag_rules(id, rule, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4)
+ON CONFLICT(id) DO UPDATE SET
+  rule = CASE
+    WHEN excluded.updated_at_ms >= tag_rules.updated_at_ms THEN excluded.rule
+    ELSE tag_rules.rule
+  END,
+  created_at_ms = min(tag_rules.created_at_ms, excluded.created_at_ms),
+  updated_at_ms = max(tag_rules.updated_at_ms, excluded.updated_at_ms)
+"#,
+        params![rule_id, rule_blob, created_at_ms, updated_at_ms],
+    )?;
+
+    Ok(())
+}
+
+fn apply_tag_rule_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let rule_id = payload["rule_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("tag_rule.delete.v1 missing rule_id"))?
+        .trim();
+    if rule_id.is_empty() {
+        return Err(anyhow!("tag_rule.delete.v1 rule_id cannot be empty"));
+    }
+    let deleted_at_ms = payload["deleted_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("tag_rule.delete.v1 missing deleted_at_ms"))?;
+
+    let deleted_at_key = format!("tag_rule.deleted_at:{rule_id}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if deleted_at_ms < existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"DELETE FROM tag_rules WHERE id = ?1 AND updated_at_ms <= ?2"#,
+        params![rule_id, deleted_at_ms],
+    )?;
+    kv_set_i64(conn, &deleted_at_key, deleted_at_ms)?;
+
+    Ok(())
+}
+
+fn apply_prompt_template_upsert(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    payload: &serde_json::Value,
+) -> Result<()> {
+    let template_id = payload["template_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("prompt_template.upsert.v1 missing template_id"))?
+        .trim();
+    if template_id.is_empty() {
+        return Err(anyhow!("prompt_template.upsert.v1 template_id cannot be empty"));
+    }
+    let created_at_ms = payload["created_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("prompt_template.upsert.v1 missing created_at_ms"))?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("prompt_template.upsert.v1 missing updated_at_ms"))?;
+
+    let deleted_at_key = format!("prompt_template.deleted_at:{template_id}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if existing_deleted_at_ms > 0 && updated_at_ms <= existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    let template = serde_json::json!({
+        "name": payload["name"].as_str().unwrap_or_default(),
+        "purpose": payload["purpose"].as_str().unwrap_or_default(),
+        "body": payload["body"].as_str().unwrap_or_default(),
+    });
+    let aad = format!("prompt_template.template:{template_id}");
+    let template_blob = encrypt_bytes(db_key, &serde_json::to_vec(&template)?, aad.as_bytes())?;
+
+    conn.execute(
+        r#"
+INSERT INTO prompt_templates(id, template, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4)
+ON CONFLICT(id) DO UPDATE SET
+  template = CASE
+    WHEN excluded.updated_at_ms >= prompt_templates.updated_at_ms THEN excluded.template
+    ELSE prompt_templates.template
+  END,
+  created_at_ms = min(prompt_templates.created_at_ms, excluded.created_at_ms),
+  updated_at_ms = max(prompt_templates.updated_at_ms, excluded.updated_at_ms)
+"#,
+        params![template_id, template_blob, created_at_ms, updated_at_ms],
+    )?;
+
+    Ok(())
+}
+
+fn apply_prompt_template_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let template_id = payload["template_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("prompt_template.delete.v1 missing template_id"))?
+        .trim();
+    if template_id.is_empty() {
+        return Err(anyhow!("prompt_template.delete.v1 template_id cannot be empty"));
+    }
+    let deleted_at_ms = payload["deleted_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("prompt_template.delete.v1 missing deleted_at_ms"))?;
+
+    let deleted_at_key = format!("prompt_template.deleted_at:{template_id}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if deleted_at_ms < existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"DELETE FROM prompt_templates WHERE id = ?1 AND updated_at_ms <= ?2"#,
+        params![template_id, deleted_at_ms],
+    )?;
+    kv_set_i64(conn, &deleted_at_key, deleted_at_ms)?;
+
+    Ok(())
+}
+
+fn llm_model_price_key_from_payload<'a>(
+    payload: &'a serde_json::Value,
+    op_type: &str,
+) -> Result<(&'a str, &'a str)> {
+    let provider_type = payload["provider_type"]
+        .as_str()
+        .ok_or_else(|| anyhow!("{op_type} missing provider_type"))?
+        .trim();
+    let model_name = payload["model_name"]
+        .as_str()
+        .ok_or_else(|| anyhow!("{op_type} missing model_name"))?
+        .trim();
+    if provider_type.is_empty() || model_name.is_empty() {
+        return Err(anyhow!("{op_type} provider_type and model_name cannot be empty"));
+    }
+    Ok((provider_type, model_name))
+}
+
+fn apply_llm_model_price_upsert(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let (provider_type, model_name) =
+        llm_model_price_key_from_payload(payload, "llm_model_price.upsert.v1")?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("llm_model_price.upsert.v1 missing updated_at_ms"))?;
+
+    let deleted_at_key = format!("llm_model_price.deleted_at:{provider_type}:{model_name}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if existing_deleted_at_ms > 0 && updated_at_ms <= existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    let input_price = payload["input_usd_per_million_tokens"]
+        .as_f64()
+        .unwrap_or_default();
+    let output_price = payload["output_usd_per_million_tokens"]
+        .as_f64()
+        .unwrap_or_default();
+
+    conn.execute(
+        r#"
+INSERT INTO llm_model_prices(provider_type, model_name, input_usd_per_mtok, output_usd_per_mtok, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?5)
+ON CONFLICT(provider_type, model_name) DO UPDATE SET
+  input_usd_per_mtok = CASE
+    WHEN excluded.updated_at_ms >= llm_model_prices.updated_at_ms THEN excluded.input_usd_per_mtok
+    ELSE llm_model_prices.input_usd_per_mtok
+  END,
+  output_usd_per_mtok = CASE
+    WHEN excluded.updated_at_ms >= llm_model_prices.updated_at_ms THEN excluded.output_usd_per_mtok
+    ELSE llm_model_prices.output_usd_per_mtok
+  END,
+  updated_at_ms = max(llm_model_prices.updated_at_ms, excluded.updated_at_ms)
+"#,
+        params![provider_type, model_name, input_price, output_price, updated_at_ms],
+    )?;
+
+    Ok(())
+}
+
+fn apply_llm_model_price_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let (provider_type, model_name) =
+        llm_model_price_key_from_payload(payload, "llm_model_price.delete.v1")?;
+    let deleted_at_ms = payload["deleted_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("llm_model_price.delete.v1 missing deleted_at_ms"))?;
+
+    let deleted_at_key = format!("llm_model_price.deleted_at:{provider_type}:{model_name}");
+    let existing_deleted_at_ms = kv_get_i64(conn, &deleted_at_key)?.unwrap_or(0);
+    if deleted_at_ms < existing_deleted_at_ms {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"DELETE FROM llm_model_prices
+           WHERE provider_type = ?1 AND model_name = ?2 AND updated_at_ms <= ?3"#,
+        params![provider_type, model_name, deleted_at_ms],
+    )?;
+    kv_set_i64(conn, &deleted_at_key, deleted_at_ms)?;
+
+    Ok(())
+}
+
 fn ensure_placeholder_conversation_row(
     conn: &Connection,
     db_key: &[u8; 32],
@@ -350,15 +627,26 @@ fn apply_todo_upsert(
     let review_stage = payload["review_stage"].as_i64();
     let next_review_at_ms = payload["next_review_at_ms"].as_i64();
     let last_review_at_ms = payload["last_review_at_ms"].as_i64();
+    // Ops from devices without todo timezones omit `tz`; keep the local value.
+    let has_tz = payload.get("tz").is_some();
+    let tz = payload["tz"].as_str().filter(|s| !s.trim().is_empty());
+    // Likewise for subtasks: older ops omit `parent_id`/`complete_with_subtasks`.
+    let has_hierarchy = payload.get("parent_id").is_some();
+    let parent_id = payload["parent_id"]
+        .as_str()
+        .filter(|s| !s.trim().is_empty() && *s != todo_id);
+    let has_complete_with_subtasks = payload.get("complete_with_subtasks").is_some();
+    let complete_with_subtasks = payload["complete_with_subtasks"].as_bool().unwrap_or(false);
 
     let title_blob = encrypt_bytes(db_key, title.as_bytes(), b"todo.title")?;
     conn.execute(
         r#"
 INSERT INTO todos(
   id, title, due_at_ms, status, source_entry_id, created_at_ms, updated_at_ms,
-  review_stage, next_review_at_ms, last_review_at_ms, needs_embedding
+  review_stage, next_review_at_ms, last_review_at_ms, tz, parent_id, complete_with_subtasks,
+  needs_embedding
 )
-VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 1)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?13, ?15, 1)
 ON CONFLICT(id) DO UPDATE SET
   title = excluded.title,
   due_at_ms = excluded.due_at_ms,
@@ -368,6 +656,12 @@ ON CONFLICT(id) DO UPDATE SET
   review_stage = excluded.review_stage,
   next_review_at_ms = excluded.next_review_at_ms,
   last_review_at_ms = excluded.last_review_at_ms,
+  tz = CASE WHEN ?12 = 1 THEN excluded.tz ELSE todos.tz END,
+  parent_id = CASE WHEN ?14 = 1 THEN excluded.parent_id ELSE todos.parent_id END,
+  complete_with_subtasks = CASE
+    WHEN ?16 = 1 THEN excluded.complete_with_subtasks
+    ELSE todos.complete_with_subtasks
+  END,
   needs_embedding = 1
 WHERE excluded.updated_at_ms >= todos.updated_at_ms
 "#,
@@ -382,12 +676,63 @@ WHERE excluded.updated_at_ms >= todos.updated_at_ms
             review_stage,
             next_review_at_ms,
             last_review_at_ms,
+            tz,
+            if has_tz { 1 } else { 0 },
+            parent_id,
+            if has_hierarchy { 1 } else { 0 },
+            if complete_with_subtasks { 1 } else { 0 },
+            if has_complete_with_subtasks { 1 } else { 0 },
         ],
     )?;
 
     Ok(())
 }
 
+fn apply_todo_dependency_set(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let todo_id = payload["todo_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("todo dependency op missing todo_id"))?;
+    let blocked_by_todo_id = payload["blocked_by_todo_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("todo dependency op missing blocked_by_todo_id"))?;
+    let is_deleted = payload["is_deleted"].as_bool().unwrap_or(false);
+    let created_at_ms = payload["created_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("todo dependency op missing created_at_ms"))?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("todo dependency op missing updated_at_ms"))?;
+
+    // A deleted todo takes its dependencies with it.
+    let deleted: i64 = conn.query_row(
+        r#"SELECT COUNT(*) FROM todo_deletions WHERE todo_id IN (?1, ?2)"#,
+        params![todo_id, blocked_by_todo_id],
+        |row| row.get(0),
+    )?;
+    if deleted > 0 || todo_id == blocked_by_todo_id {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+INSERT INTO todo_dependencies(todo_id, blocked_by_todo_id, is_deleted, created_at_ms, updated_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?5)
+ON CONFLICT(todo_id, blocked_by_todo_id) DO UPDATE SET
+  is_deleted = excluded.is_deleted,
+  updated_at_ms = excluded.updated_at_ms
+WHERE excluded.updated_at_ms > todo_dependencies.updated_at_ms
+"#,
+        params![
+            todo_id,
+            blocked_by_todo_id,
+            if is_deleted { 1 } else { 0 },
+            created_at_ms,
+            updated_at_ms
+        ],
+    )?;
+    Ok(())
+}
+
 fn apply_todo_recurrence_upsert(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
     let todo_id = payload["todo_id"]
         .as_str()
@@ -590,6 +935,18 @@ ON CONFLICT(todo_id) DO UPDATE SET
         r#"DELETE FROM todo_embeddings WHERE todo_id = ?1"#,
         params![todo_id],
     )?;
+    conn.execute(
+        r#"DELETE FROM todo_dependencies WHERE todo_id = ?1 OR blocked_by_todo_id = ?1"#,
+        params![todo_id],
+    )?;
+    conn.execute(
+        r#"DELETE FROM review_items WHERE item_kind = 'todo' AND item_id = ?1"#,
+        params![todo_id],
+    )?;
+    conn.execute(
+        r#"DELETE FROM review_activities WHERE item_kind = 'todo' AND item_id = ?1"#,
+        params![todo_id],
+    )?;
 
     conn.execute(r#"DELETE FROM todos WHERE id = ?1"#, params![todo_id])?;
 
@@ -876,6 +1233,24 @@ fn apply_event_upsert(
         .as_i64()
         .ok_or_else(|| anyhow!("event op missing updated_at_ms"))?;
 
+    let existing_delete: Option<i64> = conn
+        .query_row(
+            r#"SELECT deleted_at_ms FROM event_deletions WHERE event_id = ?1"#,
+            params![event_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(deleted_at_ms) = existing_delete {
+        // Ignore upserts that are older than (or equal to) the delete tombstone.
+        if updated_at_ms <= deleted_at_ms {
+            return Ok(());
+        }
+        conn.execute(
+            r#"DELETE FROM event_deletions WHERE event_id = ?1"#,
+            params![event_id],
+        )?;
+    }
+
     let source_entry_id = payload["source_entry_id"].as_str();
 
     let title_blob = encrypt_bytes(db_key, title.as_bytes(), b"event.title")?;
@@ -908,3 +1283,299 @@ WHERE excluded.updated_at_ms > events.updated_at_ms
 
     Ok(())
 }
+
+fn apply_event_delete(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let event_id = payload["event_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("event.delete.v1 missing event_id"))?;
+    let deleted_at_ms = payload["deleted_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("event.delete.v1 missing deleted_at_ms"))?;
+
+    conn.execute(
+        r#"
+INSERT INTO event_deletions(event_id, deleted_at_ms)
+VALUES (?1, ?2)
+ON CONFLICT(event_id) DO UPDATE SET
+  deleted_at_ms = max(event_deletions.deleted_at_ms, excluded.deleted_at_ms)
+"#,
+        params![event_id, deleted_at_ms],
+    )?;
+    // Edits made after the delete win.
+    conn.execute(
+        r#"DELETE FROM events WHERE id = ?1 AND updated_at_ms <= ?2"#,
+        params![event_id, deleted_at_ms],
+    )?;
+    for sql in [
+        r#"DELETE FROM event_recurrences
+           WHERE event_id = ?1 AND NOT EXISTS (SELECT 1 FROM events WHERE id = ?1)"#,
+        r#"DELETE FROM event_exceptions
+           WHERE event_id = ?1 AND NOT EXISTS (SELECT 1 FROM events WHERE id = ?1)"#,
+    ] {
+        conn.execute(sql, params![event_id])?;
+    }
+    Ok(())
+}
+
+/// Whether an event change stamped `updated_at_ms` predates the event's
+/// delete tombstone.
+fn is_event_change_deleted(conn: &Connection, event_id: &str, updated_at_ms: i64) -> Result<bool> {
+    let deleted_at_ms: Option<i64> = conn
+        .query_row(
+            r#"SELECT deleted_at_ms FROM event_deletions WHERE event_id = ?1"#,
+            params![event_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(deleted_at_ms.is_some_and(|deleted_at_ms| updated_at_ms <= deleted_at_ms))
+}
+
+fn apply_event_recurrence_set(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let event_id = payload["event_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("event recurrence op missing event_id"))?;
+    let rule_json = payload["rule_json"].as_str();
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("event recurrence op missing updated_at_ms"))?;
+    if is_event_change_deleted(conn, event_id, updated_at_ms)? {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+INSERT INTO event_recurrences(event_id, rule_json, updated_at_ms)
+VALUES (?1, ?2, ?3)
+ON CONFLICT(event_id) DO UPDATE SET
+  rule_json = excluded.rule_json,
+  updated_at_ms = excluded.updated_at_ms
+WHERE excluded.updated_at_ms > event_recurrences.updated_at_ms
+"#,
+        params![event_id, rule_json, updated_at_ms],
+    )?;
+    Ok(())
+}
+
+fn apply_event_exception_upsert(
+    conn: &Connection,
+    db_key: &[u8; 32],
+    payload: &serde_json::Value,
+) -> Result<()> {
+    let event_id = payload["event_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("event exception op missing event_id"))?;
+    let occurrence_start_ms = payload["occurrence_start_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("event exception op missing occurrence_start_ms"))?;
+    let kind = payload["kind"]
+        .as_str()
+        .ok_or_else(|| anyhow!("event exception op missing kind"))?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("event exception op missing updated_at_ms"))?;
+    if is_event_change_deleted(conn, event_id, updated_at_ms)? {
+        return Ok(());
+    }
+
+    let title_blob = payload["title"]
+        .as_str()
+        .map(|title| encrypt_bytes(db_key, title.as_bytes(), b"event.exception.title"))
+        .transpose()?;
+    conn.execute(
+        r#"
+INSERT INTO event_exceptions(
+  event_id, occurrence_start_ms, kind, title, start_at_ms, end_at_ms, updated_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+ON CONFLICT(event_id, occurrence_start_ms) DO UPDATE SET
+  kind = excluded.kind,
+  title = excluded.title,
+  start_at_ms = excluded.start_at_ms,
+  end_at_ms = excluded.end_at_ms,
+  updated_at_ms = excluded.updated_at_ms
+WHERE excluded.updated_at_ms > event_exceptions.updated_at_ms
+"#,
+        params![
+            event_id,
+            occurrence_start_ms,
+            kind,
+            title_blob,
+            payload["start_at_ms"].as_i64(),
+            payload["end_at_ms"].as_i64(),
+            updated_at_ms
+        ],
+    )?;
+    Ok(())
+}
+
+/// Whether a review op at `op_at_ms` targets a todo deleted no earlier than
+/// the op, or a message that is deleted.
+fn review_target_deleted(
+    conn: &Connection,
+    item_kind: &str,
+    item_id: &str,
+    op_at_ms: i64,
+) -> Result<bool> {
+    if item_kind == "todo" {
+        let deleted_at_ms: Option<i64> = conn
+            .query_row(
+                r#"SELECT deleted_at_ms FROM todo_deletions WHERE todo_id = ?1"#,
+                params![item_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        return Ok(deleted_at_ms.is_some_and(|deleted_at_ms| op_at_ms <= deleted_at_ms));
+    }
+
+    let is_deleted: Option<i64> = conn
+        .query_row(
+            r#"SELECT is_deleted FROM messages WHERE id = ?1"#,
+            params![item_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(is_deleted == Some(1))
+}
+
+fn apply_review_item_upsert(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let item_kind = payload["item_kind"]
+        .as_str()
+        .ok_or_else(|| anyhow!("review item op missing item_kind"))?;
+    let item_id = payload["item_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("review item op missing item_id"))?;
+    let is_active = payload["is_active"].as_bool().unwrap_or(true);
+    let ease_factor = payload["ease_factor"]
+        .as_f64()
+        .ok_or_else(|| anyhow!("review item op missing ease_factor"))?;
+    let interval_days = payload["interval_days"].as_i64().unwrap_or(0);
+    let repetitions = payload["repetitions"].as_i64().unwrap_or(0);
+    let lapses = payload["lapses"].as_i64().unwrap_or(0);
+    let due_at_ms = payload["due_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review item op missing due_at_ms"))?;
+    let last_reviewed_at_ms = payload["last_reviewed_at_ms"].as_i64();
+    let created_at_ms = payload["created_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review item op missing created_at_ms"))?;
+    let updated_at_ms = payload["updated_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review item op missing updated_at_ms"))?;
+    if review_target_deleted(conn, item_kind, item_id, updated_at_ms)? {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+INSERT INTO review_items(
+  item_kind, item_id, is_active, ease_factor, interval_days, repetitions, lapses,
+  due_at_ms, last_reviewed_at_ms, created_at_ms, updated_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+ON CONFLICT(item_kind, item_id) DO UPDATE SET
+  is_active = excluded.is_active,
+  ease_factor = excluded.ease_factor,
+  interval_days = excluded.interval_days,
+  repetitions = excluded.repetitions,
+  lapses = excluded.lapses,
+  due_at_ms = excluded.due_at_ms,
+  last_reviewed_at_ms = excluded.last_reviewed_at_ms,
+  updated_at_ms = excluded.updated_at_ms
+WHERE excluded.updated_at_ms > review_items.updated_at_ms
+"#,
+        params![
+            item_kind,
+            item_id,
+            if is_active { 1 } else { 0 },
+            ease_factor,
+            interval_days,
+            repetitions,
+            lapses,
+            due_at_ms,
+            last_reviewed_at_ms,
+            created_at_ms,
+            updated_at_ms,
+        ],
+    )?;
+    Ok(())
+}
+
+fn apply_message_citations_set(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let message_id = payload["message_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("message citations op missing message_id"))?;
+    let citations = payload["citations"]
+        .as_array()
+        .ok_or_else(|| anyhow!("message citations op missing citations"))?;
+    let created_at_ms = payload["created_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("message citations op missing created_at_ms"))?;
+
+    for citation in citations {
+        let marker = citation["marker"]
+            .as_i64()
+            .ok_or_else(|| anyhow!("message citation missing marker"))?;
+        let source_kind = citation["source_kind"]
+            .as_str()
+            .ok_or_else(|| anyhow!("message citation missing source_kind"))?;
+        let source_id = citation["source_id"]
+            .as_str()
+            .ok_or_else(|| anyhow!("message citation missing source_id"))?;
+        conn.execute(
+            r#"
+INSERT OR IGNORE INTO message_citations(message_id, marker, source_kind, source_id, created_at_ms)
+VALUES (?1, ?2, ?3, ?4, ?5)
+"#,
+            params![message_id, marker, source_kind, source_id, created_at_ms],
+        )?;
+    }
+    Ok(())
+}
+
+fn apply_review_activity_append(conn: &Connection, payload: &serde_json::Value) -> Result<()> {
+    let activity_id = payload["activity_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("review activity op missing activity_id"))?;
+    let item_kind = payload["item_kind"]
+        .as_str()
+        .ok_or_else(|| anyhow!("review activity op missing item_kind"))?;
+    let item_id = payload["item_id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("review activity op missing item_id"))?;
+    let grade = payload["grade"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review activity op missing grade"))?;
+    let ease_factor = payload["ease_factor"]
+        .as_f64()
+        .ok_or_else(|| anyhow!("review activity op missing ease_factor"))?;
+    let interval_days = payload["interval_days"].as_i64().unwrap_or(0);
+    let due_at_ms = payload["due_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review activity op missing due_at_ms"))?;
+    let reviewed_at_ms = payload["reviewed_at_ms"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("review activity op missing reviewed_at_ms"))?;
+    if review_target_deleted(conn, item_kind, item_id, reviewed_at_ms)? {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+INSERT OR IGNORE INTO review_activities(
+  id, item_kind, item_id, grade, ease_factor, interval_days, due_at_ms, reviewed_at_ms
+)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+"#,
+        params![
+            activity_id,
+            item_kind,
+            item_id,
+            grade,
+            ease_factor,
+            interval_days,
+            due_at_ms,
+            reviewed_at_ms
+        ],
+    )?;
+    Ok(())
+}
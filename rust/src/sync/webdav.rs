@@ -37,25 +37,36 @@ fn href_to_path(href: &str) -> Result<String> {
     Ok(href.to_string())
 }
 
-pub fn parse_propfind_multistatus(
-    base_path: &str,
-    requested_virtual_dir: &str,
-    xml: &[u8],
-) -> Result<Vec<String>> {
-    let base_path = normalize_base_path(base_path);
-    let requested_dir = normalize_dir(requested_virtual_dir);
+/// One `<response>` of a WebDAV multistatus body.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultistatusEntry {
+    pub href: String,
+    pub is_collection: bool,
+    pub etag: Option<String>,
+    /// `CS:getctag`, reported by CalDAV servers for calendar collections.
+    pub ctag: Option<String>,
+    /// `C:calendar-data`, the object body in CalDAV REPORT responses.
+    pub calendar_data: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+enum MultistatusText {
+    Href,
+    Etag,
+    Ctag,
+    CalendarData,
+}
 
+/// Parses the `<response>` elements of a multistatus body. Responses without
+/// an href are skipped; hrefs are returned as sent by the server.
+pub fn parse_multistatus(xml: &[u8]) -> Result<Vec<MultistatusEntry>> {
     let mut reader = Reader::from_reader(xml);
     reader.trim_text(true);
 
     let mut buf: Vec<u8> = Vec::new();
-
-    let mut in_response = false;
-    let mut in_href = false;
-    let mut current_href: Option<String> = None;
-    let mut current_is_collection = false;
-
-    let mut out: Vec<String> = Vec::new();
+    let mut current: Option<MultistatusEntry> = None;
+    let mut text_target: Option<MultistatusText> = None;
+    let mut out = Vec::new();
 
     loop {
         match reader.read_event_into(&mut buf) {
@@ -64,75 +75,55 @@ pub fn parse_propfind_multistatus(
                 let name = local_name(qname.as_ref());
                 match name {
                     b"response" => {
-                        in_response = true;
-                        in_href = false;
-                        current_href = None;
-                        current_is_collection = false;
+                        current = Some(MultistatusEntry::default());
+                        text_target = None;
                     }
-                    b"href" if in_response => {
-                        in_href = true;
+                    b"href" if current.is_some() => text_target = Some(MultistatusText::Href),
+                    b"getetag" if current.is_some() => text_target = Some(MultistatusText::Etag),
+                    b"getctag" if current.is_some() => text_target = Some(MultistatusText::Ctag),
+                    b"calendar-data" if current.is_some() => {
+                        text_target = Some(MultistatusText::CalendarData)
                     }
-                    b"collection" if in_response => {
-                        current_is_collection = true;
+                    b"collection" => {
+                        if let Some(entry) = current.as_mut() {
+                            entry.is_collection = true;
+                        }
                     }
                     _ => {}
                 }
             }
             Ok(Event::Empty(e)) => {
                 let qname = e.name();
-                let name = local_name(qname.as_ref());
-                if in_response && name == b"collection" {
-                    current_is_collection = true;
+                if local_name(qname.as_ref()) == b"collection" {
+                    if let Some(entry) = current.as_mut() {
+                        entry.is_collection = true;
+                    }
                 }
             }
             Ok(Event::End(e)) => {
                 let qname = e.name();
-                let name = local_name(qname.as_ref());
-                match name {
-                    b"response" if in_response => {
-                        in_response = false;
-                        in_href = false;
-
-                        let Some(href) = current_href.take() else {
-                            buf.clear();
-                            continue;
-                        };
-
-                        let href_path = href_to_path(&href)?;
-                        let Some(rest) = href_path.strip_prefix(&base_path) else {
-                            buf.clear();
-                            continue;
-                        };
-                        let rest = rest.trim_start_matches('/');
-                        if rest.is_empty() {
-                            buf.clear();
-                            continue;
-                        }
-
-                        let mut virtual_path = format!("/{rest}");
-                        if current_is_collection && !virtual_path.ends_with('/') {
-                            virtual_path.push('/');
-                        }
-                        if !current_is_collection
-                            && virtual_path.ends_with('/')
-                            && virtual_path != "/"
-                        {
-                            virtual_path.pop();
-                        }
-
-                        if normalize_dir(&virtual_path) != requested_dir {
-                            out.push(virtual_path);
+                match local_name(qname.as_ref()) {
+                    b"response" => {
+                        if let Some(entry) = current.take().filter(|e| !e.href.is_empty()) {
+                            out.push(entry);
                         }
+                        text_target = None;
                     }
-                    b"href" => {
-                        in_href = false;
-                    }
+                    b"href" | b"getetag" | b"getctag" | b"calendar-data" => text_target = None,
                     _ => {}
                 }
             }
-            Ok(Event::Text(e)) if in_response && in_href => {
-                let text = e.unescape().map_err(|_| anyhow!("invalid xml"))?;
-                current_href = Some(text.to_string());
+            Ok(Event::Text(e)) => {
+                if let (Some(target), Some(entry)) = (text_target, current.as_mut()) {
+                    let text = e.unescape().map_err(|_| anyhow!("invalid xml"))?;
+                    set_multistatus_text(entry, target, text.trim());
+                }
+            }
+            Ok(Event::CData(e)) => {
+                if let (Some(target), Some(entry)) = (text_target, current.as_mut()) {
+                    let text = String::from_utf8_lossy(&e);
+                    set_multistatus_text(entry, target, text.trim());
+                }
             }
             Ok(Event::Eof) => break,
             Err(e) => return Err(anyhow!("xml parse error: {e}")),
@@ -144,6 +135,51 @@ pub fn parse_propfind_multistatus(
     Ok(out)
 }
 
+fn set_multistatus_text(entry: &mut MultistatusEntry, target: MultistatusText, text: &str) {
+    let text = text.to_string();
+    match target {
+        MultistatusText::Href => entry.href = text,
+        MultistatusText::Etag => entry.etag = Some(text),
+        MultistatusText::Ctag => entry.ctag = Some(text),
+        MultistatusText::CalendarData => entry.calendar_data = Some(text),
+    }
+}
+
+pub fn parse_propfind_multistatus(
+    base_path: &str,
+    requested_virtual_dir: &str,
+    xml: &[u8],
+) -> Result<Vec<String>> {
+    let base_path = normalize_base_path(base_path);
+    let requested_dir = normalize_dir(requested_virtual_dir);
+
+    let mut out: Vec<String> = Vec::new();
+    for entry in parse_multistatus(xml)? {
+        let href_path = href_to_path(&entry.href)?;
+        let Some(rest) = href_path.strip_prefix(&base_path) else {
+            continue;
+        };
+        let rest = rest.trim_start_matches('/');
+        if rest.is_empty() {
+            continue;
+        }
+
+        let mut virtual_path = format!("/{rest}");
+        if entry.is_collection && !virtual_path.ends_with('/') {
+            virtual_path.push('/');
+        }
+        if !entry.is_collection && virtual_path.ends_with('/') && virtual_path != "/" {
+            virtual_path.pop();
+        }
+
+        if normalize_dir(&virtual_path) != requested_dir {
+            out.push(virtual_path);
+        }
+    }
+
+    Ok(out)
+}
+
 pub struct WebDavRemoteStore {
     client: Client,
     target_id: String,
@@ -0,0 +1,217 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+fn utc_ms(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> i64 {
+    let date = time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+        .expect("date");
+    let time = time::Time::from_hms(hour, minute, 0).expect("time");
+    time::PrimitiveDateTime::new(date, time)
+        .assume_utc()
+        .unix_timestamp()
+        * 1000
+}
+
+fn open_vault(dir: &tempfile::TempDir) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+fn todo(conn: &rusqlite::Connection, key: &[u8; 32], id: &str, title: &str, due: Option<i64>) {
+    db::upsert_todo(conn, key, id, title, due, "open", None, None, None, None).expect("todo");
+}
+
+fn flatten(page: &db::AgendaPage) -> Vec<String> {
+    page.days
+        .iter()
+        .flat_map(|day| day.items.iter())
+        .map(|item| format!("{}/{}", item.kind, item.item_id))
+        .collect()
+}
+
+#[test]
+fn agenda_merges_sources_into_local_days_and_pages() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp);
+
+    // Berlin leaves summer time on 2024-10-27.
+    todo(
+        &conn,
+        &key,
+        "todo:late",
+        "Overdue report",
+        Some(utc_ms(2024, 10, 20, 9, 0)),
+    );
+    todo(
+        &conn,
+        &key,
+        "todo:rent",
+        "Pay rent",
+        Some(utc_ms(2024, 10, 26, 22, 30)),
+    );
+    todo(
+        &conn,
+        &key,
+        "todo:gym",
+        "Gym",
+        Some(utc_ms(2024, 10, 27, 9, 0)),
+    );
+    todo(
+        &conn,
+        &key,
+        "todo:later",
+        "Out of range",
+        Some(utc_ms(2024, 11, 5, 9, 0)),
+    );
+    db::upsert_event(
+        &conn,
+        &key,
+        "event:standup",
+        "Standup",
+        utc_ms(2024, 10, 25, 7, 0),
+        utc_ms(2024, 10, 25, 7, 15),
+        "Europe/Berlin",
+        None,
+    )
+    .expect("event");
+    db::set_event_recurrence_rule(
+        &conn,
+        &key,
+        "event:standup",
+        Some(r#"{"freq":"daily","interval":1}"#),
+    )
+    .expect("rule");
+
+    let start = utc_ms(2024, 10, 25, 22, 0); // Saturday 00:00 in Berlin
+    let end = utc_ms(2024, 10, 28, 23, 0); // Tuesday 00:00 in Berlin
+    let page = db::agenda_range(&conn, &key, start, end, "Europe/Berlin", None, 0).expect("agenda");
+
+    assert_eq!(
+        page.overdue
+            .iter()
+            .map(|item| item.item_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["todo:late"]
+    );
+    assert_eq!(
+        page.days
+            .iter()
+            .map(|day| (day.date.as_str(), day.day_start_ms))
+            .collect::<Vec<_>>(),
+        vec![
+            ("2024-10-26", utc_ms(2024, 10, 25, 22, 0)),
+            ("2024-10-27", utc_ms(2024, 10, 26, 22, 0)),
+            ("2024-10-28", utc_ms(2024, 10, 27, 23, 0)),
+        ]
+    );
+    // 22:30 UTC on the 26th is already the 27th in Berlin; the standup stays
+    // at 09:00 local across the DST change.
+    assert_eq!(
+        flatten(&page),
+        vec![
+            format!("event/event:standup@{}", utc_ms(2024, 10, 26, 7, 0)),
+            "todo_due/todo:rent".to_string(),
+            format!("event/event:standup@{}", utc_ms(2024, 10, 27, 8, 0)),
+            "todo_due/todo:gym".to_string(),
+            format!("event/event:standup@{}", utc_ms(2024, 10, 28, 8, 0)),
+        ]
+    );
+    let event = &page.days[0].items[0];
+    assert!(event.is_recurring);
+    assert_eq!(event.source_id, "event:standup");
+    assert_eq!(event.end_at_ms, Some(utc_ms(2024, 10, 26, 7, 15)));
+
+    // Paging returns the same sequence, with overdue items only up front.
+    let mut paged = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut pages = 0;
+    loop {
+        let page = db::agenda_range(
+            &conn,
+            &key,
+            start,
+            end,
+            "Europe/Berlin",
+            cursor.as_deref(),
+            2,
+        )
+        .expect("page");
+        assert_eq!(page.overdue.is_empty(), pages > 0);
+        paged.extend(flatten(&page));
+        pages += 1;
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(pages, 3);
+    assert_eq!(
+        paged,
+        flatten(
+            &db::agenda_range(&conn, &key, start, end, "Europe/Berlin", None, 0).expect("agenda")
+        )
+    );
+
+    assert!(db::agenda_range(&conn, &key, start, end, "Mars/Olympus", None, 0).is_err());
+    assert!(db::agenda_range(&conn, &key, start, end, "UTC", Some("bogus"), 0).is_err());
+}
+
+#[test]
+fn agenda_includes_reviews_and_completed_todos() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp);
+    let now: i64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock")
+        .as_millis()
+        .try_into()
+        .expect("ms");
+
+    todo(&conn, &key, "todo:done", "Send invoice", Some(now + DAY_MS));
+    db::set_todo_status(&conn, &key, "todo:done", "done", None).expect("done");
+    todo(&conn, &key, "todo:flash", "Spanish verbs", None);
+    db::mark_for_review(&conn, &key, "todo", "todo:flash", Some(now + HOUR_MS)).expect("mark");
+    todo(&conn, &key, "todo:old", "Old review", None);
+    db::mark_for_review(&conn, &key, "todo", "todo:old", Some(now - 2 * DAY_MS)).expect("mark");
+
+    let page = db::agenda_range(&conn, &key, now - DAY_MS, now + 2 * DAY_MS, "UTC", None, 0)
+        .expect("agenda");
+    let items = page
+        .days
+        .iter()
+        .flat_map(|day| day.items.iter())
+        .collect::<Vec<_>>();
+    let completed = items
+        .iter()
+        .find(|item| item.kind == "completed")
+        .expect("completed item");
+    assert_eq!(completed.source_id, "todo:done");
+    assert_eq!(completed.title, "Send invoice");
+    assert!(items
+        .iter()
+        .any(|item| item.kind == "todo_due" && item.status.as_deref() == Some("done")));
+    let review = items
+        .iter()
+        .find(|item| item.kind == "review")
+        .expect("review item");
+    assert_eq!(review.item_id, "todo:todo:flash");
+    assert_eq!(review.at_ms, now + HOUR_MS);
+    assert_eq!(
+        page.overdue
+            .iter()
+            .map(|item| (item.kind.as_str(), item.source_id.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("review", "todo:old")]
+    );
+
+    for day in &page.days {
+        for item in &day.items {
+            assert!(item.at_ms >= day.day_start_ms && item.at_ms < day.day_start_ms + DAY_MS);
+        }
+    }
+}
@@ -19,11 +19,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -79,6 +81,7 @@ fn ask_ai_agenda_includes_actions_context() {
         "What should I do today?",
         0,
         rag::Focus::AllMemories,
+        Some("America/New_York"),
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -92,4 +95,51 @@ fn ask_ai_agenda_includes_actions_context() {
         .expect("prompt");
     assert!(prompt.contains("Buy milk"));
     assert!(prompt.contains("Lunch with Alice"));
+    assert!(
+        prompt.contains(&format!(
+            "local_day={}",
+            local_day(now_ms + 30 * 60 * 1000, "America/New_York")
+        )),
+        "{prompt}"
+    );
+
+    // A timezone this build does not know falls back to UTC days.
+    rag::ask_ai_with_provider(
+        &conn,
+        &key,
+        &conversation.id,
+        "What should I do today?",
+        0,
+        rag::Focus::AllMemories,
+        Some("Mars/Olympus"),
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask with unknown timezone");
+    let prompt = provider
+        .last_prompt
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("prompt");
+    assert!(prompt.contains("Lunch with Alice"));
+    assert!(
+        prompt.contains(&format!(
+            "local_day={}",
+            local_day(now_ms + 30 * 60 * 1000, "UTC")
+        )),
+        "{prompt}"
+    );
+}
+
+fn local_day(at_ms: i64, tz: &str) -> String {
+    let date = db::timezone_wall_clock(at_ms, Some(tz))
+        .expect("wall clock")
+        .date();
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
 }
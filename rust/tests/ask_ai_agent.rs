@@ -0,0 +1,238 @@
+use anyhow::Result;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::llm::tools::{
+    ToolCall, ToolCallingProvider, ToolChoice, ToolMessage, ToolSpec, ToolTurn,
+};
+use secondloop_rust::rag::agent;
+use secondloop_rust::{auth, db, embedding, sync};
+use serde_json::json;
+
+fn open_vault(
+    dir: &tempfile::TempDir,
+    name: &str,
+) -> (std::path::PathBuf, rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (app_dir, conn, key)
+}
+
+/// Plays back scripted turns and records what it was sent.
+struct ScriptedAgent {
+    turns: std::sync::Mutex<Vec<ToolTurn>>,
+    requests: std::sync::Mutex<Vec<(Vec<ToolMessage>, usize, ToolChoice)>>,
+}
+
+impl ScriptedAgent {
+    fn new(mut turns: Vec<ToolTurn>) -> Self {
+        turns.reverse();
+        Self {
+            turns: std::sync::Mutex::new(turns),
+            requests: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ToolCallingProvider for ScriptedAgent {
+    fn complete_with_tools(
+        &self,
+        messages: &[ToolMessage],
+        tools: &[ToolSpec],
+        choice: ToolChoice,
+    ) -> Result<ToolTurn> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((messages.to_vec(), tools.len(), choice));
+        Ok(self.turns.lock().unwrap().pop().expect("scripted turn"))
+    }
+}
+
+fn call(id: &str, name: &str, arguments: serde_json::Value) -> ToolTurn {
+    ToolTurn {
+        text: String::new(),
+        tool_calls: vec![ToolCall {
+            id: id.to_string(),
+            name: name.to_string(),
+            arguments,
+        }],
+        usage: None,
+    }
+}
+
+fn answer(text: &str) -> ToolTurn {
+    ToolTurn {
+        text: text.to_string(),
+        tool_calls: Vec::new(),
+        usage: None,
+    }
+}
+
+#[test]
+fn agent_searches_then_creates_a_synced_todo() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (app_dir, conn, key) = open_vault(&temp, "a");
+    let (_app_dir_b, conn_b, key_b) = open_vault(&temp, "b");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME).expect("model");
+    let notes = db::create_conversation(&conn, &key, "Notes").expect("notes");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Dentist said: book a cleaning in March",
+    )
+    .expect("note");
+
+    let provider = ScriptedAgent::new(vec![
+        call(
+            "c1",
+            agent::TOOL_SEARCH_MEMORIES,
+            json!({"query": "dentist"}),
+        ),
+        call(
+            "c2",
+            agent::TOOL_CREATE_TODO,
+            json!({"title": "Book dental cleaning", "due_at_ms": 1_710_000_000_000i64}),
+        ),
+        answer("Added \"Book dental cleaning\" to your todos."),
+    ]);
+
+    let mut tool_roles = Vec::new();
+    let mut text = String::new();
+    let result = agent::ask_ai_agent(
+        &conn,
+        &key,
+        &app_dir,
+        &chat.id,
+        "add that as a todo",
+        agent::DEFAULT_MAX_AGENT_STEPS,
+        &provider,
+        &mut |ev| {
+            if let Some(call) = ev
+                .role
+                .as_deref()
+                .and_then(|role| role.strip_prefix(agent::TOOL_CALL_ROLE_PREFIX))
+            {
+                let call: serde_json::Value = serde_json::from_str(call)?;
+                tool_roles.push(call["name"].as_str().unwrap_or_default().to_string());
+            }
+            text.push_str(&ev.text_delta);
+            Ok(())
+        },
+    )
+    .expect("agent");
+
+    assert_eq!(
+        tool_roles,
+        vec![agent::TOOL_SEARCH_MEMORIES, agent::TOOL_CREATE_TODO]
+    );
+    assert_eq!(text, "Added \"Book dental cleaning\" to your todos.");
+
+    // The search result was fed back to the model before it created the todo.
+    let requests = provider.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].1 > 0);
+    match requests[1].0.last() {
+        Some(ToolMessage::ToolResult {
+            call_id, content, ..
+        }) => {
+            assert_eq!(call_id, "c1");
+            assert!(content.contains("book a cleaning in March"), "{content}");
+        }
+        other => panic!("expected tool result, got {other:?}"),
+    }
+
+    let todos = db::list_todos(&conn, &key).expect("todos");
+    assert_eq!(todos.len(), 1);
+    assert_eq!(todos[0].title, "Book dental cleaning");
+    assert_eq!(todos[0].due_at_ms, Some(1_710_000_000_000));
+
+    let messages = db::list_messages(&conn, &key, &chat.id).expect("messages");
+    assert_eq!(
+        messages.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+        vec![
+            result.user_message_id.as_str(),
+            result.assistant_message_id.as_str()
+        ]
+    );
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-agent",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn, &key, &sync_key, &remote, "Agent").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Agent").expect("pull");
+    assert_eq!(
+        db::list_todos(&conn_b, &key_b).expect("synced todos")[0].title,
+        "Book dental cleaning"
+    );
+}
+
+#[test]
+fn agent_reports_tool_errors_and_stops_at_the_step_limit() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (app_dir, conn, key) = open_vault(&temp, "a");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:1",
+        "Water plants",
+        None,
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("todo");
+
+    let provider = ScriptedAgent::new(vec![
+        call(
+            "c1",
+            agent::TOOL_UPDATE_TODO,
+            json!({"todo_id": "todo:1", "status": "finished"}),
+        ),
+        call(
+            "c2",
+            agent::TOOL_UPDATE_TODO,
+            json!({"todo_id": "todo:1", "status": "done"}),
+        ),
+        answer("Marked it done."),
+    ]);
+
+    agent::ask_ai_agent(
+        &conn,
+        &key,
+        &app_dir,
+        &chat.id,
+        "I watered the plants",
+        2,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("agent");
+
+    let requests = provider.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 3);
+    match requests[1].0.last() {
+        Some(ToolMessage::ToolResult { content, .. }) => {
+            assert!(content.contains("unknown todo status"));
+        }
+        other => panic!("expected tool result, got {other:?}"),
+    }
+    // After two steps the model must answer in text. The tools are still
+    // declared because the history holds calls to them.
+    assert!(requests[2].1 > 0);
+    assert_eq!(requests[2].2, ToolChoice::None);
+    assert_eq!(requests[0].2, ToolChoice::Auto);
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:1").expect("todo").status,
+        "done"
+    );
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, rag, sync};
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+/// Answers by citing whichever quoted memory mentions `needle`.
+struct CitingProvider {
+    needle: &'static str,
+}
+
+impl rag::AnswerProvider for CitingProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        let marker = prompt
+            .lines()
+            .find(|line| line.starts_with('[') && line.contains(self.needle))
+            .and_then(|line| line[1..].split(']').next())
+            .expect("quoted memory")
+            .to_string();
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: "It is Ulaanbaatar ".to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: format!("[{marker}]."),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })?;
+        Ok(())
+    }
+}
+
+#[test]
+fn cited_markers_accepts_common_forms() {
+    assert_eq!(
+        rag::cited_markers("A [1], B [2][3] and C [4, 5]. See [note] or [0].")
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert!(rag::cited_markers("no markers [").is_empty());
+}
+
+#[test]
+fn ask_ai_streams_and_persists_citations() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "a");
+    let (conn_b, key_b) = open_vault(&temp, "b");
+    let conversation = db::create_conversation(&conn_a, &key_a, "Inbox").expect("conversation");
+    let fact = db::insert_message(
+        &conn_a,
+        &key_a,
+        &conversation.id,
+        "user",
+        "The capital of Mongolia is Ulaanbaatar",
+    )
+    .expect("fact");
+    db::insert_message(
+        &conn_a,
+        &key_a,
+        &conversation.id,
+        "user",
+        "Mongolia trip packing list: boots",
+    )
+    .expect("noise");
+
+    let mut emitted: Vec<serde_json::Value> = Vec::new();
+    let mut first_role: Option<String> = None;
+    let result = rag::ask_ai_with_provider(
+        &conn_a,
+        &key_a,
+        &conversation.id,
+        "What is the capital of Mongolia?",
+        5,
+        rag::Focus::AllMemories,
+        None,
+        &CitingProvider {
+            needle: "Ulaanbaatar",
+        },
+        &mut |ev| {
+            if first_role.is_none() {
+                first_role = ev.role.clone();
+            }
+            if let Some(json) = ev
+                .role
+                .as_deref()
+                .and_then(|role| role.strip_prefix(rag::CITATIONS_ROLE_PREFIX))
+            {
+                emitted = serde_json::from_str(json)?;
+            }
+            Ok(())
+        },
+    )
+    .expect("ask");
+
+    // The marker map arrives before any answer text.
+    assert!(first_role
+        .as_deref()
+        .is_some_and(|role| role.starts_with(rag::CITATIONS_ROLE_PREFIX)));
+    assert_eq!(emitted.len(), 2);
+    let fact_marker = emitted
+        .iter()
+        .find(|citation| citation["source_id"] == fact.id.as_str())
+        .and_then(|citation| citation["marker"].as_u64())
+        .expect("fact cited") as u32;
+
+    // Only the markers the answer uses are stored.
+    let expected = vec![db::MessageCitation {
+        marker: fact_marker,
+        source_kind: db::CITATION_SOURCE_MESSAGE.to_string(),
+        source_id: fact.id.clone(),
+    }];
+    assert_eq!(
+        db::list_message_citations(&conn_a, &result.assistant_message_id).expect("citations"),
+        expected
+    );
+    assert!(db::list_message_citations(&conn_a, &result.user_message_id)
+        .expect("citations")
+        .is_empty());
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-ask-ai-citations",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Citations").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Citations").expect("pull");
+    assert_eq!(
+        db::list_message_citations(&conn_b, &result.assistant_message_id).expect("synced"),
+        expected
+    );
+}
@@ -19,11 +19,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -53,16 +55,19 @@ impl rag::AnswerProvider for TwoChunkProvider {
             role: Some("assistant".to_string()),
             text_delta: "Hello".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: " world".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -90,6 +95,7 @@ fn ask_ai_stream_updates_db_and_uses_rag_context() {
         "apple",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |ev| {
             events.push(ev);
@@ -98,9 +104,14 @@ fn ask_ai_stream_updates_db_and_uses_rag_context() {
     )
     .expect("ask");
 
-    assert_eq!(events.len(), 2);
-    assert_eq!(events[0].text_delta, "OK");
-    assert!(events[1].done);
+    // The citation map for the quoted memories comes before the answer.
+    assert_eq!(events.len(), 3);
+    assert!(events[0]
+        .role
+        .as_deref()
+        .is_some_and(|role| role.starts_with(rag::CITATIONS_ROLE_PREFIX)));
+    assert_eq!(events[1].text_delta, "OK");
+    assert!(events[2].done);
 
     let prompt = provider
         .last_prompt
@@ -141,6 +152,7 @@ fn ask_ai_error_does_not_leave_empty_assistant_message() {
         "apple",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -174,6 +186,7 @@ fn ask_ai_cancel_deletes_question_and_partial_answer() {
         "question",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |ev| {
             if ev.text_delta.is_empty() {
@@ -215,6 +228,7 @@ fn ask_ai_accepts_trait_object_provider() {
         "question",
         3,
         rag::Focus::AllMemories,
+        None,
         provider.as_ref(),
         &mut |ev| {
             events.push(ev);
@@ -16,11 +16,13 @@ impl AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "hello".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: Some("assistant".to_string()),
             text_delta: "".to_string(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -53,6 +55,7 @@ fn ask_ai_inserts_non_memory_messages() {
         "When is the package delivery?",
         3,
         Focus::AllMemories,
+        None,
         &provider,
         &mut on_event,
     )
@@ -19,11 +19,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -67,6 +69,7 @@ fn ask_ai_prompt_includes_history() {
         "what now?",
         0,
         rag::Focus::ThisThread,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
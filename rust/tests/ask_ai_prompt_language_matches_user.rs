@@ -19,11 +19,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -48,6 +50,7 @@ fn ask_ai_prompt_enforces_reply_language_matches_user() {
         "今天有哪些事要做？",
         0,
         rag::Focus::ThisThread,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
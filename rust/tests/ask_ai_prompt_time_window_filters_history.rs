@@ -20,11 +20,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -93,6 +95,7 @@ fn ask_ai_time_window_prompt_filters_conversation_history_by_range() {
         rag::Focus::ThisThread,
         time_start_ms,
         time_end_ms,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -0,0 +1,306 @@
+use anyhow::Result;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, embedding, rag};
+
+/// Replies with a fixed text and records every prompt it sees.
+struct ScriptedProvider {
+    reply: &'static str,
+    prompts: std::sync::Mutex<Vec<String>>,
+}
+
+impl ScriptedProvider {
+    fn new(reply: &'static str) -> Self {
+        Self {
+            reply,
+            prompts: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn calls(&self) -> usize {
+        self.prompts.lock().unwrap().len()
+    }
+
+    fn last_prompt(&self) -> String {
+        self.prompts
+            .lock()
+            .unwrap()
+            .last()
+            .cloned()
+            .expect("prompt")
+    }
+}
+
+impl rag::AnswerProvider for ScriptedProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.prompts.lock().unwrap().push(prompt.to_string());
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: self.reply.to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })?;
+        Ok(())
+    }
+}
+
+#[test]
+fn follow_up_questions_are_rewritten_and_cached() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    let empty = db::create_conversation(&conn, &key, "Empty").expect("empty");
+    db::insert_message(
+        &conn,
+        &key,
+        &chat.id,
+        "user",
+        "What did Alice say about the launch?",
+    )
+    .expect("history");
+    db::insert_message(
+        &conn,
+        &key,
+        &chat.id,
+        "assistant",
+        "She moved it to Friday.",
+    )
+    .expect("history");
+
+    let follow_up = "and what did she say after that?";
+    let rewriter = ScriptedProvider::new(
+        r#"{"queries":["Alice after launch moved to Friday","Alice after launch moved to Friday"," Alice launch plans "]}"#,
+    );
+
+    // Without history there is nothing to resolve and no LLM call.
+    let plain =
+        rag::rewrite_search_queries(&conn, &key, &empty.id, follow_up, &rewriter).expect("plain");
+    assert_eq!(plain.queries, vec![follow_up.to_string()]);
+    assert!(!plain.llm_called);
+    assert_eq!(rewriter.calls(), 0);
+
+    let rewritten =
+        rag::rewrite_search_queries(&conn, &key, &chat.id, follow_up, &rewriter).expect("rewrite");
+    assert!(rewritten.llm_called);
+    assert_eq!(
+        rewritten.queries,
+        vec![
+            "Alice after launch moved to Friday".to_string(),
+            "Alice launch plans".to_string()
+        ]
+    );
+    let prompt = rewriter.last_prompt();
+    assert!(prompt.contains("She moved it to Friday."));
+    assert!(prompt.contains(follow_up));
+
+    // The same question over the same history is served from the cache.
+    let cached =
+        rag::rewrite_search_queries(&conn, &key, &chat.id, follow_up, &rewriter).expect("cached");
+    assert_eq!(cached.queries, rewritten.queries);
+    assert!(!cached.llm_called);
+    assert_eq!(rewriter.calls(), 1);
+
+    // New history means a new rewrite.
+    db::insert_message(&conn, &key, &chat.id, "user", "ok").expect("history");
+    rag::rewrite_search_queries(&conn, &key, &chat.id, follow_up, &rewriter).expect("rewrite");
+    assert_eq!(rewriter.calls(), 2);
+
+    // A rewrite that cannot be parsed falls back to the question uncached.
+    let broken = ScriptedProvider::new("sorry, no JSON here");
+    for _ in 0..2 {
+        let fallback = rag::rewrite_search_queries(&conn, &key, &chat.id, "who else?", &broken)
+            .expect("fallback");
+        assert_eq!(fallback.queries, vec!["who else?".to_string()]);
+        assert!(fallback.llm_called);
+    }
+    assert_eq!(broken.calls(), 2);
+}
+
+#[test]
+fn ask_ai_retrieves_with_rewritten_queries() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME).expect("model");
+    let notes = db::create_conversation(&conn, &key, "Notes").expect("notes");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Alice: once the launch slipped, hire two more testers",
+    )
+    .expect("note");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Groceries she said to get after that: oat milk",
+    )
+    .expect("note");
+
+    let provider = ScriptedProvider::new("OK");
+    rag::ask_ai_with_provider_using_active_embeddings_and_queries(
+        &conn,
+        &key,
+        &app_dir,
+        &chat.id,
+        "and what did she say after that?",
+        &["Alice launch slipped".to_string()],
+        1,
+        rag::Focus::AllMemories,
+        None,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask");
+
+    let prompt = provider.last_prompt();
+    assert!(prompt.contains("hire two more testers"));
+    assert!(!prompt.contains("oat milk"));
+    // The model still answers the user's own words.
+    assert!(prompt.contains("Question: and what did she say after that?"));
+}
+
+/// Embeds every text as the same vector and records the search queries.
+struct RecordingEmbedder {
+    queries: std::sync::Mutex<Vec<String>>,
+}
+
+impl embedding::Embedder for RecordingEmbedder {
+    fn model_name(&self) -> &str {
+        "test.recording.embedder"
+    }
+
+    fn dim(&self) -> usize {
+        4
+    }
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let mut queries = self.queries.lock().unwrap();
+        queries.extend(texts.iter().filter(|t| t.starts_with("query: ")).cloned());
+        Ok(vec![vec![1.0, 0.0, 0.0, 0.0]; texts.len()])
+    }
+}
+
+#[test]
+fn search_queries_follow_the_rewrite_setting() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    db::insert_message(&conn, &key, &chat.id, "user", "Tell me about Alice").expect("history");
+    db::insert_message(&conn, &key, &chat.id, "assistant", "She runs the launch.")
+        .expect("history");
+
+    let question = "what did she decide?";
+    let rewriter = ScriptedProvider::new(r#"{"queries":["Alice launch decision"]}"#);
+
+    db::set_ask_ai_query_rewrite_enabled(&conn, false).expect("disable");
+    let disabled = rag::search_queries_for_question(&conn, &key, &chat.id, question, 5, &rewriter)
+        .expect("disabled");
+    assert!(disabled.queries.is_empty());
+    assert!(!disabled.llm_called);
+
+    db::set_ask_ai_query_rewrite_enabled(&conn, true).expect("enable");
+    // Without retrieval there is nothing to search for.
+    let no_retrieval =
+        rag::search_queries_for_question(&conn, &key, &chat.id, question, 0, &rewriter)
+            .expect("top_k 0");
+    assert!(no_retrieval.queries.is_empty());
+    assert_eq!(rewriter.calls(), 0);
+
+    let enabled = rag::search_queries_for_question(&conn, &key, &chat.id, question, 5, &rewriter)
+        .expect("enabled");
+    assert_eq!(enabled.queries, vec!["Alice launch decision".to_string()]);
+    assert!(enabled.llm_called);
+}
+
+#[test]
+fn time_window_and_embedder_asks_retrieve_with_rewritten_queries() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    let notes = db::create_conversation(&conn, &key, "Notes").expect("notes");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    let testers = db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Alice: once the launch slipped, hire two more testers",
+    )
+    .expect("note");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Groceries she said to get after that: oat milk",
+    )
+    .expect("note");
+
+    let question = "and what did she say after that?";
+    let queries = ["Alice launch slipped".to_string()];
+    let provider = ScriptedProvider::new("OK");
+    rag::ask_ai_with_provider_using_active_embeddings_time_window_and_queries(
+        &conn,
+        &key,
+        &app_dir,
+        &chat.id,
+        question,
+        &queries,
+        1,
+        rag::Focus::AllMemories,
+        testers.created_at_ms - 60_000,
+        testers.created_at_ms + 60_000,
+        None,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("time window ask");
+    let prompt = provider.last_prompt();
+    assert!(prompt.contains("hire two more testers"));
+    assert!(!prompt.contains("oat milk"));
+
+    let embedder = RecordingEmbedder {
+        queries: std::sync::Mutex::new(Vec::new()),
+    };
+    rag::ask_ai_with_provider_using_embedder_and_queries(
+        &conn,
+        &key,
+        &embedder,
+        &chat.id,
+        question,
+        &queries,
+        1,
+        rag::Focus::AllMemories,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("embedder ask");
+    assert_eq!(
+        *embedder.queries.lock().unwrap(),
+        vec!["query: Alice launch slipped".to_string()]
+    );
+}
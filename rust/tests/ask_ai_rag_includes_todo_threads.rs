@@ -19,11 +19,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -64,6 +66,7 @@ fn ask_ai_rag_includes_todo_thread_when_activity_matches() {
         "Met the client at office",
         8,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -114,6 +117,7 @@ fn ask_ai_rag_dedups_todo_thread_when_todo_and_activity_both_match() {
         "Client follow-up",
         8,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -17,11 +17,13 @@ impl rag::AnswerProvider for StubProvider {
             role: Some("assistant".to_string()),
             text_delta: "ok".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -66,6 +68,7 @@ fn ask_ai_topk_zero_skips_embeddings_on_fastembed_failure() {
         "hello?",
         1,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut on_event,
     );
@@ -82,6 +85,7 @@ fn ask_ai_topk_zero_skips_embeddings_on_fastembed_failure() {
         "hello?",
         0,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut on_event,
     );
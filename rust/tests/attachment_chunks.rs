@@ -0,0 +1,354 @@
+use anyhow::Result;
+use secondloop_rust::content_extract::chunk::{
+    chunk_segments, chunk_text, TimedSegment, CHUNK_TARGET_BYTES,
+};
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, embedding, rag};
+
+const NEEDLE: &str = "The zebra migration budget is 4200 euros.";
+
+/// Records the prompt and answers "OK".
+#[derive(Default)]
+struct PromptRecorder {
+    prompt: std::sync::Mutex<String>,
+}
+
+impl rag::AnswerProvider for PromptRecorder {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        *self.prompt.lock().unwrap() = prompt.to_string();
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: "OK".to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+}
+
+/// Three pages of filler paragraphs with `NEEDLE` in the middle of page 3,
+/// plus the byte offset each page starts at.
+fn three_page_document() -> (String, Vec<usize>) {
+    let mut text = String::new();
+    let mut page_offsets = Vec::new();
+    for page in 1..=3 {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        page_offsets.push(text.len());
+        for paragraph in 0..40 {
+            if paragraph > 0 {
+                text.push_str("\n\n");
+            }
+            if page == 3 && paragraph == 25 {
+                text.push_str(NEEDLE);
+            } else {
+                text.push_str(&format!(
+                    "Paragraph {paragraph} on page {page} covers routine logistics and notes."
+                ));
+            }
+        }
+    }
+    (text, page_offsets)
+}
+
+#[test]
+fn chunk_text_splits_on_paragraphs_with_overlap_and_pages() {
+    let (text, page_offsets) = three_page_document();
+    let chunks = chunk_text(&text, &page_offsets);
+
+    assert!(
+        chunks.len() > 3,
+        "expected several chunks, got {}",
+        chunks.len()
+    );
+    for chunk in &chunks {
+        assert!(chunk.text.len() <= CHUNK_TARGET_BYTES);
+        assert!(chunk.text.starts_with("Paragraph") || chunk.text.starts_with(NEEDLE));
+    }
+    for pair in chunks
+        .windows(2)
+        .filter(|pair| pair[0].page == pair[1].page)
+    {
+        let first_paragraph = pair[1].text.split("\n\n").next().unwrap();
+        assert!(
+            pair[0].text.contains(first_paragraph),
+            "consecutive chunks should overlap"
+        );
+    }
+
+    let needle_chunk = chunks
+        .iter()
+        .find(|chunk| chunk.text.contains(NEEDLE))
+        .expect("needle chunk");
+    assert_eq!(needle_chunk.page, Some(3));
+    assert_eq!(chunks[0].page, Some(1));
+
+    let long_sentence = "word ".repeat(1000);
+    let windows = chunk_text(&long_sentence, &[]);
+    assert!(windows.len() > 1);
+    assert!(windows.iter().all(|chunk| chunk.page.is_none()));
+}
+
+#[test]
+fn chunk_segments_keep_time_spans() {
+    let segments = (0..40)
+        .map(|i| TimedSegment {
+            start_ms: i * 5_000,
+            end_ms: (i + 1) * 5_000,
+            text: format!("Segment {i} talks about the weekly planning session in detail."),
+        })
+        .collect::<Vec<_>>();
+
+    let chunks = chunk_segments(&segments);
+    assert!(chunks.len() > 1);
+    assert_eq!(chunks[0].start_ms, Some(0));
+    assert_eq!(chunks.last().unwrap().end_ms, Some(200_000));
+    for pair in chunks.windows(2) {
+        assert!(pair[1].start_ms < pair[0].end_ms, "chunks should overlap");
+    }
+}
+
+#[test]
+fn long_document_is_searchable_past_the_excerpt() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME)
+        .expect("set active model");
+
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message = db::insert_message(&conn, &key, &conversation.id, "user", "Quarterly report")
+        .expect("insert message");
+    let attachment = db::insert_attachment(&conn, &key, &app_dir, b"pdf", "application/pdf")
+        .expect("attachment");
+    db::link_attachment_to_message(&conn, &key, &message.id, &attachment.sha256)
+        .expect("link attachment");
+
+    let (text, page_offsets) = three_page_document();
+    let payload = serde_json::json!({
+        "schema": "secondloop.document_extract.v1",
+        "mime_type": "application/pdf",
+        "extracted_text_full": text,
+        "extracted_text_excerpt": &text[..200],
+        "extracted_text_page_offsets": page_offsets,
+        "needs_ocr": false,
+        "page_count": 3,
+    });
+    db::mark_attachment_annotation_ok(
+        &conn,
+        &key,
+        &attachment.sha256,
+        "und",
+        "document_extract.v1",
+        &payload,
+        message.created_at_ms,
+    )
+    .expect("mark annotation ok");
+
+    assert_eq!(
+        db::process_pending_attachment_chunks(&conn, &key, 10).expect("chunk"),
+        1
+    );
+    assert_eq!(
+        db::process_pending_attachment_chunks(&conn, &key, 10).expect("chunk again"),
+        0
+    );
+    let chunk_count = db::list_attachment_chunks(&conn, &key, &attachment.sha256)
+        .expect("list chunks")
+        .len();
+    assert!(chunk_count > 3);
+    assert_eq!(
+        db::process_pending_attachment_chunk_embeddings_default(&conn, &key, 100).expect("embed"),
+        chunk_count
+    );
+
+    let hits = db::search_similar_attachment_chunks_default(
+        &conn,
+        &key,
+        None,
+        "zebra migration budget",
+        3,
+    )
+    .expect("search chunks");
+    let best = hits.first().expect("chunk hit");
+    assert!(best.chunk.text.contains(NEEDLE));
+    assert_eq!(best.chunk.page, Some(3));
+    assert_eq!(best.message_id, message.id);
+
+    let other = db::create_conversation(&conn, &key, "Other").expect("other conversation");
+    let scoped = db::search_similar_attachment_chunks_default(
+        &conn,
+        &key,
+        Some(&other.id),
+        "zebra migration budget",
+        3,
+    )
+    .expect("scoped search");
+    assert!(scoped.is_empty());
+
+    let provider = PromptRecorder::default();
+    let mut roles = Vec::new();
+    rag::ask_ai_with_provider_using_active_embeddings(
+        &conn,
+        &key,
+        &app_dir,
+        &conversation.id,
+        "What is the zebra migration budget?",
+        5,
+        rag::Focus::AllMemories,
+        None,
+        &provider,
+        &mut |delta| {
+            roles.extend(delta.role);
+            Ok(())
+        },
+    )
+    .expect("ask");
+
+    let prompt = provider.prompt.lock().unwrap().clone();
+    assert!(prompt.contains("ATTACHMENT page=3"), "prompt: {prompt}");
+    assert!(prompt.contains("4200 euros"), "prompt: {prompt}");
+
+    let citations = roles
+        .iter()
+        .find_map(|role| role.strip_prefix(rag::CITATIONS_ROLE_PREFIX))
+        .expect("citations event");
+    let citations: serde_json::Value = serde_json::from_str(citations).expect("citations json");
+    let needle_chunk_id = format!("{}:{}", attachment.sha256, best.chunk.chunk_index);
+    assert!(citations.as_array().unwrap().iter().any(|c| {
+        c["source_kind"] == db::CITATION_SOURCE_ATTACHMENT_CHUNK
+            && c["source_id"] == needle_chunk_id
+    }));
+}
+
+#[test]
+fn purging_an_attachment_drops_its_chunk_vectors() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME)
+        .expect("set active model");
+
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message = db::insert_message(&conn, &key, &conversation.id, "user", "Quarterly report")
+        .expect("insert message");
+    let attachment = db::insert_attachment(&conn, &key, &app_dir, b"pdf", "application/pdf")
+        .expect("attachment");
+    db::link_attachment_to_message(&conn, &key, &message.id, &attachment.sha256)
+        .expect("link attachment");
+
+    let (text, page_offsets) = three_page_document();
+    let payload = serde_json::json!({
+        "extracted_text_full": text,
+        "extracted_text_page_offsets": page_offsets,
+    });
+    db::mark_attachment_annotation_ok(
+        &conn,
+        &key,
+        &attachment.sha256,
+        "und",
+        "document_extract.v1",
+        &payload,
+        message.created_at_ms,
+    )
+    .expect("mark annotation ok");
+    db::process_pending_attachment_chunks(&conn, &key, 10).expect("chunk");
+    let embedded =
+        db::process_pending_attachment_chunk_embeddings_default(&conn, &key, 100).expect("embed");
+    assert!(embedded > 0);
+    assert_eq!(chunk_vector_count(&conn), embedded);
+
+    db::purge_message_attachments(&conn, &key, &app_dir, &message.id).expect("purge");
+
+    assert!(db::list_attachment_chunks(&conn, &key, &attachment.sha256)
+        .expect("list chunks")
+        .is_empty());
+    assert_eq!(chunk_vector_count(&conn), 0);
+}
+
+/// Rows across every attachment chunk vector table.
+fn chunk_vector_count(conn: &rusqlite::Connection) -> usize {
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT name FROM sqlite_master
+               WHERE name LIKE 'attachment_chunk_embeddings__%'
+                 AND sql LIKE 'CREATE VIRTUAL TABLE%'"#,
+        )
+        .expect("prepare");
+    let tables = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .expect("query")
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .expect("tables");
+    tables
+        .iter()
+        .map(|table| {
+            conn.query_row(&format!(r#"SELECT COUNT(*) FROM "{table}""#), [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .expect("count") as usize
+        })
+        .sum()
+}
+
+#[test]
+fn chunks_follow_annotation_updates_and_transcripts() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let attachment =
+        db::insert_attachment(&conn, &key, &app_dir, b"audio", "audio/mpeg").expect("attachment");
+
+    let first = serde_json::json!({ "transcript_full": "Short first draft." });
+    db::mark_attachment_annotation_ok(&conn, &key, &attachment.sha256, "und", "m", &first, 1)
+        .expect("mark first");
+    db::process_pending_attachment_chunks(&conn, &key, 10).expect("chunk first");
+    let chunks = db::list_attachment_chunks(&conn, &key, &attachment.sha256).expect("list");
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].text, "Short first draft.");
+    assert_eq!(chunks[0].start_ms, None);
+
+    let segments = (0..40)
+        .map(|i| {
+            serde_json::json!({
+                "start": i as f64 * 2.5,
+                "end": (i + 1) as f64 * 2.5,
+                "text": format!("Segment {i} of the standup recording covers blockers."),
+            })
+        })
+        .collect::<Vec<_>>();
+    let second = serde_json::json!({
+        "transcript_full": "Standup recording.",
+        "segments": segments,
+    });
+    db::mark_attachment_annotation_ok(&conn, &key, &attachment.sha256, "und", "m", &second, 2)
+        .expect("mark second");
+    assert_eq!(
+        db::process_pending_attachment_chunks(&conn, &key, 10).expect("chunk second"),
+        1
+    );
+
+    let chunks = db::list_attachment_chunks(&conn, &key, &attachment.sha256).expect("list");
+    assert!(chunks.len() > 1);
+    assert!(chunks
+        .iter()
+        .all(|chunk| !chunk.text.contains("first draft")));
+    assert_eq!(chunks[0].chunk_index, 0);
+    assert_eq!(chunks[0].start_ms, Some(0));
+    assert_eq!(chunks.last().unwrap().end_ms, Some(100_000));
+}
@@ -3,6 +3,7 @@ use std::fs;
 use secondloop_rust::api::audio_transcribe;
 use secondloop_rust::auth;
 use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
 
 #[test]
 fn audio_transcribe_byok_profile_requires_existing_profile() {
@@ -51,3 +52,74 @@ fn audio_transcribe_byok_profile_multimodal_requires_existing_profile() {
     let msg = err.to_string();
     assert!(msg.contains("llm profile not found"));
 }
+
+#[test]
+fn audio_transcribe_byok_profile_does_not_fall_back_to_chat_profiles() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    fs::create_dir_all(&app_dir).expect("create app dir");
+
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test())
+        .expect("init master password");
+    let conn = db::open(&app_dir).expect("open db");
+    let create = |name: &str, provider_type: &str, model_name: &str| {
+        db::create_llm_profile(
+            &conn,
+            &key,
+            name,
+            provider_type,
+            Some("http://127.0.0.1:9"),
+            Some("sk-test"),
+            model_name,
+            false,
+        )
+        .expect("create profile")
+        .id
+    };
+    let whisper = create("whisper", "openai-compatible", "whisper-1");
+    let gemini = create("gemini", "gemini", "gemini-2.5-flash");
+    let chat = create("chat", "openai-compatible", "gpt-4o-mini");
+    db::set_llm_fallback_chain(&conn, &[chat]).expect("chain");
+
+    db::upsert_llm_model_price(&conn, &key, "openai-compatible", "whisper-1", 1.0, 1.0)
+        .expect("price");
+    db::record_llm_usage_daily(
+        &conn,
+        "2026-02-01",
+        &whisper,
+        "audio_transcribe",
+        Some(1_000_000),
+        Some(0),
+        Some(1_000_000),
+    )
+    .expect("record usage");
+    db::set_llm_spending_limit(&conn, &whisper, Some(1_000_000)).expect("limit");
+    db::set_llm_spending_limit(&conn, &gemini, Some(0)).expect("limit");
+
+    let transcribe = |profile_id: &str| {
+        audio_transcribe::audio_transcribe_byok_profile(
+            app_dir.to_string_lossy().to_string(),
+            key.to_vec(),
+            profile_id.to_string(),
+            "2026-02-06".to_string(),
+            "en".to_string(),
+            "audio/mp4".to_string(),
+            vec![0x00, 0x00, 0x00, 0x18],
+        )
+        .expect_err("should not transcribe")
+        .to_string()
+    };
+
+    // Over its limit, the profile is refused instead of handing the audio
+    // to the chat profile of the fallback chain.
+    let msg = transcribe(&whisper);
+    assert!(msg.contains("llm_spending_limit_exceeded"), "{msg}");
+
+    // The provider check applies to the requested profile, even when it is
+    // over its limit.
+    let msg = transcribe(&gemini);
+    assert!(
+        msg.contains("only supports provider_type=openai-compatible (got gemini)"),
+        "{msg}"
+    );
+}
@@ -0,0 +1,284 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::sync;
+use secondloop_rust::sync::caldav::{
+    sync_caldav, CalDavCollection, CalDavPutCondition, InMemoryCalDavCollection,
+};
+use secondloop_rust::sync::webdav::parse_multistatus;
+
+const NOW_MS: i64 = 1_730_000_000_000;
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+/// Href and body of the remote object whose body contains `needle`.
+fn find_remote(collection: &InMemoryCalDavCollection, needle: &str) -> (String, String, String) {
+    for resource in collection.list().expect("list") {
+        let (body, etag) = collection.get(&resource.href).expect("get");
+        if body.contains(needle) {
+            return (resource.href, body, etag.expect("etag"));
+        }
+    }
+    panic!("no remote object contains {needle}");
+}
+
+fn external_event(uid: &str, summary: &str, last_modified: &str) -> String {
+    format!(
+        "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Work//EN\r\nBEGIN:VEVENT\r\n\
+UID:{uid}\r\nDTSTAMP:20241101T000000Z\r\nLAST-MODIFIED:{last_modified}\r\n\
+SUMMARY:{summary}\r\nDTSTART:20241105T140000Z\r\nDTEND:20241105T150000Z\r\n\
+END:VEVENT\r\nEND:VCALENDAR\r\n"
+    )
+}
+
+#[test]
+fn caldav_sync_pushes_pulls_and_resolves_conflicts() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    let collection = InMemoryCalDavCollection::new();
+
+    db::upsert_event(
+        &conn,
+        &key,
+        "event:dentist",
+        "Dentist",
+        NOW_MS,
+        NOW_MS + 3_600_000,
+        "Europe/Berlin",
+        None,
+    )
+    .expect("event");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:report",
+        "Write report",
+        Some(NOW_MS),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("todo");
+
+    let first = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync 1");
+    assert_eq!(first.pushed_created, 2, "{first:?}");
+    assert_eq!(collection.list().expect("list").len(), 2);
+
+    let idle = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync idle");
+    assert_eq!(
+        idle.pushed_created + idle.pushed_updated + idle.pulled_created + idle.pulled_updated,
+        0,
+        "{idle:?}"
+    );
+
+    // Edits and additions made by another client come in.
+    let (href, body, etag) = find_remote(&collection, "SUMMARY:Dentist");
+    collection
+        .put(
+            &href,
+            &body.replace("SUMMARY:Dentist", "SUMMARY:Dentist (moved)"),
+            &CalDavPutCondition::Replace(etag),
+        )
+        .expect("remote edit");
+    collection
+        .put(
+            "/calendar/standup.ics",
+            &external_event("standup@work.example", "Standup", "20241101T000000Z"),
+            &CalDavPutCondition::Create,
+        )
+        .expect("remote create");
+    let pulled = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync pull");
+    assert_eq!(pulled.pulled_updated, 1, "{pulled:?}");
+    assert_eq!(pulled.pulled_created, 1, "{pulled:?}");
+    assert_eq!(
+        db::get_event(&conn, &key, "event:dentist").expect("event").title,
+        "Dentist (moved)"
+    );
+    assert_eq!(
+        db::get_event(&conn, &key, "ics:standup@work.example")
+            .expect("standup")
+            .title,
+        "Standup"
+    );
+
+    // Local edits and deletes go out.
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:report",
+        "Write quarterly report",
+        Some(NOW_MS),
+        "in_progress",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("edit todo");
+    db::delete_event(&conn, &key, "event:dentist").expect("delete event");
+    let pushed = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync push");
+    assert_eq!(pushed.pushed_updated, 1, "{pushed:?}");
+    assert_eq!(pushed.pushed_deleted, 1, "{pushed:?}");
+    let (_, todo_body, _) = find_remote(&collection, "SUMMARY:Write quarterly report");
+    assert!(todo_body.contains("STATUS:IN-PROCESS"));
+    assert_eq!(collection.list().expect("list").len(), 2);
+
+    // Both sides changed: the side modified last wins.
+    db::upsert_event(
+        &conn,
+        &key,
+        "ics:standup@work.example",
+        "Standup (local)",
+        NOW_MS,
+        NOW_MS + 900_000,
+        "UTC",
+        None,
+    )
+    .expect("local edit");
+    let (href, _, etag) = find_remote(&collection, "UID:standup@work.example");
+    collection
+        .put(
+            &href,
+            &external_event("standup@work.example", "Standup (stale)", "20200101T000000Z"),
+            &CalDavPutCondition::Replace(etag),
+        )
+        .expect("stale remote edit");
+    let conflict = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync conflict");
+    assert_eq!(conflict.conflicts, 1, "{conflict:?}");
+    assert_eq!(conflict.pushed_updated, 1, "{conflict:?}");
+    find_remote(&collection, "SUMMARY:Standup (local)");
+
+    db::upsert_event(
+        &conn,
+        &key,
+        "ics:standup@work.example",
+        "Standup (local again)",
+        NOW_MS,
+        NOW_MS + 900_000,
+        "UTC",
+        None,
+    )
+    .expect("local edit 2");
+    let (href, _, etag) = find_remote(&collection, "UID:standup@work.example");
+    collection
+        .put(
+            &href,
+            &external_event("standup@work.example", "Standup (remote)", "29991231T000000Z"),
+            &CalDavPutCondition::Replace(etag),
+        )
+        .expect("newer remote edit");
+    let conflict = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync conflict 2");
+    assert_eq!(conflict.conflicts, 1, "{conflict:?}");
+    assert_eq!(
+        db::get_event(&conn, &key, "ics:standup@work.example")
+            .expect("standup")
+            .title,
+        "Standup (remote)"
+    );
+
+    // A todo deleted on the server is dismissed here and stays off the server.
+    let (href, _, etag) = find_remote(&collection, "UID:todo:report@secondloop");
+    collection.delete(&href, Some(&etag)).expect("remote delete");
+    let deleted = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync delete");
+    assert_eq!(deleted.pulled_deleted, 1, "{deleted:?}");
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:report").expect("todo").status,
+        "dismissed"
+    );
+    let after = sync_caldav(&conn, &key, &collection, None, NOW_MS).expect("sync after");
+    assert_eq!(after.pushed_created, 0, "{after:?}");
+    assert_eq!(collection.list().expect("list").len(), 1);
+    assert!(after.errors.is_empty(), "{:?}", after.errors);
+}
+
+#[test]
+fn caldav_changes_reach_other_devices_through_the_oplog() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "secondloop_a");
+    let (conn_b, key_b) = open_vault(&temp, "secondloop_b");
+    let collection = InMemoryCalDavCollection::new();
+    collection
+        .put(
+            "/calendar/review.ics",
+            &external_event("review@work.example", "Design review", "20241101T000000Z"),
+            &CalDavPutCondition::Create,
+        )
+        .expect("remote create");
+    sync_caldav(&conn_a, &key_a, &collection, None, NOW_MS).expect("caldav A");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-caldav",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "CalDav").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "CalDav").expect("pull");
+    assert_eq!(
+        db::get_event(&conn_b, &key_b, "ics:review@work.example")
+            .expect("event B")
+            .title,
+        "Design review"
+    );
+
+    collection
+        .delete("/calendar/review.ics", None)
+        .expect("remote delete");
+    let report = sync_caldav(&conn_a, &key_a, &collection, None, NOW_MS).expect("caldav A 2");
+    assert_eq!(report.pulled_deleted, 1, "{report:?}");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "CalDav").expect("push delete");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "CalDav").expect("pull delete");
+    assert!(db::list_events(&conn_b, &key_b).expect("events B").is_empty());
+}
+
+#[test]
+fn caldav_propfind_parses_ctag_etags_and_collections() {
+    let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<multistatus xmlns="DAV:" xmlns:CS="http://calendarserver.org/ns/">
+  <response>
+    <href>/user/work/</href>
+    <propstat>
+      <prop>
+        <resourcetype><collection/><C:calendar xmlns:C="urn:ietf:params:xml:ns:caldav"/></resourcetype>
+        <CS:getctag>"c0ffee"</CS:getctag>
+      </prop>
+      <status>HTTP/1.1 200 OK</status>
+    </propstat>
+  </response>
+  <response>
+    <href>/user/work/abc.ics</href>
+    <propstat>
+      <prop>
+        <resourcetype/>
+        <getetag>&quot;e1&quot;</getetag>
+        <C:calendar-data xmlns:C="urn:ietf:params:xml:ns:caldav"><![CDATA[BEGIN:VCALENDAR
+END:VCALENDAR
+]]></C:calendar-data>
+      </prop>
+      <status>HTTP/1.1 200 OK</status>
+    </propstat>
+  </response>
+</multistatus>
+"#;
+
+    let entries = parse_multistatus(xml.as_bytes()).expect("parse");
+    assert_eq!(entries.len(), 2);
+    assert!(entries[0].is_collection);
+    assert_eq!(entries[0].ctag.as_deref(), Some("\"c0ffee\""));
+    assert!(!entries[1].is_collection);
+    assert_eq!(entries[1].href, "/user/work/abc.ics");
+    assert_eq!(entries[1].etag.as_deref(), Some("\"e1\""));
+    assert_eq!(
+        entries[1].calendar_data.as_deref(),
+        Some("BEGIN:VCALENDAR\nEND:VCALENDAR")
+    );
+    assert_eq!(entries[0].calendar_data, None);
+}
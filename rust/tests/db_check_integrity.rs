@@ -0,0 +1,132 @@
+use std::fs;
+
+use anyhow::Result;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::embedding::{Embedder, DEFAULT_EMBED_DIM};
+use secondloop_rust::{auth, db};
+
+#[derive(Clone, Debug, Default)]
+struct TestEmbedder;
+
+impl Embedder for TestEmbedder {
+    fn model_name(&self) -> &str {
+        "test-embedder"
+    }
+
+    fn dim(&self) -> usize {
+        DEFAULT_EMBED_DIM
+    }
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts
+            .iter()
+            .map(|_| {
+                let mut v = vec![0.0f32; DEFAULT_EMBED_DIM];
+                v[0] = 1.0;
+                v
+            })
+            .collect())
+    }
+}
+
+fn kinds(report: &db::IntegrityReport) -> Vec<&str> {
+    let mut kinds: Vec<&str> = report.issues.iter().map(|i| i.kind.as_str()).collect();
+    kinds.sort();
+    kinds
+}
+
+#[test]
+fn check_integrity_reports_then_fixes_issues() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message = db::insert_message(&conn, &key, &conversation.id, "user", "hello").expect("msg");
+    let deleted = db::insert_message(&conn, &key, &conversation.id, "user", "bye").expect("msg");
+    db::process_pending_message_embeddings(&conn, &key, &TestEmbedder, 100).expect("embed");
+    db::set_message_deleted(&conn, &key, &deleted.id, true).expect("delete");
+
+    let attachment =
+        db::insert_attachment(&conn, &key, &app_dir, b"bytes", "image/png").expect("attachment");
+    db::link_attachment_to_message(&conn, &key, &message.id, &attachment.sha256).expect("link");
+    fs::remove_file(app_dir.join(&attachment.path)).expect("remove attachment file");
+    fs::write(app_dir.join("attachments/deadbeef.bin"), b"orphan").expect("orphan file");
+
+    conn.execute_batch("PRAGMA foreign_keys = OFF;")
+        .expect("fk off");
+    conn.execute(
+        "INSERT INTO message_attachments(message_id, attachment_sha256, created_at) VALUES (?1, 'missing', 0)",
+        [&message.id],
+    )
+    .expect("dangling link");
+    conn.execute_batch("PRAGMA foreign_keys = ON;")
+        .expect("fk on");
+
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:orphan",
+        "Orphan",
+        None,
+        "open",
+        Some("message:gone"),
+        None,
+        None,
+        None,
+    )
+    .expect("todo");
+    conn.execute(
+        "INSERT INTO todo_series(id, rule_json, created_at_ms, updated_at_ms) VALUES ('series:empty', '{}', 0, 0)",
+        [],
+    )
+    .expect("series");
+    conn.execute(
+        "INSERT INTO semantic_parse_jobs(message_id, status, attempts, created_at_ms, updated_at_ms) VALUES (?1, 'running', 1, 0, 0)",
+        [&message.id],
+    )
+    .expect("job");
+    conn.execute(
+        "UPDATE message_tag_autofill_jobs SET status = 'running', updated_at_ms = 0 WHERE message_id = ?1",
+        [&message.id],
+    )
+    .expect("autofill job");
+
+    let now_ms = 10_000_000_000_000;
+    let report = db::check_integrity(&conn, &key, &app_dir, false, now_ms).expect("check");
+    assert_eq!(
+        kinds(&report),
+        vec![
+            db::INTEGRITY_DANGLING_MESSAGE_ATTACHMENT,
+            db::INTEGRITY_EMPTY_RECURRENCE_SERIES,
+            db::INTEGRITY_MISSING_ATTACHMENT_FILE,
+            db::INTEGRITY_ORPHAN_ATTACHMENT_FILE,
+            db::INTEGRITY_STALE_MESSAGE_EMBEDDING,
+            db::INTEGRITY_STUCK_RUNNING_JOB,
+            db::INTEGRITY_STUCK_RUNNING_JOB,
+            db::INTEGRITY_TODO_MISSING_SOURCE_ENTRY,
+        ],
+        "{report:?}"
+    );
+    assert_eq!(report.fixed_count, 0);
+    assert!(app_dir.join("attachments/deadbeef.bin").exists());
+
+    let fixed = db::check_integrity(&conn, &key, &app_dir, true, now_ms).expect("fix");
+    assert_eq!(fixed.fixed_count, 7, "{fixed:?}");
+    assert!(!app_dir.join("attachments/deadbeef.bin").exists());
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:orphan")
+            .expect("todo")
+            .source_entry_id,
+        None
+    );
+
+    // Only the unrepairable missing file is left.
+    let after = db::check_integrity(&conn, &key, &app_dir, false, now_ms).expect("recheck");
+    assert_eq!(
+        kinds(&after),
+        vec![db::INTEGRITY_MISSING_ATTACHMENT_FILE],
+        "{after:?}"
+    );
+}
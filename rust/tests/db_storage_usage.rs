@@ -0,0 +1,126 @@
+use std::fs;
+
+use secondloop_rust::auth;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
+
+fn category<'a>(report: &'a db::StorageUsageReport, category: &str) -> &'a db::StorageUsageEntry {
+    report
+        .categories
+        .iter()
+        .find(|entry| entry.category == category)
+        .unwrap_or_else(|| panic!("missing category {category}: {report:?}"))
+}
+
+#[test]
+fn storage_usage_breaks_down_app_dir() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let inbox = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message = db::insert_message(&conn, &key, &inbox.id, "user", "photo").expect("message");
+    let image =
+        db::insert_attachment(&conn, &key, &app_dir, &[7u8; 4096], "image/png").expect("image");
+    db::link_attachment_to_message(&conn, &key, &message.id, &image.sha256).expect("link");
+    let pdf =
+        db::insert_attachment(&conn, &key, &app_dir, &[1u8; 1024], "application/pdf").expect("pdf");
+
+    db::upsert_attachment_variant(
+        &conn,
+        &key,
+        &app_dir,
+        &image.sha256,
+        "thumb",
+        &[2u8; 512],
+        "image/webp",
+    )
+    .expect("variant");
+
+    let original_video = db::insert_attachment(&conn, &key, &app_dir, &[3u8; 2048], "video/mp4")
+        .expect("original video");
+    let proxy_segment = db::insert_attachment(&conn, &key, &app_dir, &[4u8; 256], "video/mp4")
+        .expect("proxy segment");
+    let manifest = serde_json::json!({
+        "schema": "secondloop.video_manifest.v2",
+        "original_sha256": original_video.sha256,
+        "original_mime_type": "video/mp4",
+        "video_segments": [
+            {"index": 0, "sha256": proxy_segment.sha256, "mime_type": "video/mp4"}
+        ]
+    });
+    db::insert_attachment(
+        &conn,
+        &key,
+        &app_dir,
+        manifest.to_string().as_bytes(),
+        "application/x.secondloop.video+json",
+    )
+    .expect("manifest");
+
+    let whisper_dir = app_dir.join("ocr/desktop/runtime/whisper");
+    fs::create_dir_all(&whisper_dir).expect("whisper dir");
+    fs::write(whisper_dir.join("ggml-base.bin"), vec![0u8; 300]).expect("whisper model");
+    fs::create_dir_all(app_dir.join("ocr/desktop/runtime/models")).expect("ocr dir");
+    fs::write(
+        app_dir.join("ocr/desktop/runtime/models/det.onnx"),
+        vec![0u8; 200],
+    )
+    .expect("ocr model");
+    fs::create_dir_all(app_dir.join("models/fastembed")).expect("models dir");
+    fs::write(app_dir.join("models/fastembed/model.onnx"), vec![0u8; 100]).expect("model");
+
+    let report = db::storage_usage(&conn, &key, &app_dir).expect("usage");
+
+    assert_eq!(
+        report.total_bytes,
+        report
+            .categories
+            .iter()
+            .map(|entry| entry.bytes)
+            .sum::<i64>()
+    );
+    assert!(category(&report, db::STORAGE_USAGE_DATABASE).bytes > 0);
+    assert!(category(&report, db::STORAGE_USAGE_OPLOG).count > 0);
+    assert!(report
+        .categories
+        .iter()
+        .any(|entry| entry.category == db::STORAGE_USAGE_VECTOR_TABLE
+            && entry.label == "message_embeddings"));
+
+    let originals = category(&report, db::STORAGE_USAGE_ATTACHMENT_ORIGINALS);
+    assert_eq!(originals.count, 4, "{report:?}");
+    let proxies = category(&report, db::STORAGE_USAGE_VIDEO_PROXIES);
+    assert_eq!(proxies.count, 1, "{report:?}");
+    assert!(proxies.bytes > 256);
+    assert_eq!(
+        category(&report, db::STORAGE_USAGE_ATTACHMENT_VARIANTS).count,
+        1
+    );
+    assert_eq!(
+        category(&report, db::STORAGE_USAGE_WHISPER_MODELS).bytes,
+        300
+    );
+    assert_eq!(category(&report, db::STORAGE_USAGE_OCR_MODELS).bytes, 200);
+    assert_eq!(
+        category(&report, db::STORAGE_USAGE_EMBEDDING_MODELS).bytes,
+        100
+    );
+
+    assert_eq!(report.by_conversation.len(), 1);
+    assert_eq!(report.by_conversation[0].category, inbox.id);
+    assert_eq!(report.by_conversation[0].label, "Inbox");
+    assert_eq!(report.by_conversation[0].count, 1);
+    assert!(report.by_conversation[0].bytes > 4096);
+
+    assert_eq!(report.by_mime_type[0].label, "image/png");
+    let pdf_entry = report
+        .by_mime_type
+        .iter()
+        .find(|entry| entry.label == "application/pdf")
+        .expect("pdf entry");
+    assert_eq!(pdf_entry.count, 1);
+    assert!(pdf_entry.bytes > 1024);
+    assert!(fs::metadata(app_dir.join(&pdf.path)).is_ok());
+}
@@ -114,6 +114,7 @@ fn document_extract_smoke_pdf_text() {
     assert!(pdf_res.full_text.contains("Hello PDF"));
     assert!(!pdf_res.needs_ocr);
     assert_eq!(pdf_res.page_count, Some(1));
+    assert_eq!(pdf_res.page_offsets, vec![0]);
 }
 
 #[test]
@@ -0,0 +1,306 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::export::ics as ics_export;
+use secondloop_rust::import::ics as ics_import;
+use secondloop_rust::sync;
+
+fn utc_ms(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> i64 {
+    let date = time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+        .expect("date");
+    let time = time::Time::from_hms(hour, minute, 0).expect("time");
+    time::PrimitiveDateTime::new(date, time)
+        .assume_utc()
+        .unix_timestamp()
+        * 1000
+}
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+fn starts(occurrences: &[db::EventOccurrence]) -> Vec<i64> {
+    occurrences.iter().map(|o| o.start_at_ms).collect()
+}
+
+/// Monday/Wednesday 09:00 Berlin standup starting 2024-10-21, six
+/// occurrences counted from the first, with 2024-10-23 excluded. Berlin leaves
+/// summer time on 2024-10-27, so 09:00 moves from 07:00 to 08:00 UTC.
+fn create_standup(conn: &rusqlite::Connection, key: &[u8; 32]) {
+    db::upsert_event(
+        conn,
+        key,
+        "event:standup",
+        "Standup",
+        utc_ms(2024, 10, 21, 7, 0),
+        utc_ms(2024, 10, 21, 7, 15),
+        "Europe/Berlin",
+        None,
+    )
+    .expect("event");
+    let rule = serde_json::json!({
+        "freq": "weekly",
+        "byday": ["MO", "WE"],
+        "count": 6,
+        "exdates_ms": [utc_ms(2024, 10, 23, 7, 0)],
+    });
+    db::set_event_recurrence_rule(conn, key, "event:standup", Some(&rule.to_string()))
+        .expect("rule");
+}
+
+fn all_occurrences(conn: &rusqlite::Connection, key: &[u8; 32]) -> Vec<db::EventOccurrence> {
+    db::list_event_occurrences_in_range(
+        conn,
+        key,
+        utc_ms(2024, 10, 1, 0, 0),
+        utc_ms(2024, 12, 1, 0, 0),
+    )
+    .expect("occurrences")
+}
+
+#[test]
+fn recurring_events_expand_with_cancelled_and_moved_occurrences() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    create_standup(&conn, &key);
+    db::upsert_event(
+        &conn,
+        &key,
+        "event:dentist",
+        "Dentist",
+        utc_ms(2024, 10, 29, 14, 0),
+        utc_ms(2024, 10, 29, 15, 0),
+        "Europe/Berlin",
+        None,
+    )
+    .expect("plain event");
+
+    let occurrences = all_occurrences(&conn, &key);
+    assert_eq!(
+        starts(&occurrences),
+        vec![
+            utc_ms(2024, 10, 21, 7, 0),
+            utc_ms(2024, 10, 28, 8, 0),
+            utc_ms(2024, 10, 29, 14, 0),
+            utc_ms(2024, 10, 30, 8, 0),
+            utc_ms(2024, 11, 4, 8, 0),
+            utc_ms(2024, 11, 6, 8, 0),
+        ]
+    );
+    assert!(!occurrences[2].is_recurring);
+    assert!(occurrences[3].is_recurring);
+    assert_eq!(occurrences[3].end_at_ms, utc_ms(2024, 10, 30, 8, 15));
+
+    db::cancel_event_occurrence(&conn, &key, "event:standup", utc_ms(2024, 10, 28, 8, 0))
+        .expect("cancel");
+    db::move_event_occurrence(
+        &conn,
+        &key,
+        "event:standup",
+        utc_ms(2024, 10, 30, 8, 0),
+        utc_ms(2024, 10, 31, 10, 0),
+        utc_ms(2024, 10, 31, 10, 30),
+        Some("Standup (moved)"),
+    )
+    .expect("move");
+    assert!(
+        db::cancel_event_occurrence(&conn, &key, "event:standup", utc_ms(2024, 10, 29, 8, 0))
+            .is_err()
+    );
+
+    let week = db::list_event_occurrences_in_range(
+        &conn,
+        &key,
+        utc_ms(2024, 10, 28, 0, 0),
+        utc_ms(2024, 11, 1, 0, 0),
+    )
+    .expect("week");
+    assert_eq!(week.len(), 2, "{week:?}");
+    assert_eq!(week[0].event_id, "event:dentist");
+    let moved = &week[1];
+    assert!(moved.is_moved);
+    assert_eq!(moved.occurrence_start_ms, utc_ms(2024, 10, 30, 8, 0));
+    assert_eq!(moved.start_at_ms, utc_ms(2024, 10, 31, 10, 0));
+    assert_eq!(moved.title, "Standup (moved)");
+
+    let events = db::list_events_in_range(
+        &conn,
+        &key,
+        utc_ms(2024, 10, 31, 0, 0),
+        utc_ms(2024, 11, 5, 0, 0),
+    )
+    .expect("events");
+    let summary = events
+        .iter()
+        .map(|e| (e.id.as_str(), e.title.as_str(), e.start_at_ms))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "event:standup",
+                "Standup (moved)",
+                utc_ms(2024, 10, 31, 10, 0)
+            ),
+            ("event:standup", "Standup", utc_ms(2024, 11, 4, 8, 0)),
+        ]
+    );
+
+    // The original slot of a moved occurrence stays empty.
+    assert!(db::list_event_occurrences_in_range(
+        &conn,
+        &key,
+        utc_ms(2024, 10, 30, 0, 0),
+        utc_ms(2024, 10, 31, 0, 0),
+    )
+    .expect("original slot")
+    .is_empty());
+
+    db::restore_event_occurrence(&conn, &key, "event:standup", utc_ms(2024, 10, 28, 8, 0))
+        .expect("restore");
+    assert!(starts(&all_occurrences(&conn, &key)).contains(&utc_ms(2024, 10, 28, 8, 0)));
+
+    db::set_event_recurrence_rule(&conn, &key, "event:standup", None).expect("clear rule");
+    let standups = all_occurrences(&conn, &key)
+        .into_iter()
+        .filter(|o| o.event_id == "event:standup")
+        .collect::<Vec<_>>();
+    assert_eq!(starts(&standups), vec![utc_ms(2024, 10, 21, 7, 0)]);
+}
+
+#[test]
+fn event_series_and_exceptions_sync_between_devices() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "secondloop_a");
+    let (conn_b, key_b) = open_vault(&temp, "secondloop_b");
+    create_standup(&conn_a, &key_a);
+    db::cancel_event_occurrence(&conn_a, &key_a, "event:standup", utc_ms(2024, 11, 4, 8, 0))
+        .expect("cancel");
+    db::move_event_occurrence(
+        &conn_a,
+        &key_a,
+        "event:standup",
+        utc_ms(2024, 10, 28, 8, 0),
+        utc_ms(2024, 10, 28, 12, 0),
+        utc_ms(2024, 10, 28, 12, 15),
+        None,
+    )
+    .expect("move");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-event-recurrence",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Events").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Events").expect("pull");
+    assert_eq!(
+        all_occurrences(&conn_b, &key_b),
+        all_occurrences(&conn_a, &key_a)
+    );
+    assert_eq!(
+        starts(&all_occurrences(&conn_b, &key_b)),
+        vec![
+            utc_ms(2024, 10, 21, 7, 0),
+            utc_ms(2024, 10, 28, 12, 0),
+            utc_ms(2024, 10, 30, 8, 0),
+            utc_ms(2024, 11, 6, 8, 0),
+        ]
+    );
+
+    db::delete_event(&conn_a, &key_a, "event:standup").expect("delete");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Events").expect("push delete");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Events").expect("pull delete");
+    assert!(all_occurrences(&conn_b, &key_b).is_empty());
+    assert_eq!(
+        db::get_event_recurrence_rule_json(&conn_b, "event:standup").expect("rule"),
+        None
+    );
+}
+
+#[test]
+fn recurring_events_round_trip_through_ics() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "secondloop_a");
+    let (conn_b, key_b) = open_vault(&temp, "secondloop_b");
+    create_standup(&conn_a, &key_a);
+    db::cancel_event_occurrence(&conn_a, &key_a, "event:standup", utc_ms(2024, 10, 28, 8, 0))
+        .expect("cancel");
+    db::move_event_occurrence(
+        &conn_a,
+        &key_a,
+        "event:standup",
+        utc_ms(2024, 10, 30, 8, 0),
+        utc_ms(2024, 10, 30, 9, 0),
+        utc_ms(2024, 10, 30, 9, 15),
+        Some("Standup (late)"),
+    )
+    .expect("move");
+
+    let (text, summary) =
+        ics_export::export_ics(&conn_a, &key_a, utc_ms(2024, 11, 1, 0, 0)).expect("export");
+    assert_eq!(summary.events, 1);
+    assert!(
+        text.contains("RRULE:FREQ=WEEKLY;COUNT=6;BYDAY=MO,WE\r\n"),
+        "{text}"
+    );
+    assert!(text.contains("EXDATE;TZID=Europe/Berlin:20241023T090000\r\n"));
+    assert!(text.contains("EXDATE;TZID=Europe/Berlin:20241028T090000\r\n"));
+    assert!(text.contains("RECURRENCE-ID;TZID=Europe/Berlin:20241030T090000\r\n"));
+    assert_eq!(text.matches("DTSTAMP:").count(), 2);
+
+    let report = ics_import::import_ics(&conn_b, &key_b, &text, None, false).expect("import");
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    assert_eq!(report.items_imported, 1);
+    assert_eq!(
+        all_occurrences(&conn_b, &key_b),
+        all_occurrences(&conn_a, &key_a)
+    );
+    // Re-importing at the source keeps the cancelled occurrence an exception
+    // rather than folding its EXDATE into the rule, so it can be restored.
+    let report = ics_import::import_ics(&conn_a, &key_a, &text, None, false).expect("import A");
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    let rule: serde_json::Value = serde_json::from_str(
+        &db::get_event_recurrence_rule_json(&conn_a, "event:standup")
+            .expect("rule")
+            .expect("recurring"),
+    )
+    .expect("rule json");
+    assert_eq!(
+        rule["exdates_ms"],
+        serde_json::json!([utc_ms(2024, 10, 23, 7, 0)])
+    );
+    db::restore_event_occurrence(&conn_a, &key_a, "event:standup", utc_ms(2024, 10, 28, 8, 0))
+        .expect("restore");
+    assert!(starts(&all_occurrences(&conn_a, &key_a)).contains(&utc_ms(2024, 10, 28, 8, 0)));
+
+    let again = ics_import::import_ics(&conn_b, &key_b, &text, None, false).expect("reimport");
+    assert_eq!(again.items_skipped_unchanged, 1, "{again:?}");
+
+    // Dropping the override upstream puts the occurrence back in place.
+    let without_override = text
+        .split_inclusive("END:VEVENT\r\n")
+        .filter(|part| !part.contains("RECURRENCE-ID"))
+        .collect::<String>();
+    let report = ics_import::import_ics(&conn_b, &key_b, &without_override, None, false)
+        .expect("import without override");
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    let occurrences = all_occurrences(&conn_b, &key_b);
+    assert_eq!(
+        starts(&occurrences),
+        vec![
+            utc_ms(2024, 10, 21, 7, 0),
+            utc_ms(2024, 10, 30, 8, 0),
+            utc_ms(2024, 11, 4, 8, 0),
+            utc_ms(2024, 11, 6, 8, 0),
+        ]
+    );
+    assert!(occurrences
+        .iter()
+        .all(|o| !o.is_moved && o.title == "Standup"));
+}
@@ -0,0 +1,228 @@
+use std::fs;
+use std::io::Write;
+
+use secondloop_rust::auth;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
+use secondloop_rust::export::jsonl::{
+    export_vault_jsonl, verify_vault_jsonl_export, JSONL_EXPORT_SCHEMA_VERSION,
+};
+
+fn read_jsonl(path: &std::path::Path) -> Vec<serde_json::Value> {
+    fs::read_to_string(path)
+        .expect("read jsonl")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("json line"))
+        .collect()
+}
+
+#[test]
+fn jsonl_export_is_decrypted_and_verifies() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message =
+        db::insert_message(&conn, &key, &conversation.id, "user", "secret note").expect("message");
+    let tag = db::upsert_tag(&conn, &key, "garden").expect("tag");
+    db::set_message_tags(&conn, &key, &message.id, std::slice::from_ref(&tag.id))
+        .expect("message tags");
+    let attachment = db::insert_attachment(&conn, &key, &app_dir, b"png bytes", "image/png")
+        .expect("attachment");
+    db::link_attachment_to_message(&conn, &key, &message.id, &attachment.sha256).expect("link");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:1",
+        "Water plants",
+        Some(1_730_808_000_000),
+        "open",
+        Some(&message.id),
+        None,
+        None,
+        None,
+    )
+    .expect("todo");
+    db::upsert_todo_recurrence(
+        &conn,
+        "todo:1",
+        "series:1",
+        r#"{"freq":"weekly","interval":1}"#,
+    )
+    .expect("recurrence");
+    db::append_todo_note(&conn, &key, "todo:1", "bought a hose", None).expect("note");
+    db::upsert_event(
+        &conn,
+        &key,
+        "event:1",
+        "Garden fair",
+        1_730_808_000_000,
+        1_730_811_600_000,
+        "UTC",
+        Some(&message.id),
+    )
+    .expect("event");
+
+    let out_dir = temp.path().join("export");
+    let summary = export_vault_jsonl(&conn, &key, &out_dir, 1_730_900_000_000).expect("export");
+    assert_eq!(summary.schema_version, JSONL_EXPORT_SCHEMA_VERSION);
+    let count = |stream: &str| {
+        summary
+            .streams
+            .iter()
+            .find(|s| s.stream == stream)
+            .map(|s| s.count)
+            .expect("stream count")
+    };
+    // The todo note is also recorded as a message next to the todo's source entry.
+    assert_eq!(count("conversations"), 1);
+    assert_eq!(count("messages"), 2);
+    assert_eq!(count("message_tags"), 1);
+    assert_eq!(count("message_attachments"), 1);
+    assert_eq!(count("todo_series"), 1);
+    assert_eq!(count("todo_recurrences"), 1);
+    assert_eq!(count("events"), 1);
+
+    let messages = read_jsonl(&out_dir.join("messages.jsonl"));
+    let exported = messages
+        .iter()
+        .find(|m| m["id"] == message.id.as_str())
+        .expect("exported message");
+    assert_eq!(exported["content"], "secret note");
+    assert_eq!(exported["conversation_id"], conversation.id.as_str());
+    let tags = read_jsonl(&out_dir.join("tags.jsonl"));
+    assert!(tags.iter().any(|t| t["name"] == "garden"));
+    let todos = read_jsonl(&out_dir.join("todos.jsonl"));
+    assert_eq!(todos[0]["title"], "Water plants");
+    let activities = read_jsonl(&out_dir.join("todo_activities.jsonl"));
+    assert!(activities.iter().any(|a| a["content"] == "bought a hose"));
+    let series = read_jsonl(&out_dir.join("todo_series.jsonl"));
+    assert_eq!(series[0]["rule"]["freq"], "weekly");
+
+    let verification = verify_vault_jsonl_export(&out_dir).expect("verify");
+    assert!(
+        verification.problems.is_empty(),
+        "{:?}",
+        verification.problems
+    );
+
+    // A dangling reference (and the resulting count mismatch) is reported.
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(out_dir.join("message_tags.jsonl"))
+        .expect("open message_tags");
+    writeln!(
+        file,
+        r#"{{"message_id":"missing","tag_id":"{}","created_at_ms":0}}"#,
+        tag.id
+    )
+    .expect("append");
+    drop(file);
+
+    let verification = verify_vault_jsonl_export(&out_dir).expect("verify tampered");
+    assert_eq!(
+        verification.problems.len(),
+        2,
+        "{:?}",
+        verification.problems
+    );
+    assert!(verification
+        .problems
+        .iter()
+        .any(|p| p.contains("message_id missing not found in messages")));
+
+    // An export written under another schema version is rejected outright.
+    let manifest_path = out_dir.join("manifest.json");
+    let mut manifest: serde_json::Value =
+        serde_json::from_slice(&fs::read(&manifest_path).expect("read manifest")).expect("json");
+    manifest["schema_version"] = (JSONL_EXPORT_SCHEMA_VERSION + 1).into();
+    fs::write(&manifest_path, manifest.to_string()).expect("write manifest");
+    let err = verify_vault_jsonl_export(&out_dir).expect_err("unknown version");
+    assert!(err
+        .to_string()
+        .contains("unsupported export schema_version"));
+}
+
+#[test]
+fn jsonl_export_includes_prompt_templates() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let template = db::upsert_prompt_template(
+        &conn,
+        &key,
+        None,
+        "Terse",
+        "ask_ai",
+        "Answer in one line.\n{contexts}\nQ: {question}",
+    )
+    .expect("template");
+
+    let out_dir = temp.path().join("export");
+    let summary = export_vault_jsonl(&conn, &key, &out_dir, 1_730_900_000_000).expect("export");
+    assert!(summary
+        .streams
+        .iter()
+        .any(|s| s.stream == "prompt_templates" && s.count == 1));
+
+    let rows = read_jsonl(&out_dir.join("prompt_templates.jsonl"));
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["id"], template.id.as_str());
+    assert_eq!(rows[0]["name"], "Terse");
+    assert_eq!(rows[0]["purpose"], "ask_ai");
+    assert_eq!(rows[0]["body"], template.body.as_str());
+    assert_eq!(rows[0]["created_at_ms"], template.created_at_ms);
+    assert_eq!(rows[0]["updated_at_ms"], template.updated_at_ms);
+
+    let verification = verify_vault_jsonl_export(&out_dir).expect("verify");
+    assert!(
+        verification.problems.is_empty(),
+        "{:?}",
+        verification.problems
+    );
+}
+
+#[test]
+fn jsonl_export_includes_llm_model_prices() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    db::upsert_llm_model_price(&conn, &key, "openai-compatible", "gpt-4o", 2.5, 10.0)
+        .expect("price");
+    let price = db::list_llm_model_prices(&conn)
+        .expect("prices")
+        .pop()
+        .expect("price row");
+
+    let out_dir = temp.path().join("export");
+    let summary = export_vault_jsonl(&conn, &key, &out_dir, 1_730_900_000_000).expect("export");
+    assert!(summary
+        .streams
+        .iter()
+        .any(|s| s.stream == "llm_model_prices" && s.count == 1));
+
+    let rows = read_jsonl(&out_dir.join("llm_model_prices.jsonl"));
+    assert_eq!(
+        rows,
+        vec![serde_json::json!({
+            "provider_type": "openai-compatible",
+            "model_name": "gpt-4o",
+            "input_usd_per_million_tokens": 2.5,
+            "output_usd_per_million_tokens": 10.0,
+            "updated_at_ms": price.updated_at_ms,
+        })]
+    );
+
+    let verification = verify_vault_jsonl_export(&out_dir).expect("verify");
+    assert!(
+        verification.problems.is_empty(),
+        "{:?}",
+        verification.problems
+    );
+}
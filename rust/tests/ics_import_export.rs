@@ -0,0 +1,291 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::export::ics as ics_export;
+use secondloop_rust::import::ics as ics_import;
+use secondloop_rust::sync;
+
+fn utc_ms(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> i64 {
+    let date = time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+        .expect("date");
+    let time = time::Time::from_hms(hour, minute, 0).expect("time");
+    time::PrimitiveDateTime::new(date, time)
+        .assume_utc()
+        .unix_timestamp()
+        * 1000
+}
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+const WORK_CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Example Corp//Calendar//EN\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701101T020000\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:planning-42@example.com\r\n\
+DTSTAMP:20241101T120000Z\r\n\
+SUMMARY:Quarterly planning\\, part 2\r\n\
+DTSTART;TZID=America/New_York:20241105T093000\r\n\
+DURATION:PT1H30M\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Reminder\r\n\
+TRIGGER:-PT15M\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VTODO\r\n\
+UID:timesheet@example.com\r\n\
+DTSTAMP:20241101T120000Z\r\n\
+SUMMARY:Submit timesh\r\n\
+\x20eet\r\n\
+STATUS:NEEDS-ACTION\r\n\
+DUE;TZID=America/New_York:20241101T170000\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=FR;COUNT=4\r\n\
+EXDATE;TZID=America/New_York:20241108T170000\r\n\
+END:VTODO\r\n\
+BEGIN:VTODO\r\n\
+SUMMARY:No uid\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+#[test]
+fn import_is_keyed_by_uid_and_skips_unchanged_items() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+
+    let preview = ics_import::import_ics(&conn, &key, WORK_CALENDAR, None, true).expect("preview");
+    assert_eq!(preview.items_imported, 2);
+    assert!(db::list_events(&conn, &key).expect("events").is_empty());
+
+    let report = ics_import::import_ics(&conn, &key, WORK_CALENDAR, None, false).expect("import");
+    assert_eq!(report.items_scanned, 3);
+    assert_eq!(report.items_imported, 2);
+    assert_eq!(report.todos_created, 1);
+    assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
+
+    let event = db::get_event(&conn, &key, "ics:planning-42@example.com").expect("event");
+    assert_eq!(event.title, "Quarterly planning, part 2");
+    // 09:30 EST.
+    assert_eq!(event.start_at_ms, utc_ms(2024, 11, 5, 14, 30));
+    assert_eq!(event.end_at_ms, utc_ms(2024, 11, 5, 16, 0));
+    assert_eq!(event.tz, "America/New_York");
+
+    let todo_id = "ics:timesheet@example.com";
+    let todo = db::get_todo(&conn, &key, todo_id).expect("todo");
+    assert_eq!(todo.title, "Submit timesheet");
+    assert_eq!(todo.status, "open");
+    // 17:00 EDT.
+    assert_eq!(todo.due_at_ms, Some(utc_ms(2024, 11, 1, 21, 0)));
+    assert_eq!(
+        db::get_todo_timezone(&conn, todo_id).expect("tz"),
+        Some("America/New_York".to_string())
+    );
+
+    // The excluded Friday is skipped, and local time survives the DST end.
+    db::set_todo_status(&conn, &key, todo_id, "done", None).expect("done");
+    let next_id = format!("todo:{todo_id}:1");
+    let next = db::get_todo(&conn, &key, &next_id).expect("spawned");
+    assert_eq!(next.due_at_ms, Some(utc_ms(2024, 11, 15, 22, 0)));
+
+    let again = ics_import::import_ics(&conn, &key, WORK_CALENDAR, None, false).expect("again");
+    assert_eq!(again.items_skipped_unchanged, 2);
+    assert_eq!(again.items_imported + again.items_updated, 0);
+
+    // An edited item updates in place. A recurring VTODO stands for its
+    // series from the current occurrence on, as clients advance DUE (and
+    // shrink COUNT) on completion, so it updates the newest occurrence.
+    let edited = WORK_CALENDAR
+        .replace("Submit timesh\r\n eet", "Submit hours")
+        .replace("20241101T170000", "20241115T170000")
+        .replace("COUNT=4", "COUNT=3")
+        .replace("Quarterly planning\\, part 2", "Quarterly planning");
+    let updated = ics_import::import_ics(&conn, &key, &edited, None, false).expect("update");
+    assert_eq!(updated.items_updated, 2);
+    assert_eq!(updated.items_imported, 0);
+    assert_eq!(db::list_events(&conn, &key).expect("events").len(), 1);
+    assert_eq!(db::list_todos(&conn, &key).expect("todos").len(), 2);
+    assert_eq!(
+        db::get_event(&conn, &key, "ics:planning-42@example.com")
+            .expect("event")
+            .title,
+        "Quarterly planning"
+    );
+    assert_eq!(
+        db::get_todo(&conn, &key, &next_id).expect("next").title,
+        "Submit hours"
+    );
+    assert_eq!(
+        db::get_todo(&conn, &key, todo_id).expect("first").title,
+        "Submit timesheet"
+    );
+    assert_eq!(
+        db::get_todo(&conn, &key, &next_id).expect("next").due_at_ms,
+        Some(utc_ms(2024, 11, 15, 22, 0))
+    );
+    let series_rule: serde_json::Value = serde_json::from_str(
+        &db::get_todo_recurrence_rule_json(&conn, &next_id)
+            .expect("rule query")
+            .expect("rule"),
+    )
+    .expect("rule json");
+    assert_eq!(series_rule["count"], 4);
+
+    assert!(ics_import::import_ics(&conn, &key, "not a calendar", None, false).is_err());
+}
+
+#[test]
+fn export_round_trips_recurrence_and_timezones() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop_a");
+
+    db::upsert_event(
+        &conn,
+        &key,
+        "event:dentist",
+        "Dentist; bring card",
+        utc_ms(2024, 11, 5, 8, 0),
+        utc_ms(2024, 11, 5, 9, 0),
+        "Europe/Berlin",
+        None,
+    )
+    .expect("upsert event");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:standup",
+        "Standup notes",
+        Some(utc_ms(2024, 11, 4, 8, 0)),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert todo");
+    db::set_todo_timezone(&conn, &key, "todo:standup", Some("Europe/Berlin")).expect("tz");
+    let rule = format!(
+        r#"{{"freq":"weekly","byday":["MO","WE"],"count":6,"exdates_ms":[{}]}}"#,
+        utc_ms(2024, 11, 11, 8, 0)
+    );
+    db::upsert_todo_recurrence_with_sync(&conn, &key, "todo:standup", "series:standup", &rule)
+        .expect("recurrence");
+    db::set_todo_status(&conn, &key, "todo:standup", "done", None).expect("done");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:taxes",
+        "File taxes",
+        None,
+        "dismissed",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert taxes");
+
+    let (text, summary) =
+        ics_export::export_ics(&conn, &key, utc_ms(2024, 11, 5, 0, 0)).expect("export");
+    assert_eq!(summary.events, 1);
+    // The completed first standup is represented by its series.
+    assert_eq!(summary.todos, 2);
+    assert!(text.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(text.lines().all(|line| line.len() <= 76));
+    for expected in [
+        "UID:event:dentist@secondloop",
+        "SUMMARY:Dentist\\; bring card",
+        "DTSTART;TZID=Europe/Berlin:20241105T090000",
+        "UID:series:standup@secondloop",
+        "DUE;TZID=Europe/Berlin:20241106T090000",
+        "RRULE:FREQ=WEEKLY;COUNT=5;BYDAY=MO,WE",
+        "EXDATE;TZID=Europe/Berlin:20241111T090000",
+        "STATUS:CANCELLED",
+    ] {
+        assert!(text.contains(expected), "missing {expected} in\n{text}");
+    }
+
+    // Re-importing our own export matches every item back to its row.
+    let reimport = ics_import::import_ics(&conn, &key, &text, None, false).expect("reimport");
+    assert_eq!(reimport.items_imported, 0, "{:?}", reimport.errors);
+    assert_eq!(reimport.items_updated, 3);
+    assert!(reimport.errors.is_empty(), "{:?}", reimport.errors);
+    assert_eq!(db::list_todos(&conn, &key).expect("todos").len(), 3);
+    assert_eq!(
+        db::get_todo_recurrence_rule_json(&conn, "todo:standup").expect("rule"),
+        Some(rule.clone())
+    );
+
+    // A fresh vault gets the same series, counted from the exported occurrence.
+    let (conn_b, key_b) = open_vault(&temp, "secondloop_b");
+    let imported = ics_import::import_ics(&conn_b, &key_b, &text, None, false).expect("import B");
+    assert_eq!(imported.items_imported, 3, "{:?}", imported.errors);
+    let event = db::get_event(&conn_b, &key_b, "event:dentist").expect("event B");
+    assert_eq!(event.start_at_ms, utc_ms(2024, 11, 5, 8, 0));
+    assert_eq!(event.tz, "Europe/Berlin");
+    assert_eq!(
+        db::get_todo(&conn_b, &key_b, "series:standup")
+            .expect("series todo B")
+            .due_at_ms,
+        Some(utc_ms(2024, 11, 6, 8, 0))
+    );
+    assert_eq!(
+        db::preview_recurrence_occurrences(
+            &db::get_todo_recurrence_rule_json(&conn_b, "series:standup")
+                .expect("rule B")
+                .expect("rule B"),
+            utc_ms(2024, 11, 6, 8, 0),
+            10
+        )
+        .expect("preview")
+        .len(),
+        5
+    );
+}
+
+#[test]
+fn imported_items_sync_to_other_devices() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "secondloop_a");
+    ics_import::import_ics(&conn_a, &key_a, WORK_CALENDAR, None, false).expect("import");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-ics",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Ics").expect("push");
+
+    let (conn_b, key_b) = open_vault(&temp, "secondloop_b");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Ics").expect("pull");
+
+    let event = db::get_event(&conn_b, &key_b, "ics:planning-42@example.com").expect("event B");
+    assert_eq!(event.title, "Quarterly planning, part 2");
+    let todo_id = "ics:timesheet@example.com";
+    assert_eq!(
+        db::get_todo(&conn_b, &key_b, todo_id)
+            .expect("todo B")
+            .title,
+        "Submit timesheet"
+    );
+    assert_eq!(
+        db::get_todo_timezone(&conn_b, todo_id).expect("tz B"),
+        Some("America/New_York".to_string())
+    );
+    assert!(db::get_todo_recurrence_rule_json(&conn_b, todo_id)
+        .expect("rule B")
+        .is_some());
+}
@@ -0,0 +1,247 @@
+use std::fs;
+
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::import::chat::{
+    import_telegram_export, import_whatsapp_export, parse_telegram_export, parse_whatsapp_export,
+};
+use secondloop_rust::sync;
+
+#[test]
+fn telegram_export_preview_import_and_sync() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let export_dir = temp_dir.path().join("ChatExport_2024-01-05");
+    fs::create_dir_all(export_dir.join("photos")).expect("photos dir");
+    fs::write(
+        export_dir.join("photos/photo_1.jpg"),
+        b"\xff\xd8\xff fake jpeg",
+    )
+    .expect("write");
+
+    let json = serde_json::json!({
+        "name": "Saved Messages",
+        "type": "saved_messages",
+        "id": 42,
+        "messages": [
+            {"id": 1, "type": "service", "date": "2024-01-01T08:00:00", "date_unixtime": "1704096000", "action": "create_group", "text": ""},
+            {"id": 2, "type": "message", "date": "2024-01-01T08:01:00", "date_unixtime": "1704096060", "from": "Me", "text": "Book dentist"},
+            {"id": 3, "type": "message", "date": "2024-01-01T08:02:00", "date_unixtime": "1704096120", "from": "Me",
+             "text": ["Link: ", {"type": "link", "text": "https://example.com"}]},
+            {"id": 4, "type": "message", "date": "2024-01-01T08:03:00", "date_unixtime": "1704096180", "from": "Me",
+             "photo": "photos/photo_1.jpg", "text": ""},
+            {"id": 5, "type": "message", "date": "2024-01-01T08:01:00", "date_unixtime": "1704096060", "from": "Me", "text": "Book dentist"}
+        ]
+    });
+    let json_path = export_dir.join("result.json");
+    fs::write(&json_path, serde_json::to_vec(&json).expect("json")).expect("write json");
+
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test())
+        .expect("init master password");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let preview = import_telegram_export(&conn, &key, &app_dir, &json_path, "en", None, true)
+        .expect("preview");
+    // Message 5 repeats message 2 verbatim; a repeat is still its own message.
+    assert_eq!(preview.items_scanned, 4, "{preview:?}");
+    assert_eq!(preview.messages_created, 4, "{preview:?}");
+    assert_eq!(preview.items_skipped_duplicate, 0, "{preview:?}");
+    assert_eq!(preview.attachments_linked, 1, "{preview:?}");
+    assert!(db::list_conversations(&conn, &key)
+        .expect("conversations")
+        .iter()
+        .all(|c| c.title != "Saved Messages"));
+
+    let report = import_telegram_export(&conn, &key, &app_dir, &json_path, "en", None, false)
+        .expect("import");
+    assert_eq!(report.messages_created, 4, "{report:?}");
+
+    let conversation = db::list_conversations(&conn, &key)
+        .expect("conversations")
+        .into_iter()
+        .find(|c| c.title == "Saved Messages")
+        .expect("saved messages conversation");
+    let messages = db::list_messages(&conn, &key, &conversation.id).expect("messages");
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[0].content, "Book dentist");
+    assert_eq!(messages[0].created_at_ms, 1_704_096_060_000);
+    assert_eq!(messages[1].content, "Book dentist");
+    assert_eq!(messages[2].content, "Link: https://example.com");
+    let attachments =
+        db::list_message_attachments(&conn, &key, &messages[3].id).expect("attachments");
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].mime_type, "image/jpeg");
+
+    let again =
+        import_telegram_export(&conn, &key, &app_dir, &json_path, "en", None, true).expect("again");
+    assert_eq!(again.messages_created, 0, "{again:?}");
+    assert_eq!(again.items_skipped_unchanged, 4, "{again:?}");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync1",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn, &key, &sync_key, &remote, "ImportTest").expect("push");
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "ImportTest").expect("pull");
+
+    let messages_b = db::list_messages(&conn_b, &key_b, &conversation.id).expect("messages B");
+    assert_eq!(messages_b.len(), 4);
+    assert_eq!(messages_b[0].created_at_ms, 1_704_096_060_000);
+    assert_eq!(
+        db::list_message_attachments(&conn_b, &key_b, &messages_b[3].id)
+            .expect("attachments B")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn whatsapp_export_parses_android_and_ios_formats() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let export_dir = temp_dir.path().join("wa");
+    fs::create_dir_all(&export_dir).expect("export dir");
+    fs::write(
+        export_dir.join("IMG-20231231-WA0001.jpg"),
+        b"\xff\xd8\xff fake",
+    )
+    .expect("write");
+
+    let android = export_dir.join("WhatsApp Chat with Notes.txt");
+    fs::write(
+        &android,
+        "12/31/23, 9:15 PM - Messages and calls are end-to-end encrypted.\n\
+         12/31/23, 9:15 PM - Me: Idea for the garden\n\
+         plant tomatoes\n\
+         12/31/23, 9:16 PM - Me: IMG-20231231-WA0001.jpg (file attached)\n\
+         1/2/24, 7:05 AM - Me: Call mom\n",
+    )
+    .expect("write android");
+
+    let chat = parse_whatsapp_export(&android, None).expect("parse android");
+    assert_eq!(chat.title, "Notes");
+    assert_eq!(chat.messages.len(), 3);
+    assert_eq!(chat.messages[0].text, "Idea for the garden\nplant tomatoes");
+    // 2023-12-31 21:15 UTC
+    assert_eq!(chat.messages[0].created_at_ms, 1_704_057_300_000);
+    assert!(chat.messages[1].text.is_empty());
+    assert_eq!(chat.messages[1].attachments.len(), 1);
+    // Month-first: 1/2/24 is January 2nd.
+    assert_eq!(chat.messages[2].created_at_ms, 1_704_179_100_000);
+
+    // Wall-clock times are read in the exporting phone's zone when given.
+    let chat = parse_whatsapp_export(&android, Some("Europe/Berlin")).expect("parse berlin");
+    assert_eq!(
+        chat.messages[0].created_at_ms,
+        1_704_057_300_000 - 3_600_000
+    );
+
+    // iOS names every export `_chat.txt`; the chat name comes from the folder
+    // the export zip was extracted to.
+    let ios_dir = temp_dir.path().join("WhatsApp Chat - Notes");
+    fs::create_dir_all(&ios_dir).expect("ios dir");
+    let ios = ios_dir.join("_chat.txt");
+    fs::write(
+        &ios,
+        "[02/01/2024, 07:05:00] Me: Call mom\n\
+         [02/01/2024, 07:05:00] Me: Call mom\n\
+         [13/01/2024, 18:00:30] Me: Pay rent\n",
+    )
+    .expect("write ios");
+    let chat = parse_whatsapp_export(&ios, None).expect("parse ios");
+    assert_eq!(chat.title, "Notes");
+    assert_eq!(chat.messages.len(), 3);
+    assert_eq!(chat.messages[0].created_at_ms, 1_704_179_100_000);
+
+    let other_dir = temp_dir.path().join("WhatsApp Chat - Alice");
+    fs::create_dir_all(&other_dir).expect("other dir");
+    fs::write(
+        other_dir.join("_chat.txt"),
+        "[02/01/2024, 07:05:00] Me: Hi\n",
+    )
+    .expect("write");
+    let other = parse_whatsapp_export(&other_dir.join("_chat.txt"), None).expect("parse other");
+    assert_eq!(other.title, "Alice");
+
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test())
+        .expect("init master password");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let report =
+        import_whatsapp_export(&conn, &key, &app_dir, &android, "en", None, false).expect("import");
+    assert_eq!(report.messages_created, 3, "{report:?}");
+    assert_eq!(report.attachments_linked, 1, "{report:?}");
+
+    // The iOS export of the same chat has the Android "Call mom" plus a
+    // second, identical "Call mom" that is a message of its own.
+    let preview =
+        import_whatsapp_export(&conn, &key, &app_dir, &ios, "en", None, true).expect("preview");
+    assert_eq!(preview.items_skipped_unchanged, 1, "{preview:?}");
+    assert_eq!(preview.messages_created, 2, "{preview:?}");
+
+    assert!(import_whatsapp_export(
+        &conn,
+        &key,
+        &app_dir,
+        &ios,
+        "en",
+        Some("Mars/Olympus"),
+        true
+    )
+    .is_err());
+
+    let rerun =
+        import_whatsapp_export(&conn, &key, &app_dir, &android, "en", None, false).expect("rerun");
+    assert_eq!(rerun.messages_created, 0, "{rerun:?}");
+    assert_eq!(rerun.items_skipped_unchanged, 3, "{rerun:?}");
+}
+
+#[test]
+fn chat_export_media_cannot_escape_the_export_folder() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let secret = temp_dir.path().join("secret.jpg");
+    fs::write(&secret, b"\xff\xd8\xff secret").expect("write secret");
+
+    let telegram_dir = temp_dir.path().join("ChatExport");
+    fs::create_dir_all(&telegram_dir).expect("telegram dir");
+    let json = serde_json::json!({
+        "name": "Leak",
+        "type": "personal_chat",
+        "id": 7,
+        "messages": [
+            {"id": 1, "type": "message", "date": "2024-01-01T08:00:00", "date_unixtime": "1704096000",
+             "from": "Me", "photo": "../secret.jpg", "text": ""},
+            {"id": 2, "type": "message", "date": "2024-01-01T08:01:00", "date_unixtime": "1704096060",
+             "from": "Me", "file": secret.to_string_lossy(), "text": "absolute"}
+        ]
+    });
+    let json_path = telegram_dir.join("result.json");
+    fs::write(&json_path, serde_json::to_vec(&json).expect("json")).expect("write json");
+    let chats = parse_telegram_export(&json_path, None).expect("telegram");
+    assert!(chats
+        .iter()
+        .flat_map(|c| &c.messages)
+        .all(|m| m.attachments.is_empty()));
+
+    let whatsapp_dir = temp_dir.path().join("WhatsApp Chat - Leak");
+    fs::create_dir_all(&whatsapp_dir).expect("whatsapp dir");
+    let txt_path = whatsapp_dir.join("_chat.txt");
+    fs::write(
+        &txt_path,
+        "[01/02/2024, 09:00:00] Ana: \u{200e}<attached: ../secret.jpg>\n",
+    )
+    .expect("write chat");
+    let chat = parse_whatsapp_export(&txt_path, None).expect("whatsapp");
+    assert_eq!(chat.messages.len(), 1);
+    assert!(chat.messages[0].attachments.is_empty());
+}
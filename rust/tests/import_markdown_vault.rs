@@ -0,0 +1,210 @@
+use std::fs;
+
+use secondloop_rust::auth;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
+use secondloop_rust::import::markdown::{import_markdown_dir, parse_markdown_note};
+
+#[test]
+fn parse_markdown_note_reads_front_matter_todos_and_embeds() {
+    let note = parse_markdown_note(
+        "fallback",
+        "---\ntitle: Weekly review\ntags:\n  - work\n  - \"#ideas\"\ncreated: 2024-01-02 09:30\n---\n\
+         Body text\n- [ ] Call the bank\n- [x] Ship release\n```\n- [ ] not a todo\n```\n\
+         ![[diagram.png|300]] and ![spec](files/my%20spec.pdf \"Spec\") ![remote](https://x.test/a.png)\n",
+        None,
+    );
+
+    assert_eq!(note.title, "Weekly review");
+    assert_eq!(note.tags, vec!["work".to_string(), "ideas".to_string()]);
+    assert_eq!(note.created_at_ms, Some(1_704_187_800_000));
+    assert_eq!(note.todos.len(), 2);
+    assert!(!note.todos[0].done);
+    assert_eq!(note.todos[1].title, "Ship release");
+    assert!(note.todos[1].done);
+    let targets: Vec<&str> = note.embeds.iter().map(|e| e.target.as_str()).collect();
+    assert_eq!(targets, vec!["diagram.png", "files/my spec.pdf"]);
+    assert!(note
+        .message_content()
+        .starts_with("# Weekly review\n\nBody text"));
+
+    let inline = parse_markdown_note("Inbox", "---\ntags: [a, b]\n---\n# Heading\ntext", None);
+    assert_eq!(inline.title, "Heading");
+    assert_eq!(inline.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(inline.message_content(), "# Heading\ntext");
+
+    let local = parse_markdown_note(
+        "Local",
+        "---\ncreated: 2024-01-02 09:30\n---\nBody",
+        Some("America/New_York"),
+    );
+    assert_eq!(local.created_at_ms, Some(1_704_187_800_000 + 5 * 3_600_000));
+    let offset = parse_markdown_note(
+        "Offset",
+        "---\ncreated: 2024-01-02T09:30:00Z\n---\nBody",
+        Some("America/New_York"),
+    );
+    assert_eq!(offset.created_at_ms, Some(1_704_187_800_000));
+}
+
+#[test]
+fn import_markdown_vault_is_idempotent() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let vault = temp_dir.path().join("Vault");
+    fs::create_dir_all(vault.join("Daily")).expect("daily dir");
+    fs::create_dir_all(vault.join("assets")).expect("assets dir");
+    fs::create_dir_all(vault.join(".obsidian")).expect("obsidian dir");
+
+    fs::write(vault.join(".obsidian/workspace.md"), "ignored").expect("write");
+    fs::write(vault.join("assets/diagram.png"), b"\x89PNG\r\n\x1a\nfake").expect("write");
+    fs::write(vault.join("Daily/spec.pdf"), b"%PDF-1.4 fake").expect("write");
+    fs::write(
+        vault.join("Daily/2024-01-02.md"),
+        "---\ntags: [work, ideas]\ndate: 2024-01-02\n---\nStandup notes\n- [ ] Follow up with Ana\n- [x] Send agenda\n![[diagram.png]]\n![](spec.pdf)\n",
+    )
+    .expect("write");
+    fs::write(vault.join("Inbox.md"), "Remember the milk\n").expect("write");
+
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test())
+        .expect("init master password");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let report = import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("import");
+    assert_eq!(report.items_scanned, 2, "{report:?}");
+    assert_eq!(report.messages_created, 2, "{report:?}");
+    assert_eq!(report.todos_created, 2, "{report:?}");
+    assert_eq!(report.attachments_linked, 2, "{report:?}");
+    assert_eq!(report.tags_applied, 2, "{report:?}");
+    assert!(report.errors.is_empty(), "{report:?}");
+
+    let conversations = db::list_conversations(&conn, &key).expect("conversations");
+    let daily = conversations
+        .iter()
+        .find(|c| c.title == "Vault/Daily")
+        .expect("daily conversation");
+    assert!(conversations.iter().any(|c| c.title == "Vault"));
+
+    let messages = db::list_messages(&conn, &key, &daily.id).expect("messages");
+    assert_eq!(messages.len(), 1);
+    let message = &messages[0];
+    assert_eq!(message.created_at_ms, 1_704_153_600_000);
+    assert!(message.content.starts_with("# 2024-01-02\n\nStandup notes"));
+
+    let mut tag_names: Vec<String> = db::list_message_tags(&conn, &key, &message.id)
+        .expect("tags")
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+    tag_names.sort();
+    assert_eq!(tag_names, vec!["ideas".to_string(), "work".to_string()]);
+
+    let attachments = db::list_message_attachments(&conn, &key, &message.id).expect("attachments");
+    let mut mimes: Vec<String> = attachments.into_iter().map(|a| a.mime_type).collect();
+    mimes.sort();
+    assert_eq!(
+        mimes,
+        vec!["application/pdf".to_string(), "image/png".to_string()]
+    );
+
+    let todos = db::list_todos(&conn, &key).expect("todos");
+    assert_eq!(todos.len(), 2);
+    assert!(todos
+        .iter()
+        .all(|t| t.source_entry_id.as_deref() == Some(message.id.as_str())));
+    assert!(todos
+        .iter()
+        .any(|t| t.title == "Send agenda" && t.status == "done"));
+
+    let rerun = import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("rerun");
+    assert_eq!(rerun.items_skipped_unchanged, 2, "{rerun:?}");
+    assert_eq!(rerun.messages_created, 0);
+    assert_eq!(rerun.todos_created, 0);
+    assert_eq!(rerun.attachments_linked, 0);
+
+    fs::write(
+        vault.join("Inbox.md"),
+        "Remember the milk\n- [x] Buy milk\n",
+    )
+    .expect("rewrite");
+    let edited = import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("edited");
+    assert_eq!(edited.items_updated, 1, "{edited:?}");
+    assert_eq!(edited.items_skipped_unchanged, 1, "{edited:?}");
+    assert_eq!(edited.messages_created, 0);
+    assert_eq!(edited.todos_created, 1);
+    assert_eq!(db::list_todos(&conn, &key).expect("todos").len(), 3);
+
+    fs::write(
+        vault.join("Inbox.md"),
+        "Remember the milk\n- [ ] Buy milk\n",
+    )
+    .expect("rewrite");
+    let unchecked =
+        import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("unchecked");
+    assert_eq!(unchecked.items_updated, 1, "{unchecked:?}");
+    assert_eq!(unchecked.todos_created, 0);
+    let todos = db::list_todos(&conn, &key).expect("todos");
+    assert_eq!(todos.len(), 3);
+    assert!(todos
+        .iter()
+        .any(|t| t.title == "Buy milk" && t.status == "open"));
+
+    // Editing a note with embeds only counts links it did not have yet.
+    fs::write(vault.join("assets/chart.png"), b"\x89PNG\r\n\x1a\nchart").expect("write");
+    fs::write(
+        vault.join("Daily/2024-01-02.md"),
+        "---\ntags: [work, ideas]\ndate: 2024-01-02\n---\nStandup notes, edited\n- [ ] Follow up with Ana\n- [x] Send agenda\n![[diagram.png]]\n![](spec.pdf)\n",
+    )
+    .expect("rewrite daily");
+    let relinked =
+        import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("relinked");
+    assert_eq!(relinked.items_updated, 1, "{relinked:?}");
+    assert_eq!(relinked.attachments_linked, 0, "{relinked:?}");
+    fs::write(
+        vault.join("Daily/2024-01-02.md"),
+        "---\ntags: [work, ideas]\ndate: 2024-01-02\n---\nStandup notes, edited\n- [ ] Follow up with Ana\n- [x] Send agenda\n![[diagram.png]]\n![](spec.pdf)\n![[chart.png]]\n",
+    )
+    .expect("rewrite daily");
+    let added = import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("added");
+    assert_eq!(added.attachments_linked, 1, "{added:?}");
+    assert_eq!(
+        db::list_message_attachments(&conn, &key, &message.id)
+            .expect("attachments")
+            .len(),
+        3
+    );
+
+    assert!(
+        import_markdown_dir(&conn, &key, &app_dir, &vault, "en", Some("Mars/Olympus")).is_err()
+    );
+}
+
+#[test]
+fn import_markdown_embeds_cannot_escape_the_vault() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let vault = temp_dir.path().join("Vault");
+    fs::create_dir_all(vault.join("Notes")).expect("notes dir");
+    let secret = temp_dir.path().join("secret.png");
+    fs::write(&secret, b"\x89PNG\r\n\x1a\nsecret").expect("write secret");
+    fs::write(
+        vault.join("Notes/leak.md"),
+        format!(
+            "![[../../secret.png]]\n![](../../secret.png)\n![[{}]]\n",
+            secret.display()
+        ),
+    )
+    .expect("write note");
+
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test())
+        .expect("init master password");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let report = import_markdown_dir(&conn, &key, &app_dir, &vault, "en", None).expect("import");
+    assert_eq!(report.messages_created, 1, "{report:?}");
+    assert_eq!(report.attachments_linked, 0, "{report:?}");
+    assert_eq!(report.errors.len(), 3, "{report:?}");
+    assert!(db::list_recent_attachments(&conn, &key, 10)
+        .expect("attachments")
+        .is_empty());
+}
@@ -1,6 +1,6 @@
 use std::io::Cursor;
 
-use secondloop_rust::llm::{anthropic, ChatDelta};
+use secondloop_rust::llm::{anthropic, ChatDelta, LlmUsage};
 
 #[test]
 fn anthropic_sse_parses_deltas_and_done() {
@@ -17,6 +17,9 @@ data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text
 event: content_block_delta
 data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":" world"}}
 
+event: message_delta
+data: {"type":"message_delta","delta":{"stop_reason":"end_turn","stop_sequence":null},"usage":{"output_tokens":12}}
+
 event: message_stop
 data: {"type":"message_stop"}
 "#;
@@ -29,21 +32,25 @@ data: {"type":"message_stop"}
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: Some(LlmUsage::new(10, 12, None)),
             },
         ]
     );
@@ -74,11 +81,13 @@ fn anthropic_non_stream_json_parses_message_content() {
                 role: Some("assistant".to_string()),
                 text_delta: "Hello from JSON".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: Some(LlmUsage::new(10, 2, None)),
             },
         ]
     );
@@ -3,7 +3,7 @@ use std::net::TcpListener;
 use std::thread;
 
 use secondloop_rust::llm::anthropic::AnthropicCompatibleProvider;
-use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::llm::{ChatDelta, LlmUsage};
 use secondloop_rust::rag::AnswerProvider;
 
 fn start_one_shot_server(
@@ -70,21 +70,25 @@ data: {"type":"message_stop"}
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: Some(LlmUsage::new(10, 0, None)),
             },
         ]
     );
@@ -0,0 +1,548 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use anyhow::Result;
+use secondloop_rust::api::core;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::llm::budget::{
+    estimate_tokens, model_context_window_tokens, truncate_to_tokens, ContextBudget, TokenCounter,
+};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, embedding, rag, sync};
+
+struct SmallWindowProvider {
+    context_window: usize,
+    last_prompt: std::sync::Mutex<Option<String>>,
+}
+
+impl rag::AnswerProvider for SmallWindowProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        *self.last_prompt.lock().unwrap() = Some(prompt.to_string());
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: "ok".to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+
+    fn context_window_tokens(&self) -> Option<usize> {
+        Some(self.context_window)
+    }
+}
+
+fn create_profile(
+    conn: &rusqlite::Connection,
+    key: &[u8; 32],
+    model: &str,
+    active: bool,
+) -> String {
+    db::create_llm_profile(
+        conn,
+        key,
+        model,
+        "openai-compatible",
+        Some("http://127.0.0.1:9"),
+        Some("sk-test"),
+        model,
+        active,
+    )
+    .expect("create profile")
+    .id
+}
+
+#[test]
+fn tokens_are_estimated_and_budgets_fit_the_window() {
+    assert_eq!(estimate_tokens(""), 0);
+    assert_eq!(estimate_tokens("abcdefgh"), 2);
+    assert_eq!(estimate_tokens("你好世界"), 4);
+    assert_eq!(estimate_tokens("éé"), 1);
+
+    let text = "hello 世界 again";
+    let truncated = truncate_to_tokens(text, 3);
+    assert!(text.starts_with(truncated));
+    assert!(estimate_tokens(truncated) <= 3);
+    assert_eq!(truncate_to_tokens(text, 100), text);
+
+    assert_eq!(model_context_window_tokens("gpt-4o-mini"), Some(128_000));
+    assert_eq!(model_context_window_tokens("gpt-4"), Some(8_192));
+    assert_eq!(
+        model_context_window_tokens("gpt-4.5-preview"),
+        Some(128_000)
+    );
+    assert_eq!(
+        model_context_window_tokens("gpt-4-0125-preview"),
+        Some(128_000)
+    );
+    assert_eq!(
+        model_context_window_tokens("ft:gpt-4o-mini:org::id"),
+        Some(128_000)
+    );
+    assert_eq!(model_context_window_tokens("o1-preview"), Some(128_000));
+    assert_eq!(
+        model_context_window_tokens("anthropic/claude-3-5-sonnet"),
+        Some(200_000)
+    );
+    assert_eq!(model_context_window_tokens("my-finetune"), None);
+
+    assert_eq!(
+        ContextBudget::for_context_window(None),
+        ContextBudget::default()
+    );
+    assert_eq!(
+        ContextBudget::for_context_window(Some(128_000)),
+        ContextBudget::default()
+    );
+    let small = ContextBudget::for_context_window(Some(2_000));
+    assert_eq!(small.context_tokens, 540);
+    assert_eq!(small.history_tokens, 360);
+}
+
+#[test]
+fn openai_models_are_counted_with_their_bpe_tokenizer() {
+    assert_eq!(
+        TokenCounter::for_model("gpt-4o-mini"),
+        TokenCounter::O200kBase
+    );
+    assert_eq!(
+        TokenCounter::for_model("openai/gpt-5"),
+        TokenCounter::O200kBase
+    );
+    assert_eq!(TokenCounter::for_model("o3-mini"), TokenCounter::O200kBase);
+    assert_eq!(
+        TokenCounter::for_model("gpt-4-turbo"),
+        TokenCounter::Cl100kBase
+    );
+    assert_eq!(
+        TokenCounter::for_model("gpt-3.5-turbo"),
+        TokenCounter::Cl100kBase
+    );
+    assert_eq!(
+        TokenCounter::for_model("claude-3-5-sonnet"),
+        TokenCounter::Estimate
+    );
+
+    for counter in [TokenCounter::O200kBase, TokenCounter::Cl100kBase] {
+        assert_eq!(counter.count(""), 0);
+        assert_eq!(counter.count("hello world"), 2);
+        assert_eq!(
+            counter.truncate("hello world, hello world", 2),
+            "hello world"
+        );
+
+        let text = "会议纪要：你好世界，再见世界";
+        let truncated = counter.truncate(text, 3);
+        assert!(text.starts_with(truncated));
+        assert!(!truncated.is_empty());
+        assert!(counter.count(truncated) <= 3);
+        assert_eq!(counter.truncate(text, 1_000), text);
+    }
+
+    // Unknown models keep the estimate.
+    let text = "hello 世界 again";
+    assert_eq!(TokenCounter::Estimate.count(text), estimate_tokens(text));
+    assert_eq!(
+        TokenCounter::Estimate.truncate(text, 3),
+        truncate_to_tokens(text, 3)
+    );
+}
+
+#[test]
+fn ask_ai_trims_contexts_to_the_model_window() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME).expect("model");
+
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let long = "x".repeat(3500);
+    db::insert_message(
+        &conn,
+        &key,
+        &conversation.id,
+        "user",
+        &format!("CTX_UNIQUE {long} alpha beta"),
+    )
+    .expect("seed");
+
+    let provider = SmallWindowProvider {
+        context_window: 2_000,
+        last_prompt: std::sync::Mutex::new(None),
+    };
+    rag::ask_ai_with_provider_using_active_embeddings(
+        &conn,
+        &key,
+        &app_dir,
+        &conversation.id,
+        "alpha beta",
+        1,
+        rag::Focus::AllMemories,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask");
+
+    let prompt = provider
+        .last_prompt
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("prompt");
+    assert!(prompt.contains("CTX_UNIQUE"));
+    assert!(!prompt.contains(&long));
+    assert!(estimate_tokens(&prompt) < 2_000);
+}
+
+#[test]
+fn usage_is_priced_rolled_up_monthly_and_synced() {
+    let temp_a = tempfile::tempdir().expect("tempdir A");
+    let app_dir_a = temp_a.path().join("secondloop_a");
+    let key_a =
+        auth::init_master_password(&app_dir_a, "pw-a", KdfParams::for_test()).expect("init A");
+    let conn_a = db::open(&app_dir_a).expect("open A db");
+    let profile = create_profile(&conn_a, &key_a, "gpt-4o-mini", true);
+
+    assert!(db::upsert_llm_model_price(
+        &conn_a,
+        &key_a,
+        "openai-compatible",
+        "gpt-4o-mini",
+        -1.0,
+        0.0
+    )
+    .is_err());
+    // Unpriced usage costs nothing.
+    db::record_llm_usage_daily(
+        &conn_a,
+        "2026-01-30",
+        &profile,
+        "ask_ai",
+        Some(1000),
+        Some(10),
+        Some(1010),
+    )
+    .expect("record unpriced");
+    db::upsert_llm_model_price(
+        &conn_a,
+        &key_a,
+        "openai-compatible",
+        "gpt-4o-mini",
+        0.15,
+        0.6,
+    )
+    .expect("price");
+    db::record_llm_usage_daily(
+        &conn_a,
+        "2026-01-31",
+        &profile,
+        "ask_ai",
+        Some(1_000_000),
+        Some(100_000),
+        Some(1_100_000),
+    )
+    .expect("record ask");
+    db::record_llm_usage_daily(
+        &conn_a,
+        "2026-01-31",
+        &profile,
+        "semantic_parse",
+        None,
+        None,
+        None,
+    )
+    .expect("record without usage");
+    db::record_llm_usage_daily(
+        &conn_a,
+        "2026-02-01",
+        &profile,
+        "ask_ai",
+        Some(2000),
+        Some(1000),
+        Some(3000),
+    )
+    .expect("record february");
+
+    let january = db::sum_llm_cost_monthly(&conn_a, "2026-01").expect("january");
+    assert_eq!(january.len(), 2);
+    let ask = january.iter().find(|c| c.purpose == "ask_ai").expect("ask");
+    assert_eq!(ask.profile_id, profile);
+    assert_eq!(ask.requests, 2);
+    assert_eq!(ask.input_tokens, 1_001_000);
+    assert_eq!(ask.cost_usd_micros, 150_000 + 60_000);
+    let parse = january
+        .iter()
+        .find(|c| c.purpose == "semantic_parse")
+        .expect("parse");
+    assert_eq!((parse.requests, parse.cost_usd_micros), (1, 0));
+    let february = db::sum_llm_cost_monthly(&conn_a, "2026-02").expect("february");
+    assert_eq!(february[0].cost_usd_micros, 300 + 600);
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-llm-prices",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "LlmPrices").expect("push");
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "LlmPrices").expect("pull");
+    let prices_b = db::list_llm_model_prices(&conn_b).expect("prices B");
+    assert_eq!(prices_b.len(), 1);
+    assert_eq!(prices_b[0].model_name, "gpt-4o-mini");
+    assert_eq!(prices_b[0].output_usd_per_million_tokens, 0.6);
+
+    db::delete_llm_model_price(&conn_a, &key_a, "openai-compatible", "gpt-4o-mini")
+        .expect("delete");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "LlmPrices").expect("push delete");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "LlmPrices").expect("pull delete");
+    assert!(db::list_llm_model_prices(&conn_b)
+        .expect("prices B")
+        .is_empty());
+}
+
+#[test]
+fn spending_limits_skip_or_block_profiles() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let primary = create_profile(&conn, &key, "gpt-4o", true);
+    let backup = create_profile(&conn, &key, "gpt-4o-mini", false);
+    db::set_llm_fallback_chain(&conn, &[backup.clone()]).expect("chain");
+    db::upsert_llm_model_price(&conn, &key, "openai-compatible", "gpt-4o", 2.5, 10.0)
+        .expect("price");
+
+    // Usage is recorded by local day; limits apply to that day's month.
+    let day = "2026-01-31";
+    let month = db::llm_usage_month(day);
+    assert_eq!(month, "2026-01");
+    db::record_llm_usage_daily(
+        &conn,
+        day,
+        &primary,
+        "ask_ai",
+        Some(1_000_000),
+        Some(0),
+        Some(1_000_000),
+    )
+    .expect("record");
+
+    let ids_on = |conn: &rusqlite::Connection, day: &str| -> Result<Vec<String>> {
+        Ok(db::load_llm_profile_chain_configs(conn, &key, None, day)?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect())
+    };
+    let ids = |conn: &rusqlite::Connection| ids_on(conn, day);
+    assert!(db::set_llm_spending_limit(&conn, "missing", Some(1)).is_err());
+    db::set_llm_spending_limit(&conn, &primary, Some(3_000_000)).expect("limit under");
+    assert_eq!(
+        ids(&conn).expect("chain"),
+        vec![primary.clone(), backup.clone()]
+    );
+
+    db::set_llm_spending_limit(&conn, &primary, Some(2_500_000)).expect("limit reached");
+    assert_eq!(ids(&conn).expect("chain"), vec![backup.clone()]);
+    assert!(db::check_llm_spending_limit(&conn, &backup, &month).is_ok());
+    // A new local month starts from zero.
+    assert_eq!(
+        ids_on(&conn, "2026-02-01").expect("chain next month"),
+        vec![primary.clone(), backup.clone()]
+    );
+    // Without a local day the current month applies, which has no usage.
+    assert_eq!(
+        ids_on(&conn, "").expect("chain without a day"),
+        vec![primary.clone(), backup.clone()]
+    );
+
+    db::set_llm_spending_limit(&conn, "", Some(1_000_000)).expect("global limit");
+    let err = ids(&conn).expect_err("blocked");
+    assert!(err.to_string().contains("llm_spending_limit_exceeded"));
+    assert_eq!(
+        db::list_llm_spending_limits(&conn).expect("limits").len(),
+        2
+    );
+
+    db::set_llm_spending_limit(&conn, "", None).expect("clear global");
+    db::delete_llm_profile(&conn, &primary).expect("delete primary");
+    assert!(db::list_llm_spending_limits(&conn)
+        .expect("limits")
+        .is_empty());
+}
+
+/// Answers every chat completions request with `body` as an event stream.
+/// Returns the base URL and the request bodies received so far.
+fn serve_openai_sse(body: String) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("local addr");
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+            let mut content_length = 0usize;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+            }
+            let mut request_body = vec![0u8; content_length];
+            let _ = reader.read_exact(&mut request_body);
+            seen.lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&request_body).into_owned());
+
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(resp.as_bytes());
+        }
+    });
+    (format!("http://{addr}/v1"), requests)
+}
+
+#[test]
+fn reported_usage_is_priced_until_the_spending_limit_blocks() {
+    let answer = serde_json::json!({"kind": "none", "confidence": 0.9}).to_string();
+    let chunk = serde_json::json!({
+        "choices": [{"index": 0, "delta": {"role": "assistant", "content": answer}}]
+    });
+    let usage = serde_json::json!({
+        "choices": [],
+        "usage": {"prompt_tokens": 1_000_000, "completion_tokens": 100_000, "total_tokens": 1_100_000}
+    });
+    let (base_url, requests) = serve_openai_sse(format!(
+        "data: {chunk}\n\ndata: {usage}\n\ndata: [DONE]\n\n"
+    ));
+
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    let profile = db::create_llm_profile(
+        &conn,
+        &key,
+        "gpt",
+        "openai-compatible",
+        Some(&base_url),
+        Some("sk-test"),
+        "gpt-4o",
+        true,
+    )
+    .expect("create profile")
+    .id;
+    db::upsert_llm_model_price(&conn, &key, "openai-compatible", "gpt-4o", 2.5, 10.0)
+        .expect("price");
+    db::set_llm_spending_limit(&conn, &profile, Some(3_000_000)).expect("limit");
+
+    let day = "2026-02-06".to_string();
+    let parse = || {
+        core::ai_semantic_parse_ask_ai_time_window(
+            app_dir.to_string_lossy().into_owned(),
+            key.to_vec(),
+            "what did I do last week?".to_string(),
+            "2026-02-06T10:00:00".to_string(),
+            "en".to_string(),
+            1,
+            day.clone(),
+        )
+    };
+
+    let json = parse().expect("first parse");
+    assert!(json.contains("\"none\""), "{json}");
+    let request = requests.lock().unwrap()[0].clone();
+    assert!(request.contains("\"include_usage\":true"), "{request}");
+
+    // 1M input tokens at $2.50 plus 100k output tokens at $10 per million.
+    let row: (i64, i64, i64, i64, i64) = conn
+        .query_row(
+            r#"SELECT requests_with_usage, input_tokens, output_tokens, total_tokens, cost_usd_micros
+               FROM llm_usage_daily
+               WHERE day = ?1 AND profile_id = ?2 AND purpose = 'semantic_parse'"#,
+            [&day, &profile],
+            |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?)),
+        )
+        .expect("usage row");
+    assert_eq!(row, (1, 1_000_000, 100_000, 1_100_000, 3_500_000));
+
+    let err = parse().expect_err("over the limit");
+    assert!(
+        err.to_string().contains("llm_spending_limit_exceeded"),
+        "{err}"
+    );
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn semantic_parse_without_a_local_day_still_runs() {
+    let answer = serde_json::json!({"kind": "none", "confidence": 0.9}).to_string();
+    let chunk = serde_json::json!({
+        "choices": [{"index": 0, "delta": {"role": "assistant", "content": answer}}]
+    });
+    let (base_url, requests) = serve_openai_sse(format!("data: {chunk}\n\ndata: [DONE]\n\n"));
+
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    let profile = db::create_llm_profile(
+        &conn,
+        &key,
+        "gpt",
+        "openai-compatible",
+        Some(&base_url),
+        Some("sk-test"),
+        "gpt-4o",
+        true,
+    )
+    .expect("create profile")
+    .id;
+    db::set_llm_spending_limit(&conn, &profile, Some(3_000_000)).expect("limit");
+
+    let json = core::ai_semantic_parse_ask_ai_time_window(
+        app_dir.to_string_lossy().into_owned(),
+        key.to_vec(),
+        "what did I do last week?".to_string(),
+        "2026-02-06T10:00:00".to_string(),
+        "en".to_string(),
+        1,
+        String::new(),
+    )
+    .expect("parse without a local day");
+    assert!(json.contains("\"none\""), "{json}");
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    let rows: i64 = conn
+        .query_row("SELECT COUNT(*) FROM llm_usage_daily", [], |r| r.get(0))
+        .expect("usage rows");
+    assert_eq!(rows, 0);
+}
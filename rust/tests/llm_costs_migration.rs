@@ -0,0 +1,33 @@
+use rusqlite::Connection;
+
+#[test]
+fn cost_and_failure_reason_migrations_can_rerun() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    drop(secondloop_rust::db::open(&app_dir).expect("create latest schema"));
+
+    // A migration interrupted after its ALTER TABLE leaves the columns in
+    // place with an older user_version; reopening must not fail on them.
+    let conn = Connection::open(app_dir.join("secondloop.sqlite3")).expect("open");
+    conn.execute_batch("PRAGMA user_version = 38;")
+        .expect("rewind user_version");
+    drop(conn);
+
+    let conn = secondloop_rust::db::open(&app_dir).expect("reopen via db::open");
+    let user_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .expect("user_version");
+    assert!(user_version >= 40, "got {user_version}");
+    conn.query_row(
+        "SELECT COUNT(cost_usd_micros) FROM llm_usage_daily",
+        [],
+        |row| row.get::<_, i64>(0),
+    )
+    .expect("cost column");
+    conn.query_row(
+        "SELECT COUNT(last_failure_reason) FROM semantic_parse_jobs",
+        [],
+        |row| row.get::<_, i64>(0),
+    )
+    .expect("failure reason column");
+}
@@ -0,0 +1,273 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::llm::fallback::{
+    classify_llm_error, run_with_fallback, FallbackAnswerProvider, LlmErrorKind, RetryPolicy,
+};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, rag};
+
+fn no_delay(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        base_delay: Duration::ZERO,
+        max_delay: Duration::ZERO,
+    }
+}
+
+/// Fails with `error` for the first `failures` calls, optionally after
+/// streaming a delta, then answers with `answer`.
+struct ScriptedProvider {
+    calls: Arc<AtomicUsize>,
+    failures: usize,
+    error: &'static str,
+    stream_before_error: bool,
+    answer: &'static str,
+}
+
+impl rag::AnswerProvider for ScriptedProvider {
+    fn stream_answer(
+        &self,
+        _prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if call < self.failures {
+            if self.stream_before_error {
+                on_event(ChatDelta {
+                    role: Some("assistant".to_string()),
+                    text_delta: "partial".to_string(),
+                    done: false,
+                    usage: None,
+                })?;
+            }
+            return Err(anyhow!("{}", self.error));
+        }
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: self.answer.to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+}
+
+fn scripted(
+    failures: usize,
+    error: &'static str,
+    answer: &'static str,
+) -> (Box<ScriptedProvider>, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let provider = Box::new(ScriptedProvider {
+        calls: calls.clone(),
+        failures,
+        error,
+        stream_before_error: false,
+        answer,
+    });
+    (provider, calls)
+}
+
+fn collect_answer(provider: &FallbackAnswerProvider) -> Result<String> {
+    let mut text = String::new();
+    rag::AnswerProvider::stream_answer(provider, "question", &mut |ev| {
+        text.push_str(&ev.text_delta);
+        Ok(())
+    })?;
+    Ok(text)
+}
+
+#[test]
+fn errors_are_classified_per_provider() {
+    let http = |status: &str, body: &str| anyhow!("request failed: HTTP {status} {body}");
+
+    assert_eq!(
+        classify_llm_error(
+            "openai-compatible",
+            &http("429 Too Many Requests", "slow down")
+        ),
+        LlmErrorKind::Retryable
+    );
+    assert_eq!(
+        classify_llm_error(
+            "openai-compatible",
+            &http(
+                "429 Too Many Requests",
+                r#"{"error":{"code":"insufficient_quota"}}"#
+            )
+        ),
+        LlmErrorKind::Fallback
+    );
+    assert_eq!(
+        classify_llm_error(
+            "anthropic-compatible",
+            &http("529 <unknown status code>", "")
+        ),
+        LlmErrorKind::Retryable
+    );
+    assert_eq!(
+        classify_llm_error("gemini-compatible", &http("503 Service Unavailable", "")),
+        LlmErrorKind::Retryable
+    );
+    assert_eq!(
+        classify_llm_error("gemini-compatible", &http("401 Unauthorized", "")),
+        LlmErrorKind::Fallback
+    );
+    assert_eq!(
+        classify_llm_error("openai-compatible", &http("400 Bad Request", "")),
+        LlmErrorKind::Fatal
+    );
+    assert_eq!(
+        classify_llm_error(
+            "openai-compatible",
+            &anyhow::Error::new(rag::StreamCancelled)
+        ),
+        LlmErrorKind::Fatal
+    );
+    assert_eq!(
+        classify_llm_error("local-gguf", &anyhow!("local_gguf_runtime_unavailable")),
+        LlmErrorKind::Fallback
+    );
+}
+
+#[test]
+fn backoff_is_jittered_and_capped() {
+    let policy = RetryPolicy {
+        max_attempts: 5,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(300),
+    };
+    for _ in 0..20 {
+        let first = policy.backoff(1);
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+        let second = policy.backoff(2);
+        assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+        let capped = policy.backoff(10);
+        assert!(capped >= Duration::from_millis(150) && capped <= Duration::from_millis(300));
+    }
+}
+
+#[test]
+fn answer_provider_retries_then_falls_back() {
+    let mut chain = FallbackAnswerProvider::new(no_delay(2));
+    let (primary, primary_calls) = scripted(usize::MAX, "request failed: HTTP 503 busy", "");
+    let (backup, backup_calls) = scripted(0, "", "from backup");
+    chain.push("primary", "openai-compatible", primary);
+    chain.push("backup", "gemini-compatible", backup);
+
+    assert_eq!(chain.usage_profile_id(), "primary");
+    assert_eq!(collect_answer(&chain).expect("answer"), "from backup");
+    assert_eq!(primary_calls.load(Ordering::SeqCst), 2);
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(chain.usage_profile_id(), "backup");
+
+    let mut flaky = FallbackAnswerProvider::new(no_delay(3));
+    let (primary, primary_calls) = scripted(1, "request failed: HTTP 429 slow", "retried");
+    flaky.push("primary", "openai-compatible", primary);
+    assert_eq!(collect_answer(&flaky).expect("answer"), "retried");
+    assert_eq!(primary_calls.load(Ordering::SeqCst), 2);
+    assert_eq!(flaky.usage_profile_id(), "primary");
+}
+
+#[test]
+fn answer_provider_stops_on_fatal_or_streamed_errors() {
+    let mut fatal = FallbackAnswerProvider::new(no_delay(3));
+    let (primary, primary_calls) = scripted(usize::MAX, "request failed: HTTP 400 bad", "");
+    let (backup, backup_calls) = scripted(0, "", "unused");
+    fatal.push("primary", "openai-compatible", primary);
+    fatal.push("backup", "openai-compatible", backup);
+    assert!(collect_answer(&fatal).is_err());
+    assert_eq!(primary_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 0);
+
+    let mut streamed = FallbackAnswerProvider::new(no_delay(3));
+    let calls = Arc::new(AtomicUsize::new(0));
+    streamed.push(
+        "primary",
+        "openai-compatible",
+        Box::new(ScriptedProvider {
+            calls: calls.clone(),
+            failures: usize::MAX,
+            error: "request failed: HTTP 503 dropped",
+            stream_before_error: true,
+            answer: "",
+        }),
+    );
+    let (backup, backup_calls) = scripted(0, "", "unused");
+    streamed.push("backup", "openai-compatible", backup);
+    assert!(collect_answer(&streamed).is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(backup_calls.load(Ordering::SeqCst), 0);
+    assert_eq!(streamed.usage_profile_id(), "primary");
+}
+
+#[test]
+fn fallback_chain_is_stored_and_resolved() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let create = |name: &str, active: bool| {
+        db::create_llm_profile(
+            &conn,
+            &key,
+            name,
+            "openai-compatible",
+            Some("http://127.0.0.1:9"),
+            Some("sk-test"),
+            name,
+            active,
+        )
+        .expect("create profile")
+        .id
+    };
+    let a = create("a", true);
+    let b = create("b", false);
+    let c = create("c", false);
+
+    db::set_llm_fallback_chain(&conn, &[c.clone(), b.clone(), a.clone(), c.clone()])
+        .expect("set chain");
+    assert_eq!(
+        db::list_llm_fallback_chain(&conn).expect("list"),
+        vec![c.clone(), b.clone(), a.clone()]
+    );
+    assert!(db::set_llm_fallback_chain(&conn, &["missing".to_string()]).is_err());
+    assert_eq!(db::list_llm_fallback_chain(&conn).expect("list").len(), 3);
+
+    let ids = |primary: Option<&str>| {
+        db::load_llm_profile_chain_configs(&conn, &key, primary, "2026-02-06")
+            .expect("load chain")
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(None), vec![a.clone(), c.clone(), b.clone()]);
+
+    db::delete_llm_profile(&conn, &b).expect("delete b");
+    assert_eq!(ids(Some(&c)), vec![c.clone(), a.clone()]);
+
+    let profiles =
+        db::load_llm_profile_chain_configs(&conn, &key, None, "2026-02-06").expect("load chain");
+    let mut tried = Vec::new();
+    let (answered, result) = run_with_fallback(&profiles, &no_delay(1), |id, profile| {
+        tried.push(id.to_string());
+        if id == a {
+            Err(anyhow!("request failed: HTTP 500 down"))
+        } else {
+            Ok(profile.model_name.clone())
+        }
+    });
+    assert_eq!(tried, vec![a.clone(), c.clone()]);
+    assert_eq!(answered.as_deref(), Some(c.as_str()));
+    assert_eq!(result.expect("answered"), "c");
+}
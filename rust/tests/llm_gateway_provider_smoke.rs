@@ -91,21 +91,25 @@ data: [DONE]
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -176,11 +180,13 @@ fn cloud_gateway_provider_handles_plain_json_with_sse_content_type() {
                 role: Some("assistant".to_string()),
                 text_delta: "fallback-json-content".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
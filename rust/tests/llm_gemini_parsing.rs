@@ -1,6 +1,6 @@
 use std::io::Cursor;
 
-use secondloop_rust::llm::{gemini, ChatDelta};
+use secondloop_rust::llm::{gemini, ChatDelta, LlmUsage};
 
 #[test]
 fn gemini_sse_parses_deltas_and_done() {
@@ -9,7 +9,7 @@ data: {"candidates":[{"content":{"role":"model","parts":[{"text":"Hello"}]}}]}
 
 data: {"candidates":[{"content":{"role":"model","parts":[{"text":" world"}]}}]}
 
-data: {"candidates":[{"content":{"role":"model","parts":[{"text":""}]},"finishReason":"STOP"}]}
+data: {"candidates":[{"content":{"role":"model","parts":[{"text":""}]},"finishReason":"STOP"}],"usageMetadata":{"promptTokenCount":8,"candidatesTokenCount":2,"thoughtsTokenCount":3,"totalTokenCount":13}}
 "#;
 
     let events = gemini::parse_generate_content_sse(sse.as_bytes()).expect("parse sse");
@@ -20,21 +20,25 @@ data: {"candidates":[{"content":{"role":"model","parts":[{"text":""}]},"finishRe
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: Some(LlmUsage::new(8, 5, Some(13))),
             },
         ]
     );
@@ -65,11 +69,13 @@ fn gemini_non_stream_json_parses_message_content() {
                 role: Some("assistant".to_string()),
                 text_delta: "Hello from JSON".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -63,21 +63,25 @@ data: {"candidates":[{"content":{"role":"model","parts":[{"text":""}]},"finishRe
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
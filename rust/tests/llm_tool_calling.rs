@@ -0,0 +1,208 @@
+use secondloop_rust::llm::tools::{ToolCall, ToolChoice, ToolMessage, ToolSpec, ToolTurn};
+use secondloop_rust::llm::{anthropic, gemini, openai, LlmUsage};
+use serde_json::json;
+
+fn history() -> Vec<ToolMessage> {
+    vec![
+        ToolMessage::User("Add milk to my list".to_string()),
+        ToolMessage::Assistant {
+            text: String::new(),
+            tool_calls: vec![
+                ToolCall {
+                    id: "call_a".to_string(),
+                    name: "create_todo".to_string(),
+                    arguments: json!({"title": "Buy milk"}),
+                },
+                ToolCall {
+                    id: "call_b".to_string(),
+                    name: "list_todos".to_string(),
+                    arguments: json!({}),
+                },
+            ],
+        },
+        ToolMessage::ToolResult {
+            call_id: "call_a".to_string(),
+            name: "create_todo".to_string(),
+            content: r#"{"created":{"todo_id":"todo:1"}}"#.to_string(),
+        },
+        ToolMessage::ToolResult {
+            call_id: "call_b".to_string(),
+            name: "list_todos".to_string(),
+            content: r#"{"todos":[]}"#.to_string(),
+        },
+    ]
+}
+
+fn tools() -> Vec<ToolSpec> {
+    vec![ToolSpec {
+        name: "create_todo".to_string(),
+        description: "Create a todo.".to_string(),
+        parameters: json!({"type": "object", "properties": {"title": {"type": "string"}}}),
+    }]
+}
+
+#[test]
+fn openai_tool_request_and_response() {
+    let req = openai::build_chat_completions_tools_request(
+        "gpt",
+        None,
+        &history(),
+        &tools(),
+        ToolChoice::Auto,
+    );
+    assert_eq!(req["stream"], false);
+    assert!(req.get("tool_choice").is_none());
+    assert_eq!(req["tools"][0]["function"]["name"], "create_todo");
+    let messages = req["messages"].as_array().expect("messages");
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[1]["content"], serde_json::Value::Null);
+    assert_eq!(
+        messages[1]["tool_calls"][0]["function"]["arguments"],
+        r#"{"title":"Buy milk"}"#
+    );
+    assert_eq!(messages[3]["role"], "tool");
+    assert_eq!(messages[3]["tool_call_id"], "call_b");
+
+    let body = r#"{"choices":[{"message":{"role":"assistant","content":null,"tool_calls":[
+        {"id":"call_1","type":"function","function":{"name":"list_events","arguments":"{\"start_ms\":1,\"end_ms\":2}"}},
+        {"id":"call_2","type":"function","function":{"name":"create_todo","arguments":"{not json"}}
+    ]}}]}"#;
+    let turn = openai::parse_chat_completions_tool_turn(body.as_bytes()).expect("parse");
+    assert_eq!(
+        turn,
+        ToolTurn {
+            text: String::new(),
+            tool_calls: vec![
+                ToolCall {
+                    id: "call_1".to_string(),
+                    name: "list_events".to_string(),
+                    arguments: json!({"start_ms": 1, "end_ms": 2}),
+                },
+                ToolCall {
+                    id: "call_2".to_string(),
+                    name: "create_todo".to_string(),
+                    arguments: json!("{not json"),
+                },
+            ],
+            usage: None,
+        }
+    );
+
+    let answer = r#"{"choices":[{"message":{"role":"assistant","content":"Done."}}],
+        "usage":{"prompt_tokens":12,"completion_tokens":3,"total_tokens":15}}"#;
+    let turn = openai::parse_chat_completions_tool_turn(answer.as_bytes()).expect("parse");
+    assert_eq!(turn.text, "Done.");
+    assert!(turn.tool_calls.is_empty());
+    assert_eq!(turn.usage, Some(LlmUsage::new(12, 3, Some(15))));
+}
+
+#[test]
+fn anthropic_tool_request_merges_results_and_parses_tool_use() {
+    let req = anthropic::build_messages_tools_request(
+        "claude",
+        512,
+        &history(),
+        &tools(),
+        ToolChoice::Auto,
+    );
+    assert_eq!(req["tools"][0]["input_schema"]["type"], "object");
+    assert!(req.get("tool_choice").is_none());
+    let messages = req["messages"].as_array().expect("messages");
+    // Both tool results share one user turn.
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[1]["content"][1]["type"], "tool_use");
+    assert_eq!(messages[1]["content"][1]["id"], "call_b");
+    assert_eq!(messages[2]["role"], "user");
+    assert_eq!(messages[2]["content"][0]["tool_use_id"], "call_a");
+    assert_eq!(messages[2]["content"][1]["tool_use_id"], "call_b");
+
+    let body = r#"{"role":"assistant","content":[
+        {"type":"text","text":"Let me check."},
+        {"type":"tool_use","id":"toolu_1","name":"search_memories","input":{"query":"milk"}}
+    ],"usage":{"input_tokens":40,"output_tokens":9}}"#;
+    let turn = anthropic::parse_messages_tool_turn(body.as_bytes()).expect("parse");
+    assert_eq!(turn.text, "Let me check.");
+    assert_eq!(
+        turn.tool_calls,
+        vec![ToolCall {
+            id: "toolu_1".to_string(),
+            name: "search_memories".to_string(),
+            arguments: json!({"query": "milk"}),
+        }]
+    );
+    assert_eq!(turn.usage, Some(LlmUsage::new(40, 9, None)));
+}
+
+#[test]
+fn gemini_tool_request_and_function_calls() {
+    let req = gemini::build_generate_content_tools_request(&history(), &tools(), ToolChoice::Auto);
+    assert!(req.get("toolConfig").is_none());
+    assert_eq!(
+        req["tools"][0]["functionDeclarations"][0]["name"],
+        "create_todo"
+    );
+    let contents = req["contents"].as_array().expect("contents");
+    assert_eq!(contents.len(), 3);
+    assert_eq!(contents[1]["role"], "model");
+    assert_eq!(
+        contents[1]["parts"][0]["functionCall"]["args"]["title"],
+        "Buy milk"
+    );
+    assert_eq!(
+        contents[2]["parts"][0]["functionResponse"]["response"]["result"]["created"]["todo_id"],
+        "todo:1"
+    );
+
+    let body = r#"{"candidates":[{"content":{"role":"model","parts":[
+        {"functionCall":{"name":"list_todos","args":{"status":"open"}}},
+        {"functionCall":{"name":"list_events","args":{"start_ms":1,"end_ms":2}}}
+    ]},"finishReason":"STOP"}],
+    "usageMetadata":{"promptTokenCount":30,"candidatesTokenCount":7,"totalTokenCount":41}}"#;
+    let turn = gemini::parse_generate_content_tool_turn(body.as_bytes()).expect("parse");
+    assert_eq!(turn.text, "");
+    assert_eq!(
+        turn.tool_calls
+            .iter()
+            .map(|call| (call.id.as_str(), call.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("call_0", "list_todos"), ("call_1", "list_events")]
+    );
+    assert_eq!(turn.usage, Some(LlmUsage::new(30, 7, Some(41))));
+}
+
+#[test]
+fn forced_text_answer_keeps_tools_declared() {
+    // A history with tool_use blocks is rejected by Anthropic unless the
+    // tools are declared, so a forced text answer sets tool_choice instead.
+    let mut messages = history();
+    messages.push(ToolMessage::User(
+        "Stop calling tools and answer the question now.".to_string(),
+    ));
+    let req = anthropic::build_messages_tools_request(
+        "claude",
+        512,
+        &messages,
+        &tools(),
+        ToolChoice::None,
+    );
+    assert_eq!(req["tools"][0]["name"], "create_todo");
+    assert_eq!(req["tool_choice"], json!({"type": "none"}));
+    assert_eq!(req["messages"][1]["content"][0]["type"], "tool_use");
+
+    let req = openai::build_chat_completions_tools_request(
+        "gpt",
+        None,
+        &messages,
+        &tools(),
+        ToolChoice::None,
+    );
+    assert_eq!(req["tools"][0]["function"]["name"], "create_todo");
+    assert_eq!(req["tool_choice"], "none");
+
+    let req = gemini::build_generate_content_tools_request(&messages, &tools(), ToolChoice::None);
+    assert_eq!(
+        req["tools"][0]["functionDeclarations"][0]["name"],
+        "create_todo"
+    );
+    assert_eq!(req["toolConfig"]["functionCallingConfig"]["mode"], "NONE");
+}
@@ -0,0 +1,272 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use secondloop_rust::db::LlmProfileConfig;
+use secondloop_rust::llm;
+use secondloop_rust::llm::local_gguf::{self, LocalGenerateRequest, LocalLlmRuntime};
+use secondloop_rust::llm::ChatDelta;
+
+fn push_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// A header-only GGUF v3 file, with a string array before the context
+/// length so the reader has to skip it.
+fn tiny_gguf(context_length: u32) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(b"GGUF");
+    buf.extend_from_slice(&3u32.to_le_bytes());
+    buf.extend_from_slice(&0u64.to_le_bytes());
+    buf.extend_from_slice(&4u64.to_le_bytes());
+
+    push_string(&mut buf, "general.architecture");
+    buf.extend_from_slice(&8u32.to_le_bytes());
+    push_string(&mut buf, "llama");
+
+    push_string(&mut buf, "tokenizer.ggml.tokens");
+    buf.extend_from_slice(&9u32.to_le_bytes());
+    buf.extend_from_slice(&8u32.to_le_bytes());
+    buf.extend_from_slice(&3u64.to_le_bytes());
+    for token in ["<s>", "hello", "</s>"] {
+        push_string(&mut buf, token);
+    }
+
+    push_string(&mut buf, "llama.context_length");
+    buf.extend_from_slice(&4u32.to_le_bytes());
+    buf.extend_from_slice(&context_length.to_le_bytes());
+
+    push_string(&mut buf, "general.name");
+    buf.extend_from_slice(&8u32.to_le_bytes());
+    push_string(&mut buf, "Tiny Test");
+    buf
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Streams the words of a canned answer and records each request. Every
+/// prompt character is a token.
+#[derive(Default)]
+struct FakeRuntime {
+    requests: Mutex<Vec<LocalGenerateRequest>>,
+    tokenize_calls: AtomicUsize,
+    context_cap: Option<u32>,
+    broken_tokenizer: bool,
+}
+
+impl LocalLlmRuntime for FakeRuntime {
+    fn generate(
+        &self,
+        request: &LocalGenerateRequest,
+        on_token: &mut dyn FnMut(&str) -> Result<()>,
+    ) -> Result<()> {
+        self.requests.lock().unwrap().push(request.clone());
+        for token in ["Hello", " from", " local"] {
+            on_token(token)?;
+        }
+        Ok(())
+    }
+
+    fn tokenize(&self, _model_path: &Path, text: &str) -> Result<Vec<u32>> {
+        self.tokenize_calls.fetch_add(1, Ordering::SeqCst);
+        if self.broken_tokenizer {
+            return Err(anyhow::anyhow!("tokenizer broke"));
+        }
+        Ok(text.chars().map(u32::from).collect())
+    }
+
+    fn detokenize(&self, _model_path: &Path, tokens: &[u32]) -> Result<String> {
+        Ok(tokens.iter().filter_map(|t| char::from_u32(*t)).collect())
+    }
+
+    fn prompt_overhead_tokens(&self, _model_path: &Path) -> Result<usize> {
+        Ok(2)
+    }
+
+    fn max_context_length(&self, _model_path: &Path) -> Result<Option<u32>> {
+        Ok(self.context_cap)
+    }
+}
+
+fn write_source(dir: &Path, name: &str, bytes: &[u8]) -> std::path::PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, bytes).expect("write source");
+    path
+}
+
+#[test]
+fn gguf_models_are_installed_listed_and_deleted() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let bytes = tiny_gguf(2048);
+    let source = write_source(temp_dir.path(), "tiny-q4.gguf", &bytes);
+
+    let err = local_gguf::install_local_gguf_model(&app_dir, &source, Some("deadbeef"))
+        .expect_err("checksum mismatch");
+    assert!(err.to_string().contains("checksum_mismatch"), "{err}");
+    assert!(local_gguf::list_local_gguf_models(&app_dir)
+        .expect("list")
+        .is_empty());
+
+    let info = local_gguf::install_local_gguf_model(&app_dir, &source, Some(&sha256_hex(&bytes)))
+        .expect("install");
+    assert_eq!(info.file_name, "tiny-q4.gguf");
+    assert_eq!(info.architecture.as_deref(), Some("llama"));
+    assert_eq!(info.name.as_deref(), Some("Tiny Test"));
+    assert_eq!(info.context_length, Some(2048));
+    assert_eq!(info.size_bytes, bytes.len() as u64);
+
+    let not_gguf = write_source(temp_dir.path(), "notes.gguf", b"plain text");
+    assert!(local_gguf::install_local_gguf_model(&app_dir, &not_gguf, None).is_err());
+
+    let models = local_gguf::list_local_gguf_models(&app_dir).expect("list");
+    assert_eq!(models, vec![info]);
+    assert_eq!(
+        local_gguf::local_gguf_model_sha256(&app_dir, "tiny-q4.gguf").expect("sha"),
+        sha256_hex(&bytes)
+    );
+
+    assert!(local_gguf::delete_local_gguf_model(&app_dir, "../secondloop.db").is_err());
+    assert!(local_gguf::delete_local_gguf_model(&app_dir, "tiny-q4.gguf").expect("delete"));
+    assert!(!local_gguf::delete_local_gguf_model(&app_dir, "tiny-q4.gguf").expect("delete again"));
+    assert!(local_gguf::list_local_gguf_models(&app_dir)
+        .expect("list")
+        .is_empty());
+}
+
+#[test]
+fn prompt_budget_keeps_head_and_question() {
+    let model_path = Path::new("model.gguf");
+    let prompt = format!(
+        "INSTRUCTIONS\n{}\nQUESTION: what now?",
+        "context line\n".repeat(1000)
+    );
+    let runtime = FakeRuntime::default();
+    let fitted =
+        local_gguf::fit_prompt_to_context(&runtime, model_path, &prompt, 1024, 512).expect("fit");
+    let fitted_tokens = fitted.chars().count();
+    // 512 minus the 2 tokens of chat template.
+    assert!(fitted_tokens <= 510, "{fitted_tokens}");
+    // The budget is used, not just respected.
+    assert!(fitted_tokens >= 500, "{fitted_tokens}");
+    assert!(fitted.starts_with("INSTRUCTIONS"));
+    assert!(fitted.ends_with("QUESTION: what now?"));
+    // The prompt is tokenized once, plus the elision marker.
+    assert_eq!(runtime.tokenize_calls.load(Ordering::SeqCst), 2);
+
+    let short = "short prompt";
+    assert_eq!(
+        local_gguf::fit_prompt_to_context(&runtime, model_path, short, 1024, 512).expect("fit"),
+        short
+    );
+
+    let broken = FakeRuntime {
+        broken_tokenizer: true,
+        ..FakeRuntime::default()
+    };
+    let err = local_gguf::fit_prompt_to_context(&broken, model_path, &prompt, 1024, 512)
+        .expect_err("tokenizer error");
+    assert!(err.to_string().contains("tokenizer broke"));
+}
+
+#[test]
+fn streamed_bytes_are_split_on_character_boundaries() {
+    let euro = "€".as_bytes();
+    let mut pending = b"price: ".to_vec();
+    pending.extend_from_slice(&euro[..2]);
+    assert_eq!(local_gguf::take_complete_utf8(&mut pending), "price: ");
+    assert_eq!(pending, &euro[..2]);
+
+    pending.extend_from_slice(&euro[2..]);
+    pending.extend_from_slice(b"5");
+    assert_eq!(local_gguf::take_complete_utf8(&mut pending), "€5");
+    assert!(pending.is_empty());
+
+    pending.extend_from_slice(b"\xff!");
+    assert_eq!(local_gguf::take_complete_utf8(&mut pending), "\u{fffd}!");
+    assert!(pending.is_empty());
+}
+
+#[test]
+fn local_gguf_profile_streams_through_registered_runtime() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let source = write_source(temp_dir.path(), "tiny-q4.gguf", &tiny_gguf(1024));
+    local_gguf::install_local_gguf_model(&app_dir, &source, None).expect("install");
+
+    let config = LlmProfileConfig {
+        provider_type: local_gguf::LOCAL_GGUF_PROVIDER_TYPE.to_string(),
+        base_url: None,
+        api_key: None,
+        model_name: "tiny-q4.gguf".to_string(),
+    };
+
+    local_gguf::set_local_llm_runtime(None);
+    let err = match llm::answer_provider_from_profile_in_app_dir(&app_dir, &config) {
+        Ok(_) => panic!("expected missing runtime"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("local_gguf_runtime_unavailable"));
+
+    let runtime = Arc::new(FakeRuntime::default());
+    local_gguf::set_local_llm_runtime(Some(runtime.clone()));
+
+    let missing = LlmProfileConfig {
+        model_name: "missing.gguf".to_string(),
+        ..config.clone()
+    };
+    assert!(llm::answer_provider_from_profile_in_app_dir(&app_dir, &missing).is_err());
+
+    let provider =
+        llm::answer_provider_from_profile_in_app_dir(&app_dir, &config).expect("provider");
+    let mut events: Vec<ChatDelta> = Vec::new();
+    provider
+        .stream_answer(&"x".repeat(10_000), &mut |ev| {
+            events.push(ev);
+            Ok(())
+        })
+        .expect("stream answer");
+
+    let text: String = events.iter().map(|ev| ev.text_delta.as_str()).collect();
+    assert_eq!(text, "Hello from local");
+    assert_eq!(events[0].role.as_deref(), Some("assistant"));
+    assert!(events.last().unwrap().done);
+
+    let requests = runtime.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].context_length, 1024);
+    assert_eq!(requests[0].max_output_tokens, 512);
+    let prompt_tokens = requests[0].prompt.chars().count();
+    assert!((500..=510).contains(&prompt_tokens), "{prompt_tokens}");
+    assert!(requests[0].model_path.ends_with("llm_models/tiny-q4.gguf"));
+    drop(requests);
+
+    let absolute = LlmProfileConfig {
+        model_name: local_gguf::local_gguf_models_dir(&app_dir)
+            .join("tiny-q4.gguf")
+            .to_string_lossy()
+            .into_owned(),
+        ..config
+    };
+    assert!(llm::answer_provider_from_profile(&absolute).is_ok());
+
+    local_gguf::set_local_llm_runtime(None);
+}
+
+#[test]
+fn runtime_context_cap_limits_model_context() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let path = write_source(temp_dir.path(), "long.gguf", &tiny_gguf(32_768));
+    let runtime = Arc::new(FakeRuntime {
+        context_cap: Some(4096),
+        ..FakeRuntime::default()
+    });
+
+    let provider = local_gguf::LocalGgufProvider::new(path, runtime).expect("provider");
+    assert_eq!(provider.context_length(), 4096);
+}
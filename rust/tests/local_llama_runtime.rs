@@ -0,0 +1,28 @@
+#![cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+
+use secondloop_rust::llm::local_gguf;
+use secondloop_rust::llm::local_llama::{llama_cpp_library_path, LlamaCppRuntime};
+
+#[test]
+fn llama_cpp_runtime_is_unavailable_until_a_library_is_installed() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+
+    let err = match local_gguf::ensure_local_llm_runtime(&app_dir) {
+        Ok(_) => panic!("expected missing library"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("local_gguf_runtime_unavailable"));
+    assert!(err.to_string().contains("not found"), "{err}");
+
+    let library_path = llama_cpp_library_path(&app_dir);
+    std::fs::create_dir_all(library_path.parent().unwrap()).expect("mkdir");
+    std::fs::write(&library_path, b"not a shared library").expect("write library");
+    let err = match local_gguf::ensure_local_llm_runtime(&app_dir) {
+        Ok(_) => panic!("expected broken library"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("local_gguf_runtime_unavailable"));
+    assert!(LlamaCppRuntime::load(&library_path).is_err());
+    assert!(local_gguf::local_llm_runtime().is_none());
+}
@@ -28,11 +28,13 @@ fn openai_non_stream_json_parses_message_content() {
                 role: Some("assistant".to_string()),
                 text_delta: "Hello from JSON".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: String::new(),
                 done: true,
+                usage: None,
             }
         ]
     );
@@ -1,4 +1,4 @@
-use secondloop_rust::llm::{openai, ChatDelta};
+use secondloop_rust::llm::{openai, ChatDelta, LlmUsage};
 
 #[test]
 fn openai_sse_parses_deltas_and_done() {
@@ -22,21 +22,31 @@ data: [DONE]
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
+            },
+            ChatDelta {
+                role: None,
+                text_delta: "".to_string(),
+                done: false,
+                usage: Some(LlmUsage::new(10, 20, Some(30))),
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -60,16 +70,19 @@ data: [DONE]
                 role: Some("assistant".to_string()),
                 text_delta: "".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "Hello world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -99,11 +112,13 @@ fn openai_json_parses_content_parts_message() {
                 role: Some("assistant".to_string()),
                 text_delta: "你好，世界".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -126,11 +141,13 @@ data: [DONE]
                 role: None,
                 text_delta: "line-one".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -157,16 +174,19 @@ data: {"type":"response.completed"}
                 role: None,
                 text_delta: "hello".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: " world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -196,11 +216,13 @@ fn openai_json_parses_responses_api_output() {
                 role: Some("assistant".to_string()),
                 text_delta: "hello world".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
@@ -233,11 +255,13 @@ fn openai_sse_falls_back_when_body_is_plain_json_object() {
                 role: Some("assistant".to_string()),
                 text_delta: "{\n  \"kind\": \"none\",\n  \"confidence\": 1.0,\n  \"start_local_iso\": null,\n  \"end_local_iso\": null\n}".to_string(),
                 done: false,
+                usage: None,
             },
             ChatDelta {
                 role: None,
                 text_delta: "".to_string(),
                 done: true,
+                usage: None,
             },
         ]
     );
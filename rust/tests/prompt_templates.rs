@@ -0,0 +1,275 @@
+use anyhow::Result;
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::prompt_templates::{
+    default_prompt_template, render_prompt_template, validate_prompt_template, PromptVariables,
+    PROMPT_TEMPLATE_PURPOSES, PURPOSE_ASK_AI, PURPOSE_MEDIA_ANNOTATION, PURPOSE_SEMANTIC_PARSE,
+};
+use secondloop_rust::{db, rag, semantic_parse, sync};
+
+#[derive(Default)]
+struct FakeProvider {
+    last_prompt: std::sync::Mutex<Option<String>>,
+}
+
+impl rag::AnswerProvider for FakeProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        *self.last_prompt.lock().unwrap() = Some(prompt.to_string());
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: r#"{"kind":"none","confidence":0.1}"#.to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+}
+
+impl FakeProvider {
+    fn prompt(&self) -> String {
+        self.last_prompt.lock().unwrap().clone().expect("prompt")
+    }
+}
+
+#[test]
+fn templates_are_validated_and_rendered() {
+    for purpose in PROMPT_TEMPLATE_PURPOSES {
+        let body = default_prompt_template(purpose).expect("default");
+        validate_prompt_template(purpose, body).expect("default template is valid");
+    }
+
+    assert!(validate_prompt_template("summaries", "{question}").is_err());
+    assert!(validate_prompt_template(PURPOSE_ASK_AI, "  ").is_err());
+    assert!(validate_prompt_template(PURPOSE_ASK_AI, "Q: {question}").is_err());
+    assert!(validate_prompt_template(PURPOSE_ASK_AI, "{question} {contexts} {lang}").is_err());
+    assert!(validate_prompt_template(PURPOSE_MEDIA_ANNOTATION, "Describe it.").is_ok());
+    validate_prompt_template(PURPOSE_ASK_AI, r#"{contexts} {"json": 1} {Question} {question}"#)
+        .expect("non-variable braces are literal");
+
+    let vars = PromptVariables::new()
+        .set("question", "why {contexts}?")
+        .set("contexts", "[1] \"tea\"");
+    assert_eq!(
+        render_prompt_template("Q={question} C={contexts} N={now} {\"k\":1}", &vars),
+        "Q=why {contexts}? C=[1] \"tea\" N={now} {\"k\":1}"
+    );
+}
+
+#[test]
+fn templates_are_selected_per_purpose_and_profile_and_reset() {
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp_dir.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let create_profile = |name: &str, active: bool| {
+        db::create_llm_profile(
+            &conn,
+            &key,
+            name,
+            "openai-compatible",
+            Some("http://127.0.0.1:9"),
+            Some("sk-test"),
+            name,
+            active,
+        )
+        .expect("create profile")
+        .id
+    };
+    let active = create_profile("active", true);
+    let other = create_profile("other", false);
+
+    assert!(
+        db::upsert_prompt_template(&conn, &key, None, "Bad", PURPOSE_ASK_AI, "{question}").is_err()
+    );
+    let global = db::upsert_prompt_template(
+        &conn,
+        &key,
+        None,
+        "Terse",
+        PURPOSE_ASK_AI,
+        "TERSE {contexts} Q: {question}",
+    )
+    .expect("global template");
+    let per_profile = db::upsert_prompt_template(
+        &conn,
+        &key,
+        None,
+        "Verbose",
+        PURPOSE_ASK_AI,
+        "VERBOSE {history} {contexts} {actions} Q: {question}",
+    )
+    .expect("profile template");
+    let parse = db::upsert_prompt_template(
+        &conn,
+        &key,
+        None,
+        "Parse",
+        PURPOSE_SEMANTIC_PARSE,
+        "PARSE {lang} {contexts} MSG {question}",
+    )
+    .expect("parse template");
+    assert_eq!(
+        db::list_prompt_templates(&conn, &key).expect("list").len(),
+        3
+    );
+
+    assert!(db::set_prompt_template_selection(
+        &conn,
+        &key,
+        PURPOSE_SEMANTIC_PARSE,
+        "",
+        Some(&global.id)
+    )
+    .is_err());
+    assert!(db::set_prompt_template_selection(
+        &conn,
+        &key,
+        PURPOSE_ASK_AI,
+        "missing",
+        Some(&global.id)
+    )
+    .is_err());
+    db::set_prompt_template_selection(&conn, &key, PURPOSE_ASK_AI, "", Some(&global.id))
+        .expect("select global");
+    db::set_prompt_template_selection(&conn, &key, PURPOSE_ASK_AI, &other, Some(&per_profile.id))
+        .expect("select per profile");
+    db::set_prompt_template_selection(&conn, &key, PURPOSE_SEMANTIC_PARSE, "", Some(&parse.id))
+        .expect("select parse");
+    assert_eq!(
+        db::list_prompt_template_selections(&conn)
+            .expect("selections")
+            .len(),
+        3
+    );
+
+    let resolve = |purpose: &str, profile: Option<&str>| {
+        db::resolve_prompt_template(&conn, &key, purpose, profile).expect("resolve")
+    };
+    assert_eq!(
+        resolve(PURPOSE_ASK_AI, None).as_deref(),
+        Some(global.body.as_str())
+    );
+    assert_eq!(
+        resolve(PURPOSE_ASK_AI, Some(&other)).as_deref(),
+        Some(per_profile.body.as_str())
+    );
+    assert_eq!(resolve(PURPOSE_MEDIA_ANNOTATION, Some(&active)), None);
+
+    // Ask AI and semantic parse render the selected templates.
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let provider = FakeProvider::default();
+    rag::ask_ai_with_provider_using_active_embeddings(
+        &conn,
+        &key,
+        &app_dir,
+        &conversation.id,
+        "what now?",
+        0,
+        rag::Focus::ThisThread,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask");
+    assert_eq!(provider.prompt(), "TERSE (none) Q: what now?");
+
+    let template = resolve(PURPOSE_SEMANTIC_PARSE, None);
+    semantic_parse::semantic_parse_message_action_json_with_template(
+        &provider,
+        template.as_deref(),
+        "finish the report",
+        "2026-02-03T12:00:00",
+        "en",
+        21 * 60,
+        &[semantic_parse::TodoCandidate {
+            id: "t1".to_string(),
+            title: "Report".to_string(),
+            status: "open".to_string(),
+            due_local_iso: None,
+        }],
+    )
+    .expect("parse");
+    assert_eq!(
+        provider.prompt(),
+        "PARSE en - id=t1 title=Report status=open\n MSG finish the report"
+    );
+
+    // Resetting restores the built-in prompt text or the built-in prompt itself.
+    let reset = db::reset_prompt_template_to_default(&conn, &key, &global.id).expect("reset");
+    assert_eq!(
+        reset.body,
+        default_prompt_template(PURPOSE_ASK_AI).expect("default")
+    );
+    assert_eq!(reset.name, "Terse");
+    db::reset_prompt_template_selections(&conn, PURPOSE_ASK_AI).expect("reset selections");
+    assert_eq!(resolve(PURPOSE_ASK_AI, Some(&other)), None);
+    assert_eq!(
+        resolve(PURPOSE_SEMANTIC_PARSE, None).as_deref(),
+        Some(parse.body.as_str())
+    );
+
+    db::delete_prompt_template(&conn, &key, &parse.id).expect("delete");
+    assert_eq!(resolve(PURPOSE_SEMANTIC_PARSE, None), None);
+    assert!(db::list_prompt_template_selections(&conn)
+        .expect("selections")
+        .is_empty());
+}
+
+#[test]
+fn templates_sync_including_deletes() {
+    let temp_a = tempfile::tempdir().expect("tempdir A");
+    let app_dir_a = temp_a.path().join("secondloop_a");
+    let key_a =
+        auth::init_master_password(&app_dir_a, "pw-a", KdfParams::for_test()).expect("init A");
+    let conn_a = db::open(&app_dir_a).expect("open A db");
+
+    let template = db::upsert_prompt_template(
+        &conn_a,
+        &key_a,
+        None,
+        "Describe",
+        PURPOSE_MEDIA_ANNOTATION,
+        "Describe briefly in {lang}.",
+    )
+    .expect("template");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-prompt-templates",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "PromptTemplates").expect("push");
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "PromptTemplates").expect("pull");
+
+    let templates_b = db::list_prompt_templates(&conn_b, &key_b).expect("templates B");
+    assert_eq!(templates_b.len(), 1);
+    assert_eq!(templates_b[0].id, template.id);
+    assert_eq!(templates_b[0].purpose, PURPOSE_MEDIA_ANNOTATION);
+    assert_eq!(templates_b[0].body, "Describe briefly in {lang}.");
+
+    db::delete_prompt_template(&conn_a, &key_a, &template.id).expect("delete");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "PromptTemplates").expect("push delete");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "PromptTemplates").expect("pull delete");
+    assert!(db::list_prompt_templates(&conn_b, &key_b)
+        .expect("templates B")
+        .is_empty());
+}
@@ -20,11 +20,13 @@ impl rag::AnswerProvider for FakeProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -125,6 +127,7 @@ fn rag_v2_compress_excludes_low_score_sentence() {
         "phoenix",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -182,6 +185,7 @@ fn rag_v2_mmr_promotes_diverse_contexts() {
         "alpha beta",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -235,6 +239,7 @@ fn rag_v2_respects_context_char_budget() {
         "alpha beta",
         2,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -296,6 +301,7 @@ fn rag_v2_includes_audio_transcript_excerpt_in_prompt_memories() {
         "voice note",
         1,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -335,6 +341,7 @@ fn rag_embedder_query_embedding_only_runs_once() {
         "hello",
         1,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
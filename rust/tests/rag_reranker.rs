@@ -0,0 +1,150 @@
+use anyhow::{anyhow, Result};
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, embedding, rag};
+
+/// Records the prompt and answers "OK".
+#[derive(Default)]
+struct PromptRecorder {
+    prompt: std::sync::Mutex<String>,
+}
+
+impl rag::AnswerProvider for PromptRecorder {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        *self.prompt.lock().unwrap() = prompt.to_string();
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: "OK".to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+}
+
+/// Scores documents containing `favorite` highest, or fails when unset.
+struct FakeReranker {
+    favorite: Option<&'static str>,
+    calls: std::sync::Mutex<Vec<(String, usize)>>,
+}
+
+impl FakeReranker {
+    fn new(favorite: Option<&'static str>) -> Self {
+        Self {
+            favorite,
+            calls: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl embedding::Reranker for FakeReranker {
+    fn model_name(&self) -> &str {
+        "fake-reranker"
+    }
+
+    fn score(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((query.to_string(), documents.len()));
+        let favorite = self.favorite.ok_or_else(|| anyhow!("model crashed"))?;
+        Ok(documents
+            .iter()
+            .map(|doc| if doc.contains(favorite) { 0.95 } else { 0.05 })
+            .collect())
+    }
+}
+
+const QUOTED_QUESTION_NOTE: &str = "[1] \"When is the dentist appointment";
+
+fn ask_with(
+    conn: &rusqlite::Connection,
+    key: &[u8; 32],
+    app_dir: &std::path::Path,
+    conversation_id: &str,
+    reranker: Option<&dyn embedding::Reranker>,
+) -> String {
+    let provider = PromptRecorder::default();
+    rag::ask_ai_with_provider_using_active_embeddings_and_queries(
+        conn,
+        key,
+        app_dir,
+        conversation_id,
+        "When is the dentist appointment?",
+        &[],
+        1,
+        rag::Focus::AllMemories,
+        reranker,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask");
+    let prompt = provider.prompt.lock().unwrap().clone();
+    prompt
+}
+
+#[test]
+fn reranker_reorders_candidates_and_falls_back_on_failure() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    db::set_active_embedding_model_name(&conn, embedding::DEFAULT_MODEL_NAME).expect("model");
+    let notes = db::create_conversation(&conn, &key, "Notes").expect("notes");
+    let chat = db::create_conversation(&conn, &key, "Chat").expect("chat");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "When is the dentist appointment? I keep forgetting",
+    )
+    .expect("note");
+    db::insert_message(
+        &conn,
+        &key,
+        &notes.id,
+        "user",
+        "Dr. Lee moved my checkup to Thursday 3pm",
+    )
+    .expect("note");
+
+    // Without a reranker the near-verbatim note wins.
+    let prompt = ask_with(&conn, &key, &app_dir, &chat.id, None);
+    assert!(prompt.contains(QUOTED_QUESTION_NOTE));
+    assert!(!prompt.contains("Thursday 3pm"));
+
+    let reranker = FakeReranker::new(Some("Thursday 3pm"));
+    let prompt = ask_with(&conn, &key, &app_dir, &chat.id, Some(&reranker));
+    assert!(prompt.contains("Thursday 3pm"));
+    assert!(!prompt.contains(QUOTED_QUESTION_NOTE));
+    let calls = reranker.calls.lock().unwrap().clone();
+    assert_eq!(
+        calls,
+        vec![("When is the dentist appointment?".to_string(), 2)]
+    );
+
+    // A failing reranker leaves the heuristic ranking in place.
+    let broken = FakeReranker::new(None);
+    let prompt = ask_with(&conn, &key, &app_dir, &chat.id, Some(&broken));
+    assert!(prompt.contains(QUOTED_QUESTION_NOTE));
+    assert!(!prompt.contains("Thursday 3pm"));
+    assert_eq!(broken.calls.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn reranker_is_optional_until_installed() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    assert!(!embedding::is_reranker_installed(temp.path()));
+    assert!(embedding::installed_reranker(temp.path()).is_none());
+}
@@ -0,0 +1,238 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::KdfParams;
+use secondloop_rust::db;
+
+fn utc_ms(year: i32, month: u8, day: u8, hour: u8) -> i64 {
+    let date = time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+        .expect("date");
+    let time = time::Time::from_hms(hour, 0, 0).expect("time");
+    time::PrimitiveDateTime::new(date, time)
+        .assume_utc()
+        .unix_timestamp()
+        * 1000
+}
+
+fn preview(rule_json: &str, start_at_ms: i64, limit: u32) -> Vec<i64> {
+    db::preview_recurrence_occurrences(rule_json, start_at_ms, limit).expect("preview")
+}
+
+#[test]
+fn preview_expands_byday_bymonthday_and_bymonth() {
+    // Every weekday, starting Friday 2024-11-01.
+    assert_eq!(
+        preview(
+            r#"{"freq":"weekly","byday":["MO","TU","WE","TH","FR"]}"#,
+            utc_ms(2024, 11, 1, 9),
+            7
+        ),
+        vec![
+            utc_ms(2024, 11, 1, 9),
+            utc_ms(2024, 11, 4, 9),
+            utc_ms(2024, 11, 5, 9),
+            utc_ms(2024, 11, 6, 9),
+            utc_ms(2024, 11, 7, 9),
+            utc_ms(2024, 11, 8, 9),
+            utc_ms(2024, 11, 11, 9),
+        ]
+    );
+
+    // Last Friday of the month.
+    assert_eq!(
+        preview(
+            r#"{"freq":"monthly","byday":["-1FR"]}"#,
+            utc_ms(2024, 11, 29, 17),
+            4
+        ),
+        vec![
+            utc_ms(2024, 11, 29, 17),
+            utc_ms(2024, 12, 27, 17),
+            utc_ms(2025, 1, 31, 17),
+            utc_ms(2025, 2, 28, 17),
+        ]
+    );
+
+    // Second Tuesday of the month.
+    assert_eq!(
+        preview(
+            r#"{"freq":"monthly","byday":["2TU"]}"#,
+            utc_ms(2024, 11, 12, 8),
+            3
+        ),
+        vec![
+            utc_ms(2024, 11, 12, 8),
+            utc_ms(2024, 12, 10, 8),
+            utc_ms(2025, 1, 14, 8),
+        ]
+    );
+
+    // Last day of the month does not drift after February.
+    assert_eq!(
+        preview(
+            r#"{"freq":"monthly","bymonthday":[-1]}"#,
+            utc_ms(2025, 1, 31, 12),
+            4
+        ),
+        vec![
+            utc_ms(2025, 1, 31, 12),
+            utc_ms(2025, 2, 28, 12),
+            utc_ms(2025, 3, 31, 12),
+            utc_ms(2025, 4, 30, 12),
+        ]
+    );
+
+    // Leap day only exists every four years.
+    assert_eq!(
+        preview(
+            r#"{"freq":"yearly","bymonth":[2],"bymonthday":[29]}"#,
+            utc_ms(2024, 2, 29, 0),
+            2
+        ),
+        vec![utc_ms(2024, 2, 29, 0), utc_ms(2028, 2, 29, 0)]
+    );
+
+    // Every other week on Monday and Wednesday, weeks starting Sunday.
+    assert_eq!(
+        preview(
+            r#"{"freq":"weekly","interval":2,"byday":["MO","WE"],"wkst":"SU"}"#,
+            utc_ms(2024, 11, 4, 9),
+            4
+        ),
+        vec![
+            utc_ms(2024, 11, 4, 9),
+            utc_ms(2024, 11, 6, 9),
+            utc_ms(2024, 11, 18, 9),
+            utc_ms(2024, 11, 20, 9),
+        ]
+    );
+}
+
+#[test]
+fn preview_honors_count_until_and_exdates() {
+    let start = utc_ms(2024, 11, 1, 9);
+    assert_eq!(
+        preview(r#"{"freq":"daily","count":3}"#, start, 10),
+        vec![start, utc_ms(2024, 11, 2, 9), utc_ms(2024, 11, 3, 9)]
+    );
+
+    let until_rule = format!(
+        r#"{{"freq":"daily","until_ms":{}}}"#,
+        utc_ms(2024, 11, 3, 9)
+    );
+    assert_eq!(preview(&until_rule, start, 10).len(), 3);
+
+    let exdate_rule = format!(
+        r#"{{"freq":"daily","exdates_ms":[{}]}}"#,
+        utc_ms(2024, 11, 2, 9)
+    );
+    assert_eq!(
+        preview(&exdate_rule, start, 3),
+        vec![start, utc_ms(2024, 11, 3, 9), utc_ms(2024, 11, 4, 9)]
+    );
+
+    for invalid in [
+        r#"{"freq":"monthly","byday":["0TU"]}"#,
+        r#"{"freq":"weekly","byday":["2TU"]}"#,
+        r#"{"freq":"monthly","bymonth":[13]}"#,
+        r#"{"freq":"daily","count":0}"#,
+        r#"{"freq":"daily","count":2,"until_ms":1}"#,
+    ] {
+        assert!(
+            db::preview_recurrence_occurrences(invalid, start, 3).is_err(),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn spawning_follows_rule_and_stops_at_count() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:weekday",
+        "Standup",
+        Some(utc_ms(2024, 11, 1, 9)),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert todo");
+    db::upsert_todo_recurrence(
+        &conn,
+        "todo:weekday",
+        "series:weekday",
+        r#"{"freq":"weekly","byday":["MO","TU","WE","TH","FR"],"count":2}"#,
+    )
+    .expect("upsert recurrence");
+
+    db::set_todo_status(&conn, &key, "todo:weekday", "done", None).expect("done #0");
+    let spawned = db::get_todo(&conn, &key, "todo:series:weekday:1").expect("spawned");
+    assert_eq!(spawned.due_at_ms, Some(utc_ms(2024, 11, 4, 9)));
+
+    db::set_todo_status(&conn, &key, "todo:series:weekday:1", "done", None).expect("done #1");
+    assert_eq!(db::list_todos(&conn, &key).expect("todos").len(), 2);
+}
+
+#[test]
+fn this_and_future_split_rebases_count_and_exdates() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    let day_ms = 24 * 60 * 60 * 1000;
+    let start = utc_ms(2024, 11, 1, 9);
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:seed",
+        "Water plants",
+        Some(start),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert seed");
+    let rule = format!(
+        r#"{{"freq":"daily","count":5,"exdates_ms":[{}]}}"#,
+        start + 4 * day_ms
+    );
+    db::upsert_todo_recurrence_with_sync(&conn, &key, "todo:seed", "series:plants", &rule)
+        .expect("upsert recurrence");
+    db::set_todo_status(&conn, &key, "todo:seed", "done", None).expect("done #0");
+    db::set_todo_status(&conn, &key, "todo:series:plants:1", "done", None).expect("done #1");
+
+    db::update_todo_due_with_scope(
+        &conn,
+        &key,
+        "todo:series:plants:2",
+        start + 2 * day_ms + 60 * 60 * 1000,
+        db::TodoRecurrenceEditScope::ThisAndFuture,
+    )
+    .expect("shift future");
+
+    let split_rule: serde_json::Value = serde_json::from_str(
+        &db::get_todo_recurrence_rule_json(&conn, "todo:series:plants:2")
+            .expect("rule query")
+            .expect("rule"),
+    )
+    .expect("rule json");
+    assert_eq!(split_rule["count"], 3);
+    assert_eq!(
+        split_rule["exdates_ms"],
+        serde_json::json!([start + 4 * day_ms + 60 * 60 * 1000])
+    );
+
+    let original_rule = db::get_todo_recurrence_rule_json(&conn, "todo:seed")
+        .expect("rule query")
+        .expect("rule");
+    assert_eq!(original_rule, rule);
+}
@@ -0,0 +1,172 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::sync;
+
+fn utc_ms(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> i64 {
+    let date = time::Date::from_calendar_date(year, time::Month::try_from(month).unwrap(), day)
+        .expect("date");
+    let time = time::Time::from_hms(hour, minute, 0).expect("time");
+    time::PrimitiveDateTime::new(date, time)
+        .assume_utc()
+        .unix_timestamp()
+        * 1000
+}
+
+#[test]
+fn daily_rule_keeps_local_time_across_dst() {
+    // Europe/Berlin springs forward on 2024-03-31: 09:00 local is 08:00Z
+    // before and 07:00Z after.
+    let occurrences = db::preview_recurrence_occurrences(
+        r#"{"freq":"daily","tz":"Europe/Berlin"}"#,
+        utc_ms(2024, 3, 30, 8, 0),
+        3,
+    )
+    .expect("preview");
+    assert_eq!(
+        occurrences,
+        vec![
+            utc_ms(2024, 3, 30, 8, 0),
+            utc_ms(2024, 3, 31, 7, 0),
+            utc_ms(2024, 4, 1, 7, 0),
+        ]
+    );
+
+    // 02:30 does not exist in New York on 2024-03-10; it becomes 03:30 EDT.
+    let gap = db::preview_recurrence_occurrences(
+        r#"{"freq":"daily","tz":"America/New_York"}"#,
+        utc_ms(2024, 3, 9, 7, 30),
+        2,
+    )
+    .expect("preview gap");
+    assert_eq!(gap[1], utc_ms(2024, 3, 10, 7, 30));
+
+    assert!(db::preview_recurrence_occurrences(
+        r#"{"freq":"daily","tz":"Mars/Olympus_Mons"}"#,
+        0,
+        2
+    )
+    .is_err());
+}
+
+#[test]
+fn monthly_rule_uses_todo_timezone_for_the_day() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let app_dir = temp.path().join("secondloop");
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+
+    // 2025-01-31 09:00 in Auckland (UTC+13) is still January 30th in UTC.
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:rent",
+        "Pay rent",
+        Some(utc_ms(2025, 1, 30, 20, 0)),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert todo");
+    assert!(db::set_todo_timezone(&conn, &key, "todo:rent", Some("Nowhere/Else")).is_err());
+    db::set_todo_timezone(&conn, &key, "todo:rent", Some("Pacific/Auckland")).expect("set tz");
+    db::upsert_todo_recurrence(
+        &conn,
+        "todo:rent",
+        "series:rent",
+        r#"{"freq":"monthly","bymonthday":[-1]}"#,
+    )
+    .expect("upsert recurrence");
+
+    db::set_todo_status(&conn, &key, "todo:rent", "done", None).expect("done");
+    let next = db::get_todo(&conn, &key, "todo:series:rent:1").expect("spawned");
+    // Last day of February, 09:00 in Auckland.
+    assert_eq!(next.due_at_ms, Some(utc_ms(2025, 2, 27, 20, 0)));
+    assert_eq!(
+        db::get_todo_timezone(&conn, "todo:series:rent:1").expect("tz"),
+        Some("Pacific/Auckland".to_string())
+    );
+}
+
+#[test]
+fn series_shift_keeps_local_time_and_timezone_syncs() {
+    let temp_a = tempfile::tempdir().expect("tempdir A");
+    let app_dir_a = temp_a.path().join("secondloop_a");
+    let key_a =
+        auth::init_master_password(&app_dir_a, "pw-a", KdfParams::for_test()).expect("init A");
+    let conn_a = db::open(&app_dir_a).expect("open A db");
+
+    db::upsert_todo(
+        &conn_a,
+        &key_a,
+        "todo:seed",
+        "Standup",
+        Some(utc_ms(2024, 3, 30, 8, 0)),
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("upsert seed");
+    db::set_todo_timezone(&conn_a, &key_a, "todo:seed", Some("Europe/Berlin")).expect("set tz");
+    db::upsert_todo_recurrence_with_sync(
+        &conn_a,
+        &key_a,
+        "todo:seed",
+        "series:standup",
+        r#"{"freq":"daily"}"#,
+    )
+    .expect("upsert recurrence");
+    db::set_todo_status(&conn_a, &key_a, "todo:seed", "in_progress", None).expect("progress");
+    db::set_todo_status(&conn_a, &key_a, "todo:seed", "done", None).expect("done");
+    let spawned = db::get_todo(&conn_a, &key_a, "todo:series:standup:1").expect("spawned");
+    assert_eq!(spawned.due_at_ms, Some(utc_ms(2024, 3, 31, 7, 0)));
+
+    // Moving the first occurrence by one local day (23 real hours across the
+    // DST change) moves the next one by one local day too.
+    db::update_todo_due_with_scope(
+        &conn_a,
+        &key_a,
+        "todo:seed",
+        utc_ms(2024, 3, 31, 7, 0),
+        db::TodoRecurrenceEditScope::WholeSeries,
+    )
+    .expect("shift series");
+    let shifted = db::get_todo(&conn_a, &key_a, "todo:series:standup:1").expect("shifted");
+    assert_eq!(shifted.due_at_ms, Some(utc_ms(2024, 4, 1, 7, 0)));
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-todo-tz",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "TodoTz").expect("push");
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "TodoTz").expect("pull");
+
+    for todo_id in ["todo:seed", "todo:series:standup:1"] {
+        assert_eq!(
+            db::get_todo_timezone(&conn_b, todo_id).expect("tz B"),
+            Some("Europe/Berlin".to_string()),
+            "{todo_id}"
+        );
+    }
+
+    db::set_todo_timezone(&conn_a, &key_a, "todo:seed", None).expect("clear tz");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "TodoTz").expect("push clear");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "TodoTz").expect("pull clear");
+    assert_eq!(
+        db::get_todo_timezone(&conn_b, "todo:seed").expect("tz B"),
+        None
+    );
+}
@@ -0,0 +1,224 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::sync;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock")
+        .as_millis()
+        .try_into()
+        .expect("ms")
+}
+
+#[test]
+fn sm2_schedule_grows_intervals_and_resets_on_lapse() {
+    let mut schedule = db::ReviewSchedule::default();
+    let mut intervals = Vec::new();
+    for _ in 0..3 {
+        schedule = db::schedule_review(&schedule, 5).expect("schedule");
+        intervals.push(schedule.interval_days);
+    }
+    // 1 day, 6 days, then 6 * 2.7 (ease after two perfect reviews).
+    assert_eq!(intervals, vec![1, 6, 16]);
+    assert_eq!(schedule.repetitions, 3);
+
+    let lapsed = db::schedule_review(&schedule, 1).expect("lapse");
+    assert_eq!(lapsed.interval_days, 1);
+    assert_eq!(lapsed.repetitions, 0);
+    assert_eq!(lapsed.lapses, 1);
+    assert!(lapsed.ease_factor < schedule.ease_factor);
+
+    let mut hard = db::ReviewSchedule::default();
+    for _ in 0..10 {
+        hard = db::schedule_review(&hard, 0).expect("fail");
+    }
+    assert!((hard.ease_factor - 1.3).abs() < 1e-9);
+    assert!(db::schedule_review(&hard, 6).is_err());
+    assert!(db::schedule_review(&hard, -1).is_err());
+}
+
+#[test]
+fn todos_and_messages_can_be_reviewed() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    let message = db::insert_message(
+        &conn,
+        &key,
+        &conversation.id,
+        "user",
+        "The capital of Mongolia is Ulaanbaatar\nmore detail",
+    )
+    .expect("message");
+    db::upsert_todo(
+        &conn,
+        &key,
+        "todo:1",
+        "Call grandma",
+        None,
+        "open",
+        None,
+        None,
+        None,
+        None,
+    )
+    .expect("todo");
+
+    let now = now_ms();
+    db::mark_for_review(&conn, &key, "todo", "todo:1", Some(now - 1)).expect("mark todo");
+    let marked =
+        db::mark_for_review(&conn, &key, "message", &message.id, None).expect("mark message");
+    assert_eq!(marked.title, "The capital of Mongolia is Ulaanbaatar");
+    assert!(marked.due_at_ms >= now + DAY_MS);
+    assert!(db::mark_for_review(&conn, &key, "event", "event:1", None).is_err());
+    assert!(db::mark_for_review(&conn, &key, "todo", "todo:missing", None).is_err());
+
+    let due_now = db::list_due_reviews(&conn, &key, now, 10).expect("due now");
+    assert_eq!(due_now.len(), 1);
+    assert_eq!(due_now[0].item_id, "todo:1");
+    let due_later = db::list_due_reviews(&conn, &key, now + 2 * DAY_MS, 10).expect("due later");
+    assert_eq!(due_later.len(), 2);
+    assert_eq!(
+        db::list_due_reviews(&conn, &key, now + 2 * DAY_MS, 1)
+            .expect("limited")
+            .len(),
+        1
+    );
+
+    assert_eq!(
+        db::preview_review_intervals(&conn, "todo", "todo:1").expect("preview"),
+        vec![1, 1, 1, 1, 1, 1]
+    );
+    db::record_review(&conn, &key, "todo", "todo:1", 4).expect("first review");
+    assert_eq!(
+        db::preview_review_intervals(&conn, "todo", "todo:1").expect("preview"),
+        vec![1, 1, 1, 6, 6, 6]
+    );
+    let reviewed = db::record_review(&conn, &key, "todo", "todo:1", 5).expect("second review");
+    assert_eq!(reviewed.repetitions, 2);
+    assert_eq!(reviewed.interval_days, 6);
+    let last_reviewed_at_ms = reviewed.last_reviewed_at_ms.expect("reviewed at");
+    assert_eq!(reviewed.due_at_ms, last_reviewed_at_ms + 6 * DAY_MS);
+
+    // The todo's own review fields follow the schedule.
+    let todo = db::get_todo(&conn, &key, "todo:1").expect("todo");
+    assert_eq!(todo.review_stage, Some(2));
+    assert_eq!(todo.next_review_at_ms, Some(reviewed.due_at_ms));
+    assert_eq!(todo.last_review_at_ms, Some(last_reviewed_at_ms));
+
+    let history = db::list_review_activities(&conn, "todo", "todo:1").expect("history");
+    assert_eq!(
+        history.iter().map(|a| a.grade).collect::<Vec<_>>(),
+        vec![4, 5]
+    );
+    assert_eq!(history[1].due_at_ms, reviewed.due_at_ms);
+
+    db::unmark_for_review(&conn, &key, "message", &message.id).expect("unmark");
+    assert!(db::record_review(&conn, &key, "message", &message.id, 5).is_err());
+    assert!(db::list_due_reviews(&conn, &key, now + 30 * DAY_MS, 10)
+        .expect("due")
+        .iter()
+        .all(|item| item.item_kind == "todo"));
+
+    db::set_message_deleted(&conn, &key, &message.id, true).expect("delete message");
+    db::mark_for_review(&conn, &key, "todo", "todo:1", Some(now)).expect("reschedule");
+    db::unmark_for_review(&conn, &key, "todo", "todo:1").expect("unmark todo");
+    let todo = db::get_todo(&conn, &key, "todo:1").expect("todo");
+    assert_eq!(todo.review_stage, None);
+    assert_eq!(todo.next_review_at_ms, None);
+    assert!(db::list_due_reviews(&conn, &key, now + 30 * DAY_MS, 10)
+        .expect("due")
+        .is_empty());
+}
+
+#[test]
+fn review_state_and_history_sync() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "a");
+    let (conn_b, key_b) = open_vault(&temp, "b");
+    db::upsert_todo(
+        &conn_a, &key_a, "todo:1", "Stretch", None, "open", None, None, None, None,
+    )
+    .expect("todo");
+    db::mark_for_review(&conn_a, &key_a, "todo", "todo:1", Some(now_ms())).expect("mark");
+    db::record_review(&conn_a, &key_a, "todo", "todo:1", 3).expect("review");
+    let reviewed = db::record_review(&conn_a, &key_a, "todo", "todo:1", 4).expect("review");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-review-scheduler",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Reviews").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Reviews").expect("pull");
+
+    let synced = db::get_review_item(&conn_b, &key_b, "todo", "todo:1")
+        .expect("get")
+        .expect("synced item");
+    assert_eq!(synced, reviewed);
+    let history = db::list_review_activities(&conn_b, "todo", "todo:1").expect("history");
+    assert_eq!(
+        history.iter().map(|a| a.grade).collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    let todo = db::get_todo(&conn_b, &key_b, "todo:1").expect("todo");
+    assert_eq!(todo.next_review_at_ms, Some(reviewed.due_at_ms));
+}
+
+#[test]
+fn review_ops_do_not_revive_deleted_items() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "a");
+    let (conn_b, key_b) = open_vault(&temp, "b");
+    let conversation = db::create_conversation(&conn_a, &key_a, "Inbox").expect("conversation");
+    let message = db::insert_message(&conn_a, &key_a, &conversation.id, "user", "Flashcard")
+        .expect("message");
+    db::upsert_todo(
+        &conn_a, &key_a, "todo:1", "Stretch", None, "open", None, None, None, None,
+    )
+    .expect("todo");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-review-scheduler",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Reviews").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Reviews").expect("pull");
+
+    for (kind, id) in [("message", message.id.as_str()), ("todo", "todo:1")] {
+        db::mark_for_review(&conn_a, &key_a, kind, id, Some(now_ms())).expect("mark");
+        db::record_review(&conn_a, &key_a, kind, id, 4).expect("review");
+    }
+    db::set_message_deleted(&conn_b, &key_b, &message.id, true).expect("delete message");
+    db::delete_todo_and_associated_messages(&conn_b, &key_b, &temp.path().join("b"), "todo:1")
+        .expect("delete todo");
+
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Reviews").expect("push reviews");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Reviews").expect("pull reviews");
+
+    let review_items: i64 = conn_b
+        .query_row("SELECT COUNT(*) FROM review_items", [], |row| row.get(0))
+        .expect("count review items");
+    assert_eq!(review_items, 0);
+    for (kind, id) in [("message", message.id.as_str()), ("todo", "todo:1")] {
+        assert!(db::list_review_activities(&conn_b, kind, id)
+            .expect("history")
+            .is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use secondloop_rust::llm::gemini::gemini_response_schema;
+use secondloop_rust::llm::json_schema;
+use secondloop_rust::llm::openai::{json_mode_response_format, json_response_format};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::semantic_parse::{self, TodoCandidate};
+use secondloop_rust::{db, rag};
+use serde_json::{json, Value};
+
+/// Answers each JSON request with the next scripted response and records
+/// the prompts and schemas it was given.
+struct ScriptedJsonProvider {
+    responses: Mutex<Vec<String>>,
+    prompts: Mutex<Vec<String>>,
+    schemas: Mutex<Vec<Value>>,
+}
+
+impl ScriptedJsonProvider {
+    fn new(responses: &[&str]) -> Self {
+        Self {
+            responses: Mutex::new(responses.iter().rev().map(|r| r.to_string()).collect()),
+            prompts: Mutex::new(Vec::new()),
+            schemas: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl rag::AnswerProvider for ScriptedJsonProvider {
+    fn stream_answer(
+        &self,
+        _prompt: &str,
+        _on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        panic!("semantic parse should request JSON output");
+    }
+
+    fn stream_answer_json(
+        &self,
+        prompt: &str,
+        schema: &Value,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        self.prompts.lock().unwrap().push(prompt.to_string());
+        self.schemas.lock().unwrap().push(schema.clone());
+        let response = self.responses.lock().unwrap().pop().expect("response");
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: response,
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })
+    }
+}
+
+fn candidates() -> Vec<TodoCandidate> {
+    vec![TodoCandidate {
+        id: "todo:1".to_string(),
+        title: "Taxes".to_string(),
+        status: "open".to_string(),
+        due_local_iso: None,
+    }]
+}
+
+#[test]
+fn schemas_validate_outputs_and_convert_for_providers() {
+    let action = semantic_parse::message_action_schema(&candidates());
+    let valid = [
+        json!({"kind": "none", "confidence": 0.2}),
+        json!({"kind": "followup", "confidence": 0.9, "todo_id": "todo:1", "new_status": "done"}),
+        json!({
+            "kind": "create",
+            "confidence": 0.8,
+            "title": "Fix the TV",
+            "status": "open",
+            "due_local_iso": "2026-02-04T15:00:00",
+            "recurrence": {"freq": "weekly", "interval": 2},
+        }),
+    ];
+    for value in &valid {
+        json_schema::validate(value, &action).expect("valid action");
+    }
+
+    let invalid = [
+        (json!({"kind": "maybe", "confidence": 0.5}), "$.kind"),
+        (json!({"kind": "none"}), "confidence"),
+        (json!({"kind": "none", "confidence": 3}), "maximum"),
+        (
+            json!({"kind": "followup", "confidence": 0.9, "todo_id": "todo:9", "new_status": "done"}),
+            "$.todo_id",
+        ),
+        (json!({"kind": "create", "confidence": 0.9}), "title"),
+        (
+            json!({"kind": "create", "confidence": 0.9, "title": "x", "due_local_iso": "tomorrow"}),
+            "$.due_local_iso",
+        ),
+        (
+            json!({
+                "kind": "create",
+                "confidence": 0.9,
+                "title": "x",
+                "recurrence": {"freq": "weekly", "interval": 0},
+            }),
+            "$.recurrence.interval",
+        ),
+    ];
+    for (value, path) in &invalid {
+        let err = json_schema::validate(value, &action).expect_err("invalid action");
+        assert!(err.contains(path), "{err} should mention {path}");
+    }
+
+    let window = semantic_parse::time_window_schema();
+    json_schema::validate(&json!({"kind": "none", "confidence": 0.1}), &window)
+        .expect("valid window");
+    json_schema::validate(
+        &json!({
+            "kind": "past",
+            "confidence": 0.9,
+            "start_local_iso": "2026-01-26T00:00:00",
+            "end_local_iso": "2026-02-02T00:00:00",
+        }),
+        &window,
+    )
+    .expect("valid window");
+    assert!(json_schema::validate(
+        &json!({"kind": "past", "confidence": 0.9, "start_local_iso": null, "end_local_iso": null}),
+        &window,
+    )
+    .is_err());
+
+    let gemini = gemini_response_schema(&window);
+    assert_eq!(gemini["type"], "OBJECT");
+    assert_eq!(
+        gemini["properties"]["kind"]["enum"],
+        json!(["none", "past", "future", "both"])
+    );
+    assert_eq!(gemini["properties"]["start_local_iso"]["type"], "STRING");
+    assert_eq!(gemini["properties"]["start_local_iso"]["nullable"], true);
+    assert!(gemini["properties"]["start_local_iso"]
+        .get("pattern")
+        .is_none());
+    assert!(gemini.get("if").is_none());
+
+    assert_eq!(
+        json_mode_response_format("Output ONLY JSON."),
+        Some(json!({"type": "json_object"}))
+    );
+    assert_eq!(json_mode_response_format("Answer briefly."), None);
+
+    let structured = json_response_format("openai/gpt-4o-mini", "Answer briefly.", &window)
+        .expect("structured output");
+    assert_eq!(structured["type"], "json_schema");
+    assert_eq!(structured["json_schema"]["schema"], window);
+    assert_eq!(
+        json_response_format("gpt-4o-2024-05-13", "Output ONLY JSON.", &window),
+        Some(json!({"type": "json_object"}))
+    );
+    assert_eq!(
+        json_response_format("llama3.1:8b", "Output ONLY JSON.", &window),
+        Some(json!({"type": "json_object"}))
+    );
+}
+
+#[test]
+fn invalid_output_gets_one_repair_round_trip() {
+    let provider = ScriptedJsonProvider::new(&[
+        r#"{"kind":"followup","confidence":0.9,"todo_id":"todo:404","new_status":"done"}"#,
+        r#"{"kind":"followup","confidence":0.9,"todo_id":"todo:1","new_status":"done"}"#,
+    ]);
+    let result = semantic_parse::semantic_parse_message_action_json(
+        &provider,
+        "I finished taxes",
+        "2026-02-03T12:00:00",
+        "en",
+        21 * 60,
+        &candidates(),
+    )
+    .expect("repaired");
+    let decoded: Value = serde_json::from_str(&result).expect("json");
+    assert_eq!(decoded["todo_id"], "todo:1");
+
+    let prompts = provider.prompts.lock().unwrap();
+    assert_eq!(prompts.len(), 2);
+    assert!(prompts[1].starts_with(prompts[0].trim_end()));
+    assert!(prompts[1].contains("previous output was rejected: $.todo_id"));
+    assert!(prompts[1].contains("todo:404"));
+    let schemas = provider.schemas.lock().unwrap();
+    assert_eq!(schemas[0]["properties"]["kind"]["enum"][1], "followup");
+
+    let provider = ScriptedJsonProvider::new(&[
+        r#"{"kind":"past","confidence":0.9,"start_local_iso":"2026-01-26T00:00:00","end_local_iso":"2026-02-02T00:00:00"}"#,
+    ]);
+    semantic_parse::semantic_parse_ask_ai_time_window_json(
+        &provider,
+        "what did I do last week",
+        "2026-02-03T12:00:00",
+        "en",
+        1,
+    )
+    .expect("valid on the first try");
+    assert_eq!(provider.prompts.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn failure_reasons_are_recorded_on_jobs() {
+    let provider = ScriptedJsonProvider::new(&["I can't help with that.", "Sorry."]);
+    let err = semantic_parse::semantic_parse_ask_ai_time_window_json(
+        &provider,
+        "what did I do last week",
+        "2026-02-03T12:00:00",
+        "en",
+        1,
+    )
+    .expect_err("still invalid after repair");
+    let no_json = err.to_string();
+    assert!(no_json.starts_with("semantic_parse_failed:no_json:"));
+    assert_eq!(provider.prompts.lock().unwrap().len(), 2);
+
+    let provider = ScriptedJsonProvider::new(&[
+        r#"{"kind":"past","confidence":0.9}"#,
+        r#"{"kind":"past","confidence":0.9,"start_local_iso":"last week"}"#,
+    ]);
+    let schema_mismatch = semantic_parse::semantic_parse_ask_ai_time_window_json(
+        &provider,
+        "what did I do last week",
+        "2026-02-03T12:00:00",
+        "en",
+        1,
+    )
+    .expect_err("still invalid after repair")
+    .to_string();
+    assert_eq!(
+        semantic_parse::failure_reason(&schema_mismatch),
+        semantic_parse::FAILURE_REASON_SCHEMA_MISMATCH
+    );
+    assert_eq!(
+        semantic_parse::failure_reason("openai-compatible request failed: HTTP 500"),
+        semantic_parse::FAILURE_REASON_REQUEST_FAILED
+    );
+    assert_eq!(
+        semantic_parse::failure_reason("llm_spending_limit_exceeded: all profiles spent 5 of 1"),
+        semantic_parse::FAILURE_REASON_SPENDING_LIMIT
+    );
+
+    let temp_dir = tempfile::tempdir().expect("tempdir");
+    let conn = db::open(temp_dir.path()).expect("open db");
+    for (i, error) in [&no_json, &schema_mismatch, &schema_mismatch, "HTTP 500"]
+        .iter()
+        .enumerate()
+    {
+        let message_id = format!("msg:{i}");
+        db::enqueue_semantic_parse_job(&conn, &message_id, 1_000).expect("enqueue");
+        db::mark_semantic_parse_job_failed(&conn, &message_id, 1, 2_000, error, 1_001)
+            .expect("failed");
+    }
+    assert_eq!(
+        db::get_semantic_parse_job_failure_reason(&conn, "msg:0")
+            .expect("reason")
+            .as_deref(),
+        Some("no_json")
+    );
+
+    let counts = db::count_semantic_parse_failure_reasons(&conn).expect("counts");
+    let counts: Vec<(&str, i64)> = counts.iter().map(|c| (c.reason.as_str(), c.jobs)).collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("schema_mismatch", 2),
+            ("no_json", 1),
+            ("request_failed", 1)
+        ]
+    );
+
+    db::mark_semantic_parse_job_retry(&conn, "msg:0", 1_002).expect("retry");
+    assert_eq!(
+        db::get_semantic_parse_job_failure_reason(&conn, "msg:0").expect("reason"),
+        None
+    );
+}
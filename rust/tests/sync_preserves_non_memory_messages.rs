@@ -19,11 +19,13 @@ impl rag::AnswerProvider for OkProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -57,6 +59,7 @@ fn sync_preserves_non_memory_messages() {
         "question",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
@@ -19,11 +19,13 @@ impl rag::AnswerProvider for OkProvider {
             role: Some("assistant".to_string()),
             text_delta: "OK".to_string(),
             done: false,
+            usage: None,
         })?;
         on_event(ChatDelta {
             role: None,
             text_delta: String::new(),
             done: true,
+            usage: None,
         })?;
         Ok(())
     }
@@ -50,6 +52,7 @@ fn sync_rag_final_answer_is_synced_via_message_set_v2() {
         "question",
         3,
         rag::Focus::AllMemories,
+        None,
         &provider,
         &mut |_ev| Ok(()),
     )
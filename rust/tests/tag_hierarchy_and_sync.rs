@@ -0,0 +1,134 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::sync;
+
+fn paths(conn: &rusqlite::Connection, key: &[u8; 32]) -> Vec<String> {
+    db::list_tag_tree(conn, key)
+        .expect("tag tree")
+        .into_iter()
+        .filter(|node| !node.tag.is_system || node.depth > 0 || node.path == "work")
+        .map(|node| node.path)
+        .collect()
+}
+
+#[test]
+fn nested_tags_filter_descendants_and_move_as_one_op() {
+    let remote = sync::InMemoryRemoteStore::new();
+    let remote_root = "SecondLoopTagTreeTest";
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-tag-tree",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+
+    let temp_a = tempfile::tempdir().expect("tempdir A");
+    let app_dir_a = temp_a.path().join("secondloop_a");
+    let key_a =
+        auth::init_master_password(&app_dir_a, "pw-a", KdfParams::for_test()).expect("init A");
+    let conn_a = db::open(&app_dir_a).expect("open A db");
+
+    let acme = db::upsert_tag_path(&conn_a, &key_a, "work/clients/acme").expect("path");
+    assert_eq!(acme.name, "acme");
+    let again = db::upsert_tag_path(&conn_a, &key_a, " work / clients / acme ").expect("again");
+    assert_eq!(again.id, acme.id);
+    let globex = db::upsert_tag_path(&conn_a, &key_a, "work/clients/globex").expect("globex");
+
+    let tree = db::list_tag_tree(&conn_a, &key_a).expect("tree");
+    let clients = tree
+        .iter()
+        .find(|node| node.path == "work/clients")
+        .expect("clients node")
+        .clone();
+    assert_eq!(clients.parent_id.as_deref(), Some("system.tag.work"));
+    assert_eq!(clients.depth, 1);
+    assert_eq!(
+        paths(&conn_a, &key_a),
+        vec![
+            "work",
+            "work/clients",
+            "work/clients/acme",
+            "work/clients/globex"
+        ]
+    );
+
+    let conversation = db::create_conversation(&conn_a, &key_a, "Main").expect("conversation");
+    let m1 =
+        db::insert_message(&conn_a, &key_a, &conversation.id, "user", "acme call").expect("m1");
+    let m2 =
+        db::insert_message(&conn_a, &key_a, &conversation.id, "user", "globex call").expect("m2");
+    db::set_message_tags(&conn_a, &key_a, &m1.id, std::slice::from_ref(&acme.id)).expect("tag m1");
+    db::set_message_tags(&conn_a, &key_a, &m2.id, std::slice::from_ref(&globex.id))
+        .expect("tag m2");
+
+    let mut by_parent =
+        db::list_message_ids_by_tag_ids_all(&conn_a, std::slice::from_ref(&clients.tag.id))
+            .expect("by parent");
+    by_parent.sort();
+    let mut expected = vec![m1.id.clone(), m2.id.clone()];
+    expected.sort();
+    assert_eq!(by_parent, expected);
+    assert_eq!(
+        db::list_message_ids_by_tag_ids(&conn_a, &conversation.id, &[acme.id.clone()])
+            .expect("by leaf"),
+        vec![m1.id.clone()]
+    );
+
+    let err =
+        db::set_tag_parent(&conn_a, &key_a, &clients.tag.id, Some(&acme.id)).expect_err("cycle");
+    assert!(err.to_string().contains("descendant"), "{err}");
+    assert!(db::set_tag_parent(&conn_a, &key_a, "system.tag.work", Some(&acme.id)).is_err());
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+
+    sync::push(&conn_a, &key_a, &sync_key, &remote, remote_root).expect("push A");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, remote_root).expect("pull B");
+    assert_eq!(paths(&conn_b, &key_b), paths(&conn_a, &key_a));
+
+    let ops_before: i64 = conn_a
+        .query_row("SELECT COUNT(*) FROM oplog", [], |row| row.get(0))
+        .expect("count ops");
+    db::set_tag_parent(
+        &conn_a,
+        &key_a,
+        &clients.tag.id,
+        Some("system.tag.personal"),
+    )
+    .expect("move subtree");
+    let ops_after: i64 = conn_a
+        .query_row("SELECT COUNT(*) FROM oplog", [], |row| row.get(0))
+        .expect("count ops");
+    assert_eq!(ops_after - ops_before, 1);
+
+    sync::push(&conn_a, &key_a, &sync_key, &remote, remote_root).expect("push move");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, remote_root).expect("pull move");
+    let tree_b = db::list_tag_tree(&conn_b, &key_b).expect("tree B");
+    assert!(tree_b
+        .iter()
+        .any(|node| node.path == "personal/clients/acme" && node.depth == 2));
+    assert!(tree_b.iter().all(|node| node.path != "work/clients"));
+
+    // Deleting a parent keeps its children in the tree, one level up.
+    let notes = db::upsert_tag_path(&conn_a, &key_a, "Project/notes").expect("notes");
+    let project = db::upsert_tag_path(&conn_a, &key_a, "Project").expect("project");
+    let archive = db::upsert_tag(&conn_a, &key_a, "Archive").expect("archive");
+    db::set_message_tags(
+        &conn_a,
+        &key_a,
+        &m1.id,
+        &[acme.id.clone(), project.id.clone()],
+    )
+    .expect("tag project");
+    db::merge_tags(&conn_a, &key_a, &project.id, &archive.id).expect("merge");
+    let tree_a = db::list_tag_tree(&conn_a, &key_a).expect("tree A");
+    let notes_node = tree_a
+        .iter()
+        .find(|node| node.tag.id == notes.id)
+        .expect("notes node");
+    assert_eq!(notes_node.path, "Archive/notes");
+}
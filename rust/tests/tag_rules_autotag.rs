@@ -0,0 +1,210 @@
+use secondloop_rust::auth;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::db;
+use secondloop_rust::sync;
+
+fn tag_names(conn: &rusqlite::Connection, key: &[u8; 32], message_id: &str) -> Vec<String> {
+    let mut names: Vec<String> = db::list_message_tags(conn, key, message_id)
+        .expect("message tags")
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    names.sort();
+    names
+}
+
+fn rule_event_sources(conn: &rusqlite::Connection, message_id: &str) -> Vec<String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT evidence_json FROM message_tag_autofill_events
+             WHERE message_id = ?1 AND decision = 'rule_match' AND applied = 1",
+        )
+        .expect("prepare");
+    let rows = stmt
+        .query_map([message_id], |row| row.get::<_, String>(0))
+        .expect("query");
+    let mut out = Vec::new();
+    for row in rows {
+        let evidence: serde_json::Value = serde_json::from_str(&row.expect("row")).expect("json");
+        out.push(evidence["sources"][0].as_str().expect("source").to_string());
+    }
+    out.sort();
+    out
+}
+
+#[test]
+fn rules_tag_on_insert_edit_and_backfill_and_sync() {
+    let temp_a = tempfile::tempdir().expect("tempdir A");
+    let app_dir_a = temp_a.path().join("secondloop_a");
+    let key_a =
+        auth::init_master_password(&app_dir_a, "pw-a", KdfParams::for_test()).expect("init A");
+    let conn_a = db::open(&app_dir_a).expect("open A db");
+
+    let conversation = db::create_conversation(&conn_a, &key_a, "Main").expect("conversation");
+    let receipts = db::create_conversation(&conn_a, &key_a, "Receipts").expect("receipts");
+    let before = db::insert_message(&conn_a, &key_a, &conversation.id, "user", "Invoice #1234")
+        .expect("before");
+
+    let invoices = db::upsert_tag(&conn_a, &key_a, "Invoices").expect("tag");
+    let photos = db::upsert_tag(&conn_a, &key_a, "Photos").expect("tag");
+    let errands = db::upsert_tag(&conn_a, &key_a, "Errands").expect("tag");
+
+    assert!(db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        None,
+        "bad",
+        db::TAG_RULE_KIND_REGEX,
+        "(unclosed",
+        &invoices.id,
+        true,
+    )
+    .is_err());
+
+    let invoice_rule = db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        None,
+        "Invoice numbers",
+        db::TAG_RULE_KIND_REGEX,
+        r"(?i)invoice #\d+",
+        &invoices.id,
+        true,
+    )
+    .expect("regex rule");
+    let photo_rule = db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        None,
+        "Images",
+        db::TAG_RULE_KIND_ATTACHMENT_MIME,
+        "image/*",
+        &photos.id,
+        true,
+    )
+    .expect("mime rule");
+    db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        None,
+        "Groceries",
+        db::TAG_RULE_KIND_KEYWORD,
+        "buy milk",
+        &errands.id,
+        true,
+    )
+    .expect("keyword rule");
+    db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        None,
+        "Receipts chat",
+        db::TAG_RULE_KIND_CONVERSATION,
+        &receipts.id,
+        &invoices.id,
+        true,
+    )
+    .expect("conversation rule");
+    assert_eq!(db::list_tag_rules(&conn_a, &key_a).expect("rules").len(), 4);
+
+    // Rules run on insert.
+    let errand = db::insert_message(
+        &conn_a,
+        &key_a,
+        &conversation.id,
+        "user",
+        "Remember to BUY  milk",
+    )
+    .expect("errand");
+    assert_eq!(tag_names(&conn_a, &key_a, &errand.id), vec!["Errands"]);
+    let receipt =
+        db::insert_message(&conn_a, &key_a, &receipts.id, "user", "paper").expect("receipt");
+    assert_eq!(tag_names(&conn_a, &key_a, &receipt.id), vec!["Invoices"]);
+
+    // ... on edit ...
+    let edited =
+        db::insert_message(&conn_a, &key_a, &conversation.id, "user", "draft").expect("draft");
+    assert!(tag_names(&conn_a, &key_a, &edited.id).is_empty());
+    db::edit_message(&conn_a, &key_a, &edited.id, "Invoice #77 paid").expect("edit");
+    assert_eq!(tag_names(&conn_a, &key_a, &edited.id), vec!["Invoices"]);
+
+    // ... and when an attachment is linked.
+    let photo_msg =
+        db::insert_message(&conn_a, &key_a, &conversation.id, "user", "look").expect("photo msg");
+    let image =
+        db::insert_attachment(&conn_a, &key_a, &app_dir_a, b"png", "image/png").expect("image");
+    db::link_attachment_to_message(&conn_a, &key_a, &photo_msg.id, &image.sha256).expect("link");
+    assert_eq!(tag_names(&conn_a, &key_a, &photo_msg.id), vec!["Photos"]);
+    assert_eq!(
+        rule_event_sources(&conn_a, &photo_msg.id),
+        vec![format!("rule:{}", photo_rule.id)]
+    );
+
+    // Messages from before the rule existed need a backfill.
+    assert!(tag_names(&conn_a, &key_a, &before.id).is_empty());
+    let applied =
+        db::backfill_tag_rules(&conn_a, &key_a, Some(&invoice_rule.id), 1).expect("backfill");
+    assert_eq!(applied, 1);
+    assert_eq!(tag_names(&conn_a, &key_a, &before.id), vec!["Invoices"]);
+    assert_eq!(
+        rule_event_sources(&conn_a, &before.id),
+        vec![format!("rule:{}", invoice_rule.id)]
+    );
+    assert_eq!(
+        db::backfill_tag_rules(&conn_a, &key_a, None, 2).expect("rerun"),
+        0
+    );
+
+    // Disabled rules do nothing.
+    db::upsert_tag_rule(
+        &conn_a,
+        &key_a,
+        Some(&photo_rule.id),
+        "Images",
+        db::TAG_RULE_KIND_ATTACHMENT_MIME,
+        "image/*",
+        &photos.id,
+        false,
+    )
+    .expect("disable");
+    let second_photo = db::insert_message(&conn_a, &key_a, &conversation.id, "user", "again")
+        .expect("second photo");
+    db::link_attachment_to_message(&conn_a, &key_a, &second_photo.id, &image.sha256)
+        .expect("link again");
+    assert!(tag_names(&conn_a, &key_a, &second_photo.id).is_empty());
+
+    // Rules sync (encrypted) to other devices, including deletions.
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-tag-rules",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "TagRules").expect("push");
+
+    let temp_b = tempfile::tempdir().expect("tempdir B");
+    let app_dir_b = temp_b.path().join("secondloop_b");
+    let key_b =
+        auth::init_master_password(&app_dir_b, "pw-b", KdfParams::for_test()).expect("init B");
+    let conn_b = db::open(&app_dir_b).expect("open B db");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "TagRules").expect("pull");
+
+    let rules_b = db::list_tag_rules(&conn_b, &key_b).expect("rules B");
+    assert_eq!(rules_b.len(), 4);
+    let photo_rule_b = rules_b
+        .iter()
+        .find(|rule| rule.id == photo_rule.id)
+        .expect("photo rule B");
+    assert!(!photo_rule_b.enabled);
+    assert_eq!(photo_rule_b.pattern, "image/*");
+    assert_eq!(tag_names(&conn_b, &key_b, &before.id), vec!["Invoices"]);
+
+    db::delete_tag_rule(&conn_a, &key_a, &invoice_rule.id).expect("delete");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "TagRules").expect("push delete");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "TagRules").expect("pull delete");
+    assert_eq!(
+        db::list_tag_rules(&conn_b, &key_b).expect("rules B").len(),
+        3
+    );
+}
@@ -0,0 +1,260 @@
+use anyhow::Result;
+use secondloop_rust::crypto::{derive_root_key, KdfParams};
+use secondloop_rust::export::jsonl::{export_vault_jsonl, verify_vault_jsonl_export};
+use secondloop_rust::llm::ChatDelta;
+use secondloop_rust::{auth, db, rag, sync};
+
+fn open_vault(dir: &tempfile::TempDir, name: &str) -> (rusqlite::Connection, [u8; 32]) {
+    let app_dir = dir.path().join(name);
+    let key = auth::init_master_password(&app_dir, "pw", KdfParams::for_test()).expect("init");
+    let conn = db::open(&app_dir).expect("open db");
+    (conn, key)
+}
+
+fn create_todo(conn: &rusqlite::Connection, key: &[u8; 32], id: &str, title: &str) {
+    db::upsert_todo(conn, key, id, title, None, "open", None, None, None, None).expect("todo");
+}
+
+fn ids(todos: &[db::Todo]) -> Vec<&str> {
+    todos.iter().map(|t| t.id.as_str()).collect()
+}
+
+#[test]
+fn subtasks_and_dependencies_reject_cycles() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    create_todo(&conn, &key, "todo:trip", "Plan trip");
+    create_todo(&conn, &key, "todo:flights", "Book flights");
+    create_todo(&conn, &key, "todo:seats", "Pick seats");
+    create_todo(&conn, &key, "todo:visa", "Get visa");
+
+    db::set_todo_parent(&conn, &key, "todo:flights", Some("todo:trip")).expect("parent");
+    db::set_todo_parent(&conn, &key, "todo:seats", Some("todo:flights")).expect("nested");
+    assert_eq!(
+        ids(&db::list_todo_subtasks(&conn, &key, "todo:trip").expect("subtasks")),
+        vec!["todo:flights"]
+    );
+    assert!(db::set_todo_parent(&conn, &key, "todo:trip", Some("todo:seats")).is_err());
+    assert!(db::set_todo_parent(&conn, &key, "todo:trip", Some("todo:trip")).is_err());
+    assert!(db::set_todo_parent(&conn, &key, "todo:trip", Some("todo:missing")).is_err());
+
+    db::add_todo_dependency(&conn, &key, "todo:flights", "todo:visa").expect("dependency");
+    db::add_todo_dependency(&conn, &key, "todo:visa", "todo:seats").expect("chain");
+    assert!(db::add_todo_dependency(&conn, &key, "todo:seats", "todo:flights").is_err());
+    assert!(db::add_todo_dependency(&conn, &key, "todo:visa", "todo:visa").is_err());
+    assert_eq!(
+        ids(&db::list_todo_blockers(&conn, &key, "todo:flights").expect("blockers")),
+        vec!["todo:visa"]
+    );
+    assert_eq!(
+        ids(&db::list_todos_blocked_by(&conn, &key, "todo:visa").expect("blocked")),
+        vec!["todo:flights"]
+    );
+
+    // Removing a link makes the reverse direction legal.
+    db::remove_todo_dependency(&conn, &key, "todo:visa", "todo:seats").expect("remove");
+    db::add_todo_dependency(&conn, &key, "todo:seats", "todo:visa").expect("reverse");
+
+    let rollup = db::get_todo_rollup(&conn, &key, "todo:flights").expect("rollup");
+    assert_eq!(rollup.parent_id.as_deref(), Some("todo:trip"));
+    assert_eq!((rollup.subtasks_total, rollup.subtasks_done), (1, 0));
+    assert_eq!(rollup.open_blocker_ids, vec!["todo:visa".to_string()]);
+    db::set_todo_status(&conn, &key, "todo:visa", "done", None).expect("visa done");
+    let rollup = db::get_todo_rollup(&conn, &key, "todo:flights").expect("rollup");
+    assert!(rollup.open_blocker_ids.is_empty());
+
+    // Deleting a todo drops its links; its subtasks become top-level.
+    db::delete_todo_and_associated_messages(&conn, &key, temp.path(), "todo:visa").expect("delete");
+    assert!(db::list_todo_blockers(&conn, &key, "todo:flights")
+        .expect("blockers")
+        .is_empty());
+    db::delete_todo_and_associated_messages(&conn, &key, temp.path(), "todo:trip")
+        .expect("delete parent");
+    assert_eq!(
+        db::get_todo_parent_id(&conn, "todo:flights").expect("parent"),
+        None
+    );
+}
+
+#[test]
+fn completing_last_subtask_completes_opted_in_parents() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    create_todo(&conn, &key, "todo:move", "Move house");
+    create_todo(&conn, &key, "todo:pack", "Pack boxes");
+    create_todo(&conn, &key, "todo:books", "Pack books");
+    create_todo(&conn, &key, "todo:van", "Rent van");
+    create_todo(&conn, &key, "todo:plants", "Rehome plants");
+    for (child, parent) in [
+        ("todo:pack", "todo:move"),
+        ("todo:van", "todo:move"),
+        ("todo:plants", "todo:move"),
+        ("todo:books", "todo:pack"),
+    ] {
+        db::set_todo_parent(&conn, &key, child, Some(parent)).expect("parent");
+    }
+    db::set_todo_complete_with_subtasks(&conn, &key, "todo:move", true).expect("opt in");
+    db::set_todo_complete_with_subtasks(&conn, &key, "todo:pack", true).expect("opt in");
+
+    db::set_todo_status(&conn, &key, "todo:van", "done", None).expect("van");
+    db::set_todo_status(&conn, &key, "todo:plants", "dismissed", None).expect("plants");
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:move").expect("move").status,
+        "open"
+    );
+
+    // Finishing the grandchild completes `pack`, which in turn completes `move`.
+    db::set_todo_status(&conn, &key, "todo:books", "done", None).expect("books");
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:pack").expect("pack").status,
+        "done"
+    );
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:move").expect("move").status,
+        "done"
+    );
+
+    // Opting in after the fact completes a parent whose subtasks are all done.
+    create_todo(&conn, &key, "todo:taxes", "File taxes");
+    create_todo(&conn, &key, "todo:receipts", "Collect receipts");
+    db::set_todo_parent(&conn, &key, "todo:receipts", Some("todo:taxes")).expect("parent");
+    db::set_todo_status(&conn, &key, "todo:receipts", "done", None).expect("receipts");
+    assert_eq!(
+        db::get_todo(&conn, &key, "todo:taxes")
+            .expect("taxes")
+            .status,
+        "open"
+    );
+    let taxes =
+        db::set_todo_complete_with_subtasks(&conn, &key, "todo:taxes", true).expect("opt in");
+    assert_eq!(taxes.status, "done");
+}
+
+#[test]
+fn hierarchy_syncs_and_exports() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn_a, key_a) = open_vault(&temp, "a");
+    let (conn_b, key_b) = open_vault(&temp, "b");
+    create_todo(&conn_a, &key_a, "todo:launch", "Launch site");
+    create_todo(&conn_a, &key_a, "todo:copy", "Write copy");
+    create_todo(&conn_a, &key_a, "todo:dns", "Configure DNS");
+    db::set_todo_parent(&conn_a, &key_a, "todo:copy", Some("todo:launch")).expect("parent");
+    db::set_todo_complete_with_subtasks(&conn_a, &key_a, "todo:launch", true).expect("opt in");
+    db::add_todo_dependency(&conn_a, &key_a, "todo:launch", "todo:dns").expect("dependency");
+    db::add_todo_dependency(&conn_a, &key_a, "todo:copy", "todo:dns").expect("dependency");
+    db::remove_todo_dependency(&conn_a, &key_a, "todo:copy", "todo:dns").expect("remove");
+
+    let remote = sync::InMemoryRemoteStore::new();
+    let sync_key = derive_root_key(
+        "sync-passphrase",
+        b"secondloop-sync-todo-hierarchy",
+        &KdfParams::for_test(),
+    )
+    .expect("derive sync key");
+    sync::push(&conn_a, &key_a, &sync_key, &remote, "Todos").expect("push");
+    sync::pull(&conn_b, &key_b, &sync_key, &remote, "Todos").expect("pull");
+
+    assert_eq!(
+        ids(&db::list_todo_subtasks(&conn_b, &key_b, "todo:launch").expect("subtasks")),
+        vec!["todo:copy"]
+    );
+    let rollup = db::get_todo_rollup(&conn_b, &key_b, "todo:launch").expect("rollup");
+    assert!(rollup.complete_with_subtasks);
+    assert_eq!(rollup.open_blocker_ids, vec!["todo:dns".to_string()]);
+    assert!(db::list_todo_blockers(&conn_b, &key_b, "todo:copy")
+        .expect("blockers")
+        .is_empty());
+
+    let out_dir = temp.path().join("export");
+    export_vault_jsonl(&conn_a, &key_a, &out_dir, 1_730_900_000_000).expect("export");
+    let dependencies =
+        std::fs::read_to_string(out_dir.join("todo_dependencies.jsonl")).expect("dependencies");
+    let dependencies = dependencies
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("json"))
+        .collect::<Vec<_>>();
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0]["todo_id"], "todo:launch");
+    assert_eq!(dependencies[0]["blocked_by_todo_id"], "todo:dns");
+    let todos = std::fs::read_to_string(out_dir.join("todos.jsonl")).expect("todos");
+    assert!(todos.lines().any(|line| {
+        let todo: serde_json::Value = serde_json::from_str(line).expect("json");
+        todo["id"] == "todo:copy" && todo["parent_id"] == "todo:launch"
+    }));
+    let verification = verify_vault_jsonl_export(&out_dir).expect("verify");
+    assert!(
+        verification.problems.is_empty(),
+        "{:?}",
+        verification.problems
+    );
+}
+
+#[derive(Default)]
+struct FakeProvider {
+    last_prompt: std::sync::Mutex<Option<String>>,
+}
+
+impl rag::AnswerProvider for FakeProvider {
+    fn stream_answer(
+        &self,
+        prompt: &str,
+        on_event: &mut dyn FnMut(ChatDelta) -> Result<()>,
+    ) -> Result<()> {
+        *self.last_prompt.lock().unwrap() = Some(prompt.to_string());
+        on_event(ChatDelta {
+            role: Some("assistant".to_string()),
+            text_delta: "OK".to_string(),
+            done: false,
+            usage: None,
+        })?;
+        on_event(ChatDelta {
+            role: None,
+            text_delta: String::new(),
+            done: true,
+            usage: None,
+        })?;
+        Ok(())
+    }
+}
+
+#[test]
+fn ask_ai_blocking_question_includes_hierarchy() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let (conn, key) = open_vault(&temp, "secondloop");
+    let conversation = db::create_conversation(&conn, &key, "Inbox").expect("conversation");
+    create_todo(&conn, &key, "todo:release", "Ship release");
+    create_todo(&conn, &key, "todo:notes", "Write release notes");
+    create_todo(&conn, &key, "todo:review", "Security review");
+    create_todo(&conn, &key, "todo:groceries", "Buy groceries");
+    db::set_todo_parent(&conn, &key, "todo:notes", Some("todo:release")).expect("parent");
+    db::add_todo_dependency(&conn, &key, "todo:release", "todo:review").expect("dependency");
+
+    let provider = FakeProvider::default();
+    rag::ask_ai_with_provider(
+        &conn,
+        &key,
+        &conversation.id,
+        "What's blocking Ship release?",
+        0,
+        rag::Focus::AllMemories,
+        None,
+        &provider,
+        &mut |_ev| Ok(()),
+    )
+    .expect("ask");
+
+    let prompt = provider
+        .last_prompt
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("prompt");
+    let release_line = prompt
+        .lines()
+        .find(|line| line.starts_with("- TODO [open] Ship release"))
+        .expect("release line");
+    assert!(release_line.contains("subtasks 0/1 done"));
+    assert!(release_line.contains("blocked by \"Security review\" [open]"));
+    assert!(prompt.contains("Write release notes (subtask of \"Ship release\")"));
+    assert!(!prompt.contains("Buy groceries"));
+}